@@ -9,7 +9,7 @@ use reth_beacon_consensus::{
     hooks::{EngineHooks, PruneHook},
     BeaconConsensusEngine, MIN_BLOCKS_FOR_PIPELINE_RUN,
 };
-use reth_blockchain_tree::{config::BlockchainTreeConfig, ShareableBlockchainTree};
+use reth_blockchain_tree::ShareableBlockchainTree;
 use reth_config::Config;
 use reth_db::{
     database::Database,
@@ -25,10 +25,13 @@ use reth_node_core::{
         db_type::DatabaseInstance,
         ext::{DefaultRethNodeCommandConfig, RethCliExt, RethNodeCommandConfig},
     },
+    datadir_version::{check_datadir_version, record_datadir_version},
     dirs::{ChainPath, DataDirPath},
     events::cl::ConsensusLayerHealthEvents,
     exit::NodeExitFuture,
-    init::init_genesis,
+    fork_schedule::ForkScheduleWatcher,
+    init::{init_genesis, load_paris_block},
+    logs_bloom_index::LogsBloomIndexer,
     version::SHORT_VERSION,
 };
 #[cfg(not(feature = "optimism"))]
@@ -38,6 +41,7 @@ use reth_node_optimism::{OptimismEngineTypes, OptimismEvmConfig};
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_provider::{providers::BlockchainProvider, ProviderFactory};
 use reth_prune::PrunerBuilder;
+use reth_revm::EvmProcessorFactory;
 use reth_rpc_engine_api::EngineApi;
 use reth_tasks::{TaskExecutor, TaskManager};
 use reth_transaction_pool::TransactionPool;
@@ -45,8 +49,8 @@ use std::{path::PathBuf, sync::Arc};
 use tokio::sync::{mpsc::unbounded_channel, oneshot};
 use tracing::*;
 
-/// Re-export `NodeConfig` from `reth_node_core`.
-pub use reth_node_core::node_config::NodeConfig;
+/// Re-export `NodeConfig` and `NodeListenAddrs` from `reth_node_core`.
+pub use reth_node_core::node_config::{NodeConfig, NodeListenAddrs};
 
 /// Launches the node, also adding any RPC extensions passed.
 ///
@@ -81,8 +85,17 @@ pub async fn launch_from_config<E: RethCliExt>(
     config.install_prometheus_recorder()?;
 
     let database = std::mem::take(&mut config.database);
+    // Refuse to open a data directory in a format this binary may not understand, the version is
+    // only recorded once the database was opened successfully.
+    let data_dir = database.data_dir(config.chain.chain);
+    if let Some(data_dir) = &data_dir {
+        check_datadir_version(&data_dir.data_dir_path(), config.allow_downgrade)?;
+    }
     let db_instance = database.init_db(config.db.log_level, config.chain.chain)?;
     info!(target: "reth::cli", "Database opened");
+    if let Some(data_dir) = &data_dir {
+        record_datadir_version(&data_dir.data_dir_path())?;
+    }
 
     match db_instance {
         DatabaseInstance::Real { db, data_dir } => {
@@ -121,11 +134,18 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
         // Does not do anything on windows.
         raise_fd_limit()?;
 
+        // Fail early on an environment the node can't run in.
+        self.config.run_preflight_checks(&self.data_dir)?;
+
         // get config
         let config = self.load_config()?;
 
         let prometheus_handle = self.config.install_prometheus_recorder()?;
 
+        // the chain spec of a custom chain may not know its Paris block, apply the one observed on
+        // previous runs
+        self.config.chain = load_paris_block(Arc::clone(&self.db), self.config.chain.clone())?;
+
         let mut provider_factory =
             ProviderFactory::new(Arc::clone(&self.db), Arc::clone(&self.config.chain));
 
@@ -133,7 +153,7 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
         let snapshotter = reth_snapshot::Snapshotter::new(
             provider_factory.clone(),
             self.data_dir.snapshots_path(),
-            self.config.chain.snapshot_block_interval,
+            self.config.chain.snapshot_config.clone(),
         )?;
 
         provider_factory = provider_factory.with_snapshots(
@@ -141,7 +161,14 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
             snapshotter.highest_snapshot_receiver(),
         )?;
 
-        self.config.start_metrics_endpoint(prometheus_handle, Arc::clone(&self.db)).await?;
+        if !self.config.rpc.rpc_disable_logs_bloom_index {
+            debug!(target: "reth::cli", "Spawning logs bloom indexer task");
+            let indexer = LogsBloomIndexer::new(provider_factory.clone());
+            executor.spawn_critical_blocking("logs bloom indexer", indexer.run());
+        }
+
+        let metrics_addr =
+            self.config.start_metrics_endpoint(prometheus_handle, Arc::clone(&self.db)).await?;
 
         debug!(target: "reth::cli", chain=%self.config.chain.chain, genesis=?self.config.chain.genesis_hash(), "Initializing genesis");
 
@@ -160,7 +187,11 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
             .config
             .pruning
             .prune_config(Arc::clone(&self.config.chain))?
-            .or(config.prune.clone());
+            .or(config.prune.clone())
+            .map(|mut prune| {
+                prune.segments = prune.segments.with_deposit_contract_guard(&self.config.chain);
+                prune
+            });
 
         // TODO: stateful node builder should be able to remove cfgs here
         #[cfg(feature = "optimism")]
@@ -170,15 +201,18 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
         #[cfg(not(feature = "optimism"))]
         let evm_config = EthEvmConfig::default();
 
+        // the executors of the tree and the pipeline share the chain spec of the factory
+        let executor_factory = EvmProcessorFactory::new(self.config.chain.clone(), evm_config);
+
         // configure blockchain tree
-        let tree_config = BlockchainTreeConfig::default();
+        let tree_config = self.config.tree_config();
         let tree = self.config.build_blockchain_tree(
             provider_factory.clone(),
             consensus.clone(),
             prune_config.clone(),
             sync_metrics_tx.clone(),
             tree_config,
-            evm_config,
+            executor_factory.clone(),
         )?;
         let canon_state_notification_sender = tree.canon_state_notification_sender();
         let blockchain_tree = ShareableBlockchainTree::new(tree);
@@ -189,6 +223,9 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
             .config
             .lookup_head(provider_factory.clone())
             .wrap_err("the head block is missing")?;
+        if let Some((fork, condition)) = self.config.chain.next_fork_after(&head) {
+            info!(target: "reth::cli", ?fork, ?condition, "Next hardfork scheduled");
+        }
 
         // setup the blockchain provider
         let blockchain_db =
@@ -237,6 +274,18 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
 
         ext.on_components_initialized(&components)?;
 
+        if let Some(path) = self.config.watch_chain_file.clone() {
+            debug!(target: "reth::cli", path=%path.display(), "Spawning fork schedule watcher task");
+            let watcher = ForkScheduleWatcher::new(
+                path,
+                self.config.chain.clone(),
+                blockchain_db.clone(),
+                network.clone(),
+                executor_factory.clone(),
+            );
+            executor.spawn_blocking(watcher.run());
+        }
+
         debug!(target: "reth::cli", "Spawning payload builder service");
 
         // TODO: stateful node builder should handle this in with_payload_builder
@@ -284,6 +333,7 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
                 mining_mode,
                 evm_config,
             )
+            .prevrandao_seed(self.config.dev.prevrandao_seed)
             .build();
 
             let mut pipeline = self
@@ -297,7 +347,7 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
                     sync_metrics_tx,
                     prune_config.clone(),
                     max_block,
-                    evm_config,
+                    executor_factory.clone(),
                 )
                 .await?;
 
@@ -319,7 +369,7 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
                     sync_metrics_tx,
                     prune_config.clone(),
                     max_block,
-                    evm_config,
+                    executor_factory.clone(),
                 )
                 .await?;
 
@@ -334,7 +384,7 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
         let pruner_events = if let Some(prune_config) = prune_config {
             let mut pruner = PrunerBuilder::new(prune_config.clone())
                 .max_reorg_depth(tree_config.max_reorg_depth() as usize)
-                .prune_delete_limit(self.config.chain.prune_delete_limit)
+                .chain_prune_config(self.config.chain.prune_config.clone())
                 .build(provider_factory, snapshotter.highest_snapshot_receiver());
 
             let events = pruner.events();
@@ -383,6 +433,7 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
             reth_node_core::events::node::handle_events(
                 Some(network.clone()),
                 Some(head.number),
+                self.config.chain.block_duration(),
                 events,
                 self.db.clone(),
             ),
@@ -439,9 +490,18 @@ impl<DB: Database + DatabaseMetrics + DatabaseMetadata + 'static> NodeBuilderWit
             .await?;
         }
 
+        let listen_addrs = NodeListenAddrs {
+            p2p: network.local_addr(),
+            http: rpc_server_handles.rpc.http_local_addr(),
+            ws: rpc_server_handles.rpc.ws_local_addr(),
+            auth: rpc_server_handles.auth.local_addr(),
+            metrics: metrics_addr,
+        };
+
         // construct node handle and return
         let node_handle = NodeHandle {
             rpc_server_handles,
+            listen_addrs,
             node_exit_future: NodeExitFuture::new(rx, self.config.debug.terminate),
         };
         Ok(node_handle)
@@ -482,6 +542,9 @@ pub struct NodeHandle {
     /// The handles to the RPC servers
     rpc_server_handles: RethRpcServerHandles,
 
+    /// The addresses the listeners of the node are bound to
+    listen_addrs: NodeListenAddrs,
+
     /// A Future which waits node exit
     /// See [`NodeExitFuture`]
     node_exit_future: NodeExitFuture,
@@ -493,6 +556,11 @@ impl NodeHandle {
         &self.rpc_server_handles
     }
 
+    /// Returns the addresses the listeners of the node are bound to.
+    pub fn listen_addrs(&self) -> NodeListenAddrs {
+        self.listen_addrs
+    }
+
     /// Waits for the node to exit, if it was configured to exit.
     pub async fn wait_for_node_exit(self) -> eyre::Result<()> {
         self.node_exit_future.await
@@ -557,6 +625,25 @@ mod tests {
         assert!(maybe_client.is_none());
     }
 
+    #[tokio::test]
+    async fn listen_addrs_with_unused_ports() {
+        let rpc_args = RpcServerArgs::default().with_http().with_unused_ports();
+        let (first, _first_manager) =
+            spawn_node(NodeConfig::test().with_rpc(rpc_args.clone())).await.unwrap();
+        let (second, _second_manager) =
+            spawn_node(NodeConfig::test().with_rpc(rpc_args)).await.unwrap();
+
+        let (first, second) = (first.listen_addrs(), second.listen_addrs());
+        assert_ne!(first.p2p.port(), 0);
+        assert_ne!(first.p2p, second.p2p);
+        assert_ne!(first.auth.port(), 0);
+        assert_ne!(first.auth, second.auth);
+        assert!(first.http.is_some_and(|addr| addr.port() != 0));
+        assert_ne!(first.http, second.http);
+        assert_eq!(first.ws, None);
+        assert_eq!(first.metrics, None);
+    }
+
     #[tokio::test]
     async fn launch_multiple_nodes() {
         // spawn_test_node takes roughly 1 second per node, so this test takes ~4 seconds
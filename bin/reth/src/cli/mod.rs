@@ -7,7 +7,8 @@ use crate::{
     },
     cli::ext::RethCliExt,
     commands::{
-        config_cmd, db, debug_cmd, import, init_cmd, node, p2p, recover, stage, test_vectors,
+        chain_spec, config_cmd, db, debug_cmd, import, init_cmd, migrate_geth, node, p2p, recover,
+        stage, stats, test_vectors, tx,
     },
     core::cli::runner::CliRunner,
     version::{LONG_VERSION, SHORT_VERSION},
@@ -81,11 +82,17 @@ impl<Ext: RethCliExt> Cli<Ext> {
             Commands::Node(command) => runner.run_command_until_exit(|ctx| command.execute(ctx)),
             Commands::Init(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::Import(command) => runner.run_blocking_until_ctrl_c(command.execute()),
+            Commands::ImportGethAncient(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute())
+            }
             Commands::Db(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::Stage(command) => runner.run_blocking_until_ctrl_c(command.execute()),
+            Commands::Stats(command) => runner.run_blocking_until_ctrl_c(command.execute()),
+            Commands::ChainSpec(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::P2P(command) => runner.run_until_ctrl_c(command.execute()),
             Commands::TestVectors(command) => runner.run_until_ctrl_c(command.execute()),
             Commands::Config(command) => runner.run_until_ctrl_c(command.execute()),
+            Commands::Tx(command) => runner.run_until_ctrl_c(command.execute()),
             Commands::Debug(command) => runner.run_command_until_exit(|ctx| command.execute(ctx)),
             Commands::Recover(command) => runner.run_command_until_exit(|ctx| command.execute(ctx)),
         }
@@ -128,12 +135,23 @@ pub enum Commands<Ext: RethCliExt = ()> {
     /// This syncs RLP encoded blocks from a file.
     #[command(name = "import")]
     Import(import::ImportCommand),
+    /// This syncs the block history from the ancient store of a local geth node.
+    ///
+    /// Only the ancient store is read, geth's LevelDB or Pebble key-value store is not supported.
+    #[command(name = "import-geth-ancient")]
+    ImportGethAncient(migrate_geth::ImportGethAncientCommand),
     /// Database debugging utilities
     #[command(name = "db")]
     Db(db::Command),
     /// Manipulate individual stages.
     #[command(name = "stage")]
     Stage(stage::Command),
+    /// Statistics gathered from executed blocks
+    #[command(name = "stats")]
+    Stats(stats::Command),
+    /// Inspect the chain specification and the hardforks processed by the node
+    #[command(name = "chain-spec")]
+    ChainSpec(chain_spec::Command),
     /// P2P Debugging utilities
     #[command(name = "p2p")]
     P2P(p2p::Command),
@@ -143,6 +161,9 @@ pub enum Commands<Ext: RethCliExt = ()> {
     /// Write config to stdout
     #[command(name = "config")]
     Config(config_cmd::Command),
+    /// Decode and sign raw transactions
+    #[command(name = "tx")]
+    Tx(tx::Command),
     /// Various debug routines
     #[command(name = "debug")]
     Debug(debug_cmd::Command),
@@ -0,0 +1,104 @@
+//! Inspect the chain specification the node runs with
+
+use crate::{
+    args::{
+        utils::{chain_help, genesis_value_parser, SUPPORTED_CHAINS},
+        DatabaseArgs,
+    },
+    dirs::{DataDirPath, MaybePlatformPath},
+};
+use clap::{Parser, Subcommand};
+use comfy_table::{Cell, Row, Table as ComfyTable};
+use reth_db::{mdbx::DatabaseArguments, open_db_read_only};
+use reth_primitives::{hex, ChainSpec, ForkId};
+use reth_provider::{ForkTransitionsReader, ProviderFactory};
+use std::sync::Arc;
+
+/// `reth chain-spec` command
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The path to the data dir for all reth files and subdirectories.
+    ///
+    /// Defaults to the OS-specific data directory:
+    ///
+    /// - Linux: `$XDG_DATA_HOME/reth/` or `$HOME/.local/share/reth/`
+    /// - Windows: `{FOLDERID_RoamingAppData}/reth/`
+    /// - macOS: `$HOME/Library/Application Support/reth/`
+    #[arg(long, value_name = "DATA_DIR", verbatim_doc_comment, default_value_t, global = true)]
+    datadir: MaybePlatformPath<DataDirPath>,
+
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = chain_help(),
+        default_value = SUPPORTED_CHAINS[0],
+        value_parser = genesis_value_parser,
+        global = true,
+    )]
+    chain: Arc<ChainSpec>,
+
+    #[clap(flatten)]
+    db: DatabaseArgs,
+
+    #[clap(subcommand)]
+    command: Subcommands,
+}
+
+#[derive(Subcommand, Debug)]
+/// `reth chain-spec` subcommands
+pub enum Subcommands {
+    /// Lists the hardfork transitions processed by the node
+    History,
+}
+
+impl Command {
+    /// Execute `chain-spec` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        // add network name to data dir
+        let data_dir = self.datadir.unwrap_or_chain_default(self.chain.chain);
+        let db_path = data_dir.db_path();
+
+        let db =
+            open_db_read_only(&db_path, DatabaseArguments::default().log_level(self.db.log_level))?;
+        let factory = ProviderFactory::new(db, self.chain.clone());
+
+        match self.command {
+            Subcommands::History => {
+                let transitions = factory.fork_transitions()?;
+
+                let mut table = ComfyTable::new();
+                table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+                table.set_header([
+                    "#",
+                    "Block",
+                    "Timestamp",
+                    "Fork ID Before",
+                    "Fork ID After",
+                    "Genesis",
+                ]);
+
+                for (index, transition) in transitions {
+                    let mut row = Row::new();
+                    row.add_cell(Cell::new(index))
+                        .add_cell(Cell::new(transition.block_number))
+                        .add_cell(Cell::new(transition.timestamp))
+                        .add_cell(Cell::new(format_fork_id(transition.fork_id_before())))
+                        .add_cell(Cell::new(format_fork_id(transition.fork_id_after())))
+                        .add_cell(Cell::new(transition.genesis_hash));
+                    table.add_row(row);
+                }
+
+                println!("{table}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_fork_id(fork_id: ForkId) -> String {
+    format!("{} (next: {})", hex::encode_prefixed(fork_id.hash.0), fork_id.next)
+}
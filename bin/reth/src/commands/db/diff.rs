@@ -7,13 +7,14 @@ use clap::Parser;
 use reth_db::{
     cursor::DbCursorRO, database::Database, mdbx::DatabaseArguments, open_db_read_only,
     table::Table, transaction::DbTx, AccountChangeSet, AccountHistory, AccountsTrie,
-    BlockBodyIndices, BlockOmmers, BlockWithdrawals, Bytecodes, CanonicalHeaders, DatabaseEnv,
-    HashedAccount, HashedStorage, HeaderNumbers, HeaderTD, Headers, PlainAccountState,
+    BlockBodyIndices, BlockOmmers, BlockWithdrawals, Bytecodes, CanonicalHeaders, ChainSpecs,
+    ChainState, DatabaseEnv, ForkTransitions, HashedAccount, HashedStorage, HeaderNumbers,
+    HeaderTD, Headers, HotAccounts, InvalidBlocks, LogsBloomBuckets, ParisBlock, PlainAccountState,
     PlainStorageState, PruneCheckpoints, Receipts, StorageChangeSet, StorageHistory, StoragesTrie,
     SyncStage, SyncStageProgress, Tables, TransactionBlock, Transactions, TxHashNumber, TxSenders,
 };
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::Debug,
     fs::{self, File},
     hash::Hash,
@@ -141,6 +142,25 @@ impl Command {
                 Tables::PruneCheckpoints => {
                     find_diffs::<PruneCheckpoints>(primary_tx, secondary_tx, output_dir)?
                 }
+                Tables::ChainState => {
+                    find_diffs::<ChainState>(primary_tx, secondary_tx, output_dir)?
+                }
+                Tables::InvalidBlocks => {
+                    find_diffs::<InvalidBlocks>(primary_tx, secondary_tx, output_dir)?
+                }
+                Tables::HotAccounts => {
+                    find_diffs::<HotAccounts>(primary_tx, secondary_tx, output_dir)?
+                }
+                Tables::LogsBloomBuckets => {
+                    find_diffs::<LogsBloomBuckets>(primary_tx, secondary_tx, output_dir)?
+                }
+                Tables::ForkTransitions => {
+                    find_diffs::<ForkTransitions>(primary_tx, secondary_tx, output_dir)?
+                }
+                Tables::ParisBlock => {
+                    find_diffs::<ParisBlock>(primary_tx, secondary_tx, output_dir)?
+                }
+                Tables::ChainSpecs => find_chain_spec_diffs(primary_tx, secondary_tx, output_dir)?,
             };
         }
 
@@ -230,6 +250,60 @@ where
     Ok(())
 }
 
+/// Find diffs for the [ChainSpecs] table.
+///
+/// Chain specs can't be compared as values, so the differences between the chain specs stored for
+/// the same genesis hash are written instead, see [reth_primitives::ChainSpecDiff].
+fn find_chain_spec_diffs(
+    primary_tx: impl DbTx,
+    secondary_tx: impl DbTx,
+    output_dir: impl AsRef<Path>,
+) -> eyre::Result<()> {
+    let table = Tables::ChainSpecs;
+
+    info!("Analyzing table {table}...");
+    let primary = primary_tx
+        .cursor_read::<ChainSpecs>()?
+        .walk(None)?
+        .collect::<Result<BTreeMap<_, _>, _>>()?;
+    let secondary = secondary_tx
+        .cursor_read::<ChainSpecs>()?
+        .walk(None)?
+        .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+    fs::create_dir_all(output_dir.as_ref())?;
+    let file_name = format!("{table}.txt");
+    let mut file = File::create(output_dir.as_ref().join(file_name.clone()))?;
+    writeln!(file, "Diff results for {table}")?;
+
+    let mut discrepancies = 0;
+    for genesis_hash in primary.keys().chain(secondary.keys()).collect::<BTreeSet<_>>() {
+        match (primary.get(genesis_hash), secondary.get(genesis_hash)) {
+            (Some(first), Some(second)) => {
+                let diff = first.diff(second);
+                if diff.is_empty() {
+                    continue
+                }
+                writeln!(file, "Chain spec of genesis {genesis_hash}:")?;
+                write!(file, "{diff}")?;
+            }
+            (Some(_), None) => {
+                writeln!(file, "Chain spec of genesis {genesis_hash} is only in the first table")?
+            }
+            (None, Some(_)) => {
+                writeln!(file, "Chain spec of genesis {genesis_hash} is only in the second table")?
+            }
+            (None, None) => unreachable!("genesis hash is taken from one of the tables"),
+        }
+        discrepancies += 1;
+    }
+    info!("Found {discrepancies} discrepancies in table {table}");
+
+    let full_file_name = output_dir.as_ref().join(file_name);
+    info!("Done writing diff results for {table} to {}", full_file_name.display());
+    Ok(())
+}
+
 /// This diff algorithm is slightly different, it will walk _each_ table, cross-checking for the
 /// element in the other table.
 fn find_diffs_advanced<T: Table>(
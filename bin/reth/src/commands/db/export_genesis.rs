@@ -0,0 +1,234 @@
+use crate::utils::DbTool;
+use clap::Parser;
+use reth_db::{
+    cursor::DbCursorRO, database::Database, models::BlockNumberAddress, tables, transaction::DbTx,
+};
+use reth_primitives::{
+    Address, BlockNumber, ChainHardforks, ChainSpec, ForkCondition, Genesis, GenesisAccount,
+    GenesisHardfork, Hardfork, Head, Header, U256,
+};
+use reth_provider::{
+    AccountReader, BlockNumReader, HeaderProvider, ProviderError, ProviderFactory, StateProvider,
+};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs::File,
+    io::BufWriter,
+    path::PathBuf,
+};
+use tracing::info;
+
+/// The arguments for the `reth db export-genesis` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The block whose state becomes the genesis alloc. Defaults to the latest block.
+    #[arg(long)]
+    at_block: Option<BlockNumber>,
+
+    /// The path the geth genesis file is written to.
+    #[arg(long, short, value_name = "FILE")]
+    output: PathBuf,
+}
+
+impl Command {
+    /// Execute `db export-genesis` command
+    ///
+    /// All accounts and storage slots that exist at the block are collected in memory, the
+    /// command is meant for devnets and chains small enough to regenesis.
+    pub fn execute<DB: Database>(self, tool: &DbTool<'_, DB>) -> eyre::Result<()> {
+        let factory = ProviderFactory::new(tool.db, tool.chain.clone());
+        let provider = factory.provider()?;
+        let at_block = match self.at_block {
+            Some(at_block) => at_block,
+            None => provider.best_block_number()?,
+        };
+        let header = provider
+            .header_by_number(at_block)?
+            .ok_or(ProviderError::HeaderNotFound(at_block.into()))?;
+        let total_difficulty = provider
+            .header_td_by_number(at_block)?
+            .ok_or(ProviderError::TotalDifficultyNotFound(at_block))?;
+
+        // the accounts and slots at the block are the ones that exist now, or that were changed
+        // after the block and might have been destroyed since
+        let tx = provider.tx_ref();
+        let mut accounts = BTreeSet::new();
+        for entry in tx.cursor_read::<tables::PlainAccountState>()?.walk(None)? {
+            accounts.insert(entry?.0);
+        }
+        for entry in tx.cursor_read::<tables::AccountChangeSet>()?.walk(Some(at_block + 1))? {
+            accounts.insert(entry?.1.address);
+        }
+        let mut slots = BTreeSet::new();
+        for entry in tx.cursor_read::<tables::PlainStorageState>()?.walk(None)? {
+            let (address, entry) = entry?;
+            slots.insert((address, entry.key));
+        }
+        for entry in tx
+            .cursor_read::<tables::StorageChangeSet>()?
+            .walk(Some(BlockNumberAddress((at_block + 1, Address::ZERO))))?
+        {
+            let (key, entry) = entry?;
+            slots.insert((key.address(), entry.key));
+        }
+        drop(provider);
+
+        let state = factory.history_by_block_number(at_block)?;
+        let mut alloc = HashMap::with_capacity(accounts.len());
+        for address in accounts {
+            let Some(account) = state.basic_account(address)? else { continue };
+            let code = state.account_code(address)?.map(|code| code.original_bytes());
+            alloc.insert(
+                address,
+                GenesisAccount::default()
+                    .with_nonce(Some(account.nonce))
+                    .with_balance(account.balance)
+                    .with_code(code),
+            );
+        }
+        for (address, key) in slots {
+            let Some(account) = alloc.get_mut(&address) else { continue };
+            let Some(value) = state.storage(address, key)?.filter(|value| !value.is_zero()) else {
+                continue
+            };
+            account.storage.get_or_insert_with(HashMap::new).insert(key, value.into());
+        }
+        info!(target: "reth::cli", at_block, accounts = alloc.len(), "Collected the state of the block");
+
+        let head = Head {
+            number: at_block,
+            hash: header.hash_slow(),
+            difficulty: header.difficulty,
+            total_difficulty,
+            timestamp: header.timestamp,
+        };
+        let spec = regenesis(&tool.chain, &head, &header, alloc);
+        serde_json::to_writer_pretty(
+            BufWriter::new(File::create(&self.output)?),
+            &spec.to_geth_genesis(),
+        )?;
+        println!(
+            "Exported the state of block {at_block} with genesis hash {} to {}",
+            spec.genesis_hash(),
+            self.output.display()
+        );
+
+        Ok(())
+    }
+}
+
+/// Returns a spec whose genesis is the given block with the given alloc.
+///
+/// The forks active at the block are collapsed to genesis, the pending ones are rescheduled
+/// relative to the new genesis.
+fn regenesis(
+    chain: &ChainSpec,
+    head: &Head,
+    header: &Header,
+    alloc: HashMap<Address, GenesisAccount>,
+) -> ChainSpec {
+    let merged = chain.fork(Hardfork::Paris).active_at_head(head);
+    let mut spec = chain.clone();
+    spec.genesis = Genesis {
+        config: chain.genesis.config.clone(),
+        nonce: header.nonce,
+        timestamp: header.timestamp,
+        extra_data: header.extra_data.clone(),
+        gas_limit: header.gas_limit,
+        difficulty: if merged { U256::ZERO } else { header.difficulty },
+        mix_hash: header.mix_hash,
+        coinbase: header.beneficiary,
+        base_fee_per_gas: header.base_fee_per_gas,
+        excess_blob_gas: header.excess_blob_gas,
+        blob_gas_used: header.blob_gas_used,
+        alloc,
+        ..Default::default()
+    };
+    spec.genesis_hash = None;
+    spec.set_hardforks(
+        chain
+            .forks_iter()
+            .map(|(fork, condition)| (fork, collapse_fork(condition, head)))
+            .collect(),
+    );
+    let mut custom_hardforks = ChainHardforks::default();
+    for (fork, condition) in chain.custom_hardforks().forks_iter() {
+        custom_hardforks.insert(GenesisHardfork::new(fork.name()), collapse_fork(condition, head));
+    }
+    spec.set_custom_hardforks(custom_hardforks);
+    spec.paris_block_and_final_difficulty = merged.then_some((0, U256::ZERO));
+    spec.invalidate_fork_caches();
+    spec
+}
+
+/// Moves the fork condition to a chain whose genesis is the given head.
+fn collapse_fork(condition: ForkCondition, head: &Head) -> ForkCondition {
+    match condition {
+        ForkCondition::Never => ForkCondition::Never,
+        ForkCondition::TTD { .. } if condition.active_at_head(head) => {
+            ForkCondition::TTD { fork_block: Some(0), total_difficulty: U256::ZERO }
+        }
+        ForkCondition::Timestamp(_) | ForkCondition::BlockAndTimestamp { .. }
+            if condition.active_at_head(head) =>
+        {
+            ForkCondition::Timestamp(0)
+        }
+        _ if condition.active_at_head(head) => ForkCondition::Block(0),
+        ForkCondition::TTD { fork_block, total_difficulty } => ForkCondition::TTD {
+            fork_block: fork_block.map(|block| block.saturating_sub(head.number)),
+            total_difficulty: total_difficulty.saturating_sub(head.total_difficulty),
+        },
+        ForkCondition::Block(_) | ForkCondition::Epoch { .. } => ForkCondition::Block(
+            condition.as_block().unwrap_or_default().saturating_sub(head.number),
+        ),
+        ForkCondition::BlockAndTimestamp { block, timestamp } => {
+            ForkCondition::BlockAndTimestamp { block: block.saturating_sub(head.number), timestamp }
+        }
+        ForkCondition::Timestamp(_) => condition,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapse_forks() {
+        let head = Head {
+            number: 100,
+            timestamp: 1000,
+            total_difficulty: U256::from(50),
+            ..Default::default()
+        };
+
+        assert_eq!(collapse_fork(ForkCondition::Block(10), &head), ForkCondition::Block(0));
+        assert_eq!(collapse_fork(ForkCondition::Block(150), &head), ForkCondition::Block(50));
+        assert_eq!(
+            collapse_fork(ForkCondition::Epoch { epoch_length: 30, epoch: 5 }, &head),
+            ForkCondition::Block(50)
+        );
+        assert_eq!(
+            collapse_fork(ForkCondition::Timestamp(999), &head),
+            ForkCondition::Timestamp(0)
+        );
+        assert_eq!(
+            collapse_fork(ForkCondition::Timestamp(2000), &head),
+            ForkCondition::Timestamp(2000)
+        );
+        assert_eq!(
+            collapse_fork(
+                ForkCondition::TTD { fork_block: None, total_difficulty: U256::from(10) },
+                &head
+            ),
+            ForkCondition::TTD { fork_block: Some(0), total_difficulty: U256::ZERO }
+        );
+        assert_eq!(
+            collapse_fork(
+                ForkCondition::TTD { fork_block: Some(120), total_difficulty: U256::from(80) },
+                &head
+            ),
+            ForkCondition::TTD { fork_block: Some(20), total_difficulty: U256::from(30) }
+        );
+        assert_eq!(collapse_fork(ForkCondition::Never, &head), ForkCondition::Never);
+    }
+}
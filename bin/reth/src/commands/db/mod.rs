@@ -28,8 +28,10 @@ use std::{
 
 mod clear;
 mod diff;
+mod export_genesis;
 mod get;
 mod list;
+mod repair;
 mod snapshots;
 /// DB List TUI
 mod tui;
@@ -86,6 +88,10 @@ pub enum Subcommands {
     },
     /// Deletes all table entries
     Clear(clear::Command),
+    /// Repairs data that doesn't match the rest of the database
+    Repair(repair::Command),
+    /// Exports the state at a block as the genesis file of a new chain
+    ExportGenesis(export_genesis::Command),
     /// Snapshots tables from database
     Snapshot(snapshots::Command),
     /// Lists current and local database versions
@@ -240,6 +246,23 @@ impl Command {
                     open_db(&db_path, DatabaseArguments::default().log_level(self.db.log_level))?;
                 command.execute(&db)?;
             }
+            Subcommands::Repair(command) => {
+                let args = DatabaseArguments::default().log_level(self.db.log_level);
+                let db = if command.is_write() {
+                    open_db(&db_path, args)?
+                } else {
+                    open_db_read_only(&db_path, args)?
+                };
+                command.execute(&db)?;
+            }
+            Subcommands::ExportGenesis(command) => {
+                let db = open_db_read_only(
+                    &db_path,
+                    DatabaseArguments::default().log_level(self.db.log_level),
+                )?;
+                let tool = DbTool::new(&db, self.chain.clone())?;
+                command.execute(&tool)?;
+            }
             Subcommands::Snapshot(command) => {
                 command.execute(&db_path, self.db.log_level, self.chain.clone())?;
             }
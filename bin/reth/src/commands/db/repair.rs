@@ -0,0 +1,128 @@
+use clap::{Parser, Subcommand};
+use reth_db::{
+    cursor::DbCursorRO,
+    database::Database,
+    tables,
+    transaction::{DbTx, DbTxMut},
+};
+use reth_primitives::{logs_bloom, BlockNumber, Header};
+use tracing::{info, warn};
+
+/// The arguments for the `reth db repair` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    #[clap(subcommand)]
+    command: Subcommands,
+}
+
+#[derive(Subcommand, Debug)]
+/// `reth db repair` subcommands
+pub enum Subcommands {
+    /// Recomputes the logs blooms of headers from the stored receipts
+    Blooms(BloomsCommand),
+}
+
+impl Command {
+    /// Returns `true` if the command needs write access to the database.
+    pub fn is_write(&self) -> bool {
+        match &self.command {
+            Subcommands::Blooms(command) => command.fix,
+        }
+    }
+
+    /// Execute `db repair` command
+    pub fn execute<DB: Database>(self, db: &DB) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::Blooms(command) => command.execute(db),
+        }
+    }
+}
+
+/// The arguments for the `reth db repair blooms` command
+#[derive(Parser, Debug)]
+pub struct BloomsCommand {
+    /// The first block to check
+    #[arg(long, default_value_t = 0)]
+    from: BlockNumber,
+
+    /// The last block to check. Defaults to the highest stored header.
+    #[arg(long)]
+    to: Option<BlockNumber>,
+
+    /// Rewrite the headers whose logs bloom doesn't match their receipts.
+    ///
+    /// A header is only rewritten if its hash with the recomputed logs bloom matches the
+    /// canonical hash. Otherwise the receipts are corrupted and the mismatch is only reported.
+    #[arg(long)]
+    fix: bool,
+}
+
+impl BloomsCommand {
+    /// Execute `db repair blooms` command
+    pub fn execute<DB: Database>(self, db: &DB) -> eyre::Result<()> {
+        let tx = db.tx()?;
+        let to = match self.to {
+            Some(to) => to,
+            None => {
+                tx.cursor_read::<tables::Headers>()?.last()?.map(|(number, _)| number).unwrap_or(0)
+            }
+        };
+
+        let mut body_indices_cursor = tx.cursor_read::<tables::BlockBodyIndices>()?;
+        let mut receipts_cursor = tx.cursor_read::<tables::Receipts>()?;
+
+        let mut checked = 0usize;
+        let mut skipped = 0usize;
+        let mut corrupted_receipts = 0usize;
+        let mut repairable = Vec::<(BlockNumber, Header)>::new();
+
+        for entry in tx.cursor_read::<tables::Headers>()?.walk_range(self.from..=to)? {
+            let (number, header) = entry?;
+
+            // receipts of pruned blocks can't be checked
+            let Some((_, body_indices)) = body_indices_cursor.seek_exact(number)? else {
+                skipped += 1;
+                continue
+            };
+            let receipts = receipts_cursor
+                .walk_range(body_indices.tx_num_range())?
+                .collect::<Result<Vec<_>, _>>()?;
+            if receipts.len() as u64 != body_indices.tx_count() {
+                skipped += 1;
+                continue
+            }
+            checked += 1;
+
+            let logs_bloom = logs_bloom(receipts.iter().flat_map(|(_, receipt)| &receipt.logs));
+            if logs_bloom == header.logs_bloom {
+                continue
+            }
+
+            let canonical_hash = tx.get::<tables::CanonicalHeaders>(number)?;
+            let repaired = Header { logs_bloom, ..header };
+            if canonical_hash == Some(repaired.hash_slow()) {
+                info!(target: "reth::cli", number, "Header logs bloom doesn't match the receipts");
+                repairable.push((number, repaired));
+            } else {
+                warn!(target: "reth::cli", number, "Receipts don't match the header logs bloom");
+                corrupted_receipts += 1;
+            }
+        }
+        drop(tx);
+
+        println!("Checked blocks: {checked}, skipped blocks without receipts: {skipped}");
+        println!("Headers with a wrong logs bloom: {}", repairable.len());
+        println!("Blocks with corrupted receipts: {corrupted_receipts}");
+
+        if self.fix && !repairable.is_empty() {
+            let tx = db.tx_mut()?;
+            for (number, header) in repairable {
+                tx.put::<tables::Headers>(number, header)?;
+            }
+            tx.commit()?;
+            println!("Repaired the headers");
+        }
+
+        Ok(())
+    }
+}
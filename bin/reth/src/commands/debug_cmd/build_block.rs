@@ -31,10 +31,7 @@ use reth_payload_builder::EthPayloadBuilderAttributes;
 #[cfg(feature = "optimism")]
 use reth_payload_builder::OptimismPayloadBuilderAttributes;
 use reth_primitives::{
-    constants::eip4844::{LoadKzgSettingsError, MAINNET_KZG_TRUSTED_SETUP},
-    fs,
-    revm_primitives::KzgSettings,
-    stage::StageId,
+    constants::eip4844::LoadKzgSettingsError, fs, revm_primitives::KzgSettings, stage::StageId,
     Address, BlobTransaction, BlobTransactionSidecar, Bytes, ChainSpec, PooledTransactionsElement,
     SealedBlock, SealedBlockWithSenders, Transaction, TransactionSigned, TxEip4844, B256, U256,
 };
@@ -133,15 +130,15 @@ impl Command {
         ))
     }
 
-    /// Loads the trusted setup params from a given file path or falls back to
-    /// `MAINNET_KZG_TRUSTED_SETUP`.
+    /// Loads the trusted setup params from a given file path or falls back to the trusted setup of
+    /// the chain spec.
     fn kzg_settings(&self) -> eyre::Result<Arc<KzgSettings>> {
         if let Some(ref trusted_setup_file) = self.trusted_setup_file {
             let trusted_setup = KzgSettings::load_trusted_setup_file(trusted_setup_file)
                 .map_err(LoadKzgSettingsError::KzgError)?;
             Ok(Arc::new(trusted_setup))
         } else {
-            Ok(Arc::clone(&MAINNET_KZG_TRUSTED_SETUP))
+            Ok(self.chain.kzg_settings()?)
         }
     }
 
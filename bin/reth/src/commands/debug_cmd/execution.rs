@@ -144,7 +144,11 @@ impl Command {
                         .clean_threshold
                         .max(stage_conf.account_hashing.clean_threshold)
                         .max(stage_conf.storage_hashing.clean_threshold),
-                    config.prune.clone().map(|prune| prune.segments).unwrap_or_default(),
+                    config
+                        .prune
+                        .clone()
+                        .map(|prune| prune.segments.with_deposit_contract_guard(&self.chain))
+                        .unwrap_or_default(),
                 )),
             )
             .build(provider_factory);
@@ -255,6 +259,7 @@ impl Command {
             reth_node_core::events::node::handle_events(
                 Some(network.clone()),
                 latest_block_number,
+                self.chain.block_duration(),
                 events,
                 db.clone(),
             ),
@@ -8,6 +8,7 @@ pub mod engine_api_store;
 mod execution;
 mod in_memory_merkle;
 mod merkle;
+mod pool_dump;
 mod replay_engine;
 
 /// `reth debug` command
@@ -30,6 +31,8 @@ pub enum Subcommands {
     BuildBlock(build_block::Command),
     /// Debug engine API by replaying stored messages.
     ReplayEngine(replay_engine::Command),
+    /// Dump the transaction pool state of a running node to a file.
+    PoolDump(pool_dump::Command),
 }
 
 impl Command {
@@ -41,6 +44,7 @@ impl Command {
             Subcommands::InMemoryMerkle(command) => command.execute(ctx).await,
             Subcommands::BuildBlock(command) => command.execute(ctx).await,
             Subcommands::ReplayEngine(command) => command.execute(ctx).await,
+            Subcommands::PoolDump(command) => command.execute(ctx).await,
         }
     }
 }
@@ -0,0 +1,50 @@
+//! Command that dumps the transaction pool state of a running node.
+
+use crate::core::cli::runner::CliContext;
+use clap::Parser;
+use eyre::Context;
+use jsonrpsee::http_client::HttpClientBuilder;
+use reth_primitives::fs;
+use reth_rpc_api::TxPoolApiClient;
+use std::path::PathBuf;
+use tracing::*;
+
+/// `reth debug pool-dump` command
+///
+/// Fetches a snapshot of the full transaction pool state of a running node via the
+/// `txpool_dump` endpoint and writes it to a JSON file.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The HTTP RPC endpoint of the running node.
+    #[arg(long, value_name = "URL", default_value = "http://localhost:8545")]
+    rpc_url: String,
+
+    /// The path of the file the dump is written to.
+    #[arg(long, value_name = "FILE")]
+    output: PathBuf,
+}
+
+impl Command {
+    /// Execute `debug pool-dump` command
+    pub async fn execute(self, _ctx: CliContext) -> eyre::Result<()> {
+        let client = HttpClientBuilder::default()
+            .build(&self.rpc_url)
+            .wrap_err_with(|| format!("Failed to create RPC client for {}", self.rpc_url))?;
+        let dump = TxPoolApiClient::txpool_dump(&client)
+            .await
+            .wrap_err("Failed to fetch the transaction pool dump")?;
+
+        info!(
+            target: "reth::cli",
+            senders = dump.senders.len(),
+            transactions = dump.transactions.len(),
+            block = dump.block_info.last_seen_block_number,
+            "Fetched transaction pool dump"
+        );
+
+        fs::write(&self.output, serde_json::to_vec_pretty(&dump)?)?;
+        info!(target: "reth::cli", path = ?self.output, "Wrote transaction pool dump");
+
+        Ok(())
+    }
+}
@@ -106,9 +106,14 @@ impl ImportCommand {
         let tip = file_client.tip().expect("file client has no tip");
         info!(target: "reth::cli", "Chain file imported");
 
-        let (mut pipeline, events) = self
-            .build_import_pipeline(config, provider_factory.clone(), &consensus, file_client)
-            .await?;
+        let (mut pipeline, events) = build_import_pipeline(
+            config,
+            self.chain.clone(),
+            provider_factory.clone(),
+            &consensus,
+            file_client,
+        )
+        .await?;
 
         // override the tip
         pipeline.set_tip(tip);
@@ -121,6 +126,7 @@ impl ImportCommand {
         tokio::spawn(reth_node_core::events::node::handle_events(
             None,
             latest_block_number,
+            self.chain.block_duration(),
             events,
             db.clone(),
         ));
@@ -136,55 +142,69 @@ impl ImportCommand {
         Ok(())
     }
 
-    async fn build_import_pipeline<DB, C>(
-        &self,
-        config: Config,
-        provider_factory: ProviderFactory<DB>,
-        consensus: &Arc<C>,
-        file_client: Arc<FileClient>,
-    ) -> eyre::Result<(Pipeline<DB>, impl Stream<Item = NodeEvent>)>
-    where
-        DB: Database + Clone + Unpin + 'static,
-        C: Consensus + 'static,
-    {
-        if !file_client.has_canonical_blocks() {
-            eyre::bail!("unable to import non canonical blocks");
-        }
+    /// Loads the reth config
+    fn load_config(&self, config_path: PathBuf) -> eyre::Result<Config> {
+        confy::load_path::<Config>(config_path.clone())
+            .wrap_err_with(|| format!("Could not load config file {:?}", config_path))
+    }
+}
 
-        let header_downloader = ReverseHeadersDownloaderBuilder::new(config.stages.headers)
-            .build(file_client.clone(), consensus.clone())
-            .into_task();
-
-        let body_downloader = BodiesDownloaderBuilder::new(config.stages.bodies)
-            .build(file_client.clone(), consensus.clone(), provider_factory.clone())
-            .into_task();
-
-        let (tip_tx, tip_rx) = watch::channel(B256::ZERO);
-        let factory =
-            reth_revm::EvmProcessorFactory::new(self.chain.clone(), EthEvmConfig::default());
-
-        let max_block = file_client.max_block().unwrap_or(0);
-        let mut pipeline = Pipeline::builder()
-            .with_tip_sender(tip_tx)
-            // we want to sync all blocks the file client provides or 0 if empty
-            .with_max_block(max_block)
-            .add_stages(
-                DefaultStages::new(
-                    provider_factory.clone(),
-                    HeaderSyncMode::Tip(tip_rx),
-                    consensus.clone(),
-                    header_downloader,
-                    body_downloader,
-                    factory.clone(),
-                )
-                .set(
-                    TotalDifficultyStage::new(consensus.clone())
-                        .with_commit_threshold(config.stages.total_difficulty.commit_threshold),
-                )
-                .set(SenderRecoveryStage {
-                    commit_threshold: config.stages.sender_recovery.commit_threshold,
-                })
-                .set(ExecutionStage::new(
+/// Builds a pipeline that syncs the blocks of the given [FileClient] instead of downloading them
+/// from the network.
+pub(crate) async fn build_import_pipeline<DB, C>(
+    config: Config,
+    chain_spec: Arc<ChainSpec>,
+    provider_factory: ProviderFactory<DB>,
+    consensus: &Arc<C>,
+    file_client: Arc<FileClient>,
+) -> eyre::Result<(Pipeline<DB>, impl Stream<Item = NodeEvent>)>
+where
+    DB: Database + Clone + Unpin + 'static,
+    C: Consensus + 'static,
+{
+    if !file_client.has_canonical_blocks() {
+        eyre::bail!("unable to import non canonical blocks");
+    }
+
+    let header_downloader = ReverseHeadersDownloaderBuilder::new(config.stages.headers)
+        .build(file_client.clone(), consensus.clone())
+        .into_task();
+
+    let body_downloader = BodiesDownloaderBuilder::new(config.stages.bodies)
+        .build(file_client.clone(), consensus.clone(), provider_factory.clone())
+        .into_task();
+
+    let prune_modes = config
+        .prune
+        .map(|prune| prune.segments.with_deposit_contract_guard(&chain_spec))
+        .unwrap_or_default();
+
+    let (tip_tx, tip_rx) = watch::channel(B256::ZERO);
+    let factory = reth_revm::EvmProcessorFactory::new(chain_spec, EthEvmConfig::default());
+
+    let max_block = file_client.max_block().unwrap_or(0);
+    let mut pipeline = Pipeline::builder()
+        .with_tip_sender(tip_tx)
+        // we want to sync all blocks the file client provides or 0 if empty
+        .with_max_block(max_block)
+        .add_stages(
+            DefaultStages::new(
+                provider_factory.clone(),
+                HeaderSyncMode::Tip(tip_rx),
+                consensus.clone(),
+                header_downloader,
+                body_downloader,
+                factory.clone(),
+            )
+            .set(
+                TotalDifficultyStage::new(consensus.clone())
+                    .with_commit_threshold(config.stages.total_difficulty.commit_threshold),
+            )
+            .set(SenderRecoveryStage {
+                commit_threshold: config.stages.sender_recovery.commit_threshold,
+            })
+            .set(
+                ExecutionStage::new(
                     factory,
                     ExecutionStageThresholds {
                         max_blocks: config.stages.execution.max_blocks,
@@ -198,21 +218,16 @@ impl ImportCommand {
                         .clean_threshold
                         .max(config.stages.account_hashing.clean_threshold)
                         .max(config.stages.storage_hashing.clean_threshold),
-                    config.prune.map(|prune| prune.segments).unwrap_or_default(),
-                )),
-            )
-            .build(provider_factory);
-
-        let events = pipeline.events().map(Into::into);
+                    prune_modes,
+                )
+                .with_commit_valid_prefix(config.stages.execution.commit_valid_prefix),
+            ),
+        )
+        .build(provider_factory);
 
-        Ok((pipeline, events))
-    }
+    let events = pipeline.events().map(Into::into);
 
-    /// Loads the reth config
-    fn load_config(&self, config_path: PathBuf) -> eyre::Result<Config> {
-        confy::load_path::<Config>(config_path.clone())
-            .wrap_err_with(|| format!("Could not load config file {:?}", config_path))
-    }
+    Ok((pipeline, events))
 }
 
 #[cfg(test)]
@@ -0,0 +1,260 @@
+//! Reader for the ancient store ("freezer") of geth.
+//!
+//! The freezer keeps finalized chain data in append-only tables. Every table consists of an index
+//! file and one or more data files:
+//!
+//! - `<table>.cidx` (or `.ridx` for uncompressed tables): a list of 6 byte entries, each a big
+//!   endian `u16` data file number followed by a big endian `u32` offset, pointing at the end of
+//!   the item in that file. The first entry is special: its offset is the number of items that
+//!   were removed from the tail of the table.
+//! - `<table>.<NNNN>.cdat` (or `.rdat`): the concatenated, optionally snappy compressed, items.
+
+use alloy_rlp::Decodable;
+use eyre::{ensure, WrapErr};
+use reth_primitives::{BlockBody, BlockNumber, Header};
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+/// The size of a single entry in a freezer index file.
+const INDEX_ENTRY_SIZE: u64 = 6;
+
+/// A single entry of a freezer index file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IndexEntry {
+    /// The number of the data file the item is stored in.
+    file_number: u16,
+    /// The offset at which the item ends in the data file.
+    offset: u32,
+}
+
+impl IndexEntry {
+    fn decode(bytes: [u8; INDEX_ENTRY_SIZE as usize]) -> Self {
+        Self {
+            file_number: u16::from_be_bytes([bytes[0], bytes[1]]),
+            offset: u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+        }
+    }
+}
+
+/// A single table of the geth freezer, e.g. `headers` or `bodies`.
+#[derive(Debug)]
+pub(crate) struct FreezerTable {
+    /// The directory the table files are stored in.
+    dir: PathBuf,
+    /// The name of the table.
+    name: &'static str,
+    /// Whether the items are snappy compressed.
+    compressed: bool,
+    /// The index file of the table.
+    index: File,
+    /// The number of entries in the index file, including the leading tail entry.
+    entries: u64,
+    /// The number of items that were removed from the tail of the table.
+    tail: u64,
+    /// The data file that was read last.
+    data: Option<(u16, File)>,
+}
+
+impl FreezerTable {
+    /// Opens the table with the given name in the freezer directory.
+    pub(crate) fn open(dir: &Path, name: &'static str, compressed: bool) -> eyre::Result<Self> {
+        let extension = if compressed { "cidx" } else { "ridx" };
+        let path = dir.join(format!("{name}.{extension}"));
+        let mut index =
+            File::open(&path).wrap_err_with(|| format!("Could not open freezer index {path:?}"))?;
+
+        let entries = index.metadata()?.len() / INDEX_ENTRY_SIZE;
+        ensure!(entries > 0, "Freezer index {path:?} is empty");
+
+        let mut first = [0; INDEX_ENTRY_SIZE as usize];
+        index.read_exact(&mut first)?;
+        let tail = IndexEntry::decode(first).offset as u64;
+
+        Ok(Self { dir: dir.to_path_buf(), name, compressed, index, entries, tail, data: None })
+    }
+
+    /// Returns the number of the first item that is still stored in the table.
+    pub(crate) fn tail(&self) -> u64 {
+        self.tail
+    }
+
+    /// Returns the number of the item that will be appended next, i.e. the number of items that
+    /// were ever written to the table.
+    pub(crate) fn head(&self) -> u64 {
+        self.tail + self.entries - 1
+    }
+
+    /// Reads the item with the given number, decompressing it if necessary.
+    pub(crate) fn get(&mut self, number: u64) -> eyre::Result<Vec<u8>> {
+        ensure!(
+            (self.tail()..self.head()).contains(&number),
+            "Item {number} is out of bounds of freezer table {} ({}..{})",
+            self.name,
+            self.tail(),
+            self.head()
+        );
+
+        let position = number - self.tail;
+        let mut start = self.read_index(position)?;
+        let end = self.read_index(position + 1)?;
+        // the first item always starts at the beginning of a file, and so does every item that
+        // doesn't fit into the remainder of the previous file
+        if position == 0 || start.file_number != end.file_number {
+            start = IndexEntry { file_number: end.file_number, offset: 0 };
+        }
+        ensure!(start.offset <= end.offset, "Corrupt index of freezer table {}", self.name);
+
+        let data = self.data_file(end.file_number)?;
+        data.seek(SeekFrom::Start(start.offset as u64))?;
+        let mut item = vec![0; (end.offset - start.offset) as usize];
+        data.read_exact(&mut item)?;
+
+        if self.compressed {
+            item = snap::raw::Decoder::new().decompress_vec(&item).wrap_err_with(|| {
+                format!("Could not decompress item {number} of freezer table {}", self.name)
+            })?;
+        }
+        Ok(item)
+    }
+
+    fn read_index(&mut self, position: u64) -> eyre::Result<IndexEntry> {
+        let mut entry = [0; INDEX_ENTRY_SIZE as usize];
+        self.index.seek(SeekFrom::Start(position * INDEX_ENTRY_SIZE))?;
+        self.index.read_exact(&mut entry)?;
+        Ok(IndexEntry::decode(entry))
+    }
+
+    fn data_file(&mut self, file_number: u16) -> eyre::Result<&mut File> {
+        if !matches!(self.data, Some((number, _)) if number == file_number) {
+            let extension = if self.compressed { "cdat" } else { "rdat" };
+            let path = self.dir.join(format!("{}.{file_number:04}.{extension}", self.name));
+            let file = File::open(&path)
+                .wrap_err_with(|| format!("Could not open freezer data file {path:?}"))?;
+            self.data = Some((file_number, file));
+        }
+        Ok(&mut self.data.as_mut().expect("data file is set").1)
+    }
+}
+
+/// The chain freezer of geth, holding the headers and bodies of all finalized blocks.
+#[derive(Debug)]
+pub(crate) struct GethAncient {
+    headers: FreezerTable,
+    bodies: FreezerTable,
+}
+
+impl GethAncient {
+    /// Opens the chain freezer at the given path.
+    ///
+    /// This accepts both the `ancient` directory of recent geth versions, which keeps the chain
+    /// freezer in the `chain` subdirectory, and the freezer directory itself.
+    pub(crate) fn open(path: &Path) -> eyre::Result<Self> {
+        let chain = path.join("chain");
+        let dir = if chain.is_dir() { chain.as_path() } else { path };
+        Ok(Self {
+            headers: FreezerTable::open(dir, "headers", true)?,
+            bodies: FreezerTable::open(dir, "bodies", true)?,
+        })
+    }
+
+    /// Returns the range of blocks that can be read from the freezer.
+    pub(crate) fn blocks(&self) -> std::ops::Range<BlockNumber> {
+        self.headers.tail().max(self.bodies.tail())..self.headers.head().min(self.bodies.head())
+    }
+
+    /// Reads and decodes the header with the given number.
+    pub(crate) fn header(&mut self, number: BlockNumber) -> eyre::Result<Header> {
+        let header = self.headers.get(number)?;
+        Header::decode(&mut header.as_slice())
+            .wrap_err_with(|| format!("Could not decode header {number}"))
+    }
+
+    /// Reads and decodes the header and body of the block with the given number.
+    pub(crate) fn block(&mut self, number: BlockNumber) -> eyre::Result<(Header, BlockBody)> {
+        let header = self.header(number)?;
+        let body = self.bodies.get(number)?;
+        let body = BlockBody::decode(&mut body.as_slice())
+            .wrap_err_with(|| format!("Could not decode body {number}"))?;
+        Ok((header, body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// Writes a table with the given items, starting a new data file after `items_per_file`
+    /// items.
+    fn write_table(
+        dir: &Path,
+        name: &str,
+        compressed: bool,
+        tail: u32,
+        items: &[Vec<u8>],
+        items_per_file: usize,
+    ) {
+        let (index_ext, data_ext) = if compressed { ("cidx", "cdat") } else { ("ridx", "rdat") };
+        let mut index = File::create(dir.join(format!("{name}.{index_ext}"))).unwrap();
+        index.write_all(&[0, 0]).unwrap();
+        index.write_all(&tail.to_be_bytes()).unwrap();
+
+        for (file_number, chunk) in items.chunks(items_per_file).enumerate() {
+            let path = dir.join(format!("{name}.{file_number:04}.{data_ext}"));
+            let mut data = File::create(path).unwrap();
+            let mut offset = 0u32;
+            for item in chunk {
+                let item = if compressed {
+                    snap::raw::Encoder::new().compress_vec(item).unwrap()
+                } else {
+                    item.clone()
+                };
+                data.write_all(&item).unwrap();
+                offset += item.len() as u32;
+                index.write_all(&(file_number as u16).to_be_bytes()).unwrap();
+                index.write_all(&offset.to_be_bytes()).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn read_freezer_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let items = (0..5u8).map(|i| vec![i; i as usize + 1]).collect::<Vec<_>>();
+
+        for compressed in [false, true] {
+            write_table(dir.path(), "items", compressed, 10, &items, 2);
+            let mut table = FreezerTable::open(dir.path(), "items", compressed).unwrap();
+            assert_eq!(table.tail(), 10);
+            assert_eq!(table.head(), 15);
+            for (i, item) in items.iter().enumerate() {
+                assert_eq!(&table.get(10 + i as u64).unwrap(), item);
+            }
+            assert!(table.get(9).is_err());
+            assert!(table.get(15).is_err());
+        }
+    }
+
+    #[test]
+    fn read_geth_ancient_blocks() {
+        let dir = tempfile::tempdir().unwrap();
+        let chain = dir.path().join("chain");
+        std::fs::create_dir(&chain).unwrap();
+
+        let headers = (0..3u64)
+            .map(|number| alloy_rlp::encode(Header { number, ..Default::default() }))
+            .collect::<Vec<_>>();
+        let bodies = vec![alloy_rlp::encode(BlockBody::default()); 3];
+        write_table(&chain, "headers", true, 0, &headers, 2);
+        write_table(&chain, "bodies", true, 0, &bodies, 2);
+
+        let mut ancient = GethAncient::open(dir.path()).unwrap();
+        assert_eq!(ancient.blocks(), 0..3);
+        let (header, body) = ancient.block(2).unwrap();
+        assert_eq!(header.number, 2);
+        assert_eq!(body, BlockBody::default());
+    }
+}
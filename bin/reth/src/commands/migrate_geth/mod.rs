@@ -0,0 +1,190 @@
+//! Command that initializes the node by importing the history in the ancient store of a local geth
+//! node.
+
+use crate::{
+    args::{
+        utils::{chain_help, genesis_value_parser, SUPPORTED_CHAINS},
+        DatabaseArgs,
+    },
+    commands::import::build_import_pipeline,
+    dirs::{DataDirPath, MaybePlatformPath},
+    version::SHORT_VERSION,
+};
+use clap::Parser;
+use eyre::Context;
+use freezer::GethAncient;
+use reth_beacon_consensus::BeaconConsensus;
+use reth_config::Config;
+use reth_db::{init_db, mdbx::DatabaseArguments};
+use reth_downloaders::file_client::FileClient;
+use reth_node_core::init::init_genesis;
+use reth_primitives::{stage::StageId, ChainSpec};
+use reth_provider::{ProviderFactory, StageCheckpointReader};
+use std::{path::PathBuf, sync::Arc};
+use tracing::{debug, info};
+
+mod freezer;
+
+/// Syncs the block history from the ancient store of a local geth node.
+///
+/// Only the ancient store is read. geth's LevelDB or Pebble key-value store, which holds the most
+/// recent blocks and the state, is not supported.
+#[derive(Debug, Parser)]
+pub struct ImportGethAncientCommand {
+    /// The path to the configuration file to use.
+    #[arg(long, value_name = "FILE", verbatim_doc_comment)]
+    config: Option<PathBuf>,
+
+    /// The path to the data dir for all reth files and subdirectories.
+    ///
+    /// Defaults to the OS-specific data directory:
+    ///
+    /// - Linux: `$XDG_DATA_HOME/reth/` or `$HOME/.local/share/reth/`
+    /// - Windows: `{FOLDERID_RoamingAppData}/reth/`
+    /// - macOS: `$HOME/Library/Application Support/reth/`
+    #[arg(long, value_name = "DATA_DIR", verbatim_doc_comment, default_value_t)]
+    datadir: MaybePlatformPath<DataDirPath>,
+
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = chain_help(),
+        default_value = SUPPORTED_CHAINS[0],
+        value_parser = genesis_value_parser
+    )]
+    chain: Arc<ChainSpec>,
+
+    #[clap(flatten)]
+    db: DatabaseArgs,
+
+    /// The path to the ancient store of geth, usually `<geth datadir>/geth/chaindata/ancient`.
+    ///
+    /// The headers and bodies of all blocks in the ancient store are imported, after which the
+    /// remaining stages are executed. Receipts are not copied, they are recreated when the blocks
+    /// are executed.
+    ///
+    /// Blocks that geth has not moved to the ancient store yet (the most recent 90000) are kept
+    /// in its LevelDB or Pebble key-value store, which is not read by this command. These are
+    /// synced from the network once the node is started.
+    #[arg(long, value_name = "PATH", verbatim_doc_comment)]
+    ancient: PathBuf,
+
+    /// The number of blocks to load into memory and import at once.
+    #[arg(long, value_name = "BLOCKS", default_value_t = 10_000)]
+    chunk_size: u64,
+}
+
+impl ImportGethAncientCommand {
+    /// Execute `import-geth-ancient` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        info!(target: "reth::cli", "reth {} starting", SHORT_VERSION);
+
+        // add network name to data dir
+        let data_dir = self.datadir.unwrap_or_chain_default(self.chain.chain);
+        let config_path = self.config.clone().unwrap_or(data_dir.config_path());
+
+        let config: Config = confy::load_path::<Config>(config_path.clone())
+            .wrap_err_with(|| format!("Could not load config file {:?}", config_path))?;
+        info!(target: "reth::cli", path = ?config_path, "Configuration loaded");
+
+        let db_path = data_dir.db_path();
+
+        info!(target: "reth::cli", path = ?db_path, "Opening database");
+        let db =
+            Arc::new(init_db(db_path, DatabaseArguments::default().log_level(self.db.log_level))?);
+        info!(target: "reth::cli", "Database opened");
+        let provider_factory = ProviderFactory::new(db.clone(), self.chain.clone());
+
+        debug!(target: "reth::cli", chain=%self.chain.chain, genesis=?self.chain.genesis_hash(), "Initializing genesis");
+
+        init_genesis(db.clone(), self.chain.clone())?;
+
+        let consensus = Arc::new(BeaconConsensus::new(self.chain.clone()));
+        info!(target: "reth::cli", "Consensus engine initialized");
+
+        info!(target: "reth::cli", path = ?self.ancient, "Opening geth ancient store");
+        let mut ancient = GethAncient::open(&self.ancient)?;
+        let blocks = ancient.blocks();
+        if blocks.start > 0 {
+            eyre::bail!(
+                "geth ancient store was pruned up to block {}, can't import the full history",
+                blocks.start
+            );
+        }
+        let genesis_hash = ancient.header(0)?.hash_slow();
+        if genesis_hash != self.chain.genesis_hash() {
+            eyre::bail!(
+                "geth ancient store belongs to a different chain, genesis hash {genesis_hash}"
+            );
+        }
+
+        let mut next_block = provider_factory
+            .provider()?
+            .get_stage_checkpoint(StageId::Finish)?
+            .map(|checkpoint| checkpoint.block_number + 1)
+            .unwrap_or(1)
+            .max(1);
+        info!(target: "reth::cli", from = next_block, to = blocks.end - 1, "Importing blocks");
+
+        while next_block < blocks.end {
+            let chunk_end = (next_block + self.chunk_size).min(blocks.end);
+            let file_client = Arc::new(FileClient::from_blocks(
+                (next_block..chunk_end)
+                    .map(|number| ancient.block(number))
+                    .collect::<eyre::Result<Vec<_>>>()?,
+            ));
+            let tip = file_client.tip().expect("file client has no tip");
+
+            let (mut pipeline, events) = build_import_pipeline(
+                config.clone(),
+                self.chain.clone(),
+                provider_factory.clone(),
+                &consensus,
+                file_client,
+            )
+            .await?;
+            pipeline.set_tip(tip);
+            debug!(target: "reth::cli", ?tip, "Tip manually set");
+
+            tokio::spawn(reth_node_core::events::node::handle_events(
+                None,
+                Some(next_block - 1),
+                self.chain.block_duration(),
+                events,
+                db.clone(),
+            ));
+
+            info!(target: "reth::cli", from = next_block, to = chunk_end - 1, "Importing chunk");
+            tokio::select! {
+                res = pipeline.run() => res?,
+                _ = tokio::signal::ctrl_c() => return Ok(()),
+            };
+
+            next_block = chunk_end;
+        }
+
+        info!(target: "reth::cli", "Finishing up");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_import_geth_ancient_command() {
+        let args: ImportGethAncientCommand = ImportGethAncientCommand::parse_from([
+            "reth",
+            "--ancient",
+            "geth/chaindata/ancient",
+            "--chunk-size",
+            "100",
+        ]);
+        assert_eq!(args.ancient, PathBuf::from("geth/chaindata/ancient"));
+        assert_eq!(args.chunk_size, 100);
+    }
+}
@@ -1,12 +1,16 @@
 //! This contains all of the `reth` commands
 
+pub mod chain_spec;
 pub mod config_cmd;
 pub mod db;
 pub mod debug_cmd;
 pub mod import;
 pub mod init_cmd;
+pub mod migrate_geth;
 pub mod node;
 pub mod p2p;
 pub mod recover;
 pub mod stage;
+pub mod stats;
 pub mod test_vectors;
+pub mod tx;
@@ -5,8 +5,8 @@
 use crate::{
     args::{
         utils::{chain_help, genesis_value_parser, parse_socket_address, SUPPORTED_CHAINS},
-        DatabaseArgs, DebugArgs, DevArgs, NetworkArgs, PayloadBuilderArgs, PruningArgs,
-        RpcServerArgs, TxPoolArgs,
+        DatabaseArgs, DebugArgs, DevArgs, NetworkArgs, PayloadBuilderArgs, PreflightArgs,
+        PruningArgs, RpcServerArgs, TxPoolArgs,
     },
     builder::{launch_from_config, NodeConfig},
     cli::{db_type::DatabaseBuilder, ext::RethCliExt},
@@ -76,14 +76,32 @@ pub struct NodeCommand<Ext: RethCliExt = ()> {
     /// Sets all ports to unused, allowing the OS to choose random unused ports when sockets are
     /// bound.
     ///
+    /// Once the node is launched, the bound addresses are printed to stdout as a single line of
+    /// JSON.
+    ///
     /// Mutually exclusive with `--instance`.
     #[arg(long, conflicts_with = "instance", global = true)]
     pub with_unused_ports: bool,
 
+    /// Start the node even if the data directory was last written by a newer version of reth.
+    ///
+    /// Older versions may misinterpret data written in a newer format, only use this if the
+    /// changed formats listed by the refused startup are known to be unaffected.
+    #[arg(long)]
+    pub allow_downgrade: bool,
+
     /// Overrides the KZG trusted setup by reading from the supplied file.
     #[arg(long, value_name = "PATH")]
     pub trusted_setup_file: Option<PathBuf>,
 
+    /// Watches the given chain spec file and applies the changes of the forks that are not active
+    /// yet while the node is running.
+    ///
+    /// Forks can only be added or moved to activations after the canonical head, the updated
+    /// fork id is advertised to peers and used by the executors.
+    #[arg(long, value_name = "FILE")]
+    pub watch_chain_file: Option<PathBuf>,
+
     /// All networking related arguments
     #[clap(flatten)]
     pub network: NetworkArgs,
@@ -116,6 +134,10 @@ pub struct NodeCommand<Ext: RethCliExt = ()> {
     #[clap(flatten)]
     pub pruning: PruningArgs,
 
+    /// All preflight check related arguments
+    #[clap(flatten)]
+    pub preflight: PreflightArgs,
+
     /// Rollup related arguments
     #[cfg(feature = "optimism")]
     #[clap(flatten)]
@@ -138,6 +160,8 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             trusted_setup_file,
             instance,
             with_unused_ports,
+            allow_downgrade,
+            watch_chain_file,
             network,
             rpc,
             txpool,
@@ -146,6 +170,7 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             db,
             dev,
             pruning,
+            preflight,
             #[cfg(feature = "optimism")]
             rollup,
             ..
@@ -157,6 +182,8 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             metrics,
             instance,
             with_unused_ports,
+            allow_downgrade,
+            watch_chain_file,
             trusted_setup_file,
             network,
             rpc,
@@ -166,6 +193,7 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             db,
             dev,
             pruning,
+            preflight,
             #[cfg(feature = "optimism")]
             rollup,
             ext,
@@ -182,6 +210,8 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             trusted_setup_file,
             instance,
             with_unused_ports,
+            allow_downgrade,
+            watch_chain_file,
             network,
             rpc,
             txpool,
@@ -190,6 +220,7 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             db,
             dev,
             pruning,
+            preflight,
             #[cfg(feature = "optimism")]
             rollup,
             ext,
@@ -206,6 +237,8 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             metrics,
             instance,
             trusted_setup_file,
+            allow_downgrade,
+            watch_chain_file,
             network,
             rpc,
             txpool,
@@ -214,6 +247,7 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             db,
             dev,
             pruning,
+            preflight,
             #[cfg(feature = "optimism")]
             rollup,
         };
@@ -227,6 +261,11 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
         // launch the node
         let handle = launch_from_config::<Ext>(node_config, ext, executor).await?;
 
+        if with_unused_ports {
+            // report the ports chosen by the OS, e.g. to test harnesses running multiple nodes
+            println!("{}", serde_json::to_string(&handle.listen_addrs())?);
+        }
+
         handle.wait_for_node_exit().await
     }
 
@@ -359,7 +398,7 @@ mod tests {
             cmd.chain.paris_block_and_final_difficulty,
             chain.paris_block_and_final_difficulty
         );
-        assert_eq!(cmd.chain.hardforks, chain.hardforks);
+        assert_eq!(cmd.chain.hardforks(), chain.hardforks());
 
         assert!(cmd.rpc.http);
         assert!(cmd.network.discovery.disable_discovery);
@@ -0,0 +1,122 @@
+//! Estimate the time and disk space needed to sync to a block
+
+use crate::{
+    args::{
+        utils::{chain_help, genesis_value_parser, SUPPORTED_CHAINS},
+        DatabaseArgs,
+    },
+    dirs::{DataDirPath, MaybePlatformPath},
+};
+use clap::Parser;
+use comfy_table::{Cell, Row, Table as ComfyTable};
+use human_bytes::human_bytes;
+use reth_db::{init_db, mdbx::DatabaseArguments};
+use reth_node_ethereum::EthEvmConfig;
+use reth_primitives::{stage::StageId, ChainSpec};
+use reth_provider::{ProviderFactory, StageCheckpointReader};
+use reth_stages::{sets::OfflineStages, Pipeline};
+use std::{sync::Arc, time::Duration};
+use tracing::info;
+
+/// `reth stage estimate` command
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The path to the data dir for all reth files and subdirectories.
+    ///
+    /// Defaults to the OS-specific data directory:
+    ///
+    /// - Linux: `$XDG_DATA_HOME/reth/` or `$HOME/.local/share/reth/`
+    /// - Windows: `{FOLDERID_RoamingAppData}/reth/`
+    /// - macOS: `$HOME/Library/Application Support/reth/`
+    #[arg(long, value_name = "DATA_DIR", verbatim_doc_comment, default_value_t)]
+    datadir: MaybePlatformPath<DataDirPath>,
+
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = chain_help(),
+        default_value = SUPPORTED_CHAINS[0],
+        value_parser = genesis_value_parser
+    )]
+    chain: Arc<ChainSpec>,
+
+    #[clap(flatten)]
+    db: DatabaseArgs,
+
+    /// The block to estimate the sync for
+    #[arg(long, short)]
+    to: u64,
+
+    /// The maximum number of blocks every stage executes during the dry run
+    #[arg(long, default_value_t = 1_000)]
+    sample_blocks: u64,
+}
+
+impl Command {
+    /// Execute `stage estimate` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        // add network name to data dir
+        let data_dir = self.datadir.unwrap_or_chain_default(self.chain.chain);
+        let db_path = data_dir.db_path();
+
+        info!(target: "reth::cli", path = ?db_path, "Opening database");
+        let db =
+            Arc::new(init_db(db_path, DatabaseArguments::default().log_level(self.db.log_level))?);
+        let provider_factory = ProviderFactory::new(db, self.chain.clone());
+
+        // Only the stages that work on downloaded blocks are sampled, the throughput of the
+        // download stages depends on the network.
+        let bodies = provider_factory
+            .provider()?
+            .get_stage_checkpoint(StageId::Bodies)?
+            .unwrap_or_default()
+            .block_number;
+        let executor_factory =
+            reth_revm::EvmProcessorFactory::new(self.chain.clone(), EthEvmConfig::default());
+        let mut pipeline = Pipeline::builder()
+            .with_max_block(bodies)
+            .add_stages(OfflineStages::new(executor_factory))
+            .build(provider_factory);
+
+        info!(target: "reth::cli", to = self.to, sample = self.sample_blocks, "Sampling stages");
+        let estimate = pipeline.estimate(self.to, self.sample_blocks).await?;
+
+        let mut table = ComfyTable::new();
+        table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+        table.set_header(["Stage", "Checkpoint", "Remaining Blocks", "Blocks/s", "ETA"]);
+        for stage in &estimate.stages {
+            let mut row = Row::new();
+            row.add_cell(Cell::new(stage.stage_id))
+                .add_cell(Cell::new(stage.checkpoint))
+                .add_cell(Cell::new(stage.remaining_blocks))
+                .add_cell(Cell::new(
+                    stage.blocks_per_second().map_or("-".to_string(), |bps| format!("{bps:.2}")),
+                ))
+                .add_cell(Cell::new(format_eta(stage.eta())));
+            table.add_row(row);
+        }
+        println!("{table}");
+
+        println!("Download stages reached block {bodies}, their throughput is not sampled.");
+        println!("Total ETA: {}", format_eta(estimate.eta()));
+        println!("Database size: {}", human_bytes(estimate.database_size as f64));
+        println!(
+            "Database size at block {}: {}",
+            self.to,
+            estimate
+                .database_size_forecast()
+                .map_or("unknown".to_string(), |size| human_bytes(size as f64))
+        );
+
+        Ok(())
+    }
+}
+
+fn format_eta(eta: Option<Duration>) -> String {
+    eta.map_or("unknown".to_string(), |eta| {
+        humantime::format_duration(Duration::from_secs(eta.as_secs())).to_string()
+    })
+}
@@ -4,6 +4,7 @@ use clap::{Parser, Subcommand};
 
 pub mod drop;
 pub mod dump;
+pub mod estimate;
 pub mod run;
 pub mod unwind;
 
@@ -30,6 +31,12 @@ pub enum Subcommands {
     Dump(dump::Command),
     /// Unwinds a certain block range, deleting it from the database.
     Unwind(unwind::Command),
+    /// Estimates the time and disk space needed to sync to a block.
+    ///
+    /// Every stage that works on downloaded blocks is executed on a sample of blocks in a
+    /// transaction that is never committed, and the throughput is extrapolated to the remaining
+    /// blocks.
+    Estimate(estimate::Command),
 }
 
 impl Command {
@@ -40,6 +47,7 @@ impl Command {
             Subcommands::Drop(command) => command.execute().await,
             Subcommands::Dump(command) => command.execute().await,
             Subcommands::Unwind(command) => command.execute().await,
+            Subcommands::Estimate(command) => command.execute().await,
         }
     }
 }
@@ -212,7 +212,12 @@ impl Command {
                                 max_duration: None,
                             },
                             config.stages.merkle.clean_threshold,
-                            config.prune.map(|prune| prune.segments).unwrap_or_default(),
+                            config
+                                .prune
+                                .map(|prune| {
+                                    prune.segments.with_deposit_contract_guard(&self.chain)
+                                })
+                                .unwrap_or_default(),
                         )),
                         None,
                     )
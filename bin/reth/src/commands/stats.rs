@@ -0,0 +1,107 @@
+//! Statistics gathered by the node
+
+use crate::{
+    args::{
+        utils::{chain_help, genesis_value_parser, SUPPORTED_CHAINS},
+        DatabaseArgs,
+    },
+    dirs::{DataDirPath, MaybePlatformPath},
+};
+use clap::{Parser, Subcommand};
+use comfy_table::{Cell, Row, Table as ComfyTable};
+use reth_db::{mdbx::DatabaseArguments, open_db_read_only};
+use reth_primitives::ChainSpec;
+use reth_provider::{HotAccountsReader, ProviderFactory};
+use std::sync::Arc;
+
+/// `reth stats` command
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The path to the data dir for all reth files and subdirectories.
+    ///
+    /// Defaults to the OS-specific data directory:
+    ///
+    /// - Linux: `$XDG_DATA_HOME/reth/` or `$HOME/.local/share/reth/`
+    /// - Windows: `{FOLDERID_RoamingAppData}/reth/`
+    /// - macOS: `$HOME/Library/Application Support/reth/`
+    #[arg(long, value_name = "DATA_DIR", verbatim_doc_comment, default_value_t, global = true)]
+    datadir: MaybePlatformPath<DataDirPath>,
+
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = chain_help(),
+        default_value = SUPPORTED_CHAINS[0],
+        value_parser = genesis_value_parser,
+        global = true,
+    )]
+    chain: Arc<ChainSpec>,
+
+    #[clap(flatten)]
+    db: DatabaseArgs,
+
+    #[clap(subcommand)]
+    command: Subcommands,
+}
+
+#[derive(Subcommand, Debug)]
+/// `reth stats` subcommands
+pub enum Subcommands {
+    /// Lists the most frequently accessed accounts of the executed blocks
+    HotAccounts {
+        /// The number of accounts to list
+        #[arg(long, short, default_value_t = 20)]
+        limit: usize,
+        /// The number of storage slots to list per account
+        #[arg(long, default_value_t = 3)]
+        slots: usize,
+    },
+}
+
+impl Command {
+    /// Execute `stats` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        // add network name to data dir
+        let data_dir = self.datadir.unwrap_or_chain_default(self.chain.chain);
+        let db_path = data_dir.db_path();
+
+        let db =
+            open_db_read_only(&db_path, DatabaseArguments::default().log_level(self.db.log_level))?;
+        let factory = ProviderFactory::new(db, self.chain.clone());
+
+        match self.command {
+            Subcommands::HotAccounts { limit, slots } => {
+                let accounts = factory.hottest_accounts(limit)?;
+
+                let mut table = ComfyTable::new();
+                table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+                table.set_header(["Address", "Score", "Last Block", "# Slots", "Hottest Slots"]);
+
+                for (address, stats) in accounts {
+                    let hottest = stats
+                        .slots
+                        .iter()
+                        .take(slots)
+                        .map(|slot| format!("{} ({})", slot.key, slot.score))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+
+                    let mut row = Row::new();
+                    row.add_cell(Cell::new(address))
+                        .add_cell(Cell::new(stats.score))
+                        .add_cell(Cell::new(stats.last_block))
+                        .add_cell(Cell::new(stats.slots.len()))
+                        .add_cell(Cell::new(hottest));
+                    table.add_row(row);
+                }
+
+                println!("{table}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use crate::args::utils::{chain_spec_value_parser, SUPPORTED_CHAINS};
+use eyre::Result;
+use reth_primitives::{fs, hex, Bytes, ChainSpec, ForkId, Head, ValidationError, B256};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use tracing::error;
+
+const VECTORS_FOLDER: &str = "testdata/micro/chainspec";
+
+/// Test vectors of a single chain.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChainSpecVectors {
+    /// The chain ID.
+    chain_id: u64,
+    /// The hash of the genesis block.
+    genesis_hash: B256,
+    /// The RLP encoded genesis header.
+    genesis_header: Bytes,
+    /// The fork ID at every head around a fork activation.
+    fork_ids: Vec<ForkIdVector>,
+    /// The result of validating every fork ID of `fork_ids` at every head of `fork_ids`.
+    fork_filter: Vec<ForkFilterVector>,
+}
+
+/// The fork ID of a chain at the given head.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ForkIdVector {
+    number: u64,
+    timestamp: u64,
+    fork_hash: String,
+    fork_next: u64,
+}
+
+/// The result of validating a remote fork ID at the given local head.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ForkFilterVector {
+    number: u64,
+    timestamp: u64,
+    remote_fork_hash: String,
+    remote_fork_next: u64,
+    result: ForkFilterResult,
+}
+
+/// The outcome of an EIP-2124 fork ID validation.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum ForkFilterResult {
+    Valid,
+    RemoteStale,
+    LocalIncompatibleOrStale,
+}
+
+/// Generates test vectors for the specified built-in `chains`. If list is empty, then generate
+/// for all built-in chains.
+pub(crate) fn generate_vectors(mut chains: Vec<String>) -> Result<()> {
+    fs::create_dir_all(VECTORS_FOLDER)?;
+
+    if chains.is_empty() {
+        chains = SUPPORTED_CHAINS.iter().map(|chain| chain.to_string()).collect();
+    }
+
+    for chain in chains {
+        if !SUPPORTED_CHAINS.contains(&chain.as_str()) {
+            error!(target: "reth::cli", "Unknown chain: {}", chain);
+            continue
+        }
+
+        println!("Generating test vectors for chain <{chain}>.");
+        let vectors = chain_spec_vectors(&chain_spec_value_parser(&chain)?);
+        serde_json::to_writer_pretty(
+            std::io::BufWriter::new(std::fs::File::create(format!(
+                "{VECTORS_FOLDER}/{chain}.json"
+            ))?),
+            &vectors,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Derives the test vectors of the given chain from its hardfork schedule.
+fn chain_spec_vectors(chain_spec: &ChainSpec) -> ChainSpecVectors {
+    let genesis_header = chain_spec.genesis_header();
+    let mut genesis_rlp = Vec::new();
+    alloy_rlp::Encodable::encode(&genesis_header, &mut genesis_rlp);
+
+    let heads = fork_heads(chain_spec);
+    let fork_ids = heads
+        .iter()
+        .map(|head| (head, chain_spec.fork_id(head)))
+        .map(|(head, fork_id)| ForkIdVector {
+            number: head.number,
+            timestamp: head.timestamp,
+            fork_hash: fork_hash(&fork_id),
+            fork_next: fork_id.next,
+        })
+        .collect();
+
+    let remote_ids = chain_spec.fork_id_table().fork_ids().collect::<Vec<_>>();
+    let mut fork_filter = Vec::new();
+    for head in &heads {
+        let filter = chain_spec.fork_filter(*head);
+        for remote in &remote_ids {
+            let result = match filter.validate(*remote) {
+                Ok(()) => ForkFilterResult::Valid,
+                Err(ValidationError::RemoteStale { .. }) => ForkFilterResult::RemoteStale,
+                Err(ValidationError::LocalIncompatibleOrStale { .. }) => {
+                    ForkFilterResult::LocalIncompatibleOrStale
+                }
+            };
+            fork_filter.push(ForkFilterVector {
+                number: head.number,
+                timestamp: head.timestamp,
+                remote_fork_hash: fork_hash(remote),
+                remote_fork_next: remote.next,
+                result,
+            });
+        }
+    }
+
+    ChainSpecVectors {
+        chain_id: chain_spec.chain.id(),
+        genesis_hash: chain_spec.genesis_hash(),
+        genesis_header: genesis_rlp.into(),
+        fork_ids,
+        fork_filter,
+    }
+}
+
+/// Returns the genesis head, and the heads right before and at every fork activation of the
+/// [ForkIdTable](reth_primitives::ForkIdTable) of the chain.
+fn fork_heads(chain_spec: &ChainSpec) -> Vec<Head> {
+    let genesis_timestamp = chain_spec.genesis.timestamp;
+    let mut heads = BTreeSet::from([Head { timestamp: genesis_timestamp, ..Default::default() }]);
+    for head in chain_spec.fork_id_table().activation_heads() {
+        let head = Head { timestamp: head.timestamp.max(genesis_timestamp), ..head };
+        let before = if head.timestamp > genesis_timestamp {
+            Head { timestamp: head.timestamp - 1, ..head }
+        } else {
+            Head { number: head.number.saturating_sub(1), ..head }
+        };
+        heads.extend([before, head]);
+    }
+    heads.into_iter().collect()
+}
+
+fn fork_hash(fork_id: &ForkId) -> String {
+    hex::encode_prefixed(fork_id.hash.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{MAINNET, MAINNET_GENESIS_HASH};
+
+    #[test]
+    fn mainnet_chain_spec_vectors() {
+        let vectors = chain_spec_vectors(&MAINNET);
+        assert_eq!(vectors.chain_id, 1);
+        assert_eq!(vectors.genesis_hash, MAINNET_GENESIS_HASH);
+
+        // EIP-2124 test vectors
+        let frontier = vectors.fork_ids.first().unwrap();
+        assert_eq!((frontier.number, frontier.fork_hash.as_str()), (0, "0xfc64ec04"));
+        assert_eq!(frontier.fork_next, 1150000);
+        let homestead = vectors.fork_ids.iter().find(|v| v.number == 1150000).unwrap();
+        assert_eq!(homestead.fork_hash, "0x97c2c34c");
+
+        // every node accepts its own fork ID
+        for vector in &vectors.fork_ids {
+            assert!(vectors.fork_filter.iter().any(|filter| filter.number == vector.number &&
+                filter.timestamp == vector.timestamp &&
+                filter.remote_fork_hash == vector.fork_hash &&
+                matches!(filter.result, ForkFilterResult::Valid)));
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use clap::{Parser, Subcommand};
 
+mod chainspec;
 mod tables;
 
 /// Generate test-vectors for different data types.
@@ -19,6 +20,12 @@ pub enum Subcommands {
         /// List of table names. Case-sensitive.
         names: Vec<String>,
     },
+    /// Generates fork ID, fork filter and genesis header test vectors for specified built-in
+    /// chains. If no chain is specified, generate for all.
+    Chainspec {
+        /// List of chain names.
+        chains: Vec<String>,
+    },
 }
 
 impl Command {
@@ -28,6 +35,9 @@ impl Command {
             Subcommands::Tables { names } => {
                 tables::generate_vectors(names)?;
             }
+            Subcommands::Chainspec { chains } => {
+                chainspec::generate_vectors(chains)?;
+            }
         }
         Ok(())
     }
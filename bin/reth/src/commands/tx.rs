@@ -0,0 +1,197 @@
+//! Command for decoding and signing raw transactions.
+
+use crate::args::utils::{chain_help, genesis_value_parser, SUPPORTED_CHAINS};
+use clap::{Parser, Subcommand};
+use eyre::WrapErr;
+use reth_primitives::{
+    hex, Address, Bytes, ChainSpec, ForkCondition, Hardfork, Transaction, TransactionKind,
+    TransactionSigned, TxEip1559, TxLegacy, TxType, B256, U256,
+};
+use reth_rpc_types_compat::transaction::from_recovered;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// `reth tx` command
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = chain_help(),
+        default_value = SUPPORTED_CHAINS[0],
+        value_parser = genesis_value_parser
+    )]
+    chain: Arc<ChainSpec>,
+
+    #[clap(subcommand)]
+    command: Subcommands,
+}
+
+/// `reth tx` subcommands
+#[derive(Subcommand, Debug)]
+pub enum Subcommands {
+    /// Decodes a raw transaction and prints its fields as JSON.
+    Decode {
+        /// The raw transaction, as hex.
+        raw: Bytes,
+    },
+    /// Signs a transaction for the chain and prints the raw transaction as hex.
+    ///
+    /// Legacy transactions are signed with EIP-155 replay protection.
+    Sign(SignArgs),
+}
+
+/// Arguments of `reth tx sign`.
+#[derive(Debug, Parser)]
+pub struct SignArgs {
+    /// The secret key to sign with, as hex.
+    #[arg(long, value_name = "HEX")]
+    private_key: B256,
+
+    /// The recipient. Creates a contract if not set.
+    #[arg(long)]
+    to: Option<Address>,
+
+    /// The value to transfer, in wei.
+    #[arg(long, default_value_t)]
+    value: U256,
+
+    /// The nonce of the sender.
+    #[arg(long, default_value_t)]
+    nonce: u64,
+
+    /// The gas limit.
+    #[arg(long, default_value_t = 21_000)]
+    gas_limit: u64,
+
+    /// Creates a legacy transaction with the given gas price, instead of an EIP-1559 transaction.
+    #[arg(long, conflicts_with_all = ["max_fee_per_gas", "max_priority_fee_per_gas"])]
+    gas_price: Option<u128>,
+
+    /// The max fee per gas of the EIP-1559 transaction.
+    #[arg(long, default_value_t = 1_000_000_000)]
+    max_fee_per_gas: u128,
+
+    /// The max priority fee per gas of the EIP-1559 transaction.
+    #[arg(long, default_value_t)]
+    max_priority_fee_per_gas: u128,
+
+    /// The input data, as hex.
+    #[arg(long, default_value_t)]
+    input: Bytes,
+}
+
+impl SignArgs {
+    /// Returns the unsigned transaction for the given chain id.
+    fn transaction(&self, chain_id: u64) -> Transaction {
+        let to = self.to.map(TransactionKind::Call).unwrap_or(TransactionKind::Create);
+        match self.gas_price {
+            Some(gas_price) => Transaction::Legacy(TxLegacy {
+                chain_id: Some(chain_id),
+                nonce: self.nonce,
+                gas_price,
+                gas_limit: self.gas_limit,
+                to,
+                value: self.value.into(),
+                input: self.input.clone(),
+            }),
+            None => Transaction::Eip1559(TxEip1559 {
+                chain_id,
+                nonce: self.nonce,
+                gas_limit: self.gas_limit,
+                max_fee_per_gas: self.max_fee_per_gas,
+                max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+                to,
+                value: self.value.into(),
+                access_list: Default::default(),
+                input: self.input.clone(),
+            }),
+        }
+    }
+}
+
+impl Command {
+    /// Execute `tx` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::Decode { raw } => {
+                let tx = TransactionSigned::decode_enveloped(&mut raw.as_ref())
+                    .map_err(|err| eyre::eyre!("failed to decode transaction: {err}"))?;
+                for violation in chain_rule_violations(&self.chain, &tx) {
+                    warn!(target: "reth::cli", "{violation}");
+                }
+                let tx = tx
+                    .into_ecrecovered()
+                    .ok_or_else(|| eyre::eyre!("failed to recover the transaction signer"))?;
+                println!("{}", serde_json::to_string_pretty(&from_recovered(tx))?);
+            }
+            Subcommands::Sign(args) => {
+                let chain_id = self.chain.chain.id();
+                let tx = args
+                    .transaction(chain_id)
+                    .sign_for_chain(args.private_key, chain_id)
+                    .wrap_err("failed to sign transaction")?;
+                info!(target: "reth::cli", hash = ?tx.hash(), "Signed transaction");
+                println!("{}", hex::encode_prefixed(tx.envelope_encoded()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the rules of the chain the transaction does not comply with.
+fn chain_rule_violations(chain: &ChainSpec, tx: &TransactionSigned) -> Vec<String> {
+    let mut violations = Vec::new();
+    match tx.chain_id() {
+        Some(chain_id) if chain_id != chain.chain.id() => violations.push(format!(
+            "transaction is signed for chain {chain_id}, not for chain {}",
+            chain.chain.id()
+        )),
+        None if tx.tx_type() == TxType::Legacy => violations
+            .push("transaction is not replay protected, it is signed without EIP-155".to_string()),
+        _ => {}
+    }
+
+    let fork = match tx.tx_type() {
+        TxType::EIP2930 => Some(Hardfork::Berlin),
+        TxType::EIP1559 => Some(Hardfork::London),
+        TxType::EIP4844 => Some(Hardfork::Cancun),
+        _ => None,
+    };
+    if let Some(fork) = fork {
+        if chain.fork(fork) == ForkCondition::Never {
+            violations.push(format!(
+                "{:?} transactions require {fork}, which is not scheduled on this chain",
+                tx.tx_type()
+            ));
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{DEV, MAINNET};
+
+    #[test]
+    fn signed_transactions_comply_with_chain_rules() {
+        let args = SignArgs::parse_from([
+            "sign",
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "--to",
+            "0x0000000000000000000000000000000000000001",
+        ]);
+        let tx = args.transaction(1).sign_for_chain(args.private_key, 1).unwrap();
+
+        let decoded =
+            TransactionSigned::decode_enveloped(&mut tx.envelope_encoded().as_ref()).unwrap();
+        assert_eq!(decoded, tx);
+        assert!(chain_rule_violations(&MAINNET, &decoded).is_empty());
+        assert_eq!(chain_rule_violations(&DEV, &decoded).len(), 1);
+    }
+}
@@ -10,7 +10,8 @@ use reth_db::{database::Database, DatabaseError};
 use reth_interfaces::{
     blockchain_tree::{
         error::{BlockchainTreeError, CanonicalError, InsertBlockError, InsertBlockErrorKind},
-        BlockAttachment, BlockStatus, BlockValidationKind, CanonicalOutcome, InsertPayloadOk,
+        BlockAttachment, BlockStatus, BlockValidationKind, CanonicalOutcome, ChainStateUpdate,
+        InsertPayloadOk,
     },
     consensus::{Consensus, ConsensusError},
     executor::{BlockExecutionError, BlockValidationError},
@@ -18,14 +19,15 @@ use reth_interfaces::{
     RethError, RethResult,
 };
 use reth_primitives::{
-    BlockHash, BlockNumHash, BlockNumber, ForkBlock, GotExpected, Hardfork, PruneModes, Receipt,
-    SealedBlock, SealedBlockWithSenders, SealedHeader, U256,
+    BlockHash, BlockNumHash, BlockNumber, ForkBlock, GotExpected, PruneModes, Receipt, SealedBlock,
+    SealedBlockWithSenders, SealedHeader, U256,
 };
 use reth_provider::{
     chain::{ChainSplit, ChainSplitTarget},
     BlockExecutionWriter, BlockNumReader, BlockWriter, BundleStateWithReceipts,
     CanonStateNotification, CanonStateNotificationSender, CanonStateNotifications, Chain,
-    ChainSpecProvider, DisplayBlocksChain, ExecutorFactory, HeaderProvider, ProviderError,
+    ChainSpecProvider, ChainStateWriter, DisplayBlocksChain, ExecutorFactory, HeaderProvider,
+    ProviderError,
 };
 use reth_stages::{MetricEvent, MetricEventsSender};
 use std::{
@@ -74,6 +76,8 @@ pub struct BlockchainTree<DB: Database, EVM: ExecutorFactory> {
     /// Metrics for sync stages.
     sync_metrics_tx: Option<MetricEventsSender>,
     prune_modes: Option<PruneModes>,
+    /// The forkchoice state and invalid blocks to persist with the next canonical commit.
+    pending_chain_state: ChainStateUpdate,
 }
 
 impl<DB: Database, EVM: ExecutorFactory> BlockchainTree<DB, EVM> {
@@ -117,6 +121,7 @@ impl<DB: Database, EVM: ExecutorFactory> BlockchainTree<DB, EVM> {
             metrics: Default::default(),
             sync_metrics_tx: None,
             prune_modes,
+            pending_chain_state: Default::default(),
         })
     }
 
@@ -366,8 +371,7 @@ impl<DB: Database, EVM: ExecutorFactory> BlockchainTree<DB, EVM> {
             .externals
             .provider_factory
             .chain_spec()
-            .fork(Hardfork::Paris)
-            .active_at_ttd(parent_td, U256::ZERO)
+            .is_paris_active_at_ttd(parent_td, U256::ZERO)
         {
             return Err(BlockExecutionError::Validation(BlockValidationError::BlockPreMerge {
                 hash: block.hash(),
@@ -396,6 +400,7 @@ impl<DB: Database, EVM: ExecutorFactory> BlockchainTree<DB, EVM> {
             &self.externals,
             block_attachment,
             block_validation_kind,
+            self.config.slow_block_thresholds(),
         )?;
 
         self.insert_chain(chain);
@@ -453,6 +458,7 @@ impl<DB: Database, EVM: ExecutorFactory> BlockchainTree<DB, EVM> {
                 canonical_fork,
                 block_attachment,
                 block_validation_kind,
+                self.config.slow_block_thresholds(),
             )?;
 
             self.block_indices_mut().insert_non_fork_block(block_number, block_hash, chain_id);
@@ -467,6 +473,7 @@ impl<DB: Database, EVM: ExecutorFactory> BlockchainTree<DB, EVM> {
                 canonical_fork,
                 &self.externals,
                 block_validation_kind,
+                self.config.slow_block_thresholds(),
             )?;
             self.insert_chain(chain);
             BlockAttachment::HistoricalFork
@@ -935,12 +942,7 @@ impl<DB: Database, EVM: ExecutorFactory> BlockchainTree<DB, EVM> {
                     hash: *block_hash,
                 }),
             )?;
-            if !self
-                .externals
-                .provider_factory
-                .chain_spec()
-                .fork(Hardfork::Paris)
-                .active_at_ttd(td, U256::ZERO)
+            if !self.externals.provider_factory.chain_spec().is_paris_active_at_ttd(td, U256::ZERO)
             {
                 return Err(CanonicalError::from(BlockValidationError::BlockPreMerge {
                     hash: *block_hash,
@@ -1095,9 +1097,17 @@ impl<DB: Database, EVM: ExecutorFactory> BlockchainTree<DB, EVM> {
         self.canon_state_notification_sender.clone()
     }
 
+    /// Records the forkchoice state and invalid blocks of the given update, they are persisted with
+    /// the next canonical commit.
+    pub fn update_chain_state(&mut self, update: ChainStateUpdate) {
+        self.pending_chain_state.extend(update);
+    }
+
     /// Write the given chain to the database as canonical.
+    ///
+    /// The pending forkchoice state and invalid blocks are written in the same transaction.
     fn commit_canonical_to_database(
-        &self,
+        &mut self,
         chain: Chain,
         recorder: &mut MakeCanonicalDurationsRecorder,
     ) -> RethResult<()> {
@@ -1151,6 +1161,9 @@ impl<DB: Database, EVM: ExecutorFactory> BlockchainTree<DB, EVM> {
                 self.prune_modes.as_ref(),
             )
             .map_err(|e| BlockExecutionError::CanonicalCommit { inner: e.to_string() })?;
+        if !self.pending_chain_state.is_empty() {
+            provider_rw.save_chain_state(&std::mem::take(&mut self.pending_chain_state))?;
+        }
 
         provider_rw.commit()?;
         recorder.record_relative(MakeCanonicalAction::CommitCanonicalChainToDatabase);
@@ -4,7 +4,7 @@
 //! blocks, as well as a list of the blocks the chain is composed of.
 
 use super::externals::TreeExternals;
-use crate::BundleStateDataRef;
+use crate::{config::SlowBlockThresholds, metrics::BlockExecutionMetrics, BundleStateDataRef};
 use reth_db::database::Database;
 use reth_interfaces::{
     blockchain_tree::{
@@ -15,7 +15,8 @@ use reth_interfaces::{
     RethResult,
 };
 use reth_primitives::{
-    BlockHash, BlockNumber, ForkBlock, GotExpected, SealedBlockWithSenders, SealedHeader, U256,
+    BlockHash, BlockNumber, BlockWithSenders, ForkBlock, GotExpected, SealedBlockWithSenders,
+    SealedHeader, U256,
 };
 use reth_provider::{
     providers::BundleStateProvider, BundleStateDataProvider, BundleStateWithReceipts, Chain,
@@ -23,11 +24,15 @@ use reth_provider::{
 };
 use reth_trie::updates::TrieUpdates;
 use std::{
+    cmp::Reverse,
     collections::BTreeMap,
     ops::{Deref, DerefMut},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
+/// The number of transactions that are listed when the execution of a block is reported as slow.
+const SLOW_BLOCK_TOP_TRANSACTIONS: usize = 5;
+
 /// A chain if the blockchain tree, that has functionality to execute blocks and append them to the
 /// it self.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -64,6 +69,7 @@ impl AppendableChain {
     ///
     /// if [BlockValidationKind::Exhaustive] is specified, the method will verify the state root of
     /// the block.
+    #[allow(clippy::too_many_arguments)]
     pub fn new_canonical_fork<DB, EF>(
         block: SealedBlockWithSenders,
         parent_header: &SealedHeader,
@@ -72,6 +78,7 @@ impl AppendableChain {
         externals: &TreeExternals<DB, EF>,
         block_attachment: BlockAttachment,
         block_validation_kind: BlockValidationKind,
+        slow_block_thresholds: SlowBlockThresholds,
     ) -> Result<Self, InsertBlockErrorKind>
     where
         DB: Database,
@@ -94,6 +101,7 @@ impl AppendableChain {
             externals,
             block_attachment,
             block_validation_kind,
+            slow_block_thresholds,
         )?;
 
         Ok(Self { chain: Chain::new(vec![block], bundle_state, trie_updates) })
@@ -102,6 +110,7 @@ impl AppendableChain {
     /// Create a new chain that forks off of an existing sidechain.
     ///
     /// This differs from [AppendableChain::new_canonical_fork] in that this starts a new fork.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new_chain_fork<DB, EF>(
         &self,
         block: SealedBlockWithSenders,
@@ -110,6 +119,7 @@ impl AppendableChain {
         canonical_fork: ForkBlock,
         externals: &TreeExternals<DB, EF>,
         block_validation_kind: BlockValidationKind,
+        slow_block_thresholds: SlowBlockThresholds,
     ) -> Result<Self, InsertBlockErrorKind>
     where
         DB: Database,
@@ -139,6 +149,7 @@ impl AppendableChain {
             externals,
             BlockAttachment::HistoricalFork,
             block_validation_kind,
+            slow_block_thresholds,
         )?;
         // extending will also optimize few things, mostly related to selfdestruct and wiping of
         // storage.
@@ -170,6 +181,7 @@ impl AppendableChain {
         externals: &TreeExternals<DB, EVM>,
         block_attachment: BlockAttachment,
         block_validation_kind: BlockValidationKind,
+        slow_block_thresholds: SlowBlockThresholds,
     ) -> RethResult<(BundleStateWithReceipts, Option<TrieUpdates>)>
     where
         BSDP: BundleStateDataProvider,
@@ -189,8 +201,16 @@ impl AppendableChain {
         let mut executor = externals.executor_factory.with_state(&provider);
         let block_hash = block.hash();
         let block = block.unseal();
+        let execution_start = Instant::now();
         executor.execute_and_verify_receipt(&block, U256::MAX)?;
         let bundle_state = executor.take_output_state();
+        Self::record_block_execution(
+            &block,
+            block_hash,
+            &bundle_state,
+            execution_start.elapsed(),
+            slow_block_thresholds,
+        );
 
         // check state root if the block extends the canonical chain __and__ if state root
         // validation was requested.
@@ -225,6 +245,56 @@ impl AppendableChain {
         }
     }
 
+    /// Records the execution metrics of the given block, and warns if its execution exceeded the
+    /// [SlowBlockThresholds], listing the transactions of the block that used the most gas.
+    fn record_block_execution(
+        block: &BlockWithSenders,
+        block_hash: BlockHash,
+        bundle_state: &BundleStateWithReceipts,
+        elapsed: Duration,
+        slow_block_thresholds: SlowBlockThresholds,
+    ) {
+        let metrics = BlockExecutionMetrics::default();
+        metrics.duration.record(elapsed);
+        let gas_per_second = block.gas_used as f64 / elapsed.as_secs_f64();
+        if gas_per_second.is_finite() {
+            metrics.gas_per_second.set(gas_per_second);
+        }
+
+        if !slow_block_thresholds.is_slow(elapsed, block.gas_used) {
+            return
+        }
+        metrics.slow_blocks.increment(1);
+
+        // the gas used by a transaction is the difference of the cumulative gas used of its
+        // receipt and the previous one
+        let mut cumulative_gas_used = 0;
+        let mut transactions = bundle_state
+            .receipts_by_block(block.number)
+            .iter()
+            .zip(block.body.iter())
+            .filter_map(|(receipt, transaction)| {
+                let receipt = receipt.as_ref()?;
+                let gas_used = receipt.cumulative_gas_used - cumulative_gas_used;
+                cumulative_gas_used = receipt.cumulative_gas_used;
+                Some((transaction.hash(), gas_used))
+            })
+            .collect::<Vec<_>>();
+        transactions.sort_unstable_by_key(|(_, gas_used)| Reverse(*gas_used));
+        transactions.truncate(SLOW_BLOCK_TOP_TRANSACTIONS);
+
+        tracing::warn!(
+            target: "blockchain_tree::chain",
+            number = block.number,
+            hash = %block_hash,
+            ?elapsed,
+            gas_used = block.gas_used,
+            gas_per_second = gas_per_second as u64,
+            top_transactions = ?transactions,
+            "Slow block execution"
+        );
+    }
+
     /// Validate and execute the given block, and append it to this chain.
     ///
     /// This expects that the block's ancestors can be traced back to the `canonical_fork` (the
@@ -247,6 +317,7 @@ impl AppendableChain {
         canonical_fork: ForkBlock,
         block_attachment: BlockAttachment,
         block_validation_kind: BlockValidationKind,
+        slow_block_thresholds: SlowBlockThresholds,
     ) -> Result<(), InsertBlockErrorKind>
     where
         DB: Database,
@@ -268,6 +339,7 @@ impl AppendableChain {
             externals,
             block_attachment,
             block_validation_kind,
+            slow_block_thresholds,
         )?;
         // extend the state.
         self.chain.append_block(block, block_state, trie_updates);
@@ -1,5 +1,42 @@
 //! Blockchain tree configuration
 
+use std::time::Duration;
+
+/// The execution time below which the gas throughput of a block is not checked, the throughput
+/// of short executions is dominated by fixed costs.
+pub const MIN_THROUGHPUT_CHECK_DURATION: Duration = Duration::from_millis(100);
+
+/// The thresholds beyond which the execution of a block is reported as slow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SlowBlockThresholds {
+    /// The maximum time the execution of a block may take.
+    pub max_execution_time: Option<Duration>,
+    /// The minimum gas throughput of the execution of a block, in gas per second.
+    ///
+    /// Only checked for blocks whose execution took at least [MIN_THROUGHPUT_CHECK_DURATION].
+    pub min_gas_per_second: Option<u64>,
+}
+
+impl Default for SlowBlockThresholds {
+    fn default() -> Self {
+        Self { max_execution_time: Some(Duration::from_secs(1)), min_gas_per_second: None }
+    }
+}
+
+impl SlowBlockThresholds {
+    /// Returns true if a block that used the given gas and took the given time to execute exceeds
+    /// the thresholds.
+    pub fn is_slow(&self, elapsed: Duration, gas_used: u64) -> bool {
+        if self.max_execution_time.is_some_and(|max| elapsed > max) {
+            return true
+        }
+        self.min_gas_per_second.is_some_and(|min| {
+            elapsed >= MIN_THROUGHPUT_CHECK_DURATION &&
+                (gas_used as f64 / elapsed.as_secs_f64()) < min as f64
+        })
+    }
+}
+
 /// The configuration for the blockchain tree.
 #[derive(Clone, Copy, Debug)]
 pub struct BlockchainTreeConfig {
@@ -19,6 +56,8 @@ pub struct BlockchainTreeConfig {
     /// be 256. It covers both number of blocks required for reorg, and number of blocks
     /// required for `BLOCKHASH` EVM opcode.
     num_of_additional_canonical_block_hashes: u64,
+    /// The thresholds beyond which the execution of a block is reported as slow.
+    slow_block_thresholds: SlowBlockThresholds,
 }
 
 impl Default for BlockchainTreeConfig {
@@ -33,6 +72,7 @@ impl Default for BlockchainTreeConfig {
             num_of_additional_canonical_block_hashes: 256,
             // max unconnected blocks.
             max_unconnected_blocks: 200,
+            slow_block_thresholds: SlowBlockThresholds::default(),
         }
     }
 }
@@ -53,9 +93,16 @@ impl BlockchainTreeConfig {
             max_reorg_depth,
             num_of_additional_canonical_block_hashes,
             max_unconnected_blocks,
+            slow_block_thresholds: SlowBlockThresholds::default(),
         }
     }
 
+    /// Set the thresholds beyond which the execution of a block is reported as slow.
+    pub fn with_slow_block_thresholds(mut self, thresholds: SlowBlockThresholds) -> Self {
+        self.slow_block_thresholds = thresholds;
+        self
+    }
+
     /// Return the maximum reorg depth.
     pub fn max_reorg_depth(&self) -> u64 {
         self.max_reorg_depth
@@ -87,4 +134,31 @@ impl BlockchainTreeConfig {
     pub fn max_unconnected_blocks(&self) -> usize {
         self.max_unconnected_blocks
     }
+
+    /// Return the thresholds beyond which the execution of a block is reported as slow.
+    pub fn slow_block_thresholds(&self) -> SlowBlockThresholds {
+        self.slow_block_thresholds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slow_block_thresholds() {
+        let thresholds = SlowBlockThresholds {
+            max_execution_time: Some(Duration::from_secs(1)),
+            min_gas_per_second: Some(10_000_000),
+        };
+        assert!(!thresholds.is_slow(Duration::from_millis(500), 30_000_000));
+        assert!(thresholds.is_slow(Duration::from_millis(1500), 30_000_000));
+        // below the minimum throughput
+        assert!(thresholds.is_slow(Duration::from_millis(500), 1_000_000));
+        // too short to check the throughput
+        assert!(!thresholds.is_slow(Duration::from_millis(50), 21_000));
+
+        let thresholds = SlowBlockThresholds { max_execution_time: None, min_gas_per_second: None };
+        assert!(!thresholds.is_slow(Duration::from_secs(10), 0));
+    }
 }
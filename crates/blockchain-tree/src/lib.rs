@@ -27,7 +27,7 @@ pub mod chain;
 pub use chain::AppendableChain;
 
 pub mod config;
-pub use config::BlockchainTreeConfig;
+pub use config::{BlockchainTreeConfig, SlowBlockThresholds};
 
 pub mod externals;
 pub use externals::TreeExternals;
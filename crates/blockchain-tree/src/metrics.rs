@@ -25,6 +25,18 @@ pub struct TreeMetrics {
     pub trie_updates_insert_recomputed: Counter,
 }
 
+/// Metrics for the execution of blocks inserted into the blockchain tree
+#[derive(Metrics)]
+#[metrics(scope = "blockchain_tree.block_execution")]
+pub(crate) struct BlockExecutionMetrics {
+    /// The time it took to execute a block
+    pub(crate) duration: Histogram,
+    /// The gas throughput of the latest executed block, in gas per second
+    pub(crate) gas_per_second: Gauge,
+    /// The number of blocks whose execution exceeded the slow block thresholds
+    pub(crate) slow_blocks: Counter,
+}
+
 /// Metrics for the blockchain tree block buffer
 #[derive(Metrics)]
 #[metrics(scope = "blockchain_tree.block_buffer")]
@@ -2,7 +2,7 @@ use reth_interfaces::{
     blockchain_tree::{
         error::{BlockchainTreeError, InsertBlockError},
         BlockValidationKind, BlockchainTreeEngine, BlockchainTreeViewer, CanonicalOutcome,
-        InsertPayloadOk,
+        ChainStateUpdate, InsertPayloadOk,
     },
     RethResult,
 };
@@ -56,6 +56,8 @@ impl BlockchainTreeEngine for NoopBlockchainTree {
         Err(BlockchainTreeError::BlockHashNotFoundInChain { block_hash: *block_hash }.into())
     }
 
+    fn update_chain_state(&self, _update: ChainStateUpdate) {}
+
     fn unwind(&self, _unwind_to: BlockNumber) -> RethResult<()> {
         Ok(())
     }
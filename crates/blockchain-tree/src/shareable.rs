@@ -6,7 +6,7 @@ use reth_db::database::Database;
 use reth_interfaces::{
     blockchain_tree::{
         error::InsertBlockError, BlockValidationKind, BlockchainTreeEngine, BlockchainTreeViewer,
-        CanonicalOutcome, InsertPayloadOk,
+        CanonicalOutcome, ChainStateUpdate, InsertPayloadOk,
     },
     RethResult,
 };
@@ -93,6 +93,11 @@ impl<DB: Database, EF: ExecutorFactory> BlockchainTreeEngine for ShareableBlockc
         res
     }
 
+    fn update_chain_state(&self, update: ChainStateUpdate) {
+        trace!(target: "blockchain_tree", ?update, "Updating chain state");
+        self.tree.write().update_chain_state(update)
+    }
+
     fn unwind(&self, unwind_to: BlockNumber) -> RethResult<()> {
         trace!(target: "blockchain_tree", ?unwind_to, "Unwinding to block number");
         let mut tree = self.tree.write();
@@ -191,6 +191,9 @@ pub struct ExecutionConfig {
         deserialize_with = "deserialize_duration"
     )]
     pub max_duration: Option<Duration>,
+    /// Whether to commit the blocks executed before a block with a mismatching receipts root or
+    /// logs bloom, before reporting the block.
+    pub commit_valid_prefix: bool,
 }
 
 impl Default for ExecutionConfig {
@@ -202,6 +205,7 @@ impl Default for ExecutionConfig {
             max_cumulative_gas: Some(30_000_000 * 50_000),
             // 10 minutes
             max_duration: Some(Duration::from_secs(10 * 60)),
+            commit_valid_prefix: false,
         }
     }
 }
@@ -8,7 +8,7 @@ use reth_interfaces::p2p::{
     priority::Priority,
 };
 use reth_primitives::{
-    BlockBody, BlockHashOrNumber, Header, HeadersDirection, PeerId, WithPeerId, B256,
+    BlockHashOrNumber, Header, HeadersDirection, PeerId, RawBlockBody, WithPeerId, B256,
 };
 use std::fmt::Debug;
 use tracing::{trace, warn};
@@ -68,13 +68,13 @@ impl AutoSealClient {
         headers
     }
 
-    async fn fetch_bodies(&self, hashes: Vec<B256>) -> Vec<BlockBody> {
+    async fn fetch_bodies(&self, hashes: Vec<B256>) -> Vec<RawBlockBody> {
         trace!(target: "consensus::auto", ?hashes, "received bodies request");
         let storage = self.storage.read().await;
         let mut bodies = Vec::new();
         for hash in hashes {
             if let Some(body) = storage.bodies.get(&hash).cloned() {
-                bodies.push(body);
+                bodies.push(body.into());
             } else {
                 break
             }
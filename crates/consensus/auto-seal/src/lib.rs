@@ -22,9 +22,9 @@ use reth_interfaces::{
 use reth_node_api::{ConfigureEvm, EngineTypes};
 use reth_primitives::{
     constants::{EMPTY_RECEIPTS, EMPTY_TRANSACTIONS, ETHEREUM_BLOCK_GAS_LIMIT},
-    proofs, Block, BlockBody, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders, Bloom,
-    ChainSpec, Header, ReceiptWithBloom, SealedBlock, SealedHeader, TransactionSigned, B256,
-    EMPTY_OMMER_ROOT_HASH, U256,
+    keccak256, proofs, Block, BlockBody, BlockHash, BlockHashOrNumber, BlockNumber,
+    BlockWithSenders, Bloom, ChainSpec, Header, ReceiptWithBloom, SealedBlock, SealedHeader,
+    TransactionSigned, B256, EMPTY_OMMER_ROOT_HASH, U256,
 };
 use reth_provider::{
     BlockExecutor, BlockReaderIdExt, BundleStateWithReceipts, CanonStateNotificationSender,
@@ -103,6 +103,7 @@ pub struct AutoSealBuilder<Client, Pool, Engine: EngineTypes, EvmConfig> {
     to_engine: UnboundedSender<BeaconEngineMessage<Engine>>,
     canon_state_notification: CanonStateNotificationSender,
     evm_config: EvmConfig,
+    prevrandao_seed: u64,
 }
 
 // === impl AutoSealBuilder ===
@@ -138,6 +139,7 @@ where
             to_engine,
             canon_state_notification,
             evm_config,
+            prevrandao_seed: 0,
         }
     }
 
@@ -147,6 +149,14 @@ where
         self
     }
 
+    /// Sets the seed that is mixed into the `prevrandao` of every mined block, default is `0`.
+    ///
+    /// See [dev_prevrandao].
+    pub fn prevrandao_seed(mut self, seed: u64) -> Self {
+        self.prevrandao_seed = seed;
+        self
+    }
+
     /// Consumes the type and returns all components
     #[track_caller]
     pub fn build(
@@ -161,6 +171,7 @@ where
             to_engine,
             canon_state_notification,
             evm_config,
+            prevrandao_seed,
         } = self;
         let auto_client = AutoSealClient::new(storage.clone());
         let task = MiningTask::new(
@@ -172,11 +183,25 @@ where
             client,
             pool,
             evm_config,
+            prevrandao_seed,
         );
         (consensus, auto_client, task)
     }
 }
 
+/// Derives the `prevrandao` of a mined block from its parent hash and number, mixed with the given
+/// seed.
+///
+/// Blocks mined on the same chain with the same seed get the same `prevrandao`, which keeps runs
+/// of contracts that depend on it reproducible.
+pub fn dev_prevrandao(parent_hash: B256, number: BlockNumber, seed: u64) -> B256 {
+    let mut buf = [0u8; 48];
+    buf[..8].copy_from_slice(&seed.to_be_bytes());
+    buf[8..40].copy_from_slice(parent_hash.as_slice());
+    buf[40..].copy_from_slice(&number.to_be_bytes());
+    keccak256(buf)
+}
+
 /// In memory storage
 #[derive(Debug, Clone, Default)]
 pub(crate) struct Storage {
@@ -270,6 +295,7 @@ impl StorageInner {
         &self,
         transactions: &[TransactionSigned],
         chain_spec: Arc<ChainSpec>,
+        prevrandao_seed: u64,
     ) -> Header {
         let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
 
@@ -277,7 +303,7 @@ impl StorageInner {
         let base_fee_per_gas = self
             .headers
             .get(&self.best_block)
-            .and_then(|parent| parent.next_block_base_fee(chain_spec.base_fee_params(timestamp)));
+            .and_then(|parent| chain_spec.next_block_base_fee(parent, timestamp));
 
         let mut header = Header {
             parent_hash: self.best_hash,
@@ -293,7 +319,7 @@ impl StorageInner {
             gas_limit: ETHEREUM_BLOCK_GAS_LIMIT,
             gas_used: 0,
             timestamp,
-            mix_hash: Default::default(),
+            mix_hash: dev_prevrandao(self.best_hash, self.best_block + 1, prevrandao_seed),
             nonce: 0,
             base_fee_per_gas,
             blob_gas_used: None,
@@ -432,11 +458,12 @@ impl StorageInner {
         client: &impl StateProviderFactory,
         chain_spec: Arc<ChainSpec>,
         evm_config: EvmConfig,
+        prevrandao_seed: u64,
     ) -> Result<(SealedHeader, BundleStateWithReceipts), BlockExecutionError>
     where
         EvmConfig: ConfigureEvm,
     {
-        let header = self.build_header_template(&transactions, chain_spec.clone());
+        let header = self.build_header_template(&transactions, chain_spec.clone(), prevrandao_seed);
 
         let block = Block { header, body: transactions, ommers: vec![], withdrawals: None }
             .with_recovered_senders()
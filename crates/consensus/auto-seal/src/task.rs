@@ -42,6 +42,8 @@ pub struct MiningTask<Client, Pool: TransactionPool, EvmConfig, Engine: EngineTy
     pipe_line_events: Option<UnboundedReceiverStream<PipelineEvent>>,
     /// The type that defines how to configure the EVM.
     evm_config: EvmConfig,
+    /// The seed mixed into the `prevrandao` of mined blocks.
+    prevrandao_seed: u64,
 }
 
 // === impl MiningTask ===
@@ -60,6 +62,7 @@ impl<EvmConfig, Client, Pool: TransactionPool, Engine: EngineTypes>
         client: Client,
         pool: Pool,
         evm_config: EvmConfig,
+        prevrandao_seed: u64,
     ) -> Self {
         Self {
             chain_spec,
@@ -73,6 +76,7 @@ impl<EvmConfig, Client, Pool: TransactionPool, Engine: EngineTypes>
             queued: Default::default(),
             pipe_line_events: None,
             evm_config,
+            prevrandao_seed,
         }
     }
 
@@ -119,6 +123,7 @@ where
                 let events = this.pipe_line_events.take();
                 let canon_state_notification = this.canon_state_notification.clone();
                 let evm_config = this.evm_config.clone();
+                let prevrandao_seed = this.prevrandao_seed;
 
                 // Create the mining future that creates a block, notifies the engine that drives
                 // the pipeline
@@ -139,6 +144,7 @@ where
                         &client,
                         chain_spec,
                         evm_config,
+                        prevrandao_seed,
                     ) {
                         Ok((new_header, bundle_state)) => {
                             // clear all transactions from pool
@@ -8,11 +8,11 @@ use crate::{
     sync::{EngineSyncController, EngineSyncEvent},
 };
 use futures::{Future, StreamExt};
-use reth_db::database::Database;
+use reth_db::{database::Database, models::StoredParisBlock};
 use reth_interfaces::{
     blockchain_tree::{
         error::{BlockchainTreeError, CanonicalError, InsertBlockError, InsertBlockErrorKind},
-        BlockStatus, BlockchainTreeEngine, CanonicalOutcome, InsertPayloadOk,
+        BlockStatus, BlockchainTreeEngine, CanonicalOutcome, ChainStateUpdate, InsertPayloadOk,
     },
     consensus::ForkchoiceState,
     executor::{BlockExecutionError, BlockValidationError},
@@ -24,11 +24,11 @@ use reth_node_api::{EngineTypes, PayloadAttributes, PayloadBuilderAttributes};
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_primitives::{
     constants::EPOCH_SLOTS, stage::StageId, BlockNumHash, BlockNumber, Head, Header, SealedBlock,
-    SealedHeader, B256,
+    SealedHeader, B256, U256,
 };
 use reth_provider::{
-    BlockIdReader, BlockReader, BlockSource, CanonChainTracker, ChainSpecProvider, ProviderError,
-    StageCheckpointReader,
+    BlockIdReader, BlockReader, BlockSource, CanonChainTracker, ChainSpecProvider,
+    ChainStateReader, ChainStateWriter, ProviderError, StageCheckpointReader,
 };
 use reth_rpc_types::engine::{
     CancunPayloadFields, ExecutionPayload, PayloadStatus, PayloadStatusEnum, PayloadValidationError,
@@ -171,7 +171,9 @@ where
         + BlockReader
         + BlockIdReader
         + CanonChainTracker
-        + StageCheckpointReader,
+        + StageCheckpointReader
+        + ChainStateReader
+        + ChainStateWriter,
     EngineT: EngineTypes,
 {
     /// Controls syncing triggered by engine updates.
@@ -220,6 +222,8 @@ where
         + BlockIdReader
         + CanonChainTracker
         + StageCheckpointReader
+        + ChainStateReader
+        + ChainStateWriter
         + ChainSpecProvider
         + 'static,
     Client: HeadersClient + BodiesClient + Clone + Unpin + 'static,
@@ -311,6 +315,11 @@ where
             hooks: EngineHooksController::new(hooks),
         };
 
+        // restore the invalid headers that were persisted before the last shutdown
+        for header in this.blockchain.invalid_block_headers()? {
+            this.invalid_headers.insert(header);
+        }
+
         let maybe_pipeline_target = match target {
             // Provided target always takes precedence.
             target @ Some(_) => target,
@@ -782,6 +791,16 @@ where
         Some(status)
     }
 
+    /// Marks the given header as invalid and persists it with the next canonical chain, so it's
+    /// still known to be invalid after a restart.
+    fn insert_invalid_header(&mut self, header: SealedHeader) {
+        self.blockchain.update_chain_state(ChainStateUpdate {
+            invalid_headers: vec![header.clone()],
+            ..Default::default()
+        });
+        self.invalid_headers.insert(header);
+    }
+
     /// Record latency metrics for one call to make a block canonical
     /// Takes start time of the call and result of the make canonical call
     ///
@@ -939,6 +958,10 @@ where
                 self.blockchain.find_block_by_hash(safe_block_hash, BlockSource::Any)?.ok_or_else(
                     || RethError::Provider(ProviderError::UnknownBlockHash(safe_block_hash)),
                 )?;
+            self.blockchain.update_chain_state(ChainStateUpdate {
+                safe: Some(safe_block_hash),
+                ..Default::default()
+            });
             self.blockchain.set_safe(safe.header.seal(safe_block_hash));
         }
         Ok(())
@@ -962,8 +985,61 @@ where
                     RethError::Provider(ProviderError::UnknownBlockHash(finalized_block_hash))
                 })?;
             self.blockchain.finalize_block(finalized.number);
+            self.blockchain.update_chain_state(ChainStateUpdate {
+                finalized: Some(finalized_block_hash),
+                ..Default::default()
+            });
             self.blockchain.set_finalized(finalized.header.seal(finalized_block_hash));
+            self.record_paris_block(finalized.number)?;
+        }
+        Ok(())
+    }
+
+    /// Records the Paris block once it is finalized, if the chain spec doesn't know it.
+    ///
+    /// The terminal proof-of-work block is the first block whose total difficulty reaches the
+    /// terminal total difficulty, and the Paris block is its child. A genesis block that reaches
+    /// it is the Paris block itself. The recorded block is applied to the chain spec on subsequent
+    /// runs.
+    fn record_paris_block(&self, finalized: BlockNumber) -> RethResult<()> {
+        let chain_spec = self.blockchain.chain_spec();
+        if chain_spec.paris_block_and_final_difficulty.is_some() {
+            return Ok(())
+        }
+        let Some(ttd) = chain_spec.terminal_total_difficulty() else { return Ok(()) };
+        let genesis_hash = chain_spec.genesis_hash();
+        if self.blockchain.paris_block(genesis_hash)?.is_some() {
+            return Ok(())
+        }
+
+        let td = |number: BlockNumber| -> RethResult<U256> {
+            self.blockchain
+                .header_td_by_number(number)?
+                .ok_or_else(|| RethError::Provider(ProviderError::TotalDifficultyNotFound(number)))
+        };
+        if td(finalized)? < ttd {
+            return Ok(())
         }
+
+        // binary search for the first block that reached the terminal total difficulty
+        let (mut low, mut high) = (0, finalized);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if td(mid)? >= ttd {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        let block = if low == 0 { 0 } else { low + 1 };
+        if block > finalized {
+            // the terminal block is finalized, but the Paris block is not yet
+            return Ok(())
+        }
+
+        let paris = StoredParisBlock { block, final_difficulty: td(low)? };
+        info!(target: "consensus::engine", block, final_difficulty = %paris.final_difficulty, "Recording the observed Paris block");
+        self.blockchain.save_paris_block(genesis_hash, paris)?;
         Ok(())
     }
 
@@ -1328,7 +1404,7 @@ where
             let parent_hash = block.parent_hash;
 
             // keep track of the invalid header
-            self.invalid_headers.insert(block.header);
+            self.insert_invalid_header(block.header);
 
             let latest_valid_hash =
                 self.latest_valid_hash_for_invalid_payload(parent_hash, Some(&error));
@@ -1417,7 +1493,7 @@ where
                     let (block, err) = err.split();
                     warn!(target: "consensus::engine", invalid_number=?block.number, invalid_hash=?block.hash(), %err, "Marking block as invalid");
 
-                    self.invalid_headers.insert(block.header);
+                    self.insert_invalid_header(block.header);
                 }
             }
         }
@@ -1590,7 +1666,7 @@ where
                     warn!(target: "consensus::engine", invalid_hash=?bad_block.hash(), invalid_number=?bad_block.number, "Bad block detected in unwind");
 
                     // update the `invalid_headers` cache with the new invalid headers
-                    self.invalid_headers.insert(*bad_block);
+                    self.insert_invalid_header(*bad_block);
                     return None
                 }
 
@@ -1751,6 +1827,8 @@ where
         + BlockIdReader
         + CanonChainTracker
         + StageCheckpointReader
+        + ChainStateReader
+        + ChainStateWriter
         + ChainSpecProvider
         + Unpin
         + 'static,
@@ -426,7 +426,7 @@ where
             provider_factory,
             vec![],
             5,
-            self.base_config.chain_spec.prune_delete_limit,
+            self.base_config.chain_spec.prune_config.clone(),
             config.max_reorg_depth() as usize,
             watch::channel(None).1,
         );
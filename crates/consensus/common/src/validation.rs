@@ -2,13 +2,15 @@
 
 use reth_interfaces::{consensus::ConsensusError, RethResult};
 use reth_primitives::{
-    constants::eip4844::{DATA_GAS_PER_BLOB, MAX_DATA_GAS_PER_BLOCK},
-    BlockNumber, ChainSpec, GotExpected, Hardfork, Header, InvalidTransactionError, SealedBlock,
-    SealedHeader, Transaction, TransactionSignedEcRecovered, TxEip1559, TxEip2930, TxEip4844,
-    TxLegacy,
+    constants::eip4844::DATA_GAS_PER_BLOB, BlobParams, BlockNumber, ChainSpec, GotExpected,
+    Hardfork, Header, InvalidTransactionError, SealedBlock, SealedHeader, Transaction,
+    TransactionSignedEcRecovered, TxEip1559, TxEip2930, TxEip4844, TxLegacy,
 };
 use reth_provider::{AccountReader, HeaderProvider, WithdrawalsProvider};
-use std::collections::{hash_map::Entry, HashMap};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 /// Validate header standalone
 pub fn validate_header_standalone(
@@ -23,6 +25,17 @@ pub fn validate_header_standalone(
         })
     }
 
+    // Check if timestamp is in future. Clock can drift but this can be consensus issue.
+    let present_timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    if header
+        .exceeds_allowed_future_timestamp(present_timestamp, chain_spec.max_future_timestamp_drift)
+    {
+        return Err(ConsensusError::TimestampIsInFuture {
+            timestamp: header.timestamp,
+            present_timestamp,
+        })
+    }
+
     // Check if base fee is set.
     if chain_spec.fork(Hardfork::London).active_at_block(header.number) &&
         header.base_fee_per_gas.is_none()
@@ -44,7 +57,10 @@ pub fn validate_header_standalone(
 
     // Ensures that EIP-4844 fields are valid once cancun is active.
     if chain_spec.fork(Hardfork::Cancun).active_at_timestamp(header.timestamp) {
-        validate_4844_header_standalone(header)?;
+        validate_4844_header_standalone(
+            header,
+            chain_spec.blob_params_at_timestamp(header.timestamp),
+        )?;
     } else if header.blob_gas_used.is_some() {
         return Err(ConsensusError::BlobGasUsedUnexpected)
     } else if header.excess_blob_gas.is_some() {
@@ -195,13 +211,19 @@ pub fn validate_all_transaction_regarding_block_and_nonces<
     Ok(())
 }
 
-/// Validate a block without regard for state:
+/// Validate a block without regard for state, checking that the block body matches its header:
 ///
 /// - Compares the ommer hash in the block header to the block body
 /// - Compares the transactions root in the block header to the block body
-/// - Pre-execution transaction validation
-/// - (Optionally) Compares the receipts root in the block header to the block body
-pub fn validate_block_standalone(
+/// - Compares the withdrawals root in the block header to the block body, post-Shanghai
+/// - Compares the blob gas used in the block header to the blob transactions in the block body and
+///   ensures it does not exceed the per-block limit, post-Cancun
+/// - Ensures no transaction exceeds the maximum transaction size of the chain, and no contract
+///   creation exceeds the maximum init code size, post-Shanghai
+///
+/// This is shared by every path that assembles a block from a header and a separately obtained
+/// body, e.g. downloaded bodies, engine API payloads and imported blocks.
+pub fn validate_block_pre_execution(
     block: &SealedBlock,
     chain_spec: &ChainSpec,
 ) -> Result<(), ConsensusError> {
@@ -218,8 +240,10 @@ pub fn validate_block_standalone(
         return Err(ConsensusError::BodyTransactionRootDiff(error.into()))
     }
 
+    validate_transaction_sizes(block, chain_spec)?;
+
     // EIP-4895: Beacon chain push withdrawals as operations
-    if chain_spec.fork(Hardfork::Shanghai).active_at_timestamp(block.timestamp) {
+    if chain_spec.is_shanghai_active_at_timestamp(block.timestamp) {
         let withdrawals =
             block.withdrawals.as_ref().ok_or(ConsensusError::BodyWithdrawalsMissing)?;
         let withdrawals_root = reth_primitives::proofs::calculate_withdrawals_root(withdrawals);
@@ -230,6 +254,8 @@ pub fn validate_block_standalone(
                 GotExpected { got: withdrawals_root, expected: *header_withdrawals_root }.into(),
             ))
         }
+    } else if block.withdrawals.as_ref().is_some_and(|withdrawals| !withdrawals.is_empty()) {
+        return Err(ConsensusError::BodyWithdrawalsUnexpected)
     }
 
     // EIP-4844: Shard Blob Transactions
@@ -244,6 +270,62 @@ pub fn validate_block_standalone(
                 expected: total_blob_gas,
             }))
         }
+
+        // Check that the block does not carry more blobs than allowed per block
+        let max_blob_gas_per_block =
+            chain_spec.blob_params_at_timestamp(block.timestamp).max_blob_gas_per_block();
+        if total_blob_gas > max_blob_gas_per_block {
+            return Err(ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock {
+                blob_gas_used: total_blob_gas,
+                max_blob_gas_per_block,
+            })
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate a block without regard for state, see [validate_block_pre_execution].
+#[deprecated(note = "use `validate_block_pre_execution` instead")]
+pub fn validate_block_standalone(
+    block: &SealedBlock,
+    chain_spec: &ChainSpec,
+) -> Result<(), ConsensusError> {
+    validate_block_pre_execution(block, chain_spec)
+}
+
+/// Validates the transactions of the block against the size limits of the chain:
+///
+/// - The encoded size of every transaction against [ChainSpec::max_tx_size], if set
+/// - The init code of every contract creation against [ChainSpec::max_init_code_size], post-Shanghai
+///   as per [EIP-3860](https://eips.ethereum.org/EIPS/eip-3860)
+pub fn validate_transaction_sizes(
+    block: &SealedBlock,
+    chain_spec: &ChainSpec,
+) -> Result<(), ConsensusError> {
+    let is_shanghai = chain_spec.is_shanghai_active_at_timestamp(block.timestamp);
+    for transaction in block.body.iter() {
+        if let Some(max_tx_size) = chain_spec.max_tx_size {
+            let size = transaction.length_without_header();
+            if size > max_tx_size {
+                return Err(ConsensusError::TransactionSizeExceeded {
+                    hash: transaction.hash(),
+                    size,
+                    max_tx_size,
+                })
+            }
+        }
+
+        if is_shanghai && transaction.kind().is_create() {
+            let size = transaction.input().len();
+            if size > chain_spec.max_init_code_size {
+                return Err(ConsensusError::TransactionInitCodeSizeExceeded {
+                    hash: transaction.hash(),
+                    size,
+                    max_init_code_size: chain_spec.max_init_code_size,
+                })
+            }
+        }
     }
 
     Ok(())
@@ -281,9 +363,13 @@ pub fn validate_block_regarding_chain<PROV: HeaderProvider + WithdrawalsProvider
 ///  * `blob_gas_used` exists as a header field
 ///  * `excess_blob_gas` exists as a header field
 ///  * `parent_beacon_block_root` exists as a header field
-///  * `blob_gas_used` is less than or equal to `MAX_DATA_GAS_PER_BLOCK`
+///  * `blob_gas_used` is less than or equal to the maximum blob gas per block of the given blob
+///    params
 ///  * `blob_gas_used` is a multiple of `DATA_GAS_PER_BLOB`
-pub fn validate_4844_header_standalone(header: &SealedHeader) -> Result<(), ConsensusError> {
+pub fn validate_4844_header_standalone(
+    header: &SealedHeader,
+    blob_params: BlobParams,
+) -> Result<(), ConsensusError> {
     let blob_gas_used = header.blob_gas_used.ok_or(ConsensusError::BlobGasUsedMissing)?;
 
     if header.excess_blob_gas.is_none() {
@@ -294,10 +380,11 @@ pub fn validate_4844_header_standalone(header: &SealedHeader) -> Result<(), Cons
         return Err(ConsensusError::ParentBeaconBlockRootMissing)
     }
 
-    if blob_gas_used > MAX_DATA_GAS_PER_BLOCK {
+    let max_blob_gas_per_block = blob_params.max_blob_gas_per_block();
+    if blob_gas_used > max_blob_gas_per_block {
         return Err(ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock {
             blob_gas_used,
-            max_blob_gas_per_block: MAX_DATA_GAS_PER_BLOCK,
+            max_blob_gas_per_block,
         })
     }
 
@@ -321,8 +408,8 @@ mod tests {
     };
     use reth_primitives::{
         hex_literal::hex, proofs, Account, Address, BlockBody, BlockHash, BlockHashOrNumber, Bytes,
-        ChainSpecBuilder, Signature, TransactionKind, TransactionSigned, Withdrawal, Withdrawals,
-        MAINNET, U256,
+        Chain, ChainSpecBuilder, Genesis, Signature, TransactionKind, TransactionSigned,
+        Withdrawal, Withdrawals, MAINNET, U256,
     };
     use std::ops::RangeBounds;
 
@@ -592,13 +679,13 @@ mod tests {
 
         // Single withdrawal
         let block = create_block_with_withdrawals(&[1]);
-        assert_eq!(validate_block_standalone(&block, &chain_spec), Ok(()));
+        assert_eq!(validate_block_pre_execution(&block, &chain_spec), Ok(()));
 
         // Multiple increasing withdrawals
         let block = create_block_with_withdrawals(&[1, 2, 3]);
-        assert_eq!(validate_block_standalone(&block, &chain_spec), Ok(()));
+        assert_eq!(validate_block_pre_execution(&block, &chain_spec), Ok(()));
         let block = create_block_with_withdrawals(&[5, 6, 7, 8, 9]);
-        assert_eq!(validate_block_standalone(&block, &chain_spec), Ok(()));
+        assert_eq!(validate_block_pre_execution(&block, &chain_spec), Ok(()));
 
         let (_, parent) = mock_block();
         let provider = Provider::new(Some(parent.clone()));
@@ -617,6 +704,42 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn pre_shanghai_block_with_withdrawals() {
+        let chain_spec = ChainSpecBuilder::mainnet().paris_activated().build();
+
+        let withdrawals = Withdrawals::new(vec![Withdrawal { index: 1, ..Default::default() }]);
+        let block = SealedBlock {
+            header: Header::default().seal_slow(),
+            withdrawals: Some(withdrawals),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            validate_block_pre_execution(&block, &chain_spec),
+            Err(ConsensusError::BodyWithdrawalsUnexpected)
+        );
+    }
+
+    #[test]
+    fn header_timestamp_drift() {
+        let present_timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let header = Header { timestamp: present_timestamp + 60, ..Default::default() }.seal_slow();
+
+        let chain_spec = ChainSpecBuilder::mainnet().build();
+        assert!(matches!(
+            validate_header_standalone(&header, &chain_spec),
+            Err(ConsensusError::TimestampIsInFuture { .. })
+        ));
+
+        let chain_spec = ChainSpecBuilder::default()
+            .chain(Chain::dev())
+            .genesis(Genesis::default())
+            .max_future_timestamp_drift(120)
+            .build();
+        assert_eq!(validate_header_standalone(&header, &chain_spec), Ok(()));
+    }
+
     #[test]
     fn shanghai_block_zero_withdrawals() {
         // ensures that if shanghai is activated, and we include a block with a withdrawals root,
@@ -633,6 +756,62 @@ mod tests {
         assert_eq!(validate_header_standalone(&header, &chain_spec), Ok(()));
     }
 
+    #[test]
+    fn block_exceeds_transaction_size_limits() {
+        let create_tx = |init_code_size: usize| {
+            let request = Transaction::Eip2930(TxEip2930 {
+                chain_id: 1u64,
+                nonce: 0,
+                gas_price: 0x28f000fff,
+                gas_limit: 10,
+                to: TransactionKind::Create,
+                value: Default::default(),
+                input: Bytes::from(vec![1; init_code_size]),
+                access_list: Default::default(),
+            });
+            let signature =
+                Signature { odd_y_parity: true, r: U256::default(), s: U256::default() };
+            TransactionSigned::from_transaction_and_signature(request, signature)
+        };
+        let block = |transaction: TransactionSigned| {
+            let body = BlockBody {
+                transactions: vec![transaction],
+                ommers: vec![],
+                withdrawals: Some(Withdrawals::default()),
+            };
+            SealedBlock::new(Header::default().seal_slow(), body)
+        };
+
+        let chain_spec =
+            ChainSpecBuilder::mainnet().shanghai_activated().max_init_code_size(100).build();
+        assert_eq!(validate_transaction_sizes(&block(create_tx(100)), &chain_spec), Ok(()));
+        let transaction = create_tx(101);
+        assert_eq!(
+            validate_transaction_sizes(&block(transaction.clone()), &chain_spec),
+            Err(ConsensusError::TransactionInitCodeSizeExceeded {
+                hash: transaction.hash(),
+                size: 101,
+                max_init_code_size: 100,
+            })
+        );
+
+        // the init code limit only applies post-Shanghai, which mainnet activates after the
+        // timestamp of the block
+        let chain_spec = ChainSpecBuilder::mainnet().max_init_code_size(100).build();
+        assert_eq!(validate_transaction_sizes(&block(transaction.clone()), &chain_spec), Ok(()));
+
+        let chain_spec = ChainSpecBuilder::mainnet().max_tx_size(100).build();
+        let size = transaction.length_without_header();
+        assert_eq!(
+            validate_transaction_sizes(&block(transaction.clone()), &chain_spec),
+            Err(ConsensusError::TransactionSizeExceeded {
+                hash: transaction.hash(),
+                size,
+                max_tx_size: 100,
+            })
+        );
+    }
+
     #[test]
     fn cancun_block_incorrect_blob_gas_used() {
         let chain_spec = ChainSpecBuilder::mainnet().cancun_activated().build();
@@ -662,11 +841,72 @@ mod tests {
 
         // validate blob, it should fail blob gas used validation
         assert_eq!(
-            validate_block_standalone(&block, &chain_spec),
+            validate_block_pre_execution(&block, &chain_spec),
             Err(ConsensusError::BlobGasUsedDiff(GotExpected {
                 got: 1,
                 expected: expected_blob_gas_used
             }))
         );
     }
+
+    #[test]
+    fn cancun_block_exceeds_max_blob_gas_per_block() {
+        let chain_spec = ChainSpecBuilder::mainnet().cancun_activated().build();
+
+        // create a tx with 10 blobs, more than allowed in a single block
+        let transaction = mock_blob_tx(1, 10);
+        let blob_gas_used = 10 * DATA_GAS_PER_BLOB;
+
+        let header = Header {
+            base_fee_per_gas: Some(1337u64),
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(&[])),
+            blob_gas_used: Some(blob_gas_used),
+            transactions_root: proofs::calculate_transaction_root(&[transaction.clone()]),
+            ..Default::default()
+        }
+        .seal_slow();
+
+        let body = BlockBody {
+            transactions: vec![transaction],
+            ommers: vec![],
+            withdrawals: Some(Withdrawals::default()),
+        };
+
+        let block = SealedBlock::new(header, body);
+
+        assert_eq!(
+            validate_block_pre_execution(&block, &chain_spec),
+            Err(ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock {
+                blob_gas_used,
+                max_blob_gas_per_block: BlobParams::cancun().max_blob_gas_per_block(),
+            })
+        );
+    }
+
+    #[test]
+    fn cancun_block_within_blob_params_of_chain() {
+        let mut chain_spec = ChainSpecBuilder::mainnet().cancun_activated().build();
+        chain_spec.blob_params = BlobParams::prague().into();
+
+        // more blobs than Cancun allows, but within the blob params of the chain
+        let transaction = mock_blob_tx(1, 9);
+        let header = Header {
+            base_fee_per_gas: Some(1337u64),
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(&[])),
+            blob_gas_used: Some(9 * DATA_GAS_PER_BLOB),
+            transactions_root: proofs::calculate_transaction_root(&[transaction.clone()]),
+            ..Default::default()
+        }
+        .seal_slow();
+
+        let body = BlockBody {
+            transactions: vec![transaction],
+            ommers: vec![],
+            withdrawals: Some(Withdrawals::default()),
+        };
+
+        let block = SealedBlock::new(header, body);
+
+        assert_eq!(validate_block_pre_execution(&block, &chain_spec), Ok(()));
+    }
 }
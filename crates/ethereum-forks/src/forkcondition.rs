@@ -0,0 +1,372 @@
+use crate::Head;
+use alloy_primitives::{BlockNumber, U256};
+#[cfg(any(test, feature = "arbitrary"))]
+use arbitrary::Arbitrary;
+#[cfg(feature = "compact")]
+use bytes::{Buf, BufMut};
+#[cfg(any(test, feature = "arbitrary"))]
+use proptest_derive::Arbitrary as PropTestArbitrary;
+#[cfg(feature = "compact")]
+use reth_codecs::Compact;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The condition at which a fork is activated.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(PropTestArbitrary, Arbitrary))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ForkCondition {
+    /// The fork is activated after a certain block.
+    Block(BlockNumber),
+    /// The fork is activated at the first block of a certain epoch.
+    ///
+    /// This is used by PoA chains (e.g. Clique) that schedule changes at epoch boundaries, the
+    /// condition resolves to the block number `epoch_length * epoch`.
+    Epoch {
+        /// The number of blocks in an epoch.
+        epoch_length: u64,
+        /// The epoch at which the fork is activated.
+        epoch: u64,
+    },
+    /// The fork is activated after a total difficulty has been reached.
+    TTD {
+        /// The block number at which TTD is reached, if it is known.
+        ///
+        /// This should **NOT** be set unless you want this block advertised as [EIP-2124][eip2124]
+        /// `FORK_NEXT`. This is currently only the case for Sepolia and Holesky.
+        ///
+        /// [eip2124]: https://eips.ethereum.org/EIPS/eip-2124
+        fork_block: Option<BlockNumber>,
+        /// The total difficulty after which the fork is activated.
+        total_difficulty: U256,
+    },
+    /// The fork is activated after a specific timestamp.
+    Timestamp(u64),
+    /// The fork is activated once both a certain block and a specific timestamp are reached.
+    ///
+    /// The fork is hashed into the [EIP-2124][eip2124] fork id by its timestamp. It is advertised
+    /// as `FORK_NEXT` by its timestamp, or by its block once the timestamp passed and the fork
+    /// only waits for the block.
+    ///
+    /// [eip2124]: https://eips.ethereum.org/EIPS/eip-2124
+    BlockAndTimestamp {
+        /// The block number after which the fork can be activated.
+        block: BlockNumber,
+        /// The timestamp after which the fork can be activated.
+        timestamp: u64,
+    },
+    /// The fork is never activated
+    #[default]
+    Never,
+}
+
+impl ForkCondition {
+    /// Returns true if the fork condition is timestamp based.
+    pub fn is_timestamp(&self) -> bool {
+        matches!(self, ForkCondition::Timestamp(_))
+    }
+
+    /// Checks whether the fork condition is satisfied at the given block.
+    ///
+    /// For TTD conditions, this will only return true if the activation block is already known.
+    ///
+    /// For timestamp and block and timestamp conditions, this will always return false.
+    pub fn active_at_block(&self, current_block: BlockNumber) -> bool {
+        let block = match self {
+            ForkCondition::TTD { fork_block, .. } => *fork_block,
+            _ => self.as_block(),
+        };
+        block.is_some_and(|block| current_block >= block)
+    }
+
+    /// Checks if the given block is the first block that satisfies the fork condition.
+    ///
+    /// This will return false for any condition that is not block or epoch based.
+    pub fn transitions_at_block(&self, current_block: BlockNumber) -> bool {
+        self.as_block() == Some(current_block)
+    }
+
+    /// Checks if a block with the given timestamp is the first block that satisfies the fork
+    /// condition, given the timestamp of its parent.
+    ///
+    /// This will return false for any condition that is not timestamp based.
+    pub fn transitions_at_timestamp(&self, timestamp: u64, parent_timestamp: u64) -> bool {
+        matches!(
+            self,
+            ForkCondition::Timestamp(time) if timestamp >= *time && parent_timestamp < *time
+        )
+    }
+
+    /// Checks if the given block is the first block that satisfies the fork condition, given its
+    /// timestamp and the timestamp of its parent.
+    ///
+    /// This will return false for TTD based conditions.
+    pub fn transitions_at_block_and_timestamp(
+        &self,
+        block_number: BlockNumber,
+        timestamp: u64,
+        parent_timestamp: u64,
+    ) -> bool {
+        match self {
+            ForkCondition::BlockAndTimestamp { .. } => {
+                self.active_at_block_and_timestamp(block_number, timestamp) &&
+                    (block_number == 0 ||
+                        !self.active_at_block_and_timestamp(
+                            block_number - 1,
+                            parent_timestamp,
+                        ))
+            }
+            _ => {
+                self.transitions_at_block(block_number) ||
+                    self.transitions_at_timestamp(timestamp, parent_timestamp)
+            }
+        }
+    }
+
+    /// Checks whether the fork condition is satisfied at the given total difficulty and difficulty
+    /// of a current block.
+    ///
+    /// The fork is considered active if the _previous_ total difficulty is above the threshold.
+    /// To achieve that, we subtract the passed `difficulty` from the current block's total
+    /// difficulty, and check if it's above the Fork Condition's total difficulty (here:
+    /// 58_750_000_000_000_000_000_000)
+    ///
+    /// This will return false for any condition that is not TTD-based.
+    pub fn active_at_ttd(&self, ttd: U256, difficulty: U256) -> bool {
+        matches!(self, ForkCondition::TTD { total_difficulty, .. }
+            if ttd.saturating_sub(difficulty) >= *total_difficulty)
+    }
+
+    /// Checks whether the fork condition is satisfied at the given timestamp.
+    ///
+    /// This will return false for any condition that is not timestamp-based, including block and
+    /// timestamp based conditions, see [ForkCondition::active_at_block_and_timestamp].
+    pub fn active_at_timestamp(&self, timestamp: u64) -> bool {
+        matches!(self, ForkCondition::Timestamp(time) if timestamp >= *time)
+    }
+
+    /// Checks whether the fork condition is satisfied at the given block and timestamp.
+    ///
+    /// This will return false for any condition that is not block, timestamp or block and
+    /// timestamp based.
+    pub fn active_at_block_and_timestamp(&self, block_number: BlockNumber, timestamp: u64) -> bool {
+        match self {
+            ForkCondition::BlockAndTimestamp { block, timestamp: time } => {
+                block_number >= *block && timestamp >= *time
+            }
+            _ => self.active_at_block(block_number) || self.active_at_timestamp(timestamp),
+        }
+    }
+
+    /// Checks whether the fork condition is satisfied at the given head block.
+    ///
+    /// This will return true if:
+    ///
+    /// - The condition is satisfied by the block number;
+    /// - The condition is satisfied by the timestamp;
+    /// - The condition is satisfied by both the block number and the timestamp;
+    /// - or the condition is satisfied by the total difficulty
+    pub fn active_at_head(&self, head: &Head) -> bool {
+        self.active_at_block_and_timestamp(head.number, head.timestamp) ||
+            self.active_at_ttd(head.total_difficulty, head.difficulty)
+    }
+
+    /// Get the total terminal difficulty for this fork condition.
+    ///
+    /// Returns `None` for fork conditions that are not TTD based.
+    pub fn ttd(&self) -> Option<U256> {
+        match self {
+            ForkCondition::TTD { total_difficulty, .. } => Some(*total_difficulty),
+            _ => None,
+        }
+    }
+
+    /// Returns the block number of the fork condition, if it is block or epoch based.
+    pub fn as_block(&self) -> Option<BlockNumber> {
+        match self {
+            ForkCondition::Block(block) => Some(*block),
+            ForkCondition::Epoch { epoch_length, epoch } => {
+                Some(epoch_length.saturating_mul(*epoch))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the timestamp of the fork condition, if it is timestamp based.
+    pub fn as_timestamp(&self) -> Option<u64> {
+        match self {
+            ForkCondition::Timestamp(timestamp) => Some(*timestamp),
+            _ => None,
+        }
+    }
+
+    /// Returns the timestamp by which this fork condition is hashed into the fork id.
+    ///
+    /// This is the timestamp of timestamp based and block and timestamp based conditions.
+    pub fn fork_id_timestamp(&self) -> Option<u64> {
+        match self {
+            ForkCondition::Timestamp(timestamp) |
+            ForkCondition::BlockAndTimestamp { timestamp, .. } => Some(*timestamp),
+            _ => None,
+        }
+    }
+}
+
+// The variants are encoded as a one byte tag followed by their fields in big endian, fixed size
+// encoding, so a fork schedule can be stored without a length prefix per condition.
+#[cfg(feature = "compact")]
+impl Compact for ForkCondition {
+    fn to_compact<B>(self, buf: &mut B) -> usize
+    where
+        B: BufMut + AsMut<[u8]>,
+    {
+        match self {
+            ForkCondition::Block(block) => {
+                buf.put_u8(0);
+                buf.put_u64(block);
+                9
+            }
+            ForkCondition::Epoch { epoch_length, epoch } => {
+                buf.put_u8(1);
+                buf.put_u64(epoch_length);
+                buf.put_u64(epoch);
+                17
+            }
+            ForkCondition::TTD { fork_block, total_difficulty } => {
+                buf.put_u8(2);
+                buf.put_slice(&total_difficulty.to_be_bytes::<32>());
+                match fork_block {
+                    Some(block) => {
+                        buf.put_u8(1);
+                        buf.put_u64(block);
+                        42
+                    }
+                    None => {
+                        buf.put_u8(0);
+                        34
+                    }
+                }
+            }
+            ForkCondition::Timestamp(timestamp) => {
+                buf.put_u8(3);
+                buf.put_u64(timestamp);
+                9
+            }
+            ForkCondition::BlockAndTimestamp { block, timestamp } => {
+                buf.put_u8(4);
+                buf.put_u64(block);
+                buf.put_u64(timestamp);
+                17
+            }
+            ForkCondition::Never => {
+                buf.put_u8(5);
+                1
+            }
+        }
+    }
+
+    fn from_compact(mut buf: &[u8], _len: usize) -> (Self, &[u8]) {
+        let Some((&tag, fields)) = buf.split_first() else {
+            panic!("Junk data in database: missing ForkCondition variant")
+        };
+        let fields_len = match tag {
+            0 | 3 => 8,
+            1 | 4 => 16,
+            2 if fields.get(32) == Some(&1) => 41,
+            2 => 33,
+            5 => 0,
+            _ => panic!("Junk data in database: unknown ForkCondition variant {tag}"),
+        };
+        assert!(
+            fields.len() >= fields_len,
+            "Junk data in database: truncated ForkCondition variant {tag}"
+        );
+
+        buf.advance(1);
+        let condition = match tag {
+            0 => ForkCondition::Block(buf.get_u64()),
+            1 => ForkCondition::Epoch { epoch_length: buf.get_u64(), epoch: buf.get_u64() },
+            2 => {
+                let total_difficulty = U256::from_be_slice(&buf[..32]);
+                buf.advance(32);
+                let fork_block = (buf.get_u8() == 1).then(|| buf.get_u64());
+                ForkCondition::TTD { fork_block, total_difficulty }
+            }
+            3 => ForkCondition::Timestamp(buf.get_u64()),
+            4 => {
+                ForkCondition::BlockAndTimestamp { block: buf.get_u64(), timestamp: buf.get_u64() }
+            }
+            _ => ForkCondition::Never,
+        };
+        (condition, buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fork_condition_activation() {
+        let epoch = ForkCondition::Epoch { epoch_length: 30000, epoch: 2 };
+        assert_eq!(epoch.as_block(), Some(60000));
+        assert!(epoch.transitions_at_block(60000));
+        assert!(!epoch.active_at_block(59999));
+
+        let both = ForkCondition::BlockAndTimestamp { block: 10, timestamp: 1000 };
+        assert_eq!(both.fork_id_timestamp(), Some(1000));
+        assert!(!both.active_at_block_and_timestamp(10, 999));
+        assert!(both.active_at_head(&Head { number: 10, timestamp: 1000, ..Default::default() }));
+        assert!(!both.active_at_timestamp(1000));
+        // the block is reached before the timestamp
+        assert!(!both.transitions_at_block_and_timestamp(11, 999, 998));
+        assert!(both.transitions_at_block_and_timestamp(12, 1000, 999));
+        assert!(!both.transitions_at_block_and_timestamp(13, 1001, 1000));
+        // the timestamp is reached before the block
+        assert!(!both.transitions_at_block_and_timestamp(9, 1009, 1008));
+        assert!(both.transitions_at_block_and_timestamp(10, 1010, 1009));
+        assert!(!both.transitions_at_block_and_timestamp(11, 1011, 1010));
+
+        let ttd = ForkCondition::TTD { fork_block: None, total_difficulty: U256::from(100) };
+        assert!(!ttd.active_at_block(u64::MAX));
+        assert!(ttd.active_at_ttd(U256::from(110), U256::from(10)));
+        assert!(!ttd.active_at_ttd(U256::from(109), U256::from(10)));
+    }
+
+    #[cfg(feature = "compact")]
+    #[test]
+    fn fork_condition_compact_roundtrip() {
+        for condition in [
+            ForkCondition::Block(1_150_000),
+            ForkCondition::Epoch { epoch_length: 30000, epoch: 2 },
+            ForkCondition::TTD { fork_block: None, total_difficulty: U256::from(100) },
+            ForkCondition::TTD { fork_block: Some(1735371), total_difficulty: U256::from(100) },
+            ForkCondition::Timestamp(1681338455),
+            ForkCondition::BlockAndTimestamp { block: 10, timestamp: 1000 },
+            ForkCondition::Never,
+        ] {
+            let mut buf = Vec::new();
+            let len = condition.to_compact(&mut buf);
+            assert_eq!(len, buf.len());
+            assert_eq!(ForkCondition::from_compact(&buf, len), (condition, &[][..]));
+        }
+    }
+
+    #[cfg(feature = "compact")]
+    #[test]
+    #[should_panic(expected = "Junk data in database: unknown ForkCondition variant 6")]
+    fn fork_condition_compact_unknown_variant() {
+        ForkCondition::from_compact(&[6, 0, 0, 0, 0, 0, 0, 0, 1], 9);
+    }
+
+    #[cfg(feature = "compact")]
+    #[test]
+    #[should_panic(expected = "Junk data in database: truncated ForkCondition variant 2")]
+    fn fork_condition_compact_truncated() {
+        let condition =
+            ForkCondition::TTD { fork_block: Some(1735371), total_difficulty: U256::from(100) };
+        let mut buf = Vec::new();
+        let len = condition.to_compact(&mut buf);
+        ForkCondition::from_compact(&buf[..len - 1], len - 1);
+    }
+}
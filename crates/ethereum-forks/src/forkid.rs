@@ -3,22 +3,21 @@
 //! Previously version of Apache licenced [`ethereum-forkid`](https://crates.io/crates/ethereum-forkid).
 
 use crate::Head;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloy_primitives::{hex, BlockNumber, B256};
 use alloy_rlp::*;
 #[cfg(any(test, feature = "arbitrary"))]
 use arbitrary::Arbitrary;
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign},
+};
 use crc::*;
 #[cfg(any(test, feature = "arbitrary"))]
 use proptest_derive::Arbitrary as PropTestArbitrary;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::{
-    cmp::Ordering,
-    collections::{BTreeMap, BTreeSet},
-    fmt,
-    ops::{Add, AddAssign},
-};
-use thiserror::Error;
 
 const CRC_32_IEEE: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
 
@@ -117,12 +116,9 @@ pub struct ForkId {
 }
 
 /// Reason for rejecting provided `ForkId`.
-#[derive(Clone, Copy, Debug, Error, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ValidationError {
     /// Remote node is outdated and needs a software update.
-    #[error(
-        "remote node is outdated and needs a software update: local={local:?}, remote={remote:?}"
-    )]
     RemoteStale {
         /// locally configured forkId
         local: ForkId,
@@ -130,7 +126,6 @@ pub enum ValidationError {
         remote: ForkId,
     },
     /// Local node is on an incompatible chain or needs a software update.
-    #[error("local node is on an incompatible chain or needs a software update: local={local:?}, remote={remote:?}")]
     LocalIncompatibleOrStale {
         /// locally configured forkId
         local: ForkId,
@@ -139,6 +134,122 @@ pub enum ValidationError {
     },
 }
 
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::RemoteStale { local, remote } => write!(
+                f,
+                "remote node is outdated and needs a software update: local={local:?}, remote={remote:?}"
+            ),
+            ValidationError::LocalIncompatibleOrStale { local, remote } => write!(
+                f,
+                "local node is on an incompatible chain or needs a software update: local={local:?}, remote={remote:?}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// A `FORK_NEXT` above this value is a timestamp rather than a block number.
+///
+/// This is the launch timestamp of mainnet, block numbers won't reach it.
+const TIMESTAMP_FORK_NEXT_THRESHOLD: u64 = 1_438_269_973;
+
+/// The outcome of checking a remote [`ForkId`] against the forks of the local chain, following
+/// `EIP-2124` and `EIP-6122`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ForkIdValidation {
+    /// The remote node is on the same chain, it may be ahead or behind the local head.
+    Compatible,
+    /// Remote node is outdated and needs a software update.
+    RemoteStale {
+        /// locally configured forkId
+        local: ForkId,
+        /// ForkId received from remote
+        remote: ForkId,
+    },
+    /// Local node is on an incompatible chain or needs a software update.
+    LocalIncompatibleOrStale {
+        /// locally configured forkId
+        local: ForkId,
+        /// ForkId received from remote
+        remote: ForkId,
+    },
+}
+
+impl ForkIdValidation {
+    /// Checks whether the `remote` fork id is compatible with the local chain.
+    ///
+    /// `fork_ids` are all fork ids of the local chain in activation order, starting with the one
+    /// of the genesis and ending with the one whose `next` is 0. `local` is the fork id at `head`.
+    ///
+    /// Implements the rules following: <https://github.com/ethereum/EIPs/blob/master/EIPS/eip-2124.md#validation-rules>
+    pub fn new<I>(head: &Head, local: ForkId, fork_ids: I, remote: ForkId) -> Self
+    where
+        I: IntoIterator<Item = ForkId>,
+    {
+        // 1) If local and remote FORK_HASH matches, compare local head to FORK_NEXT.
+        if local.hash == remote.hash {
+            // EIP-6122 doesn't tell timestamps and block numbers apart, so a large FORK_NEXT is
+            // compared to the timestamp of the head.
+            let passed = remote.next != 0 &&
+                (head.number >= remote.next ||
+                    (remote.next > TIMESTAMP_FORK_NEXT_THRESHOLD &&
+                        head.timestamp >= remote.next));
+            return if passed {
+                // 1a) A remotely announced but remotely not passed fork is already passed
+                // locally, disconnect, since the chains are incompatible.
+                Self::LocalIncompatibleOrStale { local, remote }
+            } else {
+                // 1b) No remotely announced fork or it's not yet passed locally, connect.
+                Self::Compatible
+            }
+        }
+
+        let mut past = true;
+        for fork_id in fork_ids {
+            if fork_id.hash == local.hash {
+                past = false;
+            } else if fork_id.hash == remote.hash {
+                return if !past || fork_id.next == remote.next {
+                    // 2) The remote FORK_HASH is a subset of the local past forks and the remote
+                    // FORK_NEXT matches the locally following fork, connect.
+                    // 3) The remote FORK_HASH is a superset of the local past forks and can be
+                    // completed with locally known future forks, connect.
+                    Self::Compatible
+                } else {
+                    // 2) The remote FORK_HASH is a local past fork, but the remote node doesn't
+                    // know the following fork.
+                    Self::RemoteStale { local, remote }
+                }
+            }
+        }
+
+        // 4) Reject in all other cases.
+        Self::LocalIncompatibleOrStale { local, remote }
+    }
+
+    /// Returns `true` if the remote fork id is compatible.
+    pub const fn is_compatible(&self) -> bool {
+        matches!(self, Self::Compatible)
+    }
+
+    /// Converts the outcome into a [`ValidationError`] if the remote fork id is not compatible.
+    pub const fn into_result(self) -> Result<(), ValidationError> {
+        match self {
+            Self::Compatible => Ok(()),
+            Self::RemoteStale { local, remote } => {
+                Err(ValidationError::RemoteStale { local, remote })
+            }
+            Self::LocalIncompatibleOrStale { local, remote } => {
+                Err(ValidationError::LocalIncompatibleOrStale { local, remote })
+            }
+        }
+    }
+}
+
 /// Filter that describes the state of blockchain and can be used to check incoming `ForkId`s for
 /// compatibility.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -154,6 +265,10 @@ pub struct ForkFilter {
     /// [eip-6122]: https://eips.ethereum.org/EIPS/eip-6122
     forks: BTreeMap<ForkFilterKey, ForkHash>,
 
+    /// The blocks that time-based forks additionally wait for, keyed by the timestamp of the fork,
+    /// see [ForkFilter::with_fork_blocks].
+    fork_blocks: BTreeMap<u64, BlockNumber>,
+
     /// The current head, used to select forks that are active locally.
     head: Head,
 
@@ -192,21 +307,48 @@ impl ForkFilter {
             .0;
 
         // Compute cache based on filtered forks and the current head.
-        let cache = Cache::compute_cache(&forks, head);
+        let fork_blocks = BTreeMap::new();
+        let cache = Cache::compute_cache(&forks, &fork_blocks, head);
 
         // Create and return a new `ForkFilter`.
-        Self { forks, head, cache }
+        Self { forks, fork_blocks, head, cache }
+    }
+
+    /// Sets the blocks that time-based forks additionally wait for, keyed by the timestamp of the
+    /// fork.
+    ///
+    /// Such a fork is still hashed by its timestamp, but it only becomes active once the head
+    /// reached both its timestamp and its block. While the timestamp passed and the block is
+    /// pending, the block is advertised as `FORK_NEXT`, since it activates the fork.
+    pub fn with_fork_blocks<F>(mut self, fork_blocks: F) -> Self
+    where
+        F: IntoIterator<Item = (u64, BlockNumber)>,
+    {
+        for (time, block) in fork_blocks {
+            let fork_block = self.fork_blocks.entry(time).or_default();
+            *fork_block = (*fork_block).max(block);
+        }
+        self.cache = Cache::compute_cache(&self.forks, &self.fork_blocks, self.head);
+        self
     }
 
     fn set_head_priv(&mut self, head: Head) -> Option<ForkTransition> {
         let recompute_cache = {
             let head_in_past = match self.cache.epoch_start {
                 ForkFilterKey::Block(epoch_start_block) => head.number < epoch_start_block,
-                ForkFilterKey::Time(epoch_start_time) => head.timestamp < epoch_start_time,
+                ForkFilterKey::Time(epoch_start_time) => {
+                    head.timestamp < epoch_start_time || head.number < self.cache.epoch_start_block
+                }
             };
             let head_in_future = match self.cache.epoch_end {
                 Some(ForkFilterKey::Block(epoch_end_block)) => head.number >= epoch_end_block,
-                Some(ForkFilterKey::Time(epoch_end_time)) => head.timestamp >= epoch_end_time,
+                // a fork that waits for its block is advertised by its block once its timestamp
+                // passed
+                Some(ForkFilterKey::Time(epoch_end_time)) => {
+                    head.timestamp >= epoch_end_time &&
+                        (head.number >= self.cache.epoch_end_block ||
+                            self.cache.fork_id.next == epoch_end_time)
+                }
                 None => false,
             };
 
@@ -216,7 +358,7 @@ impl ForkFilter {
         // recompute the cache
         let transition = if recompute_cache {
             let past = self.current();
-            self.cache = Cache::compute_cache(&self.forks, head);
+            self.cache = Cache::compute_cache(&self.forks, &self.fork_blocks, head);
             Some(ForkTransition { current: self.current(), past })
         } else {
             None
@@ -249,63 +391,17 @@ impl ForkFilter {
     ///
     /// Returns a `ValidationError` if the `ForkId` is not compatible.
     pub fn validate(&self, fork_id: ForkId) -> Result<(), ValidationError> {
-        // 1) If local and remote FORK_HASH matches...
-        if self.current().hash == fork_id.hash {
-            if fork_id.next == 0 {
-                // 1b) No remotely announced fork, connect.
-                return Ok(())
-            }
-
-            // We check if this fork is time-based or block number-based
-            // NOTE: This is a bit hacky but I'm unsure how else we can figure out when to use
-            // timestamp vs when to use block number..
-            let head_block_or_time = match self.cache.epoch_start {
-                ForkFilterKey::Block(_) => self.head.number,
-                ForkFilterKey::Time(_) => self.head.timestamp,
-            };
-
-            //... compare local head to FORK_NEXT.
-            return if head_block_or_time >= fork_id.next {
-                // 1a) A remotely announced but remotely not passed block is already passed locally,
-                // disconnect, since the chains are incompatible.
-                Err(ValidationError::LocalIncompatibleOrStale {
-                    local: self.current(),
-                    remote: fork_id,
-                })
-            } else {
-                // 1b) Remotely announced fork not yet passed locally, connect.
-                Ok(())
-            }
-        }
-
-        // 2) If the remote FORK_HASH is a subset of the local past forks...
-        let mut it = self.cache.past.iter();
-        while let Some((_, hash)) = it.next() {
-            if *hash == fork_id.hash {
-                // ...and the remote FORK_NEXT matches with the locally following fork block number
-                // or timestamp, connect.
-                if let Some((actual_key, _)) = it.next() {
-                    return if u64::from(*actual_key) == fork_id.next {
-                        Ok(())
-                    } else {
-                        Err(ValidationError::RemoteStale { local: self.current(), remote: fork_id })
-                    }
-                }
-
-                break
-            }
-        }
-
-        // 3) If the remote FORK_HASH is a superset of the local past forks and can be completed
-        // with locally known future forks, connect.
-        for future_fork_hash in &self.cache.future {
-            if *future_fork_hash == fork_id.hash {
-                return Ok(())
-            }
-        }
+        ForkIdValidation::new(&self.head, self.current(), self.fork_ids(), fork_id).into_result()
+    }
 
-        // 4) Reject in all other cases.
-        Err(ValidationError::LocalIncompatibleOrStale { local: self.current(), remote: fork_id })
+    /// Returns all fork ids of the chain in activation order, starting with the one of the
+    /// genesis.
+    fn fork_ids(&self) -> impl Iterator<Item = ForkId> + '_ {
+        let mut forks = self.forks.iter().peekable();
+        core::iter::from_fn(move || {
+            let (_, hash) = forks.next()?;
+            Some(ForkId { hash: *hash, next: forks.peek().map_or(0, |(key, _)| u64::from(**key)) })
+        })
     }
 }
 
@@ -327,56 +423,73 @@ struct Cache {
     // When we progress from one fork to the next one we move to the next epoch.
     epoch_start: ForkFilterKey,
     epoch_end: Option<ForkFilterKey>,
-    past: Vec<(ForkFilterKey, ForkHash)>,
-    future: Vec<ForkHash>,
+    // The blocks the time-based forks at the start and the end of the epoch wait for, 0 if none.
+    epoch_start_block: BlockNumber,
+    epoch_end_block: BlockNumber,
     fork_id: ForkId,
 }
 
 impl Cache {
     /// Compute cache.
-    fn compute_cache(forks: &BTreeMap<ForkFilterKey, ForkHash>, head: Head) -> Self {
-        // Prepare vectors to store past and future forks.
-        let mut past = Vec::with_capacity(forks.len());
-        let mut future = Vec::with_capacity(forks.len());
+    fn compute_cache(
+        forks: &BTreeMap<ForkFilterKey, ForkHash>,
+        fork_blocks: &BTreeMap<u64, BlockNumber>,
+        head: Head,
+    ) -> Self {
+        // The hash of the last active fork.
+        let mut hash = None;
 
         // Initialize variables to track the epoch range.
         let mut epoch_start = ForkFilterKey::Block(0);
         let mut epoch_end = None;
+        let mut epoch_start_block = 0;
+        let mut epoch_end_block = 0;
+
+        // Time-based forks also wait for the blocks of all earlier time-based forks, so they
+        // activate in order.
+        let mut min_block = 0;
 
-        // Iterate through forks and categorize them into past and future.
-        for (key, hash) in forks {
+        // Iterate through forks to find the last active and the first inactive one.
+        for (key, fork_hash) in forks {
             // Check if the fork is active based on its type (Block or Time).
-            let active = match key {
-                ForkFilterKey::Block(block) => *block <= head.number,
-                ForkFilterKey::Time(time) => *time <= head.timestamp,
+            let (active, fork_block) = match key {
+                ForkFilterKey::Block(block) => (*block <= head.number, 0),
+                ForkFilterKey::Time(time) => {
+                    min_block = min_block.max(fork_blocks.get(time).copied().unwrap_or_default());
+                    (*time <= head.timestamp && min_block <= head.number, min_block)
+                }
             };
 
-            // Categorize forks into past or future based on activity.
             if active {
                 epoch_start = *key;
-                past.push((*key, *hash));
-            } else {
-                if epoch_end.is_none() {
-                    epoch_end = Some(*key);
-                }
-                future.push(*hash);
+                epoch_start_block = fork_block;
+                hash = Some(*fork_hash);
+            } else if epoch_end.is_none() {
+                epoch_end = Some(*key);
+                epoch_end_block = fork_block;
             }
         }
 
-        // Create ForkId using the last past fork's hash and the next epoch start.
+        // Create ForkId using the last active fork's hash and the next epoch start. A time-based
+        // fork whose timestamp already passed is activated by its block.
+        let next = match epoch_end {
+            Some(ForkFilterKey::Time(time)) if time <= head.timestamp => epoch_end_block,
+            epoch_end => epoch_end.unwrap_or(ForkFilterKey::Block(0)).into(),
+        };
         let fork_id = ForkId {
-            hash: past.last().expect("there is always at least one - genesis - fork hash").1,
-            next: epoch_end.unwrap_or(ForkFilterKey::Block(0)).into(),
+            hash: hash.expect("there is always at least one - genesis - fork hash"),
+            next,
         };
 
         // Return the computed cache.
-        Self { epoch_start, epoch_end, past, future, fork_id }
+        Self { epoch_start, epoch_end, epoch_start_block, epoch_end_block, fork_id }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
     use alloy_primitives::b256;
 
     const GENESIS_HASH: B256 =
@@ -608,4 +721,42 @@ mod tests {
         assert!(fork_filter.set_head_priv(Head { number: b2, ..Default::default() }).is_some());
         assert_eq!(fork_filter.current(), h2);
     }
+
+    #[test]
+    fn time_fork_with_block() {
+        let h0 = ForkHash::from(GENESIS_HASH);
+        let h1 = h0 + 1000u64;
+
+        let mut fork_filter = ForkFilter::new(
+            Head { number: 10, timestamp: 500, ..Default::default() },
+            GENESIS_HASH,
+            0,
+            vec![ForkFilterKey::Time(1000)],
+        )
+        .with_fork_blocks([(1000, 20)]);
+        assert_eq!(fork_filter.current(), ForkId { hash: h0, next: 1000 });
+
+        // the timestamp passed, the fork waits for its block
+        assert_eq!(
+            fork_filter.set_head_priv(Head { number: 10, timestamp: 1000, ..Default::default() }),
+            Some(ForkTransition {
+                current: ForkId { hash: h0, next: 20 },
+                past: ForkId { hash: h0, next: 1000 }
+            })
+        );
+        assert!(fork_filter
+            .set_head_priv(Head { number: 11, timestamp: 1001, ..Default::default() })
+            .is_none());
+
+        assert!(fork_filter
+            .set_head_priv(Head { number: 20, timestamp: 1002, ..Default::default() })
+            .is_some());
+        assert_eq!(fork_filter.current(), ForkId { hash: h1, next: 0 });
+
+        // a reorg below the block deactivates the fork
+        assert!(fork_filter
+            .set_head_priv(Head { number: 19, timestamp: 1002, ..Default::default() })
+            .is_some());
+        assert_eq!(fork_filter.current(), ForkId { hash: h0, next: 20 });
+    }
 }
@@ -1,7 +1,11 @@
+use alloc::{format, string::String};
 use alloy_chains::Chain;
+use core::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::{fmt::Display, str::FromStr};
 
 /// Represents the consensus type of a blockchain fork.
 ///
@@ -48,6 +52,15 @@ pub enum Hardfork {
     ArrowGlacier,
     /// Gray Glacier.
     GrayGlacier,
+    /// Jaipur
+    #[cfg(feature = "polygon")]
+    Jaipur,
+    /// Delhi
+    #[cfg(feature = "polygon")]
+    Delhi,
+    /// Indore
+    #[cfg(feature = "polygon")]
+    Indore,
     /// Paris.
     Paris,
     /// Bedrock.
@@ -61,11 +74,36 @@ pub enum Hardfork {
     /// Canyon
     #[cfg(feature = "optimism")]
     Canyon,
+    /// Agra
+    #[cfg(feature = "polygon")]
+    Agra,
     /// Cancun.
     Cancun,
     /// Ecotone
     #[cfg(feature = "optimism")]
     Ecotone,
+    /// Fjord
+    #[cfg(feature = "optimism")]
+    Fjord,
+    /// Granite
+    #[cfg(feature = "optimism")]
+    Granite,
+    /// Holocene
+    #[cfg(feature = "optimism")]
+    Holocene,
+    /// Napoli
+    #[cfg(feature = "polygon")]
+    Napoli,
+    /// Ahmedabad
+    #[cfg(feature = "polygon")]
+    Ahmedabad,
+    /// Prague.
+    Prague,
+    /// Bhilai
+    #[cfg(feature = "polygon")]
+    Bhilai,
+    /// Osaka.
+    Osaka,
 }
 
 impl Hardfork {
@@ -94,6 +132,8 @@ impl Hardfork {
 
             // upcoming hardforks
             Hardfork::Cancun => None,
+            Hardfork::Prague => None,
+            Hardfork::Osaka => None,
 
             // optimism hardforks
             #[cfg(feature = "optimism")]
@@ -104,6 +144,27 @@ impl Hardfork {
             Hardfork::Canyon => None,
             #[cfg(feature = "optimism")]
             Hardfork::Ecotone => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Fjord => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Granite => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Holocene => None,
+            // polygon hardforks
+            #[cfg(feature = "polygon")]
+            Hardfork::Jaipur => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Delhi => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Indore => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Agra => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Napoli => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Ahmedabad => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Bhilai => None,
         }
     }
 
@@ -156,6 +217,66 @@ impl Hardfork {
 
             // upcoming hardforks
             Hardfork::Cancun => None,
+            Hardfork::Prague => None,
+            Hardfork::Osaka => None,
+
+            // optimism hardforks
+            #[cfg(feature = "optimism")]
+            Hardfork::Bedrock => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Regolith => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Canyon => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Ecotone => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Fjord => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Granite => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Holocene => None,
+            // polygon hardforks
+            #[cfg(feature = "polygon")]
+            Hardfork::Jaipur => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Delhi => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Indore => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Agra => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Napoli => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Ahmedabad => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Bhilai => None,
+        }
+    }
+
+    /// Retrieves the headline EIP of the hardfork, e.g. `EIP-1559` for [Hardfork::London].
+    ///
+    /// Returns `None` for hardforks without a single defining EIP.
+    pub fn eip(&self) -> Option<&'static str> {
+        match self {
+            Hardfork::Frontier => None,
+            Hardfork::Homestead => Some("EIP-2"),
+            Hardfork::Dao => Some("EIP-779"),
+            Hardfork::Tangerine => Some("EIP-150"),
+            Hardfork::SpuriousDragon => Some("EIP-155"),
+            Hardfork::Byzantium => Some("EIP-140"),
+            Hardfork::Constantinople => Some("EIP-1014"),
+            Hardfork::Petersburg => Some("EIP-1716"),
+            Hardfork::Istanbul => Some("EIP-1884"),
+            Hardfork::MuirGlacier => Some("EIP-2384"),
+            Hardfork::Berlin => Some("EIP-2929"),
+            Hardfork::London => Some("EIP-1559"),
+            Hardfork::ArrowGlacier => Some("EIP-4345"),
+            Hardfork::GrayGlacier => Some("EIP-5133"),
+            Hardfork::Paris => Some("EIP-3675"),
+            Hardfork::Shanghai => Some("EIP-4895"),
+            Hardfork::Cancun => Some("EIP-4844"),
+            Hardfork::Prague => Some("EIP-7702"),
+            Hardfork::Osaka => Some("EIP-7594"),
 
             // optimism hardforks
             #[cfg(feature = "optimism")]
@@ -166,6 +287,27 @@ impl Hardfork {
             Hardfork::Canyon => None,
             #[cfg(feature = "optimism")]
             Hardfork::Ecotone => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Fjord => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Granite => None,
+            #[cfg(feature = "optimism")]
+            Hardfork::Holocene => None,
+            // polygon hardforks
+            #[cfg(feature = "polygon")]
+            Hardfork::Jaipur => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Delhi => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Indore => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Agra => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Napoli => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Ahmedabad => None,
+            #[cfg(feature = "polygon")]
+            Hardfork::Bhilai => None,
         }
     }
 }
@@ -192,6 +334,8 @@ impl FromStr for Hardfork {
             "paris" => Hardfork::Paris,
             "shanghai" => Hardfork::Shanghai,
             "cancun" => Hardfork::Cancun,
+            "prague" => Hardfork::Prague,
+            "osaka" => Hardfork::Osaka,
             #[cfg(feature = "optimism")]
             "bedrock" => Hardfork::Bedrock,
             #[cfg(feature = "optimism")]
@@ -200,20 +344,52 @@ impl FromStr for Hardfork {
             "canyon" => Hardfork::Canyon,
             #[cfg(feature = "optimism")]
             "ecotone" => Hardfork::Ecotone,
+            #[cfg(feature = "optimism")]
+            "fjord" => Hardfork::Fjord,
+            #[cfg(feature = "optimism")]
+            "granite" => Hardfork::Granite,
+            #[cfg(feature = "optimism")]
+            "holocene" => Hardfork::Holocene,
+            #[cfg(feature = "polygon")]
+            "jaipur" => Hardfork::Jaipur,
+            #[cfg(feature = "polygon")]
+            "delhi" => Hardfork::Delhi,
+            #[cfg(feature = "polygon")]
+            "indore" => Hardfork::Indore,
+            #[cfg(feature = "polygon")]
+            "agra" => Hardfork::Agra,
+            #[cfg(feature = "polygon")]
+            "napoli" => Hardfork::Napoli,
+            #[cfg(feature = "polygon")]
+            "ahmedabad" => Hardfork::Ahmedabad,
+            #[cfg(feature = "polygon")]
+            "bhilai" => Hardfork::Bhilai,
             _ => return Err(format!("Unknown hardfork: {s}")),
         })
     }
 }
 
 impl Display for Hardfork {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self:?}")
     }
 }
 
+/// A chain specific hardfork that is not part of [`Hardfork`].
+///
+/// L2s and private chains implement this for their own fork types (e.g. `MyChainV2`) so the forks
+/// can be scheduled alongside the Ethereum hardforks without patching [`Hardfork`].
+pub trait ChainHardfork: Debug + Send + Sync + 'static {
+    /// The name of the fork, e.g. `MyChainV2`.
+    ///
+    /// Forks of the same chain are identified by their name, so it must be unique.
+    fn name(&self) -> &str;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
 
     #[test]
     fn check_hardfork_from_str() {
@@ -235,6 +411,8 @@ mod tests {
             "PARIS",
             "ShAnGhAI",
             "CaNcUn",
+            "PrAgUe",
+            "OsAkA",
         ];
         let expected_hardforks = [
             Hardfork::Frontier,
@@ -254,6 +432,8 @@ mod tests {
             Hardfork::Paris,
             Hardfork::Shanghai,
             Hardfork::Cancun,
+            Hardfork::Prague,
+            Hardfork::Osaka,
         ];
 
         let hardforks: Vec<Hardfork> =
@@ -265,9 +445,37 @@ mod tests {
     #[test]
     #[cfg(feature = "optimism")]
     fn check_op_hardfork_from_str() {
-        let hardfork_str = ["beDrOck", "rEgOlITH", "cAnYoN", "eCoToNe"];
-        let expected_hardforks =
-            [Hardfork::Bedrock, Hardfork::Regolith, Hardfork::Canyon, Hardfork::Ecotone];
+        let hardfork_str =
+            ["beDrOck", "rEgOlITH", "cAnYoN", "eCoToNe", "FJorD", "GrAnItE", "hOlOcEnE"];
+        let expected_hardforks = [
+            Hardfork::Bedrock,
+            Hardfork::Regolith,
+            Hardfork::Canyon,
+            Hardfork::Ecotone,
+            Hardfork::Fjord,
+            Hardfork::Granite,
+            Hardfork::Holocene,
+        ];
+
+        let hardforks: Vec<Hardfork> =
+            hardfork_str.iter().map(|h| Hardfork::from_str(h).unwrap()).collect();
+
+        assert_eq!(hardforks, expected_hardforks);
+    }
+
+    #[test]
+    #[cfg(feature = "polygon")]
+    fn check_polygon_hardfork_from_str() {
+        let hardfork_str = ["jAiPuR", "DELHI", "inDoRe", "aGrA", "NaPoLi", "ahmedABAD", "bHiLaI"];
+        let expected_hardforks = [
+            Hardfork::Jaipur,
+            Hardfork::Delhi,
+            Hardfork::Indore,
+            Hardfork::Agra,
+            Hardfork::Napoli,
+            Hardfork::Ahmedabad,
+            Hardfork::Bhilai,
+        ];
 
         let hardforks: Vec<Hardfork> =
             hardfork_str.iter().map(|h| Hardfork::from_str(h).unwrap()).collect();
@@ -275,6 +483,14 @@ mod tests {
         assert_eq!(hardforks, expected_hardforks);
     }
 
+    #[test]
+    fn check_hardfork_eip() {
+        assert_eq!(Hardfork::Frontier.eip(), None);
+        assert_eq!(Hardfork::London.eip(), Some("EIP-1559"));
+        assert_eq!(Hardfork::Shanghai.eip(), Some("EIP-4895"));
+        assert_eq!(Hardfork::Cancun.eip(), Some("EIP-4844"));
+    }
+
     #[test]
     fn check_nonexistent_hardfork_from_str() {
         assert!(Hardfork::from_str("not a hardfork").is_err());
@@ -299,11 +515,24 @@ mod tests {
             Hardfork::GrayGlacier,
         ];
 
-        let pos_hardforks = [Hardfork::Paris, Hardfork::Shanghai, Hardfork::Cancun];
+        let pos_hardforks = [
+            Hardfork::Paris,
+            Hardfork::Shanghai,
+            Hardfork::Cancun,
+            Hardfork::Prague,
+            Hardfork::Osaka,
+        ];
 
         #[cfg(feature = "optimism")]
-        let op_hardforks =
-            [Hardfork::Bedrock, Hardfork::Regolith, Hardfork::Canyon, Hardfork::Ecotone];
+        let op_hardforks = [
+            Hardfork::Bedrock,
+            Hardfork::Regolith,
+            Hardfork::Canyon,
+            Hardfork::Ecotone,
+            Hardfork::Fjord,
+            Hardfork::Granite,
+            Hardfork::Holocene,
+        ];
 
         for hardfork in pow_hardforks.iter() {
             assert_eq!(hardfork.consensus_type(), ConsensusType::ProofOfWork);
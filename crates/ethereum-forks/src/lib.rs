@@ -2,8 +2,15 @@
 //!
 //! This crate contains Ethereum fork types and helper functions.
 //!
+//! The crate is `no_std` compatible, so light clients and wasm tooling can compute and validate
+//! [EIP-2124](https://eips.ethereum.org/EIPS/eip-2124) fork ids with the same logic as the node.
+//!
 //! ## Feature Flags
 //!
+//! - `std`: Uses the standard library, enabled by default. Disable it for `no_std` targets.
+//! - `serde`: Adds `serde` support for the fork types, enabled by default.
+//! - `compact`: Adds the reth `Compact` codec for [ForkCondition], to store fork schedules in the
+//!   database.
 //! - `arbitrary`: Adds `proptest` and `arbitrary` support for primitive types.
 
 #![doc(
@@ -12,13 +19,20 @@
     issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
 )]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
+mod forkcondition;
 mod forkid;
 mod hardfork;
 mod head;
 
-pub use forkid::{ForkFilter, ForkFilterKey, ForkHash, ForkId, ForkTransition, ValidationError};
-pub use hardfork::Hardfork;
+pub use forkcondition::ForkCondition;
+pub use forkid::{
+    ForkFilter, ForkFilterKey, ForkHash, ForkId, ForkIdValidation, ForkTransition, ValidationError,
+};
+pub use hardfork::{ChainHardfork, Hardfork};
 pub use head::Head;
 
 #[cfg(any(test, feature = "arbitrary"))]
@@ -96,6 +96,12 @@ pub trait BlockchainTreeEngine: BlockchainTreeViewer + Send + Sync {
     /// Returns `Ok` if the blocks were canonicalized, or if the blocks were already canonical.
     fn make_canonical(&self, block_hash: &BlockHash) -> RethResult<CanonicalOutcome>;
 
+    /// Records the forkchoice state and invalid blocks of the given update.
+    ///
+    /// They are written to the database in the same transaction as the next chain that is made
+    /// canonical, see [BlockchainTreeEngine::make_canonical].
+    fn update_chain_state(&self, update: ChainStateUpdate);
+
     /// Unwind tables and put it inside state
     fn unwind(&self, unwind_to: BlockNumber) -> RethResult<()>;
 }
@@ -180,6 +186,36 @@ impl CanonicalOutcome {
     }
 }
 
+/// The forkchoice state and invalid blocks that are persisted with the next canonical chain, see
+/// [BlockchainTreeEngine::update_chain_state].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChainStateUpdate {
+    /// The hash of the last safe block.
+    pub safe: Option<BlockHash>,
+    /// The hash of the last finalized block.
+    pub finalized: Option<BlockHash>,
+    /// The headers of blocks that were found to be invalid.
+    pub invalid_headers: Vec<SealedHeader>,
+}
+
+impl ChainStateUpdate {
+    /// Merges the given newer update into this one.
+    pub fn extend(&mut self, update: ChainStateUpdate) {
+        if update.safe.is_some() {
+            self.safe = update.safe;
+        }
+        if update.finalized.is_some() {
+            self.finalized = update.finalized;
+        }
+        self.invalid_headers.extend(update.invalid_headers);
+    }
+
+    /// Returns true if there is nothing to persist.
+    pub fn is_empty(&self) -> bool {
+        self.safe.is_none() && self.finalized.is_none() && self.invalid_headers.is_empty()
+    }
+}
+
 /// From Engine API spec, block inclusion can be valid, accepted or invalid.
 /// Invalid case is already covered by error, but we need to make distinction
 /// between valid blocks that extend canonical chain and the ones that fork off
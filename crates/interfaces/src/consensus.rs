@@ -178,6 +178,10 @@ pub enum ConsensusError {
     #[error("missing withdrawals")]
     BodyWithdrawalsMissing,
 
+    /// Error when a block contains withdrawals before they are enabled.
+    #[error("unexpected withdrawals")]
+    BodyWithdrawalsUnexpected,
+
     /// Error when blob gas used is missing.
     #[error("missing blob gas used")]
     BlobGasUsedMissing,
@@ -226,6 +230,30 @@ pub enum ConsensusError {
     #[error("blob gas used mismatch: {0}")]
     BlobGasUsedDiff(GotExpected<u64>),
 
+    /// Error when the init code of a contract creation exceeds the maximum size of the chain.
+    #[error(
+        "transaction {hash} init code size {size} exceeds the maximum of {max_init_code_size}"
+    )]
+    TransactionInitCodeSizeExceeded {
+        /// The hash of the transaction.
+        hash: B256,
+        /// The size of the init code.
+        size: usize,
+        /// The maximum init code size of the chain.
+        max_init_code_size: usize,
+    },
+
+    /// Error when an encoded transaction exceeds the maximum transaction size of the chain.
+    #[error("transaction {hash} size {size} exceeds the maximum of {max_tx_size}")]
+    TransactionSizeExceeded {
+        /// The hash of the transaction.
+        hash: B256,
+        /// The encoded size of the transaction.
+        size: usize,
+        /// The maximum transaction size of the chain.
+        max_tx_size: usize,
+    },
+
     /// Error for a transaction that violates consensus.
     #[error(transparent)]
     InvalidTransaction(#[from] InvalidTransactionError),
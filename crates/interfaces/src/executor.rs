@@ -147,4 +147,15 @@ impl BlockExecutionError {
     pub fn is_fatal(&self) -> bool {
         matches!(self, Self::CanonicalCommit { .. } | Self::CanonicalRevert { .. })
     }
+
+    /// Returns `true` if the receipts root or the logs bloom of the executed block don't match
+    /// the header.
+    pub fn is_receipts_mismatch(&self) -> bool {
+        matches!(
+            self,
+            Self::Validation(
+                BlockValidationError::ReceiptRootDiff(_) | BlockValidationError::BloomLogDiff(_)
+            )
+        )
+    }
 }
@@ -5,16 +5,20 @@ use std::{
 
 use crate::p2p::{download::DownloadClient, error::PeerRequestResult, priority::Priority};
 use futures::{Future, FutureExt};
-use reth_primitives::{BlockBody, B256};
+use reth_primitives::{RawBlockBody, B256};
 
 /// The bodies future type
-pub type BodiesFut = Pin<Box<dyn Future<Output = PeerRequestResult<Vec<BlockBody>>> + Send + Sync>>;
+pub type BodiesFut =
+    Pin<Box<dyn Future<Output = PeerRequestResult<Vec<RawBlockBody>>> + Send + Sync>>;
 
 /// A client capable of downloading block bodies.
+///
+/// The bodies are returned as received, with their transactions still encoded. See
+/// [RawBlockBody].
 #[auto_impl::auto_impl(&, Arc, Box)]
 pub trait BodiesClient: DownloadClient {
     /// The output of the request future for querying block bodies.
-    type Output: Future<Output = PeerRequestResult<Vec<BlockBody>>> + Sync + Send + Unpin;
+    type Output: Future<Output = PeerRequestResult<Vec<RawBlockBody>>> + Sync + Send + Unpin;
 
     /// Fetches the block body for the requested block.
     fn get_block_bodies(&self, hashes: Vec<B256>) -> Self::Output {
@@ -50,9 +54,9 @@ pub struct SingleBodyRequest<Fut> {
 
 impl<Fut> Future for SingleBodyRequest<Fut>
 where
-    Fut: Future<Output = PeerRequestResult<Vec<BlockBody>>> + Sync + Send + Unpin,
+    Fut: Future<Output = PeerRequestResult<Vec<RawBlockBody>>> + Sync + Send + Unpin,
 {
-    type Output = PeerRequestResult<Option<BlockBody>>;
+    type Output = PeerRequestResult<Option<RawBlockBody>>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let resp = ready!(self.get_mut().fut.poll_unpin(cx));
@@ -163,6 +163,15 @@ pub enum DownloadError {
         #[source]
         error: Box<ConsensusError>,
     },
+    /// The transactions of a body could not be decoded.
+    #[error("failed to decode body for header {hash}: {error}")]
+    BodyDecoding {
+        /// Hash of header of the body failing decoding
+        hash: B256,
+        /// The decoding error
+        #[source]
+        error: alloy_rlp::Error,
+    },
     /// Received more bodies than requested.
     #[error("received more bodies than requested: {0}")]
     TooManyBodies(GotExpected<usize>),
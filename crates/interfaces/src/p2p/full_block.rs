@@ -3,13 +3,14 @@ use crate::{
     consensus::{Consensus, ConsensusError},
     p2p::{
         bodies::client::{BodiesClient, SingleBodyRequest},
-        error::PeerRequestResult,
+        error::{DownloadError, DownloadResult, PeerRequestResult},
         headers::client::{HeadersClient, SingleHeaderRequest},
     },
 };
 use futures::Stream;
 use reth_primitives::{
-    BlockBody, GotExpected, Header, HeadersDirection, SealedBlock, SealedHeader, WithPeerId, B256,
+    BlockBody, GotExpected, Header, HeadersDirection, RawBlockBody, SealedBlock, SealedHeader,
+    WithPeerId, B256,
 };
 use std::{
     cmp::Reverse,
@@ -50,8 +51,9 @@ where
     ///
     /// Note: this future is cancel safe
     ///
-    /// Caution: This does no validation of body (transactions) response but guarantees that the
-    /// [SealedHeader] matches the requested hash.
+    /// Caution: This does not execute the block, the body is only validated against the header
+    /// with [Consensus::validate_block]. It guarantees that the [SealedHeader] matches the
+    /// requested hash.
     pub fn get_full_block(&self, hash: B256) -> FetchFullBlockFuture<Client> {
         let client = self.client.clone();
         FetchFullBlockFuture {
@@ -63,6 +65,7 @@ where
             client,
             header: None,
             body: None,
+            consensus: Arc::clone(&self.consensus),
         }
     }
 
@@ -70,9 +73,10 @@ where
     ///
     /// Note: this future is cancel safe
     ///
-    /// Caution: This does no validation of body (transactions) responses but guarantees that
-    /// the starting [SealedHeader] matches the requested hash, and that the number of headers and
-    /// bodies received matches the requested limit.
+    /// Caution: This does not execute the blocks, the bodies are only validated against the
+    /// headers with [Consensus::validate_block]. It guarantees that the starting [SealedHeader]
+    /// matches the requested hash, and that the number of headers and bodies received matches the
+    /// requested limit.
     ///
     /// The returned future yields bodies in falling order, i.e. with descending block numbers.
     pub fn get_full_block_range(
@@ -121,6 +125,8 @@ where
     request: FullBlockRequest<Client>,
     header: Option<SealedHeader>,
     body: Option<BodyResponse>,
+    /// The consensus instance used to validate the block.
+    consensus: Arc<dyn Consensus>,
 }
 
 impl<Client> FetchFullBlockFuture<Client>
@@ -148,27 +154,32 @@ where
         match resp {
             BodyResponse::Validated(body) => Some(SealedBlock::new(header, body)),
             BodyResponse::PendingValidation(resp) => {
+                let (peer, body) = resp.split();
                 // ensure the block is valid, else retry
-                if let Err(err) = ensure_valid_body_response(&header, resp.data()) {
-                    debug!(target: "downloaders", %err, hash=?header.hash(), "Received wrong body");
-                    self.client.report_bad_message(resp.peer_id());
-                    self.header = Some(header);
-                    self.request.body = Some(self.client.get_block_body(self.hash));
-                    return None
+                match decode_valid_body_response(&*self.consensus, &header, body) {
+                    Ok(body) => Some(SealedBlock::new(header, body)),
+                    Err(err) => {
+                        debug!(target: "downloaders", %err, hash=?header.hash(), "Received wrong body");
+                        self.client.report_bad_message(peer);
+                        self.header = Some(header);
+                        self.request.body = Some(self.client.get_block_body(self.hash));
+                        None
+                    }
                 }
-                Some(SealedBlock::new(header, resp.into_data()))
             }
         }
     }
 
-    fn on_block_response(&mut self, resp: WithPeerId<BlockBody>) {
+    fn on_block_response(&mut self, resp: WithPeerId<RawBlockBody>) {
         if let Some(ref header) = self.header {
-            if let Err(err) = ensure_valid_body_response(header, resp.data()) {
-                debug!(target: "downloaders", %err, hash=?header.hash(), "Received wrong body");
-                self.client.report_bad_message(resp.peer_id());
-                return
+            let (peer, body) = resp.split();
+            match decode_valid_body_response(&*self.consensus, header, body) {
+                Ok(body) => self.body = Some(BodyResponse::Validated(body)),
+                Err(err) => {
+                    debug!(target: "downloaders", %err, hash=?header.hash(), "Received wrong body");
+                    self.client.report_bad_message(peer);
+                }
             }
-            self.body = Some(BodyResponse::Validated(resp.into_data()));
             return
         }
         self.body = Some(BodyResponse::PendingValidation(resp));
@@ -284,16 +295,16 @@ where
 /// future.
 enum ResponseResult {
     Header(PeerRequestResult<Option<Header>>),
-    Body(PeerRequestResult<Option<BlockBody>>),
+    Body(PeerRequestResult<Option<RawBlockBody>>),
 }
 
 /// The response of a body request.
 #[derive(Debug)]
 enum BodyResponse {
-    /// Already validated against transaction root of header
+    /// Already validated against transaction root of header, and decoded
     Validated(BlockBody),
     /// Still needs to be validated against header
-    PendingValidation(WithPeerId<BlockBody>),
+    PendingValidation(WithPeerId<RawBlockBody>),
 }
 
 /// Ensures the block response data matches the header.
@@ -302,9 +313,11 @@ enum BodyResponse {
 ///   - ommer hash
 ///   - transaction root
 ///   - withdrawals root
-fn ensure_valid_body_response(
+///
+/// The transactions of the body don't need to be decoded for this.
+pub fn ensure_valid_body_response(
     header: &SealedHeader,
-    block: &BlockBody,
+    block: &RawBlockBody,
 ) -> Result<(), ConsensusError> {
     let ommers_hash = block.calculate_ommers_root();
     if header.ommers_hash != ommers_hash {
@@ -341,6 +354,24 @@ fn ensure_valid_body_response(
     Ok(())
 }
 
+/// Ensures the block response data matches the header, see [ensure_valid_body_response], decodes
+/// its transactions and validates the resulting block with [Consensus::validate_block].
+fn decode_valid_body_response(
+    consensus: &dyn Consensus,
+    header: &SealedHeader,
+    body: RawBlockBody,
+) -> DownloadResult<BlockBody> {
+    let hash = header.hash();
+    ensure_valid_body_response(header, &body)
+        .map_err(|error| DownloadError::BodyValidation { hash, error: Box::new(error) })?;
+    let body = body.try_into_body().map_err(|error| DownloadError::BodyDecoding { hash, error })?;
+    let block = SealedBlock::new(header.clone(), body);
+    consensus
+        .validate_block(&block)
+        .map_err(|error| DownloadError::BodyValidation { hash, error: Box::new(error) })?;
+    Ok(block.split_header_body().1)
+}
+
 /// A future that downloads a range of full blocks from the network.
 ///
 /// This first fetches the headers for the given range using the inner `Client`. Once the request
@@ -436,18 +467,20 @@ where
                 let body = match body_resp {
                     BodyResponse::Validated(body) => body,
                     BodyResponse::PendingValidation(resp) => {
+                        let (peer, body) = resp.split();
                         // ensure the block is valid, else retry
-                        if let Err(err) = ensure_valid_body_response(header, resp.data()) {
-                            debug!(target: "downloaders", %err, hash=?header.hash(), "Received wrong body in range response");
-                            self.client.report_bad_message(resp.peer_id());
-
-                            // get body that doesn't match, put back into vecdeque, and retry it
-                            self.pending_headers.push_back(header.clone());
-                            needs_retry = true;
-                            continue
+                        match decode_valid_body_response(&*self.consensus, header, body) {
+                            Ok(body) => body,
+                            Err(err) => {
+                                debug!(target: "downloaders", %err, hash=?header.hash(), "Received wrong body in range response");
+                                self.client.report_bad_message(peer);
+
+                                // get body that doesn't match, put back into vecdeque, and retry it
+                                self.pending_headers.push_back(header.clone());
+                                needs_retry = true;
+                                continue
+                            }
                         }
-
-                        resp.into_data()
                     }
                 };
 
@@ -729,7 +762,7 @@ where
 // `FullBlockRangeRequest` future.
 enum RangeResponseResult {
     Header(PeerRequestResult<Vec<Header>>),
-    Body(PeerRequestResult<Vec<BlockBody>>),
+    Body(PeerRequestResult<Vec<RawBlockBody>>),
 }
 
 #[cfg(test)]
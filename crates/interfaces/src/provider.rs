@@ -1,6 +1,6 @@
 use reth_primitives::{
-    Address, BlockHash, BlockHashOrNumber, BlockNumber, GotExpected, SnapshotSegment,
-    TxHashOrNumber, TxNumber, B256, U256,
+    Address, BlockHash, BlockHashOrNumber, BlockNumHash, BlockNumber, DepositEventError,
+    GotExpected, SnapshotSegment, TxHashOrNumber, TxNumber, B256, U256,
 };
 use std::path::PathBuf;
 use thiserror::Error;
@@ -122,9 +122,26 @@ pub enum ProviderError {
     /// Snapshot file is not found for requested transaction.
     #[error("not able to find {0} snapshot file for transaction id {1}")]
     MissingSnapshotTx(SnapshotSegment, TxNumber),
+    /// The database was committed past or not yet up to the tip a consistent view was requested
+    /// for.
+    #[error("inconsistent database view: expected tip {expected:?}, found {got:?}")]
+    InconsistentView {
+        /// The requested tip.
+        expected: BlockNumHash,
+        /// The best block of the database, if its hash is known.
+        got: Option<BlockNumHash>,
+    },
     /// Error encountered when the block number conversion from U256 to u64 causes an overflow.
     #[error("failed to convert block number U256 to u64: {0}")]
     BlockNumberOverflow(U256),
+    /// A log of the deposit contract in the block could not be decoded as a `DepositEvent`.
+    #[error("invalid deposit event in block {block}: {err}")]
+    InvalidDepositEvent {
+        /// The block of the log.
+        block: BlockNumber,
+        /// The decoding error.
+        err: DepositEventError,
+    },
 }
 
 impl From<reth_nippy_jar::NippyJarError> for ProviderError {
@@ -5,7 +5,7 @@ use crate::p2p::{
     priority::Priority,
 };
 use futures::FutureExt;
-use reth_primitives::{BlockBody, B256};
+use reth_primitives::{BlockBody, RawBlockBody, B256};
 use std::fmt::{Debug, Formatter};
 use tokio::sync::oneshot;
 
@@ -43,7 +43,10 @@ where
         _priority: Priority,
     ) -> Self::Output {
         let (tx, rx) = oneshot::channel();
-        let _ = tx.send((self.responder)(hashes));
+        let response = (self.responder)(hashes).map(|response| {
+            response.map(|bodies| bodies.into_iter().map(RawBlockBody::from).collect())
+        });
+        let _ = tx.send(response);
         Box::pin(rx.map(|x| match x {
             Ok(value) => value,
             Err(err) => Err(err.into()),
@@ -7,8 +7,8 @@ use crate::p2p::{
 };
 use parking_lot::Mutex;
 use reth_primitives::{
-    BlockBody, BlockHashOrNumber, BlockNumHash, Header, HeadersDirection, PeerId, SealedBlock,
-    SealedHeader, WithPeerId, B256,
+    BlockBody, BlockHashOrNumber, BlockNumHash, Header, HeadersDirection, PeerId, RawBlockBody,
+    SealedBlock, SealedHeader, WithPeerId, B256,
 };
 use std::{collections::HashMap, sync::Arc};
 
@@ -40,7 +40,7 @@ impl DownloadClient for NoopFullBlockClient {
 /// Implements the `BodiesClient` trait for the `NoopFullBlockClient` struct.
 impl BodiesClient for NoopFullBlockClient {
     /// Defines the output type of the function.
-    type Output = futures::future::Ready<PeerRequestResult<Vec<BlockBody>>>;
+    type Output = futures::future::Ready<PeerRequestResult<Vec<RawBlockBody>>>;
 
     /// Retrieves block bodies based on provided hashes and priority.
     ///
@@ -206,7 +206,7 @@ impl HeadersClient for TestFullBlockClient {
 /// Implements the `BodiesClient` trait for the `TestFullBlockClient` struct.
 impl BodiesClient for TestFullBlockClient {
     /// Defines the output type of the function.
-    type Output = futures::future::Ready<PeerRequestResult<Vec<BlockBody>>>;
+    type Output = futures::future::Ready<PeerRequestResult<Vec<RawBlockBody>>>;
 
     /// Retrieves block bodies corresponding to provided hashes with a given priority.
     ///
@@ -232,7 +232,7 @@ impl BodiesClient for TestFullBlockClient {
             PeerId::random(),
             hashes
                 .iter()
-                .filter_map(|hash| bodies.get(hash).cloned())
+                .filter_map(|hash| bodies.get(hash).cloned().map(Into::into))
                 .take(self.soft_limit)
                 .collect(),
         )))
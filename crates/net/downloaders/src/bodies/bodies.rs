@@ -1,4 +1,4 @@
-use super::queue::BodiesRequestQueue;
+use super::{queue::BodiesRequestQueue, response::RawBlockResponse};
 use crate::{bodies::task::TaskDownloader, metrics::BodyDownloaderMetrics};
 use futures::Stream;
 use futures_util::StreamExt;
@@ -9,7 +9,6 @@ use reth_interfaces::{
         bodies::{
             client::BodiesClient,
             downloader::{BodyDownloader, BodyDownloaderResult},
-            response::BlockResponse,
         },
         error::{DownloadError, DownloadResult},
     },
@@ -59,7 +58,9 @@ pub struct BodiesDownloader<B: BodiesClient, Provider> {
     /// Buffered responses
     buffered_responses: BinaryHeap<OrderedBodiesResponse>,
     /// Queued body responses that can be returned for insertion into the database.
-    queued_bodies: Vec<BlockResponse>,
+    ///
+    /// These are decoded when they are returned.
+    queued_bodies: Vec<RawBlockResponse>,
     /// The bodies downloader metrics.
     metrics: BodyDownloaderMetrics,
 }
@@ -193,7 +194,7 @@ where
     }
 
     /// Queues bodies and sets the latest queued block number
-    fn queue_bodies(&mut self, bodies: Vec<BlockResponse>) {
+    fn queue_bodies(&mut self, bodies: Vec<RawBlockResponse>) {
         self.latest_queued_block_number = Some(bodies.last().expect("is not empty").block_number());
         self.queued_bodies.extend(bodies);
         self.metrics.queued_blocks.set(self.queued_bodies.len() as f64);
@@ -210,10 +211,10 @@ where
     }
 
     /// Adds a new response to the internal buffer
-    fn buffer_bodies_response(&mut self, response: Vec<BlockResponse>) {
+    fn buffer_bodies_response(&mut self, response: Vec<RawBlockResponse>) {
         // take into account capacity
-        let size = response.iter().map(BlockResponse::size).sum::<usize>() +
-            response.capacity() * mem::size_of::<BlockResponse>();
+        let size = response.iter().map(RawBlockResponse::size).sum::<usize>() +
+            response.capacity() * mem::size_of::<RawBlockResponse>();
 
         let response = OrderedBodiesResponse { resp: response, size };
         let response_len = response.len();
@@ -227,7 +228,7 @@ where
     }
 
     /// Returns a response if it's first block number matches the next expected.
-    fn try_next_buffered(&mut self) -> Option<Vec<BlockResponse>> {
+    fn try_next_buffered(&mut self) -> Option<Vec<RawBlockResponse>> {
         if let Some(next) = self.buffered_responses.peek() {
             let expected = self.next_expected_block_number();
             let next_block_range = next.block_range();
@@ -253,7 +254,7 @@ where
 
     /// Returns the next batch of block bodies that can be returned if we have enough buffered
     /// bodies
-    fn try_split_next_batch(&mut self) -> Option<Vec<BlockResponse>> {
+    fn try_split_next_batch(&mut self) -> Option<Vec<RawBlockResponse>> {
         if self.queued_bodies.len() >= self.stream_batch_size {
             let next_batch = self.queued_bodies.drain(..self.stream_batch_size).collect::<Vec<_>>();
             self.queued_bodies.shrink_to_fit();
@@ -263,6 +264,22 @@ where
         }
         None
     }
+
+    /// Decodes the bodies of the next batch and validates the blocks, before the batch is
+    /// returned for insertion into the database.
+    ///
+    /// Clears the downloader if any of the blocks is invalid.
+    fn decode_batch(&mut self, batch: Vec<RawBlockResponse>) -> BodyDownloaderResult {
+        let result = batch
+            .into_iter()
+            .map(|response| response.decode(self.consensus.as_ref()))
+            .collect::<DownloadResult<Vec<_>>>();
+        if let Err(error) = &result {
+            tracing::debug!(target: "downloaders::bodies", %error, "Invalid block in batch");
+            self.clear();
+        }
+        result
+    }
 }
 
 impl<B, Provider> BodiesDownloader<B, Provider>
@@ -349,7 +366,7 @@ where
         loop {
             // Yield next batch if ready
             if let Some(next_batch) = this.try_split_next_batch() {
-                return Poll::Ready(Some(Ok(next_batch)))
+                return Poll::Ready(Some(this.decode_batch(next_batch)))
             }
 
             // Poll requests
@@ -377,11 +394,7 @@ where
                 match this.next_headers_request() {
                     Ok(Some(request)) => {
                         this.metrics.in_flight_requests.increment(1.);
-                        this.in_progress_queue.push_new_request(
-                            Arc::clone(&this.client),
-                            Arc::clone(&this.consensus),
-                            request,
-                        );
+                        this.in_progress_queue.push_new_request(Arc::clone(&this.client), request);
                         new_request_submitted = true;
                     }
                     Ok(None) => break 'inner,
@@ -415,7 +428,7 @@ where
             this.queued_bodies.shrink_to_fit();
             this.metrics.total_flushed.increment(next_batch.len() as u64);
             this.metrics.queued_blocks.set(this.queued_bodies.len() as f64);
-            return Poll::Ready(Some(Ok(next_batch)))
+            return Poll::Ready(Some(this.decode_batch(next_batch)))
         }
 
         Poll::Pending
@@ -424,7 +437,7 @@ where
 
 #[derive(Debug)]
 struct OrderedBodiesResponse {
-    resp: Vec<BlockResponse>,
+    resp: Vec<RawBlockResponse>,
     /// The total size of the response in bytes
     size: usize,
 }
@@ -453,7 +466,7 @@ impl OrderedBodiesResponse {
 
     /// Returns the size of the response in bytes
     ///
-    /// See [BlockResponse::size]
+    /// See [RawBlockResponse::size]
     #[inline]
     fn size(&self) -> usize {
         self.size
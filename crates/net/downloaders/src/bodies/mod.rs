@@ -7,6 +7,7 @@ pub mod task;
 
 mod queue;
 mod request;
+mod response;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
@@ -1,14 +1,8 @@
-use super::request::BodiesRequestFuture;
+use super::{request::BodiesRequestFuture, response::RawBlockResponse};
 use crate::metrics::BodyDownloaderMetrics;
 use futures::{stream::FuturesUnordered, Stream};
 use futures_util::StreamExt;
-use reth_interfaces::{
-    consensus::Consensus,
-    p2p::{
-        bodies::{client::BodiesClient, response::BlockResponse},
-        error::DownloadResult,
-    },
-};
+use reth_interfaces::p2p::{bodies::client::BodiesClient, error::DownloadResult};
 use reth_primitives::{BlockNumber, SealedHeader};
 use std::{
     pin::Pin,
@@ -55,12 +49,7 @@ where
 
     /// Add new request to the queue.
     /// Expects a sorted list of headers.
-    pub(crate) fn push_new_request(
-        &mut self,
-        client: Arc<B>,
-        consensus: Arc<dyn Consensus>,
-        request: Vec<SealedHeader>,
-    ) {
+    pub(crate) fn push_new_request(&mut self, client: Arc<B>, request: Vec<SealedHeader>) {
         // Set last max requested block number
         self.last_requested_block_number = request
             .last()
@@ -70,9 +59,8 @@ where
             })
             .or(self.last_requested_block_number);
         // Create request and push into the queue.
-        self.inner.push(
-            BodiesRequestFuture::new(client, consensus, self.metrics.clone()).with_headers(request),
-        )
+        self.inner
+            .push(BodiesRequestFuture::new(client, self.metrics.clone()).with_headers(request))
     }
 }
 
@@ -80,7 +68,7 @@ impl<B> Stream for BodiesRequestQueue<B>
 where
     B: BodiesClient + 'static,
 {
-    type Item = DownloadResult<Vec<BlockResponse>>;
+    type Item = DownloadResult<Vec<RawBlockResponse>>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         self.get_mut().inner.poll_next_unpin(cx)
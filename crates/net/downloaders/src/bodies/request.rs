@@ -1,16 +1,13 @@
+use super::response::RawBlockResponse;
 use crate::metrics::{BodyDownloaderMetrics, ResponseMetrics};
 use futures::{Future, FutureExt};
-use reth_interfaces::{
-    consensus::{Consensus as ConsensusTrait, Consensus},
-    p2p::{
-        bodies::{client::BodiesClient, response::BlockResponse},
-        error::{DownloadError, DownloadResult},
-        priority::Priority,
-    },
-};
-use reth_primitives::{
-    BlockBody, GotExpected, PeerId, SealedBlock, SealedHeader, WithPeerId, B256,
+use reth_interfaces::p2p::{
+    bodies::client::BodiesClient,
+    error::{DownloadError, DownloadResult},
+    full_block::ensure_valid_body_response,
+    priority::Priority,
 };
+use reth_primitives::{GotExpected, PeerId, RawBlockBody, SealedHeader, WithPeerId, B256};
 use std::{
     collections::VecDeque,
     mem,
@@ -25,8 +22,10 @@ use std::{
 /// If the response arrived with insufficient number of bodies, the future
 /// will issue another request until all bodies are collected.
 ///
-/// It then proceeds to verify the downloaded bodies. In case of an validation error,
-/// the future will start over.
+/// It then proceeds to verify the downloaded bodies against the roots of their headers. In case
+/// of an validation error, the future will start over. The transactions of the bodies are not
+/// decoded, this is deferred until the blocks are handed out by the downloader, see
+/// [RawBlockResponse::decode].
 ///
 /// The future will filter out any empty headers (see [reth_primitives::Header::is_empty]) from the
 /// request. If [BodiesRequestFuture] was initialized with all empty headers, no request will be
@@ -40,7 +39,6 @@ use std::{
 /// and eventually disconnected.
 pub(crate) struct BodiesRequestFuture<B: BodiesClient> {
     client: Arc<B>,
-    consensus: Arc<dyn Consensus>,
     metrics: BodyDownloaderMetrics,
     /// Metrics for individual responses. This can be used to observe how the size (in bytes) of
     /// responses change while bodies are being downloaded.
@@ -48,7 +46,7 @@ pub(crate) struct BodiesRequestFuture<B: BodiesClient> {
     // Headers to download. The collection is shrunk as responses are buffered.
     pending_headers: VecDeque<SealedHeader>,
     /// Internal buffer for all blocks
-    buffer: Vec<BlockResponse>,
+    buffer: Vec<RawBlockResponse>,
     fut: Option<B::Output>,
     /// Tracks how many bodies we requested in the last request.
     last_request_len: Option<usize>,
@@ -59,14 +57,9 @@ where
     B: BodiesClient + 'static,
 {
     /// Returns an empty future. Use [BodiesRequestFuture::with_headers] to set the request.
-    pub(crate) fn new(
-        client: Arc<B>,
-        consensus: Arc<dyn Consensus>,
-        metrics: BodyDownloaderMetrics,
-    ) -> Self {
+    pub(crate) fn new(client: Arc<B>, metrics: BodyDownloaderMetrics) -> Self {
         Self {
             client,
-            consensus,
             metrics,
             response_metrics: Default::default(),
             pending_headers: Default::default(),
@@ -116,7 +109,7 @@ where
 
     /// Process block response.
     /// Returns an error if the response is invalid.
-    fn on_block_response(&mut self, response: WithPeerId<Vec<BlockBody>>) -> DownloadResult<()> {
+    fn on_block_response(&mut self, response: WithPeerId<Vec<RawBlockBody>>) -> DownloadResult<()> {
         let (peer_id, bodies) = response.split();
         let request_len = self.last_request_len.unwrap_or_default();
         let response_len = bodies.len();
@@ -159,12 +152,12 @@ where
     ///
     /// This method removes headers from the internal collection.
     /// If the response fails validation, then the header will be put back.
-    fn try_buffer_blocks(&mut self, bodies: Vec<BlockBody>) -> DownloadResult<()> {
+    fn try_buffer_blocks(&mut self, bodies: Vec<RawBlockBody>) -> DownloadResult<()> {
         let bodies_capacity = bodies.capacity();
         let bodies_len = bodies.len();
         let mut bodies = bodies.into_iter().peekable();
 
-        let mut total_size = bodies_capacity * mem::size_of::<BlockBody>();
+        let mut total_size = bodies_capacity * mem::size_of::<RawBlockBody>();
         while bodies.peek().is_some() {
             let next_header = match self.pending_headers.pop_front() {
                 Some(header) => header,
@@ -173,24 +166,22 @@ where
 
             if next_header.is_empty() {
                 // increment empty block body metric
-                total_size += mem::size_of::<BlockBody>();
-                self.buffer.push(BlockResponse::Empty(next_header));
+                total_size += mem::size_of::<RawBlockBody>();
+                self.buffer.push(RawBlockResponse::Empty(next_header));
             } else {
                 let next_body = bodies.next().unwrap();
 
                 // increment full block body metric
                 total_size += next_body.size();
 
-                let block = SealedBlock::new(next_header, next_body);
-
-                if let Err(error) = self.consensus.validate_block(&block) {
-                    // Body is invalid, put the header back and return an error
-                    let hash = block.hash();
-                    self.pending_headers.push_front(block.header);
+                if let Err(error) = ensure_valid_body_response(&next_header, &next_body) {
+                    // Body doesn't match the header, put the header back and return an error
+                    let hash = next_header.hash();
+                    self.pending_headers.push_front(next_header);
                     return Err(DownloadError::BodyValidation { hash, error: Box::new(error) })
                 }
 
-                self.buffer.push(BlockResponse::Full(block));
+                self.buffer.push(RawBlockResponse::Full(next_header, next_body));
             }
         }
 
@@ -206,7 +197,7 @@ impl<B> Future for BodiesRequestFuture<B>
 where
     B: BodiesClient + 'static,
 {
-    type Output = DownloadResult<Vec<BlockResponse>>;
+    type Output = DownloadResult<Vec<RawBlockResponse>>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
@@ -239,7 +230,7 @@ where
             // Buffer any empty headers
             while this.pending_headers.front().map(|h| h.is_empty()).unwrap_or_default() {
                 let header = this.pending_headers.pop_front().unwrap();
-                this.buffer.push(BlockResponse::Empty(header));
+                this.buffer.push(RawBlockResponse::Empty(header));
             }
         }
     }
@@ -261,16 +252,12 @@ mod tests {
         let headers = random_header_range(&mut rng, 0..20, B256::ZERO);
 
         let client = Arc::new(TestBodiesClient::default());
-        let fut = BodiesRequestFuture::new(
-            client.clone(),
-            Arc::new(TestConsensus::default()),
-            BodyDownloaderMetrics::default(),
-        )
-        .with_headers(headers.clone());
+        let fut = BodiesRequestFuture::new(client.clone(), BodyDownloaderMetrics::default())
+            .with_headers(headers.clone());
 
         assert_eq!(
             fut.await.unwrap(),
-            headers.into_iter().map(BlockResponse::Empty).collect::<Vec<_>>()
+            headers.into_iter().map(RawBlockResponse::Empty).collect::<Vec<_>>()
         );
         assert_eq!(client.times_requested(), 0);
     }
@@ -285,14 +272,18 @@ mod tests {
         let client = Arc::new(
             TestBodiesClient::default().with_bodies(bodies.clone()).with_max_batch_size(batch_size),
         );
-        let fut = BodiesRequestFuture::new(
-            client.clone(),
-            Arc::new(TestConsensus::default()),
-            BodyDownloaderMetrics::default(),
-        )
-        .with_headers(headers.clone());
-
-        assert_eq!(fut.await.unwrap(), zip_blocks(headers.iter(), &mut bodies));
+        let fut = BodiesRequestFuture::new(client.clone(), BodyDownloaderMetrics::default())
+            .with_headers(headers.clone());
+
+        let consensus = TestConsensus::default();
+        let responses = fut
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|response| response.decode(&consensus))
+            .collect::<DownloadResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(responses, zip_blocks(headers.iter(), &mut bodies));
         assert_eq!(
             client.times_requested(),
             // div_ceild
@@ -0,0 +1,63 @@
+use reth_interfaces::{
+    consensus::Consensus,
+    p2p::{
+        bodies::response::BlockResponse,
+        error::{DownloadError, DownloadResult},
+    },
+};
+use reth_primitives::{BlockNumber, RawBlockBody, SealedBlock, SealedHeader};
+
+/// A block response whose body has not been decoded yet.
+///
+/// The body was checked against the roots of the header when it was received. Its transactions
+/// are only decoded, and the block validated, once the response is handed out for insertion, see
+/// [RawBlockResponse::decode].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub(crate) enum RawBlockResponse {
+    /// Full block response (with transactions or ommers)
+    Full(SealedHeader, RawBlockBody),
+    /// The empty block response
+    Empty(SealedHeader),
+}
+
+impl RawBlockResponse {
+    /// Return the reference to the response header
+    pub(crate) fn header(&self) -> &SealedHeader {
+        match self {
+            RawBlockResponse::Full(header, _) => header,
+            RawBlockResponse::Empty(header) => header,
+        }
+    }
+
+    /// Calculates a heuristic for the in-memory size of the [RawBlockResponse].
+    #[inline]
+    pub(crate) fn size(&self) -> usize {
+        match self {
+            RawBlockResponse::Full(header, body) => header.size() + body.size(),
+            RawBlockResponse::Empty(header) => header.size(),
+        }
+    }
+
+    /// Return the block number
+    pub(crate) fn block_number(&self) -> BlockNumber {
+        self.header().number
+    }
+
+    /// Decodes the transactions of the body and validates the resulting block.
+    pub(crate) fn decode(self, consensus: &dyn Consensus) -> DownloadResult<BlockResponse> {
+        match self {
+            RawBlockResponse::Full(header, body) => {
+                let hash = header.hash();
+                let body = body
+                    .try_into_body()
+                    .map_err(|error| DownloadError::BodyDecoding { hash, error })?;
+                let block = SealedBlock::new(header, body);
+                consensus.validate_block(&block).map_err(|error| {
+                    DownloadError::BodyValidation { hash, error: Box::new(error) }
+                })?;
+                Ok(BlockResponse::Full(block))
+            }
+            RawBlockResponse::Empty(header) => Ok(BlockResponse::Empty(header)),
+        }
+    }
+}
@@ -8,7 +8,8 @@ use reth_interfaces::p2p::{
     priority::Priority,
 };
 use reth_primitives::{
-    BlockBody, BlockHash, BlockHashOrNumber, BlockNumber, Header, HeadersDirection, PeerId, B256,
+    BlockBody, BlockHash, BlockHashOrNumber, BlockNumber, Header, HeadersDirection, PeerId,
+    RawBlockBody, B256,
 };
 use std::{collections::HashMap, path::Path};
 use thiserror::Error;
@@ -98,9 +99,28 @@ impl FileClient {
         Ok(Self { headers, hash_to_number, bodies })
     }
 
+    /// Create a new file client from blocks that were already decoded, e.g. from another client's
+    /// database.
+    pub fn from_blocks(blocks: impl IntoIterator<Item = (Header, BlockBody)>) -> Self {
+        let mut headers = HashMap::new();
+        let mut hash_to_number = HashMap::new();
+        let mut bodies = HashMap::new();
+
+        for (header, body) in blocks {
+            let block_hash = header.hash_slow();
+            hash_to_number.insert(block_hash, header.number);
+            bodies.insert(block_hash, body);
+            headers.insert(header.number, header);
+        }
+
+        trace!(blocks = headers.len(), "Initialized file client");
+
+        Self { headers, hash_to_number, bodies }
+    }
+
     /// Get the tip hash of the chain.
     pub fn tip(&self) -> Option<B256> {
-        self.headers.get(&(self.headers.len() as u64)).map(|h| h.hash_slow())
+        self.max_block().and_then(|number| self.headers.get(&number)).map(|h| h.hash_slow())
     }
 
     /// Returns the highest block number of this client has or `None` if empty
@@ -207,7 +227,7 @@ impl BodiesClient for FileClient {
         // could unwrap here
         for hash in hashes {
             match self.bodies.get(&hash).cloned() {
-                Some(body) => bodies.push(body),
+                Some(body) => bodies.push(RawBlockBody::from(body)),
                 None => return Box::pin(async move { Err(RequestError::BadResponse) }),
             }
         }
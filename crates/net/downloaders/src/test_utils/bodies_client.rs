@@ -3,7 +3,7 @@ use reth_interfaces::p2p::{
     download::DownloadClient,
     priority::Priority,
 };
-use reth_primitives::{BlockBody, PeerId, B256};
+use reth_primitives::{BlockBody, PeerId, RawBlockBody, B256};
 use std::{
     collections::HashMap,
     fmt::Debug,
@@ -106,9 +106,11 @@ impl BodiesClient for TestBodiesClient {
                     .into_iter()
                     .take(max_batch_size.unwrap_or(usize::MAX))
                     .map(|hash| {
-                        bodies
-                            .remove(&hash)
-                            .expect("Downloader asked for a block it should not ask for")
+                        RawBlockBody::from(
+                            bodies
+                                .remove(&hash)
+                                .expect("Downloader asked for a block it should not ask for"),
+                        )
                     })
                     .collect(),
             )
@@ -3,7 +3,7 @@
 
 use alloy_rlp::{RlpDecodable, RlpDecodableWrapper, RlpEncodable, RlpEncodableWrapper};
 use reth_codecs::derive_arbitrary;
-use reth_primitives::{BlockBody, BlockHashOrNumber, Header, HeadersDirection, B256};
+use reth_primitives::{BlockBody, BlockHashOrNumber, Header, HeadersDirection, RawBlockBody, B256};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -69,6 +69,8 @@ impl From<Vec<B256>> for GetBlockBodies {
 
 /// The response to [`GetBlockBodies`], containing the block bodies that the peer knows about if
 /// any were found.
+///
+/// The transactions of the bodies are not decoded, see [`RawBlockBody`].
 #[derive_arbitrary(rlp, 16)]
 #[derive(Clone, Debug, PartialEq, Eq, RlpEncodableWrapper, RlpDecodableWrapper, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -77,15 +79,21 @@ pub struct BlockBodies(
     #[cfg_attr(
         any(test, feature = "arbitrary"),
         proptest(
-            strategy = "proptest::collection::vec(proptest::arbitrary::any::<BlockBody>(), 0..=20)"
+            strategy = "proptest::collection::vec(proptest::arbitrary::any::<RawBlockBody>(), 0..=20)"
         )
     )]
-    pub Vec<BlockBody>,
+    pub Vec<RawBlockBody>,
 );
 
+impl From<Vec<RawBlockBody>> for BlockBodies {
+    fn from(bodies: Vec<RawBlockBody>) -> Self {
+        BlockBodies(bodies)
+    }
+}
+
 impl From<Vec<BlockBody>> for BlockBodies {
     fn from(bodies: Vec<BlockBody>) -> Self {
-        BlockBodies(bodies)
+        BlockBodies(bodies.into_iter().map(Into::into).collect())
     }
 }
 
@@ -340,7 +348,7 @@ mod tests {
         let mut data = vec![];
         let request = RequestPair::<BlockBodies> {
             request_id: 1111,
-            message: BlockBodies(vec![
+            message: BlockBodies::from(vec![
                 BlockBody {
                     transactions: vec![
                         TransactionSigned::from_transaction_and_signature(Transaction::Legacy(TxLegacy {
@@ -411,7 +419,7 @@ mod tests {
         let data = hex!("f902dc820457f902d6f902d3f8d2f867088504a817c8088302e2489435353535353535353535353535353535353535358202008025a064b1702d9298fee62dfeccc57d322a463ad55ca201256d01f62b45b2e1c21c12a064b1702d9298fee62dfeccc57d322a463ad55ca201256d01f62b45b2e1c21c10f867098504a817c809830334509435353535353535353535353535353535353535358202d98025a052f8f61201b2b11a78d6e866abc9c3db2ae8631fa656bfe5cb53668255367afba052f8f61201b2b11a78d6e866abc9c3db2ae8631fa656bfe5cb53668255367afbf901fcf901f9a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000000940000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000000b90100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008208ae820d0582115c8215b3821a0a827788a00000000000000000000000000000000000000000000000000000000000000000880000000000000000");
         let expected = RequestPair::<BlockBodies> {
             request_id: 1111,
-            message: BlockBodies(vec![
+            message: BlockBodies::from(vec![
                 BlockBody {
                     transactions: vec![
                         TransactionSigned::from_transaction_and_signature(Transaction::Legacy(
@@ -2,7 +2,9 @@ use crate::{EthVersion, StatusBuilder};
 use alloy_chains::{Chain, NamedChain};
 use alloy_rlp::{RlpDecodable, RlpEncodable};
 use reth_codecs::derive_arbitrary;
-use reth_primitives::{hex, ChainSpec, ForkId, Genesis, Hardfork, Head, B256, MAINNET, U256};
+use reth_primitives::{
+    hex, ChainSpec, ForkId, Genesis, GenesisConfigError, Hardfork, Head, B256, MAINNET, U256,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display};
@@ -41,20 +43,22 @@ pub struct Status {
     pub forkid: ForkId,
 }
 
-impl From<Genesis> for Status {
-    fn from(genesis: Genesis) -> Status {
+impl TryFrom<Genesis> for Status {
+    type Error = GenesisConfigError;
+
+    fn try_from(genesis: Genesis) -> Result<Status, Self::Error> {
         let chain = genesis.config.chain_id;
         let total_difficulty = genesis.difficulty;
-        let chainspec = ChainSpec::from(genesis);
+        let chainspec = ChainSpec::try_from(genesis)?;
 
-        Status {
+        Ok(Status {
             version: EthVersion::Eth68 as u8,
             chain: Chain::from_id(chain),
             total_difficulty,
             blockhash: chainspec.genesis_hash(),
             genesis: chainspec.genesis_hash(),
             forkid: chainspec.fork_id(&Head::default()),
-        }
+        })
     }
 }
 
@@ -486,7 +486,7 @@ impl NetworkConfigBuilder {
             dns_discovery_config.as_mut().and_then(|c| c.bootstrap_dns_networks.as_mut())
         {
             if dns_networks.is_empty() {
-                if let Some(link) = chain_spec.chain().public_dns_network_protocol() {
+                if let Some(link) = chain_spec.dns_discovery_tree() {
                     dns_networks.insert(link.parse().expect("is valid DNS link entry"));
                 }
             }
@@ -573,7 +573,7 @@ mod tests {
         let mut chain_spec = Arc::clone(&MAINNET);
 
         // remove any `next` fields we would have by removing all hardforks
-        Arc::make_mut(&mut chain_spec).hardforks = BTreeMap::new();
+        Arc::make_mut(&mut chain_spec).set_hardforks(BTreeMap::new());
 
         // check that the forkid is initialized with the genesis and no other forks
         let genesis_fork_hash = ForkHash::from(chain_spec.genesis_hash());
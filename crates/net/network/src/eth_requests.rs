@@ -174,7 +174,7 @@ where
                     withdrawals: block.withdrawals,
                 };
 
-                bodies.push(body);
+                bodies.push(body.into());
                 if bodies.len() >= MAX_BODIES_SERVE {
                     break
                 }
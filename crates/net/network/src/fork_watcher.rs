@@ -0,0 +1,94 @@
+//! Tracks the [ForkId] of the local node while the canonical head advances.
+
+use futures::Stream;
+use pin_project::pin_project;
+use reth_primitives::{ChainSpec, ForkFilter, ForkId, ForkTransition, Head};
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+/// A stream of [ForkTransition]s, derived from a stream of canonical head updates.
+///
+/// Every head is applied to a [ForkFilter] built from the [ChainSpec], and a [ForkTransition] is
+/// yielded whenever the head crosses a fork activation boundary, so that the advertised [ForkId]
+/// can be updated, see also [NetworkHandle::update_status](crate::NetworkHandle::update_status).
+#[derive(Debug)]
+#[pin_project]
+pub struct ForkFilterWatcher<St> {
+    /// The stream of canonical head updates.
+    #[pin]
+    heads: St,
+    /// Tracks the active fork for the latest head.
+    filter: ForkFilter,
+    /// The latest head.
+    head: Head,
+}
+
+impl<St> ForkFilterWatcher<St> {
+    /// Creates a new watcher that starts at the given head.
+    pub fn new(chain_spec: &ChainSpec, head: Head, heads: St) -> Self {
+        Self { heads, filter: chain_spec.fork_filter(head), head }
+    }
+
+    /// Returns the latest head.
+    pub fn head(&self) -> Head {
+        self.head
+    }
+
+    /// Returns the [ForkId] that is active at the latest head.
+    pub fn current(&self) -> ForkId {
+        self.filter.current()
+    }
+
+    /// Returns the [ForkFilter] of the latest head.
+    pub fn fork_filter(&self) -> &ForkFilter {
+        &self.filter
+    }
+}
+
+impl<St> Stream for ForkFilterWatcher<St>
+where
+    St: Stream<Item = Head>,
+{
+    type Item = ForkTransition;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(head) = ready!(this.heads.as_mut().poll_next(cx)) else {
+                return Poll::Ready(None)
+            };
+            *this.head = head;
+            if let Some(transition) = this.filter.set_head(head) {
+                return Poll::Ready(Some(transition))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use reth_primitives::{Hardfork, MAINNET};
+
+    #[tokio::test]
+    async fn yields_transitions_at_activation_boundaries() {
+        let london = MAINNET.fork(Hardfork::London).as_block().unwrap();
+        let heads = [london - 2, london - 1, london, london + 1]
+            .map(|number| Head { number, ..Default::default() });
+        let start = Head { number: london - 3, ..Default::default() };
+
+        let mut watcher = ForkFilterWatcher::new(&MAINNET, start, futures::stream::iter(heads));
+        let before = watcher.current();
+
+        let transition = watcher.next().await.unwrap();
+        assert_eq!(transition.past, before);
+        assert_eq!(transition.current, MAINNET.fork_id(&Head { number: london, ..start }));
+        assert_eq!(watcher.head().number, london);
+
+        assert!(watcher.next().await.is_none());
+        assert_eq!(watcher.head().number, london + 1);
+    }
+}
@@ -122,6 +122,7 @@ pub mod error;
 pub mod eth_requests;
 mod fetch;
 mod flattened_response;
+mod fork_watcher;
 pub mod import;
 mod listener;
 mod manager;
@@ -139,6 +140,7 @@ pub use builder::NetworkBuilder;
 pub use config::{NetworkConfig, NetworkConfigBuilder};
 pub use discovery::{Discovery, DiscoveryEvent};
 pub use fetch::FetchClient;
+pub use fork_watcher::ForkFilterWatcher;
 pub use manager::{NetworkEvent, NetworkManager};
 pub use message::PeerRequest;
 pub use network::{NetworkEvents, NetworkHandle, NetworkProtocols};
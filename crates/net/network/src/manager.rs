@@ -579,6 +579,10 @@ where
                     self.swarm.state_mut().update_fork_id(transition.current);
                 }
             }
+            NetworkHandleMessage::ForkFilterUpdate(fork_filter) => {
+                let fork_id = self.swarm.sessions_mut().on_fork_filter_update(fork_filter);
+                self.swarm.state_mut().update_fork_id(fork_id);
+            }
             NetworkHandleMessage::GetPeerInfos(tx) => {
                 let _ = tx.send(self.swarm.sessions_mut().get_peer_info());
             }
@@ -12,7 +12,7 @@ use reth_eth_wire::{
 };
 use reth_interfaces::p2p::error::{RequestError, RequestResult};
 use reth_primitives::{
-    BlockBody, Bytes, Header, PeerId, PooledTransactionsElement, ReceiptWithBloom, B256,
+    Bytes, Header, PeerId, PooledTransactionsElement, RawBlockBody, ReceiptWithBloom, B256,
 };
 use std::{
     fmt,
@@ -246,7 +246,7 @@ pub enum PeerResponseResult {
     /// Represents a result containing block headers or an error.
     BlockHeaders(RequestResult<Vec<Header>>),
     /// Represents a result containing block bodies or an error.
-    BlockBodies(RequestResult<Vec<BlockBody>>),
+    BlockBodies(RequestResult<Vec<RawBlockBody>>),
     /// Represents a result containing pooled transactions or an error.
     PooledTransactions(RequestResult<Vec<PooledTransactionsElement>>),
     /// Represents a result containing node data or an error.
@@ -10,7 +10,7 @@ use reth_network_api::{
     NetworkError, NetworkInfo, PeerInfo, PeerKind, Peers, PeersInfo, Reputation,
     ReputationChangeKind,
 };
-use reth_primitives::{Head, NodeRecord, PeerId, TransactionSigned, B256};
+use reth_primitives::{ForkFilter, Head, NodeRecord, PeerId, TransactionSigned, B256};
 use reth_rpc_types::NetworkStatus;
 use secp256k1::SecretKey;
 use std::{
@@ -109,6 +109,15 @@ impl NetworkHandle {
         self.send_message(NetworkHandleMessage::StatusUpdate { head });
     }
 
+    /// Replaces the [ForkFilter] of the node, e.g. after the schedule of forks that are not active
+    /// yet was changed.
+    ///
+    /// The filter must be built for the current head, its [ForkId](reth_primitives::ForkId) is
+    /// advertised in the `Status` of new sessions and in the discovery ENR.
+    pub fn update_fork_filter(&self, fork_filter: ForkFilter) {
+        self.send_message(NetworkHandleMessage::ForkFilterUpdate(fork_filter));
+    }
+
     /// Announce a block over devp2p
     ///
     /// Caution: in PoS this is a noop because new blocks are no longer announced over devp2p.
@@ -434,6 +443,8 @@ pub(crate) enum NetworkHandleMessage {
         /// The head status to apply.
         head: Head,
     },
+    /// Replaces the fork filter of the node.
+    ForkFilterUpdate(ForkFilter),
     /// Retrieves the current status via a oneshot sender.
     GetStatus(oneshot::Sender<NetworkStatus>),
     /// Gets `PeerInfo` for the specified peer IDs.
@@ -214,6 +214,16 @@ impl SessionManager {
         transition
     }
 
+    /// Invoked on a replaced [ForkFilter].
+    ///
+    /// Sessions that are established from now on are validated against the new filter. Returns
+    /// the [ForkId] of the new filter.
+    pub(crate) fn on_fork_filter_update(&mut self, fork_filter: ForkFilter) -> ForkId {
+        self.fork_filter = fork_filter;
+        self.status.forkid = self.fork_filter.current();
+        self.status.forkid
+    }
+
     /// An incoming TCP connection was received. This starts the authentication process to turn this
     /// stream into an active peer session.
     ///
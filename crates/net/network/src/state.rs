@@ -528,7 +528,7 @@ mod tests {
         BlockBodies, EthVersion,
     };
     use reth_interfaces::p2p::{bodies::client::BodiesClient, error::RequestError};
-    use reth_primitives::{BlockBody, Header, PeerId, B256};
+    use reth_primitives::{BlockBody, Header, PeerId, RawBlockBody, B256};
     use reth_provider::test_utils::NoopProvider;
     use std::{
         future::poll_fn,
@@ -586,7 +586,7 @@ mod tests {
             let resp = stream.next().await.unwrap();
             match resp {
                 PeerRequest::GetBlockBodies { response, .. } => {
-                    response.send(Ok(BlockBodies(vec![body_response]))).unwrap();
+                    response.send(Ok(BlockBodies::from(vec![body_response]))).unwrap();
                 }
                 _ => unreachable!(),
             }
@@ -605,7 +605,7 @@ mod tests {
         // send requests to the state via the client
         let (peer, bodies) = client.get_block_bodies(vec![B256::random()]).await.unwrap().split();
         assert_eq!(peer, peer_id);
-        assert_eq!(bodies, vec![body]);
+        assert_eq!(bodies, vec![RawBlockBody::from(body)]);
 
         let resp = client.get_block_bodies(vec![B256::random()]).await;
         assert!(resp.is_err());
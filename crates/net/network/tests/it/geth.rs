@@ -98,9 +98,10 @@ async fn init_geth() -> (CliqueGethInstance, Arc<ChainSpec>) {
     // === check that we have the same genesis hash ===
 
     // get the chainspec from the genesis we configured for geth
-    let chainspec = ChainSpec::from(
+    let chainspec = ChainSpec::try_from(
         clique.instance.genesis().clone().expect("clique should be configured with a genesis"),
-    );
+    )
+    .unwrap();
 
     let remote_genesis = SealedHeader::from(&clique.provider.remote_genesis_block().await.unwrap());
 
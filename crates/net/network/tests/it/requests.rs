@@ -12,8 +12,8 @@ use reth_network::{
 };
 use reth_network_api::{NetworkInfo, Peers};
 use reth_primitives::{
-    Block, BlockBody, Bytes, Header, HeadersDirection, Signature, Transaction, TransactionKind,
-    TransactionSigned, TxEip2930, U256,
+    Block, BlockBody, Bytes, Header, HeadersDirection, RawBlockBody, Signature, Transaction,
+    TransactionKind, TransactionSigned, TxEip2930, U256,
 };
 use reth_provider::test_utils::MockEthProvider;
 use std::sync::Arc;
@@ -75,7 +75,7 @@ async fn test_get_body() {
         assert_eq!(blocks.len(), 1);
         let expected =
             BlockBody { transactions: block.body, ommers: block.ommers, withdrawals: None };
-        assert_eq!(blocks[0], expected);
+        assert_eq!(blocks[0], RawBlockBody::from(expected));
     }
 }
 
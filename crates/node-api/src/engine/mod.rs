@@ -43,55 +43,17 @@ pub trait EngineTypes:
 /// Validates the timestamp depending on the version called:
 ///
 /// * If V2, this ensure that the payload timestamp is pre-Cancun.
-/// * If V3, this ensures that the payload timestamp is within the Cancun timestamp.
+/// * If V3, this ensures that the payload timestamp is post-Cancun.
 ///
 /// Otherwise, this will return [AttributesValidationError::UnsupportedFork].
+///
+/// See also [EngineApiMessageVersion::is_supported_at_timestamp].
 pub fn validate_payload_timestamp(
     chain_spec: &ChainSpec,
     version: EngineApiMessageVersion,
     timestamp: u64,
 ) -> Result<(), AttributesValidationError> {
-    let is_cancun = chain_spec.is_cancun_active_at_timestamp(timestamp);
-    if version == EngineApiMessageVersion::V2 && is_cancun {
-        // From the Engine API spec:
-        //
-        // ### Update the methods of previous forks
-        //
-        // This document defines how Cancun payload should be handled by the [`Shanghai
-        // API`](https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/shanghai.md).
-        //
-        // For the following methods:
-        //
-        // - [`engine_forkchoiceUpdatedV2`](https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/shanghai.md#engine_forkchoiceupdatedv2)
-        // - [`engine_newPayloadV2`](https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/shanghai.md#engine_newpayloadV2)
-        // - [`engine_getPayloadV2`](https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/shanghai.md#engine_getpayloadv2)
-        //
-        // a validation **MUST** be added:
-        //
-        // 1. Client software **MUST** return `-38005: Unsupported fork` error if the `timestamp` of
-        //    payload or payloadAttributes is greater or equal to the Cancun activation timestamp.
-        return Err(AttributesValidationError::UnsupportedFork)
-    }
-
-    if version == EngineApiMessageVersion::V3 && !is_cancun {
-        // From the Engine API spec:
-        // <https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/cancun.md#specification-2>
-        //
-        // For `engine_getPayloadV3`:
-        //
-        // 1. Client software **MUST** return `-38005: Unsupported fork` error if the `timestamp` of
-        //    the built payload does not fall within the time frame of the Cancun fork.
-        //
-        // For `engine_forkchoiceUpdatedV3`:
-        //
-        // 2. Client software **MUST** return `-38005: Unsupported fork` error if the
-        //    `payloadAttributes` is set and the `payloadAttributes.timestamp` does not fall within
-        //    the time frame of the Cancun fork.
-        //
-        // For `engine_newPayloadV3`:
-        //
-        // 2. Client software **MUST** return `-38005: Unsupported fork` error if the `timestamp` of
-        //    the payload does not fall within the time frame of the Cancun fork.
+    if !version.is_supported_at_timestamp(chain_spec, timestamp) {
         return Err(AttributesValidationError::UnsupportedFork)
     }
     Ok(())
@@ -237,3 +199,101 @@ pub enum EngineApiMessageVersion {
     /// Added for cancun hardfork.
     V3,
 }
+
+impl EngineApiMessageVersion {
+    /// Returns `true` if the methods of this version may be called with a payload or payload
+    /// attributes of the given timestamp, according to the forks active at the timestamp.
+    ///
+    /// If this returns `false`, the Engine API spec requires the method to fail with `-38005:
+    /// Unsupported fork`.
+    ///
+    /// V1 methods are not gated by timestamp, instead post-Shanghai payloads are rejected as
+    /// invalid params because they have withdrawals, see [validate_withdrawals_presence].
+    pub fn is_supported_at_timestamp(&self, chain_spec: &ChainSpec, timestamp: u64) -> bool {
+        match self {
+            Self::V1 => true,
+            // From the Engine API spec:
+            //
+            // ### Update the methods of previous forks
+            //
+            // This document defines how Cancun payload should be handled by the [`Shanghai
+            // API`](https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/shanghai.md).
+            //
+            // For the following methods:
+            //
+            // - [`engine_forkchoiceUpdatedV2`](https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/shanghai.md#engine_forkchoiceupdatedv2)
+            // - [`engine_newPayloadV2`](https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/shanghai.md#engine_newpayloadV2)
+            // - [`engine_getPayloadV2`](https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/shanghai.md#engine_getpayloadv2)
+            //
+            // a validation **MUST** be added:
+            //
+            // 1. Client software **MUST** return `-38005: Unsupported fork` error if the
+            //    `timestamp` of payload or payloadAttributes is greater or equal to the Cancun
+            //    activation timestamp.
+            Self::V2 => !chain_spec.is_cancun_active_at_timestamp(timestamp),
+            // From the Engine API spec:
+            // <https://github.com/ethereum/execution-apis/blob/ff43500e653abde45aec0f545564abfb648317af/src/engine/cancun.md#specification-2>
+            //
+            // For `engine_getPayloadV3`:
+            //
+            // 1. Client software **MUST** return `-38005: Unsupported fork` error if the
+            //    `timestamp` of the built payload does not fall within the time frame of the Cancun
+            //    fork.
+            //
+            // For `engine_forkchoiceUpdatedV3`:
+            //
+            // 2. Client software **MUST** return `-38005: Unsupported fork` error if the
+            //    `payloadAttributes` is set and the `payloadAttributes.timestamp` does not fall
+            //    within the time frame of the Cancun fork.
+            //
+            // For `engine_newPayloadV3`:
+            //
+            // 2. Client software **MUST** return `-38005: Unsupported fork` error if the
+            //    `timestamp` of the payload does not fall within the time frame of the Cancun fork.
+            //
+            // The time frame of the Cancun fork ends with the activation of Prague, but V3 is
+            // the latest version until the Prague methods are added.
+            Self::V3 => chain_spec.is_cancun_active_at_timestamp(timestamp),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{ChainSpecBuilder, ForkCondition, Genesis};
+
+    #[test]
+    fn version_supported_at_fork_boundaries() {
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .genesis(Genesis::default())
+            .paris_activated()
+            .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(1000))
+            .with_fork(Hardfork::Cancun, ForkCondition::Timestamp(2000))
+            .with_fork(Hardfork::Prague, ForkCondition::Timestamp(3000))
+            .build();
+
+        // (timestamp, V1, V2, V3)
+        for (timestamp, v1, v2, v3) in [
+            (999, true, true, false),
+            (1000, true, true, false),
+            (1999, true, true, false),
+            (2000, true, false, true),
+            (2999, true, false, true),
+            (3000, true, false, true),
+        ] {
+            assert_eq!(
+                EngineApiMessageVersion::V1.is_supported_at_timestamp(&chain_spec, timestamp),
+                v1
+            );
+            assert_eq!(
+                EngineApiMessageVersion::V2.is_supported_at_timestamp(&chain_spec, timestamp),
+                v2
+            );
+            assert_eq!(
+                EngineApiMessageVersion::V3.is_supported_at_timestamp(&chain_spec, timestamp),
+                v3
+            );
+        }
+    }
+}
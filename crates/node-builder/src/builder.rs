@@ -12,12 +12,12 @@ use crate::{
     NodeHandle,
 };
 use eyre::Context;
-use futures::{future::Either, stream, stream_select, StreamExt};
+use futures::{future, future::Either, stream, stream_select, StreamExt};
 use reth_beacon_consensus::{
     hooks::{EngineHooks, PruneHook},
     BeaconConsensusEngine,
 };
-use reth_blockchain_tree::{BlockchainTreeConfig, ShareableBlockchainTree};
+use reth_blockchain_tree::ShareableBlockchainTree;
 use reth_db::{
     database::Database,
     database_metrics::{DatabaseMetadata, DatabaseMetrics},
@@ -25,23 +25,27 @@ use reth_db::{
 use reth_interfaces::p2p::either::EitherDownloader;
 use reth_network::{
     transactions::{TransactionFetcherConfig, TransactionsManagerConfig},
-    NetworkBuilder, NetworkEvents, NetworkHandle,
+    ForkFilterWatcher, NetworkBuilder, NetworkEvents, NetworkHandle,
 };
+use reth_network_api::NetworkInfo;
 use reth_node_core::{
     cli::config::{PayloadBuilderConfig, RethRpcConfig, RethTransactionPoolConfig},
+    datadir_version::{check_datadir_version, record_datadir_version},
     dirs::{ChainPath, DataDirPath},
     events::cl::ConsensusLayerHealthEvents,
     exit::NodeExitFuture,
-    init::init_genesis,
-    node_config::NodeConfig,
+    fork_schedule::ForkScheduleWatcher,
+    init::{init_genesis, load_paris_block},
+    logs_bloom_index::LogsBloomIndexer,
+    node_config::{NodeConfig, NodeListenAddrs},
     primitives::{kzg::KzgSettings, Head},
     utils::write_peers_to_file,
 };
-use reth_primitives::{
-    constants::eip4844::{LoadKzgSettingsError, MAINNET_KZG_TRUSTED_SETUP},
-    ChainSpec,
+use reth_primitives::{constants::eip4844::LoadKzgSettingsError, ChainSpec};
+use reth_provider::{
+    providers::BlockchainProvider, CanonStateSubscriptions, ChainSpecProvider, HeaderProvider,
+    ProviderFactory,
 };
-use reth_provider::{providers::BlockchainProvider, ChainSpecProvider, ProviderFactory};
 use reth_prune::{PrunerBuilder, PrunerEvent};
 use reth_revm::EvmProcessorFactory;
 use reth_rpc_engine_api::EngineApi;
@@ -336,7 +340,7 @@ where
         let reth_config = self.load_config(&data_dir)?;
 
         let Self {
-            config,
+            mut config,
             state: ComponentsState { types, components_builder, hooks, rpc },
             database,
         } = self;
@@ -345,11 +349,24 @@ where
         // Does not do anything on windows.
         fdlimit::raise_fd_limit()?;
 
+        // Fail early on an environment the node can't run in.
+        config.run_preflight_checks(&data_dir)?;
+
+        // The database is already open, refuse to write to it if it was written by a newer
+        // version.
+        check_datadir_version(&data_dir.data_dir_path(), config.allow_downgrade)?;
+        record_datadir_version(&data_dir.data_dir_path())?;
+
         let prometheus_handle = config.install_prometheus_recorder()?;
-        config.start_metrics_endpoint(prometheus_handle, database.clone()).await?;
+        let metrics_addr =
+            config.start_metrics_endpoint(prometheus_handle, database.clone()).await?;
 
         info!(target: "reth::cli", "Database opened");
 
+        // the chain spec of a custom chain may not know its Paris block, apply the one observed on
+        // previous runs
+        config.chain = load_paris_block(database.clone(), config.chain.clone())?;
+
         let mut provider_factory =
             ProviderFactory::new(database.clone(), Arc::clone(&config.chain));
 
@@ -357,12 +374,18 @@ where
         let snapshotter = reth_snapshot::Snapshotter::new(
             provider_factory.clone(),
             data_dir.snapshots_path(),
-            config.chain.snapshot_block_interval,
+            config.chain.snapshot_config.clone(),
         )?;
 
         provider_factory = provider_factory
             .with_snapshots(data_dir.snapshots_path(), snapshotter.highest_snapshot_receiver())?;
 
+        if !config.rpc.rpc_disable_logs_bloom_index {
+            debug!(target: "reth::cli", "Spawning logs bloom indexer task");
+            let indexer = LogsBloomIndexer::new(provider_factory.clone());
+            executor.spawn_critical_blocking("logs bloom indexer", indexer.run());
+        }
+
         debug!(target: "reth::cli", chain=%config.chain.chain, genesis=?config.chain.genesis_hash(), "Initializing genesis");
 
         let genesis_hash = init_genesis(database.clone(), config.chain.clone())?;
@@ -376,17 +399,22 @@ where
         let sync_metrics_listener = reth_stages::MetricsListener::new(sync_metrics_rx);
         executor.spawn_critical("stages metrics listener task", sync_metrics_listener);
 
-        let prune_config = config.prune_config()?.or(reth_config.prune.clone());
+        let prune_config = config.prune_config()?.or(reth_config.prune.clone()).map(|mut prune| {
+            prune.segments = prune.segments.with_deposit_contract_guard(&config.chain);
+            prune
+        });
 
         let evm_config = types.evm_config();
-        let tree_config = BlockchainTreeConfig::default();
+        // the executors of the tree and the pipeline share the chain spec of the factory
+        let executor_factory = EvmProcessorFactory::new(config.chain.clone(), evm_config.clone());
+        let tree_config = config.tree_config();
         let tree = config.build_blockchain_tree(
             provider_factory.clone(),
             consensus.clone(),
             prune_config.clone(),
             sync_metrics_tx.clone(),
             tree_config,
-            evm_config.clone(),
+            executor_factory.clone(),
         )?;
 
         let canon_state_notification_sender = tree.canon_state_notification_sender();
@@ -396,6 +424,9 @@ where
         // fetch the head block from the database
         let head =
             config.lookup_head(provider_factory.clone()).wrap_err("the head block is missing")?;
+        if let Some((fork, condition)) = config.chain.next_fork_after(&head) {
+            info!(target: "reth::cli", ?fork, ?condition, "Next hardfork scheduled");
+        }
 
         // setup the blockchain provider
         let blockchain_db =
@@ -436,6 +467,49 @@ where
         debug!(target: "reth::cli", "calling on_component_initialized hook");
         on_component_initialized.on_event(node_components.clone())?;
 
+        // keep the advertised fork id up to date when the canonical head crosses a fork boundary
+        let heads = blockchain_db.canonical_state_stream().filter_map({
+            let provider = blockchain_db.clone();
+            move |notification| {
+                let tip = notification.tip();
+                let head = provider.header_td_by_number(tip.number).ok().flatten().map(
+                    |total_difficulty| Head {
+                        number: tip.number,
+                        hash: tip.hash(),
+                        difficulty: tip.difficulty,
+                        total_difficulty,
+                        timestamp: tip.timestamp,
+                    },
+                );
+                future::ready(head)
+            }
+        });
+        let mut fork_watcher = ForkFilterWatcher::new(&config.chain, head, heads);
+        let fork_network = network.clone();
+        executor.spawn(Box::pin(async move {
+            while let Some(transition) = fork_watcher.next().await {
+                info!(
+                    target: "reth::cli",
+                    past=?transition.past,
+                    current=?transition.current,
+                    "Fork id transition"
+                );
+                fork_network.update_status(fork_watcher.head());
+            }
+        }));
+
+        if let Some(path) = config.watch_chain_file.clone() {
+            debug!(target: "reth::cli", path=%path.display(), "Spawning fork schedule watcher task");
+            let watcher = ForkScheduleWatcher::new(
+                path,
+                config.chain.clone(),
+                blockchain_db.clone(),
+                network.clone(),
+                executor_factory.clone(),
+            );
+            executor.spawn_blocking(watcher.run());
+        }
+
         // create pipeline
         let network_client = network.fetch_client().await?;
         let (consensus_engine_tx, consensus_engine_rx) = unbounded_channel();
@@ -455,6 +529,7 @@ where
                 mining_mode,
                 evm_config.clone(),
             )
+            .prevrandao_seed(config.dev.prevrandao_seed)
             .build();
 
             let mut pipeline = config
@@ -467,7 +542,7 @@ where
                     sync_metrics_tx,
                     prune_config.clone(),
                     max_block,
-                    evm_config,
+                    executor_factory.clone(),
                 )
                 .await?;
 
@@ -488,7 +563,7 @@ where
                     sync_metrics_tx,
                     prune_config.clone(),
                     max_block,
-                    evm_config,
+                    executor_factory.clone(),
                 )
                 .await?;
 
@@ -503,7 +578,7 @@ where
         let pruner_events = if let Some(prune_config) = prune_config {
             let mut pruner = PrunerBuilder::new(prune_config.clone())
                 .max_reorg_depth(tree_config.max_reorg_depth() as usize)
-                .prune_delete_limit(config.chain.prune_delete_limit)
+                .chain_prune_config(config.chain.prune_config.clone())
                 .build(provider_factory, snapshotter.highest_snapshot_receiver());
 
             let events = pruner.events();
@@ -552,6 +627,7 @@ where
             reth_node_core::events::node::handle_events(
                 Some(network.clone()),
                 Some(head.number),
+                config.chain.block_duration(),
                 events,
                 database.clone(),
             ),
@@ -601,6 +677,14 @@ where
             executor,
         } = node_components;
 
+        let listen_addrs = NodeListenAddrs {
+            p2p: network.local_addr(),
+            http: rpc_server_handles.rpc.http_local_addr(),
+            ws: rpc_server_handles.rpc.ws_local_addr(),
+            auth: rpc_server_handles.auth.local_addr(),
+            metrics: metrics_addr,
+        };
+
         let full_node = FullNode {
             evm_config,
             pool,
@@ -612,6 +696,7 @@ where
             rpc_registry,
             config,
             data_dir,
+            listen_addrs,
         };
         // Notify on node started
         on_node_started.on_event(full_node.clone())?;
@@ -689,15 +774,15 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         self.config().txpool.pool_config()
     }
 
-    /// Loads the trusted setup params from a given file path or falls back to
-    /// `MAINNET_KZG_TRUSTED_SETUP`.
+    /// Loads the trusted setup params from a given file path or falls back to the trusted setup of
+    /// the chain spec.
     pub fn kzg_settings(&self) -> eyre::Result<Arc<KzgSettings>> {
         if let Some(ref trusted_setup_file) = self.config().trusted_setup_file {
             let trusted_setup = KzgSettings::load_trusted_setup_file(trusted_setup_file)
                 .map_err(LoadKzgSettingsError::KzgError)?;
             Ok(Arc::new(trusted_setup))
         } else {
-            Ok(Arc::clone(&MAINNET_KZG_TRUSTED_SETUP))
+            Ok(self.chain_spec().kzg_settings()?)
         }
     }
 
@@ -767,6 +852,12 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
     }
 }
 
+impl<Node: FullNodeTypes> ChainSpecProvider for BuilderContext<Node> {
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        self.provider().chain_spec()
+    }
+}
+
 /// The initial state of the node builder process.
 #[derive(Debug, Default)]
 #[non_exhaustive]
@@ -8,7 +8,7 @@ use reth_node_api::{evm::ConfigureEvm, primitives::NodePrimitives, EngineTypes};
 use reth_node_core::{
     cli::components::FullProvider,
     dirs::{ChainPath, DataDirPath},
-    node_config::NodeConfig,
+    node_config::{NodeConfig, NodeListenAddrs},
 };
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_tasks::TaskExecutor;
@@ -90,6 +90,15 @@ pub struct FullNode<Node: FullNodeComponents> {
     pub(crate) config: NodeConfig,
     /// The data dir of the node.
     pub(crate) data_dir: ChainPath<DataDirPath>,
+    /// The addresses the listeners of the node are bound to.
+    pub(crate) listen_addrs: NodeListenAddrs,
+}
+
+impl<Node: FullNodeComponents> FullNode<Node> {
+    /// Returns the addresses the listeners of the node are bound to.
+    pub fn listen_addrs(&self) -> NodeListenAddrs {
+        self.listen_addrs
+    }
 }
 
 impl<Node: FullNodeComponents> Clone for FullNode<Node> {
@@ -105,6 +114,7 @@ impl<Node: FullNodeComponents> Clone for FullNode<Node> {
             rpc_registry: self.rpc_registry.clone(),
             config: self.config.clone(),
             data_dir: self.data_dir.clone(),
+            listen_addrs: self.listen_addrs,
         }
     }
 }
@@ -3,7 +3,7 @@
 use reth_db::database::Database;
 use reth_provider::{
     AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    DatabaseProviderFactory, EvmEnvProvider, StateProviderFactory,
+    DatabaseProviderFactory, EvmEnvProvider, HotAccountsReader, StateProviderFactory,
 };
 
 /// Helper trait to unify all provider traits for simplicity.
@@ -15,6 +15,7 @@ pub trait FullProvider<DB: Database>:
     + EvmEnvProvider
     + ChainSpecProvider
     + ChangeSetReader
+    + HotAccountsReader
     + CanonStateSubscriptions
     + Clone
     + Unpin
@@ -30,6 +31,7 @@ impl<T, DB: Database> FullProvider<DB> for T where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + HotAccountsReader
         + CanonStateSubscriptions
         + Clone
         + Unpin
@@ -1,8 +1,9 @@
 //! clap [Args](clap::Args) for debugging purposes
 
 use clap::Args;
+use humantime::parse_duration;
 use reth_primitives::{TxHash, B256};
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 /// Parameters for debugging purposes
 #[derive(Debug, Clone, Args, PartialEq, Default)]
@@ -64,6 +65,20 @@ pub struct DebugArgs {
     /// will be written to specified location.
     #[arg(long = "debug.engine-api-store", help_heading = "Debug", value_name = "PATH")]
     pub engine_api_store: Option<PathBuf>,
+
+    /// Warn about blocks whose execution takes longer than the given duration, 1s by default.
+    ///
+    /// Parses strings using [humantime::parse_duration]
+    #[arg(long = "debug.slow-block-time", help_heading = "Debug", value_parser = parse_duration)]
+    pub slow_block_time: Option<Duration>,
+
+    /// Warn about blocks that are executed with a lower gas throughput, in gas per second.
+    #[arg(
+        long = "debug.slow-block-min-gas-per-second",
+        help_heading = "Debug",
+        value_name = "GAS"
+    )]
+    pub slow_block_min_gas_per_second: Option<u64>,
 }
 
 #[cfg(test)]
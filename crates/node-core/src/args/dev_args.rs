@@ -39,6 +39,13 @@ pub struct DevArgs {
         verbatim_doc_comment
     )]
     pub block_time: Option<Duration>,
+
+    /// Seed mixed into the `prevrandao` of mined blocks.
+    ///
+    /// The `prevrandao` of a block is derived from its parent hash and number, so runs that mine
+    /// the same blocks with the same seed see the same randomness.
+    #[arg(long = "dev.prevrandao-seed", help_heading = "Dev testnet", default_value_t = 0)]
+    pub prevrandao_seed: u64,
 }
 
 #[cfg(test)]
@@ -56,13 +63,13 @@ mod tests {
     #[test]
     fn test_parse_dev_args() {
         let args = CommandParser::<DevArgs>::parse_from(["reth"]).args;
-        assert_eq!(args, DevArgs { dev: false, block_max_transactions: None, block_time: None });
+        assert_eq!(args, DevArgs { dev: false, ..Default::default() });
 
         let args = CommandParser::<DevArgs>::parse_from(["reth", "--dev"]).args;
-        assert_eq!(args, DevArgs { dev: true, block_max_transactions: None, block_time: None });
+        assert_eq!(args, DevArgs { dev: true, ..Default::default() });
 
         let args = CommandParser::<DevArgs>::parse_from(["reth", "--auto-mine"]).args;
-        assert_eq!(args, DevArgs { dev: true, block_max_transactions: None, block_time: None });
+        assert_eq!(args, DevArgs { dev: true, ..Default::default() });
 
         let args = CommandParser::<DevArgs>::parse_from([
             "reth",
@@ -71,7 +78,10 @@ mod tests {
             "2",
         ])
         .args;
-        assert_eq!(args, DevArgs { dev: true, block_max_transactions: Some(2), block_time: None });
+        assert_eq!(
+            args,
+            DevArgs { dev: true, block_max_transactions: Some(2), ..Default::default() }
+        );
 
         let args =
             CommandParser::<DevArgs>::parse_from(["reth", "--dev", "--dev.block-time", "1s"]).args;
@@ -80,11 +90,20 @@ mod tests {
             DevArgs {
                 dev: true,
                 block_max_transactions: None,
-                block_time: Some(std::time::Duration::from_secs(1))
+                block_time: Some(std::time::Duration::from_secs(1)),
+                ..Default::default()
             }
         );
     }
 
+    #[test]
+    fn test_parse_dev_prevrandao_seed() {
+        let args =
+            CommandParser::<DevArgs>::parse_from(["reth", "--dev", "--dev.prevrandao-seed", "42"])
+                .args;
+        assert_eq!(args, DevArgs { dev: true, prevrandao_seed: 42, ..Default::default() });
+    }
+
     #[test]
     fn test_parse_dev_args_conflicts() {
         let args = CommandParser::<DevArgs>::try_parse_from([
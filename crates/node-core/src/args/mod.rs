@@ -47,6 +47,10 @@ pub use txpool_args::TxPoolArgs;
 mod dev_args;
 pub use dev_args::DevArgs;
 
+/// PreflightArgs for configuring the startup preflight checks
+mod preflight_args;
+pub use preflight_args::PreflightArgs;
+
 /// PruneArgs for configuring the pruning and full node
 mod pruning_args;
 pub use pruning_args::PruningArgs;
@@ -115,12 +115,13 @@ impl NetworkArgs {
         let chain_bootnodes = chain_spec.bootnodes().unwrap_or_else(mainnet_nodes);
         let peers_file = self.peers_file.clone().unwrap_or(default_peers_file);
 
-        // Configure peer connections
-        let peer_config = config
+        // Configure peer connections, the trusted peers of the chain spec are always trusted
+        let mut peer_config = config
             .peers
             .clone()
             .with_max_inbound_opt(self.max_inbound_peers)
             .with_max_outbound_opt(self.max_outbound_peers);
+        peer_config.trusted_nodes.extend(chain_spec.trusted_peers.iter().copied());
 
         // Configure transactions manager
         let transactions_manager_config = TransactionsManagerConfig {
@@ -6,9 +6,7 @@ use clap::{
     builder::{RangedU64ValueParser, TypedValueParser},
     Arg, Args, Command,
 };
-use reth_primitives::constants::{
-    ETHEREUM_BLOCK_GAS_LIMIT, MAXIMUM_EXTRA_DATA_SIZE, SLOT_DURATION,
-};
+use reth_primitives::constants::{ETHEREUM_BLOCK_GAS_LIMIT, MAXIMUM_EXTRA_DATA_SIZE};
 use std::{borrow::Cow, ffi::OsStr, time::Duration};
 
 /// Parameters for configuring the Payload Builder
@@ -28,13 +26,20 @@ pub struct PayloadBuilderArgs {
     pub interval: Duration,
 
     /// The deadline for when the payload builder job should resolve.
-    #[arg(long = "builder.deadline", value_parser = parse_duration_from_secs, default_value = "12", value_name = "SECONDS")]
-    pub deadline: Duration,
+    ///
+    /// Defaults to the block time of the chain, or 12 seconds if the chain has none.
+    #[arg(long = "builder.deadline", value_parser = parse_duration_from_secs, value_name = "SECONDS")]
+    pub deadline: Option<Duration>,
 
     /// Maximum number of tasks to spawn for building a payload.
     #[arg(long = "builder.max-tasks", default_value = "3", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
     pub max_payload_tasks: usize,
 
+    /// Number of the best pool transactions whose accounts and storage are prefetched for the next
+    /// payload when a new block is committed. 0 disables prewarming.
+    #[arg(long = "builder.prewarm-txs", default_value = "100", value_name = "COUNT")]
+    pub prewarm_transactions: usize,
+
     /// By default the pending block equals the latest block
     /// to save resources and not leak txs from the tx-pool,
     /// this flag enables computing of the pending block
@@ -54,8 +59,9 @@ impl Default for PayloadBuilderArgs {
             extradata: default_extradata(),
             max_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT,
             interval: Duration::from_secs(1),
-            deadline: SLOT_DURATION,
+            deadline: None,
             max_payload_tasks: 3,
+            prewarm_transactions: 100,
             #[cfg(feature = "optimism")]
             compute_pending_block: false,
         }
@@ -71,7 +77,7 @@ impl PayloadBuilderConfig for PayloadBuilderArgs {
         self.interval
     }
 
-    fn deadline(&self) -> Duration {
+    fn deadline(&self) -> Option<Duration> {
         self.deadline
     }
 
@@ -83,6 +89,10 @@ impl PayloadBuilderConfig for PayloadBuilderArgs {
         self.max_payload_tasks
     }
 
+    fn prewarm_transactions(&self) -> usize {
+        self.prewarm_transactions
+    }
+
     #[cfg(feature = "optimism")]
     fn compute_pending_block(&self) -> bool {
         self.compute_pending_block
@@ -0,0 +1,85 @@
+//! clap [Args](clap::Args) for the startup preflight checks
+
+use clap::Args;
+use humantime::parse_duration;
+use std::time::Duration;
+
+/// The default NTP server used to measure the clock skew.
+pub const DEFAULT_NTP_SERVER: &str = "pool.ntp.org:123";
+
+/// Parameters for the preflight checks that run before the node starts
+#[derive(Debug, Clone, Args, PartialEq, Eq)]
+#[clap(next_help_heading = "Preflight")]
+pub struct PreflightArgs {
+    /// Skip the preflight checks of disk space, clock skew, file descriptor limit and ports.
+    #[arg(long = "preflight.skip", help_heading = "Preflight")]
+    pub skip: bool,
+
+    /// The NTP server the system clock is compared with.
+    #[arg(
+        long = "preflight.ntp-server",
+        help_heading = "Preflight",
+        default_value = DEFAULT_NTP_SERVER
+    )]
+    pub ntp_server: String,
+
+    /// The maximum allowed difference between the system clock and the NTP server.
+    ///
+    /// Parses strings using [humantime::parse_duration]
+    /// --preflight.max-clock-skew 2s
+    #[arg(
+        long = "preflight.max-clock-skew",
+        help_heading = "Preflight",
+        value_parser = parse_duration,
+        default_value = "2s",
+        verbatim_doc_comment
+    )]
+    pub max_clock_skew: Duration,
+}
+
+impl PreflightArgs {
+    /// Skips all preflight checks.
+    pub fn skip(mut self) -> Self {
+        self.skip = true;
+        self
+    }
+}
+
+impl Default for PreflightArgs {
+    fn default() -> Self {
+        Self {
+            skip: false,
+            ntp_server: DEFAULT_NTP_SERVER.to_string(),
+            max_clock_skew: Duration::from_secs(2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[clap(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_preflight_args() {
+        let args = CommandParser::<PreflightArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, PreflightArgs::default());
+
+        let args = CommandParser::<PreflightArgs>::parse_from([
+            "reth",
+            "--preflight.skip",
+            "--preflight.max-clock-skew",
+            "500ms",
+        ])
+        .args;
+        assert!(args.skip);
+        assert_eq!(args.max_clock_skew, Duration::from_millis(500));
+    }
+}
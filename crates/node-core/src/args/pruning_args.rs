@@ -27,19 +27,20 @@ impl PruningArgs {
                     sender_recovery: Some(PruneMode::Full),
                     transaction_lookup: None,
                     receipts: chain_spec
-                        .deposit_contract
-                        .as_ref()
+                        .deposit_contracts()
+                        .first()
                         .map(|contract| PruneMode::Before(contract.block)),
                     account_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
                     storage_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
+                    prune_deposit_contract_logs: false,
                     receipts_log_filter: ReceiptsLogPruneConfig(
                         chain_spec
-                            .deposit_contract
-                            .as_ref()
+                            .deposit_contracts()
+                            .iter()
                             .map(|contract| (contract.address, PruneMode::Before(contract.block)))
-                            .into_iter()
                             .collect(),
                     ),
+                    receipts_log_exceptions: Vec::new(),
                 },
             })
         } else {
@@ -17,6 +17,7 @@ use clap::{
     Arg, Args, Command,
 };
 use futures::TryFutureExt;
+use humantime::parse_duration;
 use rand::Rng;
 use reth_network_api::{NetworkInfo, Peers};
 use reth_node_api::{ConfigureEvmEnv, EngineTypes};
@@ -25,7 +26,10 @@ use reth_provider::{
     EvmEnvProvider, HeaderProvider, StateProviderFactory,
 };
 use reth_rpc::{
-    eth::{cache::EthStateCacheConfig, gas_oracle::GasPriceOracleConfig, RPC_DEFAULT_GAS_CAP},
+    eth::{
+        cache::EthStateCacheConfig, gas_oracle::GasPriceOracleConfig, LaggingSubscriberPolicy,
+        SubscriptionBufferConfig, DEFAULT_SUBSCRIPTION_BUFFER_SIZE, RPC_DEFAULT_GAS_CAP,
+    },
     JwtError, JwtSecret,
 };
 use reth_rpc_builder::{
@@ -42,6 +46,7 @@ use std::{
     ffi::OsStr,
     net::{IpAddr, Ipv4Addr, SocketAddr},
     path::PathBuf,
+    time::Duration,
 };
 use tracing::{debug, info};
 
@@ -148,6 +153,30 @@ pub struct RpcServerArgs {
     #[arg(long, default_value_t = RPC_DEFAULT_MAX_SUBS_PER_CONN.into())]
     pub rpc_max_subscriptions_per_connection: MaxU32,
 
+    /// Maximum number of messages that are buffered per subscription if the subscriber can't keep
+    /// up.
+    #[arg(long, value_name = "COUNT", default_value_t = DEFAULT_SUBSCRIPTION_BUFFER_SIZE)]
+    pub rpc_subscription_buffer_size: usize,
+
+    /// What to do with a subscription once its buffer is full: drop-oldest, disconnect or
+    /// coalesce.
+    #[arg(long, value_name = "POLICY", default_value_t = LaggingSubscriberPolicy::default())]
+    pub rpc_subscription_lagging_policy: LaggingSubscriberPolicy,
+
+    /// Number of blocks that have to be built on top of a block before its logs are emitted to
+    /// `logs` subscriptions.
+    ///
+    /// Emitted logs of blocks that are reorged out are emitted again with `removed: true`.
+    #[arg(long, value_name = "BLOCKS", default_value_t = 0)]
+    pub rpc_logs_confirmations: u64,
+
+    /// Number of blocks below the latest block that `reth_call` requests without a block are
+    /// executed at.
+    ///
+    /// Requests can also select such a block explicitly with the `latest-<n>` block tag.
+    #[arg(long, value_name = "BLOCKS", default_value_t = 0)]
+    pub rpc_default_block_distance: u64,
+
     /// Maximum number of RPC server connections.
     #[arg(long, value_name = "COUNT", default_value_t = RPC_DEFAULT_MAX_CONNECTIONS.into())]
     pub rpc_max_connections: MaxU32,
@@ -164,6 +193,13 @@ pub struct RpcServerArgs {
     #[arg(long, value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64))]
     pub rpc_max_logs_per_response: ZeroAsNoneU64,
 
+    /// Disable the background index of combined logs blooms of block ranges, which lets
+    /// `eth_getLogs` skip ranges without matching logs.
+    ///
+    /// Saves disk space on nodes that rarely serve log queries over long ranges.
+    #[arg(long)]
+    pub rpc_disable_logs_bloom_index: bool,
+
     /// Maximum gas limit for `eth_call` and call tracing RPC methods.
     #[arg(
         long,
@@ -174,6 +210,12 @@ pub struct RpcServerArgs {
     )]
     pub rpc_gas_cap: u64,
 
+    /// Log calls that take longer than this duration, e.g. `500ms`.
+    ///
+    /// Parses strings using [humantime::parse_duration]
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub rpc_slow_query_threshold: Option<Duration>,
+
     /// State cache configuration.
     #[clap(flatten)]
     pub rpc_state_cache: RpcStateCacheArgs,
@@ -444,6 +486,13 @@ impl RethRpcConfig for RpcServerArgs {
             .rpc_gas_cap(self.rpc_gas_cap)
             .state_cache(self.state_cache_config())
             .gpo_config(self.gas_price_oracle_config())
+            .subscription_buffer(
+                SubscriptionBufferConfig::default()
+                    .buffer_size(self.rpc_subscription_buffer_size)
+                    .lagging_policy(self.rpc_subscription_lagging_policy),
+            )
+            .log_confirmations(self.rpc_logs_confirmations)
+            .default_block_distance(self.rpc_default_block_distance)
     }
 
     fn state_cache_config(&self) -> EthStateCacheConfig {
@@ -511,7 +560,9 @@ impl RethRpcConfig for RpcServerArgs {
     }
 
     fn rpc_server_config(&self) -> RpcServerConfig {
-        let mut config = RpcServerConfig::default().with_jwt_secret(self.rpc_secret_key());
+        let mut config = RpcServerConfig::default()
+            .with_jwt_secret(self.rpc_secret_key())
+            .with_slow_query_threshold(self.rpc_slow_query_threshold);
 
         if self.http {
             let socket_address = SocketAddr::new(self.http_addr, self.http_port);
@@ -578,11 +629,17 @@ impl Default for RpcServerArgs {
             rpc_max_request_size: RPC_DEFAULT_MAX_REQUEST_SIZE_MB.into(),
             rpc_max_response_size: RPC_DEFAULT_MAX_RESPONSE_SIZE_MB.into(),
             rpc_max_subscriptions_per_connection: RPC_DEFAULT_MAX_SUBS_PER_CONN.into(),
+            rpc_subscription_buffer_size: DEFAULT_SUBSCRIPTION_BUFFER_SIZE,
+            rpc_subscription_lagging_policy: LaggingSubscriberPolicy::default(),
+            rpc_logs_confirmations: 0,
+            rpc_default_block_distance: 0,
             rpc_max_connections: RPC_DEFAULT_MAX_CONNECTIONS.into(),
             rpc_max_tracing_requests: constants::DEFAULT_MAX_TRACING_REQUESTS,
             rpc_max_blocks_per_filter: constants::DEFAULT_MAX_BLOCKS_PER_FILTER.into(),
             rpc_max_logs_per_response: (constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64).into(),
+            rpc_disable_logs_bloom_index: false,
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
+            rpc_slow_query_threshold: None,
             gas_price_oracle: GasPriceOracleArgs::default(),
             rpc_state_cache: RpcStateCacheArgs::default(),
         }
@@ -650,6 +707,42 @@ mod tests {
         assert!(args.is_err());
     }
 
+    #[test]
+    fn test_rpc_slow_query_threshold() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.rpc_slow_query_threshold, None);
+
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc-slow-query-threshold",
+            "500ms",
+        ])
+        .args;
+        assert_eq!(args.rpc_slow_query_threshold, Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_rpc_subscription_lagging_policy() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.rpc_subscription_lagging_policy, LaggingSubscriberPolicy::DropOldest);
+
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc-subscription-lagging-policy",
+            "coalesce",
+        ])
+        .args;
+        let config = args.eth_config();
+        assert_eq!(config.subscription_buffer.lagging_policy, LaggingSubscriberPolicy::Coalesce);
+
+        let args = CommandParser::<RpcServerArgs>::try_parse_from([
+            "reth",
+            "--rpc-subscription-lagging-policy",
+            "block",
+        ]);
+        assert!(args.is_err());
+    }
+
     #[test]
     fn test_rpc_server_args_parser() {
         let args =
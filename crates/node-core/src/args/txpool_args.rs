@@ -4,9 +4,8 @@ use crate::cli::config::RethTransactionPoolConfig;
 use clap::Args;
 use reth_primitives::Address;
 use reth_transaction_pool::{
-    blobstore::disk::DEFAULT_MAX_CACHED_BLOBS, validate::DEFAULT_MAX_TX_INPUT_BYTES,
-    LocalTransactionConfig, PoolConfig, PriceBumpConfig, SubPoolLimit, DEFAULT_PRICE_BUMP,
-    REPLACE_BLOB_PRICE_BUMP, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
+    blobstore::disk::DEFAULT_MAX_CACHED_BLOBS, LocalTransactionConfig, PoolConfig, PriceBumpConfig,
+    SubPoolLimit, DEFAULT_PRICE_BUMP, REPLACE_BLOB_PRICE_BUMP, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
     TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT, TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
 };
 /// Parameters for debugging purposes
@@ -47,8 +46,11 @@ pub struct TxPoolArgs {
     pub blob_transaction_price_bump: u128,
 
     /// Max size in bytes of a single transaction allowed to enter the pool
-    #[arg(long = "txpool.max_tx_input_bytes", default_value_t = DEFAULT_MAX_TX_INPUT_BYTES)]
-    pub max_tx_input_bytes: usize,
+    ///
+    /// Defaults to the maximum transaction size of the chain, or 131072 if the chain doesn't limit
+    /// it.
+    #[arg(long = "txpool.max_tx_input_bytes")]
+    pub max_tx_input_bytes: Option<usize>,
 
     /// The maximum number of blobs to keep in the in memory blob cache.
     #[arg(long = "txpool.max_cached_entries", default_value_t = DEFAULT_MAX_CACHED_BLOBS)]
@@ -77,7 +79,7 @@ impl Default for TxPoolArgs {
             max_account_slots: TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
             price_bump: DEFAULT_PRICE_BUMP,
             blob_transaction_price_bump: REPLACE_BLOB_PRICE_BUMP,
-            max_tx_input_bytes: DEFAULT_MAX_TX_INPUT_BYTES,
+            max_tx_input_bytes: None,
             max_cached_entries: DEFAULT_MAX_CACHED_BLOBS,
             no_locals: false,
             locals: Default::default(),
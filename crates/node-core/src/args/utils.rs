@@ -1,6 +1,9 @@
 //! Clap parser utilities
 
-use reth_primitives::{fs, AllGenesisFormats, BlockHashOrNumber, ChainSpec, B256};
+use reth_primitives::{
+    fs, revm::config::ensure_supported_hardforks, AllGenesisFormats, BlockHashOrNumber, ChainSpec,
+    B256,
+};
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs},
     path::PathBuf,
@@ -10,17 +13,30 @@ use std::{
 };
 
 #[cfg(feature = "optimism")]
-use reth_primitives::{BASE_GOERLI, BASE_MAINNET, BASE_SEPOLIA};
+use reth_primitives::{BASE_GOERLI, BASE_MAINNET, BASE_SEPOLIA, SUPERCHAIN_REGISTRY};
 
+#[cfg(all(feature = "goerli", not(feature = "optimism")))]
+use reth_primitives::GOERLI;
 #[cfg(not(feature = "optimism"))]
-use reth_primitives::{DEV, GOERLI, HOLESKY, MAINNET, SEPOLIA};
+use reth_primitives::{DEV, DEV_CANCUN, HOLESKY, MAINNET, SEPOLIA};
 
 #[cfg(feature = "optimism")]
 /// Chains supported by op-reth. First value should be used as the default.
-pub const SUPPORTED_CHAINS: &[&str] = &["base", "base-goerli", "base-sepolia"];
+///
+/// This includes the chains of the bundled superchain registry snapshot.
+pub const SUPPORTED_CHAINS: &[&str] =
+    &["base", "base-goerli", "base-sepolia", "zora", "mode", "fraxtal"];
 #[cfg(not(feature = "optimism"))]
 /// Chains supported by reth. First value should be used as the default.
-pub const SUPPORTED_CHAINS: &[&str] = &["mainnet", "sepolia", "goerli", "holesky", "dev"];
+pub const SUPPORTED_CHAINS: &[&str] = &[
+    "mainnet",
+    "sepolia",
+    "holesky",
+    "dev",
+    "dev-cancun",
+    #[cfg(feature = "goerli")]
+    "goerli",
+];
 
 /// Helper to parse a [Duration] from seconds
 pub fn parse_duration_from_secs(arg: &str) -> eyre::Result<Duration, std::num::ParseIntError> {
@@ -30,11 +46,13 @@ pub fn parse_duration_from_secs(arg: &str) -> eyre::Result<Duration, std::num::P
 
 /// Clap value parser for [ChainSpec]s that takes either a built-in chainspec or the path
 /// to a custom one.
+///
+/// Chain specs that schedule a hardfork the EVM can't execute are rejected.
 pub fn chain_spec_value_parser(s: &str) -> eyre::Result<Arc<ChainSpec>, eyre::Error> {
-    Ok(match s {
+    let chain_spec = match s {
         #[cfg(not(feature = "optimism"))]
         "mainnet" => MAINNET.clone(),
-        #[cfg(not(feature = "optimism"))]
+        #[cfg(all(feature = "goerli", not(feature = "optimism")))]
         "goerli" => GOERLI.clone(),
         #[cfg(not(feature = "optimism"))]
         "sepolia" => SEPOLIA.clone(),
@@ -42,17 +60,25 @@ pub fn chain_spec_value_parser(s: &str) -> eyre::Result<Arc<ChainSpec>, eyre::Er
         "holesky" => HOLESKY.clone(),
         #[cfg(not(feature = "optimism"))]
         "dev" => DEV.clone(),
+        #[cfg(not(feature = "optimism"))]
+        "dev_cancun" | "dev-cancun" => DEV_CANCUN.clone(),
         #[cfg(feature = "optimism")]
         "base_goerli" | "base-goerli" => BASE_GOERLI.clone(),
         #[cfg(feature = "optimism")]
         "base_sepolia" | "base-sepolia" => BASE_SEPOLIA.clone(),
         #[cfg(feature = "optimism")]
         "base" => BASE_MAINNET.clone(),
+        #[cfg(feature = "optimism")]
+        name if SUPERCHAIN_REGISTRY.get(name).is_some() => Arc::new(
+            SUPERCHAIN_REGISTRY.chain_spec(name).expect("chain is part of the superchain registry"),
+        ),
         _ => {
             let raw = fs::read_to_string(PathBuf::from(shellexpand::full(s)?.into_owned()))?;
             serde_json::from_str(&raw)?
         }
-    })
+    };
+    ensure_supported_hardforks(&chain_spec)?;
+    Ok(chain_spec)
 }
 
 /// The help info for the --chain flag
@@ -65,11 +91,13 @@ pub fn chain_help() -> String {
 /// The value parser matches either a known chain, the path
 /// to a json file, or a json formatted string in-memory. The json can be either
 /// a serialized [ChainSpec] or Genesis struct.
+///
+/// Chain specs that schedule a hardfork the EVM can't execute are rejected.
 pub fn genesis_value_parser(s: &str) -> eyre::Result<Arc<ChainSpec>, eyre::Error> {
-    Ok(match s {
+    let chain_spec = match s {
         #[cfg(not(feature = "optimism"))]
         "mainnet" => MAINNET.clone(),
-        #[cfg(not(feature = "optimism"))]
+        #[cfg(all(feature = "goerli", not(feature = "optimism")))]
         "goerli" => GOERLI.clone(),
         #[cfg(not(feature = "optimism"))]
         "sepolia" => SEPOLIA.clone(),
@@ -77,12 +105,18 @@ pub fn genesis_value_parser(s: &str) -> eyre::Result<Arc<ChainSpec>, eyre::Error
         "holesky" => HOLESKY.clone(),
         #[cfg(not(feature = "optimism"))]
         "dev" => DEV.clone(),
+        #[cfg(not(feature = "optimism"))]
+        "dev_cancun" | "dev-cancun" => DEV_CANCUN.clone(),
         #[cfg(feature = "optimism")]
         "base_goerli" | "base-goerli" => BASE_GOERLI.clone(),
         #[cfg(feature = "optimism")]
         "base_sepolia" | "base-sepolia" => BASE_SEPOLIA.clone(),
         #[cfg(feature = "optimism")]
         "base" => BASE_MAINNET.clone(),
+        #[cfg(feature = "optimism")]
+        name if SUPERCHAIN_REGISTRY.get(name).is_some() => Arc::new(
+            SUPERCHAIN_REGISTRY.chain_spec(name).expect("chain is part of the superchain registry"),
+        ),
         _ => {
             // try to read json from path first
             let raw = match fs::read_to_string(PathBuf::from(shellexpand::full(s)?.into_owned())) {
@@ -100,9 +134,11 @@ pub fn genesis_value_parser(s: &str) -> eyre::Result<Arc<ChainSpec>, eyre::Error
             // both serialized Genesis and ChainSpec structs supported
             let genesis: AllGenesisFormats = serde_json::from_str(&raw)?;
 
-            Arc::new(genesis.into())
+            Arc::new(genesis.try_into()?)
         }
-    })
+    };
+    ensure_supported_hardforks(&chain_spec)?;
+    Ok(chain_spec)
 }
 
 /// Parse [BlockHashOrNumber]
@@ -176,6 +212,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reject_unsupported_hardforks() {
+        let genesis = r#"{"config":{"chainId":1337,"shanghaiTime":0,"cancunTime":0,"pragueTime":0},"nonce":"0x0","timestamp":"0x0","extraData":"0x","gasLimit":"0x1388","difficulty":"0x0","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","coinbase":"0x0000000000000000000000000000000000000000","alloc":{}}"#;
+        let err = genesis_value_parser(genesis).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "hardfork Prague is scheduled at Timestamp(0) but is not supported by the EVM"
+        );
+    }
+
     #[test]
     fn parse_chain_spec_from_memory() {
         let custom_genesis_from_json = r#"
@@ -2,7 +2,7 @@
 
 use alloy_rlp::Encodable;
 use reth_network::protocol::IntoRlpxSubProtocol;
-use reth_primitives::{Bytes, BytesMut};
+use reth_primitives::{constants::SLOT_DURATION, Bytes, BytesMut, ChainSpec};
 use reth_rpc::{
     eth::{cache::EthStateCacheConfig, gas_oracle::GasPriceOracleConfig},
     JwtError, JwtSecret,
@@ -97,8 +97,15 @@ pub trait PayloadBuilderConfig {
     /// The interval at which the job should build a new payload after the last.
     fn interval(&self) -> Duration;
 
-    /// The deadline for when the payload builder job should resolve.
-    fn deadline(&self) -> Duration;
+    /// The deadline for when the payload builder job should resolve, if configured.
+    fn deadline(&self) -> Option<Duration>;
+
+    /// The deadline for when the payload builder job should resolve on the given chain.
+    ///
+    /// Falls back to the block time of the chain if no deadline is configured.
+    fn deadline_for_chain(&self, chain_spec: &ChainSpec) -> Duration {
+        self.deadline().or_else(|| chain_spec.block_duration()).unwrap_or(SLOT_DURATION)
+    }
 
     /// Target gas ceiling for built blocks.
     fn max_gas_limit(&self) -> u64;
@@ -106,6 +113,9 @@ pub trait PayloadBuilderConfig {
     /// Maximum number of tasks to spawn for building a payload.
     fn max_payload_tasks(&self) -> usize;
 
+    /// Number of the best pool transactions whose state is prefetched for the next payload.
+    fn prewarm_transactions(&self) -> usize;
+
     /// Returns whether or not to construct the pending block.
     #[cfg(feature = "optimism")]
     fn compute_pending_block(&self) -> bool;
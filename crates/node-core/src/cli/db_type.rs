@@ -27,6 +27,14 @@ impl DatabaseBuilder {
         Self::Test
     }
 
+    /// Returns the data dir of a _real_ database for the given chain, `None` for a _test_ database.
+    pub fn data_dir(&self, chain: Chain) -> Option<ChainPath<DataDirPath>> {
+        match self {
+            DatabaseBuilder::Test => None,
+            DatabaseBuilder::Real(path) => Some(path.unwrap_or_chain_default(chain)),
+        }
+    }
+
     /// Initializes and returns the [DatabaseInstance] depending on the current database type.
     ///
     /// If the [DatabaseBuilder] is test, then the [ChainPath] constructed will be derived from the
@@ -0,0 +1,262 @@
+//! Version handshake of the data directory.
+//!
+//! The node records its client version and the schema versions of its on-disk formats in the
+//! [DATADIR_VERSION_FILE_NAME] file of the data directory. A binary that is older than the one
+//! that last wrote the data directory refuses to start: the newer binary may have written data in
+//! a format the older one silently misinterprets.
+
+use reth_db::version::DB_VERSION;
+use reth_nippy_jar::NIPPY_JAR_VERSION;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+use tracing::{debug, warn};
+
+/// The name of the file in the data directory that records the version of the client that last
+/// wrote it.
+pub const DATADIR_VERSION_FILE_NAME: &str = "reth.version";
+
+/// Errors of the data directory version handshake.
+#[derive(Debug, thiserror::Error)]
+pub enum DatadirVersionError {
+    /// The data directory was last written by a newer client.
+    #[error(
+        "the data directory was last written by reth {recorded}, which is newer than this binary ({current}). {}. Upgrade reth or pass --allow-downgrade to start anyway",
+        fmt_changes(.changes)
+    )]
+    Downgrade {
+        /// The client version recorded in the data directory.
+        recorded: String,
+        /// The version of this binary.
+        current: String,
+        /// The on-disk formats that differ from the ones this binary supports.
+        changes: Vec<SchemaChange>,
+    },
+    /// The version file is malformed.
+    #[error("malformed version file {path:?}: {source}")]
+    MalformedFile {
+        /// The path to the version file.
+        path: PathBuf,
+        /// The decoding error.
+        source: serde_json::Error,
+    },
+    /// IO error occurred while reading or writing the version file.
+    #[error("IO error occurred while accessing {path:?}: {source}")]
+    Io {
+        /// The path to the version file.
+        path: PathBuf,
+        /// The encountered IO error.
+        source: io::Error,
+    },
+}
+
+/// Describes the schema changes of a downgrade for the error message.
+fn fmt_changes(changes: &[SchemaChange]) -> String {
+    if changes.is_empty() {
+        return "None of the versioned on-disk formats changed".to_string()
+    }
+    let changes = changes.iter().map(ToString::to_string).collect::<Vec<_>>();
+    format!("Changed on-disk formats: {}", changes.join(", "))
+}
+
+/// An on-disk format whose recorded schema version differs from the one of this binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    /// The subsystem that owns the format, e.g. `database`.
+    pub subsystem: String,
+    /// The schema version recorded in the data directory.
+    pub recorded: u64,
+    /// The schema version of this binary, `None` if this binary doesn't know the subsystem.
+    pub current: Option<u64>,
+}
+
+impl fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.current {
+            Some(current) => {
+                write!(f, "{} v{} (this binary supports v{current})", self.subsystem, self.recorded)
+            }
+            None => write!(f, "{} v{} (unknown to this binary)", self.subsystem, self.recorded),
+        }
+    }
+}
+
+/// The versions recorded in the [DATADIR_VERSION_FILE_NAME] file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatadirVersion {
+    /// The client version, e.g. `0.1.0-alpha.19`.
+    pub client_version: String,
+    /// The commit the client was built from.
+    pub commit: String,
+    /// The schema versions of the on-disk formats by subsystem.
+    pub schemas: BTreeMap<String, u64>,
+}
+
+impl DatadirVersion {
+    /// Returns the versions of this binary.
+    pub fn current() -> Self {
+        Self {
+            client_version: env!("CARGO_PKG_VERSION").to_string(),
+            commit: env!("VERGEN_GIT_SHA").to_string(),
+            schemas: BTreeMap::from([
+                ("database".to_string(), DB_VERSION),
+                ("static_files".to_string(), NIPPY_JAR_VERSION as u64),
+            ]),
+        }
+    }
+
+    /// Reads the versions recorded in the data directory.
+    ///
+    /// Returns `None` if the data directory has no version file, e.g. because it is new.
+    pub fn read(data_dir: &Path) -> Result<Option<Self>, DatadirVersionError> {
+        let path = data_dir.join(DATADIR_VERSION_FILE_NAME);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => return Err(DatadirVersionError::Io { path, source }),
+        };
+        serde_json::from_str(&contents)
+            .map(Some)
+            .map_err(|source| DatadirVersionError::MalformedFile { path, source })
+    }
+
+    /// Writes the versions to the data directory, creating it if necessary.
+    pub fn write(&self, data_dir: &Path) -> Result<(), DatadirVersionError> {
+        let path = data_dir.join(DATADIR_VERSION_FILE_NAME);
+        let contents = serde_json::to_string_pretty(self).expect("versions are serializable");
+        fs::create_dir_all(data_dir)
+            .and_then(|_| fs::write(&path, contents))
+            .map_err(|source| DatadirVersionError::Io { path, source })
+    }
+
+    /// Returns the on-disk formats whose schema version in `self` is newer than in `other` or
+    /// that are unknown to `other`.
+    pub fn newer_schemas(&self, other: &Self) -> Vec<SchemaChange> {
+        self.schemas
+            .iter()
+            .filter_map(|(subsystem, recorded)| {
+                let current = other.schemas.get(subsystem).copied();
+                (current < Some(*recorded)).then(|| SchemaChange {
+                    subsystem: subsystem.clone(),
+                    recorded: *recorded,
+                    current,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns true if `self` was written by a newer client than `other`.
+    ///
+    /// Client versions that aren't valid semver are not compared.
+    pub fn is_newer_than(&self, other: &Self) -> bool {
+        let newer_client = match (
+            semver::Version::parse(&self.client_version),
+            semver::Version::parse(&other.client_version),
+        ) {
+            (Ok(recorded), Ok(current)) => recorded > current,
+            _ => false,
+        };
+        newer_client || !self.newer_schemas(other).is_empty()
+    }
+}
+
+/// Checks that this binary is not older than the one that last wrote the data directory.
+///
+/// With `allow_downgrade`, a downgrade is only logged.
+pub fn check_datadir_version(
+    data_dir: &Path,
+    allow_downgrade: bool,
+) -> Result<(), DatadirVersionError> {
+    let Some(recorded) = DatadirVersion::read(data_dir)? else { return Ok(()) };
+    let current = DatadirVersion::current();
+    debug!(target: "reth::cli", ?recorded, "Checked data directory version");
+    if !recorded.is_newer_than(&current) {
+        return Ok(())
+    }
+
+    let err = DatadirVersionError::Downgrade {
+        changes: recorded.newer_schemas(&current),
+        recorded: recorded.client_version,
+        current: current.client_version,
+    };
+    if !allow_downgrade {
+        return Err(err)
+    }
+    warn!(target: "reth::cli", %err, "Starting on a data directory of a newer version");
+    Ok(())
+}
+
+/// Records the versions of this binary in the data directory.
+pub fn record_datadir_version(data_dir: &Path) -> Result<(), DatadirVersionError> {
+    DatadirVersion::current().write(data_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn new_datadir() {
+        let dir = tempfile::tempdir().unwrap();
+        let data_dir = dir.path().join("mainnet");
+        assert_matches!(check_datadir_version(&data_dir, false), Ok(()));
+
+        record_datadir_version(&data_dir).unwrap();
+        assert_eq!(DatadirVersion::read(&data_dir).unwrap(), Some(DatadirVersion::current()));
+        assert_matches!(check_datadir_version(&data_dir, false), Ok(()));
+    }
+
+    #[test]
+    fn downgrade() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut newer = DatadirVersion::current();
+        newer.client_version = "999.0.0".to_string();
+        newer.schemas.insert("database".to_string(), DB_VERSION + 1);
+        newer.schemas.insert("trie_journal".to_string(), 1);
+        newer.write(dir.path()).unwrap();
+
+        let err = check_datadir_version(dir.path(), false).unwrap_err();
+        assert_matches!(
+            &err,
+            DatadirVersionError::Downgrade { changes, .. } if changes == &[
+                SchemaChange {
+                    subsystem: "database".to_string(),
+                    recorded: DB_VERSION + 1,
+                    current: Some(DB_VERSION),
+                },
+                SchemaChange { subsystem: "trie_journal".to_string(), recorded: 1, current: None },
+            ]
+        );
+        assert!(err.to_string().contains(&format!(
+            "database v{} (this binary supports v{DB_VERSION})",
+            DB_VERSION + 1
+        )));
+
+        assert_matches!(check_datadir_version(dir.path(), true), Ok(()));
+    }
+
+    #[test]
+    fn older_datadir() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut older = DatadirVersion::current();
+        older.client_version = "0.0.1".to_string();
+        older.schemas.remove("static_files");
+        older.write(dir.path()).unwrap();
+
+        assert_matches!(check_datadir_version(dir.path(), false), Ok(()));
+    }
+
+    #[test]
+    fn malformed_file() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(DATADIR_VERSION_FILE_NAME), "invalid").unwrap();
+        assert_matches!(
+            check_datadir_version(dir.path(), false),
+            Err(DatadirVersionError::MalformedFile { .. })
+        );
+    }
+}
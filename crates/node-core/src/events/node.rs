@@ -27,6 +27,12 @@ use tracing::{info, warn};
 /// Interval of reporting node state.
 const INFO_MESSAGE_INTERVAL: Duration = Duration::from_secs(25);
 
+/// The number of missed blocks after which the node is considered stalled.
+const STALLED_BLOCKS: u32 = 5;
+
+/// The duration after which the node is considered stalled if the chain has no fixed block time.
+const STALLED_DEFAULT: Duration = Duration::from_secs(60);
+
 /// The current high-level state of the node.
 struct NodeState<DB> {
     /// Database environment.
@@ -41,6 +47,8 @@ struct NodeState<DB> {
     latest_block: Option<BlockNumber>,
     /// The time of the latest block seen by the pipeline
     latest_block_time: Option<u64>,
+    /// The targeted duration between two blocks, if the chain has a fixed block time.
+    block_time: Option<Duration>,
     /// Hash of the head block last set by fork choice update
     head_block_hash: Option<B256>,
     /// Hash of the safe block last set by fork choice update
@@ -50,13 +58,19 @@ struct NodeState<DB> {
 }
 
 impl<DB> NodeState<DB> {
-    fn new(db: DB, network: Option<NetworkHandle>, latest_block: Option<BlockNumber>) -> Self {
+    fn new(
+        db: DB,
+        network: Option<NetworkHandle>,
+        latest_block: Option<BlockNumber>,
+        block_time: Option<Duration>,
+    ) -> Self {
         Self {
             db,
             network,
             current_stage: None,
             latest_block,
             latest_block_time: None,
+            block_time,
             head_block_hash: None,
             safe_block_hash: None,
             finalized_block_hash: None,
@@ -67,6 +81,11 @@ impl<DB> NodeState<DB> {
         self.network.as_ref().map(|net| net.num_connected_peers()).unwrap_or_default()
     }
 
+    /// Returns the duration without new blocks after which the node is considered stalled.
+    fn stall_threshold(&self) -> Duration {
+        self.block_time.map_or(STALLED_DEFAULT, |block_time| block_time * STALLED_BLOCKS)
+    }
+
     /// Processes an event emitted by the pipeline
     fn handle_pipeline_event(&mut self, event: PipelineEvent) {
         match event {
@@ -319,13 +338,14 @@ impl From<PrunerEvent> for NodeEvent {
 pub async fn handle_events<E, DB>(
     network: Option<NetworkHandle>,
     latest_block_number: Option<BlockNumber>,
+    block_time: Option<Duration>,
     events: E,
     db: DB,
 ) where
     E: Stream<Item = NodeEvent> + Unpin,
     DB: DatabaseMetadata + Database + 'static,
 {
-    let state = NodeState::new(db, network, latest_block_number);
+    let state = NodeState::new(db, network, latest_block_number, block_time);
 
     let start = tokio::time::Instant::now() + Duration::from_secs(3);
     let mut info_interval = tokio::time::interval_at(start, INFO_MESSAGE_INTERVAL);
@@ -392,9 +412,11 @@ where
             } else if let Some(latest_block) = this.state.latest_block {
                 let now =
                     SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
-                if now - this.state.latest_block_time.unwrap_or(0) > 60 {
+                let since_latest_block =
+                    now.saturating_sub(this.state.latest_block_time.unwrap_or(0));
+                if since_latest_block > this.state.stall_threshold().as_secs() {
                     // Once we start receiving consensus nodes, don't emit status unless stalled for
-                    // 1 minute
+                    // a few blocks
                     info!(
                         target: "reth::cli",
                         connected_peers = this.state.num_connected_peers(),
@@ -0,0 +1,124 @@
+//! Background job that applies the fork schedule of an updated chain spec file to a running node.
+//!
+//! Only forks that are not active at the canonical head can be added or moved, see
+//! [ChainSpec::update_fork_schedule].
+
+use reth_interfaces::provider::{ProviderError, ProviderResult};
+use reth_network::NetworkHandle;
+use reth_primitives::{
+    revm::config::ensure_supported_hardforks, AllGenesisFormats, ChainSpec, Head,
+};
+use reth_provider::{BlockNumReader, HeaderProvider};
+use reth_revm::EvmProcessorFactory;
+use std::{
+    fs,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use tracing::{info, warn};
+
+/// How long to wait between two checks of the chain spec file.
+const WATCH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Watches a chain spec file and applies the changes of its fork schedule to the network and the
+/// executors of the node.
+///
+/// The file may be a chain spec or a genesis file, like the `--chain` argument.
+#[derive(Debug)]
+pub struct ForkScheduleWatcher<Provider, EvmConfig> {
+    /// The chain spec file.
+    path: PathBuf,
+    /// The provider of the canonical head.
+    provider: Provider,
+    /// The network whose fork filter is updated.
+    network: NetworkHandle,
+    /// The factory of the executors whose chain spec is updated.
+    executor_factory: EvmProcessorFactory<EvmConfig>,
+    /// The chain spec with the last applied fork schedule.
+    chain_spec: Arc<ChainSpec>,
+    /// The modification time of the file when it was last read.
+    modified: Option<SystemTime>,
+}
+
+impl<Provider, EvmConfig> ForkScheduleWatcher<Provider, EvmConfig>
+where
+    Provider: BlockNumReader + HeaderProvider,
+{
+    /// Creates a new watcher of the given file for a node running the given chain spec.
+    ///
+    /// The file is only applied once it is modified.
+    pub fn new(
+        path: PathBuf,
+        chain_spec: Arc<ChainSpec>,
+        provider: Provider,
+        network: NetworkHandle,
+        executor_factory: EvmProcessorFactory<EvmConfig>,
+    ) -> Self {
+        let modified = fs::metadata(&path).and_then(|metadata| metadata.modified()).ok();
+        Self { path, provider, network, executor_factory, chain_spec, modified }
+    }
+
+    /// Returns the chain spec with the last applied fork schedule.
+    pub fn chain_spec(&self) -> &Arc<ChainSpec> {
+        &self.chain_spec
+    }
+
+    /// Applies the fork schedule of the file if it was modified since it was last read.
+    ///
+    /// Returns the updated chain spec, or `None` if the file was not modified.
+    pub fn check(&mut self) -> eyre::Result<Option<Arc<ChainSpec>>> {
+        let modified = fs::metadata(&self.path)?.modified()?;
+        if self.modified == Some(modified) {
+            return Ok(None)
+        }
+        self.modified = Some(modified);
+
+        let genesis: AllGenesisFormats = serde_json::from_str(&fs::read_to_string(&self.path)?)?;
+        let head = self.canonical_head()?;
+        let chain_spec =
+            Arc::new(self.chain_spec.update_fork_schedule(&genesis.try_into()?, &head)?);
+        ensure_supported_hardforks(&chain_spec)?;
+        if self.chain_spec.diff(&chain_spec).is_empty() {
+            return Ok(None)
+        }
+
+        self.network.update_fork_filter(chain_spec.fork_filter(head));
+        self.executor_factory.update_chain_spec(chain_spec.clone());
+        info!(target: "reth::cli", fork_id=?chain_spec.fork_id(&head), "Applied updated fork schedule\n{}", chain_spec.display_hardforks());
+        self.chain_spec = chain_spec.clone();
+        Ok(Some(chain_spec))
+    }
+
+    /// Returns the head of the canonical chain.
+    fn canonical_head(&self) -> ProviderResult<Head> {
+        let number = self.provider.best_block_number()?;
+        let header = self
+            .provider
+            .header_by_number(number)?
+            .ok_or(ProviderError::HeaderNotFound(number.into()))?;
+        let total_difficulty = self
+            .provider
+            .header_td_by_number(number)?
+            .ok_or(ProviderError::TotalDifficultyNotFound(number))?;
+        Ok(Head {
+            number,
+            hash: header.hash_slow(),
+            difficulty: header.difficulty,
+            total_difficulty,
+            timestamp: header.timestamp,
+        })
+    }
+
+    /// Periodically checks the file for updates.
+    ///
+    /// This reads the file and the database and should be spawned as a blocking task.
+    pub async fn run(mut self) {
+        loop {
+            tokio::time::sleep(WATCH_INTERVAL).await;
+            if let Err(err) = self.check() {
+                warn!(target: "reth::cli", %err, path=%self.path.display(), "Failed to apply updated fork schedule");
+            }
+        }
+    }
+}
@@ -3,24 +3,36 @@
 use reth_db::{
     cursor::DbCursorRO,
     database::Database,
+    models::AccountBeforeTx,
     tables,
     transaction::{DbTx, DbTxMut},
 };
 use reth_interfaces::{db::DatabaseError, provider::ProviderResult};
 use reth_primitives::{
-    stage::StageId, Account, Bytecode, ChainSpec, Receipts, StorageEntry, B256, U256,
+    stage::StageId, Account, Address, Bytecode, ChainSpec, ChainSpecDiff, Genesis, GenesisAccount,
+    Head, Receipts, SealedHeader, StorageEntry, B256, U256,
 };
 use reth_provider::{
     bundle_state::{BundleStateInit, RevertsInit},
     BundleStateWithReceipts, DatabaseProviderRW, HashingWriter, HistoryWriter, OriginalValuesKnown,
     ProviderError, ProviderFactory,
 };
+use reth_trie::StateRoot;
+use serde::{
+    de::{DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+    Deserializer,
+};
 use std::{
     collections::{BTreeMap, HashMap},
+    fmt,
+    io::Read,
     sync::Arc,
 };
 use tracing::debug;
 
+/// The number of genesis accounts [init_genesis_streaming] writes to the database at once.
+const GENESIS_ALLOC_CHUNK_SIZE: usize = 10_000;
+
 /// Database initialization error type.
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
 pub enum InitDatabaseError {
@@ -34,6 +46,22 @@ pub enum InitDatabaseError {
         database_hash: B256,
     },
 
+    /// The genesis allocation could not be parsed.
+    #[error("failed to parse the genesis allocation: {0}")]
+    InvalidGenesisAlloc(String),
+
+    /// The chainspec changes the activation of hardforks that are active at the tip of the
+    /// database, compared to the chainspec the database was last initialized with.
+    #[error(
+        "the chainspec changes hardforks that are active at block {block} of the database:\n{diff}"
+    )]
+    ChainSpecMismatch {
+        /// The tip of the database.
+        block: u64,
+        /// The changed hardforks.
+        diff: ChainSpecDiff,
+    },
+
     /// Provider error.
     #[error(transparent)]
     Provider(#[from] ProviderError),
@@ -58,6 +86,8 @@ pub fn init_genesis<DB: Database>(
     if let Some((_, db_hash)) = tx.cursor_read::<tables::CanonicalHeaders>()?.first()? {
         if db_hash == hash {
             debug!("Genesis already written, skipping.");
+            drop(tx);
+            write_chain_spec(&db, &chain, hash)?;
             return Ok(hash)
         }
 
@@ -88,10 +118,297 @@ pub fn init_genesis<DB: Database>(
         tx.put::<tables::SyncStage>(stage.to_string(), Default::default())?;
     }
 
+    tx.put::<tables::ChainSpecs>(hash, (*chain).clone())?;
+
     tx.commit()?;
     Ok(hash)
 }
 
+/// Checks the chainspec against the chainspec the database was last initialized with, and stores
+/// it in its place.
+///
+/// Returns [InitDatabaseError::ChainSpecMismatch] if the activation of a hardfork changed that is
+/// active at the tip of the database in either of the chainspecs, i.e. if blocks were processed
+/// under different rules than the chainspec specifies. Hardforks of later blocks may change.
+fn write_chain_spec<DB: Database>(
+    db: &DB,
+    chain: &ChainSpec,
+    genesis_hash: B256,
+) -> Result<(), InitDatabaseError> {
+    let tx = db.tx_mut()?;
+    if let Some(stored) = tx.get::<tables::ChainSpecs>(genesis_hash)? {
+        let (number, hash) =
+            tx.cursor_read::<tables::CanonicalHeaders>()?.last()?.unwrap_or_default();
+        let header = tx.get::<tables::Headers>(number)?.unwrap_or_default();
+        let head = Head {
+            number,
+            hash,
+            difficulty: header.difficulty,
+            total_difficulty: tx
+                .get::<tables::HeaderTD>(number)?
+                .map(|td| td.0)
+                .unwrap_or_default(),
+            timestamp: header.timestamp,
+        };
+
+        let forks = stored
+            .diff(chain)
+            .forks
+            .into_iter()
+            .filter(|fork| fork.old.active_at_head(&head) || fork.new.active_at_head(&head))
+            .collect::<Vec<_>>();
+        if !forks.is_empty() {
+            return Err(InitDatabaseError::ChainSpecMismatch {
+                block: number,
+                diff: ChainSpecDiff { forks, ..Default::default() },
+            })
+        }
+    }
+
+    tx.put::<tables::ChainSpecs>(genesis_hash, chain.clone())?;
+    tx.commit()?;
+    Ok(())
+}
+
+/// Applies the Paris block recorded in the database to the chain spec, if the chain spec doesn't
+/// know it.
+///
+/// The consensus engine records the Paris block once it is finalized, see
+/// [ChainSpec::set_observed_paris_block].
+pub fn load_paris_block<DB: Database>(
+    db: DB,
+    chain: Arc<ChainSpec>,
+) -> Result<Arc<ChainSpec>, InitDatabaseError> {
+    if chain.paris_block_and_final_difficulty.is_some() {
+        return Ok(chain)
+    }
+
+    let Some(paris) = db.tx()?.get::<tables::ParisBlock>(chain.genesis_hash())? else {
+        return Ok(chain)
+    };
+    debug!(block = paris.block, "Loaded the observed Paris block.");
+
+    let mut chain = (*chain).clone();
+    chain.set_observed_paris_block(paris.block, paris.final_difficulty);
+    Ok(Arc::new(chain))
+}
+
+/// Write the genesis block if it has not already been written, streaming the genesis allocation
+/// from the `alloc` field of the given genesis JSON.
+///
+/// Unlike [init_genesis], this never holds the whole allocation in memory: the accounts are written
+/// to the database in chunks while they are parsed, and the state root of the genesis header is
+/// computed from the hashed state tables afterwards. The allocation of the chain spec's genesis is
+/// ignored, all other genesis fields are taken from the chain spec.
+///
+/// An existing genesis block in the database can only be checked against the chain spec if the
+/// genesis hash of the spec is known ahead of time.
+pub fn init_genesis_streaming<DB: Database, R: Read>(
+    db: DB,
+    chain: Arc<ChainSpec>,
+    reader: R,
+) -> Result<B256, InitDatabaseError> {
+    let tx = db.tx()?;
+    if let Some((_, db_hash)) = tx.cursor_read::<tables::CanonicalHeaders>()?.first()? {
+        return match chain.genesis_hash {
+            Some(hash) if hash != db_hash => Err(InitDatabaseError::GenesisHashMismatch {
+                chainspec_hash: hash,
+                database_hash: db_hash,
+            }),
+            _ => {
+                debug!("Genesis already written, skipping.");
+                drop(tx);
+                write_chain_spec(&db, &chain, db_hash)?;
+                Ok(db_hash)
+            }
+        }
+    }
+
+    drop(tx);
+    debug!("Writing genesis block from the streamed allocation.");
+
+    let factory = ProviderFactory::new(&db, chain.clone());
+    let provider_rw = factory.provider_rw()?;
+    stream_genesis_alloc(reader, GENESIS_ALLOC_CHUNK_SIZE, |alloc| {
+        let genesis = Genesis::default().extend_accounts(alloc);
+        insert_genesis_hashes(&provider_rw, &genesis)?;
+        insert_genesis_history(&provider_rw, &genesis)?;
+        insert_genesis_plain_state::<&DB>(provider_rw.tx_ref(), &genesis)
+    })?;
+
+    let state_root =
+        StateRoot::from_tx(provider_rw.tx_ref()).root().map_err(DatabaseError::from)?;
+    let mut header = chain.genesis_header();
+    header.state_root = state_root;
+    let header = header.seal_slow();
+    let hash = header.hash();
+    if let Some(chainspec_hash) =
+        chain.genesis_hash.filter(|chainspec_hash| *chainspec_hash != hash)
+    {
+        return Err(InitDatabaseError::GenesisHashMismatch { chainspec_hash, database_hash: hash })
+    }
+
+    insert_sealed_genesis_header::<&DB>(provider_rw.tx_ref(), header)?;
+
+    // insert sync stage
+    for stage in StageId::ALL.iter() {
+        provider_rw.tx_ref().put::<tables::SyncStage>(stage.to_string(), Default::default())?;
+    }
+
+    provider_rw.tx_ref().put::<tables::ChainSpecs>(hash, (*chain).clone())?;
+
+    provider_rw.commit()?;
+    Ok(hash)
+}
+
+/// Parses the `alloc` field of a genesis JSON and passes its accounts to `write_chunk`, in chunks
+/// of at most `chunk_size` accounts. All other fields of the genesis are skipped.
+fn stream_genesis_alloc<R, F>(
+    reader: R,
+    chunk_size: usize,
+    write_chunk: F,
+) -> Result<(), InitDatabaseError>
+where
+    R: Read,
+    F: FnMut(Vec<(Address, GenesisAccount)>) -> ProviderResult<()>,
+{
+    let mut stream = GenesisAllocStream { chunk_size, write_chunk, error: None };
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let result = (&mut deserializer).deserialize_map(&mut stream).and_then(|_| deserializer.end());
+
+    // a failed write aborts the parsing, report the write error instead of the parse error
+    if let Some(error) = stream.error {
+        return Err(error.into())
+    }
+    result.map_err(|err| InitDatabaseError::InvalidGenesisAlloc(err.to_string()))
+}
+
+/// Visits a genesis JSON object and writes the accounts of its allocation in chunks.
+struct GenesisAllocStream<F> {
+    chunk_size: usize,
+    write_chunk: F,
+    error: Option<ProviderError>,
+}
+
+impl<F> GenesisAllocStream<F>
+where
+    F: FnMut(Vec<(Address, GenesisAccount)>) -> ProviderResult<()>,
+{
+    fn write<E: serde::de::Error>(
+        &mut self,
+        chunk: Vec<(Address, GenesisAccount)>,
+    ) -> Result<(), E> {
+        if chunk.is_empty() {
+            return Ok(())
+        }
+        (self.write_chunk)(chunk).map_err(|err| {
+            self.error = Some(err);
+            E::custom("failed to write the genesis accounts")
+        })
+    }
+}
+
+impl<'de, F> Visitor<'de> for &mut GenesisAllocStream<F>
+where
+    F: FnMut(Vec<(Address, GenesisAccount)>) -> ProviderResult<()>,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a genesis object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "alloc" {
+                map.next_value_seed(GenesisAlloc(&mut *self))?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The `alloc` field of a genesis JSON object.
+struct GenesisAlloc<'a, F>(&'a mut GenesisAllocStream<F>);
+
+impl<'de, F> DeserializeSeed<'de> for GenesisAlloc<'_, F>
+where
+    F: FnMut(Vec<(Address, GenesisAccount)>) -> ProviderResult<()>,
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for GenesisAlloc<'_, F>
+where
+    F: FnMut(Vec<(Address, GenesisAccount)>) -> ProviderResult<()>,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a genesis allocation")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut chunk = Vec::with_capacity(self.0.chunk_size);
+        while let Some(account) = map.next_entry::<Address, GenesisAccount>()? {
+            chunk.push(account);
+            if chunk.len() >= self.0.chunk_size {
+                self.0.write(std::mem::take(&mut chunk))?;
+            }
+        }
+        self.0.write(chunk)
+    }
+}
+
+/// Inserts the plain state of the genesis accounts and their changesets into the database.
+///
+/// This writes the same entries as [insert_genesis_state], without collecting the state of all
+/// accounts first, so it can be called for parts of the genesis allocation.
+pub fn insert_genesis_plain_state<DB: Database>(
+    tx: &<DB as Database>::TXMut,
+    genesis: &Genesis,
+) -> ProviderResult<()> {
+    for (address, account) in &genesis.alloc {
+        let bytecode_hash = if let Some(code) = &account.code {
+            let bytecode = Bytecode::new_raw(code.clone());
+            let hash = bytecode.hash_slow();
+            tx.put::<tables::Bytecodes>(hash, bytecode)?;
+            Some(hash)
+        } else {
+            None
+        };
+
+        tx.put::<tables::PlainAccountState>(
+            *address,
+            Account {
+                nonce: account.nonce.unwrap_or_default(),
+                balance: account.balance,
+                bytecode_hash,
+            },
+        )?;
+        tx.put::<tables::AccountChangeSet>(0, AccountBeforeTx { address: *address, info: None })?;
+
+        for (key, value) in account.storage.iter().flatten() {
+            let value = U256::from_be_bytes(value.0);
+            if value != U256::ZERO {
+                tx.put::<tables::PlainStorageState>(*address, StorageEntry::new(*key, value))?;
+            }
+            tx.put::<tables::StorageChangeSet>(
+                (0, *address).into(),
+                StorageEntry::new(*key, U256::ZERO),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Inserts the genesis state into the database.
 pub fn insert_genesis_state<DB: Database>(
     tx: &<DB as Database>::TXMut,
@@ -210,7 +527,15 @@ pub fn insert_genesis_header<DB: Database>(
     tx: &<DB as Database>::TXMut,
     chain: Arc<ChainSpec>,
 ) -> ProviderResult<()> {
-    let (header, block_hash) = chain.sealed_genesis_header().split();
+    insert_sealed_genesis_header::<DB>(tx, chain.sealed_genesis_header())
+}
+
+/// Inserts the given sealed header as the genesis header.
+pub fn insert_sealed_genesis_header<DB: Database>(
+    tx: &<DB as Database>::TXMut,
+    header: SealedHeader,
+) -> ProviderResult<()> {
+    let (header, block_hash) = header.split();
 
     tx.put::<tables::CanonicalHeaders>(0, block_hash)?;
     tx.put::<tables::HeaderNumbers>(block_hash, 0)?;
@@ -226,15 +551,17 @@ mod tests {
     use super::*;
 
     use reth_db::{
-        models::{storage_sharded_key::StorageShardedKey, ShardedKey},
+        models::{storage_sharded_key::StorageShardedKey, ShardedKey, StoredParisBlock},
         table::{Table, TableRow},
         test_utils::create_test_rw_db,
         DatabaseEnv,
     };
     use reth_primitives::{
-        Address, Chain, ForkTimestamps, Genesis, GenesisAccount, IntegerList, GOERLI,
-        GOERLI_GENESIS_HASH, MAINNET, MAINNET_GENESIS_HASH, SEPOLIA, SEPOLIA_GENESIS_HASH,
+        Address, Chain, ChainSpecBuilder, ForkCondition, ForkDiff, Genesis, GenesisAccount,
+        Hardfork, IntegerList, MAINNET, MAINNET_GENESIS_HASH, SEPOLIA, SEPOLIA_GENESIS_HASH,
     };
+    #[cfg(feature = "goerli")]
+    use reth_primitives::{GOERLI, GOERLI_GENESIS_HASH};
 
     fn collect_table_entries<DB, T>(
         tx: &<DB as Database>::TX,
@@ -256,6 +583,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "goerli")]
     fn success_init_genesis_goerli() {
         let db = create_test_rw_db();
         let genesis_hash = init_genesis(db, GOERLI.clone()).unwrap();
@@ -290,36 +618,89 @@ mod tests {
         )
     }
 
+    #[test]
+    fn fail_init_changed_active_fork() {
+        let builder = ChainSpecBuilder::default()
+            .chain(Chain::from_id(1337))
+            .genesis(Genesis::default())
+            .london_activated();
+        let db = create_test_rw_db();
+        let genesis_hash = init_genesis(db.clone(), Arc::new(builder.clone().build())).unwrap();
+        assert!(db.tx().unwrap().get::<tables::ChainSpecs>(genesis_hash).unwrap().is_some());
+
+        // forks that activate after the tip of the database may change
+        let chain = builder.clone().with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(1));
+        init_genesis(db.clone(), Arc::new(chain.build())).unwrap();
+
+        // forks that are active at the tip may not
+        let err =
+            init_genesis(db.clone(), Arc::new(builder.without_fork(Hardfork::Berlin).build()));
+        assert_eq!(
+            err.unwrap_err(),
+            InitDatabaseError::ChainSpecMismatch {
+                block: 0,
+                diff: ChainSpecDiff {
+                    forks: vec![ForkDiff {
+                        fork: Hardfork::Berlin,
+                        old: ForkCondition::Block(0),
+                        new: ForkCondition::Never,
+                    }],
+                    ..Default::default()
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn load_observed_paris_block() {
+        let db = create_test_rw_db();
+        let chain = Arc::new(ChainSpecBuilder::mainnet().paris_at_ttd(U256::from(100)).build());
+        init_genesis(db.clone(), chain.clone()).unwrap();
+
+        let loaded = load_paris_block(db.clone(), chain.clone()).unwrap();
+        assert_eq!(loaded.paris_block_and_final_difficulty, None);
+
+        let paris = StoredParisBlock { block: 10, final_difficulty: U256::from(105) };
+        let tx = db.tx_mut().unwrap();
+        tx.put::<tables::ParisBlock>(chain.genesis_hash(), paris).unwrap();
+        tx.commit().unwrap();
+
+        let loaded = load_paris_block(db.clone(), chain).unwrap();
+        assert_eq!(loaded.paris_block_and_final_difficulty, Some((10, U256::from(105))));
+        assert_eq!(loaded.final_paris_total_difficulty(10), Some(U256::from(105)));
+
+        // a chain spec that knows its paris block is left as is
+        let loaded = load_paris_block(db, MAINNET.clone()).unwrap();
+        assert_eq!(
+            loaded.paris_block_and_final_difficulty,
+            MAINNET.paris_block_and_final_difficulty
+        );
+    }
+
     #[test]
     fn init_genesis_history() {
         let address_with_balance = Address::with_last_byte(1);
         let address_with_storage = Address::with_last_byte(2);
         let storage_key = B256::with_last_byte(1);
-        let chain_spec = Arc::new(ChainSpec {
-            chain: Chain::from_id(1),
-            genesis: Genesis {
-                alloc: HashMap::from([
-                    (
-                        address_with_balance,
-                        GenesisAccount { balance: U256::from(1), ..Default::default() },
-                    ),
-                    (
-                        address_with_storage,
-                        GenesisAccount {
-                            storage: Some(HashMap::from([(storage_key, B256::random())])),
-                            ..Default::default()
-                        },
-                    ),
-                ]),
-                ..Default::default()
-            },
-            hardforks: BTreeMap::default(),
-            fork_timestamps: ForkTimestamps::default(),
-            genesis_hash: None,
-            paris_block_and_final_difficulty: None,
-            deposit_contract: None,
+        let mut chain_spec = ChainSpec::default();
+        chain_spec.chain = Chain::from_id(1);
+        chain_spec.genesis = Genesis {
+            alloc: HashMap::from([
+                (
+                    address_with_balance,
+                    GenesisAccount { balance: U256::from(1), ..Default::default() },
+                ),
+                (
+                    address_with_storage,
+                    GenesisAccount {
+                        storage: Some(HashMap::from([(storage_key, B256::random())])),
+                        ..Default::default()
+                    },
+                ),
+            ]),
             ..Default::default()
-        });
+        };
+        let chain_spec = Arc::new(chain_spec);
 
         let db = create_test_rw_db();
         init_genesis(db.clone(), chain_spec).unwrap();
@@ -344,4 +725,69 @@ mod tests {
             )],
         );
     }
+
+    #[test]
+    fn init_genesis_streaming_matches_init_genesis() {
+        let genesis_json = r#"{
+            "alloc": {
+                "0x0000000000000000000000000000000000000001": { "balance": "0x1" },
+                "0x0000000000000000000000000000000000000002": {
+                    "balance": "0x0",
+                    "nonce": "0x1",
+                    "code": "0x6000",
+                    "storage": {
+                        "0x0000000000000000000000000000000000000000000000000000000000000001": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                    }
+                }
+            },
+            "config": { "chainId": 1337 },
+            "difficulty": "0x1",
+            "gasLimit": "0x1c9c380"
+        }"#;
+        let genesis: Genesis = serde_json::from_str(genesis_json).unwrap();
+        let without_alloc = Genesis { alloc: HashMap::new(), ..genesis.clone() };
+
+        let db = create_test_rw_db();
+        let mut chain_spec = ChainSpec::default();
+        chain_spec.genesis = genesis;
+        let genesis_hash = init_genesis(db.clone(), Arc::new(chain_spec)).unwrap();
+
+        let streamed_db = create_test_rw_db();
+        let mut chain_spec = ChainSpec::default();
+        chain_spec.genesis = without_alloc;
+        let streamed_hash = init_genesis_streaming(
+            streamed_db.clone(),
+            Arc::new(chain_spec),
+            genesis_json.as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(streamed_hash, genesis_hash);
+
+        let tx = db.tx().unwrap();
+        let streamed_tx = streamed_db.tx().unwrap();
+        macro_rules! assert_same_entries {
+            ($($table:ident),+) => {$(
+                assert_eq!(
+                    collect_table_entries::<Arc<DatabaseEnv>, tables::$table>(&streamed_tx)
+                        .unwrap(),
+                    collect_table_entries::<Arc<DatabaseEnv>, tables::$table>(&tx).unwrap(),
+                );
+            )+};
+        }
+        assert_same_entries!(
+            PlainAccountState,
+            PlainStorageState,
+            Bytecodes,
+            AccountChangeSet,
+            StorageChangeSet,
+            HashedAccount,
+            HashedStorage,
+            AccountHistory,
+            StorageHistory,
+            Headers
+        );
+
+        let err = init_genesis_streaming(create_test_rw_db(), MAINNET.clone(), &b"{"[..]);
+        assert!(matches!(err, Err(InitDatabaseError::InvalidGenesisAlloc(_))));
+    }
 }
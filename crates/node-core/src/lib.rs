@@ -9,12 +9,16 @@
 
 pub mod args;
 pub mod cli;
+pub mod datadir_version;
 pub mod dirs;
 pub mod events;
 pub mod exit;
+pub mod fork_schedule;
 pub mod init;
+pub mod logs_bloom_index;
 pub mod metrics;
 pub mod node_config;
+pub mod preflight;
 pub mod utils;
 pub mod version;
 
@@ -0,0 +1,67 @@
+//! Background job that builds the bucketed logs bloom index.
+//!
+//! `eth_getLogs` uses the index to skip whole buckets of blocks without reading their headers,
+//! see [LogsBloomIndexWriter].
+
+use reth_db::database::Database;
+use reth_interfaces::provider::ProviderResult;
+use reth_provider::{BlockNumReader, LogsBloomIndexWriter, ProviderFactory};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// The number of blocks below the tip that are not indexed, so buckets are rarely invalidated by
+/// reorgs.
+pub const LOGS_BLOOM_INDEX_REORG_MARGIN: u64 = 64;
+
+/// The maximum number of buckets that are built in a single database transaction.
+const BUCKETS_PER_COMMIT: usize = 16;
+
+/// How long to wait between two indexing runs.
+const INDEX_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Builds the bucketed logs bloom index of the canonical chain in the background.
+#[derive(Debug)]
+pub struct LogsBloomIndexer<DB> {
+    provider_factory: ProviderFactory<DB>,
+}
+
+impl<DB: Database> LogsBloomIndexer<DB> {
+    /// Creates a new indexer for the given database.
+    pub fn new(provider_factory: ProviderFactory<DB>) -> Self {
+        Self { provider_factory }
+    }
+
+    /// Builds all missing buckets up to [LOGS_BLOOM_INDEX_REORG_MARGIN] blocks below the tip,
+    /// returning the number of built buckets.
+    pub fn index(&self) -> ProviderResult<usize> {
+        let best_block = self.provider_factory.best_block_number()?;
+        let Some(until) = best_block.checked_sub(LOGS_BLOOM_INDEX_REORG_MARGIN) else {
+            return Ok(0)
+        };
+
+        let mut built = 0;
+        loop {
+            let provider = self.provider_factory.provider_rw()?;
+            let buckets = provider.index_logs_blooms(until, BUCKETS_PER_COMMIT)?;
+            provider.commit()?;
+            built += buckets;
+            if buckets < BUCKETS_PER_COMMIT {
+                return Ok(built)
+            }
+        }
+    }
+
+    /// Periodically extends the index as the chain grows.
+    ///
+    /// This blocks on database transactions and should be spawned as a blocking task.
+    pub async fn run(self) {
+        loop {
+            match self.index() {
+                Ok(0) => {}
+                Ok(built) => debug!(target: "reth::cli", built, "Indexed logs bloom buckets"),
+                Err(err) => warn!(target: "reth::cli", %err, "Failed to index logs bloom buckets"),
+            }
+            tokio::time::sleep(INDEX_INTERVAL).await;
+        }
+    }
+}
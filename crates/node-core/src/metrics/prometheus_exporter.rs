@@ -34,27 +34,31 @@ pub fn install_recorder() -> eyre::Result<PrometheusHandle> {
 ///
 /// The hooks are called every time the metrics are requested at the given endpoint, and can be used
 /// to record values for pull-style metrics, i.e. metrics that are not automatically updated.
+///
+/// Returns the address the endpoint is bound to.
 pub(crate) async fn serve_with_hooks<F: Hook + 'static>(
     listen_addr: SocketAddr,
     handle: PrometheusHandle,
     hooks: impl IntoIterator<Item = F>,
-) -> eyre::Result<()> {
+) -> eyre::Result<SocketAddr> {
     let hooks: Vec<_> = hooks.into_iter().collect();
 
     // Start endpoint
-    start_endpoint(listen_addr, handle, Arc::new(move || hooks.iter().for_each(|hook| hook())))
-        .await
-        .wrap_err("Could not start Prometheus endpoint")?;
+    let local_addr =
+        start_endpoint(listen_addr, handle, Arc::new(move || hooks.iter().for_each(|hook| hook())))
+            .await
+            .wrap_err("Could not start Prometheus endpoint")?;
 
-    Ok(())
+    Ok(local_addr)
 }
 
-/// Starts an endpoint at the given address to serve Prometheus metrics.
+/// Starts an endpoint at the given address to serve Prometheus metrics and returns the address it
+/// is bound to.
 async fn start_endpoint<F: Hook + 'static>(
     listen_addr: SocketAddr,
     handle: PrometheusHandle,
     hook: Arc<F>,
-) -> eyre::Result<()> {
+) -> eyre::Result<SocketAddr> {
     let make_svc = make_service_fn(move |_| {
         let handle = handle.clone();
         let hook = Arc::clone(&hook);
@@ -68,19 +72,22 @@ async fn start_endpoint<F: Hook + 'static>(
     });
     let server =
         Server::try_bind(&listen_addr).wrap_err("Could not bind to address")?.serve(make_svc);
+    let local_addr = server.local_addr();
 
     tokio::spawn(async move { server.await.expect("Metrics endpoint crashed") });
 
-    Ok(())
+    Ok(local_addr)
 }
 
 /// Serves Prometheus metrics over HTTP with database and process metrics.
+///
+/// Returns the address the endpoint is bound to.
 pub async fn serve<Metrics>(
     listen_addr: SocketAddr,
     handle: PrometheusHandle,
     db: Metrics,
     process: metrics_process::Collector,
-) -> eyre::Result<()>
+) -> eyre::Result<SocketAddr>
 where
     Metrics: DatabaseMetrics + 'static + Send + Sync,
 {
@@ -94,7 +101,7 @@ where
         Box::new(collect_memory_stats),
         Box::new(collect_io_stats),
     ];
-    serve_with_hooks(listen_addr, handle, hooks).await?;
+    let local_addr = serve_with_hooks(listen_addr, handle, hooks).await?;
 
     // We describe the metrics after the recorder is installed, otherwise this information is not
     // registered
@@ -107,7 +114,7 @@ where
     describe_io_stats();
     register_version_metrics();
 
-    Ok(())
+    Ok(local_addr)
 }
 
 #[cfg(all(feature = "jemalloc", unix))]
@@ -3,11 +3,12 @@
 use crate::{
     args::{
         get_secret_key, DatabaseArgs, DebugArgs, DevArgs, NetworkArgs, PayloadBuilderArgs,
-        PruningArgs, RpcServerArgs, TxPoolArgs,
+        PreflightArgs, PruningArgs, RpcServerArgs, TxPoolArgs,
     },
     cli::{config::RethTransactionPoolConfig, db_type::DatabaseBuilder},
     dirs::{ChainPath, DataDirPath, MaybePlatformPath},
     metrics::prometheus_exporter,
+    preflight::{self, PreflightError},
     utils::{get_single_header, write_peers_to_file},
 };
 use metrics_exporter_prometheus::PrometheusHandle;
@@ -15,7 +16,7 @@ use once_cell::sync::Lazy;
 use reth_auto_seal_consensus::{AutoSealConsensus, MiningMode};
 use reth_beacon_consensus::BeaconConsensus;
 use reth_blockchain_tree::{
-    config::BlockchainTreeConfig, externals::TreeExternals, BlockchainTree,
+    config::BlockchainTreeConfig, externals::TreeExternals, BlockchainTree, SlowBlockThresholds,
 };
 use reth_config::{
     config::{PruneConfig, StageConfig},
@@ -41,15 +42,13 @@ use reth_network::{
 };
 use reth_node_api::ConfigureEvm;
 use reth_primitives::{
-    constants::eip4844::{LoadKzgSettingsError, MAINNET_KZG_TRUSTED_SETUP},
-    kzg::KzgSettings,
-    stage::StageId,
-    BlockHashOrNumber, BlockNumber, ChainSpec, Head, SealedHeader, TxHash, B256, MAINNET,
+    constants::eip4844::LoadKzgSettingsError, kzg::KzgSettings, stage::StageId, BlockHashOrNumber,
+    BlockNumber, ChainSpec, Head, SealedHeader, TxHash, B256, MAINNET,
 };
 use reth_provider::{
     providers::BlockchainProvider, BlockHashReader, BlockNumReader, BlockReader,
-    BlockchainTreePendingStateProvider, CanonStateSubscriptions, HeaderProvider, HeaderSyncMode,
-    ProviderFactory, StageCheckpointReader,
+    BlockchainTreePendingStateProvider, CanonStateSubscriptions, ChainSpecProvider, HeaderProvider,
+    HeaderSyncMode, ProviderFactory, StageCheckpointReader,
 };
 use reth_revm::EvmProcessorFactory;
 use reth_stages::{
@@ -68,6 +67,7 @@ use reth_transaction_pool::{
 };
 use revm_inspectors::stack::Hook;
 use secp256k1::SecretKey;
+use serde::Serialize;
 use std::{
     net::{SocketAddr, SocketAddrV4},
     path::PathBuf,
@@ -175,6 +175,13 @@ pub struct NodeConfig {
     /// Overrides the KZG trusted setup by reading from the supplied file.
     pub trusted_setup_file: Option<PathBuf>,
 
+    /// Start the node even if the data directory was last written by a newer version.
+    pub allow_downgrade: bool,
+
+    /// Watches the given chain spec file and applies the changes of the forks that are not active
+    /// yet while the node is running.
+    pub watch_chain_file: Option<PathBuf>,
+
     /// All networking related arguments
     pub network: NetworkArgs,
 
@@ -199,6 +206,9 @@ pub struct NodeConfig {
     /// All pruning related arguments
     pub pruning: PruningArgs,
 
+    /// All preflight check related arguments
+    pub preflight: PreflightArgs,
+
     /// Rollup related arguments
     #[cfg(feature = "optimism")]
     pub rollup: crate::args::RollupArgs,
@@ -214,6 +224,8 @@ impl NodeConfig {
             metrics: None,
             instance: 1,
             trusted_setup_file: None,
+            allow_downgrade: false,
+            watch_chain_file: None,
             network: NetworkArgs::default(),
             rpc: RpcServerArgs::default(),
             txpool: TxPoolArgs::default(),
@@ -222,6 +234,7 @@ impl NodeConfig {
             db: DatabaseArgs::default(),
             dev: DevArgs::default(),
             pruning: PruningArgs::default(),
+            preflight: PreflightArgs::default().skip(),
             #[cfg(feature = "optimism")]
             rollup: crate::args::RollupArgs::default(),
         };
@@ -315,6 +328,12 @@ impl NodeConfig {
         self
     }
 
+    /// Set the preflight args for the node
+    pub fn with_preflight(mut self, preflight: PreflightArgs) -> Self {
+        self.preflight = preflight;
+        self
+    }
+
     /// Set the rollup args for the node
     #[cfg(feature = "optimism")]
     pub fn with_rollup(mut self, rollup: crate::args::RollupArgs) -> Self {
@@ -379,12 +398,32 @@ impl NodeConfig {
         Ok(max_block)
     }
 
+    /// Returns the [BlockchainTreeConfig], with the slow block thresholds overridden by the debug
+    /// args.
+    pub fn tree_config(&self) -> BlockchainTreeConfig {
+        let defaults = SlowBlockThresholds::default();
+        BlockchainTreeConfig::default().with_slow_block_thresholds(SlowBlockThresholds {
+            max_execution_time: self.debug.slow_block_time.or(defaults.max_execution_time),
+            min_gas_per_second: self
+                .debug
+                .slow_block_min_gas_per_second
+                .or(defaults.min_gas_per_second),
+        })
+    }
+
     /// Get the [MiningMode] from the given dev args
     pub fn mining_mode(&self, pending_transactions_listener: Receiver<TxHash>) -> MiningMode {
         if let Some(interval) = self.dev.block_time {
             MiningMode::interval(interval)
         } else if let Some(max_transactions) = self.dev.block_max_transactions {
             MiningMode::instant(max_transactions, pending_transactions_listener)
+        } else if let Some(interval) = self.chain.block_duration() {
+            info!(
+                target: "reth::cli",
+                ?interval,
+                "No mining mode specified, defaulting to the block time of the chain"
+            );
+            MiningMode::interval(interval)
         } else {
             info!(target: "reth::cli", "No mining mode specified, defaulting to ReadyTransaction");
             MiningMode::instant(1, pending_transactions_listener)
@@ -422,6 +461,9 @@ impl NodeConfig {
     }
 
     /// Build the blockchain tree
+    ///
+    /// The executors of the tree are created by the given factory, clones of it share updates of
+    /// its chain spec, see [EvmProcessorFactory::update_chain_spec].
     pub fn build_blockchain_tree<DB, EvmConfig>(
         &self,
         provider_factory: ProviderFactory<DB>,
@@ -429,18 +471,15 @@ impl NodeConfig {
         prune_config: Option<PruneConfig>,
         sync_metrics_tx: UnboundedSender<MetricEvent>,
         tree_config: BlockchainTreeConfig,
-        evm_config: EvmConfig,
+        executor_factory: EvmProcessorFactory<EvmConfig>,
     ) -> eyre::Result<BlockchainTree<DB, EvmProcessorFactory<EvmConfig>>>
     where
         DB: Database + Unpin + Clone + 'static,
         EvmConfig: ConfigureEvm + Clone + 'static,
     {
         // configure blockchain tree
-        let tree_externals = TreeExternals::new(
-            provider_factory.clone(),
-            consensus.clone(),
-            EvmProcessorFactory::new(self.chain.clone(), evm_config),
-        );
+        let tree_externals =
+            TreeExternals::new(provider_factory.clone(), consensus.clone(), executor_factory);
         let tree = BlockchainTree::new(
             tree_externals,
             tree_config,
@@ -472,14 +511,18 @@ impl NodeConfig {
             DiskFileBlobStoreConfig::default()
                 .with_max_cached_entries(self.txpool.max_cached_entries),
         )?;
-        let validator = TransactionValidationTaskExecutor::eth_builder(Arc::clone(&self.chain))
+        let mut validator = TransactionValidationTaskExecutor::eth_builder(self)
             .with_head_timestamp(head.timestamp)
             .kzg_settings(self.kzg_settings()?)
             // use an additional validation task so we can validate transactions in parallel
-            .with_additional_tasks(1)
-            // set the max tx size in bytes allowed to enter the pool
-            .with_max_tx_input_bytes(self.txpool.max_tx_input_bytes)
-            .build_with_tasks(blockchain_db.clone(), executor.clone(), blob_store.clone());
+            .with_additional_tasks(1);
+        if let Some(max_tx_input_bytes) = self.txpool.max_tx_input_bytes {
+            // set the max tx size in bytes allowed to enter the pool, this defaults to the
+            // maximum transaction size of the chain
+            validator = validator.with_max_tx_input_bytes(max_tx_input_bytes);
+        }
+        let validator =
+            validator.build_with_tasks(blockchain_db.clone(), executor.clone(), blob_store.clone());
 
         let transaction_pool =
             reth_transaction_pool::Pool::eth_pool(validator, blob_store, self.txpool.pool_config());
@@ -546,7 +589,7 @@ impl NodeConfig {
         metrics_tx: reth_stages::MetricEventsSender,
         prune_config: Option<PruneConfig>,
         max_block: Option<BlockNumber>,
-        evm_config: EvmConfig,
+        executor_factory: EvmProcessorFactory<EvmConfig>,
     ) -> eyre::Result<Pipeline<DB>>
     where
         DB: Database + Unpin + Clone + 'static,
@@ -573,22 +616,22 @@ impl NodeConfig {
                 self.debug.continuous,
                 metrics_tx,
                 prune_config,
-                evm_config,
+                executor_factory,
             )
             .await?;
 
         Ok(pipeline)
     }
 
-    /// Loads the trusted setup params from a given file path or falls back to
-    /// `MAINNET_KZG_TRUSTED_SETUP`.
+    /// Loads the trusted setup params from a given file path or falls back to the trusted setup of
+    /// the chain spec.
     pub fn kzg_settings(&self) -> eyre::Result<Arc<KzgSettings>> {
         if let Some(ref trusted_setup_file) = self.trusted_setup_file {
             let trusted_setup = KzgSettings::load_trusted_setup_file(trusted_setup_file)
                 .map_err(LoadKzgSettingsError::KzgError)?;
             Ok(Arc::new(trusted_setup))
         } else {
-            Ok(Arc::clone(&MAINNET_KZG_TRUSTED_SETUP))
+            Ok(self.chain.kzg_settings()?)
         }
     }
 
@@ -598,26 +641,28 @@ impl NodeConfig {
     }
 
     /// Serves the prometheus endpoint over HTTP with the given database and prometheus handle.
+    ///
+    /// Returns the address the endpoint is bound to, if metrics are enabled.
     pub async fn start_metrics_endpoint<Metrics>(
         &self,
         prometheus_handle: PrometheusHandle,
         db: Metrics,
-    ) -> eyre::Result<()>
+    ) -> eyre::Result<Option<SocketAddr>>
     where
         Metrics: DatabaseMetrics + 'static + Send + Sync,
     {
-        if let Some(listen_addr) = self.metrics {
-            info!(target: "reth::cli", addr = %listen_addr, "Starting metrics endpoint");
-            prometheus_exporter::serve(
-                listen_addr,
-                prometheus_handle,
-                db,
-                metrics_process::Collector::default(),
-            )
-            .await?;
-        }
-
-        Ok(())
+        let Some(listen_addr) = self.metrics else { return Ok(None) };
+
+        info!(target: "reth::cli", addr = %listen_addr, "Starting metrics endpoint");
+        let local_addr = prometheus_exporter::serve(
+            listen_addr,
+            prometheus_handle,
+            db,
+            metrics_process::Collector::default(),
+        )
+        .await?;
+
+        Ok(Some(local_addr))
     }
 
     /// Spawns the configured network and associated tasks and returns the [NetworkHandle] connected
@@ -794,7 +839,7 @@ impl NodeConfig {
         continuous: bool,
         metrics_tx: reth_stages::MetricEventsSender,
         prune_config: Option<PruneConfig>,
-        evm_config: EvmConfig,
+        executor_factory: EvmProcessorFactory<EvmConfig>,
     ) -> eyre::Result<Pipeline<DB>>
     where
         DB: Database + Clone + 'static,
@@ -811,7 +856,6 @@ impl NodeConfig {
 
         let (tip_tx, tip_rx) = watch::channel(B256::ZERO);
         use revm_inspectors::stack::InspectorStackConfig;
-        let factory = reth_revm::EvmProcessorFactory::new(self.chain.clone(), evm_config);
 
         let stack_config = InspectorStackConfig {
             use_printer_tracer: self.debug.print_inspector,
@@ -826,7 +870,7 @@ impl NodeConfig {
             },
         };
 
-        let factory = factory.with_stack_config(stack_config);
+        let factory = executor_factory.with_stack_config(stack_config);
 
         let prune_modes = prune_config.map(|prune| prune.segments).unwrap_or_default();
 
@@ -867,6 +911,7 @@ impl NodeConfig {
                             .max(stage_config.storage_hashing.clean_threshold),
                         prune_modes.clone(),
                     )
+                    .with_commit_valid_prefix(stage_config.execution.commit_valid_prefix)
                     .with_metrics_tx(metrics_tx),
                 )
                 .set(AccountHashingStage::new(
@@ -902,15 +947,83 @@ impl NodeConfig {
         self.rpc.adjust_instance_ports(self.instance);
     }
 
-    /// Sets networking and RPC ports to zero, causing the OS to choose random unused ports when
-    /// sockets are bound.
+    /// Runs the [preflight] checks of disk space, clock skew, file descriptor limit and ports,
+    /// unless they are disabled with `--preflight.skip`.
+    ///
+    /// This must be called before the RPC ports are adjusted to the instance number, see
+    /// [Self::adjust_instance_ports].
+    pub fn run_preflight_checks(
+        &self,
+        data_dir: &ChainPath<DataDirPath>,
+    ) -> Result<(), PreflightError> {
+        if self.preflight.skip {
+            debug!(target: "reth::cli", "Skipping preflight checks");
+            return Ok(())
+        }
+        info!(target: "reth::cli", "Running preflight checks");
+
+        if let Some(required) = preflight::projected_disk_usage(self.chain.chain, self.pruning.full)
+        {
+            preflight::check_disk_space(&data_dir.data_dir_path(), required)?;
+        }
+        preflight::check_clock_skew(&self.preflight.ntp_server, self.preflight.max_clock_skew)?;
+        preflight::check_fd_limit()?;
+
+        let p2p = SocketAddr::V4(SocketAddrV4::new(
+            self.network.addr,
+            self.network.port + self.instance - 1,
+        ));
+        preflight::check_tcp_port(p2p)?;
+        if !self.network.discovery.disable_discovery {
+            preflight::check_udp_port(p2p)?;
+        }
+
+        let mut rpc = self.rpc.clone();
+        rpc.adjust_instance_ports(self.instance);
+        if rpc.http {
+            preflight::check_tcp_port(SocketAddr::new(rpc.http_addr, rpc.http_port))?;
+        }
+        if rpc.ws {
+            preflight::check_tcp_port(SocketAddr::new(rpc.ws_addr, rpc.ws_port))?;
+        }
+        preflight::check_tcp_port(SocketAddr::new(rpc.auth_addr, rpc.auth_port))?;
+        if let Some(metrics) = self.metrics {
+            preflight::check_tcp_port(metrics)?;
+        }
+
+        Ok(())
+    }
+
+    /// Sets networking, RPC and metrics ports to zero, causing the OS to choose random unused
+    /// ports when sockets are bound.
+    ///
+    /// The ports that were chosen are reported by the [NodeListenAddrs] of the launched node.
     pub fn with_unused_ports(mut self) -> Self {
         self.rpc = self.rpc.with_unused_ports();
         self.network = self.network.with_unused_ports();
+        self.metrics = self.metrics.map(|addr| SocketAddr::new(addr.ip(), 0));
         self
     }
 }
 
+/// The addresses the listeners of a launched node are bound to.
+///
+/// These are the actually bound addresses, so they contain the ports chosen by the OS if the node
+/// was configured with [NodeConfig::with_unused_ports].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct NodeListenAddrs {
+    /// The address of the p2p listener.
+    pub p2p: SocketAddr,
+    /// The address of the HTTP RPC server, if enabled.
+    pub http: Option<SocketAddr>,
+    /// The address of the WS RPC server, if enabled.
+    pub ws: Option<SocketAddr>,
+    /// The address of the auth server (engine API).
+    pub auth: SocketAddr,
+    /// The address of the metrics endpoint, if enabled.
+    pub metrics: Option<SocketAddr>,
+}
+
 impl Default for NodeConfig {
     fn default() -> Self {
         Self {
@@ -920,6 +1033,8 @@ impl Default for NodeConfig {
             metrics: None,
             instance: 1,
             trusted_setup_file: None,
+            allow_downgrade: false,
+            watch_chain_file: None,
             network: NetworkArgs::default(),
             rpc: RpcServerArgs::default(),
             txpool: TxPoolArgs::default(),
@@ -928,8 +1043,15 @@ impl Default for NodeConfig {
             db: DatabaseArgs::default(),
             dev: DevArgs::default(),
             pruning: PruningArgs::default(),
+            preflight: PreflightArgs::default(),
             #[cfg(feature = "optimism")]
             rollup: crate::args::RollupArgs::default(),
         }
     }
 }
+
+impl ChainSpecProvider for NodeConfig {
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        self.chain.clone()
+    }
+}
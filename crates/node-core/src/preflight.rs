@@ -0,0 +1,300 @@
+//! Preflight checks that run before the node is launched.
+//!
+//! The checks catch misconfigurations that would otherwise only surface after hours of syncing:
+//! a data directory that is too small for the chain, a skewed system clock, a low open file
+//! descriptor limit and ports that are already bound by another process.
+
+use reth_primitives::{Chain, NamedChain};
+use std::{
+    io,
+    net::{SocketAddr, TcpListener, UdpSocket},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tracing::{debug, warn};
+
+/// One gibibyte in bytes.
+const GIB: u64 = 1024 * 1024 * 1024;
+
+/// The minimum number of open file descriptors the node needs.
+pub const MIN_FILE_DESCRIPTORS: u64 = 1024;
+
+/// The timeout of the NTP query used to measure the clock skew.
+pub const NTP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Seconds between the NTP epoch (1900) and the unix epoch (1970).
+const NTP_UNIX_OFFSET: u64 = 2_208_988_800;
+
+/// Errors of the preflight checks.
+#[derive(Debug, thiserror::Error)]
+pub enum PreflightError {
+    /// The data directory does not have enough free disk space for the chain.
+    #[error(
+        "not enough disk space in {path:?}: {} GiB available, ~{} GiB required. Free up space, choose another --datadir or run a --full node",
+        .available / GIB,
+        .required / GIB
+    )]
+    InsufficientDiskSpace {
+        /// The data directory.
+        path: PathBuf,
+        /// The available disk space in bytes.
+        available: u64,
+        /// The projected additional disk usage in bytes.
+        required: u64,
+    },
+    /// The system clock differs too much from the NTP server.
+    #[error(
+        "system clock differs from {server} by {skew:?}, the maximum is {max:?}. Synchronize the system clock or raise --preflight.max-clock-skew"
+    )]
+    ClockSkew {
+        /// The NTP server the clock was compared with.
+        server: String,
+        /// The measured clock skew.
+        skew: Duration,
+        /// The maximum allowed clock skew.
+        max: Duration,
+    },
+    /// The open file descriptor limit is too low.
+    #[error(
+        "open file descriptor limit is {limit}, at least {required} are required. Raise it with `ulimit -n`"
+    )]
+    FileDescriptorLimit {
+        /// The current limit.
+        limit: u64,
+        /// The minimum required limit.
+        required: u64,
+    },
+    /// A port the node listens on is already in use.
+    #[error(
+        "{protocol} address {addr} is not available: {source}. Stop the process using it or choose another port, e.g. with --instance"
+    )]
+    PortInUse {
+        /// The transport protocol, `TCP` or `UDP`.
+        protocol: &'static str,
+        /// The address that could not be bound.
+        addr: SocketAddr,
+        /// The bind error.
+        source: io::Error,
+    },
+}
+
+/// Returns the rough disk usage in bytes of a synced node for well known chains.
+///
+/// Returns `None` for chains with unknown disk usage, like dev or custom chains.
+pub fn projected_disk_usage(chain: Chain, full: bool) -> Option<u64> {
+    let (archive, full_node) = match chain.named()? {
+        NamedChain::Mainnet => (2_500, 1_300),
+        NamedChain::Goerli => (1_100, 400),
+        NamedChain::Sepolia => (650, 350),
+        NamedChain::Holesky => (200, 100),
+        _ => return None,
+    };
+    Some(if full { full_node } else { archive } * GIB)
+}
+
+/// Checks that the disk of the data directory has at least `required` bytes available, minus the
+/// size of the existing database.
+///
+/// If the available disk space can't be queried, a warning is logged and the check passes.
+pub fn check_disk_space(data_dir: &Path, required: u64) -> Result<(), PreflightError> {
+    // the data directory may not exist yet
+    let Some(existing) = data_dir.ancestors().find(|path| path.exists()) else { return Ok(()) };
+    let available = match fs4::available_space(existing) {
+        Ok(available) => available,
+        Err(error) => {
+            warn!(target: "reth::cli", %error, path = ?existing, "Failed to query disk space");
+            return Ok(())
+        }
+    };
+
+    let database_size = std::fs::metadata(data_dir.join("db").join("mdbx.dat"))
+        .map(|metadata| metadata.len())
+        .unwrap_or_default();
+    let required = required.saturating_sub(database_size);
+    debug!(target: "reth::cli", available, required, "Checked disk space");
+
+    if available < required {
+        return Err(PreflightError::InsufficientDiskSpace {
+            path: data_dir.to_path_buf(),
+            available,
+            required,
+        })
+    }
+    Ok(())
+}
+
+/// Queries the NTP `server` with a single SNTP request and returns the absolute difference
+/// between its clock and the system clock.
+pub fn clock_skew(server: &str, timeout: Duration) -> io::Result<Duration> {
+    let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+    socket.set_read_timeout(Some(timeout))?;
+    socket.set_write_timeout(Some(timeout))?;
+    socket.connect(server)?;
+
+    let mut request = [0u8; 48];
+    // leap indicator 0, version 4, mode 3 (client)
+    request[0] = 0b00_100_011;
+    let sent = SystemTime::now();
+    socket.send(&request)?;
+
+    let mut response = [0u8; 48];
+    let len = socket.recv(&mut response)?;
+    let received = SystemTime::now();
+    if len < response.len() {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "truncated NTP response"))
+    }
+
+    // the transmit timestamp of the server
+    let seconds = u32::from_be_bytes([response[40], response[41], response[42], response[43]]);
+    let fraction = u32::from_be_bytes([response[44], response[45], response[46], response[47]]);
+    let seconds = (seconds as u64).checked_sub(NTP_UNIX_OFFSET).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "NTP timestamp before unix epoch")
+    })?;
+    let server_time = UNIX_EPOCH +
+        Duration::from_secs(seconds) +
+        Duration::from_nanos((fraction as u64 * 1_000_000_000) >> 32);
+
+    // compare with the midpoint of the round trip
+    let local_time = sent + received.duration_since(sent).unwrap_or_default() / 2;
+    Ok(server_time.duration_since(local_time).unwrap_or_else(|err| err.duration()))
+}
+
+/// Checks that the system clock differs at most `max` from the NTP `server`.
+///
+/// If the server can't be reached, a warning is logged and the check passes.
+pub fn check_clock_skew(server: &str, max: Duration) -> Result<(), PreflightError> {
+    let skew = match clock_skew(server, NTP_TIMEOUT) {
+        Ok(skew) => skew,
+        Err(error) => {
+            warn!(target: "reth::cli", %error, server, "Failed to measure clock skew");
+            return Ok(())
+        }
+    };
+    debug!(target: "reth::cli", ?skew, server, "Checked clock skew");
+
+    if skew > max {
+        return Err(PreflightError::ClockSkew { server: server.to_string(), skew, max })
+    }
+    Ok(())
+}
+
+/// Raises the open file descriptor limit to the hard limit and checks that it is at least
+/// [MIN_FILE_DESCRIPTORS].
+pub fn check_fd_limit() -> Result<(), PreflightError> {
+    match fdlimit::raise_fd_limit() {
+        Ok(fdlimit::Outcome::LimitRaised { to, .. }) if to < MIN_FILE_DESCRIPTORS => {
+            Err(PreflightError::FileDescriptorLimit { limit: to, required: MIN_FILE_DESCRIPTORS })
+        }
+        Ok(outcome) => {
+            debug!(target: "reth::cli", ?outcome, "Checked file descriptor limit");
+            Ok(())
+        }
+        Err(error) => {
+            warn!(target: "reth::cli", %error, "Failed to query file descriptor limit");
+            Ok(())
+        }
+    }
+}
+
+/// Checks that the TCP address can be bound.
+///
+/// Addresses with port zero are skipped, the OS picks an unused port for them.
+pub fn check_tcp_port(addr: SocketAddr) -> Result<(), PreflightError> {
+    if addr.port() == 0 {
+        return Ok(())
+    }
+    TcpListener::bind(addr).map(drop).map_err(|source| PreflightError::PortInUse {
+        protocol: "TCP",
+        addr,
+        source,
+    })
+}
+
+/// Checks that the UDP address can be bound.
+///
+/// Addresses with port zero are skipped, the OS picks an unused port for them.
+pub fn check_udp_port(addr: SocketAddr) -> Result<(), PreflightError> {
+    if addr.port() == 0 {
+        return Ok(())
+    }
+    UdpSocket::bind(addr).map(drop).map_err(|source| PreflightError::PortInUse {
+        protocol: "UDP",
+        addr,
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    /// Spawns a fake NTP server that answers a single request with the given offset to the
+    /// system clock.
+    fn spawn_ntp_server(ahead: Duration) -> SocketAddr {
+        let socket = UdpSocket::bind(("127.0.0.1", 0)).unwrap();
+        let addr = socket.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let mut request = [0u8; 48];
+            let (_, peer) = socket.recv_from(&mut request).unwrap();
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap() + ahead;
+            let seconds = (now.as_secs() + NTP_UNIX_OFFSET) as u32;
+            let fraction = (((now.subsec_nanos() as u64) << 32) / 1_000_000_000) as u32;
+            let mut response = [0u8; 48];
+            response[0] = 0b00_100_100;
+            response[40..44].copy_from_slice(&seconds.to_be_bytes());
+            response[44..48].copy_from_slice(&fraction.to_be_bytes());
+            socket.send_to(&response, peer).unwrap();
+        });
+        addr
+    }
+
+    #[test]
+    fn measure_clock_skew() {
+        let server = spawn_ntp_server(Duration::from_secs(10)).to_string();
+        let skew = clock_skew(&server, NTP_TIMEOUT).unwrap();
+        assert!(skew > Duration::from_secs(9) && skew < Duration::from_secs(11), "{skew:?}");
+
+        let server = spawn_ntp_server(Duration::from_secs(10)).to_string();
+        assert_matches!(
+            check_clock_skew(&server, Duration::from_secs(2)),
+            Err(PreflightError::ClockSkew { .. })
+        );
+
+        let server = spawn_ntp_server(Duration::ZERO).to_string();
+        assert_matches!(check_clock_skew(&server, Duration::from_secs(2)), Ok(()));
+    }
+
+    #[test]
+    fn port_in_use() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let addr = listener.local_addr().unwrap();
+        assert_matches!(
+            check_tcp_port(addr),
+            Err(PreflightError::PortInUse { protocol: "TCP", .. })
+        );
+        drop(listener);
+        assert_matches!(check_tcp_port(addr), Ok(()));
+
+        let socket = UdpSocket::bind(("127.0.0.1", 0)).unwrap();
+        let addr = socket.local_addr().unwrap();
+        assert_matches!(
+            check_udp_port(addr),
+            Err(PreflightError::PortInUse { protocol: "UDP", .. })
+        );
+    }
+
+    #[test]
+    fn disk_space() {
+        assert_eq!(projected_disk_usage(Chain::mainnet(), true), Some(1_300 * GIB));
+        assert_eq!(projected_disk_usage(Chain::dev(), false), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let data_dir = dir.path().join("mainnet");
+        assert_matches!(check_disk_space(&data_dir, 0), Ok(()));
+        assert_matches!(
+            check_disk_space(&data_dir, u64::MAX),
+            Err(PreflightError::InsufficientDiskSpace { .. })
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use reth_node_api::{ConfigureEvm, ConfigureEvmEnv};
 use reth_primitives::{
+    constants::MAX_CODE_SIZE,
     revm::{config::revm_spec, env::fill_tx_env},
     revm_primitives::{AnalysisKind, CfgEnvWithHandlerCfg, TxEnv},
     Address, ChainSpec, Head, Header, Transaction, U256,
@@ -41,6 +42,11 @@ impl ConfigureEvmEnv for EthEvmConfig {
         cfg_env.perf_analyse_created_bytecodes = AnalysisKind::Analyse;
 
         cfg_env.handler_cfg.spec_id = spec_id;
+
+        // revm also derives its init code size limit from this, as twice the contract code size
+        if chain_spec.max_code_size != MAX_CODE_SIZE {
+            cfg_env.limit_contract_code_size = Some(chain_spec.max_code_size);
+        }
     }
 }
 
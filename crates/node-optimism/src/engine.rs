@@ -3,7 +3,7 @@ use reth_node_api::{
     EngineApiMessageVersion, EngineTypes, PayloadOrAttributes,
 };
 use reth_payload_builder::{EthBuiltPayload, OptimismPayloadBuilderAttributes};
-use reth_primitives::{ChainSpec, Hardfork};
+use reth_primitives::ChainSpec;
 use reth_rpc_types::engine::OptimismPayloadAttributes;
 
 /// The types used in the optimism beacon consensus engine.
@@ -49,7 +49,7 @@ pub fn validate_withdrawals_presence(
     timestamp: u64,
     has_withdrawals: bool,
 ) -> Result<(), AttributesValidationError> {
-    let is_shanghai = chain_spec.fork(Hardfork::Canyon).active_at_timestamp(timestamp);
+    let is_shanghai = chain_spec.is_canyon_active_at_timestamp(timestamp);
 
     match version {
         EngineApiMessageVersion::V1 => {
@@ -1,5 +1,6 @@
 use reth_node_api::{ConfigureEvm, ConfigureEvmEnv};
 use reth_primitives::{
+    constants::MAX_CODE_SIZE,
     revm::{config::revm_spec, env::fill_op_tx_env},
     revm_primitives::{AnalysisKind, CfgEnvWithHandlerCfg, HandlerCfg, SpecId, TxEnv},
     Address, Bytes, ChainSpec, Head, Header, Transaction, U256,
@@ -43,6 +44,11 @@ impl ConfigureEvmEnv for OptimismEvmConfig {
 
         cfg_env.handler_cfg.spec_id = spec_id;
         cfg_env.handler_cfg.is_optimism = chain_spec.is_optimism();
+
+        // revm also derives its init code size limit from this, as twice the contract code size
+        if chain_spec.max_code_size != MAX_CODE_SIZE {
+            cfg_env.limit_contract_code_size = Some(chain_spec.max_code_size);
+        }
     }
 }
 
@@ -7,10 +7,11 @@
 )]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
-use crate::metrics::PayloadBuilderMetrics;
+use crate::{metrics::PayloadBuilderMetrics, prewarm::PrewarmHints};
 use alloy_rlp::Encodable;
 use futures_core::ready;
 use futures_util::FutureExt;
+use parking_lot::Mutex;
 use reth_interfaces::RethResult;
 use reth_node_api::{BuiltPayload, PayloadBuilderAttributes};
 use reth_payload_builder::{
@@ -23,10 +24,12 @@ use reth_primitives::{
     proofs, BlockNumberOrTag, Bytes, ChainSpec, SealedBlock, Withdrawals, B256, U256,
 };
 use reth_provider::{
-    BlockReaderIdExt, BlockSource, CanonStateNotification, ProviderError, StateProviderFactory,
+    BlockReaderIdExt, BlockSource, CanonStateNotification, HotAccountsReader, ProviderError,
+    StateProviderFactory,
 };
-use reth_revm::state_change::{
-    apply_beacon_root_contract_call, post_block_withdrawals_balance_increments,
+use reth_revm::{
+    database::StateProviderDatabase,
+    state_change::{apply_beacon_root_contract_call, post_block_withdrawals_balance_increments},
 };
 use reth_tasks::TaskSpawner;
 use reth_transaction_pool::TransactionPool;
@@ -48,6 +51,7 @@ use tokio::{
 use tracing::{debug, trace, warn};
 
 mod metrics;
+pub mod prewarm;
 
 /// The [`PayloadJobGenerator`] that creates [`BasicPayloadJob`]s.
 #[derive(Debug)]
@@ -69,7 +73,9 @@ pub struct BasicPayloadJobGenerator<Client, Pool, Tasks, Builder> {
     /// See [PayloadBuilder]
     builder: Builder,
     /// Stored cached_reads for new payload jobs.
-    pre_cached: Option<PrecachedState>,
+    ///
+    /// This is shared with the task that warms it with the state of pending transactions.
+    pre_cached: Arc<Mutex<Option<PrecachedState>>>,
 }
 
 // === impl BasicPayloadJobGenerator ===
@@ -92,7 +98,7 @@ impl<Client, Pool, Tasks, Builder> BasicPayloadJobGenerator<Client, Pool, Tasks,
             config,
             chain_spec,
             builder,
-            pre_cached: None,
+            pre_cached: Default::default(),
         }
     }
 
@@ -128,7 +134,7 @@ impl<Client, Pool, Tasks, Builder> BasicPayloadJobGenerator<Client, Pool, Tasks,
     /// Returns the pre-cached reads for the given parent block if it matches the cached state's
     /// block.
     fn maybe_pre_cached(&self, parent: B256) -> Option<CachedReads> {
-        self.pre_cached.as_ref().filter(|pc| pc.block == parent).map(|pc| pc.cached.clone())
+        self.pre_cached.lock().as_ref().filter(|pc| pc.block == parent).map(|pc| pc.cached.clone())
     }
 }
 
@@ -137,7 +143,7 @@ impl<Client, Pool, Tasks, Builder> BasicPayloadJobGenerator<Client, Pool, Tasks,
 impl<Client, Pool, Tasks, Builder> PayloadJobGenerator
     for BasicPayloadJobGenerator<Client, Pool, Tasks, Builder>
 where
-    Client: StateProviderFactory + BlockReaderIdExt + Clone + Unpin + 'static,
+    Client: StateProviderFactory + BlockReaderIdExt + HotAccountsReader + Clone + Unpin + 'static,
     Pool: TransactionPool + Unpin + 'static,
     Tasks: TaskSpawner + Clone + Unpin + 'static,
     Builder: PayloadBuilder<Pool, Client> + Unpin + 'static,
@@ -210,14 +216,52 @@ where
                 }
             }
 
-            self.pre_cached = Some(PrecachedState { block: committed.tip().hash(), cached });
+            let block = committed.tip().hash();
+            *self.pre_cached.lock() = Some(PrecachedState { block, cached: cached.clone() });
+
+            // warm the cache with the state the best transactions of the pool will touch, before
+            // the next payload job needs it
+            if self.config.prewarm_transactions == 0 {
+                return
+            }
+            let mut hints = PrewarmHints::from_pool(&self.pool, self.config.prewarm_transactions);
+            if hints.is_empty() {
+                return
+            }
+            let client = self.client.clone();
+            let pre_cached = Arc::clone(&self.pre_cached);
+            self.executor.spawn_blocking(Box::pin(async move {
+                let state_provider = match client.state_by_block_hash(block) {
+                    Ok(state_provider) => state_provider,
+                    Err(err) => {
+                        debug!(target: "payload_builder", %err, ?block, "failed to prewarm state");
+                        return
+                    }
+                };
+                if let Err(err) = hints.insert_hot_slots(&client) {
+                    debug!(target: "payload_builder", %err, "failed to read hot accounts");
+                }
+                let state = StateProviderDatabase::new(&state_provider);
+                if let Err(err) = hints.warm(&mut cached, &state) {
+                    debug!(target: "payload_builder", %err, ?block, "failed to prewarm state");
+                    return
+                }
+                trace!(target: "payload_builder", accounts = hints.len(), ?block, "prewarmed state");
+
+                // only replace the cache if no newer block arrived in the meantime
+                let mut pre_cached = pre_cached.lock();
+                if pre_cached.as_ref().is_some_and(|pc| pc.block == block) {
+                    *pre_cached = Some(PrecachedState { block, cached });
+                }
+            }));
         }
     }
 }
 
 /// Pre-filled [CachedReads] for a specific block.
 ///
-/// This is extracted from the [CanonStateNotification] for the tip block.
+/// This is extracted from the [CanonStateNotification] for the tip block, and then warmed with the
+/// state the best transactions of the pool touch, see [PrewarmHints].
 #[derive(Debug, Clone)]
 pub struct PrecachedState {
     /// The block for which the state is pre-cached.
@@ -253,6 +297,8 @@ pub struct BasicPayloadJobGeneratorConfig {
     deadline: Duration,
     /// Maximum number of tasks to spawn for building a payload.
     max_payload_tasks: usize,
+    /// Number of the best pool transactions whose state is prefetched for the next payload.
+    prewarm_transactions: usize,
 }
 
 // === impl BasicPayloadJobGeneratorConfig ===
@@ -281,6 +327,15 @@ impl BasicPayloadJobGeneratorConfig {
         self
     }
 
+    /// Sets the number of the best pool transactions whose accounts and storage are prefetched
+    /// when a new block is committed, so that the next payload job starts with a warm cache.
+    ///
+    /// Setting this to 0 disables prewarming.
+    pub fn prewarm_transactions(mut self, prewarm_transactions: usize) -> Self {
+        self.prewarm_transactions = prewarm_transactions;
+        self
+    }
+
     /// Sets the data to include in the block's extra data field.
     ///
     /// Defaults to the current client version: `rlp(RETH_CLIENT_VERSION)`.
@@ -309,6 +364,7 @@ impl Default for BasicPayloadJobGeneratorConfig {
             // 12s slot time
             deadline: SLOT_DURATION,
             max_payload_tasks: 3,
+            prewarm_transactions: 100,
         }
     }
 }
@@ -0,0 +1,82 @@
+//! Warming of the [CachedReads] of upcoming payloads with state of pending transactions.
+
+use reth_interfaces::provider::ProviderResult;
+use reth_payload_builder::database::CachedReads;
+use reth_primitives::{Address, KECCAK_EMPTY, U256};
+use reth_provider::HotAccountsReader;
+use reth_transaction_pool::{PoolTransaction, TransactionPool};
+use revm::DatabaseRef;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Accounts and storage slots that the best transactions of the pool are likely to touch.
+///
+/// These are read into the [CachedReads] of the next payload before the payload job is started,
+/// so that the first build attempt doesn't have to hit the database for them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrewarmHints {
+    /// The accounts and the storage slots of them to load.
+    accounts: BTreeMap<Address, BTreeSet<U256>>,
+}
+
+impl PrewarmHints {
+    /// Collects the hints of at most `max_transactions` of the best transactions of the pool.
+    pub fn from_pool<Pool: TransactionPool>(pool: &Pool, max_transactions: usize) -> Self {
+        let mut hints = Self::default();
+        for tx in pool.best_transactions().take(max_transactions) {
+            hints.insert_transaction(&tx.transaction);
+        }
+        hints
+    }
+
+    /// Adds the state the transaction is likely to touch.
+    ///
+    /// This is the sender, the callee including its code, and everything in the access list.
+    pub fn insert_transaction<T: PoolTransaction>(&mut self, tx: &T) {
+        self.accounts.entry(tx.sender()).or_default();
+        if let Some(to) = tx.to() {
+            self.accounts.entry(to).or_default();
+        }
+        if let Some(access_list) = tx.access_list() {
+            for (address, slots) in access_list.flatten() {
+                self.accounts.entry(address).or_default().extend(slots);
+            }
+        }
+    }
+
+    /// Adds the hottest storage slots of the hinted accounts, as recorded from executed blocks.
+    ///
+    /// This covers the storage of contracts that transactions without an access list touch.
+    pub fn insert_hot_slots<P: HotAccountsReader>(&mut self, provider: &P) -> ProviderResult<()> {
+        for (address, slots) in &mut self.accounts {
+            if let Some(stats) = provider.hot_account(*address)? {
+                slots.extend(stats.slots.iter().map(|slot| U256::from_be_bytes(slot.key.0)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if there is nothing to warm.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// Returns the number of accounts to warm.
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    /// Reads all hinted accounts, their code and storage slots from `db` into `cached`.
+    pub fn warm<DB: DatabaseRef>(&self, cached: &mut CachedReads, db: DB) -> Result<(), DB::Error> {
+        let db = cached.as_db(db);
+        for (address, slots) in &self.accounts {
+            let Some(info) = db.basic_ref(*address)? else { continue };
+            if info.code.is_none() && info.code_hash != KECCAK_EMPTY {
+                db.code_by_hash_ref(info.code_hash)?;
+            }
+            for slot in slots {
+                db.storage_ref(*address, *slot)?;
+            }
+        }
+        Ok(())
+    }
+}
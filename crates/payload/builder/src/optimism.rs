@@ -3,7 +3,7 @@ use alloy_rlp::{Encodable, Error as DecodeError};
 use reth_node_api::PayloadBuilderAttributes;
 use reth_primitives::{
     revm::config::revm_spec_by_timestamp_after_merge,
-    revm_primitives::{BlobExcessGasAndPrice, BlockEnv, CfgEnv, CfgEnvWithHandlerCfg, SpecId},
+    revm_primitives::{BlockEnv, CfgEnv, CfgEnvWithHandlerCfg, SpecId},
     Address, ChainSpec, Header, TransactionSigned, Withdrawals, B256, U256,
 };
 use reth_rpc_types::engine::{OptimismPayloadAttributes, PayloadId};
@@ -107,8 +107,9 @@ impl PayloadBuilderAttributes for OptimismPayloadBuilderAttributes {
 
         // if the parent block did not have excess blob gas (i.e. it was pre-cancun), but it is
         // cancun now, we need to set the excess blob gas to the default value
+        let blob_params = chain_spec.blob_params_at_timestamp(self.timestamp());
         let blob_excess_gas_and_price = parent
-            .next_block_excess_blob_gas()
+            .next_block_excess_blob_gas(blob_params)
             .or_else(|| {
                 if spec_id.is_enabled_in(SpecId::CANCUN) {
                     // default excess blob gas is zero
@@ -117,7 +118,7 @@ impl PayloadBuilderAttributes for OptimismPayloadBuilderAttributes {
                     None
                 }
             })
-            .map(BlobExcessGasAndPrice::new);
+            .map(|excess_blob_gas| blob_params.blob_excess_gas_and_price(excess_blob_gas));
 
         let block_env = BlockEnv {
             number: U256::from(parent.number + 1),
@@ -125,12 +126,11 @@ impl PayloadBuilderAttributes for OptimismPayloadBuilderAttributes {
             timestamp: U256::from(self.timestamp()),
             difficulty: U256::ZERO,
             prevrandao: Some(self.prev_randao()),
-            gas_limit: U256::from(parent.gas_limit),
+            // follow the gas limit schedule of the chain, if any
+            gas_limit: U256::from(chain_spec.next_block_gas_limit(parent, self.timestamp())),
             // calculate basefee based on parent block's gas usage
             basefee: U256::from(
-                parent
-                    .next_block_base_fee(chain_spec.base_fee_params(self.timestamp()))
-                    .unwrap_or_default(),
+                chain_spec.next_block_base_fee(parent, self.timestamp()).unwrap_or_default(),
             ),
             // calculate excess gas based on parent block's blob gas usage
             blob_excess_gas_and_price,
@@ -20,10 +20,7 @@ mod builder {
         error::PayloadBuilderError, EthBuiltPayload, EthPayloadBuilderAttributes,
     };
     use reth_primitives::{
-        constants::{
-            eip4844::MAX_DATA_GAS_PER_BLOCK, BEACON_NONCE, EMPTY_RECEIPTS, EMPTY_TRANSACTIONS,
-        },
-        eip4844::calculate_excess_blob_gas,
+        constants::{BEACON_NONCE, EMPTY_RECEIPTS, EMPTY_TRANSACTIONS},
         proofs,
         revm::env::tx_env_with_recovered,
         Block, Header, IntoRecoveredTransaction, Receipt, Receipts, EMPTY_OMMER_ROOT_HASH, U256,
@@ -124,17 +121,21 @@ mod builder {
             let mut blob_gas_used = None;
 
             if chain_spec.is_cancun_active_at_timestamp(attributes.timestamp) {
-                excess_blob_gas = if chain_spec
-                    .is_cancun_active_at_timestamp(parent_block.timestamp)
-                {
-                    let parent_excess_blob_gas = parent_block.excess_blob_gas.unwrap_or_default();
-                    let parent_blob_gas_used = parent_block.blob_gas_used.unwrap_or_default();
-                    Some(calculate_excess_blob_gas(parent_excess_blob_gas, parent_blob_gas_used))
-                } else {
-                    // for the first post-fork block, both parent.blob_gas_used and
-                    // parent.excess_blob_gas are evaluated as 0
-                    Some(calculate_excess_blob_gas(0, 0))
-                };
+                let blob_params = chain_spec.blob_params_at_timestamp(attributes.timestamp);
+                excess_blob_gas =
+                    if chain_spec.is_cancun_active_at_timestamp(parent_block.timestamp) {
+                        let parent_excess_blob_gas =
+                            parent_block.excess_blob_gas.unwrap_or_default();
+                        let parent_blob_gas_used = parent_block.blob_gas_used.unwrap_or_default();
+                        Some(blob_params.next_block_excess_blob_gas(
+                            parent_excess_blob_gas,
+                            parent_blob_gas_used,
+                        ))
+                    } else {
+                        // for the first post-fork block, both parent.blob_gas_used and
+                        // parent.excess_blob_gas are evaluated as 0
+                        Some(blob_params.next_block_excess_blob_gas(0, 0))
+                    };
 
                 blob_gas_used = Some(0);
             }
@@ -203,6 +204,8 @@ mod builder {
         debug!(target: "payload_builder", id=%attributes.id, parent_hash = ?parent_block.hash(), parent_number = parent_block.number, "building new payload");
         let mut cumulative_gas_used = 0;
         let mut sum_blob_gas_used = 0;
+        let max_blob_gas_per_block =
+            chain_spec.blob_params_at_timestamp(attributes.timestamp).max_blob_gas_per_block();
         let block_gas_limit: u64 = initialized_block_env.gas_limit.try_into().unwrap_or(u64::MAX);
         let base_fee = initialized_block_env.basefee.to::<u64>();
 
@@ -250,7 +253,7 @@ mod builder {
             // the EIP-4844 can still fit in the block
             if let Some(blob_tx) = tx.transaction.as_eip4844() {
                 let tx_blob_gas = blob_tx.blob_gas();
-                if sum_blob_gas_used + tx_blob_gas > MAX_DATA_GAS_PER_BLOCK {
+                if sum_blob_gas_used + tx_blob_gas > max_blob_gas_per_block {
                     // we can't fit this _blob_ transaction into the block, so we mark it as
                     // invalid, which removes its dependent transactions from
                     // the iterator. This is similar to the gas limit condition
@@ -306,7 +309,7 @@ mod builder {
                 sum_blob_gas_used += tx_blob_gas;
 
                 // if we've reached the max data gas per block, we can skip blob txs entirely
-                if sum_blob_gas_used == MAX_DATA_GAS_PER_BLOCK {
+                if sum_blob_gas_used == max_blob_gas_per_block {
                     best_txs.skip_blobs();
                 }
             }
@@ -373,14 +376,19 @@ mod builder {
                 executed_txs.iter().filter(|tx| tx.is_eip4844()).map(|tx| tx.hash).collect(),
             )?;
 
+            let blob_params = chain_spec.blob_params_at_timestamp(attributes.timestamp);
+
             excess_blob_gas = if chain_spec.is_cancun_active_at_timestamp(parent_block.timestamp) {
                 let parent_excess_blob_gas = parent_block.excess_blob_gas.unwrap_or_default();
                 let parent_blob_gas_used = parent_block.blob_gas_used.unwrap_or_default();
-                Some(calculate_excess_blob_gas(parent_excess_blob_gas, parent_blob_gas_used))
+                Some(
+                    blob_params
+                        .next_block_excess_blob_gas(parent_excess_blob_gas, parent_blob_gas_used),
+                )
             } else {
                 // for the first post-fork block, both parent.blob_gas_used and
                 // parent.excess_blob_gas are evaluated as 0
-                Some(calculate_excess_blob_gas(0, 0))
+                Some(blob_params.next_block_excess_blob_gas(0, 0))
             };
 
             blob_gas_used = Some(sum_blob_gas_used);
@@ -21,11 +21,10 @@ mod builder {
     };
     use reth_primitives::{
         constants::{BEACON_NONCE, EMPTY_RECEIPTS, EMPTY_TRANSACTIONS},
-        eip4844::calculate_excess_blob_gas,
         proofs,
         revm::env::tx_env_with_recovered,
-        Block, Hardfork, Header, IntoRecoveredTransaction, Receipt, Receipts, TxType,
-        EMPTY_OMMER_ROOT_HASH, U256,
+        Block, Header, IntoRecoveredTransaction, Receipt, Receipts, TxType, EMPTY_OMMER_ROOT_HASH,
+        U256,
     };
     use reth_provider::{BundleStateWithReceipts, StateProviderFactory};
     use reth_revm::database::StateProviderDatabase;
@@ -163,17 +162,22 @@ mod builder {
             let mut blob_gas_used = None;
 
             if chain_spec.is_cancun_active_at_timestamp(attributes.payload_attributes.timestamp) {
-                excess_blob_gas = if chain_spec
-                    .is_cancun_active_at_timestamp(parent_block.timestamp)
-                {
-                    let parent_excess_blob_gas = parent_block.excess_blob_gas.unwrap_or_default();
-                    let parent_blob_gas_used = parent_block.blob_gas_used.unwrap_or_default();
-                    Some(calculate_excess_blob_gas(parent_excess_blob_gas, parent_blob_gas_used))
-                } else {
-                    // for the first post-fork block, both parent.blob_gas_used and
-                    // parent.excess_blob_gas are evaluated as 0
-                    Some(calculate_excess_blob_gas(0, 0))
-                };
+                let blob_params =
+                    chain_spec.blob_params_at_timestamp(attributes.payload_attributes.timestamp);
+                excess_blob_gas =
+                    if chain_spec.is_cancun_active_at_timestamp(parent_block.timestamp) {
+                        let parent_excess_blob_gas =
+                            parent_block.excess_blob_gas.unwrap_or_default();
+                        let parent_blob_gas_used = parent_block.blob_gas_used.unwrap_or_default();
+                        Some(blob_params.next_block_excess_blob_gas(
+                            parent_excess_blob_gas,
+                            parent_blob_gas_used,
+                        ))
+                    } else {
+                        // for the first post-fork block, both parent.blob_gas_used and
+                        // parent.excess_blob_gas are evaluated as 0
+                        Some(blob_params.next_block_excess_blob_gas(0, 0))
+                    };
 
                 blob_gas_used = Some(0);
             }
@@ -264,10 +268,8 @@ mod builder {
 
         let block_number = initialized_block_env.number.to::<u64>();
 
-        let is_regolith = chain_spec.is_fork_active_at_timestamp(
-            Hardfork::Regolith,
-            attributes.payload_attributes.timestamp,
-        );
+        let is_regolith =
+            chain_spec.is_regolith_active_at_timestamp(attributes.payload_attributes.timestamp);
 
         // apply eip-4788 pre block contract call
         pre_block_beacon_root_contract_call(
@@ -279,12 +281,14 @@ mod builder {
             &attributes,
         )?;
 
-        // Ensure that the create2deployer is force-deployed at the canyon transition. Optimism
-        // blocks will always have at least a single transaction in them (the L1 info transaction),
-        // so we can safely assume that this will always be triggered upon the transition and that
-        // the above check for empty blocks will never be hit on OP chains.
-        reth_revm::optimism::ensure_create2_deployer(
+        // Apply the state migrations of forks activating with this block, e.g. the create2deployer
+        // that is force-deployed at the canyon transition. Optimism blocks will always have at
+        // least a single transaction in them (the L1 info transaction), so we can safely assume
+        // that this will always be triggered upon the transition and that the above check for
+        // empty blocks will never be hit on OP chains.
+        reth_revm::optimism::apply_fork_state_migrations(
             chain_spec.clone(),
+            block_number,
             attributes.payload_attributes.timestamp,
             &mut db,
         )
@@ -377,10 +381,7 @@ mod builder {
                 // receipt hashes should be computed when set. The state transition process
                 // ensures this is only set for post-Canyon deposit transactions.
                 deposit_receipt_version: chain_spec
-                    .is_fork_active_at_timestamp(
-                        Hardfork::Canyon,
-                        attributes.payload_attributes.timestamp,
-                    )
+                    .is_canyon_active_at_timestamp(attributes.payload_attributes.timestamp)
                     .then_some(1),
             }));
 
@@ -524,14 +525,19 @@ mod builder {
 
         // only determine cancun fields when active
         if chain_spec.is_cancun_active_at_timestamp(attributes.payload_attributes.timestamp) {
+            let blob_params =
+                chain_spec.blob_params_at_timestamp(attributes.payload_attributes.timestamp);
             excess_blob_gas = if chain_spec.is_cancun_active_at_timestamp(parent_block.timestamp) {
                 let parent_excess_blob_gas = parent_block.excess_blob_gas.unwrap_or_default();
                 let parent_blob_gas_used = parent_block.blob_gas_used.unwrap_or_default();
-                Some(calculate_excess_blob_gas(parent_excess_blob_gas, parent_blob_gas_used))
+                Some(
+                    blob_params
+                        .next_block_excess_blob_gas(parent_excess_blob_gas, parent_blob_gas_used),
+                )
             } else {
                 // for the first post-fork block, both parent.blob_gas_used and
                 // parent.excess_blob_gas are evaluated as 0
-                Some(calculate_excess_blob_gas(0, 0))
+                Some(blob_params.next_block_excess_blob_gas(0, 0))
             };
 
             blob_gas_used = Some(0);
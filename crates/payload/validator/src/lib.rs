@@ -121,3 +121,99 @@ impl ExecutionPayloadValidator {
         Ok(sealed_block)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{
+        hex, proofs, Block, ChainSpecBuilder, Header, TransactionSigned, Withdrawals, B256,
+    };
+    use reth_rpc_types::engine::CancunPayloadFields;
+    use reth_rpc_types_compat::engine::payload::try_block_to_payload;
+
+    fn cancun_block_with_blob_tx() -> Block {
+        let blob_tx_raw = hex!("03f901388501a1f0ff430c843b9aca00843b9aca0082520894e7249813d8ccf6fa95a2203f46a64166073d58878080c005f8c6a00195f6dff17753fc89b60eac6477026a805116962c9e412de8015c0484e661c1a001aae314061d4f5bbf158f15d9417a238f9589783f58762cd39d05966b3ba2fba0013f5be9b12e7da06f0dd11a7bdc4e0db8ef33832acc23b183bd0a2c1408a757a0019d9ac55ea1a615d92965e04d960cb3be7bff121a381424f1f22865bd582e09a001def04412e76df26fefe7b0ed5e10580918ae4f355b074c0cfe5d0259157869a0011c11a415db57e43db07aef0de9280b591d65ca0cce36c7002507f8191e5d4a80a0c89b59970b119187d97ad70539f1624bbede92648e2dc007890f9658a88756c5a06fb2e3d4ce2c438c0856c2de34948b7032b1aadc4642a9666228ea8cdc7786b7");
+        let transaction = TransactionSigned::decode_enveloped(&mut &blob_tx_raw[..]).unwrap();
+        let withdrawals = Withdrawals::default();
+
+        Block {
+            header: Header {
+                base_fee_per_gas: Some(7),
+                transactions_root: proofs::calculate_transaction_root(&[transaction.clone()]),
+                withdrawals_root: Some(proofs::calculate_withdrawals_root(&withdrawals)),
+                blob_gas_used: Some(0xc0000),
+                excess_blob_gas: Some(0x580000),
+                parent_beacon_block_root: Some(B256::with_last_byte(1)),
+                ..Default::default()
+            },
+            body: vec![transaction],
+            ommers: vec![],
+            withdrawals: Some(withdrawals),
+        }
+    }
+
+    fn cancun_fields(block: &SealedBlock) -> CancunPayloadFields {
+        CancunPayloadFields {
+            parent_beacon_block_root: block.parent_beacon_block_root.unwrap(),
+            versioned_hashes: block.blob_versioned_hashes_iter().copied().collect(),
+        }
+    }
+
+    #[test]
+    fn well_formed_cancun_payload() {
+        let validator = ExecutionPayloadValidator::new(Arc::new(
+            ChainSpecBuilder::mainnet().cancun_activated().build(),
+        ));
+        let block = cancun_block_with_blob_tx().seal_slow();
+        let cancun_fields = cancun_fields(&block);
+        assert_eq!(cancun_fields.versioned_hashes.len(), 6);
+
+        let payload = try_block_to_payload(block.clone());
+        assert!(matches!(payload, ExecutionPayload::V3(_)));
+        assert_eq!(
+            validator.ensure_well_formed_payload(payload, Some(cancun_fields).into()).unwrap(),
+            block
+        );
+    }
+
+    #[test]
+    fn cancun_fields_mismatch() {
+        let validator = ExecutionPayloadValidator::new(Arc::new(
+            ChainSpecBuilder::mainnet().cancun_activated().build(),
+        ));
+        let block = cancun_block_with_blob_tx().seal_slow();
+        let mut cancun_fields = cancun_fields(&block);
+        let payload = try_block_to_payload(block);
+
+        // the versioned hashes must match the block's blob transactions in order
+        cancun_fields.versioned_hashes.reverse();
+        assert!(matches!(
+            validator.ensure_well_formed_payload(payload.clone(), Some(cancun_fields).into()),
+            Err(PayloadError::InvalidVersionedHashes)
+        ));
+
+        // without the parent beacon block root the block hash can't match
+        assert!(matches!(
+            validator.ensure_well_formed_payload(payload, None::<CancunPayloadFields>.into()),
+            Err(PayloadError::BlockHash { .. })
+        ));
+    }
+
+    #[test]
+    fn pre_cancun_payload_with_blob_transactions() {
+        let validator = ExecutionPayloadValidator::new(Arc::new(
+            ChainSpecBuilder::mainnet().shanghai_activated().build(),
+        ));
+        let mut block = cancun_block_with_blob_tx();
+        block.header.parent_beacon_block_root = None;
+        block.header.blob_gas_used = None;
+        block.header.excess_blob_gas = None;
+
+        let payload = try_block_to_payload(block.seal_slow());
+        assert!(matches!(payload, ExecutionPayload::V2(_)));
+        assert!(matches!(
+            validator.ensure_well_formed_payload(payload, None::<CancunPayloadFields>.into()),
+            Err(PayloadError::PreCancunBlockWithBlobTransactions)
+        ));
+    }
+}
@@ -20,7 +20,8 @@
 ///   denominator.
 ///
 /// Returns:
-/// The calculated base fee for the next block as a `u64`.
+/// The calculated base fee for the next block as a `u64`, which is never lower than the
+/// `minimum_base_fee` of the `base_fee_params`, if set.
 ///
 /// For more information, refer to the [EIP-1559 spec](https://github.com/ethereum/EIPs/blob/master/EIPS/eip-1559.md).
 pub fn calculate_next_block_base_fee(
@@ -32,7 +33,7 @@ pub fn calculate_next_block_base_fee(
     // Calculate the target gas by dividing the gas limit by the elasticity multiplier.
     let gas_target = gas_limit / base_fee_params.elasticity_multiplier;
 
-    match gas_used.cmp(&gas_target) {
+    let next_base_fee = match gas_used.cmp(&gas_target) {
         // If the gas used in the current block is equal to the gas target, the base fee remains the
         // same (no increase).
         std::cmp::Ordering::Equal => base_fee,
@@ -58,7 +59,10 @@ pub fn calculate_next_block_base_fee(
                     as u64,
             )
         }
-    }
+    };
+
+    // Chains with a minimum base fee don't let the base fee drop below it.
+    next_base_fee.max(base_fee_params.minimum_base_fee.unwrap_or_default())
 }
 
 #[cfg(test)]
@@ -97,6 +101,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn calculate_base_fee_with_minimum() {
+        let params = crate::BaseFeeParams::ethereum().with_minimum_base_fee(1_000_000);
+
+        // empty block, the base fee would drop by 12.5%
+        assert_eq!(calculate_next_block_base_fee(0, 30_000_000, 1_100_000, params), 1_000_000);
+        assert_eq!(calculate_next_block_base_fee(0, 30_000_000, 2_000_000, params), 1_750_000);
+        // the floor also lifts a base fee that is below the minimum
+        assert_eq!(calculate_next_block_base_fee(15_000_000, 30_000_000, 7, params), 1_000_000);
+    }
+
     #[cfg(feature = "optimism")]
     #[test]
     fn calculate_optimism_base_fee_success() {
@@ -2,7 +2,8 @@ use crate::{
     Address, Bytes, GotExpected, Header, SealedHeader, TransactionSigned,
     TransactionSignedEcRecovered, Withdrawals, B256,
 };
-use alloy_rlp::{RlpDecodable, RlpEncodable};
+use alloy_rlp::{Decodable, Encodable, RlpDecodable, RlpEncodable};
+use bytes::BufMut;
 use reth_codecs::derive_arbitrary;
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
@@ -519,11 +520,213 @@ impl BlockBody {
     }
 }
 
+/// A [BlockBody] as received from the network, with its transactions kept in their encoding.
+///
+/// Every transaction is a slice of a single buffer that holds all transactions of the body, as
+/// they are encoded in a `BlockBodies` message: legacy transactions as RLP lists and typed
+/// transactions as RLP strings of their EIP-2718 envelope. Decoding the transactions is deferred
+/// until [RawBlockBody::try_into_body] is called, while the commitments of the body can be checked
+/// against the header beforehand.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RawBlockBody {
+    /// The encoded transactions in the block
+    pub transactions: Vec<Bytes>,
+    /// Uncle headers for the given block
+    pub ommers: Vec<Header>,
+    /// Withdrawals in the block.
+    pub withdrawals: Option<Withdrawals>,
+}
+
+impl RawBlockBody {
+    /// Decodes the transactions and returns the [BlockBody].
+    ///
+    /// Returns an error if any of the transactions is malformed.
+    pub fn try_into_body(self) -> alloy_rlp::Result<BlockBody> {
+        let transactions = self
+            .transactions
+            .iter()
+            .map(|tx| {
+                let mut buf = tx.as_ref();
+                let transaction = TransactionSigned::decode(&mut buf)?;
+                if !buf.is_empty() {
+                    return Err(alloy_rlp::Error::UnexpectedLength)
+                }
+                Ok(transaction)
+            })
+            .collect::<alloy_rlp::Result<_>>()?;
+
+        Ok(BlockBody { transactions, ommers: self.ommers, withdrawals: self.withdrawals })
+    }
+
+    /// Calculate the transaction root for the block body, without decoding the transactions.
+    pub fn calculate_tx_root(&self) -> B256 {
+        crate::proofs::ordered_trie_root_with_encoder(&self.transactions, |tx, buf| {
+            buf.put_slice(envelope(tx))
+        })
+    }
+
+    /// Calculate the ommers root for the block body.
+    pub fn calculate_ommers_root(&self) -> B256 {
+        crate::proofs::calculate_ommers_root(&self.ommers)
+    }
+
+    /// Calculate the withdrawals root for the block body, if withdrawals exist. If there are no
+    /// withdrawals, this will return `None`.
+    pub fn calculate_withdrawals_root(&self) -> Option<B256> {
+        self.withdrawals.as_ref().map(|w| crate::proofs::calculate_withdrawals_root(w))
+    }
+
+    /// Calculates a heuristic for the in-memory size of the [RawBlockBody].
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.transactions.iter().map(|tx| tx.len()).sum::<usize>() +
+            self.transactions.capacity() * std::mem::size_of::<Bytes>() +
+            self.ommers.iter().map(Header::size).sum::<usize>() +
+            self.ommers.capacity() * std::mem::size_of::<Header>() +
+            self.withdrawals
+                .as_ref()
+                .map_or(std::mem::size_of::<Option<Withdrawals>>(), Withdrawals::total_size)
+    }
+
+    /// Returns the length of the payload of the transactions list.
+    fn transactions_payload_length(&self) -> usize {
+        self.transactions.iter().map(|tx| tx.len()).sum()
+    }
+
+    /// Returns the length of the payload of the body list.
+    fn payload_length(&self) -> usize {
+        let transactions_length = self.transactions_payload_length();
+        transactions_length +
+            alloy_rlp::length_of_length(transactions_length) +
+            self.ommers.length() +
+            self.withdrawals.as_ref().map_or(0, Encodable::length)
+    }
+}
+
+/// Returns the EIP-2718 envelope of a transaction in its `BlockBodies` encoding.
+///
+/// Typed transactions are stripped of their RLP string header, legacy transactions are returned
+/// as is.
+fn envelope(tx: &[u8]) -> &[u8] {
+    let mut payload = tx;
+    match alloy_rlp::Header::decode(&mut payload) {
+        Ok(header) if !header.list => payload,
+        _ => tx,
+    }
+}
+
+impl From<BlockBody> for RawBlockBody {
+    fn from(body: BlockBody) -> Self {
+        let transactions = body
+            .transactions
+            .iter()
+            .map(|tx| {
+                let mut buf = Vec::with_capacity(tx.length());
+                tx.encode(&mut buf);
+                buf.into()
+            })
+            .collect();
+
+        Self { transactions, ommers: body.ommers, withdrawals: body.withdrawals }
+    }
+}
+
+impl Encodable for RawBlockBody {
+    fn encode(&self, out: &mut dyn BufMut) {
+        alloy_rlp::Header { list: true, payload_length: self.payload_length() }.encode(out);
+        alloy_rlp::Header { list: true, payload_length: self.transactions_payload_length() }
+            .encode(out);
+        for tx in &self.transactions {
+            out.put_slice(tx);
+        }
+        self.ommers.encode(out);
+        if let Some(withdrawals) = &self.withdrawals {
+            withdrawals.encode(out);
+        }
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.payload_length();
+        payload_length + alloy_rlp::length_of_length(payload_length)
+    }
+}
+
+impl Decodable for RawBlockBody {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = alloy_rlp::Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString)
+        }
+        let started_len = buf.len();
+
+        let transactions_header = alloy_rlp::Header::decode(buf)?;
+        if !transactions_header.list {
+            return Err(alloy_rlp::Error::UnexpectedString)
+        }
+        if buf.len() < transactions_header.payload_length {
+            return Err(alloy_rlp::Error::InputTooShort)
+        }
+
+        // copy the transactions once, every transaction is a slice of the copy
+        let encoded = Bytes::copy_from_slice(&buf[..transactions_header.payload_length]);
+        *buf = &buf[transactions_header.payload_length..];
+
+        let mut transactions = Vec::new();
+        let mut remaining = &encoded[..];
+        while !remaining.is_empty() {
+            let mut payload = remaining;
+            let tx_header = alloy_rlp::Header::decode(&mut payload)?;
+            let tx_len = remaining.len() - payload.len() + tx_header.payload_length;
+            if tx_len > remaining.len() {
+                return Err(alloy_rlp::Error::InputTooShort)
+            }
+            let offset = encoded.len() - remaining.len();
+            transactions.push(encoded.slice(offset..offset + tx_len));
+            remaining = &remaining[tx_len..];
+        }
+
+        let ommers = Vec::<Header>::decode(buf)?;
+        let withdrawals = if started_len - buf.len() < header.payload_length {
+            Some(Withdrawals::decode(buf)?)
+        } else {
+            None
+        };
+
+        let consumed = started_len - buf.len();
+        if consumed != header.payload_length {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: consumed,
+            })
+        }
+
+        Ok(Self { transactions, ommers, withdrawals })
+    }
+}
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl proptest::arbitrary::Arbitrary for RawBlockBody {
+    type Parameters = ();
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::{any, Strategy};
+
+        any::<BlockBody>().prop_map(RawBlockBody::from).boxed()
+    }
+
+    type Strategy = proptest::strategy::BoxedStrategy<RawBlockBody>;
+}
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for RawBlockBody {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(BlockBody::arbitrary(u)?.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{BlockNumberOrTag::*, *};
     use crate::hex_literal::hex;
-    use alloy_rlp::{Decodable, Encodable};
     use reth_rpc_types::HexStringMissingPrefixError;
     use std::str::FromStr;
 
@@ -672,6 +875,28 @@ mod tests {
         assert_eq!(bytes[..], encoded_buf);
     }
 
+    #[test]
+    fn raw_block_body_roundtrip() {
+        // the body of the block in `encode_decode_raw_block`, one legacy transaction
+        let bytes = hex!("f90288f90218a0fe21bb173f43067a9f90cfc59bbb6830a7a2929b5de4a61f372a9db28e87f9aea01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347940000000000000000000000000000000000000000a061effbbcca94f0d3e02e5bd22e986ad57142acabf0cb3d129a6ad8d0f8752e94a0d911c25e97e27898680d242b7780b6faef30995c355a2d5de92e6b9a7212ad3aa0056b23fbba480696b65fe5a59b8f2148a1299103c4f57df839233af2cf4ca2d2b90100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008003834c4b408252081e80a00000000000000000000000000000000000000000000000000000000000000000880000000000000000842806be9da056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421f869f86702842806be9e82520894658bdf435d810c91414ec09147daa6db624063798203e880820a95a040ce7918eeb045ebf8c8b1887ca139d076bda00fa828a07881d442a72626c42da0156576a68e456e295e4c9cf67cf9f53151f329438916e0f24fc69d6bbb7fbacfc0c0");
+        let block = Block::decode(&mut bytes.as_ref()).unwrap();
+        let body = BlockBody { transactions: block.body, ommers: block.ommers, withdrawals: None };
+
+        let mut encoded = Vec::new();
+        body.encode(&mut encoded);
+
+        let raw = RawBlockBody::decode(&mut encoded.as_ref()).unwrap();
+        assert_eq!(raw, RawBlockBody::from(body.clone()));
+        assert_eq!(raw.length(), encoded.len());
+        assert_eq!(raw.calculate_tx_root(), body.calculate_tx_root());
+
+        let mut reencoded = Vec::new();
+        raw.encode(&mut reencoded);
+        assert_eq!(reencoded, encoded);
+
+        assert_eq!(raw.try_into_body().unwrap(), body);
+    }
+
     #[test]
     fn serde_blocknumber_non_0xprefix() {
         let s = "\"2\"";
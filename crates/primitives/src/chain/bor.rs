@@ -0,0 +1,137 @@
+use crate::{revm_primitives::address, Address, BlockNumber, ChainConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The genesis config key of the Bor configuration block.
+pub(crate) const GENESIS_BOR_KEY: &str = "bor";
+
+/// The system contract that manages the validator set, and thereby the block producers.
+pub const BOR_VALIDATOR_SET_ADDRESS: Address = address!("0000000000000000000000000000000000001000");
+
+/// The system contract that receives the state syncs from the root chain.
+pub const BOR_STATE_RECEIVER_ADDRESS: Address =
+    address!("0000000000000000000000000000000000001001");
+
+/// The consensus configuration of a Polygon PoS chain run by Bor.
+///
+/// This is read from the `bor` block of a genesis chain config. Most parameters changed over the
+/// lifetime of the chain, they are configured as maps from the first block a value applies to to
+/// the value, e.g. `"sprint": { "0": 64, "38189056": 16 }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorConfig {
+    /// The number of seconds between two blocks.
+    pub period: BTreeMap<BlockNumber, u64>,
+    /// The number of seconds the first block of a sprint is delayed by.
+    pub producer_delay: BTreeMap<BlockNumber, u64>,
+    /// The number of blocks a producer produces in a row.
+    pub sprint: BTreeMap<BlockNumber, u64>,
+    /// The number of seconds a backup producer waits per position behind the primary producer.
+    pub backup_multiplier: BTreeMap<BlockNumber, u64>,
+    /// The system contract that holds the producer set.
+    #[serde(default = "default_validator_contract")]
+    pub validator_contract: Address,
+    /// The system contract that receives the state syncs from the root chain.
+    #[serde(default = "default_state_receiver_contract")]
+    pub state_receiver_contract: Address,
+    /// The addresses that collect the burnt base fees.
+    #[serde(default)]
+    pub burnt_contract: BTreeMap<BlockNumber, Address>,
+}
+
+const fn default_validator_contract() -> Address {
+    BOR_VALIDATOR_SET_ADDRESS
+}
+
+const fn default_state_receiver_contract() -> Address {
+    BOR_STATE_RECEIVER_ADDRESS
+}
+
+impl BorConfig {
+    /// Reads the Bor configuration from the `bor` block of a genesis chain config.
+    ///
+    /// Returns `None` if the chain config has no `bor` block or it is malformed.
+    pub fn from_chain_config(config: &ChainConfig) -> Option<Self> {
+        let bor = config.extra_fields.get(GENESIS_BOR_KEY)?;
+        BorConfig::deserialize(bor).ok()
+    }
+
+    /// Returns the number of seconds between two blocks at the given block.
+    pub fn period(&self, block: BlockNumber) -> u64 {
+        value_at(&self.period, block).unwrap_or_default()
+    }
+
+    /// Returns the number of seconds the first block of a sprint is delayed by at the given block.
+    pub fn producer_delay(&self, block: BlockNumber) -> u64 {
+        value_at(&self.producer_delay, block).unwrap_or_default()
+    }
+
+    /// Returns the sprint length at the given block.
+    pub fn sprint_size(&self, block: BlockNumber) -> u64 {
+        value_at(&self.sprint, block).unwrap_or_default()
+    }
+
+    /// Returns the backup multiplier at the given block.
+    pub fn backup_multiplier(&self, block: BlockNumber) -> u64 {
+        value_at(&self.backup_multiplier, block).unwrap_or_default()
+    }
+
+    /// Returns the address that collects the burnt base fees at the given block, if any.
+    pub fn burnt_contract(&self, block: BlockNumber) -> Option<Address> {
+        value_at(&self.burnt_contract, block)
+    }
+
+    /// Returns true if the given block is the first block of a sprint.
+    ///
+    /// The producer set can only change at sprint boundaries.
+    pub fn is_sprint_start(&self, block: BlockNumber) -> bool {
+        let sprint = self.sprint_size(block);
+        sprint != 0 && block % sprint == 0
+    }
+}
+
+/// Returns the value of the last entry that applies at the given block.
+fn value_at<T: Copy>(values: &BTreeMap<BlockNumber, T>, block: BlockNumber) -> Option<T> {
+    values.range(..=block).next_back().map(|(_, value)| *value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bor_config_from_chain_config() {
+        let config: ChainConfig = serde_json::from_str(
+            r#"{
+                "chainId": 137,
+                "bor": {
+                    "period": {"0": 2},
+                    "producerDelay": {"0": 6, "38189056": 4},
+                    "sprint": {"0": 64, "38189056": 16},
+                    "backupMultiplier": {"0": 2},
+                    "validatorContract": "0x0000000000000000000000000000000000001000",
+                    "stateReceiverContract": "0x0000000000000000000000000000000000001001",
+                    "burntContract": {"23850000": "0x70bca57f4579f58670ab2d18ef16e02c17553c38"}
+                }
+            }"#,
+        )
+        .unwrap();
+        let bor = BorConfig::from_chain_config(&config).unwrap();
+
+        assert_eq!(bor.period(100), 2);
+        assert_eq!(bor.sprint_size(38189055), 64);
+        assert_eq!(bor.sprint_size(38189056), 16);
+        assert_eq!(bor.producer_delay(40000000), 4);
+        assert_eq!(bor.burnt_contract(1), None);
+        assert_eq!(
+            bor.burnt_contract(23850000),
+            Some(address!("70bca57f4579f58670ab2d18ef16e02c17553c38"))
+        );
+        assert!(bor.is_sprint_start(128));
+        assert!(!bor.is_sprint_start(38189057));
+        assert!(bor.is_sprint_start(38189072));
+
+        let config: ChainConfig = serde_json::from_str(r#"{"chainId": 1}"#).unwrap();
+        assert_eq!(BorConfig::from_chain_config(&config), None);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{revm_primitives::address, Address, ChainHardfork, ChainHardforks, ForkCondition};
+
+/// The system contract that manages the validator set.
+pub const BSC_VALIDATOR_SET_ADDRESS: Address = address!("0000000000000000000000000000000000001000");
+
+/// The system contract that slashes validators that miss their turn.
+pub const BSC_SLASH_ADDRESS: Address = address!("0000000000000000000000000000000000001001");
+
+/// The system contract that distributes the system rewards.
+pub const BSC_SYSTEM_REWARD_ADDRESS: Address = address!("0000000000000000000000000000000000001002");
+
+/// The BSC specific hardforks, scheduled as custom forks of the chain spec.
+///
+/// The Ethereum hardforks BSC adopted (e.g. Berlin with Hertz, Cancun with Haber) are scheduled as
+/// regular [Hardfork](crate::Hardfork)s next to these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BscHardfork {
+    /// Ramanujan: adds the backoff time for out of turn validators.
+    Ramanujan,
+    /// Niels: halts the cross chain communication.
+    Niels,
+    /// MirrorSync: enables mirror and sync of BEP-20 tokens with the beacon chain.
+    MirrorSync,
+    /// Bruno: burns a part of the gas fees (BEP-95).
+    Bruno,
+    /// Euler: increases the number of validators (BEP-127, BEP-131).
+    Euler,
+    /// Nano: suspends the cross chain bridge after the bridge exploit.
+    Nano,
+    /// Moran: hardens the cross chain bridge.
+    Moran,
+    /// Gibbs: enables the staking of the token hub.
+    Gibbs,
+    /// Planck: enables the fast finality slashing and cross chain fixes.
+    Planck,
+    /// Luban: introduces the fast finality votes (BEP-126).
+    Luban,
+    /// Plato: enables the fast finality rewards.
+    Plato,
+    /// Hertz: adopts Berlin and London, with a zero base fee (BEP-221).
+    Hertz,
+    /// HertzFix: fixes the storage of accounts broken by Hertz.
+    HertzFix,
+    /// Kepler: adopts Shanghai (BEP-241).
+    Kepler,
+    /// Feynman: enables the native staking on BSC (BEP-294).
+    Feynman,
+    /// FeynmanFix: fixes the validator set updates of Feynman.
+    FeynmanFix,
+    /// Haber: adopts Cancun (BEP-336).
+    Haber,
+    /// HaberFix: fixes the blob gas handling of Haber.
+    HaberFix,
+    /// Bohr: lets validators produce consecutive blocks (BEP-341).
+    Bohr,
+    /// Pascal: adopts Prague (BEP-439).
+    Pascal,
+    /// Lorentz: reduces the block interval to 1.5 seconds (BEP-520).
+    Lorentz,
+    /// Maxwell: reduces the block interval to 0.75 seconds (BEP-524).
+    Maxwell,
+}
+
+impl BscHardfork {
+    /// The BSC hardforks of BSC mainnet and their activation conditions.
+    pub fn bsc_mainnet() -> ChainHardforks {
+        Self::hardforks([
+            (BscHardfork::Ramanujan, ForkCondition::Block(0)),
+            (BscHardfork::Niels, ForkCondition::Block(0)),
+            (BscHardfork::MirrorSync, ForkCondition::Block(5184000)),
+            (BscHardfork::Bruno, ForkCondition::Block(13082000)),
+            (BscHardfork::Euler, ForkCondition::Block(18907621)),
+            (BscHardfork::Nano, ForkCondition::Block(21962149)),
+            (BscHardfork::Moran, ForkCondition::Block(22107423)),
+            (BscHardfork::Gibbs, ForkCondition::Block(23846001)),
+            (BscHardfork::Planck, ForkCondition::Block(27281024)),
+            (BscHardfork::Luban, ForkCondition::Block(29020050)),
+            (BscHardfork::Plato, ForkCondition::Block(30720096)),
+            (BscHardfork::Hertz, ForkCondition::Block(31302048)),
+            (BscHardfork::HertzFix, ForkCondition::Block(34140700)),
+            (BscHardfork::Kepler, ForkCondition::Timestamp(1705996800)),
+            (BscHardfork::Feynman, ForkCondition::Timestamp(1713419340)),
+            (BscHardfork::FeynmanFix, ForkCondition::Timestamp(1713419340)),
+            (BscHardfork::Haber, ForkCondition::Timestamp(1718863500)),
+            (BscHardfork::HaberFix, ForkCondition::Timestamp(1727316120)),
+            (BscHardfork::Bohr, ForkCondition::Timestamp(1727317200)),
+            (BscHardfork::Pascal, ForkCondition::Timestamp(1742436600)),
+            (BscHardfork::Lorentz, ForkCondition::Timestamp(1745903100)),
+            (BscHardfork::Maxwell, ForkCondition::Timestamp(1751250600)),
+        ])
+    }
+
+    /// The BSC hardforks of the BSC testnet (Chapel) and their activation conditions.
+    pub fn bsc_testnet() -> ChainHardforks {
+        Self::hardforks([
+            (BscHardfork::Ramanujan, ForkCondition::Block(1010000)),
+            (BscHardfork::Niels, ForkCondition::Block(1014369)),
+            (BscHardfork::MirrorSync, ForkCondition::Block(5582500)),
+            (BscHardfork::Bruno, ForkCondition::Block(13837000)),
+            (BscHardfork::Euler, ForkCondition::Block(19203503)),
+            (BscHardfork::Gibbs, ForkCondition::Block(22800220)),
+            (BscHardfork::Nano, ForkCondition::Block(23482428)),
+            (BscHardfork::Moran, ForkCondition::Block(23603940)),
+            (BscHardfork::Planck, ForkCondition::Block(28196022)),
+            (BscHardfork::Luban, ForkCondition::Block(29295050)),
+            (BscHardfork::Plato, ForkCondition::Block(29861024)),
+            (BscHardfork::Hertz, ForkCondition::Block(31103030)),
+            (BscHardfork::HertzFix, ForkCondition::Block(35682300)),
+            (BscHardfork::Kepler, ForkCondition::Timestamp(1702972800)),
+            (BscHardfork::Feynman, ForkCondition::Timestamp(1710136800)),
+            (BscHardfork::FeynmanFix, ForkCondition::Timestamp(1711342800)),
+            (BscHardfork::Haber, ForkCondition::Timestamp(1713330442)),
+            (BscHardfork::HaberFix, ForkCondition::Timestamp(1716962820)),
+            (BscHardfork::Bohr, ForkCondition::Timestamp(1724116996)),
+            (BscHardfork::Pascal, ForkCondition::Timestamp(1740452880)),
+            (BscHardfork::Lorentz, ForkCondition::Timestamp(1744097580)),
+            (BscHardfork::Maxwell, ForkCondition::Timestamp(1748243100)),
+        ])
+    }
+
+    fn hardforks<const N: usize>(forks: [(BscHardfork, ForkCondition); N]) -> ChainHardforks {
+        let mut hardforks = ChainHardforks::default();
+        for (fork, condition) in forks {
+            hardforks.insert(fork, condition);
+        }
+        hardforks
+    }
+}
+
+impl ChainHardfork for BscHardfork {
+    fn name(&self) -> &str {
+        match self {
+            BscHardfork::Ramanujan => "Ramanujan",
+            BscHardfork::Niels => "Niels",
+            BscHardfork::MirrorSync => "MirrorSync",
+            BscHardfork::Bruno => "Bruno",
+            BscHardfork::Euler => "Euler",
+            BscHardfork::Nano => "Nano",
+            BscHardfork::Moran => "Moran",
+            BscHardfork::Gibbs => "Gibbs",
+            BscHardfork::Planck => "Planck",
+            BscHardfork::Luban => "Luban",
+            BscHardfork::Plato => "Plato",
+            BscHardfork::Hertz => "Hertz",
+            BscHardfork::HertzFix => "HertzFix",
+            BscHardfork::Kepler => "Kepler",
+            BscHardfork::Feynman => "Feynman",
+            BscHardfork::FeynmanFix => "FeynmanFix",
+            BscHardfork::Haber => "Haber",
+            BscHardfork::HaberFix => "HaberFix",
+            BscHardfork::Bohr => "Bohr",
+            BscHardfork::Pascal => "Pascal",
+            BscHardfork::Lorentz => "Lorentz",
+            BscHardfork::Maxwell => "Maxwell",
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{Log, B256};
+use alloy_primitives::FixedBytes;
+
+/// The number of `bytes` fields of a `DepositEvent`.
+const DEPOSIT_EVENT_FIELDS: usize = 5;
+
+/// A decoded `DepositEvent(bytes pubkey, bytes withdrawal_credentials, bytes amount, bytes
+/// signature, bytes index)` of a [DepositContract](crate::DepositContract).
+///
+/// The contract emits all fields as ABI encoded `bytes`, the amount and the index as little-endian
+/// integers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepositEvent {
+    /// The BLS public key of the validator.
+    pub pubkey: FixedBytes<48>,
+    /// The credentials the stake is withdrawn to.
+    pub withdrawal_credentials: B256,
+    /// The deposited amount, in gwei.
+    pub amount: u64,
+    /// The BLS signature of the deposit message.
+    pub signature: FixedBytes<96>,
+    /// The index of the deposit in the deposit tree of the contract.
+    pub index: u64,
+}
+
+impl DepositEvent {
+    /// Decodes the event from the data of a deposit contract log.
+    ///
+    /// Use [DepositContract::matches](crate::DepositContract::matches) to check that the log was
+    /// emitted by the deposit contract first.
+    pub fn decode(log: &Log) -> Result<Self, DepositEventError> {
+        let data = log.data.as_ref();
+        let mut fields = [&[][..]; DEPOSIT_EVENT_FIELDS];
+        for (index, field) in fields.iter_mut().enumerate() {
+            let offset = read_usize(data, index * 32)?;
+            let len = read_usize(data, offset)?;
+            let start = offset.checked_add(32).ok_or(DepositEventError::OutOfBounds)?;
+            let end = start.checked_add(len).ok_or(DepositEventError::OutOfBounds)?;
+            *field = data.get(start..end).ok_or(DepositEventError::OutOfBounds)?;
+        }
+        let [pubkey, withdrawal_credentials, amount, signature, index] = fields;
+
+        Ok(Self {
+            pubkey: fixed_field("pubkey", pubkey)?,
+            withdrawal_credentials: fixed_field("withdrawal_credentials", withdrawal_credentials)?,
+            amount: u64::from_le_bytes(fixed_field::<8>("amount", amount)?.0),
+            signature: fixed_field("signature", signature)?,
+            index: u64::from_le_bytes(fixed_field::<8>("index", index)?.0),
+        })
+    }
+}
+
+/// Reads the ABI word at the given offset as a `usize`.
+fn read_usize(data: &[u8], offset: usize) -> Result<usize, DepositEventError> {
+    let word = offset
+        .checked_add(32)
+        .and_then(|end| data.get(offset..end))
+        .ok_or(DepositEventError::OutOfBounds)?;
+    let (high, low) = word.split_at(24);
+    if high.iter().any(|byte| *byte != 0) {
+        return Err(DepositEventError::OutOfBounds)
+    }
+    usize::try_from(u64::from_be_bytes(low.try_into().expect("8 bytes")))
+        .map_err(|_| DepositEventError::OutOfBounds)
+}
+
+/// Converts a `bytes` field of the event into its fixed size type.
+fn fixed_field<const N: usize>(
+    field: &'static str,
+    bytes: &[u8],
+) -> Result<FixedBytes<N>, DepositEventError> {
+    FixedBytes::try_from(bytes).map_err(|_| DepositEventError::InvalidLength {
+        field,
+        expected: N,
+        got: bytes.len(),
+    })
+}
+
+/// An error decoding a [DepositEvent].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DepositEventError {
+    /// An offset or length of the ABI encoding points past the end of the log data.
+    #[error("deposit event data is out of bounds")]
+    OutOfBounds,
+    /// A field does not have the size the deposit contract emits.
+    #[error("invalid length of deposit event field {field}: expected {expected}, got {got}")]
+    InvalidLength {
+        /// The name of the field.
+        field: &'static str,
+        /// The size the deposit contract emits.
+        expected: usize,
+        /// The size of the field in the log.
+        got: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bytes, DepositContract, MAINNET};
+
+    /// ABI encodes the fields of a deposit event, as the deposit contract does.
+    fn encode_fields(fields: [&[u8]; DEPOSIT_EVENT_FIELDS]) -> Bytes {
+        let mut head = Vec::new();
+        let mut tail = Vec::new();
+        for field in fields {
+            let offset = DEPOSIT_EVENT_FIELDS * 32 + tail.len();
+            head.extend_from_slice(&[0; 24]);
+            head.extend_from_slice(&(offset as u64).to_be_bytes());
+            tail.extend_from_slice(&[0; 24]);
+            tail.extend_from_slice(&(field.len() as u64).to_be_bytes());
+            tail.extend_from_slice(field);
+            tail.resize(tail.len().next_multiple_of(32), 0);
+        }
+        head.extend(tail);
+        head.into()
+    }
+
+    #[test]
+    fn decode_deposit_event() {
+        let contract = &MAINNET.deposit_contracts()[0];
+        let amount = 32_000_000_000u64.to_le_bytes();
+        let index = 7u64.to_le_bytes();
+        let log = Log {
+            address: contract.address,
+            topics: vec![contract.topic],
+            data: encode_fields([&[1; 48], &[2; 32], &amount, &[3; 96], &index]),
+        };
+        assert!(contract.matches(&log));
+        assert_eq!(log.data.len(), 576);
+
+        assert_eq!(
+            DepositEvent::decode(&log),
+            Ok(DepositEvent {
+                pubkey: FixedBytes::repeat_byte(1),
+                withdrawal_credentials: B256::repeat_byte(2),
+                amount: 32_000_000_000,
+                signature: FixedBytes::repeat_byte(3),
+                index: 7,
+            })
+        );
+
+        let other = DepositContract { topic: B256::ZERO, ..contract.clone() };
+        assert!(!other.matches(&log));
+
+        let log =
+            Log { data: encode_fields([&[1; 48], &[2; 31], &amount, &[3; 96], &index]), ..log };
+        assert_eq!(
+            DepositEvent::decode(&log),
+            Err(DepositEventError::InvalidLength {
+                field: "withdrawal_credentials",
+                expected: 32,
+                got: 31
+            })
+        );
+        let log = Log { data: log.data.slice(..300), ..log };
+        assert_eq!(DepositEvent::decode(&log), Err(DepositEventError::OutOfBounds));
+    }
+}
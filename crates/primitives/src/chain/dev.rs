@@ -0,0 +1,338 @@
+use crate::{
+    chain::{ChainSpec, ChainSpecBuilder},
+    constants::ETH_TO_WEI,
+    transaction::util::secp256k1::public_key_to_address,
+    Address, ForkCondition, Genesis, GenesisAccount, Hardfork, B256, U256,
+};
+use alloy_chains::Chain;
+use hmac::{Hmac, Mac};
+use secp256k1::{PublicKey, Scalar, SecretKey, SECP256K1};
+use sha2::Sha512;
+
+/// The mnemonic the accounts of the [DEV](crate::DEV) spec are derived from.
+pub const DEV_MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+/// The number of accounts prefunded by the [DEV](crate::DEV) spec.
+pub const DEV_NUMBER_OF_ACCOUNTS: usize = 20;
+
+/// The forks a dev chain can activate at genesis, in activation order.
+///
+/// Prague and Osaka are left out, they are not supported by the EVM.
+const DEV_HARDFORKS: [Hardfork; 15] = [
+    Hardfork::Frontier,
+    Hardfork::Homestead,
+    Hardfork::Dao,
+    Hardfork::Tangerine,
+    Hardfork::SpuriousDragon,
+    Hardfork::Byzantium,
+    Hardfork::Constantinople,
+    Hardfork::Petersburg,
+    Hardfork::Istanbul,
+    Hardfork::MuirGlacier,
+    Hardfork::Berlin,
+    Hardfork::London,
+    Hardfork::Paris,
+    Hardfork::Shanghai,
+    Hardfork::Cancun,
+];
+
+/// The number of PBKDF2 rounds that stretch a BIP-39 mnemonic into a seed.
+const BIP39_PBKDF2_ROUNDS: u32 = 2048;
+
+/// The BIP-44 path of the Ethereum accounts, without the account index: `m/44'/60'/0'/0`.
+const ETHEREUM_DERIVATION_PATH: [u32; 4] = [hardened(44), hardened(60), hardened(0), 0];
+
+/// A builder for dev chain specs, i.e. variations of the [DEV](crate::DEV) spec.
+///
+/// The prefunded accounts are derived from a BIP-39 mnemonic along the `m/44'/60'/0'/0/{index}`
+/// path, as wallets and dev tools like anvil and hardhat do. The default builder reproduces the
+/// [DEV](crate::DEV) spec.
+#[derive(Debug, Clone)]
+pub struct DevChainSpecBuilder {
+    mnemonic: String,
+    number_of_accounts: usize,
+    balance: U256,
+    chain_id: u64,
+    block_time: Option<u64>,
+    hardfork: Hardfork,
+}
+
+impl Default for DevChainSpecBuilder {
+    fn default() -> Self {
+        Self {
+            mnemonic: DEV_MNEMONIC.to_string(),
+            number_of_accounts: DEV_NUMBER_OF_ACCOUNTS,
+            balance: U256::from(1_000_000 * ETH_TO_WEI),
+            chain_id: Chain::dev().id(),
+            block_time: None,
+            hardfork: Hardfork::Shanghai,
+        }
+    }
+}
+
+impl DevChainSpecBuilder {
+    /// Set the mnemonic the prefunded accounts are derived from.
+    ///
+    /// The words are not checked against the BIP-39 word list, and no passphrase is used.
+    pub fn mnemonic(mut self, mnemonic: impl Into<String>) -> Self {
+        self.mnemonic = mnemonic.into();
+        self
+    }
+
+    /// Set the number of prefunded accounts.
+    pub fn number_of_accounts(mut self, number_of_accounts: usize) -> Self {
+        self.number_of_accounts = number_of_accounts;
+        self
+    }
+
+    /// Set the balance, in wei, of every prefunded account.
+    pub fn balance(mut self, balance: U256) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    /// Set the chain ID.
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Set the number of seconds between two auto-mined blocks.
+    pub fn block_time(mut self, block_time: u64) -> Self {
+        self.block_time = Some(block_time);
+        self
+    }
+
+    /// Set the latest fork of the chain, it and all forks before it are active at genesis.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the fork is not an Ethereum fork from Frontier to Cancun.
+    pub fn hardfork(mut self, hardfork: Hardfork) -> Self {
+        assert!(DEV_HARDFORKS.contains(&hardfork), "{hardfork} can't be activated on a dev chain");
+        self.hardfork = hardfork;
+        self
+    }
+
+    /// Returns the addresses and secret keys of the prefunded accounts.
+    pub fn accounts(&self) -> Vec<(Address, B256)> {
+        let seed = mnemonic_to_seed(&self.mnemonic, "");
+        let (mut key, mut chain_code) = master_key(&seed);
+        for index in ETHEREUM_DERIVATION_PATH {
+            (key, chain_code) = derive_child(&key, &chain_code, index);
+        }
+
+        (0..self.number_of_accounts as u32)
+            .map(|index| {
+                let (secret, _) = derive_child(&key, &chain_code, index);
+                let address = public_key_to_address(PublicKey::from_secret_key(SECP256K1, &secret));
+                (address, B256::from(secret.secret_bytes()))
+            })
+            .collect()
+    }
+
+    /// Build the resulting [`ChainSpec`].
+    pub fn build(self) -> ChainSpec {
+        let mut genesis: Genesis = serde_json::from_str(include_str!("../../res/genesis/dev.json"))
+            .expect("Can't deserialize Dev testnet genesis json");
+        genesis.config.chain_id = self.chain_id;
+        genesis.alloc.clear();
+
+        let funded = GenesisAccount::default().with_balance(self.balance);
+        let mut builder = ChainSpecBuilder::default()
+            .chain(Chain::from_id(self.chain_id))
+            .genesis(genesis)
+            .with_extra_alloc(
+                self.accounts().into_iter().map(|(address, _)| (address, funded.clone())),
+            );
+        if let Some(block_time) = self.block_time {
+            builder = builder.block_time(block_time);
+        }
+
+        let last = DEV_HARDFORKS.iter().position(|fork| *fork == self.hardfork).unwrap_or_default();
+        let forks = &DEV_HARDFORKS[..=last];
+        for fork in forks {
+            let condition = match fork {
+                Hardfork::Paris => {
+                    ForkCondition::TTD { fork_block: Some(0), total_difficulty: U256::ZERO }
+                }
+                Hardfork::Shanghai | Hardfork::Cancun => ForkCondition::Timestamp(0),
+                _ => ForkCondition::Block(0),
+            };
+            builder = builder.with_fork(*fork, condition);
+        }
+
+        let mut spec = builder.build();
+        if forks.contains(&Hardfork::Paris) {
+            spec.paris_block_and_final_difficulty = Some((0, U256::ZERO));
+        }
+        spec
+    }
+}
+
+/// Stretches a BIP-39 mnemonic and passphrase into the seed of a BIP-32 key tree.
+fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> [u8; 64] {
+    let mnemonic = mnemonic.split_whitespace().collect::<Vec<_>>().join(" ");
+    let salt = format!("mnemonic{passphrase}");
+    let mut seed = [0u8; 64];
+    pbkdf2::pbkdf2_hmac::<Sha512>(
+        mnemonic.as_bytes(),
+        salt.as_bytes(),
+        BIP39_PBKDF2_ROUNDS,
+        &mut seed,
+    );
+    seed
+}
+
+/// Returns the BIP-32 master key and chain code of a seed.
+fn master_key(seed: &[u8]) -> (SecretKey, [u8; 32]) {
+    split_key(hmac_sha512(b"Bitcoin seed", &[seed]))
+}
+
+/// Returns the BIP-32 child key and chain code at the given index.
+fn derive_child(key: &SecretKey, chain_code: &[u8; 32], index: u32) -> (SecretKey, [u8; 32]) {
+    let output = if index >= hardened(0) {
+        hmac_sha512(chain_code, &[&[0], &key.secret_bytes(), &index.to_be_bytes()])
+    } else {
+        let public = PublicKey::from_secret_key(SECP256K1, key).serialize();
+        hmac_sha512(chain_code, &[&public, &index.to_be_bytes()])
+    };
+
+    let (tweak, chain_code) = split_key(output);
+    let key = key
+        .add_tweak(&Scalar::from(tweak))
+        .expect("The probability of an invalid child key is lower than 2^-127");
+    (key, chain_code)
+}
+
+/// Splits the output of the BIP-32 HMAC into the key and the chain code.
+fn split_key(output: [u8; 64]) -> (SecretKey, [u8; 32]) {
+    let key = SecretKey::from_slice(&output[..32])
+        .expect("The probability of an invalid key is lower than 2^-127");
+    let mut chain_code = [0u8; 32];
+    chain_code.copy_from_slice(&output[32..]);
+    (key, chain_code)
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    for data in data {
+        mac.update(data);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+const fn hardened(index: u32) -> u32 {
+    index | 0x8000_0000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{address, hex, DEV, DEV_CANCUN};
+
+    // <https://github.com/trezor/python-mnemonic/blob/master/vectors.json>
+    #[test]
+    fn bip39_seed() {
+        let seed = mnemonic_to_seed(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "TREZOR",
+        );
+        assert_eq!(
+            seed,
+            hex!("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04")
+        );
+    }
+
+    // Test vector 1 of <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vectors>
+    #[test]
+    fn bip32_test_vector_1() {
+        let (mut key, mut chain_code) = master_key(&hex!("000102030405060708090a0b0c0d0e0f"));
+        assert_eq!(
+            key.secret_bytes(),
+            hex!("e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35")
+        );
+        assert_eq!(
+            chain_code,
+            hex!("873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508")
+        );
+
+        for (index, expected_key, expected_chain_code) in [
+            (
+                hardened(0),
+                hex!("edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea"),
+                hex!("47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141"),
+            ),
+            (
+                1,
+                hex!("3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368"),
+                hex!("2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19"),
+            ),
+            (
+                hardened(2),
+                hex!("cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca"),
+                hex!("04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f"),
+            ),
+            (
+                2,
+                hex!("0f479245fb19a38a1954c5c7c0ebab2f9bdfd96a17563ef28a6a4b1a2a764ef4"),
+                hex!("cfb71883f01676f587d023cc53a35bc7f88f724b1f8c2892ac1275ac822a3edd"),
+            ),
+            (
+                1_000_000_000,
+                hex!("471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8"),
+                hex!("c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e"),
+            ),
+        ] {
+            (key, chain_code) = derive_child(&key, &chain_code, index);
+            assert_eq!(key.secret_bytes(), expected_key);
+            assert_eq!(chain_code, expected_chain_code);
+        }
+    }
+
+    #[test]
+    fn default_builder_matches_dev() {
+        let accounts = DevChainSpecBuilder::default().accounts();
+        assert_eq!(accounts.len(), DEV_NUMBER_OF_ACCOUNTS);
+        assert_eq!(accounts[0].0, address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"));
+        assert_eq!(accounts[1].0, address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"));
+
+        let spec = DevChainSpecBuilder::default().build();
+        assert_eq!(spec.chain, DEV.chain);
+        assert_eq!(spec.genesis.alloc, DEV.genesis.alloc);
+        assert_eq!(spec.genesis_hash(), DEV.genesis_hash());
+        assert_eq!(spec.hardforks(), DEV.hardforks());
+        assert_eq!(spec.paris_block_and_final_difficulty, DEV.paris_block_and_final_difficulty);
+
+        let spec = DevChainSpecBuilder::default().hardfork(Hardfork::Cancun).build();
+        assert_eq!(spec.genesis_hash(), DEV_CANCUN.genesis_hash());
+        assert_eq!(spec.hardforks(), DEV_CANCUN.hardforks());
+    }
+
+    #[test]
+    fn custom_dev_chain() {
+        let spec = DevChainSpecBuilder::default()
+            .number_of_accounts(3)
+            .balance(U256::from(1))
+            .chain_id(4242)
+            .block_time(2)
+            .hardfork(Hardfork::London)
+            .build();
+
+        assert_eq!(spec.chain.id(), 4242);
+        assert_eq!(spec.genesis.config.chain_id, 4242);
+        assert_eq!(spec.genesis.alloc.len(), 3);
+        assert!(spec.genesis.alloc.values().all(|account| account.balance == U256::from(1)));
+        assert_eq!(spec.block_time, Some(2));
+        assert!(spec.fork(Hardfork::London).active_at_block(0));
+        assert_eq!(spec.fork(Hardfork::Paris), ForkCondition::Never);
+        assert!(!spec.is_shanghai_active_at_timestamp(0));
+        assert_eq!(spec.paris_block_and_final_difficulty, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Prague can't be activated on a dev chain")]
+    fn reject_prague() {
+        DevChainSpecBuilder::default().hardfork(Hardfork::Prague);
+    }
+}
@@ -0,0 +1,363 @@
+use crate::{
+    chain::{BaseFeeParams, BaseFeeParamsKind, ChainSpec, DepositContract},
+    Address, ForkCondition, Hardfork,
+};
+use alloy_chains::Chain;
+use std::{
+    collections::BTreeSet,
+    fmt::{Display, Formatter},
+};
+
+/// The differences between two [ChainSpec]s, see [ChainSpec::diff].
+///
+/// Every entry holds the value of the old spec and the value of the new spec, fields that are
+/// equal in both specs are not part of the diff.
+///
+/// # Examples
+///
+/// ```
+/// # use reth_primitives::{ForkCondition, Hardfork, MAINNET};
+/// let mut new = (*MAINNET).clone();
+/// new.insert_hardfork(Hardfork::Cancun, ForkCondition::Timestamp(1710338136));
+///
+/// let diff = MAINNET.diff(&new);
+/// assert_eq!(diff.forks.len(), 1);
+/// println!("{diff}");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChainSpecDiff {
+    /// The chain of the specs, if it differs.
+    pub chain: Option<ValueDiff<Chain>>,
+    /// The hardforks whose activation condition differs, ordered by hardfork.
+    ///
+    /// A hardfork that is missing from one of the specs is reported as [ForkCondition::Never].
+    pub forks: Vec<ForkDiff>,
+    /// The EIP-1559 base fee parameters, if they differ.
+    pub base_fee_params: Option<ValueDiff<BaseFeeParamsKind>>,
+    /// The deposit contracts, if they differ.
+    pub deposit_contracts: Option<ValueDiff<Vec<DepositContract>>>,
+    /// The header fields of the genesis block that differ.
+    pub genesis: Vec<GenesisFieldDiff>,
+    /// The accounts of the genesis allocation that were added, removed or changed.
+    pub genesis_alloc: Vec<Address>,
+}
+
+impl ChainSpecDiff {
+    /// Computes the differences between the `old` and the `new` spec.
+    pub fn new(old: &ChainSpec, new: &ChainSpec) -> Self {
+        let chain = ValueDiff::new(old.chain, new.chain);
+
+        let forks = old
+            .hardforks()
+            .keys()
+            .chain(new.hardforks().keys())
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter_map(|fork| {
+                let (old, new) = (old.fork(fork), new.fork(fork));
+                (old != new).then_some(ForkDiff { fork, old, new })
+            })
+            .collect();
+
+        let base_fee_params =
+            ValueDiff::new(old.base_fee_params.clone(), new.base_fee_params.clone());
+        let deposit_contracts =
+            ValueDiff::new(old.deposit_contracts.clone(), new.deposit_contracts.clone());
+
+        let mut genesis = Vec::new();
+        let mut push = |field: &'static str, old: String, new: String| {
+            if old != new {
+                genesis.push(GenesisFieldDiff { field, old, new });
+            }
+        };
+        push("hash", old.genesis_hash().to_string(), new.genesis_hash().to_string());
+        let (old_genesis, new_genesis) = (old.genesis(), new.genesis());
+        macro_rules! push_fields {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    push(
+                        stringify!($field),
+                        old_genesis.$field.to_string(),
+                        new_genesis.$field.to_string(),
+                    );
+                )*
+            };
+        }
+        macro_rules! push_optional_fields {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    push(
+                        stringify!($field),
+                        display_optional(old_genesis.$field),
+                        display_optional(new_genesis.$field),
+                    );
+                )*
+            };
+        }
+        push_fields!(nonce, timestamp, extra_data, gas_limit, difficulty, mix_hash, coinbase);
+        push_optional_fields!(base_fee_per_gas, excess_blob_gas, blob_gas_used);
+
+        let genesis_alloc = old_genesis
+            .alloc
+            .keys()
+            .chain(new_genesis.alloc.keys())
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter(|address| old_genesis.alloc.get(address) != new_genesis.alloc.get(address))
+            .collect();
+
+        Self { chain, forks, base_fee_params, deposit_contracts, genesis, genesis_alloc }
+    }
+
+    /// Returns true if the specs don't differ in any of the compared fields.
+    pub fn is_empty(&self) -> bool {
+        self.chain.is_none() &&
+            self.forks.is_empty() &&
+            self.base_fee_params.is_none() &&
+            self.deposit_contracts.is_none() &&
+            self.genesis.is_empty() &&
+            self.genesis_alloc.is_empty()
+    }
+}
+
+impl Display for ChainSpecDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "Chain specs are identical")
+        }
+
+        if let Some(ValueDiff { old, new }) = &self.chain {
+            writeln!(f, "Chain: {old} -> {new}")?;
+        }
+
+        if !self.forks.is_empty() {
+            writeln!(f, "Hard forks:")?;
+            for fork in self.forks.iter() {
+                writeln!(f, "- {fork}")?;
+            }
+        }
+
+        if let Some(ValueDiff { old, new }) = &self.base_fee_params {
+            writeln!(f, "Base fee params:")?;
+            writeln!(f, "- old: {}", DisplayBaseFeeParams(old))?;
+            writeln!(f, "- new: {}", DisplayBaseFeeParams(new))?;
+        }
+
+        if let Some(ValueDiff { old, new }) = &self.deposit_contracts {
+            writeln!(f, "Deposit contracts:")?;
+            writeln!(f, "- old: {}", DisplayDepositContracts(old))?;
+            writeln!(f, "- new: {}", DisplayDepositContracts(new))?;
+        }
+
+        if !self.genesis.is_empty() {
+            writeln!(f, "Genesis:")?;
+            for field in self.genesis.iter() {
+                writeln!(f, "- {field}")?;
+            }
+        }
+
+        if !self.genesis_alloc.is_empty() {
+            writeln!(f, "Genesis alloc ({} accounts changed):", self.genesis_alloc.len())?;
+            for address in self.genesis_alloc.iter() {
+                writeln!(f, "- {address}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A value that differs between two [ChainSpec]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueDiff<T> {
+    /// The value of the old spec.
+    pub old: T,
+    /// The value of the new spec.
+    pub new: T,
+}
+
+impl<T: PartialEq> ValueDiff<T> {
+    /// Returns the diff of the two values, or `None` if they are equal.
+    fn new(old: T, new: T) -> Option<Self> {
+        (old != new).then_some(Self { old, new })
+    }
+}
+
+/// A hardfork whose activation condition differs between two [ChainSpec]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForkDiff {
+    /// The hardfork.
+    pub fork: Hardfork,
+    /// The activation condition in the old spec.
+    pub old: ForkCondition,
+    /// The activation condition in the new spec.
+    pub new: ForkCondition,
+}
+
+impl Display for ForkDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:32} {} -> {}",
+            self.fork.to_string(),
+            DisplayForkCondition(&self.old),
+            DisplayForkCondition(&self.new)
+        )
+    }
+}
+
+/// A header field of the genesis block that differs between two [ChainSpec]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisFieldDiff {
+    /// The name of the field, e.g. `gas_limit`.
+    pub field: &'static str,
+    /// The value in the old spec.
+    pub old: String,
+    /// The value in the new spec.
+    pub new: String,
+}
+
+impl Display for GenesisFieldDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:32} {} -> {}", self.field, self.old, self.new)
+    }
+}
+
+/// Formats an optional genesis field, `none` if it's unset.
+fn display_optional<T: Display>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_else(|| "none".to_string())
+}
+
+struct DisplayForkCondition<'a>(&'a ForkCondition);
+
+impl Display for DisplayForkCondition<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            ForkCondition::Block(block) => write!(f, "block {block}"),
+            ForkCondition::Epoch { epoch_length, epoch } => {
+                write!(f, "epoch {epoch} (epoch length {epoch_length})")
+            }
+            ForkCondition::TTD { fork_block: Some(block), total_difficulty } => {
+                write!(f, "ttd {total_difficulty} (block {block})")
+            }
+            ForkCondition::TTD { fork_block: None, total_difficulty } => {
+                write!(f, "ttd {total_difficulty}")
+            }
+            ForkCondition::Timestamp(timestamp) => write!(f, "timestamp {timestamp}"),
+            ForkCondition::BlockAndTimestamp { block, timestamp } => {
+                write!(f, "timestamp {timestamp} (block {block})")
+            }
+            ForkCondition::Never => write!(f, "never"),
+        }
+    }
+}
+
+struct DisplayBaseFeeParams<'a>(&'a BaseFeeParamsKind);
+
+impl DisplayBaseFeeParams<'_> {
+    fn fmt_params(params: &BaseFeeParams, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "max change denominator {}, elasticity multiplier {}",
+            params.max_change_denominator, params.elasticity_multiplier
+        )?;
+        if let Some(minimum_base_fee) = params.minimum_base_fee {
+            write!(f, ", minimum base fee {minimum_base_fee}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for DisplayBaseFeeParams<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            BaseFeeParamsKind::Constant(params) => Self::fmt_params(params, f),
+            BaseFeeParamsKind::Variable(params) => {
+                for (i, (fork, params)) in params.as_slice().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{fork}: ")?;
+                    Self::fmt_params(params, f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+struct DisplayDepositContracts<'a>(&'a [DepositContract]);
+
+impl Display for DisplayDepositContracts<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "none")
+        }
+        for (i, contract) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} @{}", contract.address, contract.block)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MAINNET;
+
+    #[test]
+    fn diff_identical_specs() {
+        let diff = MAINNET.diff(&MAINNET);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "Chain specs are identical\n");
+    }
+
+    #[test]
+    fn diff_changed_specs() {
+        let mut new = (*MAINNET).clone();
+        new.insert_hardfork(Hardfork::Cancun, ForkCondition::Timestamp(1));
+        new.remove_hardfork(Hardfork::Shanghai);
+        new.base_fee_params =
+            BaseFeeParams { elasticity_multiplier: 4, ..BaseFeeParams::ethereum() }.into();
+        new.deposit_contracts.clear();
+        new.genesis.gas_limit += 1;
+        new.genesis_hash = None;
+
+        let diff = MAINNET.diff(&new);
+        assert!(diff.chain.is_none());
+        assert_eq!(
+            diff.forks,
+            vec![
+                ForkDiff {
+                    fork: Hardfork::Shanghai,
+                    old: MAINNET.fork(Hardfork::Shanghai),
+                    new: ForkCondition::Never,
+                },
+                ForkDiff {
+                    fork: Hardfork::Cancun,
+                    old: MAINNET.fork(Hardfork::Cancun),
+                    new: ForkCondition::Timestamp(1),
+                },
+            ]
+        );
+        assert!(diff.base_fee_params.is_some());
+        assert_eq!(
+            diff.deposit_contracts,
+            Some(ValueDiff { old: MAINNET.deposit_contracts.clone(), new: vec![] })
+        );
+        let fields = diff.genesis.iter().map(|field| field.field).collect::<Vec<_>>();
+        assert_eq!(fields, vec!["hash", "gas_limit"]);
+        assert!(diff.genesis_alloc.is_empty());
+
+        let display = diff.to_string();
+        assert!(display.contains("Cancun"));
+        assert!(display.contains("-> timestamp 1\n"));
+        assert!(display.contains("elasticity multiplier 4"));
+        assert!(display.contains("- new: none\n"));
+    }
+}
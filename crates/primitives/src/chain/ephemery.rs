@@ -0,0 +1,66 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The genesis timestamp of the first Ephemery iteration.
+pub const EPHEMERY_GENESIS_TIMESTAMP_BASE: u64 = 1638471600;
+
+/// The number of seconds after which the Ephemery testnet is reset to a new genesis.
+pub const EPHEMERY_PERIOD: u64 = 28 * 24 * 60 * 60;
+
+/// The chain id of the first Ephemery iteration, every iteration increments it by one.
+pub const EPHEMERY_CHAIN_ID_BASE: u64 = 39438000;
+
+/// An iteration of the Ephemery testnet, i.e. the network between two resets.
+///
+/// Every iteration starts from the same genesis state, only the genesis timestamp and the chain id
+/// differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EphemeryIteration(pub u64);
+
+impl EphemeryIteration {
+    /// Returns the iteration that is live at the given timestamp.
+    pub const fn at(timestamp: u64) -> Self {
+        Self(timestamp.saturating_sub(EPHEMERY_GENESIS_TIMESTAMP_BASE) / EPHEMERY_PERIOD)
+    }
+
+    /// Returns the iteration that is live now, according to the system clock.
+    pub fn current() -> Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        Self::at(now)
+    }
+
+    /// Returns the genesis timestamp of the iteration.
+    pub const fn genesis_timestamp(&self) -> u64 {
+        EPHEMERY_GENESIS_TIMESTAMP_BASE + self.0 * EPHEMERY_PERIOD
+    }
+
+    /// Returns the timestamp at which the network is reset to the next iteration.
+    pub const fn reset_timestamp(&self) -> u64 {
+        self.genesis_timestamp() + EPHEMERY_PERIOD
+    }
+
+    /// Returns the chain id of the iteration.
+    pub const fn chain_id(&self) -> u64 {
+        EPHEMERY_CHAIN_ID_BASE + self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ephemery_iterations() {
+        assert_eq!(EphemeryIteration::at(0), EphemeryIteration(0));
+        assert_eq!(EphemeryIteration::at(EPHEMERY_GENESIS_TIMESTAMP_BASE), EphemeryIteration(0));
+
+        let iteration = EphemeryIteration::at(EPHEMERY_GENESIS_TIMESTAMP_BASE + EPHEMERY_PERIOD);
+        assert_eq!(iteration, EphemeryIteration(1));
+        assert_eq!(
+            iteration.genesis_timestamp(),
+            EPHEMERY_GENESIS_TIMESTAMP_BASE + EPHEMERY_PERIOD
+        );
+        assert_eq!(iteration.chain_id(), EPHEMERY_CHAIN_ID_BASE + 1);
+        assert_eq!(EphemeryIteration::at(iteration.reset_timestamp() - 1), iteration);
+        assert_eq!(EphemeryIteration::at(iteration.reset_timestamp()), EphemeryIteration(2));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{chain::ChainSpec, ForkCondition, Head, B256};
+use alloy_chains::Chain;
+use std::collections::BTreeSet;
+
+/// Returns a copy of the `current` spec with the fork schedule of the `update` spec, see
+/// [ChainSpec::update_fork_schedule].
+pub(crate) fn update_fork_schedule(
+    current: &ChainSpec,
+    update: &ChainSpec,
+    head: &Head,
+) -> Result<ChainSpec, ForkScheduleError> {
+    if current.chain != update.chain {
+        return Err(ForkScheduleError::ChainMismatch {
+            current: current.chain,
+            update: update.chain,
+        })
+    }
+    if current.genesis_hash() != update.genesis_hash() {
+        return Err(ForkScheduleError::GenesisMismatch {
+            current: current.genesis_hash(),
+            update: update.genesis_hash(),
+        })
+    }
+
+    let forks = current
+        .hardforks()
+        .keys()
+        .chain(update.hardforks().keys())
+        .copied()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|fork| (fork.to_string(), current.fork(fork), update.fork(fork)));
+    let custom_forks = current
+        .custom_hardforks()
+        .forks_iter()
+        .chain(update.custom_hardforks().forks_iter())
+        .map(|(fork, _)| fork.name())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|name| (name.to_string(), current.custom_fork(name), update.custom_fork(name)))
+        .collect::<Vec<_>>();
+    for (fork, old, new) in forks.chain(custom_forks) {
+        check_fork(fork, old, new, head)?;
+    }
+
+    let mut spec = current.clone();
+    spec.set_hardforks(update.hardforks().clone());
+    spec.set_custom_hardforks(update.custom_hardforks().clone());
+    Ok(spec)
+}
+
+/// Checks that the change of the activation condition of the fork only affects blocks after the
+/// head.
+fn check_fork(
+    fork: String,
+    old: ForkCondition,
+    new: ForkCondition,
+    head: &Head,
+) -> Result<(), ForkScheduleError> {
+    if old == new {
+        return Ok(())
+    }
+    if old.active_at_head(head) {
+        return Err(ForkScheduleError::ActiveForkChanged { fork, current: old, update: new })
+    }
+    if new == ForkCondition::Never {
+        return Err(ForkScheduleError::ForkRemoved { fork })
+    }
+    if new.active_at_head(head) {
+        return Err(ForkScheduleError::ForkActivatedBeforeHead { fork, update: new })
+    }
+    Ok(())
+}
+
+/// An error applying the fork schedule of an updated chain spec, see
+/// [ChainSpec::update_fork_schedule].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ForkScheduleError {
+    /// The updated spec is for another chain.
+    #[error("the updated chain spec is for chain {update}, expected {current}")]
+    ChainMismatch {
+        /// The chain of the current spec.
+        current: Chain,
+        /// The chain of the updated spec.
+        update: Chain,
+    },
+    /// The updated spec has another genesis.
+    #[error("the updated chain spec has genesis {update}, expected {current}")]
+    GenesisMismatch {
+        /// The genesis hash of the current spec.
+        current: B256,
+        /// The genesis hash of the updated spec.
+        update: B256,
+    },
+    /// The activation condition of a fork that is already active at the head was changed.
+    #[error(
+        "fork {fork} is already active, its activation can't change from {current:?} to {update:?}"
+    )]
+    ActiveForkChanged {
+        /// The name of the fork.
+        fork: String,
+        /// The activation condition of the current spec.
+        current: ForkCondition,
+        /// The activation condition of the updated spec.
+        update: ForkCondition,
+    },
+    /// A scheduled fork was removed from the updated spec.
+    #[error("fork {fork} can't be removed from the schedule")]
+    ForkRemoved {
+        /// The name of the fork.
+        fork: String,
+    },
+    /// A fork was added or moved to an activation that the head already reached.
+    #[error("fork {fork} can't be scheduled at {update:?}, the head is already past it")]
+    ForkActivatedBeforeHead {
+        /// The name of the fork.
+        fork: String,
+        /// The activation condition of the updated spec.
+        update: ForkCondition,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChainSpecBuilder, GenesisHardfork, Hardfork, MAINNET};
+
+    /// Returns the timestamp of Shanghai on mainnet and a mainnet spec with Cancun scheduled
+    /// shortly after it.
+    fn mainnet_with_pending_cancun() -> (u64, ChainSpecBuilder) {
+        let shanghai = MAINNET.fork(Hardfork::Shanghai).as_timestamp().unwrap();
+        let builder = ChainSpecBuilder::mainnet()
+            .with_fork(Hardfork::Cancun, ForkCondition::Timestamp(shanghai + 100));
+        (shanghai, builder)
+    }
+
+    #[test]
+    fn update_pending_forks() {
+        let (shanghai, builder) = mainnet_with_pending_cancun();
+        let head = Head { number: 17_034_870, timestamp: shanghai, ..Default::default() };
+        let current = builder.clone().build();
+        let fork_id = current.fork_id(&head);
+
+        let update = builder
+            .with_fork(Hardfork::Cancun, ForkCondition::Timestamp(shanghai + 200))
+            .with_fork(Hardfork::Prague, ForkCondition::Timestamp(shanghai + 300))
+            .with_custom_fork(
+                GenesisHardfork::new("myFork"),
+                ForkCondition::Timestamp(shanghai + 400),
+            )
+            .build();
+        let updated = current.update_fork_schedule(&update, &head).unwrap();
+        assert_eq!(updated.fork(Hardfork::Cancun), ForkCondition::Timestamp(shanghai + 200));
+        assert_eq!(updated.fork(Hardfork::Prague), ForkCondition::Timestamp(shanghai + 300));
+        assert_eq!(updated.custom_fork("myFork"), ForkCondition::Timestamp(shanghai + 400));
+        assert_eq!(updated.fork_id(&head).hash, fork_id.hash);
+        assert_eq!(updated.fork_id(&head).next, shanghai + 200);
+        assert_eq!(fork_id.next, shanghai + 100);
+    }
+
+    #[test]
+    fn reject_active_fork_changes() {
+        let (shanghai, builder) = mainnet_with_pending_cancun();
+        let head = Head { number: 17_034_870, timestamp: shanghai, ..Default::default() };
+        let current = builder.clone().build();
+
+        let update = builder
+            .clone()
+            .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(shanghai + 1))
+            .build();
+        assert!(matches!(
+            current.update_fork_schedule(&update, &head),
+            Err(ForkScheduleError::ActiveForkChanged { fork, .. }) if fork == "Shanghai"
+        ));
+
+        let update =
+            builder.clone().with_fork(Hardfork::Cancun, ForkCondition::Timestamp(shanghai)).build();
+        assert!(matches!(
+            current.update_fork_schedule(&update, &head),
+            Err(ForkScheduleError::ForkActivatedBeforeHead { fork, .. }) if fork == "Cancun"
+        ));
+
+        let update = builder.clone().without_fork(Hardfork::Cancun).build();
+        assert_eq!(
+            current.update_fork_schedule(&update, &head).unwrap_err(),
+            ForkScheduleError::ForkRemoved { fork: "Cancun".to_string() }
+        );
+
+        let update = builder.chain(Chain::from_id(1337)).build();
+        assert!(matches!(
+            current.update_fork_schedule(&update, &head),
+            Err(ForkScheduleError::ChainMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{revm_primitives::address, Address, ChainHardfork};
+use serde::{Deserialize, Serialize};
+
+/// The address that collects the EIP-1559 base fees on Gnosis Chain.
+pub const GNOSIS_FEE_COLLECTOR_ADDRESS: Address =
+    address!("6BBe78ee9e474842Dbd4AB4987b3CeFE88426A92");
+
+/// The address that collects the EIP-1559 base fees on Chiado.
+pub const CHIADO_FEE_COLLECTOR_ADDRESS: Address =
+    address!("1559000000000000000000000000000000000000");
+
+/// The Gnosis Chain specific hardforks, scheduled as custom forks of the chain spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GnosisHardfork {
+    /// The switch of the AuRa validator set to the POSDAO contracts.
+    Posdao,
+}
+
+impl ChainHardfork for GnosisHardfork {
+    fn name(&self) -> &str {
+        match self {
+            GnosisHardfork::Posdao => "Posdao",
+        }
+    }
+}
+
+/// The parameters of the AuRa engine that sealed the blocks of a Gnosis chain before the merge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuraConfig {
+    /// The number of seconds per step, i.e. between two blocks.
+    pub step_duration: u64,
+    /// The contract that holds the validator set.
+    pub validator_contract: Address,
+    /// The contract that computes the block rewards.
+    pub block_reward_contract: Address,
+    /// The contract the validators commit their randomness to.
+    pub randomness_contract: Address,
+}
+
+/// The Gnosis specific configuration of a chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GnosisConfig {
+    /// The parameters of the AuRa engine.
+    pub aura: AuraConfig,
+    /// The address that collects the base fees, which Gnosis doesn't burn.
+    pub fee_collector: Address,
+}
+
+impl GnosisConfig {
+    /// The configuration of Gnosis Chain.
+    pub const fn gnosis() -> Self {
+        Self {
+            aura: AuraConfig {
+                step_duration: 5,
+                validator_contract: address!("B87BE9f7196F2AE084Ca1DE6af5264292976e013"),
+                block_reward_contract: address!("867305D19606aadBa405Ce534E303D0E225f9556"),
+                randomness_contract: address!("5870b0527DeDB1cFBD9534343Feda1a41Ce47766"),
+            },
+            fee_collector: GNOSIS_FEE_COLLECTOR_ADDRESS,
+        }
+    }
+
+    /// The configuration of the Chiado testnet.
+    pub const fn chiado() -> Self {
+        Self {
+            aura: AuraConfig {
+                step_duration: 5,
+                validator_contract: address!("1000000000000000000000000000000000000001"),
+                block_reward_contract: address!("2000000000000000000000000000000000000001"),
+                randomness_contract: address!("3000000000000000000000000000000000000001"),
+            },
+            fee_collector: CHIADO_FEE_COLLECTOR_ADDRESS,
+        }
+    }
+}
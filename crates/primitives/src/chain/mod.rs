@@ -1,17 +1,76 @@
 pub use alloy_chains::{Chain, NamedChain};
+#[cfg(feature = "polygon")]
+pub use bor::{BorConfig, BOR_STATE_RECEIVER_ADDRESS, BOR_VALIDATOR_SET_ADDRESS};
+#[cfg(feature = "bsc")]
+pub use bsc::{
+    BscHardfork, BSC_SLASH_ADDRESS, BSC_SYSTEM_REWARD_ADDRESS, BSC_VALIDATOR_SET_ADDRESS,
+};
+pub use deposit::{DepositEvent, DepositEventError};
+pub use dev::{DevChainSpecBuilder, DEV_MNEMONIC, DEV_NUMBER_OF_ACCOUNTS};
+pub use diff::{ChainSpecDiff, ForkDiff, GenesisFieldDiff, ValueDiff};
+pub use ephemery::{
+    EphemeryIteration, EPHEMERY_CHAIN_ID_BASE, EPHEMERY_GENESIS_TIMESTAMP_BASE, EPHEMERY_PERIOD,
+};
+pub use fork_schedule::ForkScheduleError;
+#[cfg(feature = "gnosis")]
+pub use gnosis::{
+    AuraConfig, GnosisConfig, GnosisHardfork, CHIADO_FEE_COLLECTOR_ADDRESS,
+    GNOSIS_FEE_COLLECTOR_ADDRESS,
+};
 pub use info::ChainInfo;
+#[cfg(feature = "optimism")]
+pub use rollup::{RollupConfig, DEFAULT_CHANNEL_TIMEOUT, SEQUENCER_FEE_VAULT_ADDRESS};
+#[cfg(feature = "goerli")]
+pub use spec::GOERLI;
 pub use spec::{
-    AllGenesisFormats, BaseFeeParams, BaseFeeParamsKind, ChainSpec, ChainSpecBuilder,
-    DisplayHardforks, ForkBaseFeeParams, ForkCondition, ForkTimestamps, DEV, GOERLI, HOLESKY,
-    MAINNET, SEPOLIA,
+    AllGenesisFormats, BaseFeeParams, BaseFeeParamsKind, BlobParams, BlobScheduleKind,
+    ChainHardforks, ChainSpec, ChainSpecBuilder, ConsensusConfig, DepositContract, DisplayFork,
+    DisplayHardforks, ForkActivationCache, ForkBaseFeeParams, ForkBlobParams, ForkIdTable,
+    GasLimitParams, GasLimitSchedule, GenesisConfigError, GenesisHardfork, PrecompileSchedule,
+    SystemContracts, DEV, DEV_CANCUN, HOLESKY, MAINNET, SEPOLIA,
 };
 #[cfg(feature = "optimism")]
 pub use spec::{BASE_GOERLI, BASE_MAINNET, BASE_SEPOLIA, OP_GOERLI};
+pub use state_migration::{ForkStateMigration, StateMigrationSchedule};
+#[cfg(feature = "optimism")]
+pub use state_migration::{CREATE_2_DEPLOYER_ADDR, CREATE_2_DEPLOYER_BYTECODE};
+#[cfg(feature = "optimism")]
+pub use superchain::{
+    SuperchainBlockId, SuperchainChainConfig, SuperchainEip1559Config, SuperchainGenesis,
+    SuperchainHardforks, SuperchainRegistry, SuperchainSystemConfig, SUPERCHAIN_REGISTRY,
+};
 
 // The chain spec module.
 mod spec;
 // The chain info module.
 mod info;
+// The chain spec diff module.
+mod diff;
+// The deposit contract events module.
+mod deposit;
+// The fork schedule updates module.
+mod fork_schedule;
+// The configurable dev chain module.
+mod dev;
+// The fork state migrations module.
+mod state_migration;
+// The superchain registry module.
+#[cfg(feature = "optimism")]
+mod superchain;
+// The OP-stack rollup config module.
+#[cfg(feature = "optimism")]
+mod rollup;
+// The Polygon PoS Bor config module.
+#[cfg(feature = "polygon")]
+mod bor;
+// The BSC hardforks module.
+#[cfg(feature = "bsc")]
+mod bsc;
+// The Gnosis AuRa and fee collector config module.
+#[cfg(feature = "gnosis")]
+mod gnosis;
+// The Ephemery testnet iterations module.
+mod ephemery;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,107 @@
+use crate::{revm_primitives::address, Address, ChainConfig};
+use serde::{Deserialize, Serialize};
+
+/// The genesis config key of the OP-stack configuration block.
+const GENESIS_OPTIMISM_KEY: &str = "optimism";
+
+/// The predeploy that collects the priority fees of L2 blocks, the coinbase of OP-stack blocks.
+pub const SEQUENCER_FEE_VAULT_ADDRESS: Address =
+    address!("4200000000000000000000000000000000000011");
+
+/// The default number of L1 blocks after which a channel of batch data times out.
+pub const DEFAULT_CHANNEL_TIMEOUT: u64 = 300;
+
+/// The rollup configuration of an OP-stack chain.
+///
+/// This is read from the `optimism` block of a genesis chain config, e.g.
+/// `"optimism": { "l1ChainId": 1, "batchInboxAddress": "0x...", "depositContractAddress": "0x..." }`,
+/// next to the EIP-1559 parameters op-geth reads from the same block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RollupConfig {
+    /// The chain id of the L1 chain the rollup settles on.
+    pub l1_chain_id: u64,
+    /// The L1 address batches of L2 transactions are submitted to.
+    pub batch_inbox_address: Address,
+    /// The L1 address of the `OptimismPortal` contract, which emits the deposits of the rollup.
+    #[serde(alias = "portalAddress")]
+    pub deposit_contract_address: Address,
+    /// The L2 address that collects the priority fees.
+    #[serde(default = "default_sequencer_fee_vault")]
+    pub sequencer_fee_vault: Address,
+    /// The number of L1 blocks after which a channel of batch data times out.
+    #[serde(default = "default_channel_timeout")]
+    pub channel_timeout: u64,
+}
+
+const fn default_sequencer_fee_vault() -> Address {
+    SEQUENCER_FEE_VAULT_ADDRESS
+}
+
+const fn default_channel_timeout() -> u64 {
+    DEFAULT_CHANNEL_TIMEOUT
+}
+
+impl RollupConfig {
+    /// Creates the rollup configuration of a chain that uses the default sequencer fee vault and
+    /// channel timeout.
+    pub const fn new(
+        l1_chain_id: u64,
+        batch_inbox_address: Address,
+        deposit_contract_address: Address,
+    ) -> Self {
+        Self {
+            l1_chain_id,
+            batch_inbox_address,
+            deposit_contract_address,
+            sequencer_fee_vault: SEQUENCER_FEE_VAULT_ADDRESS,
+            channel_timeout: DEFAULT_CHANNEL_TIMEOUT,
+        }
+    }
+
+    /// Reads the rollup configuration from the `optimism` block of a genesis chain config.
+    ///
+    /// Returns `None` if the chain config has no `optimism` block or the block lacks the L1
+    /// settlement fields, e.g. because it only configures the EIP-1559 parameters.
+    pub fn from_chain_config(config: &ChainConfig) -> Option<Self> {
+        let optimism = config.extra_fields.get(GENESIS_OPTIMISM_KEY)?;
+        RollupConfig::deserialize(optimism).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rollup_config_from_chain_config() {
+        let config: ChainConfig = serde_json::from_str(
+            r#"{
+                "chainId": 11155420,
+                "optimism": {
+                    "eip1559Elasticity": 6,
+                    "eip1559Denominator": 50,
+                    "l1ChainId": 11155111,
+                    "batchInboxAddress": "0xff00000000000000000000000000000011155420",
+                    "portalAddress": "0x16Fc5058F25648194471939df75CF27A2fdC48BC"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            RollupConfig::from_chain_config(&config),
+            Some(RollupConfig::new(
+                11155111,
+                address!("ff00000000000000000000000000000011155420"),
+                address!("16Fc5058F25648194471939df75CF27A2fdC48BC"),
+            ))
+        );
+
+        // only the EIP-1559 parameters are configured
+        let config: ChainConfig = serde_json::from_str(
+            r#"{"chainId": 10, "optimism": {"eip1559Elasticity": 6, "eip1559Denominator": 50}}"#,
+        )
+        .unwrap();
+        assert_eq!(RollupConfig::from_chain_config(&config), None);
+    }
+}
@@ -1,24 +1,198 @@
+#[cfg(feature = "polygon")]
+use crate::chain::{bor::GENESIS_BOR_KEY, BorConfig};
+#[cfg(feature = "gnosis")]
+use crate::chain::{AuraConfig, GnosisConfig};
+#[cfg(feature = "optimism")]
+use crate::chain::{RollupConfig, SEQUENCER_FEE_VAULT_ADDRESS};
+#[cfg(feature = "goerli")]
+use crate::net::goerli_nodes;
 use crate::{
+    chain::{
+        fork_schedule, ChainSpecDiff, EphemeryIteration, ForkScheduleError, ForkStateMigration,
+        StateMigrationSchedule,
+    },
     constants::{
-        EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR, EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
-        EIP1559_INITIAL_BASE_FEE, EMPTY_RECEIPTS, EMPTY_TRANSACTIONS, EMPTY_WITHDRAWALS,
+        eip4844::{
+            KzgSettingsSource, BLOB_GASPRICE_UPDATE_FRACTION, DATA_GAS_PER_BLOB,
+            MAX_BLOBS_PER_BLOCK, TARGET_BLOBS_PER_BLOCK,
+        },
+        ALLOWED_FUTURE_BLOCK_TIME_SECONDS, BEACON_ROOTS_ADDRESS,
+        CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS, EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
+        EIP1559_DEFAULT_ELASTICITY_MULTIPLIER, EIP1559_INITIAL_BASE_FEE, EMPTY_RECEIPTS,
+        EMPTY_TRANSACTIONS, EMPTY_WITHDRAWALS, HISTORY_STORAGE_ADDRESS, MAX_CODE_SIZE,
+        MAX_INIT_CODE_SIZE, SLOT_DURATION, WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS,
     },
     holesky_nodes,
-    net::{goerli_nodes, mainnet_nodes, sepolia_nodes},
+    net::{ethdisco_tree, hoodi_nodes, mainnet_nodes, op_nodes, op_testnet_nodes, sepolia_nodes},
     proofs::state_root_ref_unhashed,
-    revm_primitives::{address, b256},
-    Address, BlockNumber, ForkFilter, ForkFilterKey, ForkHash, ForkId, Genesis, Hardfork, Head,
-    Header, NodeRecord, SealedHeader, B256, EMPTY_OMMER_ROOT_HASH, U256,
+    revm_primitives::{address, b256, fake_exponential, BlobExcessGasAndPrice, MIN_BLOB_GASPRICE},
+    Address, BlockNumber, BootNode, Bytes, ChainConfig, ChainHardfork, ForkCondition, ForkFilter,
+    ForkFilterKey, ForkHash, ForkId, ForkIdValidation, Genesis, GenesisAccount, GenesisAllocExt,
+    Hardfork, Head, Header, Log, NodeRecord, PruneConfig, PruneSegment, SealedHeader,
+    SnapshotConfig, B256, EMPTY_OMMER_ROOT_HASH, U256,
 };
+#[cfg(feature = "c-kzg")]
+use crate::{constants::eip4844::LoadKzgSettingsError, kzg::KzgSettings};
 use alloy_chains::{Chain, NamedChain};
-use once_cell::sync::Lazy;
-use serde::{Deserialize, Serialize};
+use once_cell::sync::{Lazy, OnceCell};
+use reth_codecs::Compact;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fmt::{Display, Formatter},
     sync::Arc,
+    time::Duration,
 };
 
+/// The chain id of the Hoodi testnet, which is not a named chain yet.
+const HOODI_CHAIN_ID: u64 = 560048;
+
+/// The genesis config key of the Osaka activation timestamp.
+///
+/// The pinned `alloy-genesis` [ChainConfig] has no `osaka_time` field, so the timestamp is read
+/// from and written to the extra fields of the config.
+const GENESIS_OSAKA_TIME_KEY: &str = "osakaTime";
+
+/// The genesis config key of the chain specific forks, see [GenesisHardfork].
+const GENESIS_CUSTOM_FORKS_KEY: &str = "customForks";
+
+/// The genesis config key of the EIP-7840 blob schedule.
+const GENESIS_BLOB_SCHEDULE_KEY: &str = "blobSchedule";
+
+/// The genesis config key of the gas limit targets and caps per hardfork.
+const GENESIS_GAS_LIMIT_SCHEDULE_KEY: &str = "gasLimitSchedule";
+
+/// The genesis config key of the Parlia consensus parameters, as used by bsc.
+#[cfg(feature = "bsc")]
+const GENESIS_PARLIA_KEY: &str = "parlia";
+
+/// The genesis config key of the system contract addresses that differ from the canonical ones.
+const GENESIS_SYSTEM_CONTRACTS_KEY: &str = "systemContracts";
+
+/// The genesis config key of the address of the PoS deposit contract.
+const GENESIS_DEPOSIT_CONTRACT_KEY: &str = "depositContractAddress";
+
+/// The genesis config key of the chain-specific precompiles per hardfork.
+const GENESIS_PRECOMPILES_KEY: &str = "precompiles";
+
+/// The genesis config key under which chains can declare additional difficulty bomb delays.
+const GENESIS_BOMB_DELAYS_KEY: &str = "difficultyBombDelays";
+
+/// The genesis config key of the number of seconds a block timestamp may be ahead of the local
+/// clock.
+const GENESIS_MAX_FUTURE_TIMESTAMP_DRIFT_KEY: &str = "maxFutureTimestampDrift";
+
+/// The genesis config key of the targeted number of seconds between two blocks.
+const GENESIS_BLOCK_PERIOD_KEY: &str = "blockPeriodSeconds";
+
+/// The genesis config key of the maximum code size of a deployed contract.
+const GENESIS_MAX_CODE_SIZE_KEY: &str = "maxCodeSize";
+
+/// The genesis config key of the maximum init code size of a contract creation.
+const GENESIS_MAX_INIT_CODE_SIZE_KEY: &str = "maxInitCodeSize";
+
+/// The genesis config key of the maximum encoded size of a transaction.
+const GENESIS_MAX_TX_SIZE_KEY: &str = "maxTxSize";
+
+/// The genesis config key of the boot nodes of the chain.
+const GENESIS_BOOTNODES_KEY: &str = "bootnodes";
+
+/// The genesis config key of the trusted peers of the chain.
+const GENESIS_TRUSTED_PEERS_KEY: &str = "trustedPeers";
+
+/// The genesis config keys of the OP-stack hardforks, as used by op-geth.
+#[cfg(feature = "optimism")]
+const OP_GENESIS_HARDFORKS: [(&str, Hardfork); 7] = [
+    ("bedrockBlock", Hardfork::Bedrock),
+    ("regolithTime", Hardfork::Regolith),
+    ("canyonTime", Hardfork::Canyon),
+    ("ecotoneTime", Hardfork::Ecotone),
+    ("fjordTime", Hardfork::Fjord),
+    ("graniteTime", Hardfork::Granite),
+    ("holoceneTime", Hardfork::Holocene),
+];
+
+/// The genesis config keys of the Bor hardforks, read from the `bor` block as used by bor.
+#[cfg(feature = "polygon")]
+const BOR_GENESIS_HARDFORKS: [(&str, Hardfork); 7] = [
+    ("jaipurBlock", Hardfork::Jaipur),
+    ("delhiBlock", Hardfork::Delhi),
+    ("indoreBlock", Hardfork::Indore),
+    ("agraBlock", Hardfork::Agra),
+    ("napoliBlock", Hardfork::Napoli),
+    ("ahmedabadBlock", Hardfork::Ahmedabad),
+    ("bhilaiBlock", Hardfork::Bhilai),
+];
+
+/// The genesis config keys of the Ethereum hardforks that Polygon PoS activates by block number,
+/// together with the Bor hardfork that shipped with them.
+#[cfg(feature = "polygon")]
+const BOR_GENESIS_BLOCK_HARDFORKS: [(&str, Hardfork, Option<Hardfork>); 3] = [
+    ("shanghaiBlock", Hardfork::Shanghai, Some(Hardfork::Agra)),
+    ("cancunBlock", Hardfork::Cancun, Some(Hardfork::Napoli)),
+    ("pragueBlock", Hardfork::Prague, None),
+];
+
+/// The precompiles of Ethereum and the hardforks that enabled them.
+const ETHEREUM_PRECOMPILES: [(Hardfork, &[Address]); 6] = [
+    // ecrecover, sha256, ripemd160, identity
+    (
+        Hardfork::Frontier,
+        &[
+            address!("0000000000000000000000000000000000000001"),
+            address!("0000000000000000000000000000000000000002"),
+            address!("0000000000000000000000000000000000000003"),
+            address!("0000000000000000000000000000000000000004"),
+        ],
+    ),
+    // EIP-198 modexp, EIP-196 and EIP-197 bn254 add, mul and pairing
+    (
+        Hardfork::Byzantium,
+        &[
+            address!("0000000000000000000000000000000000000005"),
+            address!("0000000000000000000000000000000000000006"),
+            address!("0000000000000000000000000000000000000007"),
+            address!("0000000000000000000000000000000000000008"),
+        ],
+    ),
+    // EIP-152 blake2f
+    (Hardfork::Istanbul, &[address!("0000000000000000000000000000000000000009")]),
+    // EIP-4844 point evaluation
+    (Hardfork::Cancun, &[address!("000000000000000000000000000000000000000a")]),
+    // EIP-2537 BLS12-381 operations
+    (
+        Hardfork::Prague,
+        &[
+            address!("000000000000000000000000000000000000000b"),
+            address!("000000000000000000000000000000000000000c"),
+            address!("000000000000000000000000000000000000000d"),
+            address!("000000000000000000000000000000000000000e"),
+            address!("000000000000000000000000000000000000000f"),
+            address!("0000000000000000000000000000000000000010"),
+            address!("0000000000000000000000000000000000000011"),
+        ],
+    ),
+    // EIP-7951 secp256r1 signature verification
+    (Hardfork::Osaka, &[address!("0000000000000000000000000000000000000100")]),
+];
+
+/// The hardforks that delayed the difficulty bomb, together with the total delay in blocks once
+/// the fork is active.
+const HARDFORK_BOMB_DELAYS: [(Hardfork, u64); 6] = [
+    // EIP-649
+    (Hardfork::Byzantium, 3_000_000),
+    // EIP-1234
+    (Hardfork::Constantinople, 5_000_000),
+    // EIP-2384
+    (Hardfork::MuirGlacier, 9_000_000),
+    // EIP-3554
+    (Hardfork::London, 9_700_000),
+    // EIP-4345
+    (Hardfork::ArrowGlacier, 10_700_000),
+    // EIP-5133
+    (Hardfork::GrayGlacier, 11_400_000),
+];
+
 /// The Ethereum mainnet spec
 pub static MAINNET: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
     ChainSpec {
@@ -33,7 +207,6 @@ pub static MAINNET: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             15537394,
             U256::from(58_750_003_716_598_352_816_469u128),
         )),
-        fork_timestamps: ForkTimestamps::default().shanghai(1681338455).cancun(1710338135),
         hardforks: BTreeMap::from([
             (Hardfork::Frontier, ForkCondition::Block(0)),
             (Hardfork::Homestead, ForkCondition::Block(1150000)),
@@ -59,20 +232,50 @@ pub static MAINNET: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             (Hardfork::Shanghai, ForkCondition::Timestamp(1681338455)),
             (Hardfork::Cancun, ForkCondition::Timestamp(1710338135)),
         ]),
+        custom_hardforks: Default::default(),
         // https://etherscan.io/tx/0xe75fb554e433e03763a1560646ee22dcb74e5274b34c5ad644e7c0f619a7e1d0
-        deposit_contract: Some(DepositContract::new(
+        deposit_contracts: vec![DepositContract::new(
             address!("00000000219ab540356cbb839cbe05303d7705fa"),
             11052984,
             b256!("649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5"),
-        )),
+        )],
+        consensus: ConsensusConfig::Ethash,
         base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-        prune_delete_limit: 3500,
-        snapshot_block_interval: 500_000,
+        blob_params: BlobScheduleKind::Constant(BlobParams::cancun()),
+        gas_limit_schedule: Default::default(),
+        system_contracts: Default::default(),
+        precompiles: Default::default(),
+        state_migrations: Default::default(),
+        prune_config: PruneConfig::new(3500),
+        snapshot_config: SnapshotConfig::new(500_000),
+        kzg_settings: KzgSettingsSource::Embedded,
+        max_future_timestamp_drift: SLOT_DURATION.as_secs(),
+        block_time: Some(SLOT_DURATION.as_secs()),
+        max_code_size: MAX_CODE_SIZE,
+        max_init_code_size: MAX_INIT_CODE_SIZE,
+        max_tx_size: None,
+        bootnodes: Vec::new(),
+        trusted_peers: Vec::new(),
+        #[cfg(feature = "optimism")]
+        rollup: None,
+        #[cfg(feature = "polygon")]
+        bor: None,
+        #[cfg(feature = "gnosis")]
+        gnosis: None,
+        fork_id_table: OnceCell::new(),
+        fork_activations: OnceCell::new(),
+        sealed_genesis_header: OnceCell::new(),
+        #[cfg(feature = "c-kzg")]
+        kzg_settings_cache: OnceCell::new(),
     }
     .into()
 });
 
 /// The Goerli spec
+///
+/// Goerli is deprecated in favor of [HOLESKY] and Hoodi and only available with the `goerli`
+/// feature.
+#[cfg(feature = "goerli")]
 pub static GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
     ChainSpec {
         chain: Chain::goerli(),
@@ -83,7 +286,6 @@ pub static GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
         )),
         // <https://goerli.etherscan.io/block/7382818>
         paris_block_and_final_difficulty: Some((7382818, U256::from(10_790_000))),
-        fork_timestamps: ForkTimestamps::default().shanghai(1678832736).cancun(1705473120),
         hardforks: BTreeMap::from([
             (Hardfork::Frontier, ForkCondition::Block(0)),
             (Hardfork::Homestead, ForkCondition::Block(0)),
@@ -103,15 +305,41 @@ pub static GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             (Hardfork::Shanghai, ForkCondition::Timestamp(1678832736)),
             (Hardfork::Cancun, ForkCondition::Timestamp(1705473120)),
         ]),
+        custom_hardforks: Default::default(),
         // https://goerli.etherscan.io/tx/0xa3c07dc59bfdb1bfc2d50920fed2ef2c1c4e0a09fe2325dbc14e07702f965a78
-        deposit_contract: Some(DepositContract::new(
+        deposit_contracts: vec![DepositContract::new(
             address!("ff50ed3d0ec03ac01d4c79aad74928bff48a7b2b"),
             4367322,
             b256!("649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5"),
-        )),
+        )],
+        consensus: ConsensusConfig::Clique { period: 15, epoch: 30_000 },
         base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-        prune_delete_limit: 1700,
-        snapshot_block_interval: 1_000_000,
+        blob_params: BlobScheduleKind::Constant(BlobParams::cancun()),
+        gas_limit_schedule: Default::default(),
+        system_contracts: Default::default(),
+        precompiles: Default::default(),
+        state_migrations: Default::default(),
+        prune_config: PruneConfig::new(1700),
+        snapshot_config: SnapshotConfig::new(1_000_000),
+        kzg_settings: KzgSettingsSource::Embedded,
+        max_future_timestamp_drift: SLOT_DURATION.as_secs(),
+        block_time: Some(SLOT_DURATION.as_secs()),
+        max_code_size: MAX_CODE_SIZE,
+        max_init_code_size: MAX_INIT_CODE_SIZE,
+        max_tx_size: None,
+        bootnodes: Vec::new(),
+        trusted_peers: Vec::new(),
+        #[cfg(feature = "optimism")]
+        rollup: None,
+        #[cfg(feature = "polygon")]
+        bor: None,
+        #[cfg(feature = "gnosis")]
+        gnosis: None,
+        fork_id_table: OnceCell::new(),
+        fork_activations: OnceCell::new(),
+        sealed_genesis_header: OnceCell::new(),
+        #[cfg(feature = "c-kzg")]
+        kzg_settings_cache: OnceCell::new(),
     }
     .into()
 });
@@ -127,7 +355,6 @@ pub static SEPOLIA: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
         )),
         // <https://sepolia.etherscan.io/block/1450409>
         paris_block_and_final_difficulty: Some((1450409, U256::from(17_000_018_015_853_232u128))),
-        fork_timestamps: ForkTimestamps::default().shanghai(1677557088).cancun(1706655072),
         hardforks: BTreeMap::from([
             (Hardfork::Frontier, ForkCondition::Block(0)),
             (Hardfork::Homestead, ForkCondition::Block(0)),
@@ -151,15 +378,41 @@ pub static SEPOLIA: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             (Hardfork::Shanghai, ForkCondition::Timestamp(1677557088)),
             (Hardfork::Cancun, ForkCondition::Timestamp(1706655072)),
         ]),
+        custom_hardforks: Default::default(),
         // https://sepolia.etherscan.io/tx/0x025ecbf81a2f1220da6285d1701dc89fb5a956b62562ee922e1a9efd73eb4b14
-        deposit_contract: Some(DepositContract::new(
+        deposit_contracts: vec![DepositContract::new(
             address!("7f02c3e3c98b133055b8b348b2ac625669ed295d"),
             1273020,
             b256!("649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5"),
-        )),
+        )],
+        consensus: ConsensusConfig::Ethash,
         base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-        prune_delete_limit: 1700,
-        snapshot_block_interval: 1_000_000,
+        blob_params: BlobScheduleKind::Constant(BlobParams::cancun()),
+        gas_limit_schedule: Default::default(),
+        system_contracts: Default::default(),
+        precompiles: Default::default(),
+        state_migrations: Default::default(),
+        prune_config: PruneConfig::new(1700),
+        snapshot_config: SnapshotConfig::new(1_000_000),
+        kzg_settings: KzgSettingsSource::Embedded,
+        max_future_timestamp_drift: SLOT_DURATION.as_secs(),
+        block_time: Some(SLOT_DURATION.as_secs()),
+        max_code_size: MAX_CODE_SIZE,
+        max_init_code_size: MAX_INIT_CODE_SIZE,
+        max_tx_size: None,
+        bootnodes: Vec::new(),
+        trusted_peers: Vec::new(),
+        #[cfg(feature = "optimism")]
+        rollup: None,
+        #[cfg(feature = "polygon")]
+        bor: None,
+        #[cfg(feature = "gnosis")]
+        gnosis: None,
+        fork_id_table: OnceCell::new(),
+        fork_activations: OnceCell::new(),
+        sealed_genesis_header: OnceCell::new(),
+        #[cfg(feature = "c-kzg")]
+        kzg_settings_cache: OnceCell::new(),
     }
     .into()
 });
@@ -174,7 +427,6 @@ pub static HOLESKY: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             "b5f7f912443c940f21fd611f12828d75b534364ed9e95ca4e307729a4661bde4"
         )),
         paris_block_and_final_difficulty: Some((0, U256::from(1))),
-        fork_timestamps: ForkTimestamps::default().shanghai(1696000704).cancun(1707305664),
         hardforks: BTreeMap::from([
             (Hardfork::Frontier, ForkCondition::Block(0)),
             (Hardfork::Homestead, ForkCondition::Block(0)),
@@ -195,14 +447,40 @@ pub static HOLESKY: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             (Hardfork::Shanghai, ForkCondition::Timestamp(1696000704)),
             (Hardfork::Cancun, ForkCondition::Timestamp(1707305664)),
         ]),
-        deposit_contract: Some(DepositContract::new(
+        custom_hardforks: Default::default(),
+        deposit_contracts: vec![DepositContract::new(
             address!("4242424242424242424242424242424242424242"),
             0,
             b256!("649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5"),
-        )),
+        )],
+        consensus: ConsensusConfig::None,
         base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-        prune_delete_limit: 1700,
-        snapshot_block_interval: 1_000_000,
+        blob_params: BlobScheduleKind::Constant(BlobParams::cancun()),
+        gas_limit_schedule: Default::default(),
+        system_contracts: Default::default(),
+        precompiles: Default::default(),
+        state_migrations: Default::default(),
+        prune_config: PruneConfig::new(1700),
+        snapshot_config: SnapshotConfig::new(1_000_000),
+        kzg_settings: KzgSettingsSource::Embedded,
+        max_future_timestamp_drift: SLOT_DURATION.as_secs(),
+        block_time: Some(SLOT_DURATION.as_secs()),
+        max_code_size: MAX_CODE_SIZE,
+        max_init_code_size: MAX_INIT_CODE_SIZE,
+        max_tx_size: None,
+        bootnodes: Vec::new(),
+        trusted_peers: Vec::new(),
+        #[cfg(feature = "optimism")]
+        rollup: None,
+        #[cfg(feature = "polygon")]
+        bor: None,
+        #[cfg(feature = "gnosis")]
+        gnosis: None,
+        fork_id_table: OnceCell::new(),
+        fork_activations: OnceCell::new(),
+        sealed_genesis_header: OnceCell::new(),
+        #[cfg(feature = "c-kzg")]
+        kzg_settings_cache: OnceCell::new(),
     }
     .into()
 });
@@ -210,7 +488,8 @@ pub static HOLESKY: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
 /// Dev testnet specification
 ///
 /// Includes 20 prefunded accounts with 10_000 ETH each derived from mnemonic "test test test test
-/// test test test test test test test junk".
+/// test test test test test test test junk". See [DevChainSpecBuilder](crate::DevChainSpecBuilder)
+/// to build variations of it.
 pub static DEV: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
     ChainSpec {
         chain: Chain::dev(),
@@ -220,7 +499,6 @@ pub static DEV: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             "2f980576711e3617a5e4d83dd539548ec0f7792007d505a3d2e9674833af2d7c"
         )),
         paris_block_and_final_difficulty: Some((0, U256::from(0))),
-        fork_timestamps: ForkTimestamps::default().shanghai(0),
         hardforks: BTreeMap::from([
             (Hardfork::Frontier, ForkCondition::Block(0)),
             (Hardfork::Homestead, ForkCondition::Block(0)),
@@ -240,8 +518,30 @@ pub static DEV: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             ),
             (Hardfork::Shanghai, ForkCondition::Timestamp(0)),
         ]),
+        consensus: ConsensusConfig::None,
+        base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
+        ..Default::default()
+    }
+    .into()
+});
+
+/// Dev testnet specification with Cancun activated at genesis
+///
+/// Same as [DEV], but the genesis header also carries the Cancun fields, with a zeroed parent
+/// beacon block root, so blob transactions can be sent to it.
+pub static DEV_CANCUN: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
+    let mut hardforks = DEV.hardforks.clone();
+    hardforks.insert(Hardfork::Cancun, ForkCondition::Timestamp(0));
+    ChainSpec {
+        chain: Chain::dev(),
+        genesis: DEV.genesis.clone(),
+        genesis_hash: Some(b256!(
+            "d17effc85005cb9645be61aa4a079d5e30ee7a46dc6078f45dd57fd4f0b12b82"
+        )),
+        paris_block_and_final_difficulty: Some((0, U256::from(0))),
+        hardforks,
+        consensus: ConsensusConfig::None,
         base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-        deposit_contract: None, // TODO: do we even have?
         ..Default::default()
     }
     .into()
@@ -257,11 +557,6 @@ pub static OP_GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
         genesis_hash: Some(b256!(
             "c1fc15cd51159b1f1e5cbc4b82e85c1447ddfa33c52cf1d98d14fba0d6354be1"
         )),
-        fork_timestamps: ForkTimestamps::default()
-            .shanghai(1699981200)
-            .canyon(1699981200)
-            .cancun(1707238800)
-            .ecotone(1707238800),
         paris_block_and_final_difficulty: Some((0, U256::from(0))),
         hardforks: BTreeMap::from([
             (Hardfork::Frontier, ForkCondition::Block(0)),
@@ -295,8 +590,15 @@ pub static OP_GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             ]
             .into(),
         ),
-        prune_delete_limit: 1700,
-        snapshot_block_interval: 1_000_000,
+        rollup: Some(RollupConfig::new(
+            5,
+            address!("ff00000000000000000000000000000000000420"),
+            address!("5b47E1A08Ea6d985D6649300584e6722Ec4B1383"),
+        )),
+        state_migrations: StateMigrationSchedule::optimism(),
+        prune_config: PruneConfig::new(1700),
+        snapshot_config: SnapshotConfig::new(1_000_000),
+        kzg_settings: KzgSettingsSource::Embedded,
         ..Default::default()
     }
     .into()
@@ -312,11 +614,6 @@ pub static BASE_GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
         genesis_hash: Some(b256!(
             "a3ab140f15ea7f7443a4702da64c10314eb04d488e72974e02e2d728096b4f76"
         )),
-        fork_timestamps: ForkTimestamps::default()
-            .shanghai(1699981200)
-            .canyon(1699981200)
-            .cancun(1707238800)
-            .ecotone(1707238800),
         paris_block_and_final_difficulty: Some((0, U256::from(0))),
         hardforks: BTreeMap::from([
             (Hardfork::Frontier, ForkCondition::Block(0)),
@@ -350,8 +647,15 @@ pub static BASE_GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             ]
             .into(),
         ),
-        prune_delete_limit: 1700,
-        snapshot_block_interval: 1_000_000,
+        rollup: Some(RollupConfig::new(
+            5,
+            address!("8453100000000000000000000000000000000000"),
+            address!("e93c8cD0D409341205A592f8c4Ac1A5fe5585cfA"),
+        )),
+        state_migrations: StateMigrationSchedule::optimism(),
+        prune_config: PruneConfig::new(1700),
+        snapshot_config: SnapshotConfig::new(1_000_000),
+        kzg_settings: KzgSettingsSource::Embedded,
         ..Default::default()
     }
     .into()
@@ -367,11 +671,6 @@ pub static BASE_SEPOLIA: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
         genesis_hash: Some(b256!(
             "0dcc9e089e30b90ddfc55be9a37dd15bc551aeee999d2e2b51414c54eaf934e4"
         )),
-        fork_timestamps: ForkTimestamps::default()
-            .shanghai(1699981200)
-            .canyon(1699981200)
-            .cancun(1708534800)
-            .ecotone(1708534800),
         paris_block_and_final_difficulty: Some((0, U256::from(0))),
         hardforks: BTreeMap::from([
             (Hardfork::Frontier, ForkCondition::Block(0)),
@@ -405,8 +704,15 @@ pub static BASE_SEPOLIA: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             ]
             .into(),
         ),
-        prune_delete_limit: 1700,
-        snapshot_block_interval: 1_000_000,
+        rollup: Some(RollupConfig::new(
+            11155111,
+            address!("ff00000000000000000000000000000000084532"),
+            address!("49f53e41452C74589E85cA1677426Ba426459e85"),
+        )),
+        state_migrations: StateMigrationSchedule::optimism(),
+        prune_config: PruneConfig::new(1700),
+        snapshot_config: SnapshotConfig::new(1_000_000),
+        kzg_settings: KzgSettingsSource::Embedded,
         ..Default::default()
     }
     .into()
@@ -422,7 +728,6 @@ pub static BASE_MAINNET: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
         genesis_hash: Some(b256!(
             "f712aa9241cc24369b143cf6dce85f0902a9731e70d66818a3a5845b296c73dd"
         )),
-        fork_timestamps: ForkTimestamps::default().shanghai(1704992401).canyon(1704992401),
         paris_block_and_final_difficulty: Some((0, U256::from(0))),
         hardforks: BTreeMap::from([
             (Hardfork::Frontier, ForkCondition::Block(0)),
@@ -454,8 +759,15 @@ pub static BASE_MAINNET: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             ]
             .into(),
         ),
-        prune_delete_limit: 1700,
-        snapshot_block_interval: 1_000_000,
+        rollup: Some(RollupConfig::new(
+            1,
+            address!("ff00000000000000000000000000000000008453"),
+            address!("49048044D57e1C92A77f79988d21Fa8fAF74E97e"),
+        )),
+        state_migrations: StateMigrationSchedule::optimism(),
+        prune_config: PruneConfig::new(1700),
+        snapshot_config: SnapshotConfig::new(1_000_000),
+        kzg_settings: KzgSettingsSource::Embedded,
         ..Default::default()
     }
     .into()
@@ -463,7 +775,7 @@ pub static BASE_MAINNET: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
 
 /// A wrapper around [BaseFeeParams] that allows for specifying constant or dynamic EIP-1559
 /// parameters based on the active [Hardfork].
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum BaseFeeParamsKind {
     /// Constant [BaseFeeParams]; used for chains that don't have dynamic EIP-1559 parameters
@@ -485,11 +797,71 @@ impl From<ForkBaseFeeParams> for BaseFeeParamsKind {
     }
 }
 
+/// The Ethereum hardforks in activation order, which arbitrary fork schedules are built from.
+#[cfg(any(test, feature = "arbitrary"))]
+const ARBITRARY_HARDFORKS: [Hardfork; 18] = [
+    Hardfork::Frontier,
+    Hardfork::Homestead,
+    Hardfork::Dao,
+    Hardfork::Tangerine,
+    Hardfork::SpuriousDragon,
+    Hardfork::Byzantium,
+    Hardfork::Constantinople,
+    Hardfork::Petersburg,
+    Hardfork::Istanbul,
+    Hardfork::MuirGlacier,
+    Hardfork::Berlin,
+    Hardfork::London,
+    Hardfork::ArrowGlacier,
+    Hardfork::GrayGlacier,
+    Hardfork::Paris,
+    Hardfork::Shanghai,
+    Hardfork::Cancun,
+    Hardfork::Prague,
+];
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for BaseFeeParamsKind {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u.arbitrary()? {
+            return Ok(BaseFeeParamsKind::Constant(u.arbitrary()?))
+        }
+
+        // the parameters change at some of the hardforks, listed in activation order
+        let mut params = Vec::new();
+        for fork in ARBITRARY_HARDFORKS {
+            if u.ratio(1, 4)? {
+                params.push((fork, u.arbitrary()?));
+            }
+        }
+        Ok(ForkBaseFeeParams(params).into())
+    }
+}
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for BaseFeeParams {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // both parameters divide in the base fee computation and must not be zero
+        Ok(BaseFeeParams {
+            max_change_denominator: u.int_in_range(1..=u64::MAX)?,
+            elasticity_multiplier: u.int_in_range(1..=u64::MAX)?,
+            minimum_base_fee: u.arbitrary()?,
+        })
+    }
+}
+
 /// A type alias to a vector of tuples of [Hardfork] and [BaseFeeParams], sorted by [Hardfork]
 /// activation order. This is used to specify dynamic EIP-1559 parameters for chains like Optimism.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ForkBaseFeeParams(Vec<(Hardfork, BaseFeeParams)>);
 
+impl ForkBaseFeeParams {
+    /// Returns the [BaseFeeParams] together with the [Hardfork] that activates them.
+    pub fn as_slice(&self) -> &[(Hardfork, BaseFeeParams)] {
+        &self.0
+    }
+}
+
 impl From<Vec<(Hardfork, BaseFeeParams)>> for ForkBaseFeeParams {
     fn from(params: Vec<(Hardfork, BaseFeeParams)>) -> Self {
         ForkBaseFeeParams(params)
@@ -497,12 +869,16 @@ impl From<Vec<(Hardfork, BaseFeeParams)>> for ForkBaseFeeParams {
 }
 
 /// BaseFeeParams contains the config parameters that control block base fee computation
-#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Compact)]
 pub struct BaseFeeParams {
     /// The base_fee_max_change_denominator from EIP-1559
     pub max_change_denominator: u64,
     /// The elasticity multiplier from EIP-1559
     pub elasticity_multiplier: u64,
+    /// The minimum base fee of a block, enforced by some L2s. Unset on Ethereum, where the base
+    /// fee can drop to zero.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_base_fee: Option<u64>,
 }
 
 impl BaseFeeParams {
@@ -511,6 +887,7 @@ impl BaseFeeParams {
         BaseFeeParams {
             max_change_denominator: EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
             elasticity_multiplier: EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            minimum_base_fee: None,
         }
     }
 
@@ -522,6 +899,7 @@ impl BaseFeeParams {
                 crate::constants::OP_GOERLI_EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
             elasticity_multiplier:
                 crate::constants::OP_GOERLI_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            minimum_base_fee: None,
         }
     }
 
@@ -533,6 +911,7 @@ impl BaseFeeParams {
                 crate::constants::OP_GOERLI_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON,
             elasticity_multiplier:
                 crate::constants::OP_GOERLI_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            minimum_base_fee: None,
         }
     }
 
@@ -544,6 +923,7 @@ impl BaseFeeParams {
                 crate::constants::OP_SEPOLIA_EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
             elasticity_multiplier:
                 crate::constants::OP_SEPOLIA_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            minimum_base_fee: None,
         }
     }
 
@@ -555,6 +935,7 @@ impl BaseFeeParams {
                 crate::constants::OP_SEPOLIA_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON,
             elasticity_multiplier:
                 crate::constants::OP_SEPOLIA_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            minimum_base_fee: None,
         }
     }
 
@@ -566,6 +947,7 @@ impl BaseFeeParams {
                 crate::constants::OP_MAINNET_EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
             elasticity_multiplier:
                 crate::constants::OP_MAINNET_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            minimum_base_fee: None,
         }
     }
 
@@ -577,8 +959,246 @@ impl BaseFeeParams {
                 crate::constants::OP_MAINNET_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON,
             elasticity_multiplier:
                 crate::constants::OP_MAINNET_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            minimum_base_fee: None,
         }
     }
+
+    /// Get the base fee parameters for Polygon PoS (post Delhi)
+    #[cfg(feature = "polygon")]
+    pub const fn polygon_delhi() -> BaseFeeParams {
+        BaseFeeParams {
+            max_change_denominator:
+                crate::constants::POLYGON_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_DELHI,
+            elasticity_multiplier: EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            minimum_base_fee: None,
+        }
+    }
+
+    /// Get the base fee parameters for Polygon PoS (post Bhilai)
+    #[cfg(feature = "polygon")]
+    pub const fn polygon_bhilai() -> BaseFeeParams {
+        BaseFeeParams {
+            max_change_denominator:
+                crate::constants::POLYGON_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_BHILAI,
+            elasticity_multiplier: EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            minimum_base_fee: None,
+        }
+    }
+
+    /// Sets the minimum base fee that the base fee of a block can't drop below.
+    pub const fn with_minimum_base_fee(mut self, minimum_base_fee: u64) -> BaseFeeParams {
+        self.minimum_base_fee = Some(minimum_base_fee);
+        self
+    }
+}
+
+/// A wrapper around [BlobParams] that allows for specifying constant or fork dependent blob
+/// parameters, see [EIP-7840](https://eips.ethereum.org/EIPS/eip-7840).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BlobScheduleKind {
+    /// Constant [BlobParams]; used for chains that never change their blob parameters
+    Constant(BlobParams),
+    /// Variable [BlobParams]; used for chains that change their blob parameters with a hardfork,
+    /// like Ethereum with Prague
+    Variable(ForkBlobParams),
+}
+
+impl BlobScheduleKind {
+    /// Parses the blob schedule from the genesis `config.blobSchedule` object, which maps
+    /// lowercase hardfork names to their [BlobParams].
+    ///
+    /// Returns `None` if the chain config has no blob schedule, and an error if the schedule is
+    /// malformed or names an unknown hardfork.
+    pub fn from_chain_config(config: &ChainConfig) -> Result<Option<Self>, GenesisConfigError> {
+        let params = genesis_fork_schedule(config, GENESIS_BLOB_SCHEDULE_KEY)?;
+        Ok(params.map(|params| BlobScheduleKind::Variable(ForkBlobParams(params))))
+    }
+}
+
+impl Default for BlobScheduleKind {
+    fn default() -> Self {
+        BlobScheduleKind::Constant(BlobParams::cancun())
+    }
+}
+
+impl From<BlobParams> for BlobScheduleKind {
+    fn from(params: BlobParams) -> Self {
+        BlobScheduleKind::Constant(params)
+    }
+}
+
+impl From<ForkBlobParams> for BlobScheduleKind {
+    fn from(params: ForkBlobParams) -> Self {
+        BlobScheduleKind::Variable(params)
+    }
+}
+
+/// A type alias to a vector of tuples of [Hardfork] and [BlobParams], sorted by [Hardfork]
+/// activation order.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ForkBlobParams(Vec<(Hardfork, BlobParams)>);
+
+impl From<Vec<(Hardfork, BlobParams)>> for ForkBlobParams {
+    fn from(params: Vec<(Hardfork, BlobParams)>) -> Self {
+        ForkBlobParams(params)
+    }
+}
+
+/// BlobParams contains the config parameters that control the blob gas of a block, as defined by
+/// the EIP-7840 `blobSchedule` entries.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BlobParams {
+    /// The target number of blobs per block
+    #[serde(rename = "target")]
+    pub target_blob_count: u64,
+    /// The maximum number of blobs per block
+    #[serde(rename = "max")]
+    pub max_blob_count: u64,
+    /// The update fraction of the blob base fee
+    pub base_fee_update_fraction: u128,
+}
+
+impl BlobParams {
+    /// Get the blob parameters introduced with [Hardfork::Cancun]
+    pub const fn cancun() -> BlobParams {
+        BlobParams {
+            target_blob_count: TARGET_BLOBS_PER_BLOCK,
+            max_blob_count: MAX_BLOBS_PER_BLOCK as u64,
+            base_fee_update_fraction: BLOB_GASPRICE_UPDATE_FRACTION,
+        }
+    }
+
+    /// Get the blob parameters introduced with [Hardfork::Prague] (EIP-7691)
+    pub const fn prague() -> BlobParams {
+        BlobParams { target_blob_count: 6, max_blob_count: 9, base_fee_update_fraction: 5_007_716 }
+    }
+
+    /// Get the blob parameters of Gnosis chains, which only allow up to two blobs per block
+    #[cfg(feature = "gnosis")]
+    pub const fn gnosis() -> BlobParams {
+        BlobParams { target_blob_count: 1, max_blob_count: 2, base_fee_update_fraction: 1_112_826 }
+    }
+
+    /// Returns the maximum blob gas a block may use.
+    pub const fn max_blob_gas_per_block(&self) -> u64 {
+        self.max_blob_count * DATA_GAS_PER_BLOB
+    }
+
+    /// Returns the blob gas a block targets.
+    pub const fn target_blob_gas_per_block(&self) -> u64 {
+        self.target_blob_count * DATA_GAS_PER_BLOB
+    }
+
+    /// Calculates the excess blob gas of a block from the excess blob gas and the blob gas used of
+    /// its parent, see [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844#header-extension).
+    pub const fn next_block_excess_blob_gas(
+        &self,
+        parent_excess_blob_gas: u64,
+        parent_blob_gas_used: u64,
+    ) -> u64 {
+        (parent_excess_blob_gas + parent_blob_gas_used)
+            .saturating_sub(self.target_blob_gas_per_block())
+    }
+
+    /// Calculates the blob fee of a block with the given excess blob gas.
+    pub fn calc_blob_fee(&self, excess_blob_gas: u64) -> u128 {
+        fake_exponential(MIN_BLOB_GASPRICE, excess_blob_gas, self.base_fee_update_fraction as u64)
+    }
+
+    /// Returns the blob gas fields of the EVM block environment of a block with the given excess
+    /// blob gas.
+    pub fn blob_excess_gas_and_price(&self, excess_blob_gas: u64) -> BlobExcessGasAndPrice {
+        BlobExcessGasAndPrice {
+            excess_blob_gas,
+            blob_gasprice: self.calc_blob_fee(excess_blob_gas),
+        }
+    }
+}
+
+/// A list of [Hardfork]s and the [GasLimitParams] that take effect with them, sorted by
+/// [Hardfork] activation order.
+///
+/// This is used by chains that change the gas limit of their blocks with a hardfork, as several
+/// rollups do. Before the first fork of the schedule is active, the gas limit is only bound by the
+/// usual rules of the parent gas limit.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasLimitSchedule(Vec<(Hardfork, GasLimitParams)>);
+
+impl GasLimitSchedule {
+    /// Reads the schedule from the `gasLimitSchedule` field of a genesis chain config, which maps
+    /// hardfork names to their [GasLimitParams], e.g.
+    /// `"gasLimitSchedule": { "london": { "target": 30000000, "cap": 60000000 } }`.
+    ///
+    /// Returns `None` if the chain config has no gas limit schedule, and an error if the schedule
+    /// is malformed or names an unknown hardfork.
+    pub fn from_chain_config(config: &ChainConfig) -> Result<Option<Self>, GenesisConfigError> {
+        Ok(genesis_fork_schedule(config, GENESIS_GAS_LIMIT_SCHEDULE_KEY)?.map(GasLimitSchedule))
+    }
+}
+
+impl From<Vec<(Hardfork, GasLimitParams)>> for GasLimitSchedule {
+    fn from(params: Vec<(Hardfork, GasLimitParams)>) -> Self {
+        GasLimitSchedule(params)
+    }
+}
+
+/// A list of [Hardfork]s and the chain-specific precompiles they enable, sorted by [Hardfork]
+/// activation order.
+///
+/// These come on top of the Ethereum precompiles, which are enabled by the regular hardforks of
+/// the chain, see [ChainSpec::active_precompiles].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrecompileSchedule(Vec<(Hardfork, Vec<Address>)>);
+
+impl PrecompileSchedule {
+    /// Reads the schedule from the `precompiles` field of a genesis chain config, which maps
+    /// hardfork names to the addresses of the precompiles they enable, e.g.
+    /// `"precompiles": { "cancun": ["0x0000000000000000000000000000000000000100"] }`.
+    ///
+    /// Returns `None` if the chain config has no additional precompiles, and an error if the
+    /// schedule is malformed or names an unknown hardfork.
+    pub fn from_chain_config(config: &ChainConfig) -> Result<Option<Self>, GenesisConfigError> {
+        Ok(genesis_fork_schedule(config, GENESIS_PRECOMPILES_KEY)?.map(PrecompileSchedule))
+    }
+}
+
+impl From<Vec<(Hardfork, Vec<Address>)>> for PrecompileSchedule {
+    fn from(precompiles: Vec<(Hardfork, Vec<Address>)>) -> Self {
+        PrecompileSchedule(precompiles)
+    }
+}
+
+/// GasLimitParams contains the gas limit target and cap that take effect with a hardfork.
+#[derive(Serialize, Deserialize, Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct GasLimitParams {
+    /// The gas limit that block producers move towards, within the allowed change per block
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<u64>,
+    /// The maximum gas limit of a block
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cap: Option<u64>,
+}
+
+impl GasLimitParams {
+    /// Returns the gas limit of a block built on top of a parent with the given gas limit.
+    ///
+    /// The gas limit moves towards the target by as much as a single block allows, and is then
+    /// limited by the cap.
+    pub fn next_block_gas_limit(&self, parent_gas_limit: u64) -> u64 {
+        // the gas limit of a block must change by less than 1/1024 of its parent's gas limit
+        let max_change = (parent_gas_limit / 1024).saturating_sub(1);
+        let gas_limit = match self.target {
+            Some(target) if target > parent_gas_limit => {
+                parent_gas_limit + max_change.min(target - parent_gas_limit)
+            }
+            Some(target) => parent_gas_limit - max_change.min(parent_gas_limit - target),
+            None => parent_gas_limit,
+        };
+        self.cap.map_or(gas_limit, |cap| gas_limit.min(cap))
+    }
 }
 
 /// An Ethereum chain specification.
@@ -607,30 +1227,161 @@ pub struct ChainSpec {
     #[serde(skip, default)]
     pub paris_block_and_final_difficulty: Option<(u64, U256)>,
 
-    /// Timestamps of various hardforks
+    /// The active hard forks and their activation conditions.
     ///
-    /// This caches entries in `hardforks` map
-    #[serde(skip, default)]
-    pub fork_timestamps: ForkTimestamps,
+    /// The fork caches are computed from these, so they are only modified through
+    /// [ChainSpec::insert_hardfork] and friends, which invalidate the caches.
+    pub(crate) hardforks: BTreeMap<Hardfork, ForkCondition>,
 
-    /// The active hard forks and their activation conditions
-    pub hardforks: BTreeMap<Hardfork, ForkCondition>,
+    /// Chain specific hard forks that are not part of [Hardfork], and their activation conditions,
+    /// see [ChainSpec::set_custom_hardforks].
+    #[serde(default, skip_serializing_if = "ChainHardforks::is_empty")]
+    pub(crate) custom_hardforks: ChainHardforks,
 
-    /// The deposit contract deployed for PoS
+    /// The deposit contracts deployed for PoS, sorted by deployment block.
+    ///
+    /// Chains that migrated to a new deposit contract list all of them, see
+    /// [ChainSpec::deposit_contract_at].
     #[serde(skip, default)]
-    pub deposit_contract: Option<DepositContract>,
+    pub deposit_contracts: Vec<DepositContract>,
+
+    /// The consensus engine the chain runs before the merge, if any
+    #[serde(default)]
+    pub consensus: ConsensusConfig,
 
     /// The parameters that configure how a block's base fee is computed
     pub base_fee_params: BaseFeeParamsKind,
 
-    /// The delete limit for pruner, per block. In the actual pruner run it will be multiplied by
-    /// the amount of blocks between pruner runs to account for the difference in amount of new
-    /// data coming in.
+    /// The EIP-4844 blob parameters, configured per fork as in EIP-7840
+    #[serde(default)]
+    pub blob_params: BlobScheduleKind,
+
+    /// The gas limit targets and caps of the chain, configured per fork
+    #[serde(default)]
+    pub gas_limit_schedule: GasLimitSchedule,
+
+    /// The addresses of the protocol system contracts
+    #[serde(default)]
+    pub system_contracts: SystemContracts,
+
+    /// The chain-specific precompiles, configured per fork
+    #[serde(default)]
+    pub precompiles: PrecompileSchedule,
+
+    /// The contracts force-deployed at hardfork transitions, configured per fork
     #[serde(default)]
-    pub prune_delete_limit: usize,
+    pub state_migrations: StateMigrationSchedule,
+
+    /// The delete limits of the pruner and the history the chain requires to be retained, per
+    /// prune segment.
+    #[serde(default)]
+    pub prune_config: PruneConfig,
+
+    /// The block intervals for creating snapshots, per snapshot segment.
+    #[serde(default)]
+    pub snapshot_config: SnapshotConfig,
+
+    /// The source of the KZG trusted setup that blobs are verified with, the embedded setup of
+    /// the Ethereum KZG ceremony by default.
+    #[serde(default)]
+    pub kzg_settings: KzgSettingsSource,
+
+    /// The number of seconds the timestamp of a block may be ahead of the local clock before the
+    /// block is rejected.
+    #[serde(default = "default_max_future_timestamp_drift")]
+    pub max_future_timestamp_drift: u64,
+
+    /// The targeted number of seconds between two blocks, if the chain produces blocks at a fixed
+    /// interval.
+    #[serde(default)]
+    pub block_time: Option<u64>,
+
+    /// The maximum size of the code of a deployed contract, see
+    /// [EIP-170](https://eips.ethereum.org/EIPS/eip-170).
+    ///
+    /// The EVM limits the init code of a contract creation to twice this size, independent of
+    /// `max_init_code_size`.
+    #[serde(default = "default_max_code_size")]
+    pub max_code_size: usize,
+
+    /// The maximum size of the init code of a contract creation, enforced once
+    /// [Hardfork::Shanghai] is active, see [EIP-3860](https://eips.ethereum.org/EIPS/eip-3860).
+    #[serde(default = "default_max_init_code_size")]
+    pub max_init_code_size: usize,
+
+    /// The maximum size of an EIP-2718 encoded transaction, if the chain limits it.
+    ///
+    /// Ethereum has no such consensus limit, the transaction pool then falls back to its own
+    /// default.
+    #[serde(default)]
+    pub max_tx_size: Option<usize>,
+
+    /// The boot nodes of the chain, preferred over the built-in lists by [ChainSpec::bootnodes].
+    ///
+    /// Private networks can ship them with the spec file instead of passing them on the CLI.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bootnodes: Vec<BootNode>,
+
+    /// The peers of the chain the node always keeps connections to, in addition to the trusted
+    /// peers of the CLI.
+    #[serde(default, alias = "trustedPeers", skip_serializing_if = "Vec::is_empty")]
+    pub trusted_peers: Vec<NodeRecord>,
+
+    /// The rollup configuration of OP-stack chains
+    #[cfg(feature = "optimism")]
+    #[serde(default)]
+    pub rollup: Option<RollupConfig>,
+
+    /// The Bor consensus configuration of Polygon PoS chains
+    #[cfg(feature = "polygon")]
+    #[serde(default)]
+    pub bor: Option<BorConfig>,
+
+    /// The AuRa engine and fee collector configuration of Gnosis chains
+    #[cfg(feature = "gnosis")]
+    #[serde(default)]
+    pub gnosis: Option<GnosisConfig>,
+
+    /// The fork id transitions of the chain.
+    ///
+    /// This caches the entries in `hardforks` and is computed on first use, see
+    /// [ChainSpec::fork_id_table].
+    #[serde(skip, default)]
+    pub(crate) fork_id_table: OnceCell<ForkIdTable>,
+
+    /// The activation timestamps of the timestamp based hardforks.
+    ///
+    /// This caches the entries in `hardforks` and is computed on first use, see
+    /// [ChainSpec::fork_activations].
+    #[serde(skip, default)]
+    pub(crate) fork_activations: OnceCell<ForkActivationCache>,
+
+    /// The sealed header of the genesis block.
+    ///
+    /// This caches the header built from `genesis` and `hardforks`, whose state root is expensive
+    /// to compute for large allocations, see [ChainSpec::invalidate_fork_caches].
+    #[serde(skip, default)]
+    pub(crate) sealed_genesis_header: OnceCell<SealedHeader>,
+
+    /// The KZG trusted setup.
+    ///
+    /// This caches the setup loaded from `kzg_settings` on first use, see
+    /// [ChainSpec::kzg_settings].
+    #[cfg(feature = "c-kzg")]
+    #[serde(skip, default)]
+    pub(crate) kzg_settings_cache: OnceCell<Arc<KzgSettings>>,
+}
+
+const fn default_max_future_timestamp_drift() -> u64 {
+    ALLOWED_FUTURE_BLOCK_TIME_SECONDS
+}
 
-    /// The block interval for creating snapshots. Each snapshot will have that much blocks in it.
-    pub snapshot_block_interval: u64,
+const fn default_max_code_size() -> usize {
+    MAX_CODE_SIZE
+}
+
+const fn default_max_init_code_size() -> usize {
+    MAX_INIT_CODE_SIZE
 }
 
 impl Default for ChainSpec {
@@ -640,61 +1391,924 @@ impl Default for ChainSpec {
             genesis_hash: Default::default(),
             genesis: Default::default(),
             paris_block_and_final_difficulty: Default::default(),
-            fork_timestamps: Default::default(),
             hardforks: Default::default(),
-            deposit_contract: Default::default(),
+            custom_hardforks: Default::default(),
+            deposit_contracts: Default::default(),
+            consensus: Default::default(),
             base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-            prune_delete_limit: MAINNET.prune_delete_limit,
-            snapshot_block_interval: Default::default(),
+            blob_params: BlobScheduleKind::Constant(BlobParams::cancun()),
+            gas_limit_schedule: Default::default(),
+            system_contracts: Default::default(),
+            precompiles: Default::default(),
+            state_migrations: Default::default(),
+            prune_config: MAINNET.prune_config.clone(),
+            snapshot_config: Default::default(),
+            kzg_settings: Default::default(),
+            max_future_timestamp_drift: ALLOWED_FUTURE_BLOCK_TIME_SECONDS,
+            block_time: None,
+            max_code_size: MAX_CODE_SIZE,
+            max_init_code_size: MAX_INIT_CODE_SIZE,
+            max_tx_size: None,
+            bootnodes: Vec::new(),
+            trusted_peers: Vec::new(),
+            #[cfg(feature = "optimism")]
+            rollup: None,
+            #[cfg(feature = "polygon")]
+            bor: None,
+            #[cfg(feature = "gnosis")]
+            gnosis: None,
+            fork_id_table: OnceCell::new(),
+            fork_activations: OnceCell::new(),
+            sealed_genesis_header: OnceCell::new(),
+            #[cfg(feature = "c-kzg")]
+            kzg_settings_cache: OnceCell::new(),
         }
     }
 }
 
-impl ChainSpec {
-    /// Get information about the chain itself
-    pub fn chain(&self) -> Chain {
-        self.chain
-    }
+// The hardforks are scheduled in activation order, block based forks at increasing blocks, the
+// merge at a total difficulty and the later forks at increasing timestamps after the genesis.
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for ChainSpec {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let genesis = crate::genesis::arbitrary_genesis(u)?;
+
+        let mut hardforks = BTreeMap::new();
+        let mut block = 0u64;
+        let mut timestamp = genesis.timestamp;
+        for fork in ARBITRARY_HARDFORKS {
+            // the schedule may end at any fork
+            if !u.arbitrary::<bool>()? {
+                break
+            }
+            let condition = match fork {
+                Hardfork::Paris => {
+                    ForkCondition::TTD { fork_block: None, total_difficulty: u.arbitrary()? }
+                }
+                Hardfork::Shanghai | Hardfork::Cancun | Hardfork::Prague => {
+                    timestamp = timestamp.saturating_add(u.int_in_range(0..=1_000_000)?);
+                    ForkCondition::Timestamp(timestamp)
+                }
+                _ => {
+                    block = block.saturating_add(u.int_in_range(0..=1_000_000)?);
+                    ForkCondition::Block(block)
+                }
+            };
+            hardforks.insert(fork, condition);
+        }
 
-    /// Returns `true` if this chain contains Optimism configuration.
-    #[inline]
-    pub fn is_optimism(&self) -> bool {
-        self.chain.is_optimism()
+        Ok(ChainSpec {
+            chain: Chain::from_id(genesis.config.chain_id),
+            genesis,
+            hardforks,
+            base_fee_params: u.arbitrary()?,
+            ..Default::default()
+        })
     }
+}
 
-    /// Get the genesis block specification.
-    ///
-    /// To get the header for the genesis block, use [`Self::genesis_header`] instead.
-    pub fn genesis(&self) -> &Genesis {
-        &self.genesis
+#[cfg(any(test, feature = "arbitrary"))]
+impl proptest::arbitrary::Arbitrary for ChainSpec {
+    type Parameters = ();
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::{any, Strategy};
+
+        proptest::collection::vec(any::<u8>(), 0..1024)
+            .prop_map(|bytes| {
+                arbitrary::Unstructured::new(&bytes)
+                    .arbitrary::<ChainSpec>()
+                    .expect("arbitrary chain spec from any bytes")
+            })
+            .boxed()
     }
 
-    /// Get the header for the genesis block.
-    pub fn genesis_header(&self) -> Header {
-        // If London is activated at genesis, we set the initial base fee as per EIP-1559.
-        let base_fee_per_gas = self.initial_base_fee();
+    type Strategy = proptest::strategy::BoxedStrategy<ChainSpec>;
+}
 
-        // If shanghai is activated, initialize the header with an empty withdrawals hash, and
-        // empty withdrawals list.
-        let withdrawals_root =
-            (self.fork(Hardfork::Shanghai).active_at_timestamp(self.genesis.timestamp))
-                .then_some(EMPTY_WITHDRAWALS);
+/// The database representation of a [ChainSpec].
+///
+/// Every field of the spec is stored explicitly, the fork caches are recomputed on first use.
+/// Hardforks are stored by name, so the fork schedules can be read by builds that know a different
+/// set of [Hardfork]s.
+///
+/// Of the genesis chain config, only the fields the spec reads at runtime are stored: the chain
+/// id, `terminalTotalDifficultyPassed` and the `difficultyBombDelays`. The hardforks, schedules
+/// and engine configured in it are stored in their parsed form, see [ChainSpec::to_geth_genesis]
+/// for restoring a full chain config.
+#[derive(Debug, Compact)]
+struct StoredChainSpec {
+    chain: u64,
+    genesis_hash: Option<B256>,
+    paris_block: Option<BlockNumber>,
+    paris_final_difficulty: Option<U256>,
+    hardforks: Vec<StoredFork>,
+    custom_hardforks: Vec<StoredFork>,
+    deposit_contracts: Vec<DepositContract>,
+    consensus_engine: u64,
+    consensus_period: u64,
+    consensus_epoch: u64,
+    base_fee_params: Vec<StoredBaseFeeParams>,
+    blob_params: Vec<StoredBlobParams>,
+    gas_limit_schedule: Vec<StoredGasLimitParams>,
+    beacon_roots_contract: Address,
+    history_storage_contract: Address,
+    withdrawal_requests_contract: Address,
+    consolidation_requests_contract: Address,
+    precompiles: Vec<StoredPrecompiles>,
+    state_migrations: Vec<StoredStateMigrations>,
+    prune_delete_limit: u64,
+    prune_segment_delete_limits: Vec<StoredPruneSegmentValue>,
+    prune_min_retained_blocks: Vec<StoredPruneSegmentValue>,
+    snapshot_headers_block_interval: u64,
+    snapshot_transactions_block_interval: u64,
+    snapshot_receipts_block_interval: u64,
+    snapshot_disabled_segments: Vec<Bytes>,
+    kzg_settings_file: Option<Bytes>,
+    max_future_timestamp_drift: u64,
+    block_time: Option<u64>,
+    max_code_size: u64,
+    max_init_code_size: u64,
+    max_tx_size: Option<u64>,
+    bootnodes: Vec<Bytes>,
+    trusted_peers: Vec<Bytes>,
+    rollup: Option<StoredRollupConfig>,
+    bor: Option<StoredBorConfig>,
+    gnosis: Option<StoredGnosisConfig>,
+    genesis: StoredGenesis,
+}
 
-        // If Cancun is activated at genesis, we set:
-        // * parent beacon block root to 0x0
-        // * blob gas used to provided genesis or 0x0
-        // * excess blob gas to provided genesis or 0x0
-        let (parent_beacon_block_root, blob_gas_used, excess_blob_gas) =
-            if self.fork(Hardfork::Cancun).active_at_timestamp(self.genesis.timestamp) {
-                let blob_gas_used = self.genesis.blob_gas_used.unwrap_or(0);
-                let excess_blob_gas = self.genesis.excess_blob_gas.unwrap_or(0);
-                (Some(B256::ZERO), Some(blob_gas_used), Some(excess_blob_gas))
-            } else {
-                (None, None, None)
-            };
+/// The values of [ConsensusConfig] in [StoredChainSpec::consensus_engine].
+const STORED_CONSENSUS_NONE: u64 = 0;
+const STORED_CONSENSUS_ETHASH: u64 = 1;
+const STORED_CONSENSUS_CLIQUE: u64 = 2;
+#[cfg(feature = "bsc")]
+const STORED_CONSENSUS_PARLIA: u64 = 3;
+
+/// A hardfork or chain specific fork, and its activation condition.
+#[derive(Debug, Compact)]
+struct StoredFork {
+    condition: ForkCondition,
+    name: Bytes,
+}
 
-        Header {
-            parent_hash: B256::ZERO,
+/// The [BaseFeeParams] that take effect with a fork. Constant parameters have no fork name.
+#[derive(Debug, Compact)]
+struct StoredBaseFeeParams {
+    max_change_denominator: u64,
+    elasticity_multiplier: u64,
+    minimum_base_fee: Option<u64>,
+    fork: Bytes,
+}
+
+/// The [BlobParams] that take effect with a fork. Constant parameters have no fork name.
+#[derive(Debug, Compact)]
+struct StoredBlobParams {
+    target_blob_count: u64,
+    max_blob_count: u64,
+    base_fee_update_fraction: u128,
+    fork: Bytes,
+}
+
+/// The [GasLimitParams] that take effect with a fork.
+#[derive(Debug, Compact)]
+struct StoredGasLimitParams {
+    target: Option<u64>,
+    cap: Option<u64>,
+    fork: Bytes,
+}
+
+/// The precompiles a fork enables.
+#[derive(Debug, Compact)]
+struct StoredPrecompiles {
+    addresses: Vec<Address>,
+    fork: Bytes,
+}
+
+/// The contracts force-deployed at the transition of a fork.
+#[derive(Debug, Compact)]
+struct StoredStateMigrations {
+    migrations: Vec<StoredStateMigration>,
+    fork: Bytes,
+}
+
+/// A [ForkStateMigration].
+#[derive(Debug, Compact)]
+struct StoredStateMigration {
+    address: Address,
+    code: Bytes,
+}
+
+/// A per segment value of the [PruneConfig].
+#[derive(Debug, Compact)]
+struct StoredPruneSegmentValue {
+    value: u64,
+    segment: PruneSegment,
+}
+
+/// The rollup config of OP-stack chains.
+#[derive(Debug, Compact)]
+struct StoredRollupConfig {
+    l1_chain_id: u64,
+    batch_inbox_address: Address,
+    deposit_contract_address: Address,
+    sequencer_fee_vault: Address,
+    channel_timeout: u64,
+}
+
+/// The Bor config of Polygon PoS chains.
+#[derive(Debug, Compact)]
+struct StoredBorConfig {
+    period: Vec<StoredBlockValue>,
+    producer_delay: Vec<StoredBlockValue>,
+    sprint: Vec<StoredBlockValue>,
+    backup_multiplier: Vec<StoredBlockValue>,
+    validator_contract: Address,
+    state_receiver_contract: Address,
+    burnt_contract: Vec<StoredBlockAddress>,
+}
+
+/// The Gnosis config of Gnosis chains.
+#[derive(Debug, Compact)]
+struct StoredGnosisConfig {
+    step_duration: u64,
+    validator_contract: Address,
+    block_reward_contract: Address,
+    randomness_contract: Address,
+    fee_collector: Address,
+}
+
+/// A value that applies from a block on.
+#[derive(Debug, Compact)]
+struct StoredBlockValue {
+    block: BlockNumber,
+    value: u64,
+}
+
+/// An address that applies from a block on.
+#[derive(Debug, Compact)]
+struct StoredBlockAddress {
+    block: BlockNumber,
+    address: Address,
+}
+
+/// The genesis of a [ChainSpec], see [StoredChainSpec] for the stored fields of its chain config.
+#[derive(Debug, Compact)]
+struct StoredGenesis {
+    terminal_total_difficulty_passed: bool,
+    bomb_delays: Vec<StoredBlockValue>,
+    nonce: u64,
+    timestamp: u64,
+    gas_limit: u64,
+    difficulty: U256,
+    mix_hash: B256,
+    coinbase: Address,
+    base_fee_per_gas: Option<u64>,
+    excess_blob_gas: Option<u64>,
+    blob_gas_used: Option<u64>,
+    alloc: Vec<StoredGenesisAccount>,
+    extra_data: Bytes,
+}
+
+/// An account of the genesis allocation.
+#[derive(Debug, Compact)]
+struct StoredGenesisAccount {
+    address: Address,
+    nonce: Option<u64>,
+    balance: U256,
+    storage: Option<Vec<StoredStorageSlot>>,
+    code: Option<Bytes>,
+}
+
+/// A storage slot of a genesis account.
+#[derive(Debug, Compact)]
+struct StoredStorageSlot {
+    key: B256,
+    value: B256,
+}
+
+/// Returns the stored form of a name, e.g. of a fork.
+fn stored_name(name: &str) -> Bytes {
+    name.as_bytes().to_vec().into()
+}
+
+/// Parses a value that was stored by its string representation.
+///
+/// Returns `None` for values this build doesn't know, e.g. hardforks of disabled chain features.
+fn parse_stored<T: core::str::FromStr>(stored: &[u8]) -> Option<T> {
+    core::str::from_utf8(stored).ok()?.parse().ok()
+}
+
+impl Compact for ChainSpec {
+    fn to_compact<B>(self, buf: &mut B) -> usize
+    where
+        B: bytes::BufMut + AsMut<[u8]>,
+    {
+        let fork = |hardfork: Hardfork| stored_name(&hardfork.to_string());
+        let base_fee_params = match self.base_fee_params {
+            BaseFeeParamsKind::Constant(params) => vec![(Bytes::new(), params)],
+            BaseFeeParamsKind::Variable(params) => {
+                params.0.into_iter().map(|(hardfork, params)| (fork(hardfork), params)).collect()
+            }
+        };
+        let blob_params = match self.blob_params {
+            BlobScheduleKind::Constant(params) => vec![(Bytes::new(), params)],
+            BlobScheduleKind::Variable(params) => {
+                params.0.into_iter().map(|(hardfork, params)| (fork(hardfork), params)).collect()
+            }
+        };
+        let (consensus_engine, consensus_period, consensus_epoch) = match self.consensus {
+            ConsensusConfig::None => (STORED_CONSENSUS_NONE, 0, 0),
+            ConsensusConfig::Ethash => (STORED_CONSENSUS_ETHASH, 0, 0),
+            ConsensusConfig::Clique { period, epoch } => (STORED_CONSENSUS_CLIQUE, period, epoch),
+            #[cfg(feature = "bsc")]
+            ConsensusConfig::Parlia { period, epoch } => (STORED_CONSENSUS_PARLIA, period, epoch),
+        };
+        let prune_segment_values = |values: BTreeMap<PruneSegment, u64>| {
+            values
+                .into_iter()
+                .map(|(segment, value)| StoredPruneSegmentValue { value, segment })
+                .collect::<Vec<_>>()
+        };
+        let block_values = |values: BTreeMap<BlockNumber, u64>| {
+            values
+                .into_iter()
+                .map(|(block, value)| StoredBlockValue { block, value })
+                .collect::<Vec<_>>()
+        };
+
+        #[cfg(feature = "optimism")]
+        let rollup = self.rollup.map(|rollup| StoredRollupConfig {
+            l1_chain_id: rollup.l1_chain_id,
+            batch_inbox_address: rollup.batch_inbox_address,
+            deposit_contract_address: rollup.deposit_contract_address,
+            sequencer_fee_vault: rollup.sequencer_fee_vault,
+            channel_timeout: rollup.channel_timeout,
+        });
+        #[cfg(not(feature = "optimism"))]
+        let rollup = None;
+        #[cfg(feature = "polygon")]
+        let bor = self.bor.map(|bor| StoredBorConfig {
+            period: block_values(bor.period),
+            producer_delay: block_values(bor.producer_delay),
+            sprint: block_values(bor.sprint),
+            backup_multiplier: block_values(bor.backup_multiplier),
+            validator_contract: bor.validator_contract,
+            state_receiver_contract: bor.state_receiver_contract,
+            burnt_contract: bor
+                .burnt_contract
+                .into_iter()
+                .map(|(block, address)| StoredBlockAddress { block, address })
+                .collect(),
+        });
+        #[cfg(not(feature = "polygon"))]
+        let bor = None;
+        #[cfg(feature = "gnosis")]
+        let gnosis = self.gnosis.map(|gnosis| StoredGnosisConfig {
+            step_duration: gnosis.aura.step_duration,
+            validator_contract: gnosis.aura.validator_contract,
+            block_reward_contract: gnosis.aura.block_reward_contract,
+            randomness_contract: gnosis.aura.randomness_contract,
+            fee_collector: gnosis.fee_collector,
+        });
+        #[cfg(not(feature = "gnosis"))]
+        let gnosis = None;
+
+        let bomb_delays: BTreeMap<BlockNumber, u64> = self
+            .genesis
+            .config
+            .extra_fields
+            .get(GENESIS_BOMB_DELAYS_KEY)
+            .and_then(|delays| delays.as_object())
+            .map(|delays| {
+                delays
+                    .iter()
+                    .filter_map(|(block, delay)| Some((block.parse().ok()?, delay.as_u64()?)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut alloc = self
+            .genesis
+            .alloc
+            .into_iter()
+            .map(|(address, account)| StoredGenesisAccount {
+                address,
+                nonce: account.nonce,
+                balance: account.balance,
+                storage: account.storage.map(|storage| {
+                    let mut storage = storage
+                        .into_iter()
+                        .map(|(key, value)| StoredStorageSlot { key, value })
+                        .collect::<Vec<_>>();
+                    storage.sort_unstable_by_key(|slot| slot.key);
+                    storage
+                }),
+                code: account.code,
+            })
+            .collect::<Vec<_>>();
+        alloc.sort_unstable_by_key(|account| account.address);
+
+        StoredChainSpec {
+            chain: self.chain.id(),
+            genesis_hash: self.genesis_hash,
+            paris_block: self.paris_block_and_final_difficulty.map(|(block, _)| block),
+            paris_final_difficulty: self.paris_block_and_final_difficulty.map(|(_, ttd)| ttd),
+            hardforks: self
+                .hardforks
+                .into_iter()
+                .map(|(hardfork, condition)| StoredFork { condition, name: fork(hardfork) })
+                .collect(),
+            custom_hardforks: self
+                .custom_hardforks
+                .forks_iter()
+                .map(|(fork, condition)| StoredFork { condition, name: stored_name(fork.name()) })
+                .collect(),
+            deposit_contracts: self.deposit_contracts,
+            consensus_engine,
+            consensus_period,
+            consensus_epoch,
+            base_fee_params: base_fee_params
+                .into_iter()
+                .map(|(fork, params)| StoredBaseFeeParams {
+                    max_change_denominator: params.max_change_denominator,
+                    elasticity_multiplier: params.elasticity_multiplier,
+                    minimum_base_fee: params.minimum_base_fee,
+                    fork,
+                })
+                .collect(),
+            blob_params: blob_params
+                .into_iter()
+                .map(|(fork, params)| StoredBlobParams {
+                    target_blob_count: params.target_blob_count,
+                    max_blob_count: params.max_blob_count,
+                    base_fee_update_fraction: params.base_fee_update_fraction,
+                    fork,
+                })
+                .collect(),
+            gas_limit_schedule: self
+                .gas_limit_schedule
+                .0
+                .into_iter()
+                .map(|(hardfork, params)| StoredGasLimitParams {
+                    target: params.target,
+                    cap: params.cap,
+                    fork: fork(hardfork),
+                })
+                .collect(),
+            beacon_roots_contract: self.system_contracts.beacon_roots,
+            history_storage_contract: self.system_contracts.history_storage,
+            withdrawal_requests_contract: self.system_contracts.withdrawal_requests,
+            consolidation_requests_contract: self.system_contracts.consolidation_requests,
+            precompiles: self
+                .precompiles
+                .0
+                .into_iter()
+                .map(|(hardfork, addresses)| StoredPrecompiles { addresses, fork: fork(hardfork) })
+                .collect(),
+            state_migrations: self
+                .state_migrations
+                .iter()
+                .map(|(hardfork, migrations)| StoredStateMigrations {
+                    migrations: migrations
+                        .iter()
+                        .map(|migration| StoredStateMigration {
+                            address: migration.address,
+                            code: migration.code.clone(),
+                        })
+                        .collect(),
+                    fork: fork(hardfork),
+                })
+                .collect(),
+            prune_delete_limit: self.prune_config.delete_limit as u64,
+            prune_segment_delete_limits: prune_segment_values(
+                self.prune_config
+                    .segment_delete_limits
+                    .into_iter()
+                    .map(|(segment, limit)| (segment, limit as u64))
+                    .collect(),
+            ),
+            prune_min_retained_blocks: prune_segment_values(self.prune_config.min_retained_blocks),
+            snapshot_headers_block_interval: self.snapshot_config.headers_block_interval,
+            snapshot_transactions_block_interval: self.snapshot_config.transactions_block_interval,
+            snapshot_receipts_block_interval: self.snapshot_config.receipts_block_interval,
+            snapshot_disabled_segments: self
+                .snapshot_config
+                .disabled_segments
+                .iter()
+                .map(|segment| stored_name(segment.as_ref()))
+                .collect(),
+            kzg_settings_file: match self.kzg_settings {
+                KzgSettingsSource::Embedded => None,
+                KzgSettingsSource::File(path) => {
+                    Some(path.to_string_lossy().into_owned().into_bytes().into())
+                }
+            },
+            max_future_timestamp_drift: self.max_future_timestamp_drift,
+            block_time: self.block_time,
+            max_code_size: self.max_code_size as u64,
+            max_init_code_size: self.max_init_code_size as u64,
+            max_tx_size: self.max_tx_size.map(|size| size as u64),
+            bootnodes: self
+                .bootnodes
+                .iter()
+                .map(|node| node.to_string().into_bytes().into())
+                .collect(),
+            trusted_peers: self
+                .trusted_peers
+                .iter()
+                .map(|peer| peer.to_string().into_bytes().into())
+                .collect(),
+            rollup,
+            bor,
+            gnosis,
+            genesis: StoredGenesis {
+                terminal_total_difficulty_passed: self
+                    .genesis
+                    .config
+                    .terminal_total_difficulty_passed,
+                bomb_delays: block_values(bomb_delays),
+                nonce: self.genesis.nonce,
+                timestamp: self.genesis.timestamp,
+                gas_limit: self.genesis.gas_limit,
+                difficulty: self.genesis.difficulty,
+                mix_hash: self.genesis.mix_hash,
+                coinbase: self.genesis.coinbase,
+                base_fee_per_gas: self.genesis.base_fee_per_gas,
+                excess_blob_gas: self.genesis.excess_blob_gas,
+                blob_gas_used: self.genesis.blob_gas_used,
+                alloc,
+                extra_data: self.genesis.extra_data,
+            },
+        }
+        .to_compact(buf)
+    }
+
+    fn from_compact(buf: &[u8], len: usize) -> (Self, &[u8]) {
+        let (stored, buf) = StoredChainSpec::from_compact(buf, len);
+        let fork = |name: &Bytes| parse_stored::<Hardfork>(name);
+
+        // forks this build doesn't know are kept as chain specific forks
+        let mut hardforks = BTreeMap::new();
+        let mut custom_hardforks = ChainHardforks::default();
+        for entry in stored.hardforks {
+            match fork(&entry.name) {
+                Some(hardfork) => {
+                    hardforks.insert(hardfork, entry.condition);
+                }
+                None => custom_hardforks.insert(
+                    GenesisHardfork::new(String::from_utf8_lossy(&entry.name)),
+                    entry.condition,
+                ),
+            }
+        }
+        for entry in stored.custom_hardforks {
+            custom_hardforks.insert(
+                GenesisHardfork::new(String::from_utf8_lossy(&entry.name)),
+                entry.condition,
+            );
+        }
+
+        let mut base_fee_params = stored.base_fee_params.into_iter().map(|stored| {
+            let params = BaseFeeParams {
+                max_change_denominator: stored.max_change_denominator,
+                elasticity_multiplier: stored.elasticity_multiplier,
+                minimum_base_fee: stored.minimum_base_fee,
+            };
+            (stored.fork, params)
+        });
+        let base_fee_params = match base_fee_params.next() {
+            Some((name, params)) if name.is_empty() => BaseFeeParamsKind::Constant(params),
+            first => BaseFeeParamsKind::Variable(ForkBaseFeeParams(
+                first
+                    .into_iter()
+                    .chain(base_fee_params)
+                    .filter_map(|(name, params)| Some((fork(&name)?, params)))
+                    .collect(),
+            )),
+        };
+        let mut blob_params = stored.blob_params.into_iter().map(|stored| {
+            let params = BlobParams {
+                target_blob_count: stored.target_blob_count,
+                max_blob_count: stored.max_blob_count,
+                base_fee_update_fraction: stored.base_fee_update_fraction,
+            };
+            (stored.fork, params)
+        });
+        let blob_params = match blob_params.next() {
+            Some((name, params)) if name.is_empty() => BlobScheduleKind::Constant(params),
+            first => BlobScheduleKind::Variable(ForkBlobParams(
+                first
+                    .into_iter()
+                    .chain(blob_params)
+                    .filter_map(|(name, params)| Some((fork(&name)?, params)))
+                    .collect(),
+            )),
+        };
+        let consensus = match stored.consensus_engine {
+            STORED_CONSENSUS_NONE => ConsensusConfig::None,
+            STORED_CONSENSUS_ETHASH => ConsensusConfig::Ethash,
+            STORED_CONSENSUS_CLIQUE => ConsensusConfig::Clique {
+                period: stored.consensus_period,
+                epoch: stored.consensus_epoch,
+            },
+            #[cfg(feature = "bsc")]
+            STORED_CONSENSUS_PARLIA => ConsensusConfig::Parlia {
+                period: stored.consensus_period,
+                epoch: stored.consensus_epoch,
+            },
+            _ => unreachable!("Junk data in database: unknown ConsensusConfig variant"),
+        };
+        let prune_segment_values = |values: Vec<StoredPruneSegmentValue>| {
+            values
+                .into_iter()
+                .map(|stored| (stored.segment, stored.value))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let block_values = |values: Vec<StoredBlockValue>| {
+            values
+                .into_iter()
+                .map(|stored| (stored.block, stored.value))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let mut genesis = Genesis {
+            config: ChainConfig {
+                chain_id: stored.chain,
+                terminal_total_difficulty_passed: stored.genesis.terminal_total_difficulty_passed,
+                ..Default::default()
+            },
+            nonce: stored.genesis.nonce,
+            timestamp: stored.genesis.timestamp,
+            extra_data: stored.genesis.extra_data,
+            gas_limit: stored.genesis.gas_limit,
+            difficulty: stored.genesis.difficulty,
+            mix_hash: stored.genesis.mix_hash,
+            coinbase: stored.genesis.coinbase,
+            alloc: stored
+                .genesis
+                .alloc
+                .into_iter()
+                .map(|stored| {
+                    let account = GenesisAccount {
+                        nonce: stored.nonce,
+                        balance: stored.balance,
+                        code: stored.code,
+                        storage: stored.storage.map(|storage| {
+                            storage.into_iter().map(|slot| (slot.key, slot.value)).collect()
+                        }),
+                        ..Default::default()
+                    };
+                    (stored.address, account)
+                })
+                .collect(),
+            base_fee_per_gas: stored.genesis.base_fee_per_gas,
+            excess_blob_gas: stored.genesis.excess_blob_gas,
+            blob_gas_used: stored.genesis.blob_gas_used,
+            ..Default::default()
+        };
+        if !stored.genesis.bomb_delays.is_empty() {
+            let delays = block_values(stored.genesis.bomb_delays)
+                .into_iter()
+                .map(|(block, delay)| (block.to_string(), delay.into()))
+                .collect::<serde_json::Map<_, _>>();
+            genesis.config.extra_fields.insert(GENESIS_BOMB_DELAYS_KEY.to_string(), delays.into());
+        }
+
+        let spec = ChainSpec {
+            chain: Chain::from_id(stored.chain),
+            genesis_hash: stored.genesis_hash,
+            genesis,
+            paris_block_and_final_difficulty: stored.paris_block.zip(stored.paris_final_difficulty),
+            hardforks,
+            custom_hardforks,
+            deposit_contracts: stored.deposit_contracts,
+            consensus,
+            base_fee_params,
+            blob_params,
+            gas_limit_schedule: GasLimitSchedule(
+                stored
+                    .gas_limit_schedule
+                    .into_iter()
+                    .filter_map(|stored| {
+                        let params = GasLimitParams { target: stored.target, cap: stored.cap };
+                        Some((fork(&stored.fork)?, params))
+                    })
+                    .collect(),
+            ),
+            system_contracts: SystemContracts {
+                beacon_roots: stored.beacon_roots_contract,
+                history_storage: stored.history_storage_contract,
+                withdrawal_requests: stored.withdrawal_requests_contract,
+                consolidation_requests: stored.consolidation_requests_contract,
+            },
+            precompiles: PrecompileSchedule(
+                stored
+                    .precompiles
+                    .into_iter()
+                    .filter_map(|stored| Some((fork(&stored.fork)?, stored.addresses)))
+                    .collect(),
+            ),
+            state_migrations: stored
+                .state_migrations
+                .into_iter()
+                .filter_map(|stored| {
+                    let migrations = stored
+                        .migrations
+                        .into_iter()
+                        .map(|migration| ForkStateMigration::new(migration.address, migration.code))
+                        .collect::<Vec<_>>();
+                    Some((fork(&stored.fork)?, migrations))
+                })
+                .collect::<Vec<_>>()
+                .into(),
+            prune_config: PruneConfig {
+                delete_limit: stored.prune_delete_limit as usize,
+                segment_delete_limits: prune_segment_values(stored.prune_segment_delete_limits)
+                    .into_iter()
+                    .map(|(segment, limit)| (segment, limit as usize))
+                    .collect(),
+                min_retained_blocks: prune_segment_values(stored.prune_min_retained_blocks),
+            },
+            snapshot_config: SnapshotConfig {
+                headers_block_interval: stored.snapshot_headers_block_interval,
+                transactions_block_interval: stored.snapshot_transactions_block_interval,
+                receipts_block_interval: stored.snapshot_receipts_block_interval,
+                disabled_segments: stored
+                    .snapshot_disabled_segments
+                    .iter()
+                    .filter_map(|segment| parse_stored(segment))
+                    .collect(),
+            },
+            kzg_settings: match stored.kzg_settings_file {
+                Some(path) => {
+                    KzgSettingsSource::File(String::from_utf8_lossy(&path).into_owned().into())
+                }
+                None => KzgSettingsSource::Embedded,
+            },
+            max_future_timestamp_drift: stored.max_future_timestamp_drift,
+            block_time: stored.block_time,
+            max_code_size: stored.max_code_size as usize,
+            max_init_code_size: stored.max_init_code_size as usize,
+            max_tx_size: stored.max_tx_size.map(|size| size as usize),
+            bootnodes: stored.bootnodes.iter().filter_map(|node| parse_stored(node)).collect(),
+            trusted_peers: stored
+                .trusted_peers
+                .iter()
+                .filter_map(|peer| parse_stored(peer))
+                .collect(),
+            #[cfg(feature = "optimism")]
+            rollup: stored.rollup.map(|rollup| RollupConfig {
+                l1_chain_id: rollup.l1_chain_id,
+                batch_inbox_address: rollup.batch_inbox_address,
+                deposit_contract_address: rollup.deposit_contract_address,
+                sequencer_fee_vault: rollup.sequencer_fee_vault,
+                channel_timeout: rollup.channel_timeout,
+            }),
+            #[cfg(feature = "polygon")]
+            bor: stored.bor.map(|bor| BorConfig {
+                period: block_values(bor.period),
+                producer_delay: block_values(bor.producer_delay),
+                sprint: block_values(bor.sprint),
+                backup_multiplier: block_values(bor.backup_multiplier),
+                validator_contract: bor.validator_contract,
+                state_receiver_contract: bor.state_receiver_contract,
+                burnt_contract: bor
+                    .burnt_contract
+                    .into_iter()
+                    .map(|stored| (stored.block, stored.address))
+                    .collect(),
+            }),
+            #[cfg(feature = "gnosis")]
+            gnosis: stored.gnosis.map(|gnosis| GnosisConfig {
+                aura: AuraConfig {
+                    step_duration: gnosis.step_duration,
+                    validator_contract: gnosis.validator_contract,
+                    block_reward_contract: gnosis.block_reward_contract,
+                    randomness_contract: gnosis.randomness_contract,
+                },
+                fee_collector: gnosis.fee_collector,
+            }),
+            ..Default::default()
+        };
+        (spec, buf)
+    }
+}
+
+impl ChainSpec {
+    /// Get information about the chain itself
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+
+    /// Returns `true` if this chain contains Optimism configuration.
+    #[inline]
+    pub fn is_optimism(&self) -> bool {
+        self.chain.is_optimism()
+    }
+
+    /// Returns the consensus engine configured for this chain.
+    #[inline]
+    pub fn consensus(&self) -> ConsensusConfig {
+        self.consensus
+    }
+
+    /// Returns `true` if this chain is configured to use Ethash.
+    #[inline]
+    pub fn is_ethash(&self) -> bool {
+        matches!(self.consensus, ConsensusConfig::Ethash)
+    }
+
+    /// Returns `true` if this chain is configured to use Clique.
+    #[inline]
+    pub fn is_clique(&self) -> bool {
+        matches!(self.consensus, ConsensusConfig::Clique { .. })
+    }
+
+    /// Returns the number of seconds between blocks if this chain uses Clique.
+    #[inline]
+    pub fn clique_period(&self) -> Option<u64> {
+        match self.consensus {
+            ConsensusConfig::Clique { period, .. } => Some(period),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of blocks after which the Clique votes are reset, if this chain uses
+    /// Clique.
+    #[inline]
+    pub fn clique_epoch(&self) -> Option<u64> {
+        match self.consensus {
+            ConsensusConfig::Clique { epoch, .. } => Some(epoch),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this chain uses Parlia.
+    #[cfg(feature = "bsc")]
+    #[inline]
+    pub fn is_parlia(&self) -> bool {
+        matches!(self.consensus, ConsensusConfig::Parlia { .. })
+    }
+
+    /// Returns the number of blocks after which the validator set is updated, if this chain uses
+    /// Parlia.
+    #[cfg(feature = "bsc")]
+    #[inline]
+    pub fn parlia_epoch(&self) -> Option<u64> {
+        match self.consensus {
+            ConsensusConfig::Parlia { epoch, .. } => Some(epoch),
+            _ => None,
+        }
+    }
+
+    /// Get the genesis block specification.
+    ///
+    /// To get the header for the genesis block, use [`Self::genesis_header`] instead.
+    pub fn genesis(&self) -> &Genesis {
+        &self.genesis
+    }
+
+    /// Get the header for the genesis block.
+    ///
+    /// The header is computed on first use.
+    pub fn genesis_header(&self) -> Header {
+        self.cached_genesis_header().header().clone()
+    }
+
+    /// Returns the sealed genesis header, computing it on first use.
+    fn cached_genesis_header(&self) -> &SealedHeader {
+        self.sealed_genesis_header.get_or_init(|| {
+            let header = self.build_genesis_header();
+            let hash = self.genesis_hash.unwrap_or_else(|| header.hash_slow());
+            SealedHeader::new(header, hash)
+        })
+    }
+
+    /// Builds the header of the genesis block from the genesis and the hardforks active at it.
+    fn build_genesis_header(&self) -> Header {
+        // If London is activated at genesis, we set the initial base fee as per EIP-1559.
+        let base_fee_per_gas = self.initial_base_fee();
+
+        // If shanghai is activated, initialize the header with an empty withdrawals hash, and
+        // empty withdrawals list.
+        let withdrawals_root =
+            (self.fork(Hardfork::Shanghai).active_at_timestamp(self.genesis.timestamp))
+                .then_some(EMPTY_WITHDRAWALS);
+
+        // If Cancun is activated at genesis, we set:
+        // * parent beacon block root to 0x0
+        // * blob gas used to provided genesis or 0x0
+        // * excess blob gas to provided genesis or 0x0
+        let (parent_beacon_block_root, blob_gas_used, excess_blob_gas) =
+            if self.fork(Hardfork::Cancun).active_at_timestamp(self.genesis.timestamp) {
+                let blob_gas_used = self.genesis.blob_gas_used.unwrap_or(0);
+                let excess_blob_gas = self.genesis.excess_blob_gas.unwrap_or(0);
+                (Some(B256::ZERO), Some(blob_gas_used), Some(excess_blob_gas))
+            } else {
+                (None, None, None)
+            };
+
+        Header {
+            parent_hash: B256::ZERO,
             number: 0,
             transactions_root: EMPTY_TRANSACTIONS,
             ommers_hash: EMPTY_OMMER_ROOT_HASH,
@@ -719,7 +2333,7 @@ impl ChainSpec {
 
     /// Get the sealed header for the genesis block.
     pub fn sealed_genesis_header(&self) -> SealedHeader {
-        SealedHeader::new(self.genesis_header(), self.genesis_hash())
+        self.cached_genesis_header().clone()
     }
 
     /// Get the initial base fee of the genesis block.
@@ -732,15 +2346,42 @@ impl ChainSpec {
     }
 
     /// Get the [BaseFeeParams] for the chain at the given timestamp.
+    ///
+    /// Variable parameters are resolved by the timestamp based hardforks only, see
+    /// [Self::base_fee_params_at_block_and_timestamp] for chains that changed the parameters at a
+    /// block based hardfork.
     pub fn base_fee_params(&self, timestamp: u64) -> BaseFeeParams {
+        self.base_fee_params_where(|fork| self.fork(fork).active_at_timestamp(timestamp))
+    }
+
+    /// Get the [BaseFeeParams] for the chain at the given block number.
+    ///
+    /// Variable parameters are resolved by the block based hardforks only.
+    pub fn base_fee_params_at_block(&self, block_number: BlockNumber) -> BaseFeeParams {
+        self.base_fee_params_where(|fork| self.fork(fork).active_at_block(block_number))
+    }
+
+    /// Get the [BaseFeeParams] for the chain at the given block number and timestamp, resolving
+    /// variable parameters by both block and timestamp based hardforks.
+    pub fn base_fee_params_at_block_and_timestamp(
+        &self,
+        block_number: BlockNumber,
+        timestamp: u64,
+    ) -> BaseFeeParams {
+        self.base_fee_params_where(|fork| {
+            self.fork(fork).active_at_block_and_timestamp(block_number, timestamp)
+        })
+    }
+
+    /// Get the [BaseFeeParams] of the last hardfork for which `is_active` returns `true`.
+    fn base_fee_params_where(&self, is_active: impl Fn(Hardfork) -> bool) -> BaseFeeParams {
         match self.base_fee_params {
             BaseFeeParamsKind::Constant(bf_params) => bf_params,
             BaseFeeParamsKind::Variable(ForkBaseFeeParams { 0: ref bf_params }) => {
                 // Walk through the base fee params configuration in reverse order, and return the
-                // first one that corresponds to a hardfork that is active at the
-                // given timestamp.
+                // first one that corresponds to a hardfork that is active.
                 for (fork, params) in bf_params.iter().rev() {
-                    if self.is_fork_active_at_timestamp(*fork, timestamp) {
+                    if is_active(*fork) {
                         return *params
                     }
                 }
@@ -750,12 +2391,221 @@ impl ChainSpec {
         }
     }
 
+    /// Calculates the base fee of the block following `parent`, which has the given timestamp.
+    ///
+    /// The [BaseFeeParams] active at the number and timestamp of the child block are used, so forks
+    /// that change the EIP-1559 parameters (e.g. Optimism's Canyon) are taken into account. The
+    /// London activation block uses the initial base fee of EIP-1559, raised to the minimum base
+    /// fee of the chain if that is higher.
+    ///
+    /// Returns `None` if London is not active for the child block, or if the parent has no base fee
+    /// even though London is active for it.
+    ///
+    /// Parlia chains adopted London without the EIP-1559 fee market, their base fee is always zero.
+    pub fn next_block_base_fee(&self, parent: &Header, child_timestamp: u64) -> Option<u64> {
+        let london = self.fork(Hardfork::London);
+        let child_number = parent.number + 1;
+        if !london.active_at_block(child_number) {
+            return None
+        }
+        #[cfg(feature = "bsc")]
+        if self.is_parlia() {
+            return Some(0)
+        }
+        let params = self.base_fee_params_at_block_and_timestamp(child_number, child_timestamp);
+        if london.transitions_at_block(child_number) {
+            return Some(EIP1559_INITIAL_BASE_FEE.max(params.minimum_base_fee.unwrap_or_default()))
+        }
+
+        parent.next_block_base_fee(params)
+    }
+
+    /// Calculates the excess blob gas of the child of the given parent block, with the
+    /// [BlobParams] that are active at the timestamp of the child.
+    ///
+    /// Returns `None` if Cancun is not active for the child block. The first Cancun block
+    /// evaluates the blob gas fields of its parent as zero.
+    pub fn next_block_excess_blob_gas(&self, parent: &Header, child_timestamp: u64) -> Option<u64> {
+        if !self.is_cancun_active_at_timestamp(child_timestamp) {
+            return None
+        }
+        Some(self.blob_params_at_timestamp(child_timestamp).next_block_excess_blob_gas(
+            parent.excess_blob_gas.unwrap_or_default(),
+            parent.blob_gas_used.unwrap_or_default(),
+        ))
+    }
+
+    /// Calculates the blob fee of the child of the given parent block, see
+    /// [ChainSpec::next_block_excess_blob_gas].
+    pub fn next_block_blob_fee(&self, parent: &Header, child_timestamp: u64) -> Option<u128> {
+        let excess_blob_gas = self.next_block_excess_blob_gas(parent, child_timestamp)?;
+        Some(self.blob_params_at_timestamp(child_timestamp).calc_blob_fee(excess_blob_gas))
+    }
+
+    /// Returns the blob fee of the given block, with the [BlobParams] that are active at its
+    /// timestamp.
+    ///
+    /// Returns `None` if the block has no excess blob gas.
+    pub fn blob_fee(&self, header: &Header) -> Option<u128> {
+        header.blob_fee(self.blob_params_at_timestamp(header.timestamp))
+    }
+
+    /// Get the [GasLimitParams] for the chain at the given block number and timestamp.
+    ///
+    /// Returns `None` if no fork of the [GasLimitSchedule] is active.
+    pub fn gas_limit_at(
+        &self,
+        block_number: BlockNumber,
+        timestamp: u64,
+    ) -> Option<GasLimitParams> {
+        self.gas_limit_schedule.0.iter().rev().find_map(|(fork, params)| {
+            self.fork(*fork)
+                .active_at_block_and_timestamp(block_number, timestamp)
+                .then_some(*params)
+        })
+    }
+
+    /// Returns the gas limit of the block following `parent`, which has the given timestamp.
+    ///
+    /// This follows the [GasLimitParams] active for the child block, and keeps the gas limit of
+    /// the parent if there are none.
+    pub fn next_block_gas_limit(&self, parent: &Header, child_timestamp: u64) -> u64 {
+        self.gas_limit_at(parent.number + 1, child_timestamp)
+            .map_or(parent.gas_limit, |params| params.next_block_gas_limit(parent.gas_limit))
+    }
+
+    /// Returns the addresses of the precompiles enabled at the given timestamp and block number.
+    ///
+    /// This includes the Ethereum precompiles of all active hardforks and the chain-specific
+    /// precompiles of the [PrecompileSchedule].
+    pub fn active_precompiles(
+        &self,
+        timestamp: u64,
+        block_number: BlockNumber,
+    ) -> BTreeSet<Address> {
+        ETHEREUM_PRECOMPILES
+            .iter()
+            .map(|(fork, addresses)| (*fork, *addresses))
+            .chain(self.precompiles.0.iter().map(|(fork, addresses)| (*fork, addresses.as_slice())))
+            .filter(|(fork, _)| {
+                // chains from genesis files don't list frontier, it's active from genesis
+                *fork == Hardfork::Frontier ||
+                    self.fork(*fork).active_at_block_and_timestamp(block_number, timestamp)
+            })
+            .flat_map(|(_, addresses)| addresses.iter().copied())
+            .collect()
+    }
+
+    /// Returns the [ForkStateMigration]s of the hardforks that activate with the given block.
+    ///
+    /// Timestamp based hardforks activate with the first block whose timestamp is past the
+    /// activation time, which is determined from the timestamp of the parent block.
+    pub fn state_migrations_at(
+        &self,
+        block_number: BlockNumber,
+        timestamp: u64,
+        parent_timestamp: u64,
+    ) -> impl Iterator<Item = &ForkStateMigration> + '_ {
+        self.state_migrations
+            .iter()
+            .filter(move |(fork, _)| {
+                self.fork(*fork).transitions_at_block_and_timestamp(
+                    block_number,
+                    timestamp,
+                    parent_timestamp,
+                )
+            })
+            .flat_map(|(_, migrations)| migrations)
+    }
+
+    /// Get the [BlobParams] for the chain at the given timestamp.
+    pub fn blob_params_at_timestamp(&self, timestamp: u64) -> BlobParams {
+        match self.blob_params {
+            BlobScheduleKind::Constant(params) => params,
+            BlobScheduleKind::Variable(ForkBlobParams(ref schedule)) => {
+                // Walk through the schedule in reverse order, and return the first entry that
+                // corresponds to a hardfork that is active at the given timestamp.
+                for (fork, params) in schedule.iter().rev() {
+                    if self.is_fork_active_at_timestamp(*fork, timestamp) {
+                        return *params
+                    }
+                }
+
+                schedule.first().map(|(_, params)| *params).unwrap_or(BlobParams::cancun())
+            }
+        }
+    }
+
+    /// Returns all deposit contracts of the chain, sorted by deployment block.
+    pub fn deposit_contracts(&self) -> &[DepositContract] {
+        &self.deposit_contracts
+    }
+
+    /// Returns the deposit contract that is in use at the given block number, which is the most
+    /// recently deployed one.
+    ///
+    /// Returns `None` if no deposit contract is deployed at the block.
+    pub fn deposit_contract_at(&self, block_number: BlockNumber) -> Option<&DepositContract> {
+        self.deposit_contracts.iter().rev().find(|contract| contract.block <= block_number)
+    }
+
+    /// Returns the [SystemContracts] of the chain.
+    pub fn system_contracts(&self) -> &SystemContracts {
+        &self.system_contracts
+    }
+
+    /// Returns the address of the EIP-4788 beacon roots contract.
+    pub fn beacon_roots_contract(&self) -> Address {
+        self.system_contracts.beacon_roots
+    }
+
+    /// Returns the address of the EIP-2935 history storage contract.
+    pub fn history_storage_contract(&self) -> Address {
+        self.system_contracts.history_storage
+    }
+
+    /// Returns the address of the EIP-7002 withdrawal request contract.
+    pub fn withdrawal_requests_contract(&self) -> Address {
+        self.system_contracts.withdrawal_requests
+    }
+
+    /// Returns the address of the EIP-7251 consolidation request contract.
+    pub fn consolidation_requests_contract(&self) -> Address {
+        self.system_contracts.consolidation_requests
+    }
+
+    /// Returns the [RollupConfig] of the chain, if it is an OP-stack chain that configures one.
+    #[cfg(feature = "optimism")]
+    pub fn rollup_config(&self) -> Option<&RollupConfig> {
+        self.rollup.as_ref()
+    }
+
+    /// Returns the address that collects the priority fees of OP-stack blocks.
+    ///
+    /// This is the sequencer fee vault predeploy unless the [RollupConfig] overrides it.
+    #[cfg(feature = "optimism")]
+    pub fn sequencer_fee_vault(&self) -> Address {
+        self.rollup.map_or(SEQUENCER_FEE_VAULT_ADDRESS, |rollup| rollup.sequencer_fee_vault)
+    }
+
+    /// Returns the [BorConfig] of the chain, if it is a Polygon PoS chain.
+    #[cfg(feature = "polygon")]
+    pub fn bor_config(&self) -> Option<&BorConfig> {
+        self.bor.as_ref()
+    }
+
+    /// Returns the [GnosisConfig] of the chain, if it is a Gnosis chain.
+    #[cfg(feature = "gnosis")]
+    pub fn gnosis_config(&self) -> Option<&GnosisConfig> {
+        self.gnosis.as_ref()
+    }
+
     /// Get the hash of the genesis block.
     pub fn genesis_hash(&self) -> B256 {
         if let Some(hash) = self.genesis_hash {
             hash
         } else {
-            self.genesis_header().hash_slow()
+            self.cached_genesis_header().hash()
         }
     }
 
@@ -764,11 +2614,73 @@ impl ChainSpec {
         self.genesis.timestamp
     }
 
+    /// Returns the targeted duration between two blocks, if the chain has a fixed block time.
+    pub fn block_duration(&self) -> Option<Duration> {
+        self.block_time.map(Duration::from_secs)
+    }
+
+    /// Returns the slot the given timestamp falls into, counted from the genesis timestamp.
+    ///
+    /// Returns `None` if the chain has no fixed block time or the timestamp is before genesis.
+    pub fn slot_for_timestamp(&self, timestamp: u64) -> Option<u64> {
+        let block_time = self.block_time.filter(|block_time| *block_time > 0)?;
+        Some(timestamp.checked_sub(self.genesis.timestamp)? / block_time)
+    }
+
+    /// Returns the timestamp at which the given slot starts.
+    ///
+    /// Returns `None` if the chain has no fixed block time or the timestamp would overflow.
+    pub fn timestamp_for_slot(&self, slot: u64) -> Option<u64> {
+        slot.checked_mul(self.block_time?)?.checked_add(self.genesis.timestamp)
+    }
+
+    /// Returns the terminal total difficulty at which the chain switches to proof-of-stake.
+    ///
+    /// Chains whose genesis config sets `terminalTotalDifficultyPassed` without a
+    /// `terminalTotalDifficulty` passed it before genesis, their terminal total difficulty is zero.
+    pub fn terminal_total_difficulty(&self) -> Option<U256> {
+        self.fork(Hardfork::Paris)
+            .ttd()
+            .or_else(|| self.genesis.config.terminal_total_difficulty_passed.then_some(U256::ZERO))
+    }
+
+    /// Returns true if Paris is active at a block with the given total difficulty and difficulty.
+    ///
+    /// A block is a proof-of-stake block if the total difficulty of its parent, i.e. its own
+    /// total difficulty minus its difficulty, reached the terminal total difficulty.
+    pub fn is_paris_active_at_ttd(&self, total_difficulty: U256, difficulty: U256) -> bool {
+        self.terminal_total_difficulty()
+            .is_some_and(|ttd| total_difficulty.saturating_sub(difficulty) >= ttd)
+    }
+
+    /// Returns true if the given header is the terminal proof-of-work block, i.e. the last block
+    /// before Paris.
+    ///
+    /// The terminal block is the first block whose total difficulty reaches the terminal total
+    /// difficulty, its child is the first proof-of-stake block.
+    pub fn is_terminal_pow_block(&self, header: &Header, parent_td: U256) -> bool {
+        self.terminal_total_difficulty().is_some_and(|ttd| {
+            parent_td < ttd && parent_td.saturating_add(header.difficulty) >= ttd
+        })
+    }
+
     /// Returns the final total difficulty if the Paris hardfork is known.
     pub fn get_final_paris_total_difficulty(&self) -> Option<U256> {
         self.paris_block_and_final_difficulty.map(|(_, final_difficulty)| final_difficulty)
     }
 
+    /// Sets the Paris block and the final total difficulty observed by the node, unless the spec
+    /// already knows them.
+    ///
+    /// The specs of custom chains usually don't know the block the merge happened at. The node
+    /// records it once it is finalized, so it is known on subsequent runs.
+    pub fn set_observed_paris_block(&mut self, block: BlockNumber, final_difficulty: U256) {
+        if self.paris_block_and_final_difficulty.is_none() {
+            self.paris_block_and_final_difficulty = Some((block, final_difficulty));
+            self.invalidate_fork_caches();
+        }
+    }
+
     /// Returns the final total difficulty if the given block number is after the Paris hardfork.
     ///
     /// Note: technically this would also be valid for the block before the paris upgrade, but this
@@ -784,6 +2696,41 @@ impl ChainSpec {
         })
     }
 
+    /// Returns the difficulty bomb delay schedule of the chain.
+    ///
+    /// Each entry maps the block at which a delay activates to the total number of blocks the
+    /// bomb is pushed back from then on. The schedule is derived from the activation blocks of
+    /// the hardforks that delayed the bomb and any additional delays declared in the
+    /// `difficultyBombDelays` section of the genesis config, e.g.
+    /// `"difficultyBombDelays": {"1000000": 5000000}`. Converting a genesis with an invalid
+    /// section into a spec fails, see [GenesisConfigError].
+    pub fn bomb_delays(&self) -> BTreeMap<BlockNumber, u64> {
+        let mut delays = BTreeMap::new();
+        let mut insert = |block: BlockNumber, delay: u64| {
+            let entry = delays.entry(block).or_insert(delay);
+            *entry = (*entry).max(delay);
+        };
+
+        for (fork, delay) in HARDFORK_BOMB_DELAYS {
+            if let Some(block) = self.fork(fork).as_block() {
+                insert(block, delay);
+            }
+        }
+
+        for (block, delay) in genesis_bomb_delays(&self.genesis.config).unwrap_or_default() {
+            insert(block, delay);
+        }
+
+        delays
+    }
+
+    /// Returns the accumulated difficulty bomb delay at the given block.
+    ///
+    /// See also [`Self::bomb_delays`].
+    pub fn bomb_delay(&self, block_number: BlockNumber) -> u64 {
+        self.bomb_delays().range(..=block_number).next_back().map(|(_, delay)| *delay).unwrap_or(0)
+    }
+
     /// Get the fork filter for the given hardfork
     pub fn hardfork_fork_filter(&self, fork: Hardfork) -> Option<ForkFilter> {
         match self.fork(fork) {
@@ -797,12 +2744,68 @@ impl ChainSpec {
         &self.hardforks
     }
 
+    /// Returns the chain specific forks in this specification and their activation conditions.
+    pub fn custom_hardforks(&self) -> &ChainHardforks {
+        &self.custom_hardforks
+    }
+
+    /// Inserts or replaces the activation condition of the given fork, returning the previous
+    /// condition.
+    pub fn insert_hardfork(
+        &mut self,
+        fork: Hardfork,
+        condition: ForkCondition,
+    ) -> Option<ForkCondition> {
+        self.invalidate_fork_caches();
+        self.hardforks.insert(fork, condition)
+    }
+
+    /// Removes the given fork from the specification, returning its activation condition.
+    pub fn remove_hardfork(&mut self, fork: Hardfork) -> Option<ForkCondition> {
+        self.invalidate_fork_caches();
+        self.hardforks.remove(&fork)
+    }
+
+    /// Replaces all forks of the specification.
+    pub fn set_hardforks(&mut self, hardforks: BTreeMap<Hardfork, ForkCondition>) {
+        self.invalidate_fork_caches();
+        self.hardforks = hardforks;
+    }
+
+    /// Replaces the chain specific forks of the specification.
+    pub fn set_custom_hardforks(&mut self, custom_hardforks: ChainHardforks) {
+        self.invalidate_fork_caches();
+        self.custom_hardforks = custom_hardforks;
+    }
+
+    /// Returns the differences of the fork activations, base fee params, deposit contracts and
+    /// genesis between this spec and the given newer spec.
+    pub fn diff(&self, new: &ChainSpec) -> ChainSpecDiff {
+        ChainSpecDiff::new(self, new)
+    }
+
+    /// Returns a copy of this spec with the hardforks and chain specific forks of the given
+    /// updated spec, e.g. a reloaded chain spec file of a running node.
+    ///
+    /// The update may only add forks or move forks that are not active at the given head yet, to
+    /// activations after the head. Everything else of this spec is retained, the fork caches of the
+    /// returned spec are recomputed on the next lookup.
+    pub fn update_fork_schedule(
+        &self,
+        update: &ChainSpec,
+        head: &Head,
+    ) -> Result<ChainSpec, ForkScheduleError> {
+        fork_schedule::update_fork_schedule(self, update, head)
+    }
+
     /// Returns the hardfork display helper.
     pub fn display_hardforks(&self) -> DisplayHardforks {
-        DisplayHardforks::new(
-            self.hardforks(),
-            self.paris_block_and_final_difficulty.map(|(block, _)| block),
-        )
+        let known_paris_block = self.paris_block_and_final_difficulty.map(|(block, _)| block);
+        let mut display = DisplayHardforks::new(self.hardforks(), known_paris_block);
+        for (fork, condition) in self.custom_hardforks.forks_iter() {
+            display.insert(fork.name().to_string(), condition, known_paris_block);
+        }
+        display
     }
 
     /// Get the fork id for the given hardfork.
@@ -826,38 +2829,149 @@ impl ChainSpec {
         self.hardfork_fork_id(Hardfork::Cancun)
     }
 
+    /// Convenience method to get the fork id for [Hardfork::Prague] from a given chainspec.
+    #[inline]
+    pub fn prague_fork_id(&self) -> Option<ForkId> {
+        self.hardfork_fork_id(Hardfork::Prague)
+    }
+
     /// Get the fork condition for the given fork.
     pub fn fork(&self, fork: Hardfork) -> ForkCondition {
         self.hardforks.get(&fork).copied().unwrap_or(ForkCondition::Never)
     }
 
+    /// Returns the block number at which the given fork activates.
+    ///
+    /// TTD based forks resolve to their fork block, or to the known Paris block of the chain if
+    /// they don't have one. Returns `None` for timestamp based forks, TTD based forks whose block
+    /// is not known yet, and forks that are never activated.
+    pub fn fork_block(&self, fork: Hardfork) -> Option<BlockNumber> {
+        match self.fork(fork) {
+            ForkCondition::TTD { fork_block, .. } => {
+                fork_block.or_else(|| self.paris_block_and_final_difficulty.map(|(block, _)| block))
+            }
+            ForkCondition::BlockAndTimestamp { block, .. } => Some(block),
+            condition => condition.as_block(),
+        }
+    }
+
+    /// Returns the timestamp at which the given fork activates.
+    ///
+    /// Returns `None` for forks that are not timestamp based and forks that are never activated.
+    pub fn fork_timestamp(&self, fork: Hardfork) -> Option<u64> {
+        self.fork(fork).fork_id_timestamp()
+    }
+
+    /// Get an iterator of the hardforks that are active at the given head, in activation order.
+    pub fn active_forks_at(&self, head: &Head) -> impl Iterator<Item = Hardfork> + '_ {
+        let head = *head;
+        self.hardforks
+            .iter()
+            .filter(move |(_, condition)| condition.active_at_head(&head))
+            .map(|(fork, _)| *fork)
+    }
+
+    /// Returns the most recent hardfork that is active at the given head.
+    pub fn latest_active_fork(&self, head: &Head) -> Option<Hardfork> {
+        self.active_forks_at(head).last()
+    }
+
+    /// Returns the next hardfork that is scheduled after the given head, with its activation
+    /// condition.
+    ///
+    /// Only forks after the [latest active fork](ChainSpec::latest_active_fork) are considered, so
+    /// a TTD based fork is not reported once a later fork is active, even if the total difficulty
+    /// of the head is unknown.
+    pub fn next_fork_after(&self, head: &Head) -> Option<(Hardfork, ForkCondition)> {
+        let latest = self.latest_active_fork(head);
+        self.hardforks
+            .iter()
+            .filter(|(fork, _)| latest.map_or(true, |latest| **fork > latest))
+            .find(|(_, condition)| {
+                **condition != ForkCondition::Never && !condition.active_at_head(head)
+            })
+            .map(|(fork, condition)| (*fork, *condition))
+    }
+
     /// Get an iterator of all hardforks with their respective activation conditions.
     pub fn forks_iter(&self) -> impl Iterator<Item = (Hardfork, ForkCondition)> + '_ {
         self.hardforks.iter().map(|(f, b)| (*f, *b))
     }
 
+    /// Get the fork condition for the chain specific fork with the given name.
+    pub fn custom_fork(&self, name: &str) -> ForkCondition {
+        self.custom_hardforks.fork(name)
+    }
+
+    /// Get an iterator of the activation conditions of all forks, including the chain specific
+    /// forks.
+    fn fork_conditions_iter(&self) -> impl Iterator<Item = ForkCondition> + '_ {
+        self.hardforks
+            .values()
+            .copied()
+            .chain(self.custom_hardforks.forks_iter().map(|(_, condition)| condition))
+    }
+
     /// Convenience method to check if a fork is active at a given timestamp.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the fork is activated by a [ForkCondition::BlockAndTimestamp]: its
+    /// activation can't be determined without the block number, see
+    /// [ForkCondition::active_at_block_and_timestamp].
     #[inline]
     pub fn is_fork_active_at_timestamp(&self, fork: Hardfork, timestamp: u64) -> bool {
-        self.fork(fork).active_at_timestamp(timestamp)
+        match self.fork(fork) {
+            ForkCondition::BlockAndTimestamp { block, timestamp: time } => panic!(
+                "{fork} activates at block {block} and timestamp {time}, it can't be checked by \
+                 timestamp alone"
+            ),
+            condition => condition.active_at_timestamp(timestamp),
+        }
+    }
+
+    /// Checks if the given header is the first block at which the fork is active, i.e. the fork is
+    /// active at the header but not at its parent.
+    ///
+    /// This is used to run one-time migrations of a fork exactly once. TTD based forks are only
+    /// detected if their fork block is known.
+    pub fn is_first_block_of_fork(&self, fork: Hardfork, header: &Header, parent: &Header) -> bool {
+        let condition = self.fork(fork);
+        condition.active_at_block_and_timestamp(header.number, header.timestamp) &&
+            !condition.active_at_block_and_timestamp(parent.number, parent.timestamp)
+    }
+
+    /// Returns `true` if the given fork is active at the given timestamp, looking up timestamp
+    /// based forks in the [ForkActivationCache].
+    #[inline]
+    pub fn is_cached_fork_active_at_timestamp(&self, fork: Hardfork, timestamp: u64) -> bool {
+        self.fork_activations()
+            .is_active_at_timestamp(fork, timestamp)
+            .unwrap_or_else(|| self.is_fork_active_at_timestamp(fork, timestamp))
     }
 
     /// Convenience method to check if [Hardfork::Shanghai] is active at a given timestamp.
     #[inline]
     pub fn is_shanghai_active_at_timestamp(&self, timestamp: u64) -> bool {
-        self.fork_timestamps
-            .shanghai
-            .map(|shanghai| timestamp >= shanghai)
-            .unwrap_or_else(|| self.is_fork_active_at_timestamp(Hardfork::Shanghai, timestamp))
+        self.is_cached_fork_active_at_timestamp(Hardfork::Shanghai, timestamp)
     }
 
     /// Convenience method to check if [Hardfork::Cancun] is active at a given timestamp.
     #[inline]
     pub fn is_cancun_active_at_timestamp(&self, timestamp: u64) -> bool {
-        self.fork_timestamps
-            .cancun
-            .map(|cancun| timestamp >= cancun)
-            .unwrap_or_else(|| self.is_fork_active_at_timestamp(Hardfork::Cancun, timestamp))
+        self.is_cached_fork_active_at_timestamp(Hardfork::Cancun, timestamp)
+    }
+
+    /// Convenience method to check if [Hardfork::Prague] is active at a given timestamp.
+    #[inline]
+    pub fn is_prague_active_at_timestamp(&self, timestamp: u64) -> bool {
+        self.is_cached_fork_active_at_timestamp(Hardfork::Prague, timestamp)
+    }
+
+    /// Convenience method to check if [Hardfork::Osaka] is active at a given timestamp.
+    #[inline]
+    pub fn is_osaka_active_at_timestamp(&self, timestamp: u64) -> bool {
+        self.is_cached_fork_active_at_timestamp(Hardfork::Osaka, timestamp)
     }
 
     /// Convenience method to check if [Hardfork::Homestead] is active at a given block number.
@@ -866,74 +2980,120 @@ impl ChainSpec {
         self.fork(Hardfork::Homestead).active_at_block(block_number)
     }
 
+    /// Convenience method to check if [Hardfork::Bedrock] is active at a given block number.
+    #[cfg(feature = "optimism")]
+    #[inline]
+    pub fn is_bedrock_active_at_block(&self, block_number: u64) -> bool {
+        self.fork(Hardfork::Bedrock).active_at_block(block_number)
+    }
+
+    /// Convenience method to check if [Hardfork::Regolith] is active at a given timestamp.
+    #[cfg(feature = "optimism")]
+    #[inline]
+    pub fn is_regolith_active_at_timestamp(&self, timestamp: u64) -> bool {
+        self.is_cached_fork_active_at_timestamp(Hardfork::Regolith, timestamp)
+    }
+
+    /// Convenience method to check if [Hardfork::Canyon] is active at a given timestamp.
+    #[cfg(feature = "optimism")]
+    #[inline]
+    pub fn is_canyon_active_at_timestamp(&self, timestamp: u64) -> bool {
+        self.is_cached_fork_active_at_timestamp(Hardfork::Canyon, timestamp)
+    }
+
+    /// Convenience method to check if [Hardfork::Ecotone] is active at a given timestamp.
+    #[cfg(feature = "optimism")]
+    #[inline]
+    pub fn is_ecotone_active_at_timestamp(&self, timestamp: u64) -> bool {
+        self.is_cached_fork_active_at_timestamp(Hardfork::Ecotone, timestamp)
+    }
+
     /// Creates a [`ForkFilter`] for the block described by [Head].
     pub fn fork_filter(&self, head: Head) -> ForkFilter {
-        let forks = self.forks_iter().filter_map(|(_, condition)| {
+        let forks = self.fork_conditions_iter().filter_map(|condition| {
             // We filter out TTD-based forks w/o a pre-known block since those do not show up in the
             // fork filter.
             Some(match condition {
-                ForkCondition::Block(block) => ForkFilterKey::Block(block),
-                ForkCondition::Timestamp(time) => ForkFilterKey::Time(time),
+                ForkCondition::Block(_) | ForkCondition::Epoch { .. } => {
+                    ForkFilterKey::Block(condition.as_block()?)
+                }
+                ForkCondition::Timestamp(time) |
+                ForkCondition::BlockAndTimestamp { timestamp: time, .. } => {
+                    ForkFilterKey::Time(time)
+                }
                 ForkCondition::TTD { fork_block: Some(block), .. } => ForkFilterKey::Block(block),
                 _ => return None,
             })
         });
 
+        // forks that require both a block and a timestamp wait for their block
+        let fork_blocks = self.fork_conditions_iter().filter_map(|condition| match condition {
+            ForkCondition::BlockAndTimestamp { block, timestamp } => Some((timestamp, block)),
+            _ => None,
+        });
+
         ForkFilter::new(head, self.genesis_hash(), self.genesis_timestamp(), forks)
+            .with_fork_blocks(fork_blocks)
     }
 
     /// Compute the [`ForkId`] for the given [`Head`] folowing eip-6122 spec
+    ///
+    /// This is a lookup in the cached [ForkIdTable] of the chain.
     pub fn fork_id(&self, head: &Head) -> ForkId {
-        let mut forkhash = ForkHash::from(self.genesis_hash());
-        let mut current_applied = 0;
+        self.fork_id_table().fork_id(head)
+    }
 
-        // handle all block forks before handling timestamp based forks. see: https://eips.ethereum.org/EIPS/eip-6122
-        for (_, cond) in self.forks_iter() {
-            // handle block based forks and the sepolia merge netsplit block edge case (TTD
-            // ForkCondition with Some(block))
-            if let ForkCondition::Block(block) |
-            ForkCondition::TTD { fork_block: Some(block), .. } = cond
-            {
-                if cond.active_at_head(head) {
-                    if block != current_applied {
-                        forkhash += block;
-                        current_applied = block;
-                    }
-                } else {
-                    // we can return here because this block fork is not active, so we set the
-                    // `next` value
-                    return ForkId { hash: forkhash, next: block }
-                }
-            }
-        }
+    /// Checks whether the fork id announced by a remote node is compatible with the chain at the
+    /// given head, following the validation rules of EIP-2124 and EIP-6122.
+    pub fn validate_fork_id(&self, head: &Head, remote: ForkId) -> ForkIdValidation {
+        let table = self.fork_id_table();
+        ForkIdValidation::new(head, table.fork_id(head), table.fork_ids(), remote)
+    }
 
-        // timestamp are ALWAYS applied after the merge.
-        //
-        // this filter ensures that no block-based forks are returned
-        for timestamp in self.forks_iter().filter_map(|(_, cond)| {
-            cond.as_timestamp().filter(|time| time > &self.genesis.timestamp)
-        }) {
-            let cond = ForkCondition::Timestamp(timestamp);
-            if cond.active_at_head(head) {
-                if timestamp != current_applied {
-                    forkhash += timestamp;
-                    current_applied = timestamp;
-                }
-            } else {
-                // can safely return here because we have already handled all block forks and
-                // have handled all active timestamp forks, and set the next value to the
-                // timestamp that is known but not active yet
-                return ForkId { hash: forkhash, next: timestamp }
-            }
-        }
+    /// Returns the fork id transitions of the chain, computing them on first use.
+    pub fn fork_id_table(&self) -> &ForkIdTable {
+        self.fork_id_table.get_or_init(|| ForkIdTable::new(self))
+    }
+
+    /// Returns the activation timestamps of the timestamp based hardforks, computing them on first
+    /// use.
+    pub fn fork_activations(&self) -> &ForkActivationCache {
+        self.fork_activations.get_or_init(|| ForkActivationCache::from_hardforks(&self.hardforks))
+    }
+
+    /// Returns the KZG trusted setup of the chain, loading it from the `kzg_settings` source on
+    /// first use.
+    ///
+    /// A failed load is not cached, so a missing setup file can be fixed without restarting.
+    #[cfg(feature = "c-kzg")]
+    pub fn kzg_settings(&self) -> Result<Arc<KzgSettings>, LoadKzgSettingsError> {
+        self.kzg_settings_cache.get_or_try_init(|| self.kzg_settings.load()).cloned()
+    }
 
-        ForkId { hash: forkhash, next: 0 }
+    /// Clears the cached [ForkIdTable], [ForkActivationCache], sealed genesis header and KZG
+    /// trusted setup.
+    ///
+    /// This must be called after modifying the genesis or the KZG settings source of a chain spec
+    /// whose fork ids, genesis header or trusted setup were already computed, so they are
+    /// recomputed on the next lookup. Modifying the hardforks through [ChainSpec::insert_hardfork]
+    /// and friends calls this already.
+    pub fn invalidate_fork_caches(&mut self) {
+        self.fork_id_table = OnceCell::new();
+        self.fork_activations = OnceCell::new();
+        self.sealed_genesis_header = OnceCell::new();
+        #[cfg(feature = "c-kzg")]
+        {
+            self.kzg_settings_cache = OnceCell::new();
+        }
     }
 
     /// An internal helper function that returns a head block that satisfies a given Fork condition.
     pub(crate) fn satisfy(&self, cond: ForkCondition) -> Head {
         match cond {
             ForkCondition::Block(number) => Head { number, ..Default::default() },
+            ForkCondition::Epoch { .. } => {
+                Head { number: cond.as_block().unwrap_or_default(), ..Default::default() }
+            }
             ForkCondition::Timestamp(timestamp) => {
                 // to satisfy every timestamp ForkCondition, we find the last ForkCondition::Block
                 // if one exists, and include its block_num in the returned Head
@@ -942,6 +3102,13 @@ impl ChainSpec {
                 }
                 Head { timestamp, ..Default::default() }
             }
+            ForkCondition::BlockAndTimestamp { block, timestamp } => {
+                // like timestamp conditions, this must also satisfy all block based forks
+                let number = self
+                    .last_block_fork_before_merge_or_timestamp()
+                    .map_or(block, |last_block_num| last_block_num.max(block));
+                Head { timestamp, number, ..Default::default() }
+            }
             ForkCondition::TTD { total_difficulty, .. } => {
                 Head { total_difficulty, ..Default::default() }
             }
@@ -968,179 +3135,787 @@ impl ChainSpec {
                         if fork_block.is_some() {
                             return *fork_block
                         }
-                        // ensure curr_cond is indeed ForkCondition::Block and return block_num
-                        if let ForkCondition::Block(block_num) = curr_cond {
+                        // ensure curr_cond is indeed block based and return block_num
+                        if let Some(block_num) = curr_cond.as_block() {
                             return Some(block_num)
                         }
                     }
-                    ForkCondition::Timestamp(_) => {
-                        // ensure curr_cond is indeed ForkCondition::Block and return block_num
-                        if let ForkCondition::Block(block_num) = curr_cond {
+                    ForkCondition::Timestamp(_) | ForkCondition::BlockAndTimestamp { .. } => {
+                        // ensure curr_cond is indeed block based and return block_num
+                        if let Some(block_num) = curr_cond.as_block() {
                             return Some(block_num)
                         }
                     }
-                    ForkCondition::Block(_) | ForkCondition::Never => continue,
+                    ForkCondition::Block(_) |
+                    ForkCondition::Epoch { .. } |
+                    ForkCondition::Never => continue,
                 }
             }
         }
         None
     }
 
-    /// Build a chainspec using [`ChainSpecBuilder`]
-    pub fn builder() -> ChainSpecBuilder {
-        ChainSpecBuilder::default()
+    /// Returns the spec of an iteration of the Ephemery testnet, based on the genesis of the
+    /// network.
+    ///
+    /// The genesis timestamp and chain id of the given genesis are replaced with the ones of the
+    /// iteration, which defaults to the iteration that is live now. Returns an error if the
+    /// chain config of the genesis is invalid.
+    pub fn ephemery(
+        mut genesis: Genesis,
+        iteration: Option<EphemeryIteration>,
+    ) -> Result<Self, GenesisConfigError> {
+        let iteration = iteration.unwrap_or_else(EphemeryIteration::current);
+        genesis.timestamp = iteration.genesis_timestamp();
+        genesis.config.chain_id = iteration.chain_id();
+        genesis.try_into()
+    }
+
+    /// Converts the spec into a geth genesis, the inverse of `From<Genesis>`.
+    ///
+    /// The chain id, fork schedule and genesis header fields of the spec take precedence over the
+    /// ones of the contained genesis, so that specs tailored with the [ChainSpecBuilder] can be
+    /// handed to geth, hive or other clients. Chain specific forks are exported as `*Block` and
+    /// `*Time` entries of the config.
+    pub fn to_geth_genesis(&self) -> Genesis {
+        let mut genesis = self.genesis.clone();
+        let header = self.genesis_header();
+        genesis.base_fee_per_gas = header.base_fee_per_gas;
+        genesis.blob_gas_used = header.blob_gas_used;
+        genesis.excess_blob_gas = header.excess_blob_gas;
+
+        let config = &mut genesis.config;
+        config.chain_id = self.chain.id();
+
+        let block = |fork| match self.fork(fork) {
+            ForkCondition::Block(block) => Some(block),
+            _ => None,
+        };
+        config.homestead_block = block(Hardfork::Homestead);
+        config.dao_fork_block = block(Hardfork::Dao);
+        config.dao_fork_support = config.dao_fork_block.is_some();
+        config.eip150_block = block(Hardfork::Tangerine);
+        config.eip155_block = block(Hardfork::SpuriousDragon);
+        config.eip158_block = block(Hardfork::SpuriousDragon);
+        config.byzantium_block = block(Hardfork::Byzantium);
+        config.constantinople_block = block(Hardfork::Constantinople);
+        config.petersburg_block = block(Hardfork::Petersburg);
+        config.istanbul_block = block(Hardfork::Istanbul);
+        config.muir_glacier_block = block(Hardfork::MuirGlacier);
+        config.berlin_block = block(Hardfork::Berlin);
+        config.london_block = block(Hardfork::London);
+        config.arrow_glacier_block = block(Hardfork::ArrowGlacier);
+        config.gray_glacier_block = block(Hardfork::GrayGlacier);
+
+        // a chain merged at genesis is flagged as such instead of scheduling a netsplit block
+        let merged_at_genesis = matches!(self.paris_block_and_final_difficulty, Some((0, _)));
+        (config.terminal_total_difficulty, config.merge_netsplit_block) =
+            match self.fork(Hardfork::Paris) {
+                ForkCondition::TTD { total_difficulty, fork_block } => {
+                    (Some(total_difficulty), fork_block.filter(|_| !merged_at_genesis))
+                }
+                _ => (None, None),
+            };
+        config.terminal_total_difficulty_passed = self.paris_block_and_final_difficulty.is_some();
+
+        let timestamp = |fork| match self.fork(fork) {
+            ForkCondition::Timestamp(timestamp) => Some(timestamp),
+            _ => None,
+        };
+        config.shanghai_time = timestamp(Hardfork::Shanghai);
+        config.cancun_time = timestamp(Hardfork::Cancun);
+        config.prague_time = timestamp(Hardfork::Prague);
+        match timestamp(Hardfork::Osaka) {
+            Some(time) => {
+                config.extra_fields.insert(GENESIS_OSAKA_TIME_KEY.to_string(), time.into());
+            }
+            None => {
+                config.extra_fields.remove(GENESIS_OSAKA_TIME_KEY);
+            }
+        }
+
+        let mut custom_forks = serde_json::Map::new();
+        for (fork, condition) in self.custom_hardforks.forks_iter() {
+            let (key, value) = match condition {
+                ForkCondition::Block(block) => (format!("{}Block", fork.name()), block),
+                ForkCondition::Timestamp(timestamp) => (format!("{}Time", fork.name()), timestamp),
+                _ => continue,
+            };
+            custom_forks.insert(key, value.into());
+        }
+        if custom_forks.is_empty() {
+            config.extra_fields.remove(GENESIS_CUSTOM_FORKS_KEY);
+        } else {
+            config.extra_fields.insert(GENESIS_CUSTOM_FORKS_KEY.to_string(), custom_forks.into());
+        }
+
+        genesis
+    }
+
+    /// Build a chainspec using [`ChainSpecBuilder`]
+    pub fn builder() -> ChainSpecBuilder {
+        ChainSpecBuilder::default()
+    }
+
+    /// Returns the known bootnode records for the given chain.
+    ///
+    /// The boot nodes configured in the spec take precedence over the built-in lists.
+    /// ENRs of the spec that can't be converted to an enode record are skipped, see
+    /// [ChainSpec::boot_nodes].
+    pub fn bootnodes(&self) -> Option<Vec<NodeRecord>> {
+        use NamedChain as C;
+        if !self.bootnodes.is_empty() {
+            return Some(self.bootnodes.iter().filter_map(BootNode::node_record).collect())
+        }
+        let chain = self.chain;
+        if chain.id() == HOODI_CHAIN_ID {
+            return Some(hoodi_nodes())
+        }
+        match chain.try_into().ok()? {
+            C::Mainnet => Some(mainnet_nodes()),
+            #[cfg(feature = "goerli")]
+            C::Goerli => Some(goerli_nodes()),
+            C::Sepolia => Some(sepolia_nodes()),
+            C::Holesky => Some(holesky_nodes()),
+            C::Optimism | C::Base => Some(op_nodes()),
+            C::OptimismSepolia | C::BaseSepolia => Some(op_testnet_nodes()),
+            _ => None,
+        }
+    }
+
+    /// Returns the known boot nodes for the given chain, including the ENRs configured in the spec.
+    pub fn boot_nodes(&self) -> Option<Vec<BootNode>> {
+        if !self.bootnodes.is_empty() {
+            return Some(self.bootnodes.clone())
+        }
+        self.bootnodes().map(|nodes| nodes.into_iter().map(BootNode::from).collect())
+    }
+
+    /// Returns the EIP-1459 `enrtree://` link to the public DNS node list of the given chain.
+    pub fn dns_discovery_tree(&self) -> Option<String> {
+        use NamedChain as C;
+        let chain = self.chain;
+        if chain.id() == HOODI_CHAIN_ID {
+            return Some(ethdisco_tree("hoodi"))
+        }
+        match chain.try_into().ok()? {
+            C::Mainnet => Some(ethdisco_tree("mainnet")),
+            #[cfg(feature = "goerli")]
+            C::Goerli => Some(ethdisco_tree("goerli")),
+            C::Sepolia => Some(ethdisco_tree("sepolia")),
+            C::Holesky => Some(ethdisco_tree("holesky")),
+            _ => None,
+        }
+    }
+}
+
+/// An error converting a [Genesis] into a [ChainSpec]: a field of the genesis chain config is set,
+/// but can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid `{field}` in the genesis chain config: {reason}")]
+pub struct GenesisConfigError {
+    /// The key of the field in the chain config.
+    pub field: &'static str,
+    /// Why the field can't be parsed.
+    pub reason: String,
+}
+
+impl GenesisConfigError {
+    /// Creates a new error for the field with the given key.
+    pub fn new(field: &'static str, reason: impl Display) -> Self {
+        Self { field, reason: reason.to_string() }
+    }
+}
+
+/// Parses the `customForks` object of a genesis chain config, see [GenesisHardfork].
+fn genesis_custom_forks(config: &ChainConfig) -> Result<ChainHardforks, GenesisConfigError> {
+    let mut forks = ChainHardforks::default();
+    let Some(custom) = config.extra_fields.get(GENESIS_CUSTOM_FORKS_KEY) else { return Ok(forks) };
+    let custom = BTreeMap::<String, u64>::deserialize(custom)
+        .map_err(|err| GenesisConfigError::new(GENESIS_CUSTOM_FORKS_KEY, err))?;
+    for (key, value) in custom {
+        let (name, condition) = if let Some(name) = key.strip_suffix("Block") {
+            (name, ForkCondition::Block(value))
+        } else if let Some(name) = key.strip_suffix("Time") {
+            (name, ForkCondition::Timestamp(value))
+        } else {
+            (key.as_str(), ForkCondition::Never)
+        };
+        if name.is_empty() || condition == ForkCondition::Never {
+            return Err(GenesisConfigError::new(
+                GENESIS_CUSTOM_FORKS_KEY,
+                format!("`{key}` is not a fork block or time"),
+            ))
+        }
+        if name.parse::<Hardfork>().is_ok() {
+            return Err(GenesisConfigError::new(
+                GENESIS_CUSTOM_FORKS_KEY,
+                format!("`{name}` is not a chain specific fork"),
+            ))
+        }
+        forks.insert(GenesisHardfork::new(name), condition);
+    }
+    Ok(forks)
+}
+
+/// Parses a field of a genesis chain config that maps hardfork names to values, e.g. the
+/// `blobSchedule`, sorted by [Hardfork].
+///
+/// Returns `None` if the field is not set or empty.
+fn genesis_fork_schedule<T: DeserializeOwned>(
+    config: &ChainConfig,
+    key: &'static str,
+) -> Result<Option<Vec<(Hardfork, T)>>, GenesisConfigError> {
+    let Some(schedule) = config.extra_fields.get(key) else { return Ok(None) };
+    let schedule = BTreeMap::<String, T>::deserialize(schedule)
+        .map_err(|err| GenesisConfigError::new(key, err))?
+        .into_iter()
+        .map(|(fork, value)| {
+            let fork = fork
+                .parse::<Hardfork>()
+                .map_err(|_| GenesisConfigError::new(key, format!("unknown hardfork `{fork}`")))?;
+            Ok((fork, value))
+        })
+        .collect::<Result<BTreeMap<_, _>, _>>()?;
+    Ok((!schedule.is_empty()).then(|| schedule.into_iter().collect()))
+}
+
+/// Parses the `difficultyBombDelays` section of a genesis chain config, which maps the blocks at
+/// which the difficulty bomb is delayed to the total number of blocks it is delayed by.
+fn genesis_bomb_delays(
+    config: &ChainConfig,
+) -> Result<BTreeMap<BlockNumber, u64>, GenesisConfigError> {
+    let Some(delays) = config.extra_fields.get(GENESIS_BOMB_DELAYS_KEY) else {
+        return Ok(BTreeMap::new())
+    };
+    BTreeMap::<String, u64>::deserialize(delays)
+        .map_err(|err| GenesisConfigError::new(GENESIS_BOMB_DELAYS_KEY, err))?
+        .into_iter()
+        .map(|(block, delay)| {
+            let block = block.parse::<BlockNumber>().map_err(|_| {
+                GenesisConfigError::new(
+                    GENESIS_BOMB_DELAYS_KEY,
+                    format!("`{block}` is not a block number"),
+                )
+            })?;
+            Ok((block, delay))
+        })
+        .collect()
+}
+
+impl TryFrom<Genesis> for ChainSpec {
+    type Error = GenesisConfigError;
+
+    fn try_from(genesis: Genesis) -> Result<Self, Self::Error> {
+        // Block-based hardforks
+        let hardfork_opts = [
+            (Hardfork::Homestead, genesis.config.homestead_block),
+            (Hardfork::Dao, genesis.config.dao_fork_block),
+            (Hardfork::Tangerine, genesis.config.eip150_block),
+            (Hardfork::SpuriousDragon, genesis.config.eip155_block),
+            (Hardfork::Byzantium, genesis.config.byzantium_block),
+            (Hardfork::Constantinople, genesis.config.constantinople_block),
+            (Hardfork::Petersburg, genesis.config.petersburg_block),
+            (Hardfork::Istanbul, genesis.config.istanbul_block),
+            (Hardfork::MuirGlacier, genesis.config.muir_glacier_block),
+            (Hardfork::Berlin, genesis.config.berlin_block),
+            (Hardfork::London, genesis.config.london_block),
+            (Hardfork::ArrowGlacier, genesis.config.arrow_glacier_block),
+            (Hardfork::GrayGlacier, genesis.config.gray_glacier_block),
+        ];
+        let mut hardforks = hardfork_opts
+            .iter()
+            .filter_map(|(hardfork, opt)| opt.map(|block| (*hardfork, ForkCondition::Block(block))))
+            .collect::<BTreeMap<_, _>>();
+
+        // Paris
+        //
+        // A config that flags the TTD as passed without a netsplit block, and whose genesis
+        // already reached the TTD, is merged at genesis: the genesis is its terminal block.
+        let ttd_passed = genesis.config.terminal_total_difficulty_passed;
+        let ttd = genesis.config.terminal_total_difficulty.or(ttd_passed.then_some(U256::ZERO));
+        let merged_at_genesis = ttd.is_some_and(|ttd| {
+            ttd_passed && genesis.config.merge_netsplit_block.is_none() && genesis.difficulty >= ttd
+        });
+        if let Some(ttd) = ttd {
+            let fork_block =
+                if merged_at_genesis { Some(0) } else { genesis.config.merge_netsplit_block };
+            hardforks
+                .insert(Hardfork::Paris, ForkCondition::TTD { total_difficulty: ttd, fork_block });
+        }
+        let paris_block_and_final_difficulty = merged_at_genesis.then_some((0, genesis.difficulty));
+
+        // Time-based hardforks
+        let time_hardfork_opts = [
+            (Hardfork::Shanghai, genesis.config.shanghai_time),
+            (Hardfork::Cancun, genesis.config.cancun_time),
+            (Hardfork::Prague, genesis.config.prague_time),
+            (
+                Hardfork::Osaka,
+                genesis.config.extra_fields.get(GENESIS_OSAKA_TIME_KEY).and_then(|v| v.as_u64()),
+            ),
+        ];
+
+        let time_hardforks = time_hardfork_opts
+            .iter()
+            .filter_map(|(hardfork, opt)| {
+                opt.map(|time| (*hardfork, ForkCondition::Timestamp(time)))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        hardforks.extend(time_hardforks);
+
+        // OP-stack hardforks, e.g. `"fjordTime": 1720627201`
+        #[cfg(feature = "optimism")]
+        for (key, hardfork) in OP_GENESIS_HARDFORKS {
+            let Some(value) = genesis.config.extra_fields.get(key).and_then(|v| v.as_u64()) else {
+                continue
+            };
+            let condition = if key.ends_with("Block") {
+                ForkCondition::Block(value)
+            } else {
+                ForkCondition::Timestamp(value)
+            };
+            hardforks.insert(hardfork, condition);
+        }
+
+        // Bor hardforks, e.g. `"bor": { "delhiBlock": 38189056 }`, and the Ethereum hardforks that
+        // Polygon PoS activates by block, e.g. `"shanghaiBlock": 50523000`
+        #[cfg(feature = "polygon")]
+        let bor = BorConfig::from_chain_config(&genesis.config);
+        #[cfg(feature = "polygon")]
+        if bor.is_some() {
+            for (key, hardfork, bor_hardfork) in BOR_GENESIS_BLOCK_HARDFORKS {
+                let Some(block) = genesis.config.extra_fields.get(key).and_then(|v| v.as_u64())
+                else {
+                    continue
+                };
+                hardforks.insert(hardfork, ForkCondition::Block(block));
+                if let Some(bor_hardfork) = bor_hardfork {
+                    hardforks.insert(bor_hardfork, ForkCondition::Block(block));
+                }
+            }
+            if let Some(config) =
+                genesis.config.extra_fields.get(GENESIS_BOR_KEY).and_then(|bor| bor.as_object())
+            {
+                for (key, hardfork) in BOR_GENESIS_HARDFORKS {
+                    let Some(block) = config.get(key).and_then(|v| v.as_u64()) else { continue };
+                    hardforks.insert(hardfork, ForkCondition::Block(block));
+                }
+            }
+        }
+
+        // Chain specific forks, e.g. `"customForks": { "myForkBlock": 10 }`
+        let custom_hardforks = genesis_custom_forks(&genesis.config)?;
+
+        let consensus = ConsensusConfig::from_chain_config(&genesis.config);
+        let blob_params = BlobScheduleKind::from_chain_config(&genesis.config)?.unwrap_or_default();
+        let gas_limit_schedule =
+            GasLimitSchedule::from_chain_config(&genesis.config)?.unwrap_or_default();
+        let system_contracts =
+            SystemContracts::from_chain_config(&genesis.config)?.unwrap_or_default();
+        let precompiles =
+            PrecompileSchedule::from_chain_config(&genesis.config)?.unwrap_or_default();
+        let state_migrations = StateMigrationSchedule::from_chain_config(&genesis.config);
+        // OP-stack chains force-deploy the create2 deployer at canyon unless configured otherwise
+        #[cfg(feature = "optimism")]
+        let state_migrations = state_migrations.or_else(|| {
+            hardforks.contains_key(&Hardfork::Bedrock).then(StateMigrationSchedule::optimism)
+        });
+        let state_migrations = state_migrations.unwrap_or_default();
+        let max_future_timestamp_drift = genesis
+            .config
+            .extra_fields
+            .get(GENESIS_MAX_FUTURE_TIMESTAMP_DRIFT_KEY)
+            .and_then(|drift| drift.as_u64())
+            .unwrap_or(ALLOWED_FUTURE_BLOCK_TIME_SECONDS);
+        let block_time = genesis
+            .config
+            .extra_fields
+            .get(GENESIS_BLOCK_PERIOD_KEY)
+            .and_then(|period| period.as_u64())
+            .filter(|period| *period > 0);
+        let max_code_size = genesis
+            .config
+            .extra_fields
+            .get(GENESIS_MAX_CODE_SIZE_KEY)
+            .and_then(|size| size.as_u64())
+            .map_or(MAX_CODE_SIZE, |size| size as usize);
+        let max_init_code_size = genesis
+            .config
+            .extra_fields
+            .get(GENESIS_MAX_INIT_CODE_SIZE_KEY)
+            .and_then(|size| size.as_u64())
+            .map_or(MAX_INIT_CODE_SIZE, |size| size as usize);
+        let max_tx_size = genesis
+            .config
+            .extra_fields
+            .get(GENESIS_MAX_TX_SIZE_KEY)
+            .and_then(|size| size.as_u64())
+            .map(|size| size as usize);
+        let bootnodes = genesis
+            .config
+            .extra_fields
+            .get(GENESIS_BOOTNODES_KEY)
+            .and_then(|nodes| Vec::<BootNode>::deserialize(nodes).ok())
+            .unwrap_or_default();
+        let trusted_peers = genesis
+            .config
+            .extra_fields
+            .get(GENESIS_TRUSTED_PEERS_KEY)
+            .and_then(|nodes| Vec::<NodeRecord>::deserialize(nodes).ok())
+            .unwrap_or_default();
+        #[cfg(feature = "optimism")]
+        let rollup = RollupConfig::from_chain_config(&genesis.config);
+        // an explicitly configured deposit contract takes precedence over the alloc
+        let deposit_contracts = DepositContract::from_chain_config(&genesis.config)?
+            .or_else(|| DepositContract::from_genesis_alloc(&genesis))
+            .into_iter()
+            .collect();
+        genesis_bomb_delays(&genesis.config)?;
+
+        Ok(Self {
+            chain: genesis.config.chain_id.into(),
+            consensus,
+            blob_params,
+            gas_limit_schedule,
+            system_contracts,
+            precompiles,
+            state_migrations,
+            genesis,
+            genesis_hash: None,
+            hardforks,
+            custom_hardforks,
+            paris_block_and_final_difficulty,
+            deposit_contracts,
+            max_future_timestamp_drift,
+            block_time,
+            max_code_size,
+            max_init_code_size,
+            max_tx_size,
+            bootnodes,
+            trusted_peers,
+            #[cfg(feature = "optimism")]
+            rollup,
+            #[cfg(feature = "polygon")]
+            bor,
+            ..Default::default()
+        })
+    }
+}
+
+/// The precomputed [`ForkId`] transitions of a chain, following eip-6122.
+///
+/// Computing a fork id requires walking all hardforks of a chain, this table is computed once per
+/// [ChainSpec] so that a fork id can be looked up in `O(log n)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForkIdTable {
+    /// Block based forks sorted by their activation block.
+    blocks: Vec<ForkIdTransition>,
+    /// Timestamp based forks sorted by their activation timestamp. These are only applied after
+    /// all block based forks.
+    timestamps: Vec<ForkIdTransition>,
+    /// The fork hash after all forks are applied.
+    hash: ForkHash,
+}
+
+/// A fork that changes the [`ForkId`] of a chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ForkIdTransition {
+    /// The block or timestamp at which the fork is activated.
+    activation: u64,
+    /// The fork hash before the fork is applied.
+    hash: ForkHash,
+    /// The total difficulty at which the fork is already active, for TTD based forks with a
+    /// known fork block.
+    ttd: Option<U256>,
+    /// The block that must be reached for this and all earlier timestamp based forks to be
+    /// active, for forks that require both a block and a timestamp.
+    min_block: BlockNumber,
+}
+
+impl ForkIdTable {
+    /// Computes the fork id transitions of the given chain spec.
+    pub fn new(spec: &ChainSpec) -> Self {
+        let mut hash = ForkHash::from(spec.genesis_hash());
+        let mut current_applied = 0;
+
+        // handle block based forks and the sepolia merge netsplit block edge case (TTD
+        // ForkCondition with Some(block)), sorted by block since chain specific forks are not
+        // ordered with the rest
+        let mut block_forks = spec
+            .fork_conditions_iter()
+            .filter_map(|cond| match cond {
+                ForkCondition::Block(block) => Some((block, None)),
+                ForkCondition::TTD { fork_block: Some(block), total_difficulty } => {
+                    Some((block, Some(total_difficulty)))
+                }
+                ForkCondition::Epoch { .. } => cond.as_block().map(|block| (block, None)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        block_forks.sort_by_key(|(block, _)| *block);
+
+        let mut blocks = Vec::with_capacity(block_forks.len());
+        for (block, ttd) in block_forks {
+            blocks.push(ForkIdTransition { activation: block, hash, ttd, min_block: block });
+            if block != current_applied {
+                hash += block;
+                current_applied = block;
+            }
+        }
+
+        // timestamp are ALWAYS applied after the merge.
+        //
+        // this filter ensures that no block-based forks are returned, forks that require both a
+        // block and a timestamp are hashed by their timestamp
+        let mut timestamp_forks = spec
+            .fork_conditions_iter()
+            .filter_map(|cond| {
+                let time =
+                    cond.fork_id_timestamp().filter(|time| *time > spec.genesis.timestamp)?;
+                let block = match cond {
+                    ForkCondition::BlockAndTimestamp { block, .. } => block,
+                    _ => 0,
+                };
+                Some((time, block))
+            })
+            .collect::<Vec<_>>();
+        timestamp_forks.sort_by_key(|(time, _)| *time);
+
+        let mut timestamps = Vec::with_capacity(timestamp_forks.len());
+        let mut min_block = 0;
+        for (time, block) in timestamp_forks {
+            min_block = min_block.max(block);
+            timestamps.push(ForkIdTransition { activation: time, hash, ttd: None, min_block });
+            if time != current_applied {
+                hash += time;
+                current_applied = time;
+            }
+        }
+
+        Self { blocks, timestamps, hash }
+    }
+
+    /// Returns the [`ForkId`] for the given [`Head`].
+    pub fn fork_id(&self, head: &Head) -> ForkId {
+        let mut next = self.blocks.partition_point(|fork| fork.activation <= head.number);
+        // TTD based forks are also active before their fork block once the total difficulty is
+        // reached
+        while let Some(fork) = self.blocks.get(next) {
+            let ttd_reached = fork
+                .ttd
+                .is_some_and(|ttd| head.total_difficulty.saturating_sub(head.difficulty) >= ttd);
+            if !ttd_reached {
+                return ForkId { hash: fork.hash, next: fork.activation }
+            }
+            next += 1;
+        }
+
+        let next = self.timestamps.partition_point(|fork| {
+            fork.activation <= head.timestamp && fork.min_block <= head.number
+        });
+        match self.timestamps.get(next) {
+            // the timestamp of the fork passed, so it is activated by the block it waits for
+            Some(fork) if fork.activation <= head.timestamp => {
+                ForkId { hash: fork.hash, next: fork.min_block }
+            }
+            Some(fork) => ForkId { hash: fork.hash, next: fork.activation },
+            None => ForkId { hash: self.hash, next: 0 },
+        }
+    }
+
+    /// Returns the heads at which the forks of the chain are activated, in activation order.
+    ///
+    /// Timestamp based forks are activated on top of the last block based fork and the blocks they
+    /// wait for.
+    pub fn activation_heads(&self) -> impl Iterator<Item = Head> + '_ {
+        let last_block = self.blocks.last().map_or(0, |fork| fork.activation);
+        self.blocks.iter().map(|fork| Head { number: fork.activation, ..Default::default() }).chain(
+            self.timestamps.iter().map(move |fork| Head {
+                number: last_block.max(fork.min_block),
+                timestamp: fork.activation,
+                ..Default::default()
+            }),
+        )
+    }
+
+    /// Returns all fork ids of the chain in activation order, starting with the one of the
+    /// genesis.
+    pub fn fork_ids(&self) -> impl Iterator<Item = ForkId> + '_ {
+        let mut current_applied = 0;
+        self.blocks
+            .iter()
+            .chain(&self.timestamps)
+            // forks at the genesis and forks sharing their activation with the previous one don't
+            // change the fork hash
+            .filter(move |fork| {
+                let applied = fork.activation != current_applied;
+                current_applied = fork.activation;
+                applied
+            })
+            .map(|fork| ForkId { hash: fork.hash, next: fork.activation })
+            .chain(core::iter::once(ForkId { hash: self.hash, next: 0 }))
+    }
+}
+
+/// The activation timestamps of the timestamp based hardforks of a chain.
+///
+/// This caches the [ForkCondition::Timestamp] entries of the hardforks of a [ChainSpec], see
+/// [ChainSpec::fork_activations].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ForkActivationCache {
+    timestamps: BTreeMap<Hardfork, u64>,
+}
+
+impl ForkActivationCache {
+    /// Creates a new [`ForkActivationCache`] from the given hardforks by extracting the timestamps
+    pub fn from_hardforks(forks: &BTreeMap<Hardfork, ForkCondition>) -> Self {
+        let timestamps = forks
+            .iter()
+            .filter_map(|(fork, condition)| Some((*fork, condition.as_timestamp()?)))
+            .collect();
+        Self { timestamps }
+    }
+
+    /// Returns the activation timestamp of the given fork, if it's timestamp based.
+    pub fn timestamp(&self, fork: Hardfork) -> Option<u64> {
+        self.timestamps.get(&fork).copied()
     }
 
-    /// Returns the known bootnode records for the given chain.
-    pub fn bootnodes(&self) -> Option<Vec<NodeRecord>> {
-        use NamedChain as C;
-        let chain = self.chain;
-        match chain.try_into().ok()? {
-            C::Mainnet => Some(mainnet_nodes()),
-            C::Goerli => Some(goerli_nodes()),
-            C::Sepolia => Some(sepolia_nodes()),
-            C::Holesky => Some(holesky_nodes()),
-            _ => None,
-        }
+    /// Returns whether the given fork is active at the given timestamp, or `None` if the fork is
+    /// not timestamp based.
+    #[inline]
+    pub fn is_active_at_timestamp(&self, fork: Hardfork, timestamp: u64) -> Option<bool> {
+        self.timestamp(fork).map(|activation| timestamp >= activation)
+    }
+
+    /// Returns all timestamp based forks and their activation timestamps.
+    pub fn iter(&self) -> impl Iterator<Item = (Hardfork, u64)> + '_ {
+        self.timestamps.iter().map(|(fork, timestamp)| (*fork, *timestamp))
     }
 }
 
-impl From<Genesis> for ChainSpec {
-    fn from(genesis: Genesis) -> Self {
-        // Block-based hardforks
-        let hardfork_opts = [
-            (Hardfork::Homestead, genesis.config.homestead_block),
-            (Hardfork::Dao, genesis.config.dao_fork_block),
-            (Hardfork::Tangerine, genesis.config.eip150_block),
-            (Hardfork::SpuriousDragon, genesis.config.eip155_block),
-            (Hardfork::Byzantium, genesis.config.byzantium_block),
-            (Hardfork::Constantinople, genesis.config.constantinople_block),
-            (Hardfork::Petersburg, genesis.config.petersburg_block),
-            (Hardfork::Istanbul, genesis.config.istanbul_block),
-            (Hardfork::MuirGlacier, genesis.config.muir_glacier_block),
-            (Hardfork::Berlin, genesis.config.berlin_block),
-            (Hardfork::London, genesis.config.london_block),
-            (Hardfork::ArrowGlacier, genesis.config.arrow_glacier_block),
-            (Hardfork::GrayGlacier, genesis.config.gray_glacier_block),
-        ];
-        let mut hardforks = hardfork_opts
-            .iter()
-            .filter_map(|(hardfork, opt)| opt.map(|block| (*hardfork, ForkCondition::Block(block))))
-            .collect::<BTreeMap<_, _>>();
+/// An ordered collection of chain specific hardforks, keyed by their name.
+///
+/// These are the forks of a chain that are not part of [Hardfork], see [ChainHardfork]. They are
+/// included in the [ForkId] and [ForkFilter] of the chain and are displayed by
+/// [DisplayHardforks].
+///
+/// The forks are serialized as a map of their names to their activation conditions, and are
+/// deserialized as [GenesisHardfork]s.
+#[derive(Debug, Clone, Default)]
+pub struct ChainHardforks {
+    /// The forks and their activation conditions, in insertion order.
+    forks: Vec<(Arc<dyn ChainHardfork>, ForkCondition)>,
+}
 
-        // Paris
-        if let Some(ttd) = genesis.config.terminal_total_difficulty {
-            hardforks.insert(
-                Hardfork::Paris,
-                ForkCondition::TTD {
-                    total_difficulty: ttd,
-                    fork_block: genesis.config.merge_netsplit_block,
-                },
-            );
+impl ChainHardforks {
+    /// Inserts the given fork with the given activation condition.
+    ///
+    /// If a fork with the same name already exists, it is replaced.
+    pub fn insert(&mut self, fork: impl ChainHardfork, condition: ForkCondition) {
+        let entry = (Arc::new(fork) as Arc<dyn ChainHardfork>, condition);
+        if let Some(existing) = self.forks.iter_mut().find(|(f, _)| f.name() == entry.0.name()) {
+            *existing = entry;
+        } else {
+            self.forks.push(entry);
         }
+    }
 
-        // Time-based hardforks
-        let time_hardfork_opts = [
-            (Hardfork::Shanghai, genesis.config.shanghai_time),
-            (Hardfork::Cancun, genesis.config.cancun_time),
-        ];
+    /// Removes the fork with the given name and returns its activation condition, if it exists.
+    pub fn remove(&mut self, name: &str) -> Option<ForkCondition> {
+        let idx = self.forks.iter().position(|(fork, _)| fork.name() == name)?;
+        Some(self.forks.remove(idx).1)
+    }
 
-        let time_hardforks = time_hardfork_opts
+    /// Returns the activation condition of the fork with the given name.
+    ///
+    /// Returns [ForkCondition::Never] if there is no such fork.
+    pub fn fork(&self, name: &str) -> ForkCondition {
+        self.forks
             .iter()
-            .filter_map(|(hardfork, opt)| {
-                opt.map(|time| (*hardfork, ForkCondition::Timestamp(time)))
-            })
-            .collect::<BTreeMap<_, _>>();
+            .find(|(fork, _)| fork.name() == name)
+            .map(|(_, condition)| *condition)
+            .unwrap_or(ForkCondition::Never)
+    }
 
-        hardforks.extend(time_hardforks);
+    /// Get an iterator of all forks with their respective activation conditions.
+    pub fn forks_iter(&self) -> impl Iterator<Item = (&dyn ChainHardfork, ForkCondition)> + '_ {
+        self.forks.iter().map(|(fork, condition)| (fork.as_ref(), *condition))
+    }
 
-        Self {
-            chain: genesis.config.chain_id.into(),
-            genesis,
-            genesis_hash: None,
-            fork_timestamps: ForkTimestamps::from_hardforks(&hardforks),
-            hardforks,
-            paris_block_and_final_difficulty: None,
-            deposit_contract: None,
-            ..Default::default()
-        }
+    /// Returns the number of forks.
+    pub fn len(&self) -> usize {
+        self.forks.len()
+    }
+
+    /// Returns `true` if there are no forks.
+    pub fn is_empty(&self) -> bool {
+        self.forks.is_empty()
     }
 }
 
-/// Various timestamps of forks
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
-pub struct ForkTimestamps {
-    /// The timestamp of the shanghai fork
-    pub shanghai: Option<u64>,
-    /// The timestamp of the cancun fork
-    pub cancun: Option<u64>,
-    /// The timestamp of the Regolith fork
-    #[cfg(feature = "optimism")]
-    pub regolith: Option<u64>,
-    /// The timestamp of the Canyon fork
-    #[cfg(feature = "optimism")]
-    pub canyon: Option<u64>,
-    /// The timestamp of the Ecotone fork
-    #[cfg(feature = "optimism")]
-    pub ecotone: Option<u64>,
+impl Serialize for ChainHardforks {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.forks_iter().map(|(fork, condition)| (fork.name(), condition)))
+    }
 }
 
-impl ForkTimestamps {
-    /// Creates a new [`ForkTimestamps`] from the given hardforks by extracing the timestamps
-    fn from_hardforks(forks: &BTreeMap<Hardfork, ForkCondition>) -> Self {
-        let mut timestamps = ForkTimestamps::default();
-        if let Some(shanghai) = forks.get(&Hardfork::Shanghai).and_then(|f| f.as_timestamp()) {
-            timestamps = timestamps.shanghai(shanghai);
-        }
-        if let Some(cancun) = forks.get(&Hardfork::Cancun).and_then(|f| f.as_timestamp()) {
-            timestamps = timestamps.cancun(cancun);
-        }
-        #[cfg(feature = "optimism")]
-        {
-            if let Some(regolith) = forks.get(&Hardfork::Regolith).and_then(|f| f.as_timestamp()) {
-                timestamps = timestamps.regolith(regolith);
-            }
-            if let Some(canyon) = forks.get(&Hardfork::Canyon).and_then(|f| f.as_timestamp()) {
-                timestamps = timestamps.canyon(canyon);
+impl<'de> Deserialize<'de> for ChainHardforks {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ChainHardforksVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ChainHardforksVisitor {
+            type Value = ChainHardforks;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a map of fork names to activation conditions")
             }
-            if let Some(ecotone) = forks.get(&Hardfork::Ecotone).and_then(|f| f.as_timestamp()) {
-                timestamps = timestamps.ecotone(ecotone);
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut forks = ChainHardforks::default();
+                while let Some((name, condition)) = map.next_entry::<String, ForkCondition>()? {
+                    forks.insert(GenesisHardfork::new(name), condition);
+                }
+                Ok(forks)
             }
         }
-        timestamps
-    }
-
-    /// Sets the given shanghai timestamp
-    pub fn shanghai(mut self, shanghai: u64) -> Self {
-        self.shanghai = Some(shanghai);
-        self
-    }
 
-    /// Sets the given cancun timestamp
-    pub fn cancun(mut self, cancun: u64) -> Self {
-        self.cancun = Some(cancun);
-        self
+        deserializer.deserialize_map(ChainHardforksVisitor)
     }
+}
 
-    /// Sets the given regolith timestamp
-    #[cfg(feature = "optimism")]
-    pub fn regolith(mut self, regolith: u64) -> Self {
-        self.regolith = Some(regolith);
-        self
-    }
+/// A chain specific fork that was scheduled in the `config` of a genesis file.
+///
+/// These are the `*Block` and `*Time` entries of the `customForks` object of the `config`, e.g.
+/// `"customForks": { "myForkTime": 1337 }` schedules `myFork` at timestamp `1337`. The names of
+/// the forks can't be the ones of a [Hardfork].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisHardfork {
+    /// The name of the fork, without the `Block`/`Time` suffix.
+    name: String,
+}
 
-    /// Sets the given canyon timestamp
-    #[cfg(feature = "optimism")]
-    pub fn canyon(mut self, canyon: u64) -> Self {
-        self.canyon = Some(canyon);
-        self
+impl GenesisHardfork {
+    /// Creates a new fork with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
     }
+}
 
-    /// Sets the given ecotone timestamp
-    #[cfg(feature = "optimism")]
-    pub fn ecotone(mut self, ecotone: u64) -> Self {
-        self.ecotone = Some(ecotone);
-        self
+impl ChainHardfork for GenesisHardfork {
+    fn name(&self) -> &str {
+        &self.name
     }
 }
 
@@ -1172,11 +3947,13 @@ impl From<Arc<ChainSpec>> for AllGenesisFormats {
     }
 }
 
-impl From<AllGenesisFormats> for ChainSpec {
-    fn from(genesis: AllGenesisFormats) -> Self {
+impl TryFrom<AllGenesisFormats> for ChainSpec {
+    type Error = GenesisConfigError;
+
+    fn try_from(genesis: AllGenesisFormats) -> Result<Self, Self::Error> {
         match genesis {
-            AllGenesisFormats::Geth(genesis) => genesis.into(),
-            AllGenesisFormats::Reth(genesis) => genesis,
+            AllGenesisFormats::Geth(genesis) => genesis.try_into(),
+            AllGenesisFormats::Reth(genesis) => Ok(genesis),
         }
     }
 }
@@ -1186,7 +3963,26 @@ impl From<AllGenesisFormats> for ChainSpec {
 pub struct ChainSpecBuilder {
     chain: Option<Chain>,
     genesis: Option<Genesis>,
+    extra_alloc: Vec<(Address, GenesisAccount)>,
     hardforks: BTreeMap<Hardfork, ForkCondition>,
+    custom_hardforks: ChainHardforks,
+    consensus: ConsensusConfig,
+    system_contracts: SystemContracts,
+    state_migrations: StateMigrationSchedule,
+    deposit_contracts: Vec<DepositContract>,
+    max_future_timestamp_drift: Option<u64>,
+    block_time: Option<u64>,
+    max_code_size: Option<usize>,
+    max_init_code_size: Option<usize>,
+    max_tx_size: Option<usize>,
+    bootnodes: Vec<BootNode>,
+    trusted_peers: Vec<NodeRecord>,
+    #[cfg(feature = "optimism")]
+    rollup: Option<RollupConfig>,
+    #[cfg(feature = "polygon")]
+    bor: Option<BorConfig>,
+    #[cfg(feature = "gnosis")]
+    gnosis: Option<GnosisConfig>,
 }
 
 impl ChainSpecBuilder {
@@ -1195,7 +3991,26 @@ impl ChainSpecBuilder {
         Self {
             chain: Some(MAINNET.chain),
             genesis: Some(MAINNET.genesis.clone()),
+            extra_alloc: Vec::new(),
             hardforks: MAINNET.hardforks.clone(),
+            custom_hardforks: MAINNET.custom_hardforks.clone(),
+            consensus: MAINNET.consensus,
+            system_contracts: MAINNET.system_contracts,
+            state_migrations: MAINNET.state_migrations.clone(),
+            deposit_contracts: MAINNET.deposit_contracts.clone(),
+            max_future_timestamp_drift: Some(MAINNET.max_future_timestamp_drift),
+            block_time: MAINNET.block_time,
+            max_code_size: Some(MAINNET.max_code_size),
+            max_init_code_size: Some(MAINNET.max_init_code_size),
+            max_tx_size: MAINNET.max_tx_size,
+            bootnodes: MAINNET.bootnodes.clone(),
+            trusted_peers: MAINNET.trusted_peers.clone(),
+            #[cfg(feature = "optimism")]
+            rollup: None,
+            #[cfg(feature = "polygon")]
+            bor: None,
+            #[cfg(feature = "gnosis")]
+            gnosis: None,
         }
     }
 
@@ -1211,6 +4026,108 @@ impl ChainSpecBuilder {
         self
     }
 
+    /// Add accounts to the allocation of the genesis block.
+    ///
+    /// The accounts are merged into the genesis when the spec is built, see
+    /// [GenesisAllocExt::merge_alloc].
+    pub fn with_extra_alloc(
+        mut self,
+        accounts: impl IntoIterator<Item = (Address, GenesisAccount)>,
+    ) -> Self {
+        self.extra_alloc.extend(accounts);
+        self
+    }
+
+    /// Set the consensus engine.
+    pub fn consensus(mut self, consensus: ConsensusConfig) -> Self {
+        self.consensus = consensus;
+        self
+    }
+
+    /// Set the number of seconds a block timestamp may be ahead of the local clock.
+    pub fn max_future_timestamp_drift(mut self, drift: u64) -> Self {
+        self.max_future_timestamp_drift = Some(drift);
+        self
+    }
+
+    /// Set the targeted number of seconds between two blocks.
+    pub fn block_time(mut self, block_time: u64) -> Self {
+        self.block_time = Some(block_time);
+        self
+    }
+
+    /// Set the maximum size of the code of a deployed contract.
+    pub fn max_code_size(mut self, max_code_size: usize) -> Self {
+        self.max_code_size = Some(max_code_size);
+        self
+    }
+
+    /// Set the maximum size of the init code of a contract creation.
+    pub fn max_init_code_size(mut self, max_init_code_size: usize) -> Self {
+        self.max_init_code_size = Some(max_init_code_size);
+        self
+    }
+
+    /// Set the maximum size of an encoded transaction.
+    pub fn max_tx_size(mut self, max_tx_size: usize) -> Self {
+        self.max_tx_size = Some(max_tx_size);
+        self
+    }
+
+    /// Set the boot nodes of the chain.
+    pub fn bootnodes(mut self, bootnodes: Vec<BootNode>) -> Self {
+        self.bootnodes = bootnodes;
+        self
+    }
+
+    /// Set the trusted peers of the chain.
+    pub fn trusted_peers(mut self, trusted_peers: Vec<NodeRecord>) -> Self {
+        self.trusted_peers = trusted_peers;
+        self
+    }
+
+    /// Set the rollup configuration of an OP-stack chain.
+    #[cfg(feature = "optimism")]
+    pub fn rollup(mut self, rollup: RollupConfig) -> Self {
+        self.rollup = Some(rollup);
+        self
+    }
+
+    /// Set the Bor consensus configuration of a Polygon PoS chain.
+    #[cfg(feature = "polygon")]
+    pub fn bor(mut self, bor: BorConfig) -> Self {
+        self.bor = Some(bor);
+        self
+    }
+
+    /// Set the AuRa engine and fee collector configuration of a Gnosis chain.
+    #[cfg(feature = "gnosis")]
+    pub fn gnosis(mut self, gnosis: GnosisConfig) -> Self {
+        self.gnosis = Some(gnosis);
+        self
+    }
+
+    /// Set the addresses of the system contracts.
+    pub fn system_contracts(mut self, system_contracts: SystemContracts) -> Self {
+        self.system_contracts = system_contracts;
+        self
+    }
+
+    /// Set the contracts force-deployed at hardfork transitions.
+    pub fn state_migrations(mut self, state_migrations: StateMigrationSchedule) -> Self {
+        self.state_migrations = state_migrations;
+        self
+    }
+
+    /// Add a deposit contract, e.g. one the chain migrated to.
+    ///
+    /// The contracts are kept sorted by deployment block.
+    pub fn deposit_contract(mut self, contract: DepositContract) -> Self {
+        let index = self.deposit_contracts.partition_point(|c| c.block <= contract.block);
+        self.deposit_contracts.insert(index, contract);
+        self
+    }
+
     /// Add the given fork with the given activation condition to the spec.
     pub fn with_fork(mut self, fork: Hardfork, condition: ForkCondition) -> Self {
         self.hardforks.insert(fork, condition);
@@ -1223,6 +4140,12 @@ impl ChainSpecBuilder {
         self
     }
 
+    /// Add the given chain specific fork with the given activation condition to the spec.
+    pub fn with_custom_fork(mut self, fork: impl ChainHardfork, condition: ForkCondition) -> Self {
+        self.custom_hardforks.insert(fork, condition);
+        self
+    }
+
     /// Enable the Paris hardfork at the given TTD.
     ///
     /// Does not set the merge netsplit block.
@@ -1326,6 +4249,20 @@ impl ChainSpecBuilder {
         self
     }
 
+    /// Enable Prague at genesis.
+    pub fn prague_activated(mut self) -> Self {
+        self = self.cancun_activated();
+        self.hardforks.insert(Hardfork::Prague, ForkCondition::Timestamp(0));
+        self
+    }
+
+    /// Enable Osaka at genesis.
+    pub fn osaka_activated(mut self) -> Self {
+        self = self.prague_activated();
+        self.hardforks.insert(Hardfork::Osaka, ForkCondition::Timestamp(0));
+        self
+    }
+
     /// Enable Bedrock at genesis
     #[cfg(feature = "optimism")]
     pub fn bedrock_activated(mut self) -> Self {
@@ -1349,6 +4286,9 @@ impl ChainSpecBuilder {
         // Canyon also activates changes from L1's Shanghai hardfork
         self.hardforks.insert(Hardfork::Shanghai, ForkCondition::Timestamp(0));
         self.hardforks.insert(Hardfork::Canyon, ForkCondition::Timestamp(0));
+        if self.state_migrations.is_empty() {
+            self.state_migrations = StateMigrationSchedule::optimism();
+        }
         self
     }
 
@@ -1361,6 +4301,30 @@ impl ChainSpecBuilder {
         self
     }
 
+    /// Enable Fjord at genesis
+    #[cfg(feature = "optimism")]
+    pub fn fjord_activated(mut self) -> Self {
+        self = self.ecotone_activated();
+        self.hardforks.insert(Hardfork::Fjord, ForkCondition::Timestamp(0));
+        self
+    }
+
+    /// Enable Granite at genesis
+    #[cfg(feature = "optimism")]
+    pub fn granite_activated(mut self) -> Self {
+        self = self.fjord_activated();
+        self.hardforks.insert(Hardfork::Granite, ForkCondition::Timestamp(0));
+        self
+    }
+
+    /// Enable Holocene at genesis
+    #[cfg(feature = "optimism")]
+    pub fn holocene_activated(mut self) -> Self {
+        self = self.granite_activated();
+        self.hardforks.insert(Hardfork::Holocene, ForkCondition::Timestamp(0));
+        self
+    }
+
     /// Build the resulting [`ChainSpec`].
     ///
     /// # Panics
@@ -1370,12 +4334,30 @@ impl ChainSpecBuilder {
     pub fn build(self) -> ChainSpec {
         ChainSpec {
             chain: self.chain.expect("The chain is required"),
-            genesis: self.genesis.expect("The genesis is required"),
+            genesis: self.genesis.expect("The genesis is required").merge_alloc(self.extra_alloc),
             genesis_hash: None,
-            fork_timestamps: ForkTimestamps::from_hardforks(&self.hardforks),
             hardforks: self.hardforks,
+            custom_hardforks: self.custom_hardforks,
+            consensus: self.consensus,
+            system_contracts: self.system_contracts,
+            state_migrations: self.state_migrations,
             paris_block_and_final_difficulty: None,
-            deposit_contract: None,
+            deposit_contracts: self.deposit_contracts,
+            max_future_timestamp_drift: self
+                .max_future_timestamp_drift
+                .unwrap_or(ALLOWED_FUTURE_BLOCK_TIME_SECONDS),
+            block_time: self.block_time,
+            max_code_size: self.max_code_size.unwrap_or(MAX_CODE_SIZE),
+            max_init_code_size: self.max_init_code_size.unwrap_or(MAX_INIT_CODE_SIZE),
+            max_tx_size: self.max_tx_size,
+            bootnodes: self.bootnodes,
+            trusted_peers: self.trusted_peers,
+            #[cfg(feature = "optimism")]
+            rollup: self.rollup,
+            #[cfg(feature = "polygon")]
+            bor: self.bor,
+            #[cfg(feature = "gnosis")]
+            gnosis: self.gnosis,
             ..Default::default()
         }
     }
@@ -1386,107 +4368,26 @@ impl From<&Arc<ChainSpec>> for ChainSpecBuilder {
         Self {
             chain: Some(value.chain),
             genesis: Some(value.genesis.clone()),
+            extra_alloc: Vec::new(),
             hardforks: value.hardforks.clone(),
-        }
-    }
-}
-
-/// The condition at which a fork is activated.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
-pub enum ForkCondition {
-    /// The fork is activated after a certain block.
-    Block(BlockNumber),
-    /// The fork is activated after a total difficulty has been reached.
-    TTD {
-        /// The block number at which TTD is reached, if it is known.
-        ///
-        /// This should **NOT** be set unless you want this block advertised as [EIP-2124][eip2124]
-        /// `FORK_NEXT`. This is currently only the case for Sepolia and Holesky.
-        ///
-        /// [eip2124]: https://eips.ethereum.org/EIPS/eip-2124
-        fork_block: Option<BlockNumber>,
-        /// The total difficulty after which the fork is activated.
-        total_difficulty: U256,
-    },
-    /// The fork is activated after a specific timestamp.
-    Timestamp(u64),
-    /// The fork is never activated
-    #[default]
-    Never,
-}
-
-impl ForkCondition {
-    /// Returns true if the fork condition is timestamp based.
-    pub fn is_timestamp(&self) -> bool {
-        matches!(self, ForkCondition::Timestamp(_))
-    }
-
-    /// Checks whether the fork condition is satisfied at the given block.
-    ///
-    /// For TTD conditions, this will only return true if the activation block is already known.
-    ///
-    /// For timestamp conditions, this will always return false.
-    pub fn active_at_block(&self, current_block: BlockNumber) -> bool {
-        matches!(self, ForkCondition::Block(block)
-        | ForkCondition::TTD { fork_block: Some(block), .. } if current_block >= *block)
-    }
-
-    /// Checks if the given block is the first block that satisfies the fork condition.
-    ///
-    /// This will return false for any condition that is not block based.
-    pub fn transitions_at_block(&self, current_block: BlockNumber) -> bool {
-        matches!(self, ForkCondition::Block(block) if current_block == *block)
-    }
-
-    /// Checks whether the fork condition is satisfied at the given total difficulty and difficulty
-    /// of a current block.
-    ///
-    /// The fork is considered active if the _previous_ total difficulty is above the threshold.
-    /// To achieve that, we subtract the passed `difficulty` from the current block's total
-    /// difficulty, and check if it's above the Fork Condition's total difficulty (here:
-    /// 58_750_000_000_000_000_000_000)
-    ///
-    /// This will return false for any condition that is not TTD-based.
-    pub fn active_at_ttd(&self, ttd: U256, difficulty: U256) -> bool {
-        matches!(self, ForkCondition::TTD { total_difficulty, .. }
-            if ttd.saturating_sub(difficulty) >= *total_difficulty)
-    }
-
-    /// Checks whether the fork condition is satisfied at the given timestamp.
-    ///
-    /// This will return false for any condition that is not timestamp-based.
-    pub fn active_at_timestamp(&self, timestamp: u64) -> bool {
-        matches!(self, ForkCondition::Timestamp(time) if timestamp >= *time)
-    }
-
-    /// Checks whether the fork condition is satisfied at the given head block.
-    ///
-    /// This will return true if:
-    ///
-    /// - The condition is satisfied by the block number;
-    /// - The condition is satisfied by the timestamp;
-    /// - or the condition is satisfied by the total difficulty
-    pub fn active_at_head(&self, head: &Head) -> bool {
-        self.active_at_block(head.number) ||
-            self.active_at_timestamp(head.timestamp) ||
-            self.active_at_ttd(head.total_difficulty, head.difficulty)
-    }
-
-    /// Get the total terminal difficulty for this fork condition.
-    ///
-    /// Returns `None` for fork conditions that are not TTD based.
-    pub fn ttd(&self) -> Option<U256> {
-        match self {
-            ForkCondition::TTD { total_difficulty, .. } => Some(*total_difficulty),
-            _ => None,
-        }
-    }
-
-    /// Returns the timestamp of the fork condition, if it is timestamp based.
-    pub fn as_timestamp(&self) -> Option<u64> {
-        match self {
-            ForkCondition::Timestamp(timestamp) => Some(*timestamp),
-            _ => None,
+            custom_hardforks: value.custom_hardforks.clone(),
+            consensus: value.consensus,
+            system_contracts: value.system_contracts,
+            state_migrations: value.state_migrations.clone(),
+            deposit_contracts: value.deposit_contracts.clone(),
+            max_future_timestamp_drift: Some(value.max_future_timestamp_drift),
+            block_time: value.block_time,
+            max_code_size: Some(value.max_code_size),
+            max_init_code_size: Some(value.max_init_code_size),
+            max_tx_size: value.max_tx_size,
+            bootnodes: value.bootnodes.clone(),
+            trusted_peers: value.trusted_peers.clone(),
+            #[cfg(feature = "optimism")]
+            rollup: value.rollup,
+            #[cfg(feature = "polygon")]
+            bor: value.bor.clone(),
+            #[cfg(feature = "gnosis")]
+            gnosis: value.gnosis,
         }
     }
 }
@@ -1497,19 +4398,47 @@ impl ForkCondition {
 ///
 /// - Block and timestamp based forks are formatted in the same manner (`{name} <({eip})>
 ///   @{condition}`)
+/// - Epoch based forks are formatted as `{name} <({eip})> @{block} (epoch {epoch})`
+/// - Forks that require both a block and a timestamp are formatted as `{name} <({eip})>
+///   @{timestamp} (block {block})`
 /// - TTD based forks are formatted separately as `{name} <({eip})> @{ttd} (network is <not> known
 ///   to be merged)`
 ///
 /// An optional EIP can be attached to the fork to display as well. This should generally be in the
-/// form of just `EIP-x`, e.g. `EIP-1559`.
-#[derive(Debug)]
-struct DisplayFork {
+/// form of just `EIP-x`, e.g. `EIP-1559`. For [Hardfork]s this is the headline EIP of the fork, see
+/// [Hardfork::eip].
+///
+/// The fork serializes to a machine-readable object with the same information.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayFork {
     /// The name of the hardfork (e.g. Frontier)
-    name: String,
+    pub name: String,
     /// The fork condition
-    activated_at: ForkCondition,
+    pub activated_at: ForkCondition,
     /// An optional EIP (e.g. `EIP-1559`).
-    eip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eip: Option<String>,
+    /// Whether the network is known to be merged, only set for TTD based forks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub known_merged: Option<bool>,
+}
+
+impl DisplayFork {
+    /// Creates a new [`DisplayFork`] without an EIP.
+    fn new(name: String, activated_at: ForkCondition) -> Self {
+        let known_merged = match activated_at {
+            ForkCondition::TTD { fork_block, .. } => Some(fork_block.is_some()),
+            _ => None,
+        };
+        Self { name, activated_at, eip: None, known_merged }
+    }
+
+    /// Sets the EIP of the fork.
+    fn with_eip(mut self, eip: Option<&str>) -> Self {
+        self.eip = eip.map(String::from);
+        self
+    }
 }
 
 impl Display for DisplayFork {
@@ -1524,6 +4453,13 @@ impl Display for DisplayFork {
             ForkCondition::Block(at) | ForkCondition::Timestamp(at) => {
                 write!(f, "{:32} @{}", name_with_eip, at)?;
             }
+            ForkCondition::Epoch { epoch, .. } => {
+                let block = self.activated_at.as_block().unwrap_or_default();
+                write!(f, "{:32} @{} (epoch {})", name_with_eip, block, epoch)?;
+            }
+            ForkCondition::BlockAndTimestamp { block, timestamp } => {
+                write!(f, "{:32} @{} (block {})", name_with_eip, timestamp, block)?;
+            }
             ForkCondition::TTD { fork_block, total_difficulty } => {
                 writeln!(
                     f,
@@ -1558,26 +4494,34 @@ impl Display for DisplayFork {
 /// ```text
 /// Pre-merge hard forks (block based):
 // - Frontier                         @0
-// - Homestead                        @1150000
-// - Dao                              @1920000
-// - Tangerine                        @2463000
-// - SpuriousDragon                   @2675000
-// - Byzantium                        @4370000
-// - Constantinople                   @7280000
-// - Petersburg                       @7280000
-// - Istanbul                         @9069000
-// - MuirGlacier                      @9200000
-// - Berlin                           @12244000
-// - London                           @12965000
-// - ArrowGlacier                     @13773000
-// - GrayGlacier                      @15050000
+// - Homestead (EIP-2)                @1150000
+// - Dao (EIP-779)                    @1920000
+// - Tangerine (EIP-150)              @2463000
+// - SpuriousDragon (EIP-155)         @2675000
+// - Byzantium (EIP-140)              @4370000
+// - Constantinople (EIP-1014)        @7280000
+// - Petersburg (EIP-1716)            @7280000
+// - Istanbul (EIP-1884)              @9069000
+// - MuirGlacier (EIP-2384)           @9200000
+// - Berlin (EIP-2929)                @12244000
+// - London (EIP-1559)                @12965000
+// - ArrowGlacier (EIP-4345)          @13773000
+// - GrayGlacier (EIP-5133)           @15050000
 // Merge hard forks:
-// - Paris                            @58750000000000000000000 (network is known to be merged)
+// - Paris (EIP-3675)                 @58750000000000000000000 (network is known to be merged)
 //
 // Post-merge hard forks (timestamp based):
-// - Shanghai                         @1681338455
+// - Shanghai (EIP-4895)              @1681338455
+/// ```
+/// 
+/// The list also serializes to a machine-readable object, e.g. to print it as JSON:
+/// ```
+/// # use reth_primitives::MAINNET;
+/// let json = serde_json::to_string_pretty(&MAINNET.display_hardforks()).unwrap();
+/// assert!(json.contains("\"preMerge\""));
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DisplayHardforks {
     /// A list of pre-merge (block based) hardforks
     pre_merge: Vec<DisplayFork>,
@@ -1623,33 +4567,140 @@ impl DisplayHardforks {
         let mut post_merge = Vec::new();
 
         for (fork, condition) in hardforks {
-            let mut display_fork =
-                DisplayFork { name: fork.to_string(), activated_at: *condition, eip: None };
+            let display_fork = DisplayFork::new(fork.to_string(), *condition).with_eip(fork.eip());
 
             match condition {
-                ForkCondition::Block(_) => {
+                ForkCondition::Block(_) | ForkCondition::Epoch { .. } => {
                     pre_merge.push(display_fork);
                 }
                 ForkCondition::TTD { total_difficulty, .. } => {
-                    display_fork.activated_at = ForkCondition::TTD {
+                    let activated_at = ForkCondition::TTD {
                         fork_block: known_paris_block,
                         total_difficulty: *total_difficulty,
                     };
-                    with_merge.push(display_fork);
+                    with_merge.push(
+                        DisplayFork::new(display_fork.name, activated_at).with_eip(fork.eip()),
+                    );
                 }
-                ForkCondition::Timestamp(_) => {
+                ForkCondition::Timestamp(_) | ForkCondition::BlockAndTimestamp { .. } => {
                     post_merge.push(display_fork);
                 }
                 ForkCondition::Never => continue,
             }
         }
-
-        Self { pre_merge, with_merge, post_merge }
+
+        Self { pre_merge, with_merge, post_merge }
+    }
+
+    /// Inserts a fork that is not part of [Hardfork], e.g. a [ChainHardfork].
+    ///
+    /// Block and timestamp based forks are placed after all forks that activate at the same time or
+    /// earlier.
+    pub fn insert(
+        &mut self,
+        name: String,
+        condition: ForkCondition,
+        known_paris_block: Option<u64>,
+    ) {
+        let activation = |fork: &DisplayFork| match fork.activated_at {
+            ForkCondition::Block(at) |
+            ForkCondition::Timestamp(at) |
+            ForkCondition::BlockAndTimestamp { timestamp: at, .. } => at,
+            ForkCondition::Epoch { .. } => fork.activated_at.as_block().unwrap_or_default(),
+            _ => 0,
+        };
+
+        let display_fork = DisplayFork::new(name, condition);
+        match condition {
+            ForkCondition::Block(_) | ForkCondition::Epoch { .. } => {
+                let at = condition.as_block().unwrap_or_default();
+                let idx = self.pre_merge.partition_point(|fork| activation(fork) <= at);
+                self.pre_merge.insert(idx, display_fork);
+            }
+            ForkCondition::TTD { total_difficulty, .. } => {
+                let activated_at =
+                    ForkCondition::TTD { fork_block: known_paris_block, total_difficulty };
+                self.with_merge.push(DisplayFork::new(display_fork.name, activated_at));
+            }
+            ForkCondition::Timestamp(at) |
+            ForkCondition::BlockAndTimestamp { timestamp: at, .. } => {
+                let idx = self.post_merge.partition_point(|fork| activation(fork) <= at);
+                self.post_merge.insert(idx, display_fork);
+            }
+            ForkCondition::Never => {}
+        }
+    }
+}
+
+/// The consensus engine a chain uses to seal blocks before the merge.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ConsensusConfig {
+    /// No engine is configured, e.g. for chains that launched with PoS.
+    #[default]
+    None,
+    /// Proof-of-work using Ethash.
+    Ethash,
+    /// Proof-of-authority using Clique.
+    Clique {
+        /// The number of seconds between blocks.
+        period: u64,
+        /// The number of blocks after which votes are reset and a checkpoint is created.
+        epoch: u64,
+    },
+    /// Proof-of-staked-authority using Parlia, as run by BSC.
+    #[cfg(feature = "bsc")]
+    Parlia {
+        /// The number of seconds between blocks.
+        period: u64,
+        /// The number of blocks after which the validator set is updated.
+        epoch: u64,
+    },
+}
+
+impl ConsensusConfig {
+    /// The default Clique epoch length, used if the genesis config doesn't specify one.
+    pub const DEFAULT_CLIQUE_EPOCH: u64 = 30_000;
+
+    /// The default Parlia epoch length, used if the genesis config doesn't specify one.
+    #[cfg(feature = "bsc")]
+    pub const DEFAULT_PARLIA_EPOCH: u64 = 200;
+
+    /// Extracts the consensus engine from the `clique` or `ethash` section of a genesis config,
+    /// or from the `parlia` section if the `bsc` feature is enabled.
+    ///
+    /// If several sections are present, Parlia takes precedence over Clique, which takes
+    /// precedence over Ethash.
+    pub fn from_chain_config(config: &ChainConfig) -> Self {
+        #[cfg(feature = "bsc")]
+        if let Some(parlia) =
+            config.extra_fields.get(GENESIS_PARLIA_KEY).and_then(|p| p.as_object())
+        {
+            let field = |name: &str| parlia.get(name).and_then(|value| value.as_u64());
+            return ConsensusConfig::Parlia {
+                period: field("period").unwrap_or_default(),
+                epoch: field("epoch")
+                    .filter(|epoch| *epoch != 0)
+                    .unwrap_or(Self::DEFAULT_PARLIA_EPOCH),
+            }
+        }
+        if let Some(clique) = &config.clique {
+            return ConsensusConfig::Clique {
+                period: clique.period.unwrap_or_default(),
+                epoch: clique
+                    .epoch
+                    .filter(|epoch| *epoch != 0)
+                    .unwrap_or(Self::DEFAULT_CLIQUE_EPOCH),
+            }
+        }
+        if config.ethash.is_some() {
+            return ConsensusConfig::Ethash
+        }
+        ConsensusConfig::None
     }
 }
 
 /// PoS deposit contract details.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Compact)]
 pub struct DepositContract {
     /// Deposit Contract Address
     pub address: Address,
@@ -1660,19 +4711,132 @@ pub struct DepositContract {
 }
 
 impl DepositContract {
-    fn new(address: Address, block: BlockNumber, topic: B256) -> Self {
+    /// The signature of the `DepositEvent` of the canonical deposit contract.
+    pub const DEPOSIT_EVENT_TOPIC: B256 =
+        b256!("649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5");
+
+    /// Creates a new deposit contract.
+    pub fn new(address: Address, block: BlockNumber, topic: B256) -> Self {
         DepositContract { address, block, topic }
     }
+
+    /// Reads the deposit contract from the `depositContractAddress` field of a genesis chain
+    /// config.
+    ///
+    /// The contract is expected to be part of the genesis allocation and to emit the canonical
+    /// `DepositEvent`. Returns `None` if the chain config has no deposit contract address, and an
+    /// error if the address is invalid.
+    pub fn from_chain_config(config: &ChainConfig) -> Result<Option<Self>, GenesisConfigError> {
+        let Some(address) = config.extra_fields.get(GENESIS_DEPOSIT_CONTRACT_KEY) else {
+            return Ok(None)
+        };
+        let address = Address::deserialize(address)
+            .map_err(|err| GenesisConfigError::new(GENESIS_DEPOSIT_CONTRACT_KEY, err))?;
+        Ok(Some(Self::new(address, 0, Self::DEPOSIT_EVENT_TOPIC)))
+    }
+
+    /// Finds the canonical deposit contract in a genesis allocation.
+    ///
+    /// An account is considered to be the deposit contract if its code dispatches the
+    /// `deposit(bytes,bytes,bytes,bytes32)` function and emits the canonical `DepositEvent`, which
+    /// is how devnet genesis files predeploy it. If several accounts match, the lowest address is
+    /// used.
+    pub fn from_genesis_alloc(genesis: &Genesis) -> Option<Self> {
+        genesis
+            .alloc
+            .iter()
+            .filter(|(_, account)| {
+                account.code.as_ref().is_some_and(|code| Self::is_deposit_contract_code(code))
+            })
+            .map(|(address, _)| *address)
+            .min()
+            .map(|address| Self::new(address, 0, Self::DEPOSIT_EVENT_TOPIC))
+    }
+
+    /// Returns `true` if the log was emitted by the contract and carries its `DepositEvent`
+    /// signature, see [DepositEvent](crate::DepositEvent) for decoding it.
+    pub fn matches(&self, log: &Log) -> bool {
+        log.address == self.address && log.topics.first() == Some(&self.topic)
+    }
+
+    /// Returns `true` if the runtime code looks like the canonical deposit contract.
+    fn is_deposit_contract_code(code: &[u8]) -> bool {
+        // `PUSH4 <deposit selector>` of the function dispatcher
+        const DEPOSIT_SELECTOR: [u8; 5] = [0x63, 0x22, 0x89, 0x51, 0x18];
+
+        let mut event_topic = [0x7f; 33];
+        event_topic[1..].copy_from_slice(Self::DEPOSIT_EVENT_TOPIC.as_slice());
+
+        let contains = |needle: &[u8]| code.windows(needle.len()).any(|window| window == needle);
+        contains(&DEPOSIT_SELECTOR) && contains(&event_topic)
+    }
+}
+
+/// The addresses of the protocol system contracts, which are called by the system at the start or
+/// end of a block.
+///
+/// These default to the canonical addresses of the EIPs. Custom chains that deploy them elsewhere
+/// can override single addresses in the `systemContracts` field of their genesis chain config, e.g.
+/// `"systemContracts": { "beaconRoots": "0x..." }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct SystemContracts {
+    /// The EIP-4788 beacon roots contract
+    pub beacon_roots: Address,
+    /// The EIP-2935 history storage contract
+    pub history_storage: Address,
+    /// The EIP-7002 withdrawal request contract
+    pub withdrawal_requests: Address,
+    /// The EIP-7251 consolidation request contract
+    pub consolidation_requests: Address,
+}
+
+impl SystemContracts {
+    /// Reads the system contract overrides from the `systemContracts` field of a genesis chain
+    /// config. Contracts that are not overridden keep their canonical address.
+    ///
+    /// Returns `None` if the chain config has no system contract overrides, and an error if the
+    /// overrides are malformed or name an unknown contract.
+    pub fn from_chain_config(config: &ChainConfig) -> Result<Option<Self>, GenesisConfigError> {
+        let Some(contracts) = config.extra_fields.get(GENESIS_SYSTEM_CONTRACTS_KEY) else {
+            return Ok(None)
+        };
+        SystemContracts::deserialize(contracts)
+            .map(Some)
+            .map_err(|err| GenesisConfigError::new(GENESIS_SYSTEM_CONTRACTS_KEY, err))
+    }
+}
+
+impl Default for SystemContracts {
+    fn default() -> Self {
+        Self {
+            beacon_roots: BEACON_ROOTS_ADDRESS,
+            history_storage: HISTORY_STORAGE_ADDRESS,
+            withdrawal_requests: WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS,
+            consolidation_requests: CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{b256, hex, trie::TrieAccount, ChainConfig, GenesisAccount};
+    use crate::{
+        b256, constants::DEV_CANCUN_GENESIS_HASH, hex, trie::TrieAccount, Bytes, ChainConfig,
+        GenesisAccount,
+    };
     use alloy_rlp::Encodable;
     use bytes::BytesMut;
     use std::{collections::HashMap, str::FromStr};
 
+    /// Returns a genesis with the given JSON chain config and an empty allocation.
+    fn genesis_with_config(config: &str) -> Genesis {
+        let s = format!(
+            r#"{{"config":{config},"nonce":"0x0","timestamp":"0x0","extraData":"0x","gasLimit":"0x4c4b40","difficulty":"0x1","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","coinbase":"0x0000000000000000000000000000000000000000","alloc":{{}}}}"#
+        );
+        serde_json::from_str(&s).unwrap()
+    }
+
     fn test_fork_ids(spec: &ChainSpec, cases: &[(Head, ForkId)]) {
         for (block, expected_id) in cases {
             let computed_id = spec.fork_id(block);
@@ -1713,29 +4877,49 @@ mod tests {
             MAINNET.display_hardforks().to_string(),
             "Pre-merge hard forks (block based):
 - Frontier                         @0
-- Homestead                        @1150000
-- Dao                              @1920000
-- Tangerine                        @2463000
-- SpuriousDragon                   @2675000
-- Byzantium                        @4370000
-- Constantinople                   @7280000
-- Petersburg                       @7280000
-- Istanbul                         @9069000
-- MuirGlacier                      @9200000
-- Berlin                           @12244000
-- London                           @12965000
-- ArrowGlacier                     @13773000
-- GrayGlacier                      @15050000
+- Homestead (EIP-2)                @1150000
+- Dao (EIP-779)                    @1920000
+- Tangerine (EIP-150)              @2463000
+- SpuriousDragon (EIP-155)         @2675000
+- Byzantium (EIP-140)              @4370000
+- Constantinople (EIP-1014)        @7280000
+- Petersburg (EIP-1716)            @7280000
+- Istanbul (EIP-1884)              @9069000
+- MuirGlacier (EIP-2384)           @9200000
+- Berlin (EIP-2929)                @12244000
+- London (EIP-1559)                @12965000
+- ArrowGlacier (EIP-4345)          @13773000
+- GrayGlacier (EIP-5133)           @15050000
 Merge hard forks:
-- Paris                            @58750000000000000000000 (network is known to be merged)
+- Paris (EIP-3675)                 @58750000000000000000000 (network is known to be merged)
 
 Post-merge hard forks (timestamp based):
-- Shanghai                         @1681338455
-- Cancun                           @1710338135
+- Shanghai (EIP-4895)              @1681338455
+- Cancun (EIP-4844)                @1710338135
 "
         );
     }
 
+    #[test]
+    fn test_hardfork_list_json_mainnet() {
+        let json = serde_json::to_value(MAINNET.display_hardforks()).unwrap();
+        assert_eq!(
+            json["preMerge"][0],
+            serde_json::json!({ "name": "Frontier", "activatedAt": { "Block": 0 } })
+        );
+        assert_eq!(json["preMerge"].as_array().unwrap().len(), 14);
+        assert_eq!(json["withMerge"][0]["name"], "Paris");
+        assert_eq!(json["withMerge"][0]["knownMerged"], true);
+        assert_eq!(
+            json["postMerge"][0],
+            serde_json::json!({
+                "name": "Shanghai",
+                "activatedAt": { "Timestamp": 1681338455 },
+                "eip": "EIP-4895"
+            })
+        );
+    }
+
     #[test]
     fn test_hardfork_list_ignores_disabled_forks() {
         let spec = ChainSpec::builder()
@@ -1752,33 +4936,482 @@ Post-merge hard forks (timestamp based):
         );
     }
 
-    // Tests that the ForkTimestamps are correctly set up.
+    #[test]
+    fn test_custom_hardforks() {
+        #[derive(Debug)]
+        struct MyChainV2;
+
+        impl ChainHardfork for MyChainV2 {
+            fn name(&self) -> &str {
+                "MyChainV2"
+            }
+        }
+
+        let builder = ChainSpec::builder()
+            .chain(Chain::mainnet())
+            .genesis(Genesis::default())
+            .with_fork(Hardfork::Frontier, ForkCondition::Block(0))
+            .with_fork(Hardfork::Homestead, ForkCondition::Block(10))
+            .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(1337));
+        let spec = builder
+            .clone()
+            .with_custom_fork(MyChainV2, ForkCondition::Block(5))
+            .with_custom_fork(MyChainV2, ForkCondition::Timestamp(2000))
+            .build();
+        // the same schedule using a built-in fork in place of the custom one
+        let expected = builder.with_fork(Hardfork::Cancun, ForkCondition::Timestamp(2000)).build();
+
+        assert_eq!(spec.custom_hardforks().len(), 1);
+        assert_eq!(spec.custom_fork("MyChainV2"), ForkCondition::Timestamp(2000));
+        assert_eq!(spec.custom_fork("MyChainV3"), ForkCondition::Never);
+
+        for head in [
+            Head { number: 0, timestamp: 0, ..Default::default() },
+            Head { number: 10, timestamp: 1337, ..Default::default() },
+            Head { number: 10, timestamp: 2000, ..Default::default() },
+        ] {
+            assert_eq!(spec.fork_id(&head), expected.fork_id(&head));
+            assert_eq!(spec.fork_filter(head), expected.fork_filter(head));
+        }
+
+        assert_eq!(
+            spec.display_hardforks().to_string(),
+            "Pre-merge hard forks (block based):
+- Frontier                         @0
+- Homestead (EIP-2)                @10
+Post-merge hard forks (timestamp based):
+- Shanghai (EIP-4895)              @1337
+- MyChainV2                        @2000
+"
+        );
+    }
+
+    #[test]
+    fn test_block_and_timestamp_fork_condition() {
+        let condition = ForkCondition::BlockAndTimestamp { block: 20, timestamp: 2000 };
+        assert!(!condition.active_at_block(20));
+        assert!(!condition.active_at_timestamp(2000));
+        assert!(!condition.active_at_block_and_timestamp(19, 2000));
+        assert!(!condition.active_at_block_and_timestamp(20, 1999));
+        assert!(condition.active_at_block_and_timestamp(20, 2000));
+        let head = Head { number: 21, timestamp: 2001, ..Default::default() };
+        assert!(condition.active_at_head(&head));
+
+        let builder = ChainSpec::builder()
+            .chain(Chain::mainnet())
+            .genesis(Genesis::default())
+            .with_fork(Hardfork::Frontier, ForkCondition::Block(0))
+            .with_fork(Hardfork::Homestead, ForkCondition::Block(10))
+            .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(1337));
+        let spec =
+            builder.clone().with_custom_fork(GenesisHardfork::new("myFork"), condition).build();
+        // the fork is advertised by its timestamp
+        let expected = builder.with_fork(Hardfork::Cancun, ForkCondition::Timestamp(2000)).build();
+
+        for head in [
+            Head { number: 0, timestamp: 0, ..Default::default() },
+            Head { number: 10, timestamp: 1337, ..Default::default() },
+            Head { number: 20, timestamp: 2000, ..Default::default() },
+        ] {
+            assert_eq!(spec.fork_id(&head), expected.fork_id(&head));
+            assert_eq!(spec.fork_filter(head).current(), expected.fork_filter(head).current());
+        }
+
+        // the timestamp is reached, but not the block, so the block is advertised as next
+        let head = Head { number: 19, timestamp: 2000, ..Default::default() };
+        let pending = ForkId {
+            hash: expected
+                .fork_id(&Head { number: 19, timestamp: 1999, ..Default::default() })
+                .hash,
+            next: 20,
+        };
+        assert_eq!(spec.fork_id(&head), pending);
+        assert_eq!(spec.fork_filter(head).current(), pending);
+        assert_eq!(
+            spec.fork_id_table().activation_heads().collect::<Vec<_>>(),
+            vec![
+                Head::default(),
+                Head { number: 10, ..Default::default() },
+                Head { number: 10, timestamp: 1337, ..Default::default() },
+                Head { number: 20, timestamp: 2000, ..Default::default() },
+            ]
+        );
+
+        assert_eq!(
+            spec.satisfy(condition),
+            Head { number: 20, timestamp: 2000, ..Default::default() }
+        );
+
+        assert_eq!(
+            spec.display_hardforks().to_string(),
+            "Pre-merge hard forks (block based):
+- Frontier                         @0
+- Homestead (EIP-2)                @10
+Post-merge hard forks (timestamp based):
+- Shanghai (EIP-4895)              @1337
+- myFork                           @2000 (block 20)
+"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Shanghai activates at block 20 and timestamp 2000, it can't be checked by timestamp alone"
+    )]
+    fn test_block_and_timestamp_fork_by_timestamp() {
+        let spec = ChainSpec::builder()
+            .chain(Chain::mainnet())
+            .genesis(Genesis::default())
+            .with_fork(Hardfork::Frontier, ForkCondition::Block(0))
+            .with_fork(
+                Hardfork::Shanghai,
+                ForkCondition::BlockAndTimestamp { block: 20, timestamp: 2000 },
+            )
+            .build();
+        spec.is_shanghai_active_at_timestamp(2000);
+    }
+
+    #[test]
+    fn test_fork_id_table() {
+        // the merge netsplit block of sepolia is applied once the total difficulty is reached
+        let netsplit = Head { number: 1735371, ..Default::default() };
+        let ttd_reached = Head {
+            number: 1450409,
+            total_difficulty: U256::from(17_000_018_015_853_232u128),
+            difficulty: U256::from(1),
+            ..Default::default()
+        };
+        assert_eq!(SEPOLIA.fork_id(&ttd_reached), SEPOLIA.fork_id(&netsplit));
+        assert_ne!(
+            SEPOLIA.fork_id(&Head { number: 1450409, ..Default::default() }),
+            SEPOLIA.fork_id(&netsplit)
+        );
+
+        let mut spec = ChainSpecBuilder::mainnet().build();
+        let head = Head { number: 12965000, ..Default::default() };
+        let london = spec.fork_id(&head);
+
+        // modifying the hardforks invalidates the cached table
+        spec.remove_hardfork(Hardfork::London);
+        assert_ne!(spec.fork_id(&head), london);
+        assert_eq!(spec.fork_id(&head), ForkIdTable::new(&spec).fork_id(&head));
+    }
+
+    #[test]
+    fn validate_fork_id() {
+        let shanghai = MAINNET.fork(Hardfork::Shanghai).as_timestamp().unwrap();
+        let cancun = MAINNET.fork(Hardfork::Cancun).as_timestamp().unwrap();
+        let gray_glacier = MAINNET.fork_id(&Head { number: 15050000, ..Default::default() });
+        let head = Head { number: 17034870, timestamp: shanghai, ..Default::default() };
+        let local = MAINNET.fork_id(&head);
+        let future = MAINNET.fork_id(&Head { timestamp: cancun, ..head });
+
+        let fork_ids = MAINNET.fork_id_table().fork_ids().collect::<Vec<_>>();
+        assert_eq!(fork_ids[0], ForkId { hash: ForkHash(hex!("fc64ec04")), next: 1150000 });
+        assert_eq!(fork_ids.last().unwrap().next, 0);
+        assert!(fork_ids.contains(&gray_glacier) && fork_ids.contains(&local));
+
+        // same fork, the remote fork is either unknown or not passed yet
+        for remote in [ForkId { next: 0, ..local }, local, ForkId { next: u64::MAX, ..local }] {
+            assert_eq!(MAINNET.validate_fork_id(&head, remote), ForkIdValidation::Compatible);
+        }
+        // remote is out of sync but knows the following fork, or is ahead of the local head
+        for remote in [gray_glacier, future] {
+            assert_eq!(MAINNET.validate_fork_id(&head, remote), ForkIdValidation::Compatible);
+        }
+
+        // remote doesn't know about shanghai
+        let remote = ForkId { next: 0, ..gray_glacier };
+        assert_eq!(
+            MAINNET.validate_fork_id(&head, remote),
+            ForkIdValidation::RemoteStale { local, remote }
+        );
+        // remote announces a fork that already passed locally
+        let remote = ForkId { next: shanghai, ..local };
+        assert_eq!(
+            MAINNET.validate_fork_id(&head, remote),
+            ForkIdValidation::LocalIncompatibleOrStale { local, remote }
+        );
+        // remote is on another chain
+        let remote = SEPOLIA.fork_id(&head);
+        assert!(!MAINNET.validate_fork_id(&head, remote).is_compatible());
+        assert_eq!(
+            MAINNET.fork_filter(head).validate(remote),
+            MAINNET.validate_fork_id(&head, remote).into_result()
+        );
+    }
+
+    #[test]
+    fn builder_extra_alloc() {
+        let funded = GenesisAccount::default().with_balance(U256::from(1));
+        let spec = ChainSpecBuilder::mainnet()
+            .with_extra_alloc([(Address::repeat_byte(0xaa), funded.clone())])
+            .build();
+        assert_eq!(spec.genesis.alloc.len(), MAINNET.genesis.alloc.len() + 1);
+        assert_eq!(spec.genesis.alloc.get(&Address::repeat_byte(0xaa)), Some(&funded));
+        assert_ne!(spec.genesis_hash(), MAINNET.genesis_hash());
+    }
+
+    #[test]
+    fn observed_paris_block() {
+        let mut spec = ChainSpecBuilder::mainnet().paris_at_ttd(U256::from(100)).build();
+        assert_eq!(spec.final_paris_total_difficulty(10), None);
+
+        spec.set_observed_paris_block(10, U256::from(105));
+        assert_eq!(spec.paris_block_and_final_difficulty, Some((10, U256::from(105))));
+        assert_eq!(spec.final_paris_total_difficulty(9), None);
+        assert_eq!(spec.final_paris_total_difficulty(10), Some(U256::from(105)));
+
+        // a known paris block is not overridden
+        spec.set_observed_paris_block(11, U256::from(110));
+        assert_eq!(spec.paris_block_and_final_difficulty, Some((10, U256::from(105))));
+    }
+
+    #[test]
+    fn genesis_header_is_cached() {
+        let mut spec = ChainSpecBuilder::mainnet()
+            .genesis(Genesis::default().extend_accounts([(
+                Address::with_last_byte(1),
+                GenesisAccount::default().with_balance(U256::from(1)),
+            )]))
+            .build();
+        assert!(spec.sealed_genesis_header.get().is_none());
+
+        let hash = spec.genesis_hash();
+        let header = spec.sealed_genesis_header.get().unwrap().clone();
+        assert_eq!(header.hash(), hash);
+        assert_eq!(header.hash(), header.header().hash_slow());
+        assert_eq!(spec.sealed_genesis_header(), header);
+
+        // the cached header is used until it is invalidated
+        spec.genesis.alloc.clear();
+        assert_eq!(spec.genesis_hash(), hash);
+        spec.invalidate_fork_caches();
+        assert_ne!(spec.genesis_hash(), hash);
+        assert_eq!(spec.genesis_header().state_root, crate::constants::EMPTY_ROOT_HASH);
+    }
+
+    #[test]
+    #[cfg(feature = "c-kzg")]
+    fn kzg_settings_are_cached() {
+        use crate::constants::eip4844::MAINNET_KZG_TRUSTED_SETUP;
+
+        let mut spec = ChainSpec::default();
+        assert!(spec.kzg_settings_cache.get().is_none());
+        assert!(Arc::ptr_eq(&spec.kzg_settings().unwrap(), &MAINNET_KZG_TRUSTED_SETUP));
+
+        // the cached setup is used until it is invalidated
+        spec.kzg_settings = KzgSettingsSource::File("/does/not/exist.txt".into());
+        assert!(spec.kzg_settings().is_ok());
+        spec.invalidate_fork_caches();
+        assert!(spec.kzg_settings().is_err());
+        assert!(spec.kzg_settings_cache.get().is_none());
+
+        let mut json = serde_json::to_value(&spec).unwrap();
+        assert_eq!(json["kzg_settings"], serde_json::json!({ "file": "/does/not/exist.txt" }));
+        json["kzg_settings"] = "embedded".into();
+        let spec: ChainSpec = serde_json::from_value(json).unwrap();
+        assert_eq!(spec.kzg_settings, KzgSettingsSource::Embedded);
+    }
+
+    #[test]
+    fn test_epoch_fork_condition() {
+        let condition = ForkCondition::Epoch { epoch_length: 30000, epoch: 2 };
+        assert_eq!(condition.as_block(), Some(60000));
+        assert!(!condition.active_at_block(59999));
+        assert!(condition.active_at_block(60000));
+        assert!(condition.transitions_at_block(60000));
+        assert!(!condition.transitions_at_block(60001));
+        assert!(!condition.transitions_at_timestamp(60000, 59999));
+
+        let builder = ChainSpec::builder()
+            .chain(Chain::mainnet())
+            .genesis(Genesis::default())
+            .with_fork(Hardfork::Frontier, ForkCondition::Block(0))
+            .with_fork(Hardfork::Homestead, ForkCondition::Block(10));
+        let spec = builder.clone().with_fork(Hardfork::Tangerine, condition).build();
+        let expected = builder.with_fork(Hardfork::Tangerine, ForkCondition::Block(60000)).build();
+
+        for number in [0, 10, 59999, 60000] {
+            let head = Head { number, ..Default::default() };
+            assert_eq!(spec.fork_id(&head), expected.fork_id(&head));
+            assert_eq!(spec.fork_filter(head), expected.fork_filter(head));
+        }
+        assert_eq!(
+            spec.hardfork_fork_id(Hardfork::Tangerine),
+            expected.hardfork_fork_id(Hardfork::Tangerine)
+        );
+
+        // the condition is part of the serialized chainspec
+        let serialized = serde_json::to_string(&condition).unwrap();
+        assert_eq!(serialized, r#"{"Epoch":{"epoch_length":30000,"epoch":2}}"#);
+        assert_eq!(serde_json::from_str::<ForkCondition>(&serialized).unwrap(), condition);
+
+        assert_eq!(
+            spec.display_hardforks().to_string(),
+            "Pre-merge hard forks (block based):
+- Frontier                         @0
+- Homestead (EIP-2)                @10
+- Tangerine (EIP-150)              @60000 (epoch 2)
+"
+        );
+    }
+
+    // Tests that the ForkActivationCache is correctly set up.
     #[test]
     fn test_fork_timestamps() {
         let spec = ChainSpec::builder().chain(Chain::mainnet()).genesis(Genesis::default()).build();
-        assert!(spec.fork_timestamps.shanghai.is_none());
+        assert!(spec.fork_activations().timestamp(Hardfork::Shanghai).is_none());
 
         let spec = ChainSpec::builder()
             .chain(Chain::mainnet())
             .genesis(Genesis::default())
             .with_fork(Hardfork::Shanghai, ForkCondition::Timestamp(1337))
             .build();
-        assert_eq!(spec.fork_timestamps.shanghai, Some(1337));
+        assert_eq!(spec.fork_activations().timestamp(Hardfork::Shanghai), Some(1337));
         assert!(spec.is_shanghai_active_at_timestamp(1337));
         assert!(!spec.is_shanghai_active_at_timestamp(1336));
     }
 
+    #[test]
+    fn test_osaka_fork_timestamp() {
+        let spec = ChainSpec::builder()
+            .chain(Chain::mainnet())
+            .genesis(Genesis::default())
+            .with_fork(Hardfork::Prague, ForkCondition::Timestamp(1000))
+            .with_fork(Hardfork::Osaka, ForkCondition::Timestamp(2000))
+            .build();
+        assert_eq!(spec.fork_activations().timestamp(Hardfork::Osaka), Some(2000));
+        assert!(spec.is_prague_active_at_timestamp(1999));
+        assert!(!spec.is_osaka_active_at_timestamp(1999));
+        assert!(spec.is_osaka_active_at_timestamp(2000));
+
+        let spec = ChainSpecBuilder::mainnet().osaka_activated().build();
+        assert!(spec.is_prague_active_at_timestamp(0));
+        assert!(spec.is_osaka_active_at_timestamp(0));
+
+        // the activation is read from the extra fields of the genesis config
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"shanghaiTime":0,"cancunTime":0,"pragueTime":1000,"osakaTime":2000}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(spec.fork(Hardfork::Osaka), ForkCondition::Timestamp(2000));
+        assert!(spec.custom_hardforks().is_empty());
+        assert_eq!(
+            spec.to_geth_genesis().config.extra_fields.get("osakaTime"),
+            Some(&serde_json::json!(2000))
+        );
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_op_fork_predicates() {
+        assert!(!OP_GOERLI.is_bedrock_active_at_block(4061223));
+        assert!(OP_GOERLI.is_bedrock_active_at_block(4061224));
+        assert!(!OP_GOERLI.is_regolith_active_at_timestamp(1679079599));
+        assert!(OP_GOERLI.is_regolith_active_at_timestamp(1679079600));
+        assert!(!OP_GOERLI.is_canyon_active_at_timestamp(1699981199));
+        assert!(OP_GOERLI.is_canyon_active_at_timestamp(1699981200));
+        assert!(!OP_GOERLI.is_ecotone_active_at_timestamp(1707238799));
+        assert!(OP_GOERLI.is_ecotone_active_at_timestamp(1707238800));
+        assert_eq!(OP_GOERLI.fork_activations().timestamp(Hardfork::Ecotone), Some(1707238800));
+    }
+
+    #[cfg(feature = "polygon")]
+    #[test]
+    fn test_polygon_genesis_hardforks() {
+        let genesis = genesis_with_config(
+            r#"{"chainId":137,"londonBlock":23850000,"shanghaiBlock":50523000,"cancunBlock":54876000,"bor":{"jaipurBlock":23850000,"delhiBlock":38189056,"bhilaiBlock":73440256,"period":{"0":2},"producerDelay":{"0":6,"38189056":4},"sprint":{"0":64,"38189056":16},"backupMultiplier":{"0":2}}}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(spec.fork(Hardfork::Jaipur), ForkCondition::Block(23850000));
+        assert_eq!(spec.fork(Hardfork::Delhi), ForkCondition::Block(38189056));
+        assert_eq!(spec.fork(Hardfork::Bhilai), ForkCondition::Block(73440256));
+        assert_eq!(spec.fork(Hardfork::Shanghai), ForkCondition::Block(50523000));
+        assert_eq!(spec.fork(Hardfork::Agra), ForkCondition::Block(50523000));
+        assert_eq!(spec.fork(Hardfork::Cancun), ForkCondition::Block(54876000));
+        assert_eq!(spec.fork(Hardfork::Napoli), ForkCondition::Block(54876000));
+        assert!(spec.custom_hardforks.is_empty());
+
+        let bor = spec.bor_config().unwrap();
+        assert_eq!(bor.sprint_size(38189055), 64);
+        assert_eq!(bor.sprint_size(38189056), 16);
+
+        let spec = ChainSpec {
+            base_fee_params: BaseFeeParamsKind::Variable(
+                vec![
+                    (Hardfork::London, BaseFeeParams::ethereum()),
+                    (Hardfork::Delhi, BaseFeeParams::polygon_delhi()),
+                    (Hardfork::Bhilai, BaseFeeParams::polygon_bhilai()),
+                ]
+                .into(),
+            ),
+            ..spec
+        };
+        assert_eq!(spec.base_fee_params_at_block(38189055), BaseFeeParams::ethereum());
+        assert_eq!(spec.base_fee_params_at_block(38189056), BaseFeeParams::polygon_delhi());
+        assert_eq!(spec.base_fee_params_at_block(73440256), BaseFeeParams::polygon_bhilai());
+    }
+
+    #[test]
+    fn test_genesis_state_migrations() {
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"londonBlock":5,"stateMigrations":{"london":[{"address":"0x0000000000000000000000000000000000000100","code":"0x6000"}]}}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+
+        assert_eq!(spec.state_migrations_at(4, 0, 0).count(), 0);
+        assert_eq!(
+            spec.state_migrations_at(5, 0, 0).collect::<Vec<_>>(),
+            vec![&ForkStateMigration::new(
+                address!("0000000000000000000000000000000000000100"),
+                Bytes::from_static(&[0x60, 0x00])
+            )]
+        );
+        assert_eq!(spec.state_migrations_at(6, 0, 0).count(), 0);
+        assert!(MAINNET.state_migrations.is_empty());
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_op_state_migrations() {
+        let canyon = 1704992401;
+        let create2_deployer = ForkStateMigration::create2_deployer();
+        assert_eq!(BASE_MAINNET.state_migrations_at(0, canyon - 1, canyon - 3).count(), 0);
+        assert_eq!(
+            BASE_MAINNET.state_migrations_at(0, canyon, canyon - 2).collect::<Vec<_>>(),
+            vec![&create2_deployer]
+        );
+        assert_eq!(BASE_MAINNET.state_migrations_at(0, canyon + 2, canyon).count(), 0);
+
+        // custom OP chains get the create2 deployer unless they declare their own migrations
+        let spec = ChainSpec::try_from(genesis_with_config(
+            r#"{"chainId":1337,"bedrockBlock":0,"canyonTime":10}"#,
+        ))
+        .unwrap();
+        assert_eq!(spec.state_migrations_at(0, 10, 8).collect::<Vec<_>>(), vec![&create2_deployer]);
+    }
+
     // Tests that all predefined timestamps are correctly set up in the chainspecs
     #[test]
     fn test_predefined_chain_spec_fork_timestamps() {
-        let predefined = [&MAINNET, &SEPOLIA, &HOLESKY, &GOERLI];
+        let predefined = [
+            &MAINNET,
+            &SEPOLIA,
+            &HOLESKY,
+            #[cfg(feature = "goerli")]
+            &GOERLI,
+        ];
 
         for spec in predefined.iter() {
-            let expected_timestamp_forks = &spec.fork_timestamps;
-            let got_timestamp_forks = ForkTimestamps::from_hardforks(&spec.hardforks);
-
-            // make sure they're the same
-            assert_eq!(expected_timestamp_forks, &got_timestamp_forks);
+            let activations = spec.fork_activations();
+            for (fork, condition) in &spec.hardforks {
+                assert_eq!(activations.timestamp(*fork), condition.as_timestamp());
+            }
+            assert_eq!(
+                activations.iter().count(),
+                spec.hardforks.values().filter(|c| c.as_timestamp().is_some()).count()
+            );
         }
     }
 
@@ -2008,6 +5641,7 @@ Post-merge hard forks (timestamp based):
     }
 
     #[test]
+    #[cfg(feature = "goerli")]
     fn goerli_hardfork_fork_ids() {
         test_hardfork_fork_ids(
             &GOERLI,
@@ -2234,6 +5868,7 @@ Post-merge hard forks (timestamp based):
     }
 
     #[test]
+    #[cfg(feature = "goerli")]
     fn goerli_forkids() {
         test_fork_ids(
             &GOERLI,
@@ -2341,6 +5976,27 @@ Post-merge hard forks (timestamp based):
         )
     }
 
+    #[test]
+    fn dev_cancun_forkids() {
+        test_fork_ids(
+            &DEV_CANCUN,
+            &[(
+                Head { number: 0, ..Default::default() },
+                ForkId { hash: ForkHash([0xd5, 0xbc, 0xa3, 0xa4]), next: 0 },
+            )],
+        )
+    }
+
+    #[test]
+    fn dev_cancun_genesis_header() {
+        let header = DEV_CANCUN.build_genesis_header();
+        assert_eq!(header.hash_slow(), DEV_CANCUN_GENESIS_HASH);
+        assert_eq!(header.parent_beacon_block_root, Some(B256::ZERO));
+        assert_eq!(header.blob_gas_used, Some(0));
+        assert_eq!(header.excess_blob_gas, Some(0));
+        assert_eq!(header.state_root, DEV.genesis_header().state_root);
+    }
+
     #[cfg(feature = "optimism")]
     #[test]
     fn optimism_goerli_forkids() {
@@ -2421,6 +6077,29 @@ Post-merge hard forks (timestamp based):
         );
     }
 
+    #[cfg(feature = "bsc")]
+    #[test]
+    fn test_bsc_spec() {
+        let genesis = genesis_with_config(
+            r#"{"chainId":56,"londonBlock":31302048,"parlia":{"period":3,"epoch":200}}"#,
+        );
+        let spec = ChainSpec {
+            custom_hardforks: crate::BscHardfork::bsc_mainnet(),
+            ..ChainSpec::try_from(genesis).unwrap()
+        };
+        assert!(spec.is_parlia());
+        assert_eq!(spec.parlia_epoch(), Some(ConsensusConfig::DEFAULT_PARLIA_EPOCH));
+        assert_eq!(spec.consensus(), ConsensusConfig::from_chain_config(&spec.genesis.config));
+        assert_eq!(spec.custom_hardforks().len(), 22);
+        assert_eq!(spec.custom_fork("Luban"), ForkCondition::Block(29020050));
+
+        // the base fee stays zero once London is active
+        let parent = Header { number: 31302047, gas_used: 100_000_000, ..Default::default() };
+        assert_eq!(spec.next_block_base_fee(&parent, 0), Some(0));
+        let parent = Header { number: 31302046, ..Default::default() };
+        assert_eq!(spec.next_block_base_fee(&parent, 0), None);
+    }
+
     /// Checks that time-based forks work
     ///
     /// This is based off of the test vectors here: https://github.com/ethereum/go-ethereum/blob/5c8cc10d1e05c23ff1108022f4150749e73c0ca1/core/forkid/forkid_test.go#L155-L188
@@ -2629,6 +6308,122 @@ Post-merge hard forks (timestamp based):
             .active_at_ttd(first_pos_block_ttd, first_pos_difficulty));
     }
 
+    #[test]
+    fn terminal_pow_block() {
+        let chainspec = ChainSpecBuilder::mainnet().build();
+        let ttd = U256::from(58_750_000_000_000_000_000_000_u128);
+        assert_eq!(chainspec.terminal_total_difficulty(), Some(ttd));
+
+        // terminal PoW block #15537393
+        let terminal =
+            Header { difficulty: U256::from(11055787484078698_u128), ..Default::default() };
+        let terminal_td = U256::from(58750003716598352816469_u128);
+        let parent_td = terminal_td - terminal.difficulty;
+        assert!(chainspec.is_terminal_pow_block(&terminal, parent_td));
+        assert!(!chainspec.is_paris_active_at_ttd(terminal_td, terminal.difficulty));
+
+        // its parent and the first PoS block #15537394 are not terminal blocks
+        let parent = Header { difficulty: U256::from(1), ..Default::default() };
+        assert!(!chainspec.is_terminal_pow_block(&parent, parent_td - parent.difficulty));
+        assert!(!chainspec.is_terminal_pow_block(&Header::default(), terminal_td));
+        assert!(chainspec.is_paris_active_at_ttd(terminal_td, U256::ZERO));
+
+        // a chain that passed the ttd before genesis without configuring it
+        let mut genesis = Genesis::default();
+        genesis.config.terminal_total_difficulty_passed = true;
+        let chainspec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(chainspec.terminal_total_difficulty(), Some(U256::ZERO));
+        assert!(chainspec.is_paris_active_at_ttd(U256::ZERO, U256::ZERO));
+
+        assert_eq!(
+            ChainSpec::try_from(Genesis::default()).unwrap().terminal_total_difficulty(),
+            None
+        );
+    }
+
+    #[test]
+    fn geth_genesis_roundtrip() {
+        let forks = |spec: &ChainSpec| {
+            spec.forks_iter().filter(|(fork, _)| *fork != Hardfork::Frontier).collect::<Vec<_>>()
+        };
+
+        for spec in [&*MAINNET, &*DEV, &*DEV_CANCUN] {
+            let genesis = spec.to_geth_genesis();
+            assert_eq!(genesis.config.chain_id, spec.chain.id());
+
+            let exported = ChainSpec::try_from(genesis).unwrap();
+            assert_eq!(exported.genesis_hash(), spec.genesis_hash());
+            assert_eq!(forks(&exported), forks(spec));
+        }
+        assert_eq!(
+            ChainSpec::try_from(DEV.to_geth_genesis()).unwrap().paris_block_and_final_difficulty,
+            DEV.paris_block_and_final_difficulty
+        );
+
+        // forks and custom forks applied with the builder are exported
+        let spec = ChainSpecBuilder::mainnet()
+            .genesis(Genesis::default())
+            .cancun_activated()
+            .with_custom_fork(GenesisHardfork::new("myFork"), ForkCondition::Timestamp(1337))
+            .build();
+        let genesis = spec.to_geth_genesis();
+        assert_eq!(genesis.config.cancun_time, Some(0));
+        assert_eq!(genesis.config.london_block, Some(0));
+        assert_eq!(
+            genesis.config.extra_fields.get("customForks"),
+            Some(&serde_json::json!({ "myForkTime": 1337 }))
+        );
+        assert_eq!(genesis.base_fee_per_gas, Some(EIP1559_INITIAL_BASE_FEE));
+        assert_eq!(genesis.excess_blob_gas, Some(0));
+
+        let exported = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(exported.genesis_hash(), spec.genesis_hash());
+        assert_eq!(forks(&exported), forks(&spec));
+        assert_eq!(exported.custom_fork("myFork"), ForkCondition::Timestamp(1337));
+    }
+
+    #[test]
+    fn ttd_passed_merged_at_genesis() {
+        let merged_at_genesis =
+            ForkCondition::TTD { total_difficulty: U256::ZERO, fork_block: Some(0) };
+
+        // the ttd is passed and reached by the genesis
+        let mut genesis = Genesis { difficulty: U256::from(1), ..Default::default() };
+        genesis.config.terminal_total_difficulty = Some(U256::ZERO);
+        genesis.config.terminal_total_difficulty_passed = true;
+        let chainspec = ChainSpec::try_from(genesis.clone()).unwrap();
+        assert_eq!(chainspec.fork(Hardfork::Paris), merged_at_genesis);
+        assert_eq!(chainspec.paris_block_and_final_difficulty, Some((0, U256::from(1))));
+        assert_eq!(chainspec.fork_block(Hardfork::Paris), Some(0));
+
+        // the ttd is passed without being configured
+        genesis.config.terminal_total_difficulty = None;
+        let chainspec = ChainSpec::try_from(genesis.clone()).unwrap();
+        assert_eq!(chainspec.fork(Hardfork::Paris), merged_at_genesis);
+        assert_eq!(chainspec.paris_block_and_final_difficulty, Some((0, U256::from(1))));
+
+        // the ttd is not passed
+        genesis.config.terminal_total_difficulty = Some(U256::ZERO);
+        genesis.config.terminal_total_difficulty_passed = false;
+        let chainspec = ChainSpec::try_from(genesis.clone()).unwrap();
+        assert_eq!(
+            chainspec.fork(Hardfork::Paris),
+            ForkCondition::TTD { total_difficulty: U256::ZERO, fork_block: None }
+        );
+        assert_eq!(chainspec.paris_block_and_final_difficulty, None);
+
+        // the ttd is passed, but after genesis: the paris block is unknown
+        let ttd = U256::from(58_750_000_000_000_000_000_000_u128);
+        genesis.config.terminal_total_difficulty = Some(ttd);
+        genesis.config.terminal_total_difficulty_passed = true;
+        let chainspec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(
+            chainspec.fork(Hardfork::Paris),
+            ForkCondition::TTD { total_difficulty: ttd, fork_block: None }
+        );
+        assert_eq!(chainspec.paris_block_and_final_difficulty, None);
+    }
+
     #[test]
     fn geth_genesis_with_shanghai() {
         let geth_genesis = r#"
@@ -2697,7 +6492,7 @@ Post-merge hard forks (timestamp based):
         "#;
 
         let genesis: Genesis = serde_json::from_str(geth_genesis).unwrap();
-        let chainspec = ChainSpec::from(genesis);
+        let chainspec = ChainSpec::try_from(genesis).unwrap();
 
         // assert a bunch of hardforks that should be set
         assert_eq!(
@@ -2841,7 +6636,7 @@ Post-merge hard forks (timestamp based):
 
         let _genesis = serde_json::from_str::<Genesis>(hive_json).unwrap();
         let genesis = serde_json::from_str::<AllGenesisFormats>(hive_json).unwrap();
-        let chainspec: ChainSpec = genesis.into();
+        let chainspec: ChainSpec = genesis.try_into().unwrap();
         assert_eq!(chainspec.genesis_hash, None);
         assert_eq!(chainspec.chain, Chain::from_named(NamedChain::Optimism));
         let expected_state_root: B256 =
@@ -2984,7 +6779,7 @@ Post-merge hard forks (timestamp based):
 
         // check that it deserializes properly
         let genesis: Genesis = serde_json::from_str(hive_paris).unwrap();
-        let chainspec = ChainSpec::from(genesis);
+        let chainspec = ChainSpec::try_from(genesis).unwrap();
 
         // make sure we are at ForkHash("bc0c2605") with Head post-cancun
         let expected_forkid = ForkId { hash: ForkHash([0xbc, 0x0c, 0x26, 0x05]), next: 0 };
@@ -3041,6 +6836,872 @@ Post-merge hard forks (timestamp based):
         assert_eq!(genesis.config.cancun_time, Some(4661));
     }
 
+    #[test]
+    fn test_genesis_clique_consensus() {
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"homesteadBlock":0,"clique":{"period":5,"epoch":100}}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+
+        assert!(spec.is_clique());
+        assert!(!spec.is_ethash());
+        assert_eq!(spec.consensus(), ConsensusConfig::Clique { period: 5, epoch: 100 });
+        assert_eq!(spec.clique_period(), Some(5));
+        assert_eq!(spec.clique_epoch(), Some(100));
+
+        // a missing epoch falls back to the default
+        let genesis = genesis_with_config(r#"{"chainId":1337,"clique":{"period":0}}"#);
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(spec.clique_epoch(), Some(ConsensusConfig::DEFAULT_CLIQUE_EPOCH));
+
+        let genesis = genesis_with_config(r#"{"chainId":1337,"ethash":{}}"#);
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert!(spec.is_ethash());
+        assert_eq!(spec.clique_period(), None);
+
+        #[cfg(feature = "goerli")]
+        {
+            assert!(GOERLI.is_clique());
+            assert_eq!(GOERLI.clique_period(), Some(15));
+        }
+        assert!(MAINNET.is_ethash());
+        assert_eq!(HOLESKY.consensus(), ConsensusConfig::None);
+    }
+
+    #[test]
+    fn test_bomb_delays() {
+        assert_eq!(MAINNET.bomb_delay(0), 0);
+        assert_eq!(MAINNET.bomb_delay(4_369_999), 0);
+        assert_eq!(MAINNET.bomb_delay(4_370_000), 3_000_000);
+        assert_eq!(MAINNET.bomb_delay(7_280_000), 5_000_000);
+        assert_eq!(MAINNET.bomb_delay(9_200_000), 9_000_000);
+        assert_eq!(MAINNET.bomb_delay(12_965_000), 9_700_000);
+        assert_eq!(MAINNET.bomb_delay(13_773_000), 10_700_000);
+        assert_eq!(MAINNET.bomb_delay(15_050_000), 11_400_000);
+        assert_eq!(MAINNET.bomb_delay(u64::MAX), 11_400_000);
+
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"byzantiumBlock":0,"difficultyBombDelays":{"100":4000000}}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(spec.bomb_delays(), BTreeMap::from([(0, 3_000_000), (100, 4_000_000)]));
+        assert_eq!(spec.bomb_delay(99), 3_000_000);
+        assert_eq!(spec.bomb_delay(100), 4_000_000);
+
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"difficultyBombDelays":{"100":4000000,"notABlock":1}}"#,
+        );
+        assert_eq!(
+            ChainSpec::try_from(genesis).unwrap_err(),
+            GenesisConfigError::new("difficultyBombDelays", "`notABlock` is not a block number")
+        );
+        for delays in [r#"{"100":"4000000"}"#, "[100]"] {
+            let genesis = genesis_with_config(&format!(
+                r#"{{"chainId":1337,"difficultyBombDelays":{delays}}}"#
+            ));
+            assert_eq!(ChainSpec::try_from(genesis).unwrap_err().field, "difficultyBombDelays");
+        }
+    }
+
+    #[test]
+    fn test_next_block_base_fee() {
+        let london = MAINNET.fork(Hardfork::London).as_block().unwrap();
+
+        let parent = Header { number: london - 2, ..Default::default() };
+        assert_eq!(MAINNET.next_block_base_fee(&parent, 0), None);
+
+        let parent = Header { number: london - 1, ..Default::default() };
+        assert_eq!(MAINNET.next_block_base_fee(&parent, 0), Some(EIP1559_INITIAL_BASE_FEE));
+
+        let parent = Header {
+            number: london,
+            gas_limit: 30_000_000,
+            gas_used: 30_000_000,
+            base_fee_per_gas: Some(EIP1559_INITIAL_BASE_FEE),
+            ..Default::default()
+        };
+        assert_eq!(
+            MAINNET.next_block_base_fee(&parent, 0),
+            parent.next_block_base_fee(BaseFeeParams::ethereum())
+        );
+        assert_eq!(MAINNET.next_block_base_fee(&parent, 0), Some(1_125_000_000));
+
+        // London is active but the parent lacks a base fee
+        let parent = Header { number: london, ..Default::default() };
+        assert_eq!(MAINNET.next_block_base_fee(&parent, 0), None);
+    }
+
+    #[test]
+    fn test_base_fee_params_at_block() {
+        let params = BaseFeeParams {
+            max_change_denominator: 50,
+            elasticity_multiplier: 4,
+            minimum_base_fee: None,
+        };
+        let spec = ChainSpec {
+            hardforks: BTreeMap::from([
+                (Hardfork::London, ForkCondition::Block(0)),
+                (Hardfork::GrayGlacier, ForkCondition::Block(100)),
+                (Hardfork::Shanghai, ForkCondition::Timestamp(1000)),
+            ]),
+            base_fee_params: ForkBaseFeeParams::from(vec![
+                (Hardfork::London, BaseFeeParams::ethereum()),
+                (Hardfork::GrayGlacier, params),
+            ])
+            .into(),
+            ..Default::default()
+        };
+
+        assert_eq!(spec.base_fee_params_at_block(99), BaseFeeParams::ethereum());
+        assert_eq!(spec.base_fee_params_at_block(100), params);
+        // timestamp based resolution doesn't see the block based forks
+        assert_eq!(spec.base_fee_params(u64::MAX), BaseFeeParams::ethereum());
+        assert_eq!(spec.base_fee_params_at_block_and_timestamp(100, 0), params);
+
+        let parent = Header {
+            number: 99,
+            gas_limit: 40_000_000,
+            gas_used: 20_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        };
+        assert_eq!(spec.next_block_base_fee(&parent, 0), parent.next_block_base_fee(params));
+    }
+
+    #[test]
+    fn test_minimum_base_fee() {
+        let params: BaseFeeParams = serde_json::from_str(
+            r#"{"max_change_denominator":8,"elasticity_multiplier":2,"minimum_base_fee":5000000000}"#,
+        )
+        .unwrap();
+        assert_eq!(params, BaseFeeParams::ethereum().with_minimum_base_fee(5_000_000_000));
+        assert_eq!(
+            serde_json::to_value(BaseFeeParams::ethereum()).unwrap(),
+            serde_json::json!({"max_change_denominator":8,"elasticity_multiplier":2})
+        );
+
+        let spec = ChainSpec {
+            hardforks: BTreeMap::from([(Hardfork::London, ForkCondition::Block(1))]),
+            base_fee_params: params.into(),
+            ..Default::default()
+        };
+        // the initial base fee at the London transition is raised to the minimum
+        let genesis = Header::default();
+        assert_eq!(spec.next_block_base_fee(&genesis, 0), Some(5_000_000_000));
+
+        let parent = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(5_000_000_000),
+            ..Default::default()
+        };
+        assert_eq!(spec.next_block_base_fee(&parent, 0), Some(5_000_000_000));
+    }
+
+    #[test]
+    fn test_system_contracts() {
+        assert_eq!(MAINNET.beacon_roots_contract(), BEACON_ROOTS_ADDRESS);
+        assert_eq!(MAINNET.history_storage_contract(), HISTORY_STORAGE_ADDRESS);
+
+        let genesis: Genesis = serde_json::from_str(
+            r#"{
+                "config": {
+                    "chainId": 1337,
+                    "systemContracts": {
+                        "beaconRoots": "0x1111111111111111111111111111111111111111",
+                        "consolidationRequests": "0x2222222222222222222222222222222222222222"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let spec = ChainSpec::try_from(genesis).unwrap();
+
+        assert_eq!(
+            spec.beacon_roots_contract(),
+            address!("1111111111111111111111111111111111111111")
+        );
+        assert_eq!(
+            spec.consolidation_requests_contract(),
+            address!("2222222222222222222222222222222222222222")
+        );
+        // contracts that are not overridden keep their canonical address
+        assert_eq!(spec.history_storage_contract(), HISTORY_STORAGE_ADDRESS);
+        assert_eq!(spec.withdrawal_requests_contract(), WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS);
+
+        // malformed addresses and misspelled contracts are rejected instead of being ignored
+        for contracts in [
+            r#"{"beaconRoots":"0x11"}"#,
+            r#"{"beaconRoot":"0x1111111111111111111111111111111111111111"}"#,
+        ] {
+            let genesis = genesis_with_config(&format!(
+                r#"{{"chainId":1337,"systemContracts":{contracts}}}"#
+            ));
+            assert_eq!(ChainSpec::try_from(genesis).unwrap_err().field, "systemContracts");
+        }
+    }
+
+    #[test]
+    fn test_max_future_timestamp_drift() {
+        assert_eq!(MAINNET.max_future_timestamp_drift, 12);
+        assert_eq!(
+            ChainSpec::try_from(Genesis::default()).unwrap().max_future_timestamp_drift,
+            ALLOWED_FUTURE_BLOCK_TIME_SECONDS
+        );
+
+        let genesis: Genesis = serde_json::from_str(
+            r#"{
+                "config": {
+                    "chainId": 1337,
+                    "maxFutureTimestampDrift": 2
+                }
+            }"#,
+        )
+        .unwrap();
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(spec.max_future_timestamp_drift, 2);
+        assert!(spec.custom_hardforks.forks_iter().next().is_none());
+    }
+
+    #[test]
+    fn test_max_code_init_code_and_tx_size() {
+        assert_eq!(MAINNET.max_code_size, 24576);
+        assert_eq!(MAINNET.max_init_code_size, 49152);
+        assert_eq!(MAINNET.max_tx_size, None);
+        let spec = ChainSpec::try_from(Genesis::default()).unwrap();
+        assert_eq!(spec.max_code_size, MAX_CODE_SIZE);
+        assert_eq!(spec.max_init_code_size, MAX_INIT_CODE_SIZE);
+        assert_eq!(spec.max_tx_size, None);
+
+        let genesis: Genesis = serde_json::from_str(
+            r#"{
+                "config": {
+                    "chainId": 1337,
+                    "maxCodeSize": 32768,
+                    "maxInitCodeSize": 98304,
+                    "maxTxSize": 262144
+                }
+            }"#,
+        )
+        .unwrap();
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(spec.max_code_size, 32768);
+        assert_eq!(spec.max_init_code_size, 98304);
+        assert_eq!(spec.max_tx_size, Some(262144));
+    }
+
+    #[test]
+    fn test_slot_for_timestamp() {
+        let genesis_timestamp = MAINNET.genesis_timestamp();
+        assert_eq!(MAINNET.block_duration(), Some(SLOT_DURATION));
+        assert_eq!(MAINNET.slot_for_timestamp(genesis_timestamp - 1), None);
+        assert_eq!(MAINNET.slot_for_timestamp(genesis_timestamp + 25), Some(2));
+        assert_eq!(MAINNET.timestamp_for_slot(2), Some(genesis_timestamp + 24));
+
+        let spec = ChainSpec::try_from(Genesis::default()).unwrap();
+        assert_eq!(spec.block_duration(), None);
+        assert_eq!(spec.slot_for_timestamp(100), None);
+        assert_eq!(spec.timestamp_for_slot(1), None);
+
+        let genesis: Genesis = serde_json::from_str(
+            r#"{
+                "timestamp": "0x64",
+                "config": {
+                    "chainId": 1337,
+                    "blockPeriodSeconds": 2
+                }
+            }"#,
+        )
+        .unwrap();
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(spec.block_duration(), Some(Duration::from_secs(2)));
+        assert_eq!(spec.slot_for_timestamp(105), Some(2));
+        assert_eq!(spec.timestamp_for_slot(3), Some(106));
+        assert!(spec.custom_hardforks.forks_iter().next().is_none());
+    }
+
+    #[test]
+    fn chain_spec_bootnodes_and_trusted_peers() {
+        let enode = "enode://6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0@10.3.58.6:30303?discport=30301";
+        let genesis: Genesis = serde_json::from_str(&format!(
+            r#"{{
+                "config": {{
+                    "chainId": 1337,
+                    "bootnodes": ["{enode}"],
+                    "trustedPeers": ["{enode}"]
+                }},
+                "nonce": "0x0",
+                "timestamp": "0x0",
+                "extraData": "0x",
+                "gasLimit": "0x4c4b40",
+                "difficulty": "0x1",
+                "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "coinbase": "0x0000000000000000000000000000000000000000",
+                "alloc": {{}}
+            }}"#
+        ))
+        .unwrap();
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        let node: NodeRecord = enode.parse().unwrap();
+        assert_eq!(spec.bootnodes(), Some(vec![node]));
+        assert_eq!(spec.trusted_peers, vec![node]);
+
+        // the spec's boot nodes take precedence over the built-in lists
+        let spec = ChainSpecBuilder::mainnet().bootnodes(vec![node.into()]).build();
+        assert_eq!(spec.bootnodes(), Some(vec![node]));
+        assert_eq!(ChainSpecBuilder::mainnet().build().bootnodes(), Some(mainnet_nodes()));
+    }
+
+    #[test]
+    fn chain_spec_compact_roundtrip() {
+        let mut buf = Vec::new();
+        let len = ChainSpec::clone(&MAINNET).to_compact(&mut buf);
+        let (spec, rest) = ChainSpec::from_compact(&buf, len);
+        assert!(rest.is_empty());
+
+        assert_eq!(spec.chain, MAINNET.chain);
+        assert_eq!(spec.genesis_hash, MAINNET.genesis_hash);
+        assert_eq!(spec.genesis_hash(), MAINNET.genesis_hash());
+        assert_eq!(spec.paris_block_and_final_difficulty, MAINNET.paris_block_and_final_difficulty);
+        assert_eq!(spec.hardforks, MAINNET.hardforks);
+        assert_eq!(spec.deposit_contracts, MAINNET.deposit_contracts);
+        assert_eq!(spec.base_fee_params, MAINNET.base_fee_params);
+        assert_eq!(spec.genesis_header(), MAINNET.genesis_header());
+        assert_eq!(spec.bomb_delays(), MAINNET.bomb_delays());
+        assert_eq!(spec.prune_config, MAINNET.prune_config);
+        assert_eq!(spec.bootnodes, MAINNET.bootnodes);
+        assert_eq!(spec.fork_id(&Head::default()), MAINNET.fork_id(&Head::default()));
+
+        let params = BaseFeeParams { minimum_base_fee: Some(7), ..BaseFeeParams::ethereum() };
+        let mut buf = Vec::new();
+        let len = params.to_compact(&mut buf);
+        assert_eq!(BaseFeeParams::from_compact(&buf, len).0, params);
+    }
+
+    #[test]
+    fn chain_spec_compact_roundtrip_custom_fork() {
+        let account = GenesisAccount {
+            nonce: Some(1),
+            balance: U256::from(2),
+            code: Some(Bytes::from_static(&[0x60, 0x00])),
+            storage: Some(HashMap::from([(B256::with_last_byte(1), B256::with_last_byte(2))])),
+            ..Default::default()
+        };
+        let spec = ChainSpecBuilder::mainnet()
+            .genesis(Genesis::default().extend_accounts([(Address::with_last_byte(1), account)]))
+            .with_custom_fork(GenesisHardfork::new("myFork"), ForkCondition::Timestamp(1337))
+            .consensus(ConsensusConfig::Clique { period: 5, epoch: 100 })
+            .max_code_size(0x8000)
+            .build();
+
+        let mut buf = Vec::new();
+        let len = spec.clone().to_compact(&mut buf);
+        let (decoded, rest) = ChainSpec::from_compact(&buf, len);
+        assert!(rest.is_empty());
+
+        assert_eq!(decoded.custom_hardforks().fork("myFork"), ForkCondition::Timestamp(1337));
+        assert_eq!(decoded.hardforks, spec.hardforks);
+        assert_eq!(decoded.consensus, spec.consensus);
+        assert_eq!(decoded.max_code_size, 0x8000);
+        assert_eq!(decoded.genesis.alloc, spec.genesis.alloc);
+        assert_eq!(decoded.genesis_hash(), spec.genesis_hash());
+
+        let head = Head { timestamp: 1337, ..Default::default() };
+        assert_eq!(decoded.fork_id(&head), spec.fork_id(&head));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn arbitrary_chain_spec_roundtrip(spec: ChainSpec) {
+            let mut buf = Vec::new();
+            let len = spec.clone().to_compact(&mut buf);
+            let (decoded, _) = ChainSpec::from_compact(&buf, len);
+            assert_eq!(decoded.hardforks, spec.hardforks);
+            assert_eq!(decoded.base_fee_params, spec.base_fee_params);
+            assert_eq!(decoded.genesis_hash(), spec.genesis_hash());
+
+            // every fork is active at the head that satisfies it, with the same fork id
+            for condition in spec.hardforks.values() {
+                let head = spec.satisfy(*condition);
+                assert!(condition.active_at_head(&head));
+                assert_eq!(decoded.fork_id(&head), spec.fork_id(&head));
+            }
+        }
+    }
+
+    #[test]
+    fn test_active_forks_at() {
+        let head = Head { number: 12965000, timestamp: 1628166822, ..Default::default() };
+        assert_eq!(
+            MAINNET.active_forks_at(&head).collect::<Vec<_>>(),
+            [
+                Hardfork::Frontier,
+                Hardfork::Homestead,
+                Hardfork::Dao,
+                Hardfork::Tangerine,
+                Hardfork::SpuriousDragon,
+                Hardfork::Byzantium,
+                Hardfork::Constantinople,
+                Hardfork::Petersburg,
+                Hardfork::Istanbul,
+                Hardfork::MuirGlacier,
+                Hardfork::Berlin,
+                Hardfork::London,
+            ]
+        );
+        assert_eq!(MAINNET.latest_active_fork(&head), Some(Hardfork::London));
+
+        let head = Head { number: 12964999, ..head };
+        assert_eq!(MAINNET.latest_active_fork(&head), Some(Hardfork::Berlin));
+
+        // post-merge forks are activated by the timestamp and the total difficulty
+        let head = Head {
+            number: 20000000,
+            timestamp: 1710338135,
+            total_difficulty: U256::from(58_750_003_716_598_352_816_469u128),
+            ..Default::default()
+        };
+        assert!(MAINNET.active_forks_at(&head).any(|fork| fork == Hardfork::Paris));
+        assert_eq!(MAINNET.latest_active_fork(&head), Some(Hardfork::Cancun));
+
+        let spec = ChainSpecBuilder::default().chain(Chain::dev()).genesis(Genesis::default());
+        assert_eq!(spec.build().latest_active_fork(&head), None);
+    }
+
+    #[test]
+    fn test_next_fork_after() {
+        let head = Head { number: 12964999, timestamp: 1628166812, ..Default::default() };
+        assert_eq!(
+            MAINNET.next_fork_after(&head),
+            Some((Hardfork::London, ForkCondition::Block(12965000)))
+        );
+
+        // the total difficulty is unknown, but Paris is not reported once Shanghai is active
+        let head = Head { number: 17034870, timestamp: 1681338455, ..Default::default() };
+        assert_eq!(
+            MAINNET.next_fork_after(&head),
+            Some((Hardfork::Cancun, ForkCondition::Timestamp(1710338135)))
+        );
+
+        let head = Head { number: 19426587, timestamp: 1710338135, ..Default::default() };
+        assert_eq!(MAINNET.next_fork_after(&head), None);
+    }
+
+    #[test]
+    fn test_is_first_block_of_fork() {
+        let condition = ForkCondition::Timestamp(1681338455);
+        assert!(condition.transitions_at_timestamp(1681338455, 1681338443));
+        assert!(condition.transitions_at_timestamp(1681338460, 1681338443));
+        assert!(!condition.transitions_at_timestamp(1681338467, 1681338455));
+        assert!(!condition.transitions_at_timestamp(1681338443, 1681338431));
+
+        let parent = Header { number: 17034869, timestamp: 1681338443, ..Default::default() };
+        let header = Header { number: 17034870, timestamp: 1681338455, ..Default::default() };
+        assert!(MAINNET.is_first_block_of_fork(Hardfork::Shanghai, &header, &parent));
+        assert!(!MAINNET.is_first_block_of_fork(Hardfork::London, &header, &parent));
+        assert!(!MAINNET.is_first_block_of_fork(Hardfork::Cancun, &header, &parent));
+
+        let parent = Header { number: 12964999, ..Default::default() };
+        let header = Header { number: 12965000, ..Default::default() };
+        assert!(MAINNET.is_first_block_of_fork(Hardfork::London, &header, &parent));
+        assert!(!MAINNET.is_first_block_of_fork(Hardfork::Berlin, &header, &parent));
+
+        // the Paris block is known on Sepolia
+        let parent = Header { number: 1735370, ..Default::default() };
+        let header = Header { number: 1735371, ..Default::default() };
+        assert!(SEPOLIA.is_first_block_of_fork(Hardfork::Paris, &header, &parent));
+    }
+
+    #[test]
+    fn test_fork_block_and_timestamp() {
+        assert_eq!(MAINNET.fork_block(Hardfork::Frontier), Some(0));
+        assert_eq!(MAINNET.fork_block(Hardfork::London), Some(12965000));
+        assert_eq!(MAINNET.fork_block(Hardfork::Paris), Some(15537394));
+        assert_eq!(MAINNET.fork_block(Hardfork::Shanghai), None);
+        assert_eq!(MAINNET.fork_timestamp(Hardfork::Shanghai), Some(1681338455));
+        assert_eq!(MAINNET.fork_timestamp(Hardfork::London), None);
+
+        // Sepolia advertises its Paris block in the fork condition
+        assert_eq!(SEPOLIA.fork_block(Hardfork::Paris), Some(1735371));
+
+        // the Paris block is unknown without a fork block or a known transition
+        let spec = ChainSpecBuilder::mainnet().build();
+        assert_eq!(spec.fork_block(Hardfork::Paris), None);
+        assert_eq!(spec.fork_block(Hardfork::Osaka), None);
+        assert_eq!(spec.fork_timestamp(Hardfork::Osaka), None);
+    }
+
+    #[test]
+    fn test_deposit_contracts() {
+        assert_eq!(MAINNET.deposit_contract_at(11052983), None);
+        assert_eq!(
+            MAINNET.deposit_contract_at(11052984).map(|contract| contract.address),
+            Some(address!("00000000219ab540356cbb839cbe05303d7705fa"))
+        );
+
+        let old = DepositContract::new(
+            Address::with_last_byte(1),
+            10,
+            DepositContract::DEPOSIT_EVENT_TOPIC,
+        );
+        let new = DepositContract::new(
+            Address::with_last_byte(2),
+            20,
+            DepositContract::DEPOSIT_EVENT_TOPIC,
+        );
+        let spec = ChainSpecBuilder::default()
+            .chain(Chain::dev())
+            .genesis(Genesis::default())
+            .deposit_contract(new.clone())
+            .deposit_contract(old.clone())
+            .build();
+        assert_eq!(spec.deposit_contracts(), [old.clone(), new.clone()]);
+        assert_eq!(spec.deposit_contract_at(9), None);
+        assert_eq!(spec.deposit_contract_at(19), Some(&old));
+        assert_eq!(spec.deposit_contract_at(20), Some(&new));
+
+        let genesis: Genesis = serde_json::from_str(
+            r#"{
+                "config": {
+                    "chainId": 1337,
+                    "depositContractAddress": "0x4242424242424242424242424242424242424242"
+                }
+            }"#,
+        )
+        .unwrap();
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(
+            spec.deposit_contracts(),
+            [DepositContract::new(
+                address!("4242424242424242424242424242424242424242"),
+                0,
+                DepositContract::DEPOSIT_EVENT_TOPIC
+            )]
+        );
+        assert!(ChainSpec::try_from(Genesis::default()).unwrap().deposit_contracts().is_empty());
+
+        // an invalid address is rejected instead of falling back to the alloc
+        let genesis = genesis_with_config(r#"{"chainId":1337,"depositContractAddress":"0x42"}"#);
+        assert_eq!(ChainSpec::try_from(genesis).unwrap_err().field, "depositContractAddress");
+    }
+
+    #[test]
+    fn test_deposit_contract_from_genesis_alloc() {
+        // dispatcher of `deposit` followed by the log of the `DepositEvent`
+        let mut code = vec![0x80, 0x63, 0x22, 0x89, 0x51, 0x18, 0x14, 0x7f];
+        code.extend_from_slice(DepositContract::DEPOSIT_EVENT_TOPIC.as_slice());
+        code.push(0xa1);
+
+        let contract = GenesisAccount::default().with_code(Some(code.clone().into()));
+        let other = GenesisAccount::default().with_code(Some(Bytes::from_static(&[0x60, 0x00])));
+        let genesis = Genesis::default().extend_accounts([
+            (Address::with_last_byte(1), other),
+            (Address::with_last_byte(0x42), contract.clone()),
+        ]);
+        assert_eq!(
+            ChainSpec::try_from(genesis.clone()).unwrap().deposit_contracts(),
+            [DepositContract::new(
+                Address::with_last_byte(0x42),
+                0,
+                DepositContract::DEPOSIT_EVENT_TOPIC
+            )]
+        );
+
+        // the configured deposit contract wins over the alloc
+        let mut configured = genesis;
+        configured.config.extra_fields.insert(
+            "depositContractAddress".to_string(),
+            serde_json::json!("0x4242424242424242424242424242424242424242"),
+        );
+        assert_eq!(
+            ChainSpec::try_from(configured).unwrap().deposit_contracts()[0].address,
+            address!("4242424242424242424242424242424242424242")
+        );
+
+        // code that only emits the event is not the deposit contract
+        let partial = GenesisAccount::default().with_code(Some(code[7..].to_vec().into()));
+        let genesis = Genesis::default().extend_accounts([(Address::with_last_byte(1), partial)]);
+        assert!(ChainSpec::try_from(genesis).unwrap().deposit_contracts().is_empty());
+    }
+
+    #[test]
+    fn test_active_precompiles() {
+        let precompiles = |addresses: &[u8]| {
+            addresses.iter().map(|a| Address::with_last_byte(*a)).collect::<BTreeSet<_>>()
+        };
+
+        assert_eq!(MAINNET.active_precompiles(0, 0), precompiles(&[1, 2, 3, 4]));
+        assert_eq!(
+            MAINNET.active_precompiles(0, 4_370_000),
+            precompiles(&[1, 2, 3, 4, 5, 6, 7, 8])
+        );
+        assert_eq!(
+            MAINNET.active_precompiles(1_710_338_135, 19_426_587),
+            precompiles(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
+        );
+        assert_eq!(MAINNET.active_precompiles(1_746_612_311, 22_431_084).len(), 17);
+
+        let custom = address!("0000000000000000000000000000000000001000");
+        let genesis: Genesis = serde_json::from_str(
+            r#"{
+                "config": {
+                    "chainId": 1337,
+                    "byzantiumBlock": 0,
+                    "istanbulBlock": 0,
+                    "shanghaiTime": 0,
+                    "cancunTime": 1000,
+                    "precompiles": {
+                        "cancun": ["0x0000000000000000000000000000000000001000"]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let spec = ChainSpec::try_from(genesis).unwrap();
+
+        let before = spec.active_precompiles(999, 10);
+        assert_eq!(before, precompiles(&[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+        let after = spec.active_precompiles(1000, 10);
+        assert!(after.contains(&custom));
+        assert!(after.contains(&Address::with_last_byte(10)));
+        assert_eq!(after.len(), 11);
+
+        // malformed schedules are rejected instead of being ignored
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"precompiles":{"unknown":["0x0000000000000000000000000000000000002000"]}}"#,
+        );
+        assert_eq!(
+            ChainSpec::try_from(genesis).unwrap_err(),
+            GenesisConfigError::new("precompiles", "unknown hardfork `unknown`")
+        );
+        let genesis = genesis_with_config(r#"{"chainId":1337,"precompiles":{"cancun":"0x1000"}}"#);
+        assert_eq!(ChainSpec::try_from(genesis).unwrap_err().field, "precompiles");
+    }
+
+    #[test]
+    fn test_gas_limit_schedule() {
+        let genesis: Genesis = serde_json::from_str(
+            r#"{
+                "config": {
+                    "chainId": 1337,
+                    "londonBlock": 10,
+                    "shanghaiTime": 1000,
+                    "gasLimitSchedule": {
+                        "london": { "target": 30000000 },
+                        "shanghai": { "target": 60000000, "cap": 45000000 }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        let spec = ChainSpec::try_from(genesis).unwrap();
+
+        assert_eq!(spec.gas_limit_at(9, 0), None);
+        assert_eq!(
+            spec.gas_limit_at(10, 999),
+            Some(GasLimitParams { target: Some(30_000_000), cap: None })
+        );
+        assert_eq!(
+            spec.gas_limit_at(10, 1000),
+            Some(GasLimitParams { target: Some(60_000_000), cap: Some(45_000_000) })
+        );
+
+        // no schedule, the gas limit is kept
+        let parent = Header { number: 8, gas_limit: 20_000_000, ..Default::default() };
+        assert_eq!(spec.next_block_gas_limit(&parent, 0), 20_000_000);
+        // moves towards the target by less than 1/1024 of the parent gas limit
+        let parent = Header { number: 9, gas_limit: 20_000_000, ..Default::default() };
+        assert_eq!(spec.next_block_gas_limit(&parent, 0), 20_000_000 + 20_000_000 / 1024 - 1);
+        let parent = Header { number: 9, gas_limit: 29_999_990, ..Default::default() };
+        assert_eq!(spec.next_block_gas_limit(&parent, 0), 30_000_000);
+        let parent = Header { number: 9, gas_limit: 40_000_000, ..Default::default() };
+        assert_eq!(spec.next_block_gas_limit(&parent, 0), 40_000_000 - 40_000_000 / 1024 + 1);
+        // the cap wins over the target
+        let parent = Header { number: 9, gas_limit: 45_000_000, ..Default::default() };
+        assert_eq!(spec.next_block_gas_limit(&parent, 1000), 45_000_000);
+
+        // malformed schedules are rejected instead of being ignored
+        let genesis =
+            genesis_with_config(r#"{"chainId":1337,"gasLimitSchedule":{"unknown":{"cap":1}}}"#);
+        assert_eq!(
+            ChainSpec::try_from(genesis).unwrap_err(),
+            GenesisConfigError::new("gasLimitSchedule", "unknown hardfork `unknown`")
+        );
+        for schedule in [r#"{"london":{"cap":"1"}}"#, r#"{"london":{"caps":1}}"#] {
+            let genesis = genesis_with_config(&format!(
+                r#"{{"chainId":1337,"gasLimitSchedule":{schedule}}}"#
+            ));
+            assert_eq!(ChainSpec::try_from(genesis).unwrap_err().field, "gasLimitSchedule");
+        }
+    }
+
+    #[test]
+    fn test_blob_schedule() {
+        assert_eq!(MAINNET.blob_params_at_timestamp(u64::MAX), BlobParams::cancun());
+
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"cancunTime":0,"pragueTime":100,"blobSchedule":{"cancun":{"target":3,"max":6,"baseFeeUpdateFraction":3338477},"prague":{"target":6,"max":9,"baseFeeUpdateFraction":5007716}}}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(spec.blob_params_at_timestamp(0), BlobParams::cancun());
+        assert_eq!(spec.blob_params_at_timestamp(99), BlobParams::cancun());
+        assert_eq!(spec.blob_params_at_timestamp(100), BlobParams::prague());
+        assert!(spec.custom_hardforks().is_empty());
+
+        // the excess blob gas and the blob fee of a block follow the params of the block
+        let parent = Header {
+            excess_blob_gas: Some(0),
+            blob_gas_used: Some(BlobParams::cancun().max_blob_gas_per_block()),
+            ..Default::default()
+        };
+        assert_eq!(
+            spec.next_block_excess_blob_gas(&parent, 99),
+            Some(BlobParams::cancun().target_blob_gas_per_block())
+        );
+        assert_eq!(spec.next_block_excess_blob_gas(&parent, 100), Some(0));
+        assert_eq!(spec.next_block_blob_fee(&parent, 100), Some(1));
+        assert_eq!(MAINNET.next_block_excess_blob_gas(&parent, 0), None);
+        assert_eq!(
+            BlobParams::cancun().calc_blob_fee(10_000_000),
+            crate::eip4844::calc_blob_gasprice(10_000_000)
+        );
+
+        // custom L2 blob limits without Prague
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"cancunTime":0,"blobSchedule":{"cancun":{"target":1,"max":2,"baseFeeUpdateFraction":1000}}}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+        assert_eq!(
+            spec.blob_params_at_timestamp(0),
+            BlobParams { target_blob_count: 1, max_blob_count: 2, base_fee_update_fraction: 1000 }
+        );
+
+        // malformed schedules are rejected instead of falling back to the cancun params
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"cancunTime":0,"blobSchedule":{"unknown":{"target":1,"max":1,"baseFeeUpdateFraction":1}}}"#,
+        );
+        assert_eq!(
+            ChainSpec::try_from(genesis).unwrap_err(),
+            GenesisConfigError::new("blobSchedule", "unknown hardfork `unknown`")
+        );
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"cancunTime":0,"blobSchedule":{"cancun":{"target":1}}}"#,
+        );
+        assert_eq!(ChainSpec::try_from(genesis).unwrap_err().field, "blobSchedule");
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_genesis_op_forks() {
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"bedrockBlock":0,"regolithTime":0,"canyonTime":10,"ecotoneTime":20,"fjordTime":30,"graniteTime":40,"holoceneTime":50}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+
+        assert!(spec.custom_hardforks().is_empty());
+        assert_eq!(spec.fork(Hardfork::Bedrock), ForkCondition::Block(0));
+        assert_eq!(spec.fork(Hardfork::Regolith), ForkCondition::Timestamp(0));
+        assert_eq!(spec.fork(Hardfork::Canyon), ForkCondition::Timestamp(10));
+        assert_eq!(spec.fork(Hardfork::Ecotone), ForkCondition::Timestamp(20));
+        assert_eq!(spec.fork(Hardfork::Fjord), ForkCondition::Timestamp(30));
+        assert_eq!(spec.fork(Hardfork::Granite), ForkCondition::Timestamp(40));
+        assert_eq!(spec.fork(Hardfork::Holocene), ForkCondition::Timestamp(50));
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn test_genesis_op_rollup_config() {
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"bedrockBlock":0,"optimism":{"eip1559Elasticity":6,"eip1559Denominator":50,"l1ChainId":1,"batchInboxAddress":"0xff00000000000000000000000000000000001337","depositContractAddress":"0x0000000000000000000000000000000000001337","sequencerFeeVault":"0x0000000000000000000000000000000000000042","channelTimeout":50}}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+
+        let rollup = spec.rollup_config().unwrap();
+        assert_eq!(rollup.l1_chain_id, 1);
+        assert_eq!(
+            rollup.batch_inbox_address,
+            address!("ff00000000000000000000000000000000001337")
+        );
+        assert_eq!(
+            rollup.deposit_contract_address,
+            address!("0000000000000000000000000000000000001337")
+        );
+        assert_eq!(rollup.channel_timeout, 50);
+        assert_eq!(
+            spec.sequencer_fee_vault(),
+            address!("0000000000000000000000000000000000000042")
+        );
+
+        assert_eq!(BASE_MAINNET.rollup_config().map(|rollup| rollup.l1_chain_id), Some(1));
+        assert_eq!(BASE_SEPOLIA.rollup_config().map(|rollup| rollup.l1_chain_id), Some(11155111));
+        assert_eq!(BASE_MAINNET.sequencer_fee_vault(), SEQUENCER_FEE_VAULT_ADDRESS);
+        assert_eq!(DEV.rollup_config(), None);
+    }
+
+    #[test]
+    fn test_genesis_custom_forks() {
+        let genesis = genesis_with_config(
+            r#"{"chainId":1337,"homesteadBlock":0,"shanghaiTime":0,"customForks":{"myForkBlock":10,"otherForkTime":1337},"notAForkTime":42}"#,
+        );
+        let spec = ChainSpec::try_from(genesis).unwrap();
+
+        assert_eq!(spec.custom_hardforks().len(), 2);
+        assert_eq!(spec.custom_fork("myFork"), ForkCondition::Block(10));
+        assert_eq!(spec.custom_fork("otherFork"), ForkCondition::Timestamp(1337));
+        // only the forks declared in `customForks` are chain specific forks
+        assert_eq!(spec.custom_fork("notAFork"), ForkCondition::Never);
+
+        // the same schedule using built-in forks in place of the custom ones
+        let mut expected = spec.clone();
+        expected.set_custom_hardforks(Default::default());
+        expected.insert_hardfork(Hardfork::Byzantium, ForkCondition::Block(10));
+        expected.insert_hardfork(Hardfork::Cancun, ForkCondition::Timestamp(1337));
+
+        for head in [
+            Head { number: 0, timestamp: 0, ..Default::default() },
+            Head { number: 10, timestamp: 0, ..Default::default() },
+            Head { number: 10, timestamp: 1337, ..Default::default() },
+        ] {
+            assert_eq!(spec.fork_id(&head), expected.fork_id(&head));
+        }
+        assert_ne!(
+            spec.fork_id(&Head { number: 10, ..Default::default() }),
+            spec.fork_id(&Head { number: 0, ..Default::default() })
+        );
+
+        // the custom forks survive a roundtrip through the reth chain spec format
+        let json = serde_json::to_value(&spec).unwrap();
+        assert_eq!(
+            json["custom_hardforks"],
+            serde_json::json!({ "myFork": { "Block": 10 }, "otherFork": { "Timestamp": 1337 } })
+        );
+        let deserialized: ChainSpec = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.custom_hardforks().len(), 2);
+        assert_eq!(deserialized.fork_id(&Head::default()), spec.fork_id(&Head::default()));
+        assert!(serde_json::to_value(&*MAINNET).unwrap().get("custom_hardforks").is_none());
+    }
+
+    #[test]
+    fn test_genesis_custom_forks_malformed() {
+        for (custom_forks, reason) in [
+            (r#"{"myFork":10}"#, "`myFork` is not a fork block or time"),
+            (r#"{"Block":10}"#, "`Block` is not a fork block or time"),
+            (r#"{"cancunTime":1337}"#, "`cancun` is not a chain specific fork"),
+        ] {
+            let genesis =
+                genesis_with_config(&format!(r#"{{"chainId":1337,"customForks":{custom_forks}}}"#));
+            assert_eq!(
+                ChainSpec::try_from(genesis).unwrap_err(),
+                GenesisConfigError::new("customForks", reason)
+            );
+        }
+        for custom_forks in [r#"{"myForkTime":"soon"}"#, "[10]"] {
+            let genesis =
+                genesis_with_config(&format!(r#"{{"chainId":1337,"customForks":{custom_forks}}}"#));
+            assert_eq!(ChainSpec::try_from(genesis).unwrap_err().field, "customForks");
+        }
+    }
+
     #[test]
     fn test_default_cancun_header_forkhash() {
         // set the gas limit from the hive test genesis according to the hash
@@ -3077,6 +7738,46 @@ Post-merge hard forks (timestamp based):
         assert_eq!(ForkHash::from(genesis_hash), expected_forkhash);
     }
 
+    #[test]
+    fn ephemery_iteration_genesis() {
+        let s = r#"{"config":{"chainId":39438000,"terminalTotalDifficulty":0,"shanghaiTime":0},"nonce":"0x0","timestamp":"0x0","extraData":"0x","gasLimit":"0x1c9c380","difficulty":"0x1","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","coinbase":"0x0000000000000000000000000000000000000000","alloc":{}}"#;
+        let genesis: Genesis = serde_json::from_str(s).unwrap();
+
+        let iteration = EphemeryIteration(120);
+        let spec = ChainSpec::ephemery(genesis.clone(), Some(iteration)).unwrap();
+        assert_eq!(spec.chain, Chain::from_id(iteration.chain_id()));
+        assert_eq!(spec.genesis.timestamp, iteration.genesis_timestamp());
+        assert!(spec.fork(Hardfork::Shanghai).active_at_timestamp(spec.genesis.timestamp));
+
+        // every iteration has its own genesis
+        let next = ChainSpec::ephemery(genesis, Some(EphemeryIteration(121))).unwrap();
+        assert_ne!(spec.genesis_hash(), next.genesis_hash());
+    }
+
+    #[test]
+    fn preset_bootnodes() {
+        let hoodi = ChainSpec { chain: Chain::from_id(HOODI_CHAIN_ID), ..Default::default() };
+        assert_eq!(hoodi.bootnodes(), Some(hoodi_nodes()));
+        assert_eq!(HOLESKY.bootnodes(), Some(holesky_nodes()));
+        #[cfg(feature = "optimism")]
+        {
+            assert_eq!(BASE_MAINNET.bootnodes(), Some(op_nodes()));
+            assert_eq!(BASE_SEPOLIA.bootnodes(), Some(op_testnet_nodes()));
+        }
+    }
+
+    #[test]
+    fn dns_discovery_trees() {
+        assert_eq!(MAINNET.dns_discovery_tree(), MAINNET.chain.public_dns_network_protocol());
+        assert_eq!(HOLESKY.dns_discovery_tree(), HOLESKY.chain.public_dns_network_protocol());
+        let hoodi = ChainSpec { chain: Chain::from_id(HOODI_CHAIN_ID), ..Default::default() };
+        assert_eq!(
+            hoodi.dns_discovery_tree().as_deref(),
+            Some("enrtree://AKA3AM6LPBYEUDMVNU3BSVQJ5AD45Y7YPOHJLEF6W26QOE4VTUDPE@all.hoodi.ethdisco.net")
+        );
+        assert_eq!(DEV.dns_discovery_tree(), None);
+    }
+
     #[test]
     fn holesky_paris_activated_at_genesis() {
         assert!(HOLESKY
@@ -3149,7 +7850,7 @@ Post-merge hard forks (timestamp based):
             genesis_hash: None,
             hardforks: BTreeMap::from([(Hardfork::Frontier, ForkCondition::Never)]),
             paris_block_and_final_difficulty: None,
-            deposit_contract: None,
+            deposit_contracts: Vec::new(),
             ..Default::default()
         };
 
@@ -3164,7 +7865,7 @@ Post-merge hard forks (timestamp based):
             genesis_hash: None,
             hardforks: BTreeMap::from([(Hardfork::Shanghai, ForkCondition::Never)]),
             paris_block_and_final_difficulty: None,
-            deposit_contract: None,
+            deposit_contracts: Vec::new(),
             ..Default::default()
         };
 
@@ -3185,5 +7886,6 @@ Post-merge hard forks (timestamp based):
             genesis.next_block_base_fee(BASE_SEPOLIA.base_fee_params(genesis.timestamp)).unwrap();
         // <https://base-sepolia.blockscout.com/block/1>
         assert_eq!(base_fee, 980000000);
+        assert_eq!(BASE_SEPOLIA.next_block_base_fee(&genesis, genesis.timestamp), Some(base_fee));
     }
 }
@@ -0,0 +1,132 @@
+#[cfg(feature = "optimism")]
+use crate::{address, hex};
+use crate::{Address, Bytes, ChainConfig, Hardfork};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The genesis config key of the state migrations per hardfork.
+const GENESIS_STATE_MIGRATIONS_KEY: &str = "stateMigrations";
+
+/// The address of the create2 deployer, force-deployed by [Hardfork::Canyon].
+#[cfg(feature = "optimism")]
+pub const CREATE_2_DEPLOYER_ADDR: Address = address!("13b0D85CcB8bf860b6b79AF3029fCA081AE9beF2");
+
+/// The raw bytecode of the create2 deployer contract.
+#[cfg(feature = "optimism")]
+pub const CREATE_2_DEPLOYER_BYTECODE: [u8; 1584] = hex!("6080604052600436106100435760003560e01c8063076c37b21461004f578063481286e61461007157806356299481146100ba57806366cfa057146100da57600080fd5b3661004a57005b600080fd5b34801561005b57600080fd5b5061006f61006a366004610327565b6100fa565b005b34801561007d57600080fd5b5061009161008c366004610327565b61014a565b60405173ffffffffffffffffffffffffffffffffffffffff909116815260200160405180910390f35b3480156100c657600080fd5b506100916100d5366004610349565b61015d565b3480156100e657600080fd5b5061006f6100f53660046103ca565b610172565b61014582826040518060200161010f9061031a565b7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe082820381018352601f90910116604052610183565b505050565b600061015683836102e7565b9392505050565b600061016a8484846102f0565b949350505050565b61017d838383610183565b50505050565b6000834710156101f4576040517f08c379a000000000000000000000000000000000000000000000000000000000815260206004820152601d60248201527f437265617465323a20696e73756666696369656e742062616c616e636500000060448201526064015b60405180910390fd5b815160000361025f576040517f08c379a000000000000000000000000000000000000000000000000000000000815260206004820181905260248201527f437265617465323a2062797465636f6465206c656e677468206973207a65726f60448201526064016101eb565b8282516020840186f5905073ffffffffffffffffffffffffffffffffffffffff8116610156576040517f08c379a000000000000000000000000000000000000000000000000000000000815260206004820152601960248201527f437265617465323a204661696c6564206f6e206465706c6f790000000000000060448201526064016101eb565b60006101568383305b6000604051836040820152846020820152828152600b8101905060ff815360559020949350505050565b61014e806104ad83390190565b6000806040838503121561033a57600080fd5b50508035926020909101359150565b60008060006060848603121561035e57600080fd5b8335925060208401359150604084013573ffffffffffffffffffffffffffffffffffffffff8116811461039057600080fd5b809150509250925092565b7f4e487b7100000000000000000000000000000000000000000000000000000000600052604160045260246000fd5b6000806000606084860312156103df57600080fd5b8335925060208401359150604084013567ffffffffffffffff8082111561040557600080fd5b818601915086601f83011261041957600080fd5b81358181111561042b5761042b61039b565b604051601f82017fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe0908116603f011681019083821181831017156104715761047161039b565b8160405282815289602084870101111561048a57600080fd5b826020860160208301376000602084830101528095505050505050925092509256fe608060405234801561001057600080fd5b5061012e806100206000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063249cb3fa14602d575b600080fd5b603c603836600460b1565b604e565b60405190815260200160405180910390f35b60008281526020818152604080832073ffffffffffffffffffffffffffffffffffffffff8516845290915281205460ff16608857600060aa565b7fa2ef4600d742022d532d4747cb3547474667d6f13804902513b2ec01c848f4b45b9392505050565b6000806040838503121560c357600080fd5b82359150602083013573ffffffffffffffffffffffffffffffffffffffff8116811460ed57600080fd5b80915050925092905056fea26469706673582212205ffd4e6cede7d06a5daf93d48d0541fc68189eeb16608c1999a82063b666eb1164736f6c63430008130033a2646970667358221220fdc4a0fe96e3b21c108ca155438d37c9143fb01278a3c1d274948bad89c564ba64736f6c63430008130033");
+
+/// An irregular state transition that force-deploys a contract when a hardfork activates.
+///
+/// The code of the account is replaced, its balance, nonce and storage are left untouched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForkStateMigration {
+    /// The address of the deployed contract.
+    pub address: Address,
+    /// The runtime bytecode of the deployed contract.
+    pub code: Bytes,
+}
+
+impl ForkStateMigration {
+    /// Creates a new migration that deploys the given code at the given address.
+    pub const fn new(address: Address, code: Bytes) -> Self {
+        Self { address, code }
+    }
+
+    /// The migration that force-deploys the create2 deployer at the [Hardfork::Canyon] transition.
+    #[cfg(feature = "optimism")]
+    pub const fn create2_deployer() -> Self {
+        Self::new(CREATE_2_DEPLOYER_ADDR, Bytes::from_static(&CREATE_2_DEPLOYER_BYTECODE))
+    }
+}
+
+/// A list of [Hardfork]s and the [ForkStateMigration]s that are applied at their transition,
+/// sorted by [Hardfork] activation order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateMigrationSchedule(Vec<(Hardfork, Vec<ForkStateMigration>)>);
+
+impl StateMigrationSchedule {
+    /// The state migrations of OP-stack chains, the create2 deployer preinstall of
+    /// [Hardfork::Canyon].
+    #[cfg(feature = "optimism")]
+    pub fn optimism() -> Self {
+        vec![(Hardfork::Canyon, vec![ForkStateMigration::create2_deployer()])].into()
+    }
+
+    /// Reads the schedule from the `stateMigrations` field of a genesis chain config, which maps
+    /// hardfork names to the contracts they deploy, e.g.
+    /// `"stateMigrations": { "canyon": [{ "address": "0x...", "code": "0x..." }] }`.
+    ///
+    /// Returns `None` if the chain config has no state migrations. Entries for unknown hardforks
+    /// are ignored.
+    pub fn from_chain_config(config: &ChainConfig) -> Option<Self> {
+        let schedule = config.extra_fields.get(GENESIS_STATE_MIGRATIONS_KEY)?;
+        let schedule = BTreeMap::<String, Vec<ForkStateMigration>>::deserialize(schedule).ok()?;
+        let migrations = schedule
+            .into_iter()
+            .filter_map(|(fork, migrations)| Some((fork.parse::<Hardfork>().ok()?, migrations)))
+            .collect::<BTreeMap<_, _>>();
+        if migrations.is_empty() {
+            return None
+        }
+        Some(StateMigrationSchedule(migrations.into_iter().collect()))
+    }
+
+    /// Returns true if the schedule contains no migrations.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the hardforks and the migrations applied at their transition.
+    pub fn iter(&self) -> impl Iterator<Item = (Hardfork, &[ForkStateMigration])> {
+        self.0.iter().map(|(fork, migrations)| (*fork, migrations.as_slice()))
+    }
+}
+
+impl From<Vec<(Hardfork, Vec<ForkStateMigration>)>> for StateMigrationSchedule {
+    fn from(migrations: Vec<(Hardfork, Vec<ForkStateMigration>)>) -> Self {
+        StateMigrationSchedule(migrations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_migrations_from_chain_config() {
+        let config: ChainConfig = serde_json::from_str(
+            r#"{
+                "chainId": 1337,
+                "stateMigrations": {
+                    "cancun": [{ "address": "0x0000000000000000000000000000000000000100", "code": "0x6000" }],
+                    "unknown": [{ "address": "0x0000000000000000000000000000000000000200", "code": "0x6001" }]
+                }
+            }"#,
+        )
+        .unwrap();
+        let schedule = StateMigrationSchedule::from_chain_config(&config).unwrap();
+        assert_eq!(
+            schedule,
+            vec![(
+                Hardfork::Cancun,
+                vec![ForkStateMigration::new(
+                    crate::address!("0000000000000000000000000000000000000100"),
+                    Bytes::from_static(&[0x60, 0x00])
+                )]
+            )]
+            .into()
+        );
+
+        let config: ChainConfig = serde_json::from_str(r#"{"chainId": 1337}"#).unwrap();
+        assert_eq!(StateMigrationSchedule::from_chain_config(&config), None);
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn create2_deployer_codehash() {
+        assert_eq!(
+            crate::keccak256(CREATE_2_DEPLOYER_BYTECODE),
+            crate::b256!("b0550b5b431e30d38000efb7107aaa0ade03d48a7198a140edda9d27134468b2")
+        );
+    }
+}
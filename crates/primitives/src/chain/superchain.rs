@@ -0,0 +1,351 @@
+use crate::{
+    chain::{
+        BaseFeeParams, BaseFeeParamsKind, ChainSpec, StateMigrationSchedule,
+        SEQUENCER_FEE_VAULT_ADDRESS,
+    },
+    constants::{
+        OP_MAINNET_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON,
+        OP_MAINNET_EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
+        OP_MAINNET_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+    },
+    Bytes, ChainConfig, ForkCondition, Genesis, Hardfork, PruneConfig, SnapshotConfig, B256, U256,
+};
+use alloy_chains::Chain;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The extra data of the genesis block of OP-stack chains.
+const BEDROCK_GENESIS_EXTRA_DATA: &[u8] = b"BEDROCK";
+
+/// The snapshot of the superchain registry that is bundled with reth.
+///
+/// This contains OP-stack chains that don't have a dedicated static spec, e.g. Zora, Mode and
+/// Fraxtal.
+pub static SUPERCHAIN_REGISTRY: Lazy<SuperchainRegistry> = Lazy::new(|| {
+    SuperchainRegistry::from_json(include_str!("../../res/superchain.json"))
+        .expect("Can't deserialize superchain registry snapshot")
+});
+
+/// A set of chain configs of the
+/// [superchain registry](https://github.com/ethereum-optimism/superchain-registry), from which
+/// [ChainSpec]s are materialized at runtime.
+///
+/// # Examples
+///
+/// ```
+/// # use reth_primitives::SUPERCHAIN_REGISTRY;
+/// let zora = SUPERCHAIN_REGISTRY.chain_spec("zora").unwrap();
+/// assert_eq!(zora.chain.id(), 7777777);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuperchainRegistry {
+    /// The hardfork activation times shared by all chains of the superchain, the config of a chain
+    /// can override them.
+    #[serde(default)]
+    pub hardforks: SuperchainHardforks,
+    /// The chains of the registry.
+    #[serde(default)]
+    pub chains: Vec<SuperchainChainConfig>,
+}
+
+impl SuperchainRegistry {
+    /// Parses a registry from JSON.
+    pub fn from_json(s: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(s)
+    }
+
+    /// Adds the chain config to the registry, replacing the config with the same chain id.
+    pub fn insert(&mut self, config: SuperchainChainConfig) {
+        self.chains.retain(|chain| chain.chain_id != config.chain_id);
+        self.chains.push(config);
+    }
+
+    /// Returns the config of the chain with the given name or chain id.
+    ///
+    /// Names are matched case insensitively, with `-` and `_` treated as spaces.
+    pub fn get(&self, name_or_id: &str) -> Option<&SuperchainChainConfig> {
+        if let Ok(chain_id) = name_or_id.parse::<u64>() {
+            return self.chains.iter().find(|chain| chain.chain_id == chain_id)
+        }
+        let normalize = |name: &str| name.to_lowercase().replace(['-', '_'], " ");
+        let name = normalize(name_or_id);
+        self.chains.iter().find(|chain| normalize(&chain.name) == name)
+    }
+
+    /// Returns the [ChainSpec] of the chain with the given name or chain id, with the hardforks of
+    /// the superchain applied unless the chain overrides them.
+    pub fn chain_spec(&self, name_or_id: &str) -> Option<ChainSpec> {
+        self.get(name_or_id).map(|chain| chain.chain_spec(&self.hardforks))
+    }
+}
+
+/// The activation times of the OP-stack hardforks.
+///
+/// Hardforks without an execution layer change, e.g. Delta, are ignored. So are Fjord and later
+/// hardforks, which the bundled EVM doesn't support yet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuperchainHardforks {
+    /// The Regolith activation time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regolith_time: Option<u64>,
+    /// The Canyon activation time, which also activates Shanghai.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canyon_time: Option<u64>,
+    /// The Ecotone activation time, which also activates Cancun.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ecotone_time: Option<u64>,
+}
+
+impl SuperchainHardforks {
+    /// Returns the hardforks, falling back to the given defaults for hardforks that are not set.
+    pub fn or(self, defaults: &Self) -> Self {
+        Self {
+            regolith_time: self.regolith_time.or(defaults.regolith_time),
+            canyon_time: self.canyon_time.or(defaults.canyon_time),
+            ecotone_time: self.ecotone_time.or(defaults.ecotone_time),
+        }
+    }
+}
+
+/// The config of a chain in the superchain registry.
+///
+/// This follows the layout of the chain configs of the registry, fields that are irrelevant to the
+/// execution layer are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuperchainChainConfig {
+    /// The name of the chain.
+    pub name: String,
+    /// The chain id.
+    pub chain_id: u64,
+    /// The hardfork activation times of the chain, these override the hardforks of the
+    /// superchain.
+    #[serde(flatten)]
+    pub hardforks: SuperchainHardforks,
+    /// The genesis of the chain.
+    pub genesis: SuperchainGenesis,
+    /// The EIP-1559 parameters, defaults to the parameters of OP Mainnet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimism: Option<SuperchainEip1559Config>,
+}
+
+impl SuperchainChainConfig {
+    /// Parses a chain config from the TOML format of the registry.
+    pub fn from_toml(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+
+    /// Materializes the [ChainSpec] of the chain, the hardforks of the chain fall back to the
+    /// given superchain hardforks.
+    ///
+    /// The genesis allocation is not part of the registry configs, the spec only carries the
+    /// genesis header fields and the genesis hash.
+    pub fn chain_spec(&self, superchain: &SuperchainHardforks) -> ChainSpec {
+        let genesis = Genesis {
+            config: ChainConfig { chain_id: self.chain_id, ..Default::default() },
+            timestamp: self.genesis.l2_time,
+            gas_limit: self.genesis.system_config.gas_limit,
+            extra_data: Bytes::from_static(BEDROCK_GENESIS_EXTRA_DATA),
+            coinbase: SEQUENCER_FEE_VAULT_ADDRESS,
+            ..Default::default()
+        };
+
+        let mut hardforks = BTreeMap::from([
+            (Hardfork::Frontier, ForkCondition::Block(0)),
+            (Hardfork::Homestead, ForkCondition::Block(0)),
+            (Hardfork::Tangerine, ForkCondition::Block(0)),
+            (Hardfork::SpuriousDragon, ForkCondition::Block(0)),
+            (Hardfork::Byzantium, ForkCondition::Block(0)),
+            (Hardfork::Constantinople, ForkCondition::Block(0)),
+            (Hardfork::Petersburg, ForkCondition::Block(0)),
+            (Hardfork::Istanbul, ForkCondition::Block(0)),
+            (Hardfork::MuirGlacier, ForkCondition::Block(0)),
+            (Hardfork::Berlin, ForkCondition::Block(0)),
+            (Hardfork::London, ForkCondition::Block(0)),
+            (Hardfork::ArrowGlacier, ForkCondition::Block(0)),
+            (Hardfork::GrayGlacier, ForkCondition::Block(0)),
+            (
+                Hardfork::Paris,
+                ForkCondition::TTD { fork_block: Some(0), total_difficulty: U256::ZERO },
+            ),
+            (Hardfork::Bedrock, ForkCondition::Block(0)),
+        ]);
+        let forks = self.hardforks.or(superchain);
+        hardforks.insert(
+            Hardfork::Regolith,
+            ForkCondition::Timestamp(forks.regolith_time.unwrap_or_default()),
+        );
+        if let Some(time) = forks.canyon_time {
+            hardforks.insert(Hardfork::Shanghai, ForkCondition::Timestamp(time));
+            hardforks.insert(Hardfork::Canyon, ForkCondition::Timestamp(time));
+        }
+        if let Some(time) = forks.ecotone_time {
+            hardforks.insert(Hardfork::Cancun, ForkCondition::Timestamp(time));
+            hardforks.insert(Hardfork::Ecotone, ForkCondition::Timestamp(time));
+        }
+
+        let eip1559 = self.optimism.unwrap_or_default();
+        ChainSpec {
+            chain: Chain::from_id(self.chain_id),
+            genesis,
+            genesis_hash: Some(self.genesis.l2.hash),
+            paris_block_and_final_difficulty: Some((0, U256::ZERO)),
+            hardforks,
+            base_fee_params: BaseFeeParamsKind::Variable(
+                vec![
+                    (
+                        Hardfork::London,
+                        BaseFeeParams {
+                            max_change_denominator: eip1559.eip1559_denominator,
+                            elasticity_multiplier: eip1559.eip1559_elasticity,
+                            minimum_base_fee: None,
+                        },
+                    ),
+                    (
+                        Hardfork::Canyon,
+                        BaseFeeParams {
+                            max_change_denominator: eip1559.eip1559_denominator_canyon,
+                            elasticity_multiplier: eip1559.eip1559_elasticity,
+                            minimum_base_fee: None,
+                        },
+                    ),
+                ]
+                .into(),
+            ),
+            state_migrations: StateMigrationSchedule::optimism(),
+            prune_config: PruneConfig::new(1700),
+            snapshot_config: SnapshotConfig::new(1_000_000),
+            ..Default::default()
+        }
+    }
+}
+
+/// The genesis of a chain in the superchain registry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuperchainGenesis {
+    /// The timestamp of the L2 genesis block.
+    pub l2_time: u64,
+    /// The L2 genesis block.
+    pub l2: SuperchainBlockId,
+    /// The system config at genesis.
+    pub system_config: SuperchainSystemConfig,
+}
+
+/// A block referenced by the superchain registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuperchainBlockId {
+    /// The hash of the block.
+    pub hash: B256,
+    /// The number of the block.
+    pub number: u64,
+}
+
+/// The system config of a chain at genesis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuperchainSystemConfig {
+    /// The gas limit of the genesis block.
+    #[serde(rename = "gasLimit")]
+    pub gas_limit: u64,
+}
+
+/// The EIP-1559 parameters of a chain in the superchain registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuperchainEip1559Config {
+    /// The elasticity multiplier.
+    pub eip1559_elasticity: u64,
+    /// The base fee max change denominator before Canyon.
+    pub eip1559_denominator: u64,
+    /// The base fee max change denominator since Canyon.
+    pub eip1559_denominator_canyon: u64,
+}
+
+impl Default for SuperchainEip1559Config {
+    fn default() -> Self {
+        Self {
+            eip1559_elasticity: OP_MAINNET_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+            eip1559_denominator: OP_MAINNET_EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
+            eip1559_denominator_canyon: OP_MAINNET_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ForkHash, ForkId, Head};
+
+    #[test]
+    fn bundled_snapshot() {
+        for name in ["zora", "mode", "fraxtal"] {
+            let spec = SUPERCHAIN_REGISTRY.chain_spec(name).unwrap();
+            assert!(spec.is_optimism());
+            let by_id = SUPERCHAIN_REGISTRY.chain_spec(&spec.chain.id().to_string()).unwrap();
+            assert_eq!(by_id.genesis_hash(), spec.genesis_hash());
+        }
+        assert!(SUPERCHAIN_REGISTRY.chain_spec("unknown").is_none());
+    }
+
+    #[test]
+    fn superchain_hardforks() {
+        let zora = SUPERCHAIN_REGISTRY.chain_spec("zora").unwrap();
+        for (fork, timestamp) in [
+            (Hardfork::Shanghai, 1704992401),
+            (Hardfork::Canyon, 1704992401),
+            (Hardfork::Cancun, 1710374401),
+            (Hardfork::Ecotone, 1710374401),
+        ] {
+            assert_eq!(zora.fork(fork), ForkCondition::Timestamp(timestamp));
+        }
+        assert_eq!(zora.base_fee_params(0), BaseFeeParams::optimism());
+
+        // fraxtal overrides canyon
+        let fraxtal = SUPERCHAIN_REGISTRY.chain_spec("fraxtal").unwrap();
+        assert_eq!(fraxtal.fork(Hardfork::Canyon), ForkCondition::Timestamp(0));
+    }
+
+    #[test]
+    fn parse_registry_toml() {
+        let config = SuperchainChainConfig::from_toml(
+            r#"
+name = "Test Chain"
+chain_id = 424242
+public_rpc = "https://rpc.test"
+block_time = 2
+canyon_time = 10
+delta_time = 20
+ecotone_time = 30
+
+[genesis]
+l2_time = 5
+
+[genesis.l1]
+hash = "0x0000000000000000000000000000000000000000000000000000000000000001"
+number = 1
+
+[genesis.l2]
+hash = "0x0000000000000000000000000000000000000000000000000000000000000002"
+number = 0
+
+[genesis.system_config]
+gasLimit = 30000000
+
+[optimism]
+eip1559_elasticity = 10
+eip1559_denominator = 50
+eip1559_denominator_canyon = 250
+"#,
+        )
+        .unwrap();
+
+        let mut registry = SuperchainRegistry::default();
+        registry.insert(config);
+        let spec = registry.chain_spec("test-chain").unwrap();
+        assert_eq!(spec.genesis_hash(), B256::with_last_byte(2));
+        assert_eq!(spec.genesis.timestamp, 5);
+        assert_eq!(spec.base_fee_params(30).elasticity_multiplier, 10);
+        assert_eq!(
+            spec.fork_id(&Head { timestamp: 30, ..Default::default() }),
+            ForkId { hash: ForkHash::from(spec.genesis_hash()) + 10u64 + 30u64, next: 0 }
+        );
+    }
+}
@@ -7,9 +7,24 @@ pub use alloy_eips::eip4844::{
     FIELD_ELEMENTS_PER_BLOB, FIELD_ELEMENT_BYTES, MAX_BLOBS_PER_BLOCK, MAX_DATA_GAS_PER_BLOCK,
     TARGET_BLOBS_PER_BLOCK, TARGET_DATA_GAS_PER_BLOCK, VERSIONED_HASH_VERSION_KZG,
 };
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Where the KZG trusted setup of a chain is loaded from, see
+/// [ChainSpec::kzg_settings](crate::ChainSpec::kzg_settings).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KzgSettingsSource {
+    /// The setup of the Ethereum KZG ceremony that is embedded in the binary.
+    #[default]
+    Embedded,
+    /// A trusted setup file in the text format of the c-kzg library, loaded on first use.
+    File(PathBuf),
+}
 
 #[cfg(feature = "c-kzg")]
 mod trusted_setup {
+    use super::KzgSettingsSource;
     use crate::kzg::KzgSettings;
     use once_cell::sync::Lazy;
     use std::{io::Write, sync::Arc};
@@ -37,6 +52,21 @@ mod trusted_setup {
         KzgSettings::load_trusted_setup_file(file.path()).map_err(LoadKzgSettingsError::KzgError)
     }
 
+    impl KzgSettingsSource {
+        /// Loads the [KzgSettings] from the source.
+        ///
+        /// The embedded setup is shared, a file is read again on every call.
+        pub fn load(&self) -> Result<Arc<KzgSettings>, LoadKzgSettingsError> {
+            match self {
+                Self::Embedded => Ok(Arc::clone(&MAINNET_KZG_TRUSTED_SETUP)),
+                Self::File(path) => Ok(Arc::new(
+                    KzgSettings::load_trusted_setup_file(path)
+                        .map_err(LoadKzgSettingsError::KzgError)?,
+                )),
+            }
+        }
+    }
+
     /// Error type for loading the trusted setup.
     #[derive(Debug, thiserror::Error)]
     pub enum LoadKzgSettingsError {
@@ -57,5 +87,16 @@ mod trusted_setup {
         fn ensure_load_kzg_settings() {
             let _settings = Arc::clone(&MAINNET_KZG_TRUSTED_SETUP);
         }
+
+        #[test]
+        fn load_kzg_settings_source() {
+            let embedded = KzgSettingsSource::Embedded.load().unwrap();
+            assert!(Arc::ptr_eq(&embedded, &MAINNET_KZG_TRUSTED_SETUP));
+
+            let source: KzgSettingsSource =
+                serde_json::from_str(r#"{"file":"/does/not/exist.txt"}"#).unwrap();
+            assert_eq!(source, KzgSettingsSource::File("/does/not/exist.txt".into()));
+            assert!(source.load().is_err());
+        }
     }
 }
@@ -38,6 +38,14 @@ pub const BEACON_NONCE: u64 = 0u64;
 /// See <https://github.com/paradigmxyz/reth/issues/3233>.
 pub const ETHEREUM_BLOCK_GAS_LIMIT: u64 = 30_000_000;
 
+/// The maximum size of the code of a deployed contract as defined in
+/// [EIP-170](https://eips.ethereum.org/EIPS/eip-170).
+pub const MAX_CODE_SIZE: usize = 24576;
+
+/// The maximum size of the init code of a contract creation as defined in
+/// [EIP-3860](https://eips.ethereum.org/EIPS/eip-3860), twice the EIP-170 contract code size limit.
+pub const MAX_INIT_CODE_SIZE: usize = 2 * MAX_CODE_SIZE;
+
 /// The minimum tx fee below which the txpool will reject the transaction.
 ///
 /// Configured to `7` WEI which is the lowest possible value of base fee under mainnet EIP-1559
@@ -109,6 +117,14 @@ pub const OP_SEPOLIA_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON: u64 = 250;
 #[cfg(feature = "optimism")]
 pub const OP_SEPOLIA_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER: u64 = 10;
 
+/// Base fee max change denominator for Polygon PoS as defined in the Delhi hardfork.
+#[cfg(feature = "polygon")]
+pub const POLYGON_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_DELHI: u64 = 16;
+
+/// Base fee max change denominator for Polygon PoS as defined in the Bhilai hardfork.
+#[cfg(feature = "polygon")]
+pub const POLYGON_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_BHILAI: u64 = 64;
+
 /// Multiplier for converting gwei to wei.
 pub const GWEI_TO_WEI: u64 = 1_000_000_000;
 
@@ -141,6 +157,10 @@ pub const HOLESKY_GENESIS_HASH: B256 =
 pub const DEV_GENESIS_HASH: B256 =
     b256!("2f980576711e3617a5e4d83dd539548ec0f7792007d505a3d2e9674833af2d7c");
 
+/// Testnet genesis hash, with Cancun activated at genesis.
+pub const DEV_CANCUN_GENESIS_HASH: B256 =
+    b256!("d17effc85005cb9645be61aa4a079d5e30ee7a46dc6078f45dd57fd4f0b12b82");
+
 /// Optimism goerli genesis hash.
 pub const GOERLI_OP_GENESIS: B256 =
     b256!("c1fc15cd51159b1f1e5cbc4b82e85c1447ddfa33c52cf1d98d14fba0d6354be1");
@@ -182,8 +202,8 @@ pub const BEACON_CONSENSUS_REORG_UNWIND_DEPTH: u64 = 3;
 
 /// Max seconds from current time allowed for blocks, before they're considered future blocks.
 ///
-/// This is only used when checking whether or not the timestamp for pre-merge blocks is in the
-/// future.
+/// This is the default of chains that don't configure a
+/// [max_future_timestamp_drift](crate::ChainSpec::max_future_timestamp_drift).
 ///
 /// See:
 /// <https://github.com/ethereum/go-ethereum/blob/a196f3e8a22b6ad22ced5c2e3baf32bc3ebd4ec9/consensus/ethash/consensus.go#L227-L229>
@@ -192,6 +212,17 @@ pub const ALLOWED_FUTURE_BLOCK_TIME_SECONDS: u64 = 15;
 /// The address for the beacon roots contract defined in EIP-4788.
 pub const BEACON_ROOTS_ADDRESS: Address = address!("000F3df6D732807Ef1319fB7B8bB8522d0Beac02");
 
+/// The address for the history storage contract defined in EIP-2935.
+pub const HISTORY_STORAGE_ADDRESS: Address = address!("0000F90827F1C53a10cb7A02335B175320002935");
+
+/// The address for the withdrawal request predeploy defined in EIP-7002.
+pub const WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS: Address =
+    address!("00000961Ef480Eb55e80D19ad83579A64c007002");
+
+/// The address for the consolidation request predeploy defined in EIP-7251.
+pub const CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS: Address =
+    address!("0000BBdDc7CE488642fb579F8B00f3a590007251");
+
 /// The caller to be used when calling the EIP-4788 beacon roots contract at the beginning of the
 /// block.
 pub const SYSTEM_ADDRESS: Address = address!("fffffffffffffffffffffffffffffffffffffffe");
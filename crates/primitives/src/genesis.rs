@@ -4,9 +4,75 @@
 #[doc(inline)]
 pub use alloy_genesis::*;
 
+use crate::Address;
+use std::collections::hash_map::Entry;
+
 #[cfg(any(test, feature = "test-utils"))]
 pub use allocator::GenesisAllocator;
 
+/// Extension methods to layer accounts on top of the allocation of a [Genesis].
+pub trait GenesisAllocExt {
+    /// Merges the given accounts into the allocation.
+    ///
+    /// Accounts that are already allocated are updated by the given account: its balance replaces
+    /// the existing one, as do its nonce and code if they are set, and its storage slots are
+    /// merged into the existing storage. If an account is given more than once, the last one wins.
+    fn merge_alloc(self, other: impl IntoIterator<Item = (Address, GenesisAccount)>) -> Self;
+}
+
+impl GenesisAllocExt for Genesis {
+    fn merge_alloc(mut self, other: impl IntoIterator<Item = (Address, GenesisAccount)>) -> Self {
+        for (address, account) in other {
+            match self.alloc.entry(address) {
+                Entry::Vacant(entry) => {
+                    entry.insert(account);
+                }
+                Entry::Occupied(mut entry) => {
+                    let existing = entry.get_mut();
+                    existing.balance = account.balance;
+                    if account.nonce.is_some() {
+                        existing.nonce = account.nonce;
+                    }
+                    if account.code.is_some() {
+                        existing.code = account.code;
+                    }
+                    if let Some(storage) = account.storage {
+                        existing.storage.get_or_insert_with(Default::default).extend(storage);
+                    }
+                }
+            }
+        }
+        self
+    }
+}
+
+/// Generates an arbitrary [Genesis] whose allocation holds a few accounts.
+///
+/// [Genesis] is defined in `alloy-genesis`, which is why this is not an
+/// [Arbitrary](arbitrary::Arbitrary) implementation.
+#[cfg(any(test, feature = "arbitrary"))]
+pub fn arbitrary_genesis(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Genesis> {
+    let mut accounts = Vec::new();
+    for _ in 0..u.int_in_range(0..=8)? {
+        let account =
+            GenesisAccount { nonce: u.arbitrary()?, balance: u.arbitrary()?, ..Default::default() };
+        accounts.push((u.arbitrary()?, account));
+    }
+
+    let genesis = Genesis {
+        config: ChainConfig { chain_id: u.arbitrary()?, ..Default::default() },
+        nonce: u.arbitrary()?,
+        timestamp: u.arbitrary()?,
+        extra_data: u.arbitrary()?,
+        gas_limit: u.arbitrary()?,
+        difficulty: u.arbitrary()?,
+        mix_hash: u.arbitrary()?,
+        coinbase: u.arbitrary()?,
+        ..Default::default()
+    };
+    Ok(genesis.extend_accounts(accounts))
+}
+
 #[cfg(any(test, feature = "test-utils"))]
 mod allocator {
     use crate::{public_key_to_address, Address, Bytes, B256, U256};
@@ -212,3 +278,47 @@ mod allocator {
 
     impl<T> RngDebug for T where T: RngCore + std::fmt::Debug {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bytes, B256, U256};
+    use std::collections::HashMap;
+
+    #[test]
+    fn merge_alloc() {
+        let (slot, other_slot) = (B256::with_last_byte(1), B256::with_last_byte(2));
+        let contract = GenesisAccount {
+            nonce: Some(1),
+            balance: U256::from(1),
+            code: Some(Bytes::from_static(&[0x60, 0x00])),
+            storage: Some(HashMap::from([(slot, B256::with_last_byte(1))])),
+            ..Default::default()
+        };
+        let genesis = Genesis::default().extend_accounts([(Address::with_last_byte(1), contract)]);
+
+        let update = GenesisAccount {
+            balance: U256::from(2),
+            storage: Some(HashMap::from([(other_slot, B256::with_last_byte(2))])),
+            ..Default::default()
+        };
+        let funded = GenesisAccount::default().with_balance(U256::from(3));
+        let genesis = genesis.merge_alloc([
+            (Address::with_last_byte(1), update),
+            (Address::with_last_byte(2), funded.clone()),
+        ]);
+
+        let merged = &genesis.alloc[&Address::with_last_byte(1)];
+        assert_eq!(merged.balance, U256::from(2));
+        assert_eq!(merged.nonce, Some(1));
+        assert_eq!(merged.code, Some(Bytes::from_static(&[0x60, 0x00])));
+        assert_eq!(
+            merged.storage,
+            Some(HashMap::from([
+                (slot, B256::with_last_byte(1)),
+                (other_slot, B256::with_last_byte(2))
+            ]))
+        );
+        assert_eq!(genesis.alloc[&Address::with_last_byte(2)], funded);
+    }
+}
@@ -1,13 +1,8 @@
 use crate::{
     basefee::calculate_next_block_base_fee,
-    constants,
-    constants::{
-        ALLOWED_FUTURE_BLOCK_TIME_SECONDS, EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH,
-        MINIMUM_GAS_LIMIT,
-    },
-    eip4844::{calc_blob_gasprice, calculate_excess_blob_gas},
-    keccak256, Address, BaseFeeParams, BlockHash, BlockNumHash, BlockNumber, Bloom, Bytes,
-    ChainSpec, GotExpected, GotExpectedBoxed, Hardfork, B256, B64, U256,
+    constants::{EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH, MINIMUM_GAS_LIMIT},
+    keccak256, Address, BaseFeeParams, BlobParams, BlockHash, BlockNumHash, BlockNumber, Bloom,
+    Bytes, ChainSpec, GotExpected, GotExpectedBoxed, Hardfork, B256, B64, U256,
 };
 use alloy_rlp::{length_of_length, Decodable, Encodable, EMPTY_LIST_CODE, EMPTY_STRING_CODE};
 use bytes::{Buf, BufMut, BytesMut};
@@ -187,11 +182,14 @@ impl Header {
 
     /// Checks if the block's timestamp is in the future based on the present timestamp.
     ///
-    /// Clock can drift but this can be consensus issue.
-    ///
-    /// Note: This check is relevant only pre-merge.
-    pub fn exceeds_allowed_future_timestamp(&self, present_timestamp: u64) -> bool {
-        self.timestamp > present_timestamp + ALLOWED_FUTURE_BLOCK_TIME_SECONDS
+    /// Clock can drift but this can be consensus issue, so the timestamp may be up to
+    /// `max_future_drift` seconds ahead of the present timestamp.
+    pub fn exceeds_allowed_future_timestamp(
+        &self,
+        present_timestamp: u64,
+        max_future_drift: u64,
+    ) -> bool {
+        self.timestamp > present_timestamp.saturating_add(max_future_drift)
     }
 
     /// Returns the parent block's number and hash
@@ -224,20 +222,23 @@ impl Header {
         self.transactions_root == EMPTY_ROOT_HASH
     }
 
-    /// Returns the blob fee for _this_ block according to the EIP-4844 spec.
+    /// Returns the blob fee for _this_ block according to the EIP-4844 spec, with the given blob
+    /// params of this block.
     ///
     /// Returns `None` if `excess_blob_gas` is None
-    pub fn blob_fee(&self) -> Option<u128> {
-        self.excess_blob_gas.map(calc_blob_gasprice)
+    pub fn blob_fee(&self, blob_params: BlobParams) -> Option<u128> {
+        self.excess_blob_gas.map(|excess_blob_gas| blob_params.calc_blob_fee(excess_blob_gas))
     }
 
-    /// Returns the blob fee for the next block according to the EIP-4844 spec.
+    /// Returns the blob fee for the next block according to the EIP-4844 spec, with the given blob
+    /// params of the next block.
     ///
     /// Returns `None` if `excess_blob_gas` is None.
     ///
     /// See also [Self::next_block_excess_blob_gas]
-    pub fn next_block_blob_fee(&self) -> Option<u128> {
-        self.next_block_excess_blob_gas().map(calc_blob_gasprice)
+    pub fn next_block_blob_fee(&self, blob_params: BlobParams) -> Option<u128> {
+        self.next_block_excess_blob_gas(blob_params)
+            .map(|excess_blob_gas| blob_params.calc_blob_fee(excess_blob_gas))
     }
 
     /// Calculate base fee for next block according to the EIP-1559 spec.
@@ -252,11 +253,12 @@ impl Header {
         ))
     }
 
-    /// Calculate excess blob gas for the next block according to the EIP-4844 spec.
+    /// Calculate excess blob gas for the next block according to the EIP-4844 spec, with the given
+    /// blob params of the next block.
     ///
     /// Returns a `None` if no excess blob gas is set, no EIP-4844 support
-    pub fn next_block_excess_blob_gas(&self) -> Option<u64> {
-        Some(calculate_excess_blob_gas(self.excess_blob_gas?, self.blob_gas_used?))
+    pub fn next_block_excess_blob_gas(&self, blob_params: BlobParams) -> Option<u64> {
+        Some(blob_params.next_block_excess_blob_gas(self.excess_blob_gas?, self.blob_gas_used?))
     }
 
     /// Seal the header with a known hash.
@@ -637,6 +639,15 @@ pub enum HeaderValidationError {
         child_gas_limit: u64,
     },
 
+    /// Error when the child gas limit is above the gas limit cap of the chain.
+    #[error("child gas limit {child_gas_limit} is above the gas limit cap {cap}")]
+    GasLimitAboveCap {
+        /// The gas limit cap of the chain.
+        cap: u64,
+        /// The child gas limit.
+        child_gas_limit: u64,
+    },
+
     /// Error when blob gas used is missing.
     #[error("missing blob gas used")]
     BlobGasUsedMissing,
@@ -839,20 +850,26 @@ impl SealedHeader {
             }
         }
 
+        // Check the gas limit cap of the chain, if any
+        if let Some(cap) =
+            chain_spec.gas_limit_at(self.number, self.timestamp).and_then(|params| params.cap)
+        {
+            if self.gas_limit > cap {
+                return Err(HeaderValidationError::GasLimitAboveCap {
+                    cap,
+                    child_gas_limit: self.gas_limit,
+                })
+            }
+        }
+
         // EIP-1559 check base fee
         if chain_spec.fork(Hardfork::London).active_at_block(self.number) {
             let base_fee = self.base_fee_per_gas.ok_or(HeaderValidationError::BaseFeeMissing)?;
 
-            let expected_base_fee =
-                if chain_spec.fork(Hardfork::London).transitions_at_block(self.number) {
-                    constants::EIP1559_INITIAL_BASE_FEE
-                } else {
-                    // This BaseFeeMissing will not happen as previous blocks are checked to have
-                    // them.
-                    parent
-                        .next_block_base_fee(chain_spec.base_fee_params(self.timestamp))
-                        .ok_or(HeaderValidationError::BaseFeeMissing)?
-                };
+            // This BaseFeeMissing will not happen as previous blocks are checked to have them.
+            let expected_base_fee = chain_spec
+                .next_block_base_fee(parent, self.timestamp)
+                .ok_or(HeaderValidationError::BaseFeeMissing)?;
             if expected_base_fee != base_fee {
                 return Err(HeaderValidationError::BaseFeeDiff(GotExpected {
                     expected: expected_base_fee,
@@ -863,7 +880,10 @@ impl SealedHeader {
 
         // ensure that the blob gas fields for this block
         if chain_spec.fork(Hardfork::Cancun).active_at_timestamp(self.timestamp) {
-            self.validate_4844_header_against_parent(parent)?;
+            self.validate_4844_header_against_parent(
+                parent,
+                chain_spec.blob_params_at_timestamp(self.timestamp),
+            )?;
         }
 
         Ok(())
@@ -872,17 +892,18 @@ impl SealedHeader {
     /// Validates that the EIP-4844 header fields are correct with respect to the parent block. This
     /// ensures that the `blob_gas_used` and `excess_blob_gas` fields exist in the child header, and
     /// that the `excess_blob_gas` field matches the expected `excess_blob_gas` calculated from the
-    /// parent header fields.
+    /// parent header fields and the blob params of this block.
     pub fn validate_4844_header_against_parent(
         &self,
         parent: &SealedHeader,
+        blob_params: BlobParams,
     ) -> Result<(), HeaderValidationError> {
         // From [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844#header-extension):
         //
         // > For the first post-fork block, both parent.blob_gas_used and parent.excess_blob_gas
         // > are evaluated as 0.
         //
-        // This means in the first post-fork block, calculate_excess_blob_gas will return 0.
+        // This means in the first post-fork block, the excess blob gas is 0.
         let parent_blob_gas_used = parent.blob_gas_used.unwrap_or(0);
         let parent_excess_blob_gas = parent.excess_blob_gas.unwrap_or(0);
 
@@ -893,7 +914,7 @@ impl SealedHeader {
             self.excess_blob_gas.ok_or(HeaderValidationError::ExcessBlobGasMissing)?;
 
         let expected_excess_blob_gas =
-            calculate_excess_blob_gas(parent_excess_blob_gas, parent_blob_gas_used);
+            blob_params.next_block_excess_blob_gas(parent_excess_blob_gas, parent_blob_gas_used);
         if expected_excess_blob_gas != excess_blob_gas {
             return Err(HeaderValidationError::ExcessBlobGasDiff {
                 diff: GotExpected { got: excess_blob_gas, expected: expected_excess_blob_gas },
@@ -1394,6 +1415,35 @@ mod tests {
         assert_eq!(child.validate_gas_limit(&parent, &chain_spec), Ok(()));
     }
 
+    #[test]
+    fn test_gas_limit_above_cap() {
+        use crate::{ForkCondition, GasLimitParams, Hardfork};
+        use std::collections::BTreeMap;
+
+        let parent = Header { gas_limit: 1024 * 10, ..Default::default() }.seal_slow();
+        let child = Header {
+            number: 1,
+            parent_hash: parent.hash(),
+            gas_limit: parent.gas_limit + 5,
+            ..Default::default()
+        }
+        .seal_slow();
+        let cap = GasLimitParams { target: None, cap: Some(parent.gas_limit) };
+        let chain_spec = ChainSpec {
+            hardforks: BTreeMap::from([(Hardfork::Frontier, ForkCondition::Block(0))]),
+            gas_limit_schedule: vec![(Hardfork::Frontier, cap)].into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            child.validate_against_parent(&parent, &chain_spec),
+            Err(HeaderValidationError::GasLimitAboveCap {
+                cap: parent.gas_limit,
+                child_gas_limit: child.gas_limit,
+            })
+        );
+    }
+
     #[test]
     fn test_gas_limit_below_minimum() {
         let parent = SealedHeader {
@@ -46,34 +46,45 @@ mod withdrawal;
 pub use account::{Account, Bytecode};
 pub use block::{
     Block, BlockBody, BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag, BlockWithSenders,
-    ForkBlock, RpcBlockHash, SealedBlock, SealedBlockWithSenders,
+    ForkBlock, RawBlockBody, RpcBlockHash, SealedBlock, SealedBlockWithSenders,
 };
+#[cfg(feature = "goerli")]
+pub use chain::GOERLI;
 pub use chain::{
-    AllGenesisFormats, BaseFeeParams, BaseFeeParamsKind, Chain, ChainInfo, ChainSpec,
-    ChainSpecBuilder, DisplayHardforks, ForkBaseFeeParams, ForkCondition, ForkTimestamps,
-    NamedChain, DEV, GOERLI, HOLESKY, MAINNET, SEPOLIA,
+    AllGenesisFormats, BaseFeeParams, BaseFeeParamsKind, BlobParams, BlobScheduleKind, Chain,
+    ChainHardforks, ChainInfo, ChainSpec, ChainSpecBuilder, ChainSpecDiff, ConsensusConfig,
+    DepositContract, DepositEvent, DepositEventError, DevChainSpecBuilder, DisplayFork,
+    DisplayHardforks, EphemeryIteration, ForkActivationCache, ForkBaseFeeParams, ForkBlobParams,
+    ForkDiff, ForkIdTable, ForkScheduleError, ForkStateMigration, GasLimitParams, GasLimitSchedule,
+    GenesisConfigError, GenesisFieldDiff, GenesisHardfork, NamedChain, PrecompileSchedule,
+    StateMigrationSchedule, SystemContracts, ValueDiff, DEV, DEV_CANCUN, DEV_MNEMONIC,
+    DEV_NUMBER_OF_ACCOUNTS, HOLESKY, MAINNET, SEPOLIA,
 };
 pub use compression::*;
 pub use constants::{
-    DEV_GENESIS_HASH, EMPTY_OMMER_ROOT_HASH, GOERLI_GENESIS_HASH, HOLESKY_GENESIS_HASH,
-    KECCAK_EMPTY, MAINNET_GENESIS_HASH, SEPOLIA_GENESIS_HASH,
+    DEV_CANCUN_GENESIS_HASH, DEV_GENESIS_HASH, EMPTY_OMMER_ROOT_HASH, GOERLI_GENESIS_HASH,
+    HOLESKY_GENESIS_HASH, KECCAK_EMPTY, MAINNET_GENESIS_HASH, SEPOLIA_GENESIS_HASH,
 };
 pub use error::{GotExpected, GotExpectedBoxed};
-pub use genesis::{ChainConfig, Genesis, GenesisAccount};
+pub use genesis::{ChainConfig, Genesis, GenesisAccount, GenesisAllocExt};
 pub use header::{Header, HeaderValidationError, HeadersDirection, SealedHeader};
 pub use integer_list::IntegerList;
 pub use log::{logs_bloom, Log};
 pub use net::{
-    goerli_nodes, holesky_nodes, mainnet_nodes, parse_nodes, sepolia_nodes, NodeRecord,
-    GOERLI_BOOTNODES, HOLESKY_BOOTNODES, MAINNET_BOOTNODES, SEPOLIA_BOOTNODES,
+    ethdisco_tree, holesky_nodes, hoodi_nodes, mainnet_nodes, op_nodes, op_testnet_nodes,
+    parse_boot_nodes, parse_nodes, sepolia_nodes, BootNode, BootNodeParseError, Enr, NodeRecord,
+    HOLESKY_BOOTNODES, HOODI_BOOTNODES, MAINNET_BOOTNODES, OP_BOOTNODES, OP_TESTNET_BOOTNODES,
+    SEPOLIA_BOOTNODES,
 };
+#[cfg(feature = "goerli")]
+pub use net::{goerli_nodes, GOERLI_BOOTNODES};
 pub use peer::{PeerId, WithPeerId};
 pub use prune::{
-    PruneCheckpoint, PruneMode, PruneModes, PruneProgress, PruneSegment, PruneSegmentError,
-    ReceiptsLogPruneConfig, MINIMUM_PRUNING_DISTANCE,
+    PruneCheckpoint, PruneConfig, PruneMode, PruneModes, PruneProgress, PruneSegment,
+    PruneSegmentError, ReceiptsLogException, ReceiptsLogPruneConfig, MINIMUM_PRUNING_DISTANCE,
 };
 pub use receipt::{Receipt, ReceiptWithBloom, ReceiptWithBloomRef, Receipts};
-pub use snapshot::SnapshotSegment;
+pub use snapshot::{SnapshotConfig, SnapshotSegment};
 pub use storage::StorageEntry;
 
 #[cfg(feature = "c-kzg")]
@@ -131,10 +142,48 @@ pub use c_kzg as kzg;
 #[cfg(feature = "optimism")]
 mod optimism {
     pub use crate::{
-        chain::{BASE_GOERLI, BASE_MAINNET, BASE_SEPOLIA, OP_GOERLI},
+        chain::{
+            RollupConfig, SuperchainBlockId, SuperchainChainConfig, SuperchainEip1559Config,
+            SuperchainGenesis, SuperchainHardforks, SuperchainRegistry, SuperchainSystemConfig,
+            BASE_GOERLI, BASE_MAINNET, BASE_SEPOLIA, CREATE_2_DEPLOYER_ADDR,
+            CREATE_2_DEPLOYER_BYTECODE, DEFAULT_CHANNEL_TIMEOUT, OP_GOERLI,
+            SEQUENCER_FEE_VAULT_ADDRESS, SUPERCHAIN_REGISTRY,
+        },
         transaction::{TxDeposit, DEPOSIT_TX_TYPE_ID},
     };
 }
 
 #[cfg(feature = "optimism")]
 pub use optimism::*;
+
+/// Polygon PoS specific re-exports
+#[cfg(feature = "polygon")]
+mod polygon {
+    pub use crate::chain::{BorConfig, BOR_STATE_RECEIVER_ADDRESS, BOR_VALIDATOR_SET_ADDRESS};
+}
+
+#[cfg(feature = "polygon")]
+pub use polygon::*;
+
+/// Gnosis specific re-exports
+#[cfg(feature = "gnosis")]
+mod gnosis {
+    pub use crate::chain::{
+        AuraConfig, GnosisConfig, GnosisHardfork, CHIADO_FEE_COLLECTOR_ADDRESS,
+        GNOSIS_FEE_COLLECTOR_ADDRESS,
+    };
+}
+
+#[cfg(feature = "gnosis")]
+pub use gnosis::*;
+
+/// BSC specific re-exports
+#[cfg(feature = "bsc")]
+mod bsc {
+    pub use crate::chain::{
+        BscHardfork, BSC_SLASH_ADDRESS, BSC_SYSTEM_REWARD_ADDRESS, BSC_VALIDATOR_SET_ADDRESS,
+    };
+}
+
+#[cfg(feature = "bsc")]
+pub use bsc::*;
@@ -1,4 +1,10 @@
 pub use reth_rpc_types::NodeRecord;
+use reth_rpc_types::{NodeRecordParseError, PeerId};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, net::IpAddr, str::FromStr};
+
+/// A signed Ethereum Node Record, see [EIP-778](https://eips.ethereum.org/EIPS/eip-778).
+pub type Enr = enr::Enr<secp256k1::SecretKey>;
 
 // <https://github.com/ledgerwatch/erigon/blob/610e648dc43ec8cd6563313e28f06f534a9091b3/params/bootnodes.go>
 
@@ -20,6 +26,7 @@ pub static SEPOLIA_BOOTNODES : [&str; 5] = [
 ];
 
 /// GOERLI bootnodes
+#[cfg(feature = "goerli")]
 pub static GOERLI_BOOTNODES : [&str; 7] = [
     // Upstream bootnodes
     "enode://011f758e6552d105183b1761c5e2dea0111bc20fd5f6422bc7f91e0fabbec9a6595caf6239b37feb773dddd3f87240d99d859431891e4a642cf2a0a9e6cbb98a@51.141.78.53:30303",
@@ -41,12 +48,53 @@ pub static HOLESKY_BOOTNODES : [&str; 2] = [
     "enode://a3435a0155a3e837c02f5e7f5662a2f1fbc25b48e4dc232016e1c51b544cb5b4510ef633ea3278c0e970fa8ad8141e2d4d0f9f95456c537ff05fdf9b31c15072@178.128.136.233:30303",
 ];
 
+/// Ethereum Foundation Hoodi BOOTNODES
+pub static HOODI_BOOTNODES : [&str; 3] = [
+    "enode://2112dd3839dd752813d4df7f40936f06829fc54c0e051a93967c26e5f5d27d99d886b57b4ffcc3c475e930ec9e79c56ef1dbb7d86ca5ee83a9d2ccf36e5c240c@134.209.138.84:30303",
+    "enode://60203fcb3524e07c5df60a14ae1c9c5b24023ea5d47463dfae051d2c9f3219f309657537576090ca0ae641f73d419f53d8e8000d7a464319d4784acd7d2abc41@209.38.124.160:30303",
+    "enode://8ae4a48101b2299597341263da0deb47cc38aa4d3ef4b7430b897d49bfa10eb1ccfe1655679b1ed46928ef177fbf21b86837bd724400196c508427a6f41602cd@134.199.184.23:30303",
+];
+
+/// OP stack mainnet BOOTNODES, shared by OP Mainnet and Base
+pub static OP_BOOTNODES : [&str; 8] = [
+    // OP Labs
+    "enode://ca2774c3c401325850b2477fd7d0f27911efbf79b1e8b335066516e2bd8c4c9e0ba9696a94b1cb030a88eac582305ff55e905e64fb77fe0edcd70a4e5296d3ec@34.65.175.185:30305",
+    "enode://dd751a9ef8912be1bfa7a5e34e2c3785cc5253110bd929f385e07ba7ac19929fb0e0c5d93f77827291f4da02b2232240fbc47ea7ce04c46e333e452f8656b667@34.65.107.0:30305",
+    "enode://c5d289b56a77b6a2342ca29956dfd07aadf45364dde8ab20d1dc4efd4d1bc6b4655d902501daea308f4d8950737a4e93a4dfedd17b49cd5760ffd127837ca965@34.65.202.239:30305",
+    // Base
+    "enode://87a32fd13bd596b2ffca97020e31aef4ddcc1bbd4b95bb633d16c1329f654f34049ed240a36b449fda5e5225d70fe40bc667f53c304b71f8e68fc9d448690b51@3.231.138.188:30301",
+    "enode://ca21ea8f176adb2e229ce2d700830c844af0ea941a1d8152a9513b966fe525e809c3a6c73a2c18a12b74ed6ec4380edf91662778fe0b79f6a591236e49e176f9@184.72.129.189:30301",
+    "enode://acf4507a211ba7c1e52cdf4eef62cdc3c32e7c9c47998954f7ba024026f9a6b2150cd3f0b734d9c78e507ab70d59ba61dfe5c45e1078c7ad0775fb251d7735a2@3.220.145.177:30301",
+    "enode://8a5a5006159bf079d06a04e5eceab2a1ce6e0f721875b2a9c96905336219dbe14203d38f70f3754686a6324f786c2f9852d8c0dd3adac2d080f4db35efc678c5@3.231.11.52:30301",
+    "enode://cdadbe835308ad3557f9a1de8db411da1a260a98f8421d62da90e71da66e55e98aaa8e90aa7ce01b408a54e4bd2253d701218081ded3dbe5efbbc7b41d7cef79@54.198.153.150:30301",
+];
+
+/// OP stack testnet BOOTNODES, shared by OP Sepolia and Base Sepolia
+pub static OP_TESTNET_BOOTNODES : [&str; 5] = [
+    // OP Labs
+    "enode://09d1a6110757b95628cc54ab6cc50a29773075ed00e3a25bd9388807c9a6c007664e88646a6fefd82baad5d8374ba555e426e8aed93f0f0c517e2eb5d929b2a2@34.65.21.188:30305",
+    "enode://2d4e7e9d48f4dd4efe9342706dd1b0024681bd4c3300d021f86fc75eab7865d4e0cbec6fbc883f011cfd6a57423e7e2f6e104baad2b744c3cafaec6bc7dc92c1@34.65.43.171:30305",
+    "enode://9d7a3efefe442351217e73b3a593bcb8efffb55b4807699972145324eab5e6b382152f8d24f6301baebbfb5ecd4127bd3faab2842c04cd432bdf50ba092f6645@34.65.109.126:30305",
+    // Base
+    "enode://548f715f3fc388a7c917ba644a2f16270f1ede48a5d88a4d14ea287cc916068363f3092e39936f1a3e7885198bef0e5af951f1d7b1041ce8ba4010917777e71f@18.210.176.114:30301",
+    "enode://6f10052847a966a725c9f4adf6716f9141155b99a0fb487fea3f51498f4c2a2cb8d534e680ee678f9447db85b93ff7c74562762c3714783a7233ac448603b25f@107.21.251.55:30301",
+];
+
+/// The public key the Ethereum Foundation signs the ethdisco.net DNS node lists with.
+const ETHDISCO_TREE_KEY: &str = "AKA3AM6LPBYEUDMVNU3BSVQJ5AD45Y7YPOHJLEF6W26QOE4VTUDPE";
+
+/// Returns the EIP-1459 `enrtree://` link to the ethdisco.net node list of the given network.
+pub fn ethdisco_tree(network: &str) -> String {
+    format!("enrtree://{ETHDISCO_TREE_KEY}@all.{network}.ethdisco.net")
+}
+
 /// Returns parsed mainnet nodes
 pub fn mainnet_nodes() -> Vec<NodeRecord> {
     parse_nodes(&MAINNET_BOOTNODES[..])
 }
 
 /// Returns parsed goerli nodes
+#[cfg(feature = "goerli")]
 pub fn goerli_nodes() -> Vec<NodeRecord> {
     parse_nodes(&GOERLI_BOOTNODES[..])
 }
@@ -61,11 +109,132 @@ pub fn holesky_nodes() -> Vec<NodeRecord> {
     parse_nodes(&HOLESKY_BOOTNODES[..])
 }
 
+/// Returns parsed hoodi nodes
+pub fn hoodi_nodes() -> Vec<NodeRecord> {
+    parse_nodes(&HOODI_BOOTNODES[..])
+}
+
+/// Returns parsed OP stack mainnet nodes
+pub fn op_nodes() -> Vec<NodeRecord> {
+    parse_nodes(&OP_BOOTNODES[..])
+}
+
+/// Returns parsed OP stack testnet nodes
+pub fn op_testnet_nodes() -> Vec<NodeRecord> {
+    parse_nodes(&OP_TESTNET_BOOTNODES[..])
+}
+
 /// Parses all the nodes
 pub fn parse_nodes(nodes: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<NodeRecord> {
     nodes.into_iter().map(|s| s.as_ref().parse().unwrap()).collect()
 }
 
+/// Parses all the boot nodes, given either as enode URLs or as ENRs.
+pub fn parse_boot_nodes(nodes: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<BootNode> {
+    nodes.into_iter().map(|s| s.as_ref().parse().unwrap()).collect()
+}
+
+/// A boot node of a chain.
+///
+/// Discv4 only needs the enode URL of a node, discv5 can only be bootstrapped from signed ENRs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BootNode {
+    /// An `enode://` URL.
+    Enode(NodeRecord),
+    /// A signed `enr:` record.
+    Enr(Enr),
+}
+
+impl BootNode {
+    /// Returns the enode record of the boot node.
+    ///
+    /// Returns `None` for an ENR that doesn't carry an IP address and a UDP port. The TCP port of
+    /// an ENR defaults to its UDP port.
+    pub fn node_record(&self) -> Option<NodeRecord> {
+        match self {
+            BootNode::Enode(record) => Some(*record),
+            BootNode::Enr(enr) => {
+                let udp_port = enr.udp4().or_else(|| enr.udp6())?;
+                Some(
+                    NodeRecord {
+                        address: enr
+                            .ip4()
+                            .map(IpAddr::from)
+                            .or_else(|| enr.ip6().map(IpAddr::from))?,
+                        tcp_port: enr.tcp4().or_else(|| enr.tcp6()).unwrap_or(udp_port),
+                        udp_port,
+                        id: PeerId::from_slice(&enr.public_key().serialize_uncompressed()[1..]),
+                    }
+                    .into_ipv4_mapped(),
+                )
+            }
+        }
+    }
+
+    /// Returns the ENR of the boot node, if it was given as one.
+    pub fn enr(&self) -> Option<&Enr> {
+        match self {
+            BootNode::Enode(_) => None,
+            BootNode::Enr(enr) => Some(enr),
+        }
+    }
+}
+
+impl From<NodeRecord> for BootNode {
+    fn from(record: NodeRecord) -> Self {
+        BootNode::Enode(record)
+    }
+}
+
+impl From<Enr> for BootNode {
+    fn from(enr: Enr) -> Self {
+        BootNode::Enr(enr)
+    }
+}
+
+impl fmt::Display for BootNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BootNode::Enode(record) => record.fmt(f),
+            BootNode::Enr(enr) => f.write_str(&enr.to_base64()),
+        }
+    }
+}
+
+/// Possible error types when parsing a [BootNode].
+#[derive(Debug, thiserror::Error)]
+pub enum BootNodeParseError {
+    /// The enode URL is invalid.
+    #[error(transparent)]
+    Enode(#[from] NodeRecordParseError),
+    /// The ENR is invalid.
+    #[error("invalid ENR: {0}")]
+    Enr(String),
+}
+
+impl FromStr for BootNode {
+    type Err = BootNodeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("enr:") {
+            return s.parse().map(BootNode::Enr).map_err(BootNodeParseError::Enr)
+        }
+        Ok(BootNode::Enode(s.parse()?))
+    }
+}
+
+impl Serialize for BootNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BootNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -204,4 +373,24 @@ mod tests {
             id: PeerId::from_str("6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0").unwrap(),
         })
     }
+
+    #[test]
+    fn test_boot_node_enr() {
+        // the example record of EIP-778
+        let enr = "enr:-IS4QHCYrYZbAKWCBRlAy5zzaDZXJBGkcnh4MHcBFZntXNFrdvJjX04jRzjzCBOonrkTfj499SZuOh8R33Ls8RRcy5wBgmlkgnY0gmlwhH8AAAGJc2VjcDI1NmsxoQPKY0yuDUmstAHYpMa2_oxVtw0RW_QAdpzBQA8yWM0xOIN1ZHCCdl8";
+        let node: BootNode = enr.parse().unwrap();
+        assert!(node.enr().is_some());
+        assert_eq!(node.to_string(), enr);
+
+        let record = node.node_record().unwrap();
+        assert_eq!(record.address, IpAddr::V4([127, 0, 0, 1].into()));
+        assert_eq!(record.udp_port, 30303);
+        assert_eq!(record.tcp_port, 30303);
+
+        let enode = "enode://6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0@10.3.58.6:30303?discport=30301";
+        let nodes = parse_boot_nodes([enr, enode]);
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[1], BootNode::Enode(enode.parse().unwrap()));
+        assert_eq!(serde_json::to_string(&nodes[1]).unwrap(), format!("\"{enode}\""));
+    }
 }
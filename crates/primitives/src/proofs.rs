@@ -85,8 +85,8 @@ pub fn calculate_receipt_root_optimism(
     // encoding. In the Regolith Hardfork, we must strip the deposit nonce from the
     // receipts before calculating the receipt root. This was corrected in the Canyon
     // hardfork.
-    if chain_spec.is_fork_active_at_timestamp(crate::Hardfork::Regolith, timestamp) &&
-        !chain_spec.is_fork_active_at_timestamp(crate::Hardfork::Canyon, timestamp)
+    if chain_spec.is_regolith_active_at_timestamp(timestamp) &&
+        !chain_spec.is_canyon_active_at_timestamp(timestamp)
     {
         let receipts = receipts
             .iter()
@@ -128,8 +128,8 @@ pub fn calculate_receipt_root_ref_optimism(
     // encoding. In the Regolith Hardfork, we must strip the deposit nonce from the
     // receipts before calculating the receipt root. This was corrected in the Canyon
     // hardfork.
-    if chain_spec.is_fork_active_at_timestamp(crate::Hardfork::Regolith, timestamp) &&
-        !chain_spec.is_fork_active_at_timestamp(crate::Hardfork::Canyon, timestamp)
+    if chain_spec.is_regolith_active_at_timestamp(timestamp) &&
+        !chain_spec.is_canyon_active_at_timestamp(timestamp)
     {
         let receipts = receipts
             .iter()
@@ -261,9 +261,11 @@ pub mod triehash {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "goerli")]
+    use crate::GOERLI;
     use crate::{
         bloom, constants::EMPTY_ROOT_HASH, hex_literal::hex, Block, GenesisAccount, Log, TxType,
-        GOERLI, HOLESKY, MAINNET, SEPOLIA,
+        HOLESKY, MAINNET, SEPOLIA,
     };
     use alloy_primitives::b256;
     use alloy_rlp::Decodable;
@@ -650,13 +652,16 @@ mod tests {
             "mainnet state root mismatch"
         );
 
-        let expected_goerli_state_root =
-            b256!("5d6cded585e73c4e322c30c2f782a336316f17dd85a4863b9d838d2d4b8b3008");
-        let calculated_goerli_state_root = state_root_ref_unhashed(&GOERLI.genesis.alloc);
-        assert_eq!(
-            expected_goerli_state_root, calculated_goerli_state_root,
-            "goerli state root mismatch"
-        );
+        #[cfg(feature = "goerli")]
+        {
+            let expected_goerli_state_root =
+                b256!("5d6cded585e73c4e322c30c2f782a336316f17dd85a4863b9d838d2d4b8b3008");
+            let calculated_goerli_state_root = state_root_ref_unhashed(&GOERLI.genesis.alloc);
+            assert_eq!(
+                expected_goerli_state_root, calculated_goerli_state_root,
+                "goerli state root mismatch"
+            );
+        }
 
         let expected_sepolia_state_root =
             b256!("5eb6e371a698b8d68f665192350ffcecbbbf322916f4b51bd79bb6887da3f494");
@@ -0,0 +1,83 @@
+use crate::PruneSegment;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The pruning parameters of a chain, see [ChainSpec](crate::ChainSpec).
+///
+/// Pruning a row of the receipts is much cheaper than pruning a row of the storage history, so
+/// every segment can have its own delete limit. A chain can also require segments to retain more
+/// history than the [PruneSegment::min_blocks] of the node, e.g. OP chains whose deposit receipts
+/// are needed to derive the chain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PruneConfig {
+    /// The delete limit of the segments without their own limit, per block. In the actual pruner
+    /// run it will be multiplied by the amount of blocks between pruner runs to account for the
+    /// difference in amount of new data coming in.
+    pub delete_limit: usize,
+    /// The delete limits of specific segments, per block.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub segment_delete_limits: BTreeMap<PruneSegment, usize>,
+    /// The number of blocks the chain requires specific segments to retain.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub min_retained_blocks: BTreeMap<PruneSegment, u64>,
+}
+
+impl PruneConfig {
+    /// Creates a config with the same delete limit for all segments.
+    pub fn new(delete_limit: usize) -> Self {
+        Self { delete_limit, ..Default::default() }
+    }
+
+    /// Sets the delete limit of the given segment, per block.
+    pub fn with_segment_delete_limit(mut self, segment: PruneSegment, delete_limit: usize) -> Self {
+        self.segment_delete_limits.insert(segment, delete_limit);
+        self
+    }
+
+    /// Sets the number of blocks the chain requires the given segment to retain.
+    pub fn with_min_retained_blocks(mut self, segment: PruneSegment, blocks: u64) -> Self {
+        self.min_retained_blocks.insert(segment, blocks);
+        self
+    }
+
+    /// Returns the delete limit of the given segment, per block.
+    pub fn delete_limit(&self, segment: PruneSegment) -> usize {
+        self.segment_delete_limits.get(&segment).copied().unwrap_or(self.delete_limit)
+    }
+
+    /// Returns the number of blocks the given segment has to retain, the highest of the
+    /// [PruneSegment::min_blocks] of the node and the history required by the chain.
+    pub fn min_blocks(&self, segment: PruneSegment) -> u64 {
+        self.min_retained_blocks
+            .get(&segment)
+            .map_or(segment.min_blocks(), |blocks| (*blocks).max(segment.min_blocks()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MINIMUM_PRUNING_DISTANCE;
+
+    #[test]
+    fn segment_limits() {
+        let config = PruneConfig::new(3500)
+            .with_segment_delete_limit(PruneSegment::StorageHistory, 500)
+            .with_min_retained_blocks(PruneSegment::Receipts, 100_000)
+            .with_min_retained_blocks(PruneSegment::AccountHistory, 10);
+
+        assert_eq!(config.delete_limit(PruneSegment::Receipts), 3500);
+        assert_eq!(config.delete_limit(PruneSegment::StorageHistory), 500);
+
+        assert_eq!(config.min_blocks(PruneSegment::Receipts), 100_000);
+        assert_eq!(config.min_blocks(PruneSegment::AccountHistory), MINIMUM_PRUNING_DISTANCE);
+        assert_eq!(config.min_blocks(PruneSegment::TransactionLookup), 0);
+
+        let json = r#"{"delete_limit":3500,"segment_delete_limits":{"StorageHistory":500}}"#;
+        assert_eq!(
+            serde_json::from_str::<PruneConfig>(json).unwrap(),
+            PruneConfig::new(3500).with_segment_delete_limit(PruneSegment::StorageHistory, 500)
+        );
+    }
+}
@@ -1,10 +1,12 @@
 mod checkpoint;
+mod config;
 mod mode;
 mod segment;
 mod target;
 
-use crate::{Address, BlockNumber};
+use crate::{Address, BlockNumber, Log, B256};
 pub use checkpoint::PruneCheckpoint;
+pub use config::PruneConfig;
 pub use mode::PruneMode;
 pub use segment::{PruneSegment, PruneSegmentError};
 use serde::{Deserialize, Serialize};
@@ -87,6 +89,29 @@ impl ReceiptsLogPruneConfig {
     }
 }
 
+/// A log that is exempt from receipts pruning: receipts containing a log emitted by `address`
+/// with `topic` as its first topic are retained regardless of the receipts prune modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptsLogException {
+    /// The address of the contract that emits the log.
+    pub address: Address,
+    /// The event signature of the log.
+    pub topic: B256,
+}
+
+impl ReceiptsLogException {
+    /// Returns `true` if the given log is covered by the exception.
+    pub fn matches(&self, log: &Log) -> bool {
+        log.address == self.address && log.topics.first() == Some(&self.topic)
+    }
+
+    /// Returns `true` if any of the given logs is covered by any of the exceptions.
+    pub fn any_matches<'a>(exceptions: &[Self], logs: impl IntoIterator<Item = &'a Log>) -> bool {
+        !exceptions.is_empty() &&
+            logs.into_iter().any(|log| exceptions.iter().any(|exception| exception.matches(log)))
+    }
+}
+
 /// Progress of pruning.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PruneProgress {
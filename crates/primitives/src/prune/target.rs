@@ -1,4 +1,4 @@
-use crate::{PruneMode, ReceiptsLogPruneConfig};
+use crate::{ChainSpec, PruneMode, ReceiptsLogException, ReceiptsLogPruneConfig};
 use serde::{Deserialize, Deserializer, Serialize};
 
 /// Minimum distance from the tip necessary for the node to work correctly:
@@ -37,12 +37,24 @@ pub struct PruneModes {
         deserialize_with = "deserialize_opt_prune_mode_with_min_blocks::<MINIMUM_PRUNING_DISTANCE, _>"
     )]
     pub storage_history: Option<PruneMode>,
+    /// Allows pruning the `DepositEvent` logs of the deposit contract of the chain.
+    ///
+    /// By default they're retained regardless of `receipts` and `receipts_log_filter`, since
+    /// staking infrastructure relies on them, see [PruneModes::with_deposit_contract_guard].
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub prune_deposit_contract_logs: bool,
     /// Receipts pruning configuration by retaining only those receipts that contain logs emitted
     /// by the specified addresses, discarding others. This setting is overridden by `receipts`.
     ///
     /// The [BlockNumber](`crate::BlockNumber`) represents the starting block from which point
     /// onwards the receipts are preserved.
     pub receipts_log_filter: ReceiptsLogPruneConfig,
+    /// Logs whose receipts are never pruned, overriding both `receipts` and
+    /// `receipts_log_filter`.
+    ///
+    /// These are derived from the chain spec rather than configured.
+    #[serde(skip)]
+    pub receipts_log_exceptions: Vec<ReceiptsLogException>,
 }
 
 impl PruneModes {
@@ -59,8 +71,25 @@ impl PruneModes {
             receipts: Some(PruneMode::Full),
             account_history: Some(PruneMode::Full),
             storage_history: Some(PruneMode::Full),
+            prune_deposit_contract_logs: false,
             receipts_log_filter: Default::default(),
+            receipts_log_exceptions: Vec::new(),
+        }
+    }
+
+    /// Exempts the `DepositEvent` logs of the deposit contracts of the given chain from receipts
+    /// pruning, unless [PruneModes::prune_deposit_contract_logs] is set.
+    pub fn with_deposit_contract_guard(mut self, chain_spec: &ChainSpec) -> Self {
+        if !self.prune_deposit_contract_logs {
+            for contract in chain_spec.deposit_contracts() {
+                let exception =
+                    ReceiptsLogException { address: contract.address, topic: contract.topic };
+                if !self.receipts_log_exceptions.contains(&exception) {
+                    self.receipts_log_exceptions.push(exception);
+                }
+            }
         }
+        self
     }
 }
 
@@ -101,6 +130,7 @@ fn deserialize_opt_prune_mode_with_min_blocks<'de, const MIN_BLOCKS: u64, D: Des
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Log, B256, MAINNET};
     use assert_matches::assert_matches;
     use serde::Deserialize;
 
@@ -123,4 +153,26 @@ mod tests {
             Err(err) if err.to_string() == "invalid value: string \"full\", expected prune mode that leaves at least 10 blocks in the database"
         );
     }
+
+    #[test]
+    fn deposit_contract_guard() {
+        let contract = &MAINNET.deposit_contracts()[0];
+        let exception = ReceiptsLogException { address: contract.address, topic: contract.topic };
+
+        let modes = PruneModes::all().with_deposit_contract_guard(&MAINNET);
+        assert_eq!(modes.receipts_log_exceptions, vec![exception]);
+
+        let modes = PruneModes { prune_deposit_contract_logs: true, ..PruneModes::all() }
+            .with_deposit_contract_guard(&MAINNET);
+        assert!(modes.receipts_log_exceptions.is_empty());
+
+        let deposit_log = Log {
+            address: contract.address,
+            topics: vec![contract.topic],
+            data: Default::default(),
+        };
+        let other_log = Log { topics: vec![B256::ZERO], ..deposit_log.clone() };
+        assert!(ReceiptsLogException::any_matches(&[exception], &[other_log.clone(), deposit_log]));
+        assert!(!ReceiptsLogException::any_matches(&[exception], &[other_log]));
+    }
 }
@@ -1,4 +1,34 @@
-use crate::{ChainSpec, Hardfork, Head};
+use crate::{ChainSpec, ForkCondition, Hardfork, Head};
+
+/// Hardforks that can be scheduled by a chain spec but can't be executed.
+///
+/// The executor doesn't implement the Prague EIPs, and the pinned revm has no Osaka spec id.
+pub const UNSUPPORTED_HARDFORKS: [Hardfork; 2] = [Hardfork::Prague, Hardfork::Osaka];
+
+/// Returns an error if the chain spec schedules one of the [UNSUPPORTED_HARDFORKS].
+///
+/// The spec id of a block is derived from the active hardforks, so such a chain spec must be
+/// rejected before any block is executed with it.
+pub fn ensure_supported_hardforks(chain_spec: &ChainSpec) -> Result<(), UnsupportedHardforkError> {
+    match UNSUPPORTED_HARDFORKS
+        .into_iter()
+        .map(|fork| (fork, chain_spec.fork(fork)))
+        .find(|(_, condition)| *condition != ForkCondition::Never)
+    {
+        Some((fork, condition)) => Err(UnsupportedHardforkError { fork, condition }),
+        None => Ok(()),
+    }
+}
+
+/// A chain spec schedules a hardfork that can't be executed, see [ensure_supported_hardforks].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("hardfork {fork} is scheduled at {condition:?} but is not supported by the EVM")]
+pub struct UnsupportedHardforkError {
+    /// The unsupported hardfork.
+    pub fork: Hardfork,
+    /// The activation condition of the hardfork.
+    pub condition: ForkCondition,
+}
 
 /// Returns the spec id at the given timestamp.
 ///
@@ -10,17 +40,24 @@ pub fn revm_spec_by_timestamp_after_merge(
 ) -> revm_primitives::SpecId {
     #[cfg(feature = "optimism")]
     if chain_spec.is_optimism() {
-        if chain_spec.fork(Hardfork::Ecotone).active_at_timestamp(timestamp) {
+        if chain_spec.is_ecotone_active_at_timestamp(timestamp) {
             return revm_primitives::ECOTONE
-        } else if chain_spec.fork(Hardfork::Canyon).active_at_timestamp(timestamp) {
+        } else if chain_spec.is_canyon_active_at_timestamp(timestamp) {
             return revm_primitives::CANYON
-        } else if chain_spec.fork(Hardfork::Regolith).active_at_timestamp(timestamp) {
+        } else if chain_spec.is_regolith_active_at_timestamp(timestamp) {
             return revm_primitives::REGOLITH
         } else {
             return revm_primitives::BEDROCK
         }
     }
 
+    if let Some(fork) = UNSUPPORTED_HARDFORKS
+        .into_iter()
+        .find(|fork| chain_spec.fork(*fork).active_at_timestamp(timestamp))
+    {
+        panic!("hardfork {fork} is not supported by the EVM")
+    }
+
     if chain_spec.is_cancun_active_at_timestamp(timestamp) {
         revm_primitives::CANCUN
     } else if chain_spec.is_shanghai_active_at_timestamp(timestamp) {
@@ -45,36 +82,40 @@ pub fn revm_spec(chain_spec: &ChainSpec, block: Head) -> revm_primitives::SpecId
         }
     }
 
-    if chain_spec.fork(Hardfork::Cancun).active_at_head(&block) {
-        revm_primitives::CANCUN
-    } else if chain_spec.fork(Hardfork::Shanghai).active_at_head(&block) {
-        revm_primitives::SHANGHAI
-    } else if chain_spec.fork(Hardfork::Paris).active_at_head(&block) {
-        revm_primitives::MERGE
-    } else if chain_spec.fork(Hardfork::London).active_at_head(&block) {
-        revm_primitives::LONDON
-    } else if chain_spec.fork(Hardfork::Berlin).active_at_head(&block) {
-        revm_primitives::BERLIN
-    } else if chain_spec.fork(Hardfork::Istanbul).active_at_head(&block) {
-        revm_primitives::ISTANBUL
-    } else if chain_spec.fork(Hardfork::Petersburg).active_at_head(&block) {
-        revm_primitives::PETERSBURG
-    } else if chain_spec.fork(Hardfork::Byzantium).active_at_head(&block) {
-        revm_primitives::BYZANTIUM
-    } else if chain_spec.fork(Hardfork::SpuriousDragon).active_at_head(&block) {
-        revm_primitives::SPURIOUS_DRAGON
-    } else if chain_spec.fork(Hardfork::Tangerine).active_at_head(&block) {
-        revm_primitives::TANGERINE
-    } else if chain_spec.fork(Hardfork::Homestead).active_at_head(&block) {
-        revm_primitives::HOMESTEAD
-    } else if chain_spec.fork(Hardfork::Frontier).active_at_head(&block) {
-        revm_primitives::FRONTIER
-    } else {
+    chain_spec.active_forks_at(&block).filter_map(ethereum_spec_id).last().unwrap_or_else(|| {
         panic!(
             "invalid hardfork chainspec: expected at least one hardfork, got {:?}",
-            chain_spec.hardforks
+            chain_spec.hardforks()
         )
-    }
+    })
+}
+
+/// Returns the spec id that is introduced by the given Ethereum hardfork.
+///
+/// Returns `None` for hardforks that don't change the EVM rules on their own.
+///
+/// # Panics
+///
+/// This function panics if the hardfork is one of the [UNSUPPORTED_HARDFORKS], chain specs that
+/// schedule them are rejected by [ensure_supported_hardforks].
+fn ethereum_spec_id(fork: Hardfork) -> Option<revm_primitives::SpecId> {
+    let spec_id = match fork {
+        Hardfork::Frontier => revm_primitives::FRONTIER,
+        Hardfork::Homestead => revm_primitives::HOMESTEAD,
+        Hardfork::Tangerine => revm_primitives::TANGERINE,
+        Hardfork::SpuriousDragon => revm_primitives::SPURIOUS_DRAGON,
+        Hardfork::Byzantium => revm_primitives::BYZANTIUM,
+        Hardfork::Petersburg => revm_primitives::PETERSBURG,
+        Hardfork::Istanbul => revm_primitives::ISTANBUL,
+        Hardfork::Berlin => revm_primitives::BERLIN,
+        Hardfork::London => revm_primitives::LONDON,
+        Hardfork::Paris => revm_primitives::MERGE,
+        Hardfork::Shanghai => revm_primitives::SHANGHAI,
+        Hardfork::Cancun => revm_primitives::CANCUN,
+        Hardfork::Prague | Hardfork::Osaka => panic!("hardfork {fork} is not supported by the EVM"),
+        _ => return None,
+    };
+    Some(spec_id)
 }
 
 #[cfg(test)]
@@ -165,6 +206,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unsupported_hardforks() {
+        assert_eq!(ensure_supported_hardforks(&MAINNET), Ok(()));
+        assert_eq!(
+            ensure_supported_hardforks(&ChainSpecBuilder::mainnet().cancun_activated().build()),
+            Ok(())
+        );
+        assert_eq!(
+            ensure_supported_hardforks(&ChainSpecBuilder::mainnet().osaka_activated().build()),
+            Err(UnsupportedHardforkError {
+                fork: Hardfork::Prague,
+                condition: ForkCondition::Timestamp(0)
+            })
+        );
+        let spec = ChainSpecBuilder::mainnet()
+            .cancun_activated()
+            .with_fork(Hardfork::Osaka, ForkCondition::Timestamp(100))
+            .build();
+        assert_eq!(
+            ensure_supported_hardforks(&spec),
+            Err(UnsupportedHardforkError {
+                fork: Hardfork::Osaka,
+                condition: ForkCondition::Timestamp(100)
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "hardfork Prague is not supported by the EVM")]
+    fn test_unsupported_revm_spec() {
+        revm_spec(&ChainSpecBuilder::mainnet().prague_activated().build(), Head::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "hardfork Prague is not supported by the EVM")]
+    fn test_unsupported_revm_spec_by_timestamp() {
+        let spec = ChainSpecBuilder::mainnet().prague_activated().build();
+        revm_spec_by_timestamp_after_merge(&spec, 0);
+    }
+
     #[test]
     fn test_eth_spec() {
         assert_eq!(
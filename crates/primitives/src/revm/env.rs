@@ -1,5 +1,5 @@
 use crate::{
-    constants::{BEACON_ROOTS_ADDRESS, SYSTEM_ADDRESS},
+    constants::SYSTEM_ADDRESS,
     recover_signer_unchecked,
     revm_primitives::{BlockEnv, Env, TransactTo, TxEnv},
     Address, Bytes, Chain, ChainSpec, Header, Transaction, TransactionKind,
@@ -18,6 +18,15 @@ pub fn fill_block_env(
 ) {
     let coinbase = block_coinbase(chain_spec, header, after_merge);
     fill_block_env_with_coinbase(block_env, header, after_merge, coinbase);
+
+    // the blob gas price follows the blob params of the chain at this block
+    if let Some(excess_blob_gas) = header.excess_blob_gas {
+        block_env.blob_excess_gas_and_price = Some(
+            chain_spec
+                .blob_params_at_timestamp(header.timestamp)
+                .blob_excess_gas_and_price(excess_blob_gas),
+        );
+    }
 }
 
 /// Fill block environment with coinbase.
@@ -130,10 +139,17 @@ pub fn tx_env_with_recovered(transaction: &TransactionSignedEcRecovered) -> TxEn
 ///  * the call does not follow the EIP-1559 burn semantics - no value should be transferred as
 ///  part of the call
 ///  * if no code exists at `BEACON_ROOTS_ADDRESS`, the call must fail silently
-pub fn fill_tx_env_with_beacon_root_contract_call(env: &mut Env, parent_beacon_block_root: B256) {
+///
+/// The beacon roots contract is [BEACON_ROOTS_ADDRESS](crate::constants::BEACON_ROOTS_ADDRESS),
+/// unless the chain deploys it elsewhere, see [ChainSpec::beacon_roots_contract].
+pub fn fill_tx_env_with_beacon_root_contract_call(
+    env: &mut Env,
+    beacon_roots_contract: Address,
+    parent_beacon_block_root: B256,
+) {
     env.tx = TxEnv {
         caller: SYSTEM_ADDRESS,
-        transact_to: TransactTo::Call(BEACON_ROOTS_ADDRESS),
+        transact_to: TransactTo::Call(beacon_roots_contract),
         // Explicitly set nonce to None so revm does not do any nonce checks
         nonce: None,
         gas_limit: 30_000_000,
@@ -0,0 +1,85 @@
+use crate::{snapshot::SnapshotSegment, BlockNumber};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// The snapshot intervals of a chain, per segment.
+///
+/// Each snapshot of a segment contains that much blocks, a segment with an interval of zero or
+/// that is disabled is not snapshotted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SnapshotConfig {
+    /// The number of blocks per headers snapshot.
+    pub headers_block_interval: u64,
+    /// The number of blocks per transactions snapshot.
+    pub transactions_block_interval: u64,
+    /// The number of blocks per receipts snapshot.
+    pub receipts_block_interval: u64,
+    /// The segments that are not snapshotted.
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub disabled_segments: BTreeSet<SnapshotSegment>,
+}
+
+impl SnapshotConfig {
+    /// Creates a config with the same block interval for all segments.
+    pub fn new(block_interval: u64) -> Self {
+        Self {
+            headers_block_interval: block_interval,
+            transactions_block_interval: block_interval,
+            receipts_block_interval: block_interval,
+            disabled_segments: BTreeSet::new(),
+        }
+    }
+
+    /// Sets the number of blocks per snapshot of the given segment.
+    pub fn with_block_interval(mut self, segment: SnapshotSegment, block_interval: u64) -> Self {
+        match segment {
+            SnapshotSegment::Headers => self.headers_block_interval = block_interval,
+            SnapshotSegment::Transactions => self.transactions_block_interval = block_interval,
+            SnapshotSegment::Receipts => self.receipts_block_interval = block_interval,
+        }
+        self
+    }
+
+    /// Disables snapshotting of the given segment.
+    pub fn disable(mut self, segment: SnapshotSegment) -> Self {
+        self.disabled_segments.insert(segment);
+        self
+    }
+
+    /// Returns the number of blocks per snapshot of the given segment, or `None` if the segment
+    /// is not snapshotted.
+    pub fn block_interval(&self, segment: SnapshotSegment) -> Option<BlockNumber> {
+        let block_interval = match segment {
+            SnapshotSegment::Headers => self.headers_block_interval,
+            SnapshotSegment::Transactions => self.transactions_block_interval,
+            SnapshotSegment::Receipts => self.receipts_block_interval,
+        };
+        (block_interval > 0 && !self.disabled_segments.contains(&segment)).then_some(block_interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_intervals() {
+        let config = SnapshotConfig::new(500_000)
+            .with_block_interval(SnapshotSegment::Receipts, 100_000)
+            .disable(SnapshotSegment::Transactions);
+
+        assert_eq!(config.block_interval(SnapshotSegment::Headers), Some(500_000));
+        assert_eq!(config.block_interval(SnapshotSegment::Receipts), Some(100_000));
+        assert_eq!(config.block_interval(SnapshotSegment::Transactions), None);
+        assert_eq!(SnapshotConfig::default().block_interval(SnapshotSegment::Headers), None);
+
+        let json = r#"{"headers_block_interval":500000,"disabled_segments":["Receipts"]}"#;
+        assert_eq!(
+            serde_json::from_str::<SnapshotConfig>(json).unwrap(),
+            SnapshotConfig::default()
+                .with_block_interval(SnapshotSegment::Headers, 500_000)
+                .disable(SnapshotSegment::Receipts)
+        );
+    }
+}
@@ -1,11 +1,13 @@
 //! Snapshot primitives.
 
 mod compression;
+mod config;
 mod filters;
 mod segment;
 
 use alloy_primitives::BlockNumber;
 pub use compression::Compression;
+pub use config::SnapshotConfig;
 pub use filters::{Filters, InclusionFilter, PerfectHashingFunction};
 pub use segment::{SegmentConfig, SegmentHeader, SnapshotSegment};
 
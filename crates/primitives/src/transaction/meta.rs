@@ -11,6 +11,8 @@ pub struct TransactionMeta {
     pub block_hash: B256,
     /// Number of the block.
     pub block_number: u64,
+    /// Timestamp of the block.
+    pub timestamp: u64,
     /// Base fee of the block.
     pub base_fee: Option<u64>,
     /// The excess blob gas of the block.
@@ -161,6 +161,21 @@ impl Transaction {
         }
     }
 
+    /// Signs the transaction with the given secret key for the given chain.
+    ///
+    /// The chain id of the transaction is set to `chain_id` before signing, so the signature can't
+    /// be replayed on other chains. This turns legacy transactions into
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) transactions.
+    pub fn sign_for_chain(
+        mut self,
+        secret: B256,
+        chain_id: u64,
+    ) -> Result<TransactionSigned, util::secp256k1::Error> {
+        self.set_chain_id(chain_id);
+        let signature = util::secp256k1::sign_message(secret, self.signature_hash())?;
+        Ok(TransactionSigned::from_transaction_and_signature(self, signature))
+    }
+
     /// Gets the transaction's [`TransactionKind`], which is the address of the recipient or
     /// [`TransactionKind::Create`] if the transaction is a contract creation.
     pub fn kind(&self) -> &TransactionKind {
@@ -1592,6 +1607,36 @@ mod tests {
     use secp256k1::{KeyPair, Secp256k1};
     use std::str::FromStr;
 
+    #[test]
+    fn test_sign_for_chain() {
+        let secp = Secp256k1::new();
+        let key_pair = KeyPair::new(&secp, &mut rand::thread_rng());
+        let secret = B256::from_slice(&key_pair.secret_bytes()[..]);
+        let tx = Transaction::Legacy(TxLegacy {
+            chain_id: None,
+            nonce: 1,
+            gas_price: 1_000_000_000,
+            gas_limit: 21_000,
+            to: TransactionKind::Call(Address::with_last_byte(1)),
+            value: U256::from(1).into(),
+            input: Bytes::default(),
+        });
+
+        let signed = tx.sign_for_chain(secret, 1).unwrap();
+        assert_eq!(signed.chain_id(), Some(1));
+        // EIP-155: v = recovery id + chain id * 2 + 35
+        assert!(signed.signature().v(signed.chain_id()) >= 37);
+        assert_eq!(
+            signed.recover_signer(),
+            Some(crate::public_key_to_address(key_pair.public_key()))
+        );
+
+        let mut encoded = BytesMut::new();
+        signed.encode_enveloped(&mut encoded);
+        let decoded = TransactionSigned::decode_enveloped(&mut &encoded[..]).unwrap();
+        assert_eq!(decoded, signed);
+    }
+
     #[test]
     fn test_decode_empty_typed_tx() {
         let input = [0x80u8];
@@ -14,10 +14,8 @@ pub struct PrunerBuilder {
     pub segments: PruneModes,
     /// The number of blocks that can be re-orged.
     pub max_reorg_depth: usize,
-    /// The delete limit for pruner, per block. In the actual pruner run it will be multiplied by
-    /// the amount of blocks between pruner runs to account for the difference in amount of new
-    /// data coming in.
-    pub prune_delete_limit: usize,
+    /// The delete limits and retained history of the chain, per prune segment.
+    pub chain_prune_config: reth_primitives::PruneConfig,
 }
 
 impl PrunerBuilder {
@@ -46,9 +44,9 @@ impl PrunerBuilder {
         self
     }
 
-    /// Sets the delete limit for pruner, per block.
-    pub fn prune_delete_limit(mut self, prune_delete_limit: usize) -> Self {
-        self.prune_delete_limit = prune_delete_limit;
+    /// Sets the delete limits and retained history of the chain, per prune segment.
+    pub fn chain_prune_config(mut self, chain_prune_config: reth_primitives::PruneConfig) -> Self {
+        self.chain_prune_config = chain_prune_config;
         self
     }
 
@@ -64,7 +62,7 @@ impl PrunerBuilder {
             provider_factory,
             segments.into_vec(),
             self.block_interval,
-            self.prune_delete_limit,
+            self.chain_prune_config,
             self.max_reorg_depth,
             highest_snapshots_rx,
         )
@@ -77,7 +75,7 @@ impl Default for PrunerBuilder {
             block_interval: 5,
             segments: PruneModes::none(),
             max_reorg_depth: 64,
-            prune_delete_limit: MAINNET.prune_delete_limit,
+            chain_prune_config: MAINNET.prune_config.clone(),
         }
     }
 }
@@ -6,7 +6,7 @@ use crate::{
     Metrics, PrunerError, PrunerEvent,
 };
 use reth_db::database::Database;
-use reth_primitives::{BlockNumber, PruneMode, PruneProgress, PruneSegment};
+use reth_primitives::{BlockNumber, PruneConfig, PruneMode, PruneProgress, PruneSegment};
 use reth_provider::{ProviderFactory, PruneCheckpointReader};
 use reth_snapshot::HighestSnapshotsTracker;
 use reth_tokio_util::EventListeners;
@@ -32,8 +32,9 @@ pub struct Pruner<DB> {
     /// number is updated with the tip block number the pruner was called with. It's used in
     /// conjunction with `min_block_interval` to determine when the pruning needs to be initiated.
     previous_tip_block_number: Option<BlockNumber>,
-    /// Maximum total entries to prune (delete from database) per block.
-    delete_limit: usize,
+    /// Maximum entries of every segment to prune (delete from database) per block, and the history
+    /// the chain requires the segments to retain.
+    prune_config: PruneConfig,
     /// Maximum number of blocks to be pruned per run, as an additional restriction to
     /// `previous_tip_block_number`.
     prune_max_blocks_per_run: usize,
@@ -49,7 +50,7 @@ impl<DB: Database> Pruner<DB> {
         provider_factory: ProviderFactory<DB>,
         segments: Vec<Arc<dyn Segment<DB>>>,
         min_block_interval: usize,
-        delete_limit: usize,
+        prune_config: PruneConfig,
         prune_max_blocks_per_run: usize,
         highest_snapshots_tracker: HighestSnapshotsTracker,
     ) -> Self {
@@ -58,7 +59,7 @@ impl<DB: Database> Pruner<DB> {
             segments,
             min_block_interval,
             previous_tip_block_number: None,
-            delete_limit,
+            prune_config,
             prune_max_blocks_per_run,
             highest_snapshots_tracker,
             metrics: Metrics::default(),
@@ -91,10 +92,10 @@ impl<DB: Database> Pruner<DB> {
         // TODO(alexey): prune snapshotted segments of data (headers, transactions)
         let highest_snapshots = *self.highest_snapshots_tracker.borrow();
 
-        // Multiply `self.delete_limit` (number of rows to delete per block) by number of blocks
-        // since last pruner run. `self.previous_tip_block_number` is close to
-        // `tip_block_number`, usually within `self.block_interval` blocks, so
-        // `delete_limit` will not be too high. If it's too high, we additionally limit it by
+        // Multiply the delete limits of the segments (number of rows to delete per block) by number
+        // of blocks since last pruner run. `self.previous_tip_block_number` is close to
+        // `tip_block_number`, usually within `self.block_interval` blocks, so the delete limits
+        // will not be too high. If they're too high, we additionally limit them by
         // `self.prune_max_blocks_per_run`.
         //
         // Also see docs for `self.previous_tip_block_number`.
@@ -106,11 +107,12 @@ impl<DB: Database> Pruner<DB> {
                 tip_block_number.saturating_sub(previous_tip_block_number) as usize
             }))
             .min(self.prune_max_blocks_per_run);
-        let mut delete_limit = self.delete_limit * blocks_since_last_run;
 
         for segment in &self.segments {
+            let delete_limit =
+                self.prune_config.delete_limit(segment.segment()) * blocks_since_last_run;
             if delete_limit == 0 {
-                break
+                continue
             }
 
             if let Some((to_block, prune_mode)) = segment
@@ -118,6 +120,11 @@ impl<DB: Database> Pruner<DB> {
                 .map(|mode| mode.prune_target_block(tip_block_number, segment.segment()))
                 .transpose()?
                 .flatten()
+                .and_then(|(to_block, prune_mode)| {
+                    let to_block =
+                        self.retained_target_block(tip_block_number, segment.segment(), to_block)?;
+                    Some((to_block, prune_mode))
+                })
             {
                 trace!(
                     target: "pruner",
@@ -146,7 +153,6 @@ impl<DB: Database> Pruner<DB> {
                     .set(to_block as f64);
 
                 done = done && output.done;
-                delete_limit = delete_limit.saturating_sub(output.pruned);
                 stats.insert(
                     segment.segment(),
                     (PruneProgress::from_done(output.done), output.pruned),
@@ -157,6 +163,8 @@ impl<DB: Database> Pruner<DB> {
         }
 
         if let Some(snapshots) = highest_snapshots {
+            let delete_limit =
+                self.prune_config.delete_limit(PruneSegment::Headers) * blocks_since_last_run;
             if let (Some(to_block), true) = (snapshots.headers, delete_limit > 0) {
                 let prune_mode = PruneMode::Before(to_block + 1);
                 trace!(
@@ -182,13 +190,14 @@ impl<DB: Database> Pruner<DB> {
                     .record(segment_start.elapsed());
 
                 done = done && output.done;
-                delete_limit = delete_limit.saturating_sub(output.pruned);
                 stats.insert(
                     PruneSegment::Headers,
                     (PruneProgress::from_done(output.done), output.pruned),
                 );
             }
 
+            let delete_limit =
+                self.prune_config.delete_limit(PruneSegment::Transactions) * blocks_since_last_run;
             if let (Some(to_block), true) = (snapshots.transactions, delete_limit > 0) {
                 let prune_mode = PruneMode::Before(to_block + 1);
                 trace!(
@@ -214,7 +223,6 @@ impl<DB: Database> Pruner<DB> {
                     .record(segment_start.elapsed());
 
                 done = done && output.done;
-                delete_limit = delete_limit.saturating_sub(output.pruned);
                 stats.insert(
                     PruneSegment::Transactions,
                     (PruneProgress::from_done(output.done), output.pruned),
@@ -232,7 +240,6 @@ impl<DB: Database> Pruner<DB> {
             target: "pruner",
             %tip_block_number,
             ?elapsed,
-            %delete_limit,
             %done,
             ?stats,
             "Pruner finished"
@@ -264,13 +271,29 @@ impl<DB: Database> Pruner<DB> {
             false
         }
     }
+
+    /// Returns the block up to which the segment can be pruned, capping the target block so that
+    /// the history the chain requires the segment to retain is kept.
+    ///
+    /// Returns `None` if the chain is not long enough yet to prune anything.
+    fn retained_target_block(
+        &self,
+        tip_block_number: BlockNumber,
+        segment: PruneSegment,
+        to_block: BlockNumber,
+    ) -> Option<BlockNumber> {
+        match self.prune_config.min_blocks(segment) {
+            0 => Some(to_block),
+            min_blocks => tip_block_number.checked_sub(min_blocks).map(|max| to_block.min(max)),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::Pruner;
     use reth_db::test_utils::create_test_rw_db;
-    use reth_primitives::MAINNET;
+    use reth_primitives::{PruneConfig, PruneSegment, MAINNET};
     use reth_provider::ProviderFactory;
     use tokio::sync::watch;
 
@@ -278,7 +301,14 @@ mod tests {
     fn is_pruning_needed() {
         let db = create_test_rw_db();
         let provider_factory = ProviderFactory::new(db, MAINNET.clone());
-        let mut pruner = Pruner::new(provider_factory, vec![], 5, 0, 5, watch::channel(None).1);
+        let mut pruner = Pruner::new(
+            provider_factory,
+            vec![],
+            5,
+            PruneConfig::default(),
+            5,
+            watch::channel(None).1,
+        );
 
         // No last pruned block number was set before
         let first_block_number = 1;
@@ -294,4 +324,28 @@ mod tests {
         let third_block_number = second_block_number;
         assert!(!pruner.is_pruning_needed(third_block_number));
     }
+
+    #[test]
+    fn retained_target_block() {
+        let db = create_test_rw_db();
+        let provider_factory = ProviderFactory::new(db, MAINNET.clone());
+        let prune_config =
+            PruneConfig::new(10).with_min_retained_blocks(PruneSegment::Receipts, 100_000);
+        let pruner =
+            Pruner::new(provider_factory, vec![], 5, prune_config, 5, watch::channel(None).1);
+
+        assert_eq!(
+            pruner.retained_target_block(50_000, PruneSegment::TransactionLookup, 50_000),
+            Some(50_000)
+        );
+        assert_eq!(
+            pruner.retained_target_block(150_000, PruneSegment::Receipts, 139_936),
+            Some(50_000)
+        );
+        assert_eq!(
+            pruner.retained_target_block(150_000, PruneSegment::Receipts, 10_000),
+            Some(10_000)
+        );
+        assert_eq!(pruner.retained_target_block(50_000, PruneSegment::Receipts, 39_936), None);
+    }
 }
@@ -4,18 +4,26 @@ use crate::{
 };
 use reth_db::{database::Database, tables};
 use reth_interfaces::provider::ProviderResult;
-use reth_primitives::{PruneCheckpoint, PruneMode, PruneSegment};
+use reth_primitives::{PruneCheckpoint, PruneMode, PruneSegment, ReceiptsLogException};
 use reth_provider::{DatabaseProviderRW, PruneCheckpointWriter, TransactionsProvider};
 use tracing::{instrument, trace};
 
 #[derive(Debug)]
 pub struct Receipts {
     mode: PruneMode,
+    /// Receipts with logs matching any of these are retained.
+    log_exceptions: Vec<ReceiptsLogException>,
 }
 
 impl Receipts {
     pub fn new(mode: PruneMode) -> Self {
-        Self { mode }
+        Self { mode, log_exceptions: Vec::new() }
+    }
+
+    /// Retains the receipts with logs matching any of the given exceptions.
+    pub fn with_log_exceptions(mut self, log_exceptions: Vec<ReceiptsLogException>) -> Self {
+        self.log_exceptions = log_exceptions;
+        self
     }
 }
 
@@ -47,11 +55,16 @@ impl<DB: Database> Segment<DB> for Receipts {
         let (pruned, done) = provider.prune_table_with_range::<tables::Receipts>(
             tx_range,
             input.delete_limit,
-            |_| false,
+            |(_, receipt)| ReceiptsLogException::any_matches(&self.log_exceptions, &receipt.logs),
             |row| last_pruned_transaction = row.0,
         )?;
         trace!(target: "pruner", %pruned, %done, "Pruned receipts");
 
+        // Retained receipts at the end of the range have been checked as well
+        if done {
+            last_pruned_transaction = tx_range_end;
+        }
+
         let last_pruned_block = provider
             .transaction_block(last_pruned_transaction)?
             .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?
@@ -4,7 +4,8 @@ use crate::{
 };
 use reth_db::{database::Database, tables};
 use reth_primitives::{
-    PruneCheckpoint, PruneMode, PruneSegment, ReceiptsLogPruneConfig, MINIMUM_PRUNING_DISTANCE,
+    PruneCheckpoint, PruneMode, PruneSegment, ReceiptsLogException, ReceiptsLogPruneConfig,
+    MINIMUM_PRUNING_DISTANCE,
 };
 use reth_provider::{BlockReader, DatabaseProviderRW, PruneCheckpointWriter, TransactionsProvider};
 use tracing::{instrument, trace};
@@ -12,11 +13,19 @@ use tracing::{instrument, trace};
 #[derive(Debug)]
 pub struct ReceiptsByLogs {
     config: ReceiptsLogPruneConfig,
+    /// Receipts with logs matching any of these are retained.
+    log_exceptions: Vec<ReceiptsLogException>,
 }
 
 impl ReceiptsByLogs {
     pub fn new(config: ReceiptsLogPruneConfig) -> Self {
-        Self { config }
+        Self { config, log_exceptions: Vec::new() }
+    }
+
+    /// Retains the receipts with logs matching any of the given exceptions.
+    pub fn with_log_exceptions(mut self, log_exceptions: Vec<ReceiptsLogException>) -> Self {
+        self.log_exceptions = log_exceptions;
+        self
     }
 }
 
@@ -139,10 +148,11 @@ impl<DB: Database> Segment<DB> for ReceiptsByLogs {
                 tx_range,
                 limit,
                 |(tx_num, receipt)| {
-                    let skip = num_addresses > 0 &&
+                    let skip = (num_addresses > 0 &&
                         receipt.logs.iter().any(|log| {
                             filtered_addresses[..num_addresses].contains(&&log.address)
-                        });
+                        })) ||
+                        ReceiptsLogException::any_matches(&self.log_exceptions, &receipt.logs);
 
                     if skip {
                         last_skipped_transaction = *tx_num;
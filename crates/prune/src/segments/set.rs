@@ -46,16 +46,20 @@ impl<DB: Database> SegmentSet<DB> {
             account_history,
             storage_history,
             receipts_log_filter,
+            receipts_log_exceptions,
+            ..
         } = prune_modes;
 
         SegmentSet::default()
             // Receipts
-            .segment_opt(receipts.map(Receipts::new))
+            .segment_opt(receipts.map(|mode| {
+                Receipts::new(mode).with_log_exceptions(receipts_log_exceptions.clone())
+            }))
             // Receipts by logs
-            .segment_opt(
-                (!receipts_log_filter.is_empty())
-                    .then(|| ReceiptsByLogs::new(receipts_log_filter.clone())),
-            )
+            .segment_opt((!receipts_log_filter.is_empty()).then(|| {
+                ReceiptsByLogs::new(receipts_log_filter.clone())
+                    .with_log_exceptions(receipts_log_exceptions.clone())
+            }))
             // Transaction lookup
             .segment_opt(transaction_lookup.map(TransactionLookup::new))
             // Sender recovery
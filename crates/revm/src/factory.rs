@@ -3,6 +3,7 @@ use crate::{
     processor::EVMProcessor,
     stack::{InspectorStack, InspectorStackConfig},
 };
+use parking_lot::RwLock;
 use reth_interfaces::executor::BlockExecutionError;
 use reth_node_api::ConfigureEvm;
 use reth_primitives::ChainSpec;
@@ -10,9 +11,12 @@ use reth_provider::{ExecutorFactory, PrunableBlockExecutor, StateProvider};
 use std::sync::Arc;
 
 /// Factory for creating [EVMProcessor].
+///
+/// The chain spec is shared by all clones of the factory, so an update of the fork schedule with
+/// [EvmProcessorFactory::update_chain_spec] applies to the executors of every clone.
 #[derive(Clone, Debug)]
 pub struct EvmProcessorFactory<EvmConfig> {
-    chain_spec: Arc<ChainSpec>,
+    chain_spec: Arc<RwLock<Arc<ChainSpec>>>,
     stack: Option<InspectorStack>,
     /// Type that defines how the produced EVM should be configured.
     evm_config: EvmConfig,
@@ -21,7 +25,20 @@ pub struct EvmProcessorFactory<EvmConfig> {
 impl<EvmConfig> EvmProcessorFactory<EvmConfig> {
     /// Create new factory
     pub fn new(chain_spec: Arc<ChainSpec>, evm_config: EvmConfig) -> Self {
-        Self { chain_spec, stack: None, evm_config }
+        Self { chain_spec: Arc::new(RwLock::new(chain_spec)), stack: None, evm_config }
+    }
+
+    /// Returns the chain spec the executors are created with.
+    pub fn chain_spec(&self) -> Arc<ChainSpec> {
+        self.chain_spec.read().clone()
+    }
+
+    /// Replaces the chain spec of this factory and all its clones.
+    ///
+    /// Executors that were already created keep the previous spec, this is meant for changes of
+    /// the forks that are not active yet, see [ChainSpec::update_fork_schedule].
+    pub fn update_chain_spec(&self, chain_spec: Arc<ChainSpec>) {
+        *self.chain_spec.write() = chain_spec;
     }
 
     /// Sets the inspector stack for all generated executors.
@@ -47,7 +64,7 @@ where
     ) -> Box<dyn PrunableBlockExecutor<Error = BlockExecutionError> + 'a> {
         let database_state = StateProviderDatabase::new(sp);
         let mut evm = Box::new(EVMProcessor::new_with_db(
-            self.chain_spec.clone(),
+            self.chain_spec(),
             database_state,
             self.evm_config.clone(),
         ));
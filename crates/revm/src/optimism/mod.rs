@@ -2,7 +2,7 @@ use reth_interfaces::{
     executor::{self as reth_executor, BlockExecutionError},
     RethError,
 };
-use reth_primitives::{address, b256, hex, Address, Block, Bytes, ChainSpec, Hardfork, B256, U256};
+use reth_primitives::{hex, Block, ChainSpec, Hardfork, U256};
 use revm::{
     primitives::{Bytecode, HashMap, SpecId},
     DatabaseCommit, L1BlockInfo,
@@ -13,14 +13,6 @@ use tracing::trace;
 /// Optimism-specific processor implementation for the `EVMProcessor`
 pub mod processor;
 
-/// The address of the create2 deployer
-const CREATE_2_DEPLOYER_ADDR: Address = address!("13b0D85CcB8bf860b6b79AF3029fCA081AE9beF2");
-/// The codehash of the create2 deployer contract.
-const CREATE_2_DEPLOYER_CODEHASH: B256 =
-    b256!("b0550b5b431e30d38000efb7107aaa0ade03d48a7198a140edda9d27134468b2");
-/// The raw bytecode of the create2 deployer contract.
-const CREATE_2_DEPLOYER_BYTECODE: [u8; 1584] = hex!("6080604052600436106100435760003560e01c8063076c37b21461004f578063481286e61461007157806356299481146100ba57806366cfa057146100da57600080fd5b3661004a57005b600080fd5b34801561005b57600080fd5b5061006f61006a366004610327565b6100fa565b005b34801561007d57600080fd5b5061009161008c366004610327565b61014a565b60405173ffffffffffffffffffffffffffffffffffffffff909116815260200160405180910390f35b3480156100c657600080fd5b506100916100d5366004610349565b61015d565b3480156100e657600080fd5b5061006f6100f53660046103ca565b610172565b61014582826040518060200161010f9061031a565b7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe082820381018352601f90910116604052610183565b505050565b600061015683836102e7565b9392505050565b600061016a8484846102f0565b949350505050565b61017d838383610183565b50505050565b6000834710156101f4576040517f08c379a000000000000000000000000000000000000000000000000000000000815260206004820152601d60248201527f437265617465323a20696e73756666696369656e742062616c616e636500000060448201526064015b60405180910390fd5b815160000361025f576040517f08c379a000000000000000000000000000000000000000000000000000000000815260206004820181905260248201527f437265617465323a2062797465636f6465206c656e677468206973207a65726f60448201526064016101eb565b8282516020840186f5905073ffffffffffffffffffffffffffffffffffffffff8116610156576040517f08c379a000000000000000000000000000000000000000000000000000000000815260206004820152601960248201527f437265617465323a204661696c6564206f6e206465706c6f790000000000000060448201526064016101eb565b60006101568383305b6000604051836040820152846020820152828152600b8101905060ff815360559020949350505050565b61014e806104ad83390190565b6000806040838503121561033a57600080fd5b50508035926020909101359150565b60008060006060848603121561035e57600080fd5b8335925060208401359150604084013573ffffffffffffffffffffffffffffffffffffffff8116811461039057600080fd5b809150509250925092565b7f4e487b7100000000000000000000000000000000000000000000000000000000600052604160045260246000fd5b6000806000606084860312156103df57600080fd5b8335925060208401359150604084013567ffffffffffffffff8082111561040557600080fd5b818601915086601f83011261041957600080fd5b81358181111561042b5761042b61039b565b604051601f82017fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe0908116603f011681019083821181831017156104715761047161039b565b8160405282815289602084870101111561048a57600080fd5b826020860160208301376000602084830101528095505050505050925092509256fe608060405234801561001057600080fd5b5061012e806100206000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063249cb3fa14602d575b600080fd5b603c603836600460b1565b604e565b60405190815260200160405180910390f35b60008281526020818152604080832073ffffffffffffffffffffffffffffffffffffffff8516845290915281205460ff16608857600060aa565b7fa2ef4600d742022d532d4747cb3547474667d6f13804902513b2ec01c848f4b45b9392505050565b6000806040838503121560c357600080fd5b82359150602083013573ffffffffffffffffffffffffffffffffffffffff8116811460ed57600080fd5b80915050925092905056fea26469706673582212205ffd4e6cede7d06a5daf93d48d0541fc68189eeb16608c1999a82063b666eb1164736f6c63430008130033a2646970667358221220fdc4a0fe96e3b21c108ca155438d37c9143fb01278a3c1d274948bad89c564ba64736f6c63430008130033");
-
 /// The function selector of the "setL1BlockValuesEcotone" function in the L1Block contract.
 const L1_BLOCK_ECOTONE_SELECTOR: [u8; 4] = hex!("440a5e20");
 
@@ -200,7 +192,7 @@ impl RethL1BlockInfo for L1BlockInfo {
             return Ok(U256::ZERO)
         }
 
-        let spec_id = if chain_spec.is_fork_active_at_timestamp(Hardfork::Regolith, timestamp) {
+        let spec_id = if chain_spec.is_regolith_active_at_timestamp(timestamp) {
             SpecId::REGOLITH
         } else if chain_spec.is_fork_active_at_timestamp(Hardfork::Bedrock, timestamp) {
             SpecId::BEDROCK
@@ -220,7 +212,7 @@ impl RethL1BlockInfo for L1BlockInfo {
         timestamp: u64,
         input: &[u8],
     ) -> Result<U256, BlockExecutionError> {
-        let spec_id = if chain_spec.is_fork_active_at_timestamp(Hardfork::Regolith, timestamp) {
+        let spec_id = if chain_spec.is_regolith_active_at_timestamp(timestamp) {
             SpecId::REGOLITH
         } else if chain_spec.is_fork_active_at_timestamp(Hardfork::Bedrock, timestamp) {
             SpecId::BEDROCK
@@ -235,43 +227,42 @@ impl RethL1BlockInfo for L1BlockInfo {
     }
 }
 
-/// The Canyon hardfork issues an irregular state transition that force-deploys the create2
-/// deployer contract. This is done by directly setting the code of the create2 deployer account
-/// prior to executing any transactions on the timestamp activation of the fork.
-pub fn ensure_create2_deployer<DB>(
+/// Applies the [ForkStateMigration](reth_primitives::ForkStateMigration)s of the hardforks that
+/// activate with the given block, e.g. the force-deployment of the create2 deployer at the Canyon
+/// transition. This is done by directly setting the code of the deployed accounts prior to
+/// executing any transactions of the block.
+pub fn apply_fork_state_migrations<DB>(
     chain_spec: Arc<ChainSpec>,
+    block_number: u64,
     timestamp: u64,
     db: &mut revm::State<DB>,
 ) -> Result<(), RethError>
 where
     DB: revm::Database,
 {
-    // If the canyon hardfork is active at the current timestamp, and it was not active at the
-    // previous block timestamp (heuristically, block time is not perfectly constant at 2s), and the
-    // chain is an optimism chain, then we need to force-deploy the create2 deployer contract.
-    if chain_spec.is_optimism() &&
-        chain_spec.is_fork_active_at_timestamp(Hardfork::Canyon, timestamp) &&
-        !chain_spec.is_fork_active_at_timestamp(Hardfork::Canyon, timestamp - 2)
-    {
-        trace!(target: "evm", "Forcing create2 deployer contract deployment on Canyon transition");
+    // The parent timestamp is not known here, heuristically the block time of OP chains is 2s
+    // (it is not perfectly constant), which is enough to detect timestamp based transitions.
+    let parent_timestamp = timestamp.saturating_sub(2);
+    for migration in chain_spec.state_migrations_at(block_number, timestamp, parent_timestamp) {
+        trace!(target: "evm", address = ?migration.address, "Applying fork state migration");
 
-        // Load the create2 deployer account from the cache.
+        // Load the account from the cache.
         let acc = db
-            .load_cache_account(CREATE_2_DEPLOYER_ADDR)
+            .load_cache_account(migration.address)
             .map_err(|_| RethError::Custom("Failed to load account".to_string()))?;
 
-        // Update the account info with the create2 deployer codehash and bytecode.
+        // Update the account info with the migrated codehash and bytecode.
         let mut acc_info = acc.account_info().unwrap_or_default();
-        acc_info.code_hash = CREATE_2_DEPLOYER_CODEHASH;
-        acc_info.code = Some(Bytecode::new_raw(Bytes::from_static(&CREATE_2_DEPLOYER_BYTECODE)));
+        let code = Bytecode::new_raw(migration.code.clone());
+        acc_info.code_hash = code.hash_slow();
+        acc_info.code = Some(code);
 
         // Convert the cache account back into a revm account and mark it as touched.
         let mut revm_acc: revm::primitives::Account = acc_info.into();
         revm_acc.mark_touch();
 
-        // Commit the create2 deployer account to the database.
-        db.commit(HashMap::from([(CREATE_2_DEPLOYER_ADDR, revm_acc)]));
-        return Ok(())
+        // Commit the migrated account to the database.
+        db.commit(HashMap::from([(migration.address, revm_acc)]));
     }
 
     Ok(())
@@ -50,6 +50,7 @@ where
         total_difficulty: U256,
     ) -> Result<(), BlockExecutionError> {
         let receipts = self.execute_inner(block, total_difficulty)?;
+        self.merge_transitions(block);
         self.save_receipts(receipts)
     }
 
@@ -75,11 +76,13 @@ where
                 block.timestamp,
             ) {
                 debug!(target: "evm", %error, ?receipts, "receipts verification failed");
+                self.discard_transitions();
                 return Err(error)
             };
             self.stats.receipt_root_duration += time.elapsed();
         }
 
+        self.merge_transitions(block);
         self.save_receipts(receipts)
     }
 
@@ -95,15 +98,20 @@ where
             return Ok((Vec::new(), 0))
         }
 
-        let is_regolith =
-            self.chain_spec.fork(Hardfork::Regolith).active_at_timestamp(block.timestamp);
-
-        // Ensure that the create2deployer is force-deployed at the canyon transition. Optimism
-        // blocks will always have at least a single transaction in them (the L1 info transaction),
-        // so we can safely assume that this will always be triggered upon the transition and that
-        // the above check for empty blocks will never be hit on OP chains.
-        super::ensure_create2_deployer(self.chain_spec().clone(), block.timestamp, self.db_mut())
-            .map_err(|_| {
+        let is_regolith = self.chain_spec.is_regolith_active_at_timestamp(block.timestamp);
+
+        // Apply the state migrations of forks activating with this block, e.g. the create2deployer
+        // that is force-deployed at the canyon transition. Optimism blocks will always have at
+        // least a single transaction in them (the L1 info transaction), so we can safely assume
+        // that this will always be triggered upon the transition and that the above check for
+        // empty blocks will never be hit on OP chains.
+        super::apply_fork_state_migrations(
+            self.chain_spec().clone(),
+            block.number,
+            block.timestamp,
+            self.db_mut(),
+        )
+        .map_err(|_| {
             BlockExecutionError::OptimismBlockExecution(
                 OptimismBlockExecutionError::ForceCreate2DeployerFail,
             )
@@ -181,7 +189,7 @@ where
                 #[cfg(feature = "optimism")]
                 deposit_receipt_version: self
                     .chain_spec()
-                    .is_fork_active_at_timestamp(Hardfork::Canyon, block.timestamp)
+                    .is_canyon_active_at_timestamp(block.timestamp)
                     .then_some(1),
             });
         }
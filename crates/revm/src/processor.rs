@@ -9,7 +9,7 @@ use reth_node_api::ConfigureEvm;
 use reth_primitives::{
     Address, Block, BlockNumber, BlockWithSenders, Bloom, ChainSpec, GotExpected, Hardfork, Header,
     PruneMode, PruneModes, PruneSegmentError, Receipt, ReceiptWithBloom, Receipts,
-    TransactionSigned, Withdrawals, B256, MINIMUM_PRUNING_DISTANCE, U256,
+    ReceiptsLogException, TransactionSigned, Withdrawals, B256, MINIMUM_PRUNING_DISTANCE, U256,
 };
 use reth_provider::{
     BlockExecutor, BlockExecutorStats, ProviderError, PrunableBlockExecutor, StateProvider,
@@ -19,7 +19,7 @@ use revm::{
     inspector_handle_register,
     interpreter::Host,
     primitives::{CfgEnvWithHandlerCfg, ResultAndState},
-    Evm, Handler, State, StateBuilder,
+    Evm, Handler, State, StateBuilder, TransitionState,
 };
 use std::{sync::Arc, time::Instant};
 
@@ -289,6 +289,9 @@ where
     }
 
     /// Execute the block, verify gas usage and apply post-block state changes.
+    ///
+    /// The state changes of the block are not merged into the bundle state yet, see
+    /// [Self::merge_transitions].
     pub(crate) fn execute_inner(
         &mut self,
         block: &BlockWithSenders,
@@ -311,6 +314,11 @@ where
         self.apply_post_execution_state_change(block, total_difficulty)?;
         self.stats.apply_post_execution_state_changes_duration += time.elapsed();
 
+        Ok(receipts)
+    }
+
+    /// Merges the state changes of the executed block into the bundle state.
+    pub(crate) fn merge_transitions(&mut self, block: &BlockWithSenders) {
         let time = Instant::now();
         let retention = if self.tip.map_or(true, |tip| {
             !self
@@ -332,8 +340,12 @@ where
         if self.first_block.is_none() {
             self.first_block = Some(block.number);
         }
+    }
 
-        Ok(receipts)
+    /// Discards the state changes of the executed block that have not been merged into the bundle
+    /// state.
+    pub(crate) fn discard_transitions(&mut self) {
+        self.db_mut().transition_state = Some(TransitionState::default());
     }
 
     /// Save receipts to the executor.
@@ -358,12 +370,25 @@ where
 
         let block_number = first_block + self.receipts.len() as u64;
 
+        let exceptions = &self.prune_modes.receipts_log_exceptions;
+
         // Block receipts should not be retained
         if self.prune_modes.receipts == Some(PruneMode::Full) ||
                 // [`PruneSegment::Receipts`] takes priority over [`PruneSegment::ContractLogs`]
             self.prune_modes.receipts.map_or(false, |mode| mode.should_prune(block_number, tip))
         {
-            receipts.clear();
+            if exceptions.is_empty() {
+                receipts.clear();
+            } else {
+                // Receipts with exempt logs are retained regardless of the prune mode
+                for receipt in receipts.iter_mut() {
+                    if receipt.as_ref().is_some_and(|receipt| {
+                        !ReceiptsLogException::any_matches(exceptions, &receipt.logs)
+                    }) {
+                        receipt.take();
+                    }
+                }
+            }
             return Ok(())
         }
 
@@ -390,7 +415,9 @@ where
             // If there is an address_filter, and it does not contain any of the
             // contract addresses, then remove this receipts
             if let Some((_, filter)) = &self.pruning_address_filter {
-                if !inner_receipt.logs.iter().any(|log| filter.contains(&log.address)) {
+                if !inner_receipt.logs.iter().any(|log| filter.contains(&log.address)) &&
+                    !ReceiptsLogException::any_matches(exceptions, &inner_receipt.logs)
+                {
                     receipt.take();
                 }
             }
@@ -414,6 +441,7 @@ where
         total_difficulty: U256,
     ) -> Result<(), BlockExecutionError> {
         let receipts = self.execute_inner(block, total_difficulty)?;
+        self.merge_transitions(block);
         self.save_receipts(receipts)
     }
 
@@ -435,11 +463,13 @@ where
                 verify_receipt(block.header.receipts_root, block.header.logs_bloom, receipts.iter())
             {
                 debug!(target: "evm", %error, ?receipts, "receipts verification failed");
+                self.discard_transitions();
                 return Err(error)
             };
             self.stats.receipt_root_duration += time.elapsed();
         }
 
+        self.merge_transitions(block);
         self.save_receipts(receipts)
     }
 
@@ -782,6 +812,63 @@ mod tests {
         assert_eq!(parent_beacon_block_root_storage, U256::from(0x69));
     }
 
+    #[test]
+    fn receipts_mismatch_discards_block_state() {
+        let header = Header {
+            timestamp: 1,
+            number: 1,
+            parent_beacon_block_root: Some(B256::with_last_byte(0x69)),
+            excess_blob_gas: Some(0),
+            receipts_root: B256::with_last_byte(0x42),
+            ..Header::default()
+        };
+
+        let mut db = StateProviderTest::default();
+
+        let beacon_root_contract_account = Account {
+            balance: U256::ZERO,
+            bytecode_hash: Some(keccak256(BEACON_ROOT_CONTRACT_CODE.clone())),
+            nonce: 1,
+        };
+
+        db.insert_account(
+            BEACON_ROOTS_ADDRESS,
+            beacon_root_contract_account,
+            Some(BEACON_ROOT_CONTRACT_CODE.clone()),
+            HashMap::new(),
+        );
+
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::from(&*MAINNET)
+                .shanghai_activated()
+                .with_fork(Hardfork::Cancun, ForkCondition::Timestamp(1))
+                .build(),
+        );
+
+        let mut executor = EVMProcessor::new_with_db(
+            chain_spec,
+            StateProviderDatabase::new(db),
+            EthEvmConfig::default(),
+        );
+
+        // the beacon root contract call changes the state, but the receipts root is wrong
+        let err = executor
+            .execute_and_verify_receipt(
+                &BlockWithSenders {
+                    block: Block { header, body: vec![], ommers: vec![], withdrawals: None },
+                    senders: vec![],
+                },
+                U256::ZERO,
+            )
+            .expect_err("Executing block with wrong receipts root should fail");
+        assert!(err.is_receipts_mismatch());
+
+        // the state changes of the block are not part of the output
+        let state = executor.take_output_state();
+        assert!(state.is_empty());
+        assert!(state.state().state().is_empty());
+    }
+
     #[test]
     fn eip_4788_no_code_cancun() {
         // This test ensures that we "silently fail" when cancun is active and there is no code at
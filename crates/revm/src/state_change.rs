@@ -90,7 +90,11 @@ where
     let previous_env = Box::new(evm.env().clone());
 
     // modify env for pre block call
-    fill_tx_env_with_beacon_root_contract_call(&mut evm.context.evm.env, parent_beacon_block_root);
+    fill_tx_env_with_beacon_root_contract_call(
+        &mut evm.context.evm.env,
+        chain_spec.beacon_roots_contract(),
+        parent_beacon_block_root,
+    );
 
     let mut state = match evm.transact() {
         Ok(res) => res.state,
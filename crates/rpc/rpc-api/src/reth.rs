@@ -1,5 +1,9 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use reth_primitives::{Address, BlockId, U256};
+use reth_primitives::{Address, Bytes, TxHash, B256, U256};
+use reth_rpc_types::{
+    engine::PayloadAttributes, state::StateOverride, BlockOverrides, PayloadBuildReport,
+    RethBlockId, TransactionRequest,
+};
 use std::collections::HashMap;
 
 /// Reth API namespace for reth-specific methods
@@ -7,9 +11,75 @@ use std::collections::HashMap;
 #[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
 pub trait RethApi {
     /// Returns all ETH balance changes in a block
+    ///
+    /// Besides the regular block identifiers, the block can be given as `latest-<n>`.
     #[method(name = "getBalanceChangesInBlock")]
     async fn reth_get_balance_changes_in_block(
         &self,
-        block_id: BlockId,
+        block_id: RethBlockId,
     ) -> RpcResult<HashMap<Address, U256>>;
+
+    /// Creates a subscription that emits an event whenever a pool transaction is replaced,
+    /// dropped or included in a block.
+    ///
+    /// If transaction hashes are provided, only events of these transactions are emitted.
+    #[subscription(
+        name = "subscribeTransactionLifecycle",
+        unsubscribe = "unsubscribeTransactionLifecycle",
+        item = reth_rpc_types::TransactionLifecycleEvent
+    )]
+    async fn reth_subscribe_transaction_lifecycle(
+        &self,
+        hashes: Option<Vec<TxHash>>,
+    ) -> jsonrpsee::core::SubscriptionResult;
+
+    /// Creates a subscription that emits the old and new values of the given storage slots
+    /// whenever a new canonical chain changes them.
+    #[subscription(
+        name = "subscribeStorage",
+        unsubscribe = "unsubscribeStorage",
+        item = reth_rpc_types::StorageChangesEvent
+    )]
+    async fn reth_subscribe_storage(
+        &self,
+        slots: HashMap<Address, Vec<B256>>,
+    ) -> jsonrpsee::core::SubscriptionResult;
+}
+
+/// Reth API namespace for reth-specific methods that execute calls.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethCallApi {
+    /// Executes a new message call immediately without creating a transaction on the block chain,
+    /// like `eth_call`.
+    ///
+    /// Besides the regular block identifiers, the block can be given as `latest-<n>`, the
+    /// canonical block `n` blocks below the latest block. If no block is given, the call is
+    /// executed at the configured default distance below the latest block.
+    #[method(name = "call")]
+    async fn reth_call(
+        &self,
+        request: TransactionRequest,
+        block_id: Option<RethBlockId>,
+        state_overrides: Option<StateOverride>,
+        block_overrides: Option<Box<BlockOverrides>>,
+    ) -> RpcResult<Bytes>;
+}
+
+/// Reth API namespace for reth-specific methods that are only served on the authenticated
+/// engine endpoint.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethEngineApi {
+    /// Builds a block with the given payload attributes on top of the given parent block, or the
+    /// latest block if no parent is given, without storing or submitting it.
+    ///
+    /// Returns the would-be block and the inclusion or exclusion reason of every candidate
+    /// transaction of the pool.
+    #[method(name = "buildPayload")]
+    async fn reth_build_payload(
+        &self,
+        attributes: PayloadAttributes,
+        parent_block_hash: Option<B256>,
+    ) -> RpcResult<PayloadBuildReport>;
 }
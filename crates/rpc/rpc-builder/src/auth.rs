@@ -120,6 +120,7 @@ where
     // Configure the module and start the server.
     let mut module = RpcModule::new(());
     module.merge(engine_api.into_rpc()).expect("No conflicting methods");
+    module.merge(RethEngineApiServer::into_rpc(eth_api.clone())).expect("No conflicting methods");
     let engine_eth = EngineEthApi::new(eth_api, eth_filter);
     module.merge(engine_eth.into_rpc()).expect("No conflicting methods");
 
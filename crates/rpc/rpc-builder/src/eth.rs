@@ -5,7 +5,7 @@ use reth_rpc::{
     eth::{
         cache::{EthStateCache, EthStateCacheConfig},
         gas_oracle::GasPriceOracleConfig,
-        EthFilterConfig, FeeHistoryCacheConfig, RPC_DEFAULT_GAS_CAP,
+        EthFilterConfig, FeeHistoryCacheConfig, SubscriptionBufferConfig, RPC_DEFAULT_GAS_CAP,
     },
     BlockingTaskPool, EthApi, EthFilter, EthPubSub,
 };
@@ -48,6 +48,14 @@ pub struct EthConfig {
     pub stale_filter_ttl: std::time::Duration,
     /// Settings for the fee history cache
     pub fee_history_cache: FeeHistoryCacheConfig,
+    /// Settings for buffering subscription messages of slow subscribers
+    pub subscription_buffer: SubscriptionBufferConfig,
+    /// The number of blocks on top of a block before its logs are emitted to `logs`
+    /// subscriptions.
+    pub log_confirmations: u64,
+    /// The number of blocks below the latest block that `reth_call` requests without a block are
+    /// executed at.
+    pub default_block_distance: u64,
 }
 
 impl EthConfig {
@@ -74,6 +82,9 @@ impl Default for EthConfig {
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
             stale_filter_ttl: DEFAULT_STALE_FILTER_TTL,
             fee_history_cache: FeeHistoryCacheConfig::default(),
+            subscription_buffer: SubscriptionBufferConfig::default(),
+            log_confirmations: 0,
+            default_block_distance: 0,
         }
     }
 }
@@ -114,4 +125,22 @@ impl EthConfig {
         self.rpc_gas_cap = rpc_gas_cap;
         self
     }
+
+    /// Configures how subscription messages are buffered for slow subscribers
+    pub fn subscription_buffer(mut self, subscription_buffer: SubscriptionBufferConfig) -> Self {
+        self.subscription_buffer = subscription_buffer;
+        self
+    }
+
+    /// Configures the confirmation depth of logs emitted to `logs` subscriptions
+    pub fn log_confirmations(mut self, log_confirmations: u64) -> Self {
+        self.log_confirmations = log_confirmations;
+        self
+    }
+
+    /// Configures the default block distance below the latest block for `reth_call`
+    pub fn default_block_distance(mut self, default_block_distance: u64) -> Self {
+        self.default_block_distance = default_block_distance;
+        self
+    }
 }
@@ -196,7 +196,7 @@ use reth_rpc::{
     },
     AdminApi, AuthLayer, BlockingTaskGuard, BlockingTaskPool, Claims, DebugApi, EngineEthApi,
     EthApi, EthFilter, EthPubSub, EthSubscriptionIdProvider, JwtAuthValidator, JwtSecret, NetApi,
-    OtterscanApi, RPCApi, RethApi, TraceApi, TxPoolApi, Web3Api,
+    OtterscanApi, RPCApi, RethApi, RethCallApi, TraceApi, TxPoolApi, Web3Api,
 };
 use reth_rpc_api::servers::*;
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
@@ -1106,6 +1106,7 @@ where
     /// Configures the auth module that includes the
     ///   * `engine_` namespace
     ///   * `api_` namespace
+    ///   * `reth_buildPayload`
     ///
     /// Note: This does _not_ register the `engine_` in this registry.
     pub fn create_auth_module<EngineApi, EngineT>(&mut self, engine_api: EngineApi) -> AuthRpcModule
@@ -1118,7 +1119,10 @@ where
 
         module.merge(engine_api.into_rpc()).expect("No conflicting methods");
 
-        // also merge a subset of `eth_` handlers
+        // also merge the `reth_` payload dry-run and a subset of `eth_` handlers
+        module
+            .merge(RethEngineApiServer::into_rpc(eth_handlers.api.clone()))
+            .expect("No conflicting methods");
         let engine_eth = EngineEthApi::new(eth_handlers.api.clone(), eth_handlers.filter);
         module.merge(engine_eth.into_rpc()).expect("No conflicting methods");
 
@@ -1240,9 +1244,18 @@ where
                         .into(),
                         RethRpcModule::Ots => OtterscanApi::new(eth_api.clone()).into_rpc().into(),
                         RethRpcModule::Reth => {
-                            RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
-                                .into_rpc()
-                                .into()
+                            let mut module = RethApi::new(
+                                self.provider.clone(),
+                                self.pool.clone(),
+                                self.events.clone(),
+                                Box::new(self.executor.clone()),
+                            )
+                            .into_rpc();
+                            let call_api = RethCallApi::new(eth_api.clone())
+                                .with_default_distance(self.config.eth.default_block_distance);
+                            module.merge(call_api.into_rpc()).expect("No conflicts");
+
+                            module.into()
                         }
                         RethRpcModule::EthCallBundle => {
                             EthBundle::new(eth_api.clone(), self.blocking_pool_guard.clone())
@@ -1340,7 +1353,9 @@ where
             self.events.clone(),
             self.network.clone(),
             executor,
-        );
+        )
+        .with_buffer_config(self.config.eth.subscription_buffer)
+        .with_log_confirmations(self.config.eth.log_confirmations);
 
         EthHandlers { api, cache, filter, pubsub, blocking_task_pool }
     }
@@ -1416,8 +1431,13 @@ where
     }
 
     /// Instantiates RethApi
-    pub fn reth_api(&mut self) -> RethApi<Provider> {
-        RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+    pub fn reth_api(&mut self) -> RethApi<Provider, Pool, Events> {
+        RethApi::new(
+            self.provider.clone(),
+            self.pool.clone(),
+            self.events.clone(),
+            Box::new(self.executor.clone()),
+        )
     }
 }
 
@@ -1452,6 +1472,8 @@ pub struct RpcServerConfig {
     ipc_endpoint: Option<Endpoint>,
     /// JWT secret for authentication
     jwt_secret: Option<JwtSecret>,
+    /// Calls that take longer than this are logged
+    slow_query_threshold: Option<Duration>,
 }
 
 impl fmt::Debug for RpcServerConfig {
@@ -1465,6 +1487,7 @@ impl fmt::Debug for RpcServerConfig {
             .field("ipc_server_config", &self.ipc_server_config)
             .field("ipc_endpoint", &self.ipc_endpoint.as_ref().map(|endpoint| endpoint.path()))
             .field("jwt_secret", &self.jwt_secret)
+            .field("slow_query_threshold", &self.slow_query_threshold)
             .finish()
     }
 }
@@ -1582,6 +1605,15 @@ impl RpcServerConfig {
         self
     }
 
+    /// Configures the threshold above which calls are logged as slow queries.
+    ///
+    /// The log entry contains the method, a hash of the params, the duration and the size of the
+    /// response.
+    pub fn with_slow_query_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.slow_query_threshold = threshold;
+        self
+    }
+
     /// Returns true if any server is configured.
     ///
     /// If no server is configured, no server will be be launched on [RpcServerConfig::start].
@@ -1666,7 +1698,7 @@ impl RpcServerConfig {
                     .http
                     .as_ref()
                     .or(modules.ws.as_ref())
-                    .map(RpcServerMetrics::new)
+                    .map(|module| RpcServerMetrics::new(module, self.slow_query_threshold))
                     .unwrap_or_default(),
             )
             .await?;
@@ -1691,7 +1723,11 @@ impl RpcServerConfig {
                 self.ws_cors_domains.take(),
                 self.jwt_secret.clone(),
                 ServerKind::WS(ws_socket_addr),
-                modules.ws.as_ref().map(RpcServerMetrics::new).unwrap_or_default(),
+                modules
+                    .ws
+                    .as_ref()
+                    .map(|module| RpcServerMetrics::new(module, self.slow_query_threshold))
+                    .unwrap_or_default(),
             )
             .await?;
             ws_local_addr = Some(addr);
@@ -1706,7 +1742,11 @@ impl RpcServerConfig {
                 self.http_cors_domains.take(),
                 self.jwt_secret.clone(),
                 ServerKind::Http(http_socket_addr),
-                modules.http.as_ref().map(RpcServerMetrics::new).unwrap_or_default(),
+                modules
+                    .http
+                    .as_ref()
+                    .map(|module| RpcServerMetrics::new(module, self.slow_query_threshold))
+                    .unwrap_or_default(),
             )
             .await?;
             http_local_addr = Some(addr);
@@ -1731,7 +1771,11 @@ impl RpcServerConfig {
         server.ws_http = self.build_ws_http(modules).await?;
 
         if let Some(builder) = self.ipc_server_config {
-            let metrics = modules.ipc.as_ref().map(RpcServerMetrics::new).unwrap_or_default();
+            let metrics = modules
+                .ipc
+                .as_ref()
+                .map(|module| RpcServerMetrics::new(module, self.slow_query_threshold))
+                .unwrap_or_default();
             let ipc_path = self
                 .ipc_endpoint
                 .unwrap_or_else(|| Endpoint::new(DEFAULT_IPC_ENDPOINT.to_string()));
@@ -4,10 +4,29 @@ use jsonrpsee::{
     RpcModule,
 };
 use reth_metrics::{
-    metrics::{Counter, Histogram},
+    metrics::{Counter, Gauge, Histogram},
     Metrics,
 };
-use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Instant};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    hash::{Hash, Hasher},
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// The maximum number of dispatched calls that are tracked per method.
+///
+/// Calls that never finish (e.g. because the connection was dropped) are evicted once this limit is
+/// reached.
+const MAX_PENDING_CALLS_PER_METHOD: usize = 1024;
+
+/// The maximum number of slow calls that wait for the size of their response to be known.
+const MAX_PENDING_SLOW_CALLS: usize = 1024;
 
 /// Metrics for the RPC server
 #[derive(Default, Clone)]
@@ -16,35 +35,49 @@ pub(crate) struct RpcServerMetrics {
 }
 
 /// Metrics for the RPC server
-#[derive(Default, Clone)]
+#[derive(Default)]
 struct RpcServerMetricsInner {
     /// Connection metrics per transport type
     connection_metrics: ConnectionMetrics,
     /// Call metrics per RPC method
     call_metrics: HashMap<&'static str, RpcServerCallMetrics>,
+    /// Calls that were dispatched but did not finish yet, per RPC method
+    pending_calls: HashMap<&'static str, Mutex<VecDeque<PendingCall>>>,
+    /// Logs calls above a configured duration, if enabled
+    slow_query_log: Option<SlowQueryLog>,
+    /// Counter used to assign an id to each request
+    next_request_id: AtomicU64,
 }
 
 impl RpcServerMetrics {
-    pub(crate) fn new(module: &RpcModule<()>) -> Self {
+    /// Creates the metrics for the methods of the given module.
+    ///
+    /// If a `slow_query_threshold` is set, all calls that take longer than the threshold are
+    /// logged.
+    pub(crate) fn new(module: &RpcModule<()>, slow_query_threshold: Option<Duration>) -> Self {
         Self {
             inner: Arc::new(RpcServerMetricsInner {
                 connection_metrics: ConnectionMetrics::default(),
                 call_metrics: HashMap::from_iter(module.method_names().map(|method| {
                     (method, RpcServerCallMetrics::new_with_labels(&[("method", method)]))
                 })),
+                pending_calls: HashMap::from_iter(
+                    module.method_names().map(|method| (method, Mutex::default())),
+                ),
+                slow_query_log: slow_query_threshold.map(SlowQueryLog::new),
+                next_request_id: AtomicU64::new(0),
             }),
         }
     }
 }
 
-#[derive(Clone)]
 struct ConnectionMetrics {
-    http: RpcServerConnectionMetrics,
-    ws: RpcServerConnectionMetrics,
+    http: TransportMetrics,
+    ws: TransportMetrics,
 }
 
 impl ConnectionMetrics {
-    fn get_metrics(&self, transport: TransportProtocol) -> &RpcServerConnectionMetrics {
+    fn get_metrics(&self, transport: TransportProtocol) -> &TransportMetrics {
         match transport {
             TransportProtocol::Http => &self.http,
             TransportProtocol::WebSocket => &self.ws,
@@ -54,11 +87,58 @@ impl ConnectionMetrics {
 
 impl Default for ConnectionMetrics {
     fn default() -> Self {
+        Self { http: TransportMetrics::new("http"), ws: TransportMetrics::new("ws") }
+    }
+}
+
+/// Connection metrics and concurrency counters of a single transport.
+struct TransportMetrics {
+    metrics: RpcServerConnectionMetrics,
+    /// The number of currently open connections
+    connections: AtomicU64,
+    /// The number of requests that are currently being processed
+    requests_in_flight: AtomicU64,
+}
+
+impl TransportMetrics {
+    fn new(transport: &'static str) -> Self {
         Self {
-            http: RpcServerConnectionMetrics::new_with_labels(&[("transport", "http")]),
-            ws: RpcServerConnectionMetrics::new_with_labels(&[("transport", "ws")]),
+            metrics: RpcServerConnectionMetrics::new_with_labels(&[("transport", transport)]),
+            connections: AtomicU64::new(0),
+            requests_in_flight: AtomicU64::new(0),
         }
     }
+
+    fn on_connect(&self) {
+        let connections = self.connections.fetch_add(1, Ordering::Relaxed) + 1;
+        self.metrics.connections_opened.increment(1);
+        self.metrics.connections_active.set(connections as f64);
+    }
+
+    fn on_disconnect(&self) {
+        let connections = self.connections.fetch_sub(1, Ordering::Relaxed).saturating_sub(1);
+        self.metrics.connections_closed.increment(1);
+        self.metrics.connections_active.set(connections as f64);
+    }
+
+    fn on_request(&self) {
+        let in_flight = self.requests_in_flight.fetch_add(1, Ordering::Relaxed) + 1;
+        self.metrics.requests_started.increment(1);
+        self.metrics.requests_in_flight.set(in_flight as f64);
+
+        // sample the concurrency per connection, the IPC server doesn't report connections
+        let connections = self.connections.load(Ordering::Relaxed).max(1);
+        self.metrics.requests_per_connection.record(in_flight as f64 / connections as f64);
+    }
+
+    fn on_response(&self, started_at: Instant, response_bytes: usize) {
+        let in_flight = self.requests_in_flight.fetch_sub(1, Ordering::Relaxed).saturating_sub(1);
+        // capture request latency for this request/response pair
+        self.metrics.request_time_seconds.record(started_at.elapsed().as_secs_f64());
+        self.metrics.response_size_bytes.record(response_bytes as f64);
+        self.metrics.requests_finished.increment(1);
+        self.metrics.requests_in_flight.set(in_flight as f64);
+    }
 }
 
 /// Metrics for the RPC connections
@@ -69,12 +149,20 @@ struct RpcServerConnectionMetrics {
     connections_opened: Counter,
     /// The number of connections closed
     connections_closed: Counter,
+    /// The number of currently open connections
+    connections_active: Gauge,
     /// The number of requests started
     requests_started: Counter,
     /// The number of requests finished
     requests_finished: Counter,
+    /// The number of requests that are currently being processed
+    requests_in_flight: Gauge,
+    /// The number of requests in flight per open connection, sampled when a request starts
+    requests_per_connection: Histogram,
     /// Response for a single request/response pair
     request_time_seconds: Histogram,
+    /// The size of a single response in bytes
+    response_size_bytes: Histogram,
 }
 
 /// Metrics for the RPC calls
@@ -87,12 +175,105 @@ struct RpcServerCallMetrics {
     successful: Counter,
     /// The number of failed calls
     failed: Counter,
+    /// The number of calls that exceeded the slow query threshold
+    slow: Counter,
+    /// Time between receiving the request and dispatching the call
+    queue_time_seconds: Histogram,
     /// Response for a single call
     time_seconds: Histogram,
 }
 
+/// Identifies a single request and the time it was received.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RequestStart {
+    /// Unique id of the request
+    id: u64,
+    /// When the request was received
+    at: Instant,
+}
+
+/// A call that was dispatched to its method handler.
+#[derive(Debug)]
+struct PendingCall {
+    /// When the call was dispatched
+    dispatched_at: Instant,
+    /// Hash of the call's params
+    params_hash: u64,
+}
+
+/// A call that took longer than the slow query threshold.
+#[derive(Debug)]
+struct SlowCall {
+    method: &'static str,
+    params_hash: Option<u64>,
+    queue_time: Option<Duration>,
+    duration: Duration,
+    success: bool,
+}
+
+impl SlowCall {
+    /// Emits the log entry for this call.
+    fn log(&self, transport: TransportProtocol, response_bytes: Option<usize>) {
+        warn!(
+            target: "rpc::server",
+            method = self.method,
+            params_hash = ?self.params_hash.map(|hash| format!("{hash:016x}")),
+            duration = ?self.duration,
+            queue_time = ?self.queue_time,
+            response_bytes,
+            success = self.success,
+            ?transport,
+            "Slow RPC call"
+        );
+    }
+}
+
+/// Collects the calls that exceed the threshold and logs them once the response is sent.
+#[derive(Debug)]
+struct SlowQueryLog {
+    /// Calls that take at least this long are logged
+    threshold: Duration,
+    /// Slow calls per request id, waiting for the size of the response
+    pending: Mutex<HashMap<u64, Vec<SlowCall>>>,
+}
+
+impl SlowQueryLog {
+    fn new(threshold: Duration) -> Self {
+        Self { threshold, pending: Default::default() }
+    }
+
+    /// Records a call that exceeded the threshold, it's logged once the response is sent.
+    fn on_slow_call(&self, request_id: u64, call: SlowCall, transport: TransportProtocol) {
+        let mut pending = self.pending.lock().expect("not poisoned");
+        if pending.len() >= MAX_PENDING_SLOW_CALLS && !pending.contains_key(&request_id) {
+            // too many responses outstanding, log without the response size
+            drop(pending);
+            call.log(transport, None);
+            return
+        }
+        pending.entry(request_id).or_default().push(call);
+    }
+
+    /// Logs all slow calls of the request with the size of the response.
+    fn on_response(&self, request_id: u64, response_bytes: usize, transport: TransportProtocol) {
+        let Some(calls) = self.pending.lock().expect("not poisoned").remove(&request_id) else {
+            return
+        };
+        for call in calls {
+            call.log(transport, Some(response_bytes));
+        }
+    }
+}
+
+/// Returns the hash of the raw params of a call.
+fn params_hash(params: &Params<'_>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    params.as_str().hash(&mut hasher);
+    hasher.finish()
+}
+
 impl Logger for RpcServerMetrics {
-    type Instant = Instant;
+    type Instant = RequestStart;
 
     fn on_connect(
         &self,
@@ -100,23 +281,37 @@ impl Logger for RpcServerMetrics {
         _request: &HttpRequest,
         transport: TransportProtocol,
     ) {
-        self.inner.connection_metrics.get_metrics(transport).connections_opened.increment(1)
+        self.inner.connection_metrics.get_metrics(transport).on_connect()
     }
 
     fn on_request(&self, transport: TransportProtocol) -> Self::Instant {
-        self.inner.connection_metrics.get_metrics(transport).requests_started.increment(1);
-        Instant::now()
+        self.inner.connection_metrics.get_metrics(transport).on_request();
+        RequestStart {
+            id: self.inner.next_request_id.fetch_add(1, Ordering::Relaxed),
+            at: Instant::now(),
+        }
     }
 
     fn on_call(
         &self,
         method_name: &str,
-        _params: Params<'_>,
+        params: Params<'_>,
         _kind: MethodKind,
         _transport: TransportProtocol,
     ) {
         let Some(call_metrics) = self.inner.call_metrics.get(method_name) else { return };
         call_metrics.started.increment(1);
+
+        if let Some(pending) = self.inner.pending_calls.get(method_name) {
+            let mut pending = pending.lock().expect("not poisoned");
+            if pending.len() >= MAX_PENDING_CALLS_PER_METHOD {
+                pending.pop_front();
+            }
+            pending.push_back(PendingCall {
+                dispatched_at: Instant::now(),
+                params_hash: params_hash(&params),
+            });
+        }
     }
 
     fn on_result(
@@ -124,27 +319,63 @@ impl Logger for RpcServerMetrics {
         method_name: &str,
         success: MethodResponseResult,
         started_at: Self::Instant,
-        _transport: TransportProtocol,
+        transport: TransportProtocol,
     ) {
-        let Some(call_metrics) = self.inner.call_metrics.get(method_name) else { return };
+        let Some((method, call_metrics)) = self.inner.call_metrics.get_key_value(method_name)
+        else {
+            return
+        };
 
         // capture call latency
-        call_metrics.time_seconds.record(started_at.elapsed().as_secs_f64());
+        let duration = started_at.at.elapsed();
+        call_metrics.time_seconds.record(duration.as_secs_f64());
         if success.is_success() {
             call_metrics.successful.increment(1);
         } else {
             call_metrics.failed.increment(1);
         }
+
+        // Calls of the same method are matched in dispatch order, so the queue time and params of
+        // concurrent calls to the same method are best effort.
+        let pending = self
+            .inner
+            .pending_calls
+            .get(method_name)
+            .and_then(|pending| pending.lock().expect("not poisoned").pop_front());
+        let queue_time = pending
+            .as_ref()
+            .map(|call| call.dispatched_at.saturating_duration_since(started_at.at));
+        if let Some(queue_time) = queue_time {
+            call_metrics.queue_time_seconds.record(queue_time.as_secs_f64());
+        }
+
+        if let Some(slow_query_log) = &self.inner.slow_query_log {
+            if duration >= slow_query_log.threshold {
+                call_metrics.slow.increment(1);
+                let call = SlowCall {
+                    method: *method,
+                    params_hash: pending.map(|call| call.params_hash),
+                    queue_time,
+                    duration,
+                    success: success.is_success(),
+                };
+                slow_query_log.on_slow_call(started_at.id, call, transport);
+            }
+        }
     }
 
-    fn on_response(&self, _result: &str, started_at: Self::Instant, transport: TransportProtocol) {
-        let metrics = self.inner.connection_metrics.get_metrics(transport);
-        // capture request latency for this request/response pair
-        metrics.request_time_seconds.record(started_at.elapsed().as_secs_f64());
-        metrics.requests_finished.increment(1);
+    fn on_response(&self, result: &str, started_at: Self::Instant, transport: TransportProtocol) {
+        self.inner
+            .connection_metrics
+            .get_metrics(transport)
+            .on_response(started_at.at, result.len());
+
+        if let Some(slow_query_log) = &self.inner.slow_query_log {
+            slow_query_log.on_response(started_at.id, result.len(), transport);
+        }
     }
 
     fn on_disconnect(&self, _remote_addr: SocketAddr, transport: TransportProtocol) {
-        self.inner.connection_metrics.get_metrics(transport).connections_closed.increment(1)
+        self.inner.connection_metrics.get_metrics(transport).on_disconnect()
     }
 }
@@ -9,7 +9,9 @@ use reth_node_api::{
 };
 use reth_payload_builder::PayloadStore;
 use reth_primitives::{BlockHash, BlockHashOrNumber, BlockNumber, ChainSpec, Hardfork, B256, U64};
-use reth_provider::{BlockReader, EvmEnvProvider, HeaderProvider, StateProviderFactory};
+use reth_provider::{
+    BlockReader, ChainSpecProvider, EvmEnvProvider, HeaderProvider, StateProviderFactory,
+};
 use reth_rpc_api::EngineApiServer;
 use reth_rpc_types::engine::{
     CancunPayloadFields, ExecutionPayload, ExecutionPayloadBodiesV1, ExecutionPayloadEnvelopeV2,
@@ -59,14 +61,14 @@ where
     /// Create new instance of [EngineApi].
     pub fn new(
         provider: Provider,
-        chain_spec: Arc<ChainSpec>,
+        chain_spec: impl ChainSpecProvider,
         beacon_consensus: BeaconConsensusEngineHandle<EngineT>,
         payload_store: PayloadStore<EngineT>,
         task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
         let inner = Arc::new(EngineApiInner {
             provider,
-            chain_spec,
+            chain_spec: chain_spec.chain_spec(),
             beacon_consensus,
             payload_store,
             task_spawner,
@@ -384,8 +386,7 @@ where
         let merge_terminal_td = self
             .inner
             .chain_spec
-            .fork(Hardfork::Paris)
-            .ttd()
+            .terminal_total_difficulty()
             .expect("the engine API should not be running for chains w/o paris");
 
         // Compare total difficulty values
@@ -944,4 +945,41 @@ mod tests {
             assert_eq!(config, transition_config);
         }
     }
+
+    // https://github.com/ethereum/execution-apis/blob/main/src/engine/cancun.md#specification
+    mod version_gating {
+        use super::*;
+        use reth_node_api::AttributesValidationError;
+        use reth_primitives::{ChainSpecBuilder, ForkCondition};
+
+        #[test]
+        fn rejects_versions_of_other_forks() {
+            let chain_spec = ChainSpecBuilder::mainnet()
+                .shanghai_activated()
+                .with_fork(Hardfork::Cancun, ForkCondition::Timestamp(1000))
+                .with_fork(Hardfork::Prague, ForkCondition::Timestamp(2000))
+                .build();
+
+            let cases = [
+                (EngineApiMessageVersion::V1, 999, true),
+                (EngineApiMessageVersion::V2, 999, true),
+                (EngineApiMessageVersion::V2, 1000, false),
+                (EngineApiMessageVersion::V3, 999, false),
+                (EngineApiMessageVersion::V3, 1000, true),
+                (EngineApiMessageVersion::V3, 2000, true),
+            ];
+            for (version, timestamp, supported) in cases {
+                let result = validate_payload_timestamp(&chain_spec, version, timestamp);
+                if supported {
+                    assert_matches!(result, Ok(()), "{version:?} at {timestamp}");
+                } else {
+                    assert_matches!(
+                        result,
+                        Err(AttributesValidationError::UnsupportedFork),
+                        "{version:?} at {timestamp}"
+                    );
+                }
+            }
+        }
+    }
 }
@@ -17,6 +17,7 @@ mod net;
 mod otterscan;
 mod peer;
 pub mod relay;
+mod reth;
 mod rpc;
 
 // re-export for convenience
@@ -42,4 +43,5 @@ pub use mev::*;
 pub use net::*;
 pub use otterscan::*;
 pub use peer::*;
+pub use reth::*;
 pub use rpc::*;
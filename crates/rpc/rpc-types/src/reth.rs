@@ -0,0 +1,355 @@
+//! Types for the `reth` namespace.
+
+use crate::{Block, BlockId};
+use alloy_primitives::{Address, Bytes, TxHash, B256, U256, U64};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+/// A block identifier accepted by `reth` namespace methods.
+///
+/// In addition to all [BlockId] forms, this accepts the `latest-<n>` tag, which refers to the
+/// canonical block `n` blocks below the latest block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RethBlockId {
+    /// The canonical block the given number of blocks below the latest block.
+    LatestMinus(u64),
+    /// A regular block identifier.
+    Id(BlockId),
+}
+
+impl From<BlockId> for RethBlockId {
+    fn from(id: BlockId) -> Self {
+        RethBlockId::Id(id)
+    }
+}
+
+impl FromStr for RethBlockId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(distance) = s.strip_prefix("latest-") {
+            return distance
+                .parse()
+                .map(RethBlockId::LatestMinus)
+                .map_err(|err| format!("invalid block distance {distance:?}: {err}"))
+        }
+        serde_json::from_value(serde_json::Value::String(s.to_string()))
+            .map(RethBlockId::Id)
+            .map_err(|err| err.to_string())
+    }
+}
+
+impl Serialize for RethBlockId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            RethBlockId::LatestMinus(distance) => {
+                serializer.serialize_str(&format!("latest-{distance}"))
+            }
+            RethBlockId::Id(id) => id.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RethBlockId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if let Some(s) = value.as_str() {
+            return s.parse().map_err(serde::de::Error::custom)
+        }
+        BlockId::deserialize(value).map(RethBlockId::Id).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A lifecycle event of a pool transaction, emitted by the
+/// `reth_subscribeTransactionLifecycle` subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionLifecycleEvent {
+    /// The hash of the transaction the event belongs to.
+    pub hash: TxHash,
+    /// What happened to the transaction.
+    #[serde(flatten)]
+    pub status: TransactionLifecycleStatus,
+}
+
+/// What happened to a pool transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum TransactionLifecycleStatus {
+    /// The transaction was replaced by another transaction with the same sender and nonce.
+    Replaced {
+        /// The hash of the replacement transaction.
+        #[serde(rename = "replacedBy")]
+        replaced_by: TxHash,
+    },
+    /// The transaction was removed from the pool without being included.
+    Dropped {
+        /// Why the transaction was removed.
+        reason: TransactionDropReason,
+    },
+    /// The transaction was included in a block.
+    Included {
+        /// The hash of the block that includes the transaction.
+        #[serde(rename = "blockHash")]
+        block_hash: B256,
+    },
+}
+
+/// The reason a transaction was dropped from the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionDropReason {
+    /// The transaction was evicted because the pool reached its configured limits.
+    PoolLimits,
+    /// The transaction became invalid, e.g. because its nonce is too low.
+    Invalid,
+}
+
+/// The changes of watched storage slots by a new canonical chain, emitted by the
+/// `reth_subscribeStorage` subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageChangesEvent {
+    /// The number of the new canonical tip.
+    #[serde(rename = "blockNumber")]
+    pub block_number: U64,
+    /// The hash of the new canonical tip.
+    #[serde(rename = "blockHash")]
+    pub block_hash: B256,
+    /// The changed slots, sorted by address and slot.
+    pub changes: Vec<StorageChange>,
+}
+
+/// A watched storage slot whose value changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageChange {
+    /// The address of the account.
+    pub address: Address,
+    /// The storage slot.
+    pub slot: B256,
+    /// The value at the previous canonical tip.
+    #[serde(rename = "previousValue")]
+    pub previous_value: U256,
+    /// The value at the new canonical tip.
+    pub value: U256,
+}
+
+/// The result of a `reth_buildPayload` dry run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PayloadBuildReport {
+    /// The block that would have been built.
+    pub block: Block,
+    /// The outcome of every candidate transaction, in the order they were considered.
+    ///
+    /// Descendants of excluded transactions are not considered and hence not part of this list.
+    pub transactions: Vec<CandidateTransaction>,
+}
+
+/// The outcome of a transaction that was considered for inclusion in a payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CandidateTransaction {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// Whether the transaction was included.
+    #[serde(flatten)]
+    pub outcome: CandidateTransactionOutcome,
+}
+
+/// Whether a candidate transaction was included in a payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum CandidateTransactionOutcome {
+    /// The transaction was included.
+    Included {
+        /// The gas used by the transaction.
+        #[serde(rename = "gasUsed")]
+        gas_used: U64,
+    },
+    /// The transaction was not included.
+    Excluded {
+        /// Why the transaction was not included.
+        reason: TransactionExclusionReason,
+        /// The execution error, if the transaction failed to execute.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+}
+
+/// The reason a candidate transaction was not included in a payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionExclusionReason {
+    /// The transaction's gas limit exceeds the gas remaining in the block.
+    ExceedsBlockGasLimit,
+    /// The transaction's blob gas exceeds the blob gas remaining in the block.
+    ExceedsBlobGasLimit,
+    /// The transaction was submitted privately and must not be included in a shared payload.
+    Private,
+    /// The transaction's nonce is lower than the sender's nonce.
+    NonceTooLow,
+    /// The transaction is invalid in the payload's state.
+    Invalid,
+}
+
+/// A snapshot of the full state of the transaction pool, returned by `txpool_dump`.
+///
+/// The JSON shape matches the pool's own dump so that a written dump can be loaded back into a
+/// pool in a test harness.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolDump {
+    /// The block the pool tracks.
+    pub block_info: TxpoolDumpBlockInfo,
+    /// The on-chain state of all senders of the transactions in the pool.
+    pub senders: Vec<TxpoolDumpSender>,
+    /// All transactions in the pool, ordered by sender and nonce.
+    pub transactions: Vec<TxpoolDumpTransaction>,
+}
+
+/// The block a [TxpoolDump] was taken at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolDumpBlockInfo {
+    /// Hash of the tracked block.
+    pub last_seen_block_hash: B256,
+    /// Number of the tracked block.
+    pub last_seen_block_number: u64,
+    /// The base fee of the next block.
+    pub pending_basefee: u64,
+    /// The blob fee of the next block.
+    pub pending_blob_fee: Option<u128>,
+}
+
+/// The on-chain state of a sender in a [TxpoolDump].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolDumpSender {
+    /// The address of the sender.
+    pub address: Address,
+    /// The on-chain nonce of the sender.
+    pub state_nonce: u64,
+    /// The on-chain balance of the sender.
+    pub balance: U256,
+}
+
+/// A transaction in a [TxpoolDump].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolDumpTransaction {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The sender of the transaction.
+    pub sender: Address,
+    /// The nonce of the transaction.
+    pub nonce: u64,
+    /// The sub-pool that contains the transaction, e.g. `pending` or `queued`.
+    pub subpool: String,
+    /// The names of the state flags of the transaction.
+    pub state: Vec<String>,
+    /// The combined cost of all transactions of the sender that come before this transaction.
+    pub cumulative_cost: U256,
+    /// The priority assigned by the pool's ordering, only set for pending transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    /// The submission identifier of the transaction, only set for pending transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submission_id: Option<u64>,
+    /// Where the transaction originated from, e.g. `local` or `external`.
+    pub origin: String,
+    /// Whether it is allowed to propagate the transaction.
+    pub propagate: bool,
+    /// The EIP-2718 encoded transaction.
+    pub raw: Bytes,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlockNumberOrTag;
+
+    #[test]
+    fn serde_reth_block_id() {
+        let id: RethBlockId = serde_json::from_str(r#""latest-5""#).unwrap();
+        assert_eq!(id, RethBlockId::LatestMinus(5));
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""latest-5""#);
+
+        let id: RethBlockId = serde_json::from_str(r#""latest""#).unwrap();
+        assert_eq!(id, RethBlockId::Id(BlockId::Number(BlockNumberOrTag::Latest)));
+
+        let id: RethBlockId = serde_json::from_str(r#""0x10""#).unwrap();
+        assert_eq!(id, RethBlockId::Id(BlockId::Number(BlockNumberOrTag::Number(16))));
+
+        let id: RethBlockId = serde_json::from_str(
+            r#"{"blockHash":"0x0000000000000000000000000000000000000000000000000000000000000001"}"#,
+        )
+        .unwrap();
+        assert_eq!(id, RethBlockId::Id(BlockId::from(B256::with_last_byte(1))));
+
+        assert!(serde_json::from_str::<RethBlockId>(r#""latest-x""#).is_err());
+        assert!(serde_json::from_str::<RethBlockId>(r#""latest--1""#).is_err());
+    }
+
+    #[test]
+    fn serde_transaction_lifecycle_event() {
+        let s = r#"{"hash":"0x0000000000000000000000000000000000000000000000000000000000000001","status":"replaced","replacedBy":"0x0000000000000000000000000000000000000000000000000000000000000002"}"#;
+        let event: TransactionLifecycleEvent = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            event,
+            TransactionLifecycleEvent {
+                hash: TxHash::with_last_byte(1),
+                status: TransactionLifecycleStatus::Replaced {
+                    replaced_by: TxHash::with_last_byte(2)
+                },
+            }
+        );
+        assert_eq!(serde_json::to_string(&event).unwrap(), s);
+
+        let s = r#"{"hash":"0x0000000000000000000000000000000000000000000000000000000000000001","status":"dropped","reason":"poolLimits"}"#;
+        let event: TransactionLifecycleEvent = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            event.status,
+            TransactionLifecycleStatus::Dropped { reason: TransactionDropReason::PoolLimits }
+        );
+        assert_eq!(serde_json::to_string(&event).unwrap(), s);
+    }
+
+    #[test]
+    fn serde_storage_changes_event() {
+        let s = r#"{"blockNumber":"0x1","blockHash":"0x0000000000000000000000000000000000000000000000000000000000000002","changes":[{"address":"0x0000000000000000000000000000000000000003","slot":"0x0000000000000000000000000000000000000000000000000000000000000004","previousValue":"0x0","value":"0x5"}]}"#;
+        let event: StorageChangesEvent = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            event,
+            StorageChangesEvent {
+                block_number: U64::from(1),
+                block_hash: B256::with_last_byte(2),
+                changes: vec![StorageChange {
+                    address: Address::with_last_byte(3),
+                    slot: B256::with_last_byte(4),
+                    previous_value: U256::ZERO,
+                    value: U256::from(5),
+                }],
+            }
+        );
+        assert_eq!(serde_json::to_string(&event).unwrap(), s);
+    }
+
+    #[test]
+    fn serde_candidate_transaction() {
+        let s = r#"{"hash":"0x0000000000000000000000000000000000000000000000000000000000000001","status":"included","gasUsed":"0x5208"}"#;
+        let tx: CandidateTransaction = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            tx.outcome,
+            CandidateTransactionOutcome::Included { gas_used: U64::from(21_000) }
+        );
+        assert_eq!(serde_json::to_string(&tx).unwrap(), s);
+
+        let s = r#"{"hash":"0x0000000000000000000000000000000000000000000000000000000000000001","status":"excluded","reason":"exceedsBlockGasLimit"}"#;
+        let tx: CandidateTransaction = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            tx.outcome,
+            CandidateTransactionOutcome::Excluded {
+                reason: TransactionExclusionReason::ExceedsBlockGasLimit,
+                error: None
+            }
+        );
+        assert_eq!(serde_json::to_string(&tx).unwrap(), s);
+    }
+}
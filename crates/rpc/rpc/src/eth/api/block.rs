@@ -76,9 +76,11 @@ where
 
         if let Some((block, receipts)) = block_and_receipts {
             let block_number = block.number;
+            let timestamp = block.timestamp;
             let base_fee = block.base_fee_per_gas;
             let block_hash = block.hash();
             let excess_blob_gas = block.excess_blob_gas;
+            let blob_params = self.provider().chain_spec().blob_params_at_timestamp(timestamp);
             let block = block.unseal();
 
             #[cfg(feature = "optimism")]
@@ -98,6 +100,7 @@ where
                         index: idx as u64,
                         block_hash,
                         block_number,
+                        timestamp,
                         base_fee,
                         excess_blob_gas,
                     };
@@ -111,6 +114,7 @@ where
                         meta,
                         receipt.clone(),
                         &receipts,
+                        blob_params,
                         #[cfg(feature = "optimism")]
                         op_tx_meta,
                     )
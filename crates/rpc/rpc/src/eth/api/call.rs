@@ -5,8 +5,8 @@ use crate::{
         error::{ensure_success, EthApiError, EthResult, RevertError, RpcInvalidTransactionError},
         revm_utils::{
             apply_state_overrides, build_call_evm_env, caller_gas_allowance,
-            cap_tx_gas_limit_with_caller_allowance, get_precompiles, inspect, prepare_call_env,
-            transact, EvmOverrides,
+            cap_tx_gas_limit_with_caller_allowance, inspect, prepare_call_env, transact,
+            EvmOverrides,
         },
         EthTransactions,
     },
@@ -405,7 +405,10 @@ where
         // can consume the list since we're not using the request anymore
         let initial = request.access_list.take().unwrap_or_default();
 
-        let precompiles = get_precompiles(env.handler_cfg.spec_id);
+        let precompiles = self
+            .provider()
+            .chain_spec()
+            .active_precompiles(env.block.timestamp.to(), env.block.number.to());
         let mut inspector = AccessListInspector::new(initial, from, to, precompiles);
         let (result, env) = inspect(&mut db, env, &mut inspector)?;
 
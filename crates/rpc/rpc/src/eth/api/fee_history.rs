@@ -6,10 +6,8 @@ use futures::{
     FutureExt, Stream, StreamExt,
 };
 use metrics::atomics::AtomicU64;
-use reth_primitives::{
-    eip4844::{calc_blob_gasprice, calculate_excess_blob_gas},
-    Receipt, SealedBlock, TransactionSigned, B256, U256,
-};
+use parking_lot::RwLock;
+use reth_primitives::{BlobParams, ChainSpec, Receipt, SealedBlock, TransactionSigned, B256, U256};
 use reth_provider::{BlockReaderIdExt, CanonStateNotification, ChainSpecProvider};
 use reth_rpc_types::TxGasAndReward;
 use serde::{Deserialize, Serialize};
@@ -34,6 +32,7 @@ impl FeeHistoryCache {
         let inner = FeeHistoryCacheInner {
             lower_bound: Default::default(),
             upper_bound: Default::default(),
+            blob_fees: RwLock::new(BlobFeeRing::new(config.max_blocks as usize)),
             config,
             entries: Default::default(),
             eth_cache,
@@ -66,7 +65,7 @@ impl FeeHistoryCache {
     }
 
     /// Insert block data into the cache.
-    async fn insert_blocks<I>(&self, blocks: I)
+    async fn insert_blocks<I>(&self, blocks: I, chain_spec: &ChainSpec)
     where
         I: Iterator<Item = (SealedBlock, Arc<Vec<Receipt>>)>,
     {
@@ -75,7 +74,8 @@ impl FeeHistoryCache {
         let percentiles = self.predefined_percentiles();
         // Insert all new blocks and calculate approximated rewards
         for (block, receipts) in blocks {
-            let mut fee_history_entry = FeeHistoryEntry::new(&block);
+            let mut fee_history_entry =
+                FeeHistoryEntry::new(&block, chain_spec.blob_params_at_timestamp(block.timestamp));
             fee_history_entry.rewards = calculate_reward_percentiles_for_block(
                 &percentiles,
                 fee_history_entry.gas_used,
@@ -111,6 +111,31 @@ impl FeeHistoryCache {
         self.inner.lower_bound.store(lower_bound, SeqCst);
     }
 
+    /// Records the blob fees of new canonical blocks, see [Self::blob_fee_history].
+    fn insert_blob_fees<'a>(
+        &self,
+        blocks: impl IntoIterator<Item = &'a SealedBlock>,
+        chain_spec: &ChainSpec,
+    ) {
+        let mut blob_fees = self.inner.blob_fees.write();
+        for block in blocks {
+            blob_fees.push(BlobFeeEntry::new(block, chain_spec));
+        }
+    }
+
+    /// Returns the blob fees of the given canonical block, if it is one of the most recent blocks.
+    pub fn blob_fee_entry(&self, block_number: u64) -> Option<BlobFeeEntry> {
+        self.inner.blob_fees.read().get(block_number).copied()
+    }
+
+    /// Returns the blob fees of the canonical blocks in the `start_block..=end_block` range.
+    ///
+    /// Unlike [Self::get_history], this is served from a ring buffer of the most recent canonical
+    /// blocks without any async locking. Returns `None` if any block of the range is not cached.
+    pub fn blob_fee_history(&self, start_block: u64, end_block: u64) -> Option<Vec<BlobFeeEntry>> {
+        self.inner.blob_fees.read().range(start_block, end_block)
+    }
+
     /// Get UpperBound value for FeeHistoryCache
     pub fn upper_bound(&self) -> u64 {
         self.inner.upper_bound.load(SeqCst)
@@ -193,9 +218,97 @@ struct FeeHistoryCacheInner {
     config: FeeHistoryCacheConfig,
     /// Stores the entries of the cache
     entries: tokio::sync::RwLock<BTreeMap<u64, FeeHistoryEntry>>,
+    /// Stores the blob fees of the most recent canonical blocks
+    blob_fees: RwLock<BlobFeeRing>,
     eth_cache: EthStateCache,
 }
 
+/// A ring buffer of the blob fees of the most recent canonical blocks.
+///
+/// The entries are consecutive, so a block is looked up by its distance to the oldest entry.
+#[derive(Debug)]
+struct BlobFeeRing {
+    /// The entries, ordered by block number
+    entries: VecDeque<BlobFeeEntry>,
+    /// Max number of entries
+    capacity: usize,
+}
+
+impl BlobFeeRing {
+    fn new(capacity: usize) -> Self {
+        Self { entries: VecDeque::with_capacity(capacity), capacity }
+    }
+
+    /// Appends the entry of a new canonical block.
+    ///
+    /// Entries of the same or higher block numbers were reorged out and are removed. If the block
+    /// doesn't connect to the newest entry, the buffer starts over.
+    fn push(&mut self, entry: BlobFeeEntry) {
+        while self.entries.back().map_or(false, |last| last.number >= entry.number) {
+            self.entries.pop_back();
+        }
+        if self.entries.back().map_or(false, |last| last.number + 1 != entry.number) {
+            self.entries.clear();
+        }
+
+        self.entries.push_back(entry);
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+    }
+
+    fn get(&self, block_number: u64) -> Option<&BlobFeeEntry> {
+        let first = self.entries.front()?.number;
+        self.entries.get(block_number.checked_sub(first)? as usize)
+    }
+
+    fn range(&self, start_block: u64, end_block: u64) -> Option<Vec<BlobFeeEntry>> {
+        let first = self.entries.front()?.number;
+        let last = self.entries.back()?.number;
+        if start_block < first || end_block > last || start_block > end_block {
+            return None
+        }
+        Some(
+            self.entries
+                .range((start_block - first) as usize..=(end_block - first) as usize)
+                .copied()
+                .collect(),
+        )
+    }
+}
+
+/// The blob fees of a canonical block, see [FeeHistoryCache::blob_fee_history].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlobFeeEntry {
+    /// Number of the block.
+    pub number: u64,
+    /// Hash of the block.
+    pub hash: B256,
+    /// The base fee per blob gas of the block, `None` before EIP-4844.
+    pub base_fee_per_blob_gas: Option<u128>,
+    /// Blob gas used ratio of the block.
+    pub blob_gas_used_ratio: f64,
+    /// The base fee per blob gas of the next block, `None` before EIP-4844.
+    pub next_block_blob_fee: Option<u128>,
+}
+
+impl BlobFeeEntry {
+    /// Creates a new entry from a sealed block, with the [BlobParams] of the chain at the block.
+    ///
+    /// The blob fee of the next block is calculated for a child with the timestamp of the block.
+    pub fn new(block: &SealedBlock, chain_spec: &ChainSpec) -> Self {
+        let blob_params = chain_spec.blob_params_at_timestamp(block.timestamp);
+        BlobFeeEntry {
+            number: block.number,
+            hash: block.hash(),
+            base_fee_per_blob_gas: block.blob_fee(blob_params),
+            blob_gas_used_ratio: block.blob_gas_used() as f64 /
+                blob_params.max_blob_gas_per_block() as f64,
+            next_block_blob_fee: block.next_block_blob_fee(blob_params),
+        }
+    }
+}
+
 /// Awaits for new chain events and directly inserts them into the cache so they're available
 /// immediately before they need to be fetched from disk.
 pub async fn fee_history_cache_new_blocks_task<St, Provider>(
@@ -230,7 +343,7 @@ pub async fn fee_history_cache_new_blocks_task<St, Provider>(
         tokio::select! {
             res = &mut fetch_missing_block =>  {
                 if let Ok(res) = res {
-                    fee_history_cache.insert_blocks(res.into_iter()).await;
+                    fee_history_cache.insert_blocks(res.into_iter(), &provider.chain_spec()).await;
                 }
             }
             event = events.next() =>  {
@@ -245,7 +358,9 @@ pub async fn fee_history_cache_new_blocks_task<St, Provider>(
                             (block.block.clone(), Arc::new(receipts.iter().flatten().cloned().collect::<Vec<_>>()))
                         })
                         .unzip();
-                    fee_history_cache.insert_blocks(blocks.into_iter().zip(receipts)).await;
+                    let chain_spec = provider.chain_spec();
+                    fee_history_cache.insert_blob_fees(&blocks, &chain_spec);
+                    fee_history_cache.insert_blocks(blocks.into_iter().zip(receipts), &chain_spec).await;
 
                     // keep track of missing blocks
                     missing_blocks = fee_history_cache.missing_consecutive_blocks().await;
@@ -333,6 +448,8 @@ pub struct FeeHistoryEntry {
     /// The total amount of blob gas consumed by the transactions within the block,
     /// added in EIP-4844
     pub blob_gas_used: Option<u64>,
+    /// The blob params of the chain at this block.
+    pub blob_params: BlobParams,
     /// Gas used by this block.
     pub gas_used: u64,
     /// Gas limit by this block.
@@ -347,15 +464,16 @@ impl FeeHistoryEntry {
     /// Creates a new entry from a sealed block.
     ///
     /// Note: This does not calculate the rewards for the block.
-    pub fn new(block: &SealedBlock) -> Self {
+    pub fn new(block: &SealedBlock, blob_params: BlobParams) -> Self {
         FeeHistoryEntry {
             base_fee_per_gas: block.base_fee_per_gas.unwrap_or_default(),
             gas_used_ratio: block.gas_used as f64 / block.gas_limit as f64,
-            base_fee_per_blob_gas: block.blob_fee(),
+            base_fee_per_blob_gas: block.blob_fee(blob_params),
             blob_gas_used_ratio: block.blob_gas_used() as f64 /
-                reth_primitives::constants::eip4844::MAX_DATA_GAS_PER_BLOCK as f64,
+                blob_params.max_blob_gas_per_block() as f64,
             excess_blob_gas: block.excess_blob_gas,
             blob_gas_used: block.blob_gas_used,
+            blob_params,
             gas_used: block.gas_used,
             header_hash: block.hash(),
             gas_limit: block.gas_limit,
@@ -363,19 +481,68 @@ impl FeeHistoryEntry {
         }
     }
 
-    /// Returns the blob fee for the next block according to the EIP-4844 spec.
+    /// Returns the blob fee for the next block according to the EIP-4844 spec, with the blob
+    /// params of this block.
     ///
     /// Returns `None` if `excess_blob_gas` is None.
     ///
     /// See also [Self::next_block_excess_blob_gas]
     pub fn next_block_blob_fee(&self) -> Option<u128> {
-        self.next_block_excess_blob_gas().map(calc_blob_gasprice)
+        self.next_block_excess_blob_gas()
+            .map(|excess_blob_gas| self.blob_params.calc_blob_fee(excess_blob_gas))
     }
 
-    /// Calculate excess blob gas for the next block according to the EIP-4844 spec.
+    /// Calculate excess blob gas for the next block according to the EIP-4844 spec, with the blob
+    /// params of this block.
     ///
     /// Returns a `None` if no excess blob gas is set, no EIP-4844 support
     pub fn next_block_excess_blob_gas(&self) -> Option<u64> {
-        Some(calculate_excess_blob_gas(self.excess_blob_gas?, self.blob_gas_used?))
+        Some(
+            self.blob_params.next_block_excess_blob_gas(self.excess_blob_gas?, self.blob_gas_used?),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(number: u64) -> BlobFeeEntry {
+        BlobFeeEntry {
+            number,
+            hash: B256::with_last_byte(number as u8),
+            base_fee_per_blob_gas: Some(number as u128),
+            blob_gas_used_ratio: 0.,
+            next_block_blob_fee: Some(number as u128 + 1),
+        }
+    }
+
+    #[test]
+    fn blob_fee_ring() {
+        let mut ring = BlobFeeRing::new(3);
+        assert_eq!(ring.get(0), None);
+        assert_eq!(ring.range(0, 0), None);
+
+        for number in 1..=4 {
+            ring.push(entry(number));
+        }
+        // the oldest entry was evicted
+        assert_eq!(ring.get(1), None);
+        assert_eq!(ring.get(4), Some(&entry(4)));
+        assert_eq!(ring.range(2, 4), Some(vec![entry(2), entry(3), entry(4)]));
+        assert_eq!(ring.range(1, 4), None);
+        assert_eq!(ring.range(3, 5), None);
+
+        // a reorg replaces the entries of the reorged blocks
+        let mut reorged = entry(3);
+        reorged.hash = B256::repeat_byte(0xff);
+        ring.push(reorged);
+        assert_eq!(ring.range(2, 3), Some(vec![entry(2), reorged]));
+        assert_eq!(ring.get(4), None);
+
+        // a gap starts over
+        ring.push(entry(10));
+        assert_eq!(ring.get(2), None);
+        assert_eq!(ring.range(10, 10), Some(vec![entry(10)]));
     }
 }
@@ -10,7 +10,9 @@ use crate::{
 use reth_network_api::NetworkInfo;
 use reth_node_api::ConfigureEvmEnv;
 use reth_primitives::{basefee::calculate_next_block_base_fee, BlockNumberOrTag, U256};
-use reth_provider::{BlockReaderIdExt, ChainSpecProvider, EvmEnvProvider, StateProviderFactory};
+use reth_provider::{
+    BlockNumReader, BlockReaderIdExt, ChainSpecProvider, EvmEnvProvider, StateProviderFactory,
+};
 use reth_rpc_types::FeeHistory;
 use reth_transaction_pool::TransactionPool;
 use tracing::debug;
@@ -35,10 +37,25 @@ where
     }
 
     /// Returns a suggestion for a base fee for blob transactions.
+    ///
+    /// This is served from the blob fees of the [FeeHistoryCache](crate::eth::FeeHistoryCache) if
+    /// it is up to date with the latest block.
     pub(crate) async fn blob_base_fee(&self) -> EthResult<U256> {
+        let chain_info = self.provider().chain_info()?;
+        if let Some(entry) = self
+            .fee_history_cache()
+            .blob_fee_entry(chain_info.best_number)
+            .filter(|entry| entry.hash == chain_info.best_hash)
+        {
+            return entry.next_block_blob_fee.ok_or(EthApiError::ExcessBlobGasNotSet).map(U256::from)
+        }
+
+        let chain_spec = self.provider().chain_spec();
         self.block(BlockNumberOrTag::Latest)
             .await?
-            .and_then(|h: reth_primitives::SealedBlock| h.next_block_blob_fee())
+            .and_then(|h: reth_primitives::SealedBlock| {
+                h.next_block_blob_fee(chain_spec.blob_params_at_timestamp(h.timestamp))
+            })
             .ok_or(EthApiError::ExcessBlobGasNotSet)
             .map(U256::from)
     }
@@ -115,6 +132,9 @@ where
 
         let mut rewards: Vec<Vec<U256>> = Vec::new();
 
+        // The blob fees of recent blocks are served from their own ring buffer
+        let blob_fees = self.fee_history_cache().blob_fee_history(start_block, end_block);
+
         // Check if the requested range is within the cache bounds
         let fee_entries = self.fee_history_cache().get_history(start_block, end_block).await;
 
@@ -126,9 +146,11 @@ where
             for entry in &fee_entries {
                 base_fee_per_gas.push(U256::from(entry.base_fee_per_gas));
                 gas_used_ratio.push(entry.gas_used_ratio);
-                base_fee_per_blob_gas
-                    .push(U256::from(entry.base_fee_per_blob_gas.unwrap_or_default()));
-                blob_gas_used_ratio.push(entry.blob_gas_used_ratio);
+                if blob_fees.is_none() {
+                    base_fee_per_blob_gas
+                        .push(U256::from(entry.base_fee_per_blob_gas.unwrap_or_default()));
+                    blob_gas_used_ratio.push(entry.blob_gas_used_ratio);
+                }
 
                 if let Some(percentiles) = &reward_percentiles {
                     let mut block_rewards = Vec::with_capacity(percentiles.len());
@@ -155,8 +177,10 @@ where
                 self.provider().chain_spec().base_fee_params(last_entry_timestamp),
             )));
 
-            base_fee_per_blob_gas
-                .push(U256::from(last_entry.next_block_blob_fee().unwrap_or_default()));
+            if blob_fees.is_none() {
+                base_fee_per_blob_gas
+                    .push(U256::from(last_entry.next_block_blob_fee().unwrap_or_default()));
+            }
         } else {
             // read the requested header range
             let headers = self.provider().sealed_headers_range(start_block..=end_block)?;
@@ -164,14 +188,19 @@ where
                 return Err(EthApiError::InvalidBlockRange)
             }
 
+            let chain_spec = self.provider().chain_spec();
             for header in &headers {
                 base_fee_per_gas.push(U256::from(header.base_fee_per_gas.unwrap_or_default()));
                 gas_used_ratio.push(header.gas_used as f64 / header.gas_limit as f64);
-                base_fee_per_blob_gas.push(U256::from(header.blob_fee().unwrap_or_default()));
-                blob_gas_used_ratio.push(
-                    header.blob_gas_used.unwrap_or_default() as f64 /
-                        reth_primitives::constants::eip4844::MAX_DATA_GAS_PER_BLOCK as f64,
-                );
+                if blob_fees.is_none() {
+                    let blob_params = chain_spec.blob_params_at_timestamp(header.timestamp);
+                    base_fee_per_blob_gas
+                        .push(U256::from(header.blob_fee(blob_params).unwrap_or_default()));
+                    blob_gas_used_ratio.push(
+                        header.blob_gas_used.unwrap_or_default() as f64 /
+                            blob_params.max_blob_gas_per_block() as f64,
+                    );
+                }
 
                 // Percentiles were specified, so we need to collect reward percentile ino
                 if let Some(percentiles) = &reward_percentiles {
@@ -203,15 +232,30 @@ where
                 last_header.gas_used,
                 last_header.gas_limit,
                 last_header.base_fee_per_gas.unwrap_or_default(),
-                self.provider().chain_spec().base_fee_params(last_header.timestamp),
+                chain_spec.base_fee_params(last_header.timestamp),
             )));
 
             // Same goes for the `base_fee_per_blob_gas`:
             // > "[..] includes the next block after the newest of the returned range, because this value can be derived from the newest block.
-            base_fee_per_blob_gas
-                .push(U256::from(last_header.next_block_blob_fee().unwrap_or_default()));
+            if blob_fees.is_none() {
+                let blob_params = chain_spec.blob_params_at_timestamp(last_header.timestamp);
+                base_fee_per_blob_gas.push(U256::from(
+                    last_header.next_block_blob_fee(blob_params).unwrap_or_default(),
+                ));
+            }
         };
 
+        if let Some(blob_fees) = blob_fees {
+            for entry in &blob_fees {
+                base_fee_per_blob_gas
+                    .push(U256::from(entry.base_fee_per_blob_gas.unwrap_or_default()));
+                blob_gas_used_ratio.push(entry.blob_gas_used_ratio);
+            }
+            let last_entry = blob_fees.last().expect("is not empty");
+            base_fee_per_blob_gas
+                .push(U256::from(last_entry.next_block_blob_fee.unwrap_or_default()));
+        }
+
         Ok(FeeHistory {
             base_fee_per_gas,
             gas_used_ratio,
@@ -18,14 +18,20 @@ use reth_network_api::NetworkInfo;
 use reth_node_api::ConfigureEvmEnv;
 use reth_primitives::{
     revm_primitives::{BlockEnv, CfgEnvWithHandlerCfg},
-    Address, BlockId, BlockNumberOrTag, ChainInfo, SealedBlockWithSenders, SealedHeader, B256,
-    U256, U64,
+    Address, BlockId, BlockNumberOrTag, ChainInfo, SealedBlockWithSenders, SealedHeader,
+    Withdrawals, B256, U256, U64,
 };
 
 use reth_provider::{
-    BlockReaderIdExt, ChainSpecProvider, EvmEnvProvider, StateProviderBox, StateProviderFactory,
+    BlockReaderIdExt, ChainSpecProvider, EvmEnvProvider, HeaderProvider, StateProviderBox,
+    StateProviderFactory,
+};
+use reth_rpc_types::{
+    engine::PayloadAttributes, BlockTransactionsKind, PayloadBuildReport, SyncInfo, SyncStatus,
+};
+use reth_rpc_types_compat::{
+    block::from_block, engine::payload::convert_standalone_withdraw_to_withdrawal,
 };
-use reth_rpc_types::{SyncInfo, SyncStatus};
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::TransactionPool;
 use revm_primitives::{CfgEnv, SpecId};
@@ -282,18 +288,26 @@ where
                 self.provider().latest_header()?.ok_or_else(|| EthApiError::UnknownBlockNumber)?;
 
             let (mut latest_header, block_hash) = latest.split();
-            // child block
-            latest_header.number += 1;
             // assumed child block is in the next slot: 12s
-            latest_header.timestamp += 12;
+            let timestamp = latest_header.timestamp + 12;
             // base fee of the child block
             let chain_spec = self.provider().chain_spec();
 
-            latest_header.base_fee_per_gas = latest_header
-                .next_block_base_fee(chain_spec.base_fee_params(latest_header.timestamp));
+            latest_header.base_fee_per_gas =
+                chain_spec.next_block_base_fee(&latest_header, timestamp);
+            // child block
+            latest_header.number += 1;
+            latest_header.timestamp = timestamp;
 
             // update excess blob gas consumed above target
-            latest_header.excess_blob_gas = latest_header.next_block_excess_blob_gas();
+            latest_header.excess_blob_gas =
+                chain_spec.next_block_excess_blob_gas(&latest_header, timestamp);
+
+            // the priority fees of OP-stack blocks are paid to the sequencer fee vault
+            #[cfg(feature = "optimism")]
+            if chain_spec.is_optimism() {
+                latest_header.beneficiary = chain_spec.sequencer_fee_vault();
+            }
 
             // we're reusing the same block hash because we need this to lookup the block's state
             let latest = SealedHeader::new(latest_header, block_hash);
@@ -363,6 +377,77 @@ where
         })
         .await
     }
+
+    /// Builds a block with the given payload attributes and the transactions of the pool on top
+    /// of the given parent, or the latest block if no parent is given.
+    ///
+    /// This is a dry run: the block is neither stored nor submitted anywhere. Returns the block
+    /// together with the outcome of every transaction that was considered for inclusion.
+    pub async fn build_payload_dry_run(
+        &self,
+        attributes: PayloadAttributes,
+        parent_block_hash: Option<B256>,
+    ) -> EthResult<PayloadBuildReport> {
+        let parent_id = parent_block_hash.map_or(BlockNumberOrTag::Latest.into(), BlockId::from);
+        let parent = self
+            .provider()
+            .sealed_header_by_id(parent_id)?
+            .ok_or_else(|| EthApiError::UnknownBlockNumber)?;
+        let chain_spec = self.provider().chain_spec();
+
+        let (mut header, parent_hash) = parent.split();
+        header.base_fee_per_gas = chain_spec.next_block_base_fee(&header, attributes.timestamp);
+        header.excess_blob_gas =
+            chain_spec.next_block_excess_blob_gas(&header, attributes.timestamp);
+        header.number += 1;
+        header.timestamp = attributes.timestamp;
+        header.beneficiary = attributes.suggested_fee_recipient;
+        header.mix_hash = attributes.prev_randao;
+        header.parent_beacon_block_root = attributes.parent_beacon_block_root;
+
+        let withdrawals =
+            chain_spec.is_shanghai_active_at_timestamp(attributes.timestamp).then(|| {
+                Withdrawals::new(
+                    attributes
+                        .withdrawals
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(convert_standalone_withdraw_to_withdrawal)
+                        .collect(),
+                )
+            });
+
+        // we're reusing the parent's hash because we need it to lookup the parent's state
+        let origin = PendingBlockEnvOrigin::Payload {
+            header: SealedHeader::new(header, parent_hash),
+            withdrawals,
+        };
+
+        let mut cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LATEST);
+        let mut block_env = BlockEnv::default();
+        self.provider().fill_env_with_header(
+            &mut cfg,
+            &mut block_env,
+            origin.header(),
+            self.inner.evm_config.clone(),
+        )?;
+        let env = PendingBlockEnv { cfg, block_env, origin };
+
+        self.on_blocking_task(|this| async move {
+            let (block, transactions) =
+                env.build_block_with_report(this.provider(), this.pool())?;
+            let total_difficulty = this.provider().header_td(&parent_hash)?.unwrap_or_default();
+            let block_hash = block.hash();
+            let block = from_block(
+                block.unseal(),
+                total_difficulty,
+                BlockTransactionsKind::Full,
+                Some(block_hash),
+            )?;
+            Ok(PayloadBuildReport { block, transactions })
+        })
+        .await
+    }
 }
 
 impl<Provider, Pool, Events, EvmConfig> std::fmt::Debug
@@ -2,20 +2,24 @@
 
 use crate::eth::error::{EthApiError, EthResult};
 use reth_primitives::{
-    constants::{eip4844::MAX_DATA_GAS_PER_BLOCK, BEACON_NONCE},
+    constants::BEACON_NONCE,
     proofs,
     revm::env::tx_env_with_recovered,
     revm_primitives::{
         BlockEnv, CfgEnvWithHandlerCfg, EVMError, Env, InvalidTransaction, ResultAndState, SpecId,
     },
     Block, BlockId, BlockNumberOrTag, ChainSpec, Header, IntoRecoveredTransaction, Receipt,
-    Receipts, SealedBlockWithSenders, SealedHeader, B256, EMPTY_OMMER_ROOT_HASH, U256,
+    Receipts, SealedBlockWithSenders, SealedHeader, Withdrawals, B256, EMPTY_OMMER_ROOT_HASH, U256,
+    U64,
 };
 use reth_provider::{BundleStateWithReceipts, ChainSpecProvider, StateProviderFactory};
 use reth_revm::{
     database::StateProviderDatabase,
     state_change::{apply_beacon_root_contract_call, post_block_withdrawals_balance_increments},
 };
+use reth_rpc_types::{
+    CandidateTransaction, CandidateTransactionOutcome, TransactionExclusionReason,
+};
 use reth_transaction_pool::{BestTransactionsAttributes, TransactionPool};
 use revm::{db::states::bundle_state::BundleRetention, Database, DatabaseCommit, State};
 use revm_primitives::EnvWithHandlerCfg;
@@ -44,6 +48,20 @@ impl PendingBlockEnv {
         client: &Client,
         pool: &Pool,
     ) -> EthResult<SealedBlockWithSenders>
+    where
+        Client: StateProviderFactory + ChainSpecProvider,
+        Pool: TransactionPool,
+    {
+        self.build_block_with_report(client, pool).map(|(block, _)| block)
+    }
+
+    /// Builds a pending block like [Self::build_block], and also returns the outcome of every
+    /// transaction of the pool that was considered for inclusion.
+    pub(crate) fn build_block_with_report<Client, Pool>(
+        self,
+        client: &Client,
+        pool: &Pool,
+    ) -> EthResult<(SealedBlockWithSenders, Vec<CandidateTransaction>)>
     where
         Client: StateProviderFactory + ChainSpecProvider,
         Pool: TransactionPool,
@@ -73,13 +91,22 @@ impl PendingBlockEnv {
                 (block.withdrawals.clone(), block.withdrawals_root)
             }
             PendingBlockEnvOrigin::DerivedFromLatest(_) => (None, None),
+            PendingBlockEnvOrigin::Payload { ref withdrawals, .. } => {
+                let withdrawals_root = withdrawals
+                    .as_ref()
+                    .map(|withdrawals| proofs::calculate_withdrawals_root(withdrawals));
+                (withdrawals.clone(), withdrawals_root)
+            }
         };
 
         let chain_spec = client.chain_spec();
+        let max_blob_gas_per_block = chain_spec
+            .blob_params_at_timestamp(block_env.timestamp.to::<u64>())
+            .max_blob_gas_per_block();
 
-        let parent_beacon_block_root = if origin.is_actual_pending() {
-            // apply eip-4788 pre block contract call if we got the block from the CL with the real
-            // parent beacon block root
+        let parent_beacon_block_root = if origin.has_parent_beacon_block_root() {
+            // apply eip-4788 pre block contract call if we got the real parent beacon block root
+            // from the CL
             pre_block_beacon_root_contract_call(
                 &mut db,
                 chain_spec.as_ref(),
@@ -94,6 +121,11 @@ impl PendingBlockEnv {
         };
 
         let mut receipts = Vec::new();
+        let mut candidates = Vec::new();
+        let excluded = |hash, reason, error| CandidateTransaction {
+            hash,
+            outcome: CandidateTransactionOutcome::Excluded { reason, error },
+        };
 
         while let Some(pool_tx) = best_txs.next() {
             // ensure we still have capacity for this transaction
@@ -102,6 +134,11 @@ impl PendingBlockEnv {
                 // which also removes all dependent transaction from the iterator before we can
                 // continue
                 best_txs.mark_invalid(&pool_tx);
+                candidates.push(excluded(
+                    *pool_tx.hash(),
+                    TransactionExclusionReason::ExceedsBlockGasLimit,
+                    None,
+                ));
                 continue
             }
 
@@ -110,6 +147,11 @@ impl PendingBlockEnv {
                 // them as invalid here which removes all dependent transactions from the iterator
                 // before we can continue
                 best_txs.mark_invalid(&pool_tx);
+                candidates.push(excluded(
+                    *pool_tx.hash(),
+                    TransactionExclusionReason::Private,
+                    None,
+                ));
                 continue
             }
 
@@ -120,12 +162,17 @@ impl PendingBlockEnv {
             // the EIP-4844 can still fit in the block
             if let Some(blob_tx) = tx.transaction.as_eip4844() {
                 let tx_blob_gas = blob_tx.blob_gas();
-                if sum_blob_gas_used + tx_blob_gas > MAX_DATA_GAS_PER_BLOCK {
+                if sum_blob_gas_used + tx_blob_gas > max_blob_gas_per_block {
                     // we can't fit this _blob_ transaction into the block, so we mark it as
                     // invalid, which removes its dependent transactions from
                     // the iterator. This is similar to the gas limit condition
                     // for regular transactions above.
                     best_txs.mark_invalid(&pool_tx);
+                    candidates.push(excluded(
+                        *pool_tx.hash(),
+                        TransactionExclusionReason::ExceedsBlobGasLimit,
+                        None,
+                    ));
                     continue
                 }
             }
@@ -141,13 +188,20 @@ impl PendingBlockEnv {
                 Err(err) => {
                     match err {
                         EVMError::Transaction(err) => {
-                            if matches!(err, InvalidTransaction::NonceTooLow { .. }) {
+                            let reason = if matches!(err, InvalidTransaction::NonceTooLow { .. }) {
                                 // if the nonce is too low, we can skip this transaction
+                                TransactionExclusionReason::NonceTooLow
                             } else {
                                 // if the transaction is invalid, we can skip it and all of its
                                 // descendants
                                 best_txs.mark_invalid(&pool_tx);
-                            }
+                                TransactionExclusionReason::Invalid
+                            };
+                            candidates.push(excluded(
+                                *pool_tx.hash(),
+                                reason,
+                                Some(err.to_string()),
+                            ));
                             continue
                         }
                         err => {
@@ -168,7 +222,7 @@ impl PendingBlockEnv {
                 sum_blob_gas_used += tx_blob_gas;
 
                 // if we've reached the max data gas per block, we can skip blob txs entirely
-                if sum_blob_gas_used == MAX_DATA_GAS_PER_BLOCK {
+                if sum_blob_gas_used == max_blob_gas_per_block {
                     best_txs.skip_blobs();
                 }
             }
@@ -178,6 +232,11 @@ impl PendingBlockEnv {
             // add gas used by the transaction to cumulative gas used, before creating the receipt
             cumulative_gas_used += gas_used;
 
+            candidates.push(CandidateTransaction {
+                hash: *pool_tx.hash(),
+                outcome: CandidateTransactionOutcome::Included { gas_used: U64::from(gas_used) },
+            });
+
             // Push transaction changeset and calculate header bloom filter for receipt.
             receipts.push(Some(Receipt {
                 tx_type: tx.tx_type(),
@@ -261,7 +320,7 @@ impl PendingBlockEnv {
 
         // seal the block
         let block = Block { header, body: executed_txs, ommers: vec![], withdrawals };
-        Ok(SealedBlockWithSenders { block: block.seal_slow(), senders })
+        Ok((SealedBlockWithSenders { block: block.seal_slow(), senders }, candidates))
     }
 }
 
@@ -316,6 +375,17 @@ pub(crate) enum PendingBlockEnvOrigin {
     ///  - the block number
     ///  - fees
     DerivedFromLatest(SealedHeader),
+    /// The _modified_ header of a parent block, configured with the attributes of a payload that
+    /// should be built on top of it.
+    ///
+    /// Unlike [PendingBlockEnvOrigin::DerivedFromLatest], the header also carries the fee
+    /// recipient, prevrandao and parent beacon block root of the payload.
+    Payload {
+        /// The modified parent header.
+        header: SealedHeader,
+        /// The withdrawals of the payload, `None` pre shanghai.
+        withdrawals: Option<Withdrawals>,
+    },
 }
 
 impl PendingBlockEnvOrigin {
@@ -324,6 +394,11 @@ impl PendingBlockEnvOrigin {
         matches!(self, PendingBlockEnvOrigin::ActualPending(_))
     }
 
+    /// Returns true if the header carries the real parent beacon block root of the block to build.
+    fn has_parent_beacon_block_root(&self) -> bool {
+        !matches!(self, PendingBlockEnvOrigin::DerivedFromLatest(_))
+    }
+
     /// Consumes the type and returns the actual pending block.
     pub(crate) fn into_actual_pending(self) -> Option<SealedBlockWithSenders> {
         match self {
@@ -339,7 +414,8 @@ impl PendingBlockEnvOrigin {
     pub(crate) fn state_block_id(&self) -> BlockId {
         match self {
             PendingBlockEnvOrigin::ActualPending(_) => BlockNumberOrTag::Pending.into(),
-            PendingBlockEnvOrigin::DerivedFromLatest(header) => BlockId::Hash(header.hash().into()),
+            PendingBlockEnvOrigin::DerivedFromLatest(header) |
+            PendingBlockEnvOrigin::Payload { header, .. } => BlockId::Hash(header.hash().into()),
         }
     }
 
@@ -347,10 +423,12 @@ impl PendingBlockEnvOrigin {
     ///
     /// For the [PendingBlockEnvOrigin::ActualPending] this is the parent hash of the block.
     /// For the [PendingBlockEnvOrigin::DerivedFromLatest] this is the hash of the _latest_ header.
+    /// For the [PendingBlockEnvOrigin::Payload] this is the hash of the parent header.
     fn build_target_hash(&self) -> B256 {
         match self {
             PendingBlockEnvOrigin::ActualPending(block) => block.parent_hash,
-            PendingBlockEnvOrigin::DerivedFromLatest(header) => header.hash(),
+            PendingBlockEnvOrigin::DerivedFromLatest(header) |
+            PendingBlockEnvOrigin::Payload { header, .. } => header.hash(),
         }
     }
 
@@ -358,7 +436,8 @@ impl PendingBlockEnvOrigin {
     pub(crate) fn header(&self) -> &SealedHeader {
         match self {
             PendingBlockEnvOrigin::ActualPending(block) => &block.header,
-            PendingBlockEnvOrigin::DerivedFromLatest(header) => header,
+            PendingBlockEnvOrigin::DerivedFromLatest(header) |
+            PendingBlockEnvOrigin::Payload { header, .. } => header,
         }
     }
 }
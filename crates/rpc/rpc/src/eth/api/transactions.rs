@@ -15,9 +15,8 @@ use async_trait::async_trait;
 use reth_network_api::NetworkInfo;
 use reth_node_api::ConfigureEvmEnv;
 use reth_primitives::{
-    eip4844::calc_blob_gasprice,
     revm::env::{fill_block_env_with_coinbase, tx_env_with_recovered},
-    Address, BlockId, BlockNumberOrTag, Bytes, FromRecoveredPooledTransaction, Header,
+    Address, BlobParams, BlockId, BlockNumberOrTag, Bytes, FromRecoveredPooledTransaction, Header,
     IntoRecoveredTransaction, Receipt, SealedBlock, SealedBlockWithSenders,
     TransactionKind::{Call, Create},
     TransactionMeta, TransactionSigned, TransactionSignedEcRecovered, B256, U128, U256, U64,
@@ -1114,7 +1113,8 @@ where
             Some(recpts) => recpts,
             None => return Err(EthApiError::UnknownBlockNumber),
         };
-        build_transaction_receipt_with_block_receipts(tx, meta, receipt, &all_receipts)
+        let blob_params = self.provider().chain_spec().blob_params_at_timestamp(meta.timestamp);
+        build_transaction_receipt_with_block_receipts(tx, meta, receipt, &all_receipts, blob_params)
     }
 
     /// Helper function for `eth_getTransactionReceipt` (optimism)
@@ -1142,6 +1142,7 @@ where
             meta,
             receipt,
             &receipts,
+            self.provider().chain_spec().blob_params_at_timestamp(meta.timestamp),
             optimism_tx_meta,
         )
     }
@@ -1364,6 +1365,7 @@ pub(crate) fn build_transaction_receipt_with_block_receipts(
     meta: TransactionMeta,
     receipt: Receipt,
     all_receipts: &[Receipt],
+    blob_params: BlobParams,
     #[cfg(feature = "optimism")] optimism_tx_meta: OptimismTxMeta,
 ) -> EthResult<TransactionReceipt> {
     // Note: we assume this transaction is valid, because it's mined (or part of pending block) and
@@ -1401,7 +1403,10 @@ pub(crate) fn build_transaction_receipt_with_block_receipts(
         logs_bloom: receipt.bloom_slow(),
         status_code: if receipt.success { Some(U64::from(1)) } else { Some(U64::from(0)) },
         // EIP-4844 fields
-        blob_gas_price: meta.excess_blob_gas.map(calc_blob_gasprice).map(U128::from),
+        blob_gas_price: meta
+            .excess_blob_gas
+            .map(|excess_blob_gas| blob_params.calc_blob_fee(excess_blob_gas))
+            .map(U128::from),
         blob_gas_used: transaction.transaction.blob_gas_used().map(U128::from),
         ..Default::default()
     };
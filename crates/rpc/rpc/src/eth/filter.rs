@@ -11,7 +11,7 @@ use core::fmt;
 
 use async_trait::async_trait;
 use jsonrpsee::{core::RpcResult, server::IdProvider};
-use reth_primitives::{ChainInfo, IntoRecoveredTransaction, TxHash};
+use reth_primitives::{ChainInfo, IntoRecoveredTransaction, Receipt, TxHash};
 use reth_provider::{BlockIdReader, BlockReader, EvmEnvProvider, ProviderError};
 use reth_rpc_api::EthFilterApiServer;
 use reth_rpc_types::{
@@ -447,18 +447,78 @@ where
         let address_filter = FilteredParams::address_filter(&filter.address);
         let topics_filter = FilteredParams::topics_filter(&filter.topics);
 
+        // skip whole buckets of indexed blocks whose combined bloom doesn't match the filter
+        let skipped = self
+            .provider
+            .logs_bloom_buckets(from_block..=to_block)?
+            .into_iter()
+            .filter(|(_, bloom)| {
+                !(FilteredParams::matches_address(*bloom, &address_filter) &&
+                    FilteredParams::matches_topics(*bloom, &topics_filter))
+            })
+            .map(|(bucket, _)| bucket);
+        let ranges = remove_skipped_ranges(from_block..=to_block, skipped);
+
+        let matches_bloom = |bloom| {
+            FilteredParams::matches_address(bloom, &address_filter) &&
+                FilteredParams::matches_topics(bloom, &topics_filter)
+        };
+        let append_logs = |all_logs: &mut Vec<Log>,
+                           block: BlockNumHash,
+                           receipts: &[Receipt]|
+         -> Result<(), FilterError> {
+            append_matching_block_logs(
+                all_logs,
+                &self.provider,
+                &filter_params,
+                block,
+                receipts,
+                false,
+            )?;
+
+            // size check but only if range is multiple blocks, so we always return all logs of a
+            // single block
+            let is_multi_block_range = from_block != to_block;
+            if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
+                return Err(FilterError::QueryExceedsMaxResults(self.max_logs_per_response))
+            }
+            Ok(())
+        };
+
+        // receipts of snapshotted blocks are scanned from the snapshots directly, the receipts of
+        // recent blocks are served from the cache
+        let highest_snapshot_block = self.provider.highest_snapshot_receipts_block();
+
         // loop over the range of new blocks and check logs if the filter matches the log's bloom
         // filter
-        for (from, to) in
-            BlockRangeInclusiveIter::new(from_block..=to_block, self.max_headers_range)
+        for (from, to) in ranges
+            .into_iter()
+            .flat_map(|range| BlockRangeInclusiveIter::new(range, self.max_headers_range))
         {
+            let from = match highest_snapshot_block {
+                Some(highest) if highest >= from => {
+                    let snapshot_to = highest.min(to);
+                    let blocks = self
+                        .provider
+                        .receipts_by_block_range_filtered(from..=snapshot_to, |header| {
+                            matches_bloom(header.logs_bloom)
+                        })?;
+                    for (block, receipts) in blocks {
+                        append_logs(&mut all_logs, block, &receipts)?;
+                    }
+                    snapshot_to + 1
+                }
+                _ => from,
+            };
+            if from > to {
+                continue
+            }
+
             let headers = self.provider.headers_range(from..=to)?;
 
             for (idx, header) in headers.iter().enumerate() {
                 // only if filter matches
-                if FilteredParams::matches_address(header.logs_bloom, &address_filter) &&
-                    FilteredParams::matches_topics(header.logs_bloom, &topics_filter)
-                {
+                if matches_bloom(header.logs_bloom) {
                     // these are consecutive headers, so we can use the parent hash of the next
                     // block to get the current header's hash
                     let block_hash = match headers.get(idx + 1) {
@@ -470,23 +530,11 @@ where
                     };
 
                     if let Some(receipts) = self.eth_cache.get_receipts(block_hash).await? {
-                        append_matching_block_logs(
+                        append_logs(
                             &mut all_logs,
-                            &self.provider,
-                            &filter_params,
                             BlockNumHash::new(header.number, block_hash),
                             &receipts,
-                            false,
                         )?;
-
-                        // size check but only if range is multiple blocks, so we always return all
-                        // logs of a single block
-                        let is_multi_block_range = from_block != to_block;
-                        if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
-                            return Err(FilterError::QueryExceedsMaxResults(
-                                self.max_logs_per_response,
-                            ));
-                        }
                     }
                 }
             }
@@ -731,11 +779,45 @@ impl Iterator for BlockRangeInclusiveIter {
     }
 }
 
+/// Returns the parts of `range` that are not covered by the ascending, non-overlapping `skipped`
+/// ranges.
+fn remove_skipped_ranges(
+    range: RangeInclusive<u64>,
+    skipped: impl IntoIterator<Item = RangeInclusive<u64>>,
+) -> Vec<RangeInclusive<u64>> {
+    let (mut next, end) = range.into_inner();
+    let mut ranges = Vec::new();
+    for skipped in skipped {
+        if next > end {
+            break
+        }
+        if *skipped.start() > next {
+            ranges.push(next..=(*skipped.start() - 1).min(end));
+        }
+        next = next.max(skipped.end().saturating_add(1));
+    }
+    if next <= end {
+        ranges.push(next..=end);
+    }
+    ranges
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand::{thread_rng, Rng};
 
+    #[test]
+    fn test_remove_skipped_ranges() {
+        assert_eq!(remove_skipped_ranges(10..=100, []), vec![10..=100]);
+        assert_eq!(
+            remove_skipped_ranges(10..=100, [0..=19, 40..=59, 80..=99]),
+            vec![20..=39, 60..=79, 100..=100]
+        );
+        assert_eq!(remove_skipped_ranges(10..=100, [0..=49, 50..=149]), vec![]);
+        assert_eq!(remove_skipped_ranges(10..=100, [120..=139]), vec![10..=100]);
+    }
+
     #[test]
     fn test_block_range_iter() {
         for _ in 0..100 {
@@ -14,11 +14,16 @@ mod signer;
 pub(crate) mod utils;
 
 pub use api::{
-    fee_history::{fee_history_cache_new_blocks_task, FeeHistoryCache, FeeHistoryCacheConfig},
+    fee_history::{
+        fee_history_cache_new_blocks_task, BlobFeeEntry, FeeHistoryCache, FeeHistoryCacheConfig,
+    },
     EthApi, EthApiSpec, EthTransactions, TransactionSource, RPC_DEFAULT_GAS_CAP,
 };
 
 pub use bundle::EthBundle;
 pub use filter::{EthFilter, EthFilterConfig};
 pub use id_provider::EthSubscriptionIdProvider;
-pub use pubsub::EthPubSub;
+pub(crate) use pubsub::pipe_from_stream;
+pub use pubsub::{
+    EthPubSub, LaggingSubscriberPolicy, SubscriptionBufferConfig, DEFAULT_SUBSCRIPTION_BUFFER_SIZE,
+};
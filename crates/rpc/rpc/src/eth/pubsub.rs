@@ -4,8 +4,8 @@ use crate::{eth::logs_utils, result::invalid_params_rpc_err};
 use futures::StreamExt;
 use jsonrpsee::{server::SubscriptionMessage, PendingSubscriptionSink, SubscriptionSink};
 use reth_network_api::NetworkInfo;
-use reth_primitives::{IntoRecoveredTransaction, TxHash};
-use reth_provider::{BlockReader, CanonStateSubscriptions, EvmEnvProvider};
+use reth_primitives::{BlockNumHash, BlockNumber, IntoRecoveredTransaction, TxHash};
+use reth_provider::{chain::BlockReceipts, BlockReader, CanonStateSubscriptions, EvmEnvProvider};
 use reth_rpc_api::EthPubSubApiServer;
 use reth_rpc_types::{
     pubsub::{
@@ -16,12 +16,22 @@ use reth_rpc_types::{
 };
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::{NewTransactionEvent, TransactionPool};
-use serde::Serialize;
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::Notify;
 use tokio_stream::{
     wrappers::{BroadcastStream, ReceiverStream},
     Stream,
 };
+use tracing::debug;
+
+/// The default number of messages that are buffered per subscription.
+pub const DEFAULT_SUBSCRIPTION_BUFFER_SIZE: usize = 1024;
 
 /// `Eth` pubsub RPC implementation.
 ///
@@ -32,6 +42,11 @@ pub struct EthPubSub<Provider, Pool, Events, Network> {
     inner: Arc<EthPubSubInner<Provider, Pool, Events, Network>>,
     /// The type that's used to spawn subscription tasks.
     subscription_task_spawner: Box<dyn TaskSpawner>,
+    /// How messages are buffered for subscribers that can't keep up.
+    buffer_config: SubscriptionBufferConfig,
+    /// The number of blocks on top of a block before its logs are emitted to `logs`
+    /// subscriptions.
+    log_confirmations: u64,
 }
 
 // === impl EthPubSub ===
@@ -59,7 +74,28 @@ impl<Provider, Pool, Events, Network> EthPubSub<Provider, Pool, Events, Network>
         subscription_task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
         let inner = EthPubSubInner { provider, pool, chain_events, network };
-        Self { inner: Arc::new(inner), subscription_task_spawner }
+        Self {
+            inner: Arc::new(inner),
+            subscription_task_spawner,
+            buffer_config: SubscriptionBufferConfig::default(),
+            log_confirmations: 0,
+        }
+    }
+
+    /// Configures how messages are buffered for subscribers that can't keep up.
+    pub fn with_buffer_config(mut self, buffer_config: SubscriptionBufferConfig) -> Self {
+        self.buffer_config = buffer_config;
+        self
+    }
+
+    /// Configures the number of blocks that have to be built on top of a block before its logs
+    /// are emitted to `logs` subscriptions.
+    ///
+    /// Logs that were already emitted are emitted again with `removed: true` if their block is
+    /// reorged out.
+    pub fn with_log_confirmations(mut self, log_confirmations: u64) -> Self {
+        self.log_confirmations = log_confirmations;
+        self
     }
 }
 
@@ -81,8 +117,11 @@ where
     ) -> jsonrpsee::core::SubscriptionResult {
         let sink = pending.accept().await?;
         let pubsub = self.inner.clone();
+        let buffer_config = self.buffer_config;
+        let log_confirmations = self.log_confirmations;
         self.subscription_task_spawner.spawn(Box::pin(async move {
-            let _ = handle_accepted(pubsub, sink, kind, params).await;
+            let _ =
+                handle_accepted(pubsub, sink, kind, params, buffer_config, log_confirmations).await;
         }));
 
         Ok(())
@@ -95,6 +134,8 @@ async fn handle_accepted<Provider, Pool, Events, Network>(
     accepted_sink: SubscriptionSink,
     kind: SubscriptionKind,
     params: Option<Params>,
+    buffer_config: SubscriptionBufferConfig,
+    log_confirmations: u64,
 ) -> Result<(), jsonrpsee::core::Error>
 where
     Provider: BlockReader + EvmEnvProvider + Clone + 'static,
@@ -107,7 +148,7 @@ where
             let stream = pubsub
                 .new_headers_stream()
                 .map(|block| EthSubscriptionResult::Header(Box::new(block.into())));
-            pipe_from_stream(accepted_sink, stream).await
+            pipe_from_stream(accepted_sink, stream, buffer_config).await
         }
         SubscriptionKind::Logs => {
             // if no params are provided, used default filter params
@@ -118,9 +159,10 @@ where
                 }
                 _ => FilteredParams::default(),
             };
-            let stream =
-                pubsub.log_stream(filter).map(|log| EthSubscriptionResult::Log(Box::new(log)));
-            pipe_from_stream(accepted_sink, stream).await
+            let stream = pubsub
+                .log_stream(filter, log_confirmations)
+                .map(|log| EthSubscriptionResult::Log(Box::new(log)));
+            pipe_from_stream(accepted_sink, stream, buffer_config).await
         }
         SubscriptionKind::NewPendingTransactions => {
             if let Some(params) = params {
@@ -134,7 +176,7 @@ where
                                 ),
                             ))
                         });
-                        return pipe_from_stream(accepted_sink, stream).await
+                        return pipe_from_stream(accepted_sink, stream, buffer_config).await
                     }
                     Params::Bool(false) | Params::None => {
                         // only hashes requested
@@ -151,7 +193,7 @@ where
             let stream = pubsub
                 .pending_transaction_hashes_stream()
                 .map(EthSubscriptionResult::TransactionHash);
-            pipe_from_stream(accepted_sink, stream).await
+            pipe_from_stream(accepted_sink, stream, buffer_config).await
         }
         SubscriptionKind::Syncing => {
             // get new block subscription
@@ -189,34 +231,150 @@ where
 }
 
 /// Pipes all stream items to the subscription sink.
-async fn pipe_from_stream<T, St>(
+///
+/// Items are buffered while the subscriber is busy, once the buffer is full the configured
+/// [LaggingSubscriberPolicy] is applied.
+pub(crate) async fn pipe_from_stream<T, St>(
     sink: SubscriptionSink,
     mut stream: St,
+    config: SubscriptionBufferConfig,
 ) -> Result<(), jsonrpsee::core::Error>
 where
     St: Stream<Item = T> + Unpin,
     T: Serialize,
 {
-    loop {
-        tokio::select! {
-            _ = sink.closed() => {
-                // connection dropped
-                break Ok(())
-            },
-            maybe_item = stream.next() => {
-                let item = match maybe_item {
-                    Some(item) => item,
-                    None => {
-                        // stream ended
-                        break  Ok(())
-                    },
-                };
-                let msg = SubscriptionMessage::from_json(&item)?;
-                if sink.send(msg).await.is_err() {
-                    break Ok(());
+    let buffer = Mutex::new(VecDeque::new());
+    let buffered = Notify::new();
+
+    // reads the stream into the buffer, returns `false` if the subscriber should be disconnected
+    let produce = async {
+        while let Some(item) = stream.next().await {
+            let msg = SubscriptionMessage::from_json(&item)?;
+            {
+                let mut buffer = buffer.lock().expect("not poisoned");
+                if buffer.len() >= config.buffer_size {
+                    match config.lagging_policy {
+                        LaggingSubscriberPolicy::DropOldest => {
+                            buffer.pop_front();
+                        }
+                        LaggingSubscriberPolicy::Coalesce => buffer.clear(),
+                        LaggingSubscriberPolicy::Disconnect => {
+                            debug!(target: "rpc::eth", subscription=?sink.subscription_id(), "Closing lagging subscription");
+                            return Ok(false)
+                        }
+                    }
+                }
+                buffer.push_back(msg);
+            }
+            buffered.notify_one();
+        }
+        Ok::<_, jsonrpsee::core::Error>(true)
+    };
+
+    // sends the buffered messages to the subscriber
+    let consume = async {
+        loop {
+            let next = buffer.lock().expect("not poisoned").pop_front();
+            match next {
+                Some(msg) => {
+                    if sink.send(msg).await.is_err() {
+                        break
+                    }
                 }
+                None => buffered.notified().await,
             }
         }
+    };
+
+    let stream_ended = tokio::select! {
+        _ = sink.closed() => {
+            // connection dropped
+            return Ok(())
+        },
+        stream_ended = produce => stream_ended?,
+        _ = consume => {
+            // connection dropped
+            return Ok(())
+        },
+    };
+
+    if stream_ended {
+        // send the remaining messages
+        for msg in buffer.into_inner().expect("not poisoned") {
+            if sink.send(msg).await.is_err() {
+                break
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// What to do with a subscription whose subscriber can't keep up with the stream of messages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LaggingSubscriberPolicy {
+    /// Drop the oldest buffered message to make room for the new one.
+    #[default]
+    DropOldest,
+    /// Close the subscription.
+    Disconnect,
+    /// Drop all buffered messages and only keep the most recent one.
+    Coalesce,
+}
+
+impl fmt::Display for LaggingSubscriberPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LaggingSubscriberPolicy::DropOldest => f.write_str("drop-oldest"),
+            LaggingSubscriberPolicy::Disconnect => f.write_str("disconnect"),
+            LaggingSubscriberPolicy::Coalesce => f.write_str("coalesce"),
+        }
+    }
+}
+
+impl FromStr for LaggingSubscriberPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "drop-oldest" => Ok(LaggingSubscriberPolicy::DropOldest),
+            "disconnect" => Ok(LaggingSubscriberPolicy::Disconnect),
+            "coalesce" => Ok(LaggingSubscriberPolicy::Coalesce),
+            _ => Err(format!("invalid lagging subscriber policy: {s}")),
+        }
+    }
+}
+
+/// Settings for buffering subscription messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubscriptionBufferConfig {
+    /// The maximum number of messages that are buffered per subscription.
+    pub buffer_size: usize,
+    /// What to do once the buffer of a subscription is full.
+    pub lagging_policy: LaggingSubscriberPolicy,
+}
+
+impl SubscriptionBufferConfig {
+    /// Sets the maximum number of messages that are buffered per subscription.
+    pub fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size.max(1);
+        self
+    }
+
+    /// Sets what to do once the buffer of a subscription is full.
+    pub fn lagging_policy(mut self, lagging_policy: LaggingSubscriberPolicy) -> Self {
+        self.lagging_policy = lagging_policy;
+        self
+    }
+}
+
+impl Default for SubscriptionBufferConfig {
+    fn default() -> Self {
+        Self {
+            buffer_size: DEFAULT_SUBSCRIPTION_BUFFER_SIZE,
+            lagging_policy: LaggingSubscriberPolicy::default(),
+        }
     }
 }
 
@@ -301,21 +459,155 @@ where
         })
     }
 
-    /// Returns a stream that yields all logs that match the given filter.
-    fn log_stream(&self, filter: FilteredParams) -> impl Stream<Item = Log> {
+    /// Returns a stream that yields all logs that match the given filter, once their block has
+    /// the given number of confirmations.
+    fn log_stream(&self, filter: FilteredParams, confirmations: u64) -> impl Stream<Item = Log> {
+        let mut confirmed = ConfirmedLogs::new(confirmations);
         BroadcastStream::new(self.chain_events.subscribe_to_canonical_state())
             .map(move |canon_state| {
-                canon_state.expect("new block subscription never ends").block_receipts()
-            })
-            .flat_map(futures::stream::iter)
-            .flat_map(move |(block_receipts, removed)| {
-                let all_logs = logs_utils::matching_block_logs_with_tx_hashes(
-                    &filter,
-                    block_receipts.block,
-                    block_receipts.tx_receipts.iter().map(|(tx, receipt)| (*tx, receipt)),
-                    removed,
-                );
-                futures::stream::iter(all_logs)
+                let block_receipts =
+                    canon_state.expect("new block subscription never ends").block_receipts();
+                futures::stream::iter(confirmed.on_block_receipts(&filter, block_receipts))
             })
+            .flatten()
+    }
+}
+
+/// Holds back the logs of new blocks until the blocks reached a confirmation depth.
+///
+/// Logs of blocks that are reorged out before they were confirmed are dropped silently, logs that
+/// were already emitted are emitted again with `removed: true`.
+#[derive(Debug)]
+struct ConfirmedLogs {
+    /// The number of blocks that have to be built on top of a block before its logs are emitted.
+    confirmations: u64,
+    /// The matching logs of canonical blocks that are not confirmed yet, in ascending order.
+    pending: VecDeque<(BlockNumHash, Vec<Log>)>,
+    /// The highest block whose logs were emitted.
+    emitted: Option<BlockNumber>,
+}
+
+impl ConfirmedLogs {
+    /// Creates a new instance with the given confirmation depth.
+    fn new(confirmations: u64) -> Self {
+        Self { confirmations, pending: VecDeque::new(), emitted: None }
+    }
+
+    /// Processes the receipts of a canonical state notification and returns the logs to emit.
+    ///
+    /// The receipts are expected in the order of `CanonStateNotification::block_receipts`, with
+    /// the receipts of reverted blocks flagged.
+    fn on_block_receipts(
+        &mut self,
+        filter: &FilteredParams,
+        block_receipts: Vec<(BlockReceipts, bool)>,
+    ) -> Vec<Log> {
+        let mut logs = Vec::new();
+        let mut first_removed = None;
+        let mut tip = None;
+
+        for (block_receipts, removed) in block_receipts {
+            let block = block_receipts.block;
+            let block_logs = logs_utils::matching_block_logs_with_tx_hashes(
+                filter,
+                block,
+                block_receipts.tx_receipts.iter().map(|(tx, receipt)| (*tx, receipt)),
+                removed,
+            );
+
+            if !removed {
+                self.pending.push_back((block, block_logs));
+                tip = Some(block.number);
+            } else if self.emitted.is_some_and(|emitted| block.number <= emitted) {
+                first_removed.get_or_insert(block.number);
+                logs.extend(block_logs);
+            } else {
+                self.pending.retain(|(pending, _)| pending.hash != block.hash);
+            }
+        }
+
+        if let Some(first_removed) = first_removed {
+            self.emitted = first_removed.checked_sub(1);
+        }
+
+        if let Some(tip) = tip {
+            while let Some((block, _)) = self.pending.front() {
+                if block.number.saturating_add(self.confirmations) > tip {
+                    break
+                }
+                let (block, block_logs) = self.pending.pop_front().expect("is not empty");
+                logs.extend(block_logs);
+                self.emitted = Some(block.number);
+            }
+        }
+
+        logs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{Address, Receipt, B256};
+
+    fn block_receipts(number: BlockNumber, fork: u8) -> BlockReceipts {
+        let log =
+            reth_primitives::Log { address: Address::with_last_byte(1), ..Default::default() };
+        BlockReceipts {
+            block: BlockNumHash::new(number, B256::with_last_byte(fork)),
+            tx_receipts: vec![(TxHash::ZERO, Receipt { logs: vec![log], ..Default::default() })],
+        }
+    }
+
+    fn blocks(logs: &[Log]) -> Vec<(u64, bool)> {
+        logs.iter().map(|log| (log.block_number.unwrap().to(), log.removed)).collect()
+    }
+
+    #[test]
+    fn emits_logs_at_confirmation_depth() {
+        let filter = FilteredParams::default();
+        let mut confirmed = ConfirmedLogs::new(2);
+
+        let logs = confirmed.on_block_receipts(&filter, vec![(block_receipts(1, 0), false)]);
+        assert!(logs.is_empty());
+        let logs = confirmed.on_block_receipts(&filter, vec![(block_receipts(2, 0), false)]);
+        assert!(logs.is_empty());
+        let logs = confirmed.on_block_receipts(
+            &filter,
+            vec![(block_receipts(3, 0), false), (block_receipts(4, 0), false)],
+        );
+        assert_eq!(blocks(&logs), [(1, false), (2, false)]);
+
+        // reorg of blocks 2 to 4, only the logs of block 2 were emitted
+        let logs = confirmed.on_block_receipts(
+            &filter,
+            vec![
+                (block_receipts(2, 0), true),
+                (block_receipts(3, 0), true),
+                (block_receipts(4, 0), true),
+                (block_receipts(2, 1), false),
+                (block_receipts(3, 1), false),
+            ],
+        );
+        assert_eq!(blocks(&logs), [(2, true)]);
+        assert_eq!(confirmed.emitted, Some(1));
+
+        let logs = confirmed.on_block_receipts(&filter, vec![(block_receipts(4, 1), false)]);
+        assert_eq!(blocks(&logs), [(2, false)]);
+        assert_eq!(logs[0].block_hash, Some(B256::with_last_byte(1)));
+    }
+
+    #[test]
+    fn emits_logs_immediately_without_confirmations() {
+        let filter = FilteredParams::default();
+        let mut confirmed = ConfirmedLogs::new(0);
+
+        let logs = confirmed.on_block_receipts(&filter, vec![(block_receipts(1, 0), false)]);
+        assert_eq!(blocks(&logs), [(1, false)]);
+        let logs = confirmed.on_block_receipts(
+            &filter,
+            vec![(block_receipts(1, 0), true), (block_receipts(1, 1), false)],
+        );
+        assert_eq!(blocks(&logs), [(1, true), (1, false)]);
     }
 }
@@ -20,10 +20,9 @@ use revm::primitives::{Bytes, OptimismFields};
 use revm::{
     db::CacheDB,
     inspector_handle_register,
-    precompile::{PrecompileSpecId, Precompiles},
     primitives::{
         db::DatabaseRef, BlockEnv, Bytecode, CfgEnvWithHandlerCfg, EnvWithHandlerCfg,
-        ResultAndState, SpecId, TransactTo, TxEnv,
+        ResultAndState, TransactTo, TxEnv,
     },
     Database, GetInspector,
 };
@@ -115,13 +114,6 @@ impl FillableTransaction for TransactionSigned {
     }
 }
 
-/// Returns the addresses of the precompiles corresponding to the SpecId.
-#[inline]
-pub(crate) fn get_precompiles(spec_id: SpecId) -> impl IntoIterator<Item = Address> {
-    let spec = PrecompileSpecId::from_spec_id(spec_id);
-    Precompiles::new(spec).addresses().copied().map(Address::from)
-}
-
 /// Executes the [EnvWithHandlerCfg] against the given [Database] without committing state changes.
 pub(crate) fn transact<DB>(
     db: DB,
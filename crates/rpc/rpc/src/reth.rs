@@ -1,39 +1,127 @@
-use crate::eth::error::{EthApiError, EthResult};
+use crate::eth::{
+    error::{EthApiError, EthResult},
+    pipe_from_stream,
+    revm_utils::EvmOverrides,
+    EthApi, SubscriptionBufferConfig,
+};
 use async_trait::async_trait;
-use jsonrpsee::core::RpcResult;
+use futures::{future, Stream, StreamExt};
+use jsonrpsee::{core::RpcResult, PendingSubscriptionSink};
 use reth_interfaces::RethResult;
-use reth_primitives::{Address, BlockId, U256};
-use reth_provider::{BlockReaderIdExt, ChangeSetReader, StateProviderFactory};
-use reth_rpc_api::RethApiServer;
+use reth_network_api::NetworkInfo;
+use reth_node_api::ConfigureEvmEnv;
+use reth_primitives::{Address, BlockId, Bytes, TxHash, B256, U256, U64};
+use reth_provider::{
+    BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader, EvmEnvProvider,
+    StateProviderFactory,
+};
+use reth_rpc_api::{RethApiServer, RethCallApiServer, RethEngineApiServer};
+use reth_rpc_types::{
+    engine::PayloadAttributes, state::StateOverride, BlockOverrides, PayloadBuildReport,
+    RethBlockId, RpcBlockHash, StorageChange, StorageChangesEvent, TransactionDropReason,
+    TransactionLifecycleEvent, TransactionLifecycleStatus, TransactionRequest,
+};
 use reth_tasks::TaskSpawner;
-use std::{collections::HashMap, future::Future, sync::Arc};
+use reth_transaction_pool::{FullTransactionEvent, PoolTransaction, TransactionPool};
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::Arc,
+};
 use tokio::sync::oneshot;
 
 /// `reth` API implementation.
 ///
 /// This type provides the functionality for handling `reth` prototype RPC requests.
-pub struct RethApi<Provider> {
-    inner: Arc<RethApiInner<Provider>>,
+pub struct RethApi<Provider, Pool, Events> {
+    inner: Arc<RethApiInner<Provider, Pool, Events>>,
 }
 
 // === impl RethApi ===
 
-impl<Provider> RethApi<Provider> {
+impl<Provider, Pool, Events> RethApi<Provider, Pool, Events> {
     /// The provider that can interact with the chain.
     pub fn provider(&self) -> &Provider {
         &self.inner.provider
     }
 
+    /// The transaction pool.
+    pub fn pool(&self) -> &Pool {
+        &self.inner.pool
+    }
+
+    /// The type that notifies about new canonical chains.
+    pub fn events(&self) -> &Events {
+        &self.inner.events
+    }
+
     /// Create a new instance of the [RethApi]
-    pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
-        let inner = Arc::new(RethApiInner { provider, task_spawner });
+    pub fn new(
+        provider: Provider,
+        pool: Pool,
+        events: Events,
+        task_spawner: Box<dyn TaskSpawner>,
+    ) -> Self {
+        let inner = Arc::new(RethApiInner { provider, pool, events, task_spawner });
         Self { inner }
     }
 }
 
-impl<Provider> RethApi<Provider>
+impl<Provider, Pool, Events> RethApi<Provider, Pool, Events>
+where
+    Pool: TransactionPool + 'static,
+{
+    /// Returns a stream of lifecycle events of the pool's transactions.
+    ///
+    /// If `hashes` is not empty, only events of these transactions are yielded.
+    pub fn transaction_lifecycle_stream(
+        &self,
+        hashes: impl IntoIterator<Item = TxHash>,
+    ) -> impl Stream<Item = TransactionLifecycleEvent> + Unpin {
+        let hashes = hashes.into_iter().collect::<HashSet<_>>();
+        self.pool().all_transactions_event_listener().filter_map(move |event| {
+            let event = transaction_lifecycle_event(event)
+                .filter(|event| hashes.is_empty() || hashes.contains(&event.hash));
+            future::ready(event)
+        })
+    }
+}
+
+impl<Provider, Pool, Events> RethApi<Provider, Pool, Events>
+where
+    Events: CanonStateSubscriptions,
+{
+    /// Returns a stream of the changes of the given storage slots by new canonical chains.
+    pub fn storage_changes_stream(
+        &self,
+        slots: HashMap<Address, Vec<B256>>,
+    ) -> impl Stream<Item = StorageChangesEvent> + Unpin {
+        let watched = slots
+            .into_iter()
+            .flat_map(|(address, slots)| slots.into_iter().map(move |slot| (address, slot)))
+            .collect();
+        self.events().storage_watch_stream(watched).map(|notification| StorageChangesEvent {
+            block_number: U64::from(notification.tip.number),
+            block_hash: notification.tip.hash,
+            changes: notification
+                .changes
+                .into_iter()
+                .map(|change| StorageChange {
+                    address: change.address,
+                    slot: change.slot,
+                    previous_value: change.previous,
+                    value: change.value,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl<Provider, Pool, Events> RethApi<Provider, Pool, Events>
 where
     Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Pool: 'static,
+    Events: 'static,
 {
     /// Executes the future on a new blocking task.
     async fn on_blocking_task<C, F, R>(&self, c: C) -> EthResult<R>
@@ -55,13 +143,17 @@ where
     /// Returns a map of addresses to changed account balanced for a particular block.
     pub async fn balance_changes_in_block(
         &self,
-        block_id: BlockId,
+        block_id: RethBlockId,
     ) -> EthResult<HashMap<Address, U256>> {
         self.on_blocking_task(|this| async move { this.try_balance_changes_in_block(block_id) })
             .await
     }
 
-    fn try_balance_changes_in_block(&self, block_id: BlockId) -> EthResult<HashMap<Address, U256>> {
+    fn try_balance_changes_in_block(
+        &self,
+        block_id: RethBlockId,
+    ) -> EthResult<HashMap<Address, U256>> {
+        let block_id = resolve_block_id(self.provider(), block_id)?;
         let Some(block_number) = self.provider().block_number_for_id(block_id)? else {
             return Err(EthApiError::UnknownBlockNumber)
         };
@@ -84,34 +176,223 @@ where
 }
 
 #[async_trait]
-impl<Provider> RethApiServer for RethApi<Provider>
+impl<Provider, Pool, Events> RethApiServer for RethApi<Provider, Pool, Events>
 where
     Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Pool: TransactionPool + 'static,
+    Events: CanonStateSubscriptions + 'static,
 {
     /// Handler for `reth_getBalanceChangesInBlock`
     async fn reth_get_balance_changes_in_block(
         &self,
-        block_id: BlockId,
+        block_id: RethBlockId,
     ) -> RpcResult<HashMap<Address, U256>> {
         Ok(RethApi::balance_changes_in_block(self, block_id).await?)
     }
+
+    /// Handler for `reth_subscribeTransactionLifecycle`
+    async fn reth_subscribe_transaction_lifecycle(
+        &self,
+        pending: PendingSubscriptionSink,
+        hashes: Option<Vec<TxHash>>,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        let sink = pending.accept().await?;
+        let stream = self.transaction_lifecycle_stream(hashes.unwrap_or_default());
+        self.inner.task_spawner.spawn(Box::pin(async move {
+            let _ = pipe_from_stream(sink, stream, SubscriptionBufferConfig::default()).await;
+        }));
+        Ok(())
+    }
+
+    /// Handler for `reth_subscribeStorage`
+    async fn reth_subscribe_storage(
+        &self,
+        pending: PendingSubscriptionSink,
+        slots: HashMap<Address, Vec<B256>>,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        let sink = pending.accept().await?;
+        let stream = self.storage_changes_stream(slots);
+        self.inner.task_spawner.spawn(Box::pin(async move {
+            let _ = pipe_from_stream(sink, stream, SubscriptionBufferConfig::default()).await;
+        }));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Provider, Pool, Network, EvmConfig> RethEngineApiServer
+    for EthApi<Provider, Pool, Network, EvmConfig>
+where
+    Provider:
+        BlockReaderIdExt + ChainSpecProvider + StateProviderFactory + EvmEnvProvider + 'static,
+    Pool: TransactionPool + Clone + 'static,
+    Network: NetworkInfo + Send + Sync + 'static,
+    EvmConfig: ConfigureEvmEnv + 'static,
+{
+    /// Handler for `reth_buildPayload`
+    async fn reth_build_payload(
+        &self,
+        attributes: PayloadAttributes,
+        parent_block_hash: Option<B256>,
+    ) -> RpcResult<PayloadBuildReport> {
+        Ok(EthApi::build_payload_dry_run(self, attributes, parent_block_hash).await?)
+    }
+}
+
+/// `reth` API implementation of the methods that execute calls, backed by the `eth` API.
+pub struct RethCallApi<Provider, Pool, Network, EvmConfig> {
+    /// The `eth` API that executes the calls.
+    eth_api: EthApi<Provider, Pool, Network, EvmConfig>,
+    /// Number of blocks below the latest block that calls without a block are executed at.
+    default_distance: u64,
+}
+
+// === impl RethCallApi ===
+
+impl<Provider, Pool, Network, EvmConfig> RethCallApi<Provider, Pool, Network, EvmConfig> {
+    /// Create a new instance of the [RethCallApi]
+    pub fn new(eth_api: EthApi<Provider, Pool, Network, EvmConfig>) -> Self {
+        Self { eth_api, default_distance: 0 }
+    }
+
+    /// Sets the number of blocks below the latest block that calls without a block are executed
+    /// at.
+    pub fn with_default_distance(mut self, default_distance: u64) -> Self {
+        self.default_distance = default_distance;
+        self
+    }
+}
+
+impl<Provider, Pool, Network, EvmConfig> RethCallApi<Provider, Pool, Network, EvmConfig>
+where
+    Provider:
+        BlockReaderIdExt + ChainSpecProvider + StateProviderFactory + EvmEnvProvider + 'static,
+    Pool: TransactionPool + Clone + 'static,
+    Network: NetworkInfo + Send + Sync + 'static,
+    EvmConfig: ConfigureEvmEnv + 'static,
+{
+    /// Executes the call at the given block, or at the default distance below the latest block.
+    pub async fn call(
+        &self,
+        request: TransactionRequest,
+        block_id: Option<RethBlockId>,
+        overrides: EvmOverrides,
+    ) -> EthResult<Bytes> {
+        let block_id = block_id.unwrap_or(RethBlockId::LatestMinus(self.default_distance));
+        let block_id = resolve_block_id(self.eth_api.provider(), block_id)?;
+        self.eth_api.call(request, Some(block_id), overrides).await
+    }
+}
+
+#[async_trait]
+impl<Provider, Pool, Network, EvmConfig> RethCallApiServer
+    for RethCallApi<Provider, Pool, Network, EvmConfig>
+where
+    Provider:
+        BlockReaderIdExt + ChainSpecProvider + StateProviderFactory + EvmEnvProvider + 'static,
+    Pool: TransactionPool + Clone + 'static,
+    Network: NetworkInfo + Send + Sync + 'static,
+    EvmConfig: ConfigureEvmEnv + 'static,
+{
+    /// Handler for `reth_call`
+    async fn reth_call(
+        &self,
+        request: TransactionRequest,
+        block_id: Option<RethBlockId>,
+        state_overrides: Option<StateOverride>,
+        block_overrides: Option<Box<BlockOverrides>>,
+    ) -> RpcResult<Bytes> {
+        Ok(RethCallApi::call(
+            self,
+            request,
+            block_id,
+            EvmOverrides::new(state_overrides, block_overrides),
+        )
+        .await?)
+    }
+}
+
+impl<Provider, Pool, Network, EvmConfig> std::fmt::Debug
+    for RethCallApi<Provider, Pool, Network, EvmConfig>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RethCallApi")
+            .field("default_distance", &self.default_distance)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Provider, Pool, Network, EvmConfig> Clone for RethCallApi<Provider, Pool, Network, EvmConfig> {
+    fn clone(&self) -> Self {
+        Self { eth_api: self.eth_api.clone(), default_distance: self.default_distance }
+    }
+}
+
+/// Resolves a [RethBlockId] to a [BlockId] the provider understands.
+///
+/// A `latest-<n>` tag is pinned to the hash of the canonical block at that height, so that the
+/// request keeps operating on that block even if the chain advances or reorgs in the meantime.
+fn resolve_block_id<Provider: BlockReaderIdExt>(
+    provider: &Provider,
+    block_id: RethBlockId,
+) -> EthResult<BlockId> {
+    let distance = match block_id {
+        RethBlockId::Id(id) => return Ok(id),
+        RethBlockId::LatestMinus(distance) => distance,
+    };
+    let number = provider
+        .best_block_number()?
+        .checked_sub(distance)
+        .ok_or(EthApiError::UnknownBlockNumber)?;
+    let hash = provider.block_hash(number)?.ok_or(EthApiError::UnknownBlockNumber)?;
+    Ok(BlockId::Hash(RpcBlockHash { block_hash: hash, require_canonical: Some(true) }))
+}
+
+/// Converts a pool event into a [TransactionLifecycleEvent], if it's relevant for the lifecycle
+/// subscription.
+fn transaction_lifecycle_event<T: PoolTransaction>(
+    event: FullTransactionEvent<T>,
+) -> Option<TransactionLifecycleEvent> {
+    let (hash, status) = match event {
+        FullTransactionEvent::Replaced { transaction, replaced_by } => {
+            (*transaction.hash(), TransactionLifecycleStatus::Replaced { replaced_by })
+        }
+        FullTransactionEvent::Discarded(hash) => (
+            hash,
+            TransactionLifecycleStatus::Dropped { reason: TransactionDropReason::PoolLimits },
+        ),
+        FullTransactionEvent::Invalid(hash) => {
+            (hash, TransactionLifecycleStatus::Dropped { reason: TransactionDropReason::Invalid })
+        }
+        FullTransactionEvent::Mined { tx_hash, block_hash } => {
+            (tx_hash, TransactionLifecycleStatus::Included { block_hash })
+        }
+        FullTransactionEvent::Pending(_) |
+        FullTransactionEvent::Queued(_) |
+        FullTransactionEvent::Propagated(_) => return None,
+    };
+    Some(TransactionLifecycleEvent { hash, status })
 }
 
-impl<Provider> std::fmt::Debug for RethApi<Provider> {
+impl<Provider, Pool, Events> std::fmt::Debug for RethApi<Provider, Pool, Events> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RethApi").finish_non_exhaustive()
     }
 }
 
-impl<Provider> Clone for RethApi<Provider> {
+impl<Provider, Pool, Events> Clone for RethApi<Provider, Pool, Events> {
     fn clone(&self) -> Self {
         Self { inner: Arc::clone(&self.inner) }
     }
 }
 
-struct RethApiInner<Provider> {
+struct RethApiInner<Provider, Pool, Events> {
     /// The provider that can interact with the chain.
     provider: Provider,
+    /// The transaction pool, used for transaction lifecycle subscriptions.
+    pool: Pool,
+    /// Canonical state notifications, used for storage subscriptions.
+    events: Events,
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
 }
@@ -4,9 +4,11 @@ use reth_primitives::{Address, U256, U64};
 use reth_rpc_api::TxPoolApiServer;
 use reth_rpc_types::{
     txpool::{TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolInspectSummary, TxpoolStatus},
-    Transaction,
+    Transaction, TxpoolDump, TxpoolDumpBlockInfo, TxpoolDumpSender, TxpoolDumpTransaction,
+};
+use reth_transaction_pool::{
+    AllPoolTransactions, PoolDump, PoolTransaction, SubPool, TransactionOrigin, TransactionPool,
 };
-use reth_transaction_pool::{AllPoolTransactions, PoolTransaction, TransactionPool};
 use std::collections::BTreeMap;
 use tracing::trace;
 
@@ -135,6 +137,65 @@ where
         trace!(target: "rpc::eth", "Serving txpool_inspect");
         Ok(self.content())
     }
+
+    /// Returns a snapshot of the full state of the pool.
+    ///
+    /// Handler for `txpool_dump`
+    async fn txpool_dump(&self) -> Result<TxpoolDump> {
+        trace!(target: "rpc::eth", "Serving txpool_dump");
+        let PoolDump { block_info, senders, transactions } = self.pool.dump();
+        Ok(TxpoolDump {
+            block_info: TxpoolDumpBlockInfo {
+                last_seen_block_hash: block_info.last_seen_block_hash,
+                last_seen_block_number: block_info.last_seen_block_number,
+                pending_basefee: block_info.pending_basefee,
+                pending_blob_fee: block_info.pending_blob_fee,
+            },
+            senders: senders
+                .into_iter()
+                .map(|sender| TxpoolDumpSender {
+                    address: sender.address,
+                    state_nonce: sender.state_nonce,
+                    balance: sender.balance,
+                })
+                .collect(),
+            transactions: transactions
+                .into_iter()
+                .map(|tx| TxpoolDumpTransaction {
+                    hash: tx.hash,
+                    sender: tx.sender,
+                    nonce: tx.nonce,
+                    subpool: subpool_name(tx.subpool).to_string(),
+                    state: tx.state,
+                    cumulative_cost: tx.cumulative_cost,
+                    priority: tx.priority,
+                    submission_id: tx.submission_id,
+                    origin: origin_name(tx.origin).to_string(),
+                    propagate: tx.propagate,
+                    raw: tx.raw,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Returns the name of the sub-pool, as used in the pool's own dump.
+fn subpool_name(subpool: SubPool) -> &'static str {
+    match subpool {
+        SubPool::Queued => "queued",
+        SubPool::BaseFee => "baseFee",
+        SubPool::Blob => "blob",
+        SubPool::Pending => "pending",
+    }
+}
+
+/// Returns the name of the transaction origin, as used in the pool's own dump.
+fn origin_name(origin: TransactionOrigin) -> &'static str {
+    match origin {
+        TransactionOrigin::Local => "local",
+        TransactionOrigin::External => "external",
+        TransactionOrigin::Private => "private",
+    }
 }
 
 impl<Pool> std::fmt::Debug for TxPoolApi<Pool> {
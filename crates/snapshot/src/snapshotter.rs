@@ -3,7 +3,10 @@
 use crate::{segments, segments::Segment, SnapshotterError};
 use reth_db::{database::Database, snapshot::iter_snapshots};
 use reth_interfaces::{RethError, RethResult};
-use reth_primitives::{snapshot::HighestSnapshots, BlockNumber, TxNumber};
+use reth_primitives::{
+    snapshot::{HighestSnapshots, SnapshotConfig},
+    BlockNumber, SnapshotSegment, TxNumber,
+};
 use reth_provider::{BlockReader, DatabaseProviderRO, ProviderFactory, TransactionsProviderExt};
 use std::{
     collections::HashMap,
@@ -36,8 +39,8 @@ pub struct Snapshotter<DB> {
     highest_snapshots_notifier: watch::Sender<Option<HighestSnapshots>>,
     /// Channel receiver to be cloned and shared that already comes with the newest value
     highest_snapshots_tracker: HighestSnapshotsTracker,
-    /// Block interval after which the snapshot is taken.
-    block_interval: u64,
+    /// Block intervals after which the snapshots of the segments are taken.
+    config: SnapshotConfig,
 }
 
 /// Tracker for the latest [`HighestSnapshots`] value.
@@ -57,15 +60,22 @@ impl SnapshotTargets {
         self.headers.is_some() || self.receipts.is_some() || self.transactions.is_some()
     }
 
-    /// Returns `true` if all targets are either [None] or multiple of `block_interval`.
-    fn is_multiple_of_block_interval(&self, block_interval: u64) -> bool {
+    /// Returns `true` if all targets are either [None] or multiple of the block interval of their
+    /// segment.
+    fn is_multiple_of_block_interval(&self, config: &SnapshotConfig) -> bool {
         [
-            self.headers.as_ref(),
-            self.receipts.as_ref().map(|(blocks, _)| blocks),
-            self.transactions.as_ref().map(|(blocks, _)| blocks),
+            (self.headers.as_ref(), SnapshotSegment::Headers),
+            (self.receipts.as_ref().map(|(blocks, _)| blocks), SnapshotSegment::Receipts),
+            (self.transactions.as_ref().map(|(blocks, _)| blocks), SnapshotSegment::Transactions),
         ]
         .iter()
-        .all(|blocks| blocks.map_or(true, |blocks| (blocks.end() + 1) % block_interval == 0))
+        .all(|(blocks, segment)| {
+            blocks.map_or(true, |blocks| {
+                config
+                    .block_interval(*segment)
+                    .is_some_and(|block_interval| (blocks.end() + 1) % block_interval == 0)
+            })
+        })
     }
 
     // Returns `true` if all targets are either [`None`] or has beginning of the range equal to the
@@ -92,7 +102,7 @@ impl<DB: Database> Snapshotter<DB> {
     pub fn new(
         provider_factory: ProviderFactory<DB>,
         snapshots_path: impl AsRef<Path>,
-        block_interval: u64,
+        config: SnapshotConfig,
     ) -> RethResult<Self> {
         let (highest_snapshots_notifier, highest_snapshots_tracker) = watch::channel(None);
 
@@ -102,7 +112,7 @@ impl<DB: Database> Snapshotter<DB> {
             highest_snapshots: HighestSnapshots::default(),
             highest_snapshots_notifier,
             highest_snapshots_tracker,
-            block_interval,
+            config,
         };
 
         snapshotter.create_directory()?;
@@ -177,7 +187,7 @@ impl<DB: Database> Snapshotter<DB> {
 
     /// Run the snapshotter
     pub fn run(&mut self, targets: SnapshotTargets) -> SnapshotterResult {
-        debug_assert!(targets.is_multiple_of_block_interval(self.block_interval));
+        debug_assert!(targets.is_multiple_of_block_interval(&self.config));
         debug_assert!(targets.is_contiguous_to_highest_snapshots(self.highest_snapshots));
 
         self.run_segment::<segments::Receipts>(targets.receipts.clone().map(|(range, _)| range))?;
@@ -220,71 +230,75 @@ impl<DB: Database> Snapshotter<DB> {
     }
 
     /// Returns a snapshot targets at the provided finalized block number, respecting the block
-    /// interval. The target is determined by the check against last snapshots.
+    /// intervals of the segments. The target is determined by the check against last snapshots.
     pub fn get_snapshot_targets(
         &self,
         finalized_block_number: BlockNumber,
     ) -> RethResult<SnapshotTargets> {
         let provider = self.provider_factory.provider()?;
 
-        // Round down `finalized_block_number` to a multiple of `block_interval`
-        let to_block_number = finalized_block_number.saturating_sub(
-            // Adjust for 0-indexed block numbers
-            (finalized_block_number + 1) % self.block_interval,
-        );
-
         // Calculate block ranges to snapshot
         let headers_block_range =
-            self.get_snapshot_target_block_range(to_block_number, self.highest_snapshots.headers);
+            self.get_snapshot_target_block_range(SnapshotSegment::Headers, finalized_block_number);
         let receipts_block_range =
-            self.get_snapshot_target_block_range(to_block_number, self.highest_snapshots.receipts);
+            self.get_snapshot_target_block_range(SnapshotSegment::Receipts, finalized_block_number);
         let transactions_block_range = self
-            .get_snapshot_target_block_range(to_block_number, self.highest_snapshots.transactions);
+            .get_snapshot_target_block_range(SnapshotSegment::Transactions, finalized_block_number);
 
         // Calculate transaction ranges to snapshot
         let mut block_to_tx_number_cache = HashMap::default();
-        let receipts_tx_range = self.get_snapshot_target_tx_range(
-            &provider,
-            &mut block_to_tx_number_cache,
-            self.highest_snapshots.receipts,
-            &receipts_block_range,
-        )?;
-        let transactions_tx_range = self.get_snapshot_target_tx_range(
-            &provider,
-            &mut block_to_tx_number_cache,
-            self.highest_snapshots.transactions,
-            &transactions_block_range,
-        )?;
+        let receipts = receipts_block_range
+            .map(|block_range| {
+                let tx_range = self.get_snapshot_target_tx_range(
+                    &provider,
+                    &mut block_to_tx_number_cache,
+                    self.highest_snapshots.receipts,
+                    &block_range,
+                )?;
+                RethResult::Ok((block_range, tx_range))
+            })
+            .transpose()?;
+        let transactions = transactions_block_range
+            .map(|block_range| {
+                let tx_range = self.get_snapshot_target_tx_range(
+                    &provider,
+                    &mut block_to_tx_number_cache,
+                    self.highest_snapshots.transactions,
+                    &block_range,
+                )?;
+                RethResult::Ok((block_range, tx_range))
+            })
+            .transpose()?;
 
-        Ok(SnapshotTargets {
-            headers: headers_block_range
-                .size_hint()
-                .1
-                .expect("finalized block should be >= last headers snapshot")
-                .ge(&(self.block_interval as usize))
-                .then_some(headers_block_range),
-            receipts: receipts_block_range
-                .size_hint()
-                .1
-                .expect("finalized block should be >= last receipts snapshot")
-                .ge(&(self.block_interval as usize))
-                .then_some((receipts_block_range, receipts_tx_range)),
-            transactions: transactions_block_range
-                .size_hint()
-                .1
-                .expect("finalized block should be >= last transactions snapshot")
-                .ge(&(self.block_interval as usize))
-                .then_some((transactions_block_range, transactions_tx_range)),
-        })
+        Ok(SnapshotTargets { headers: headers_block_range, receipts, transactions })
     }
 
+    /// Returns the next block range to snapshot for the segment, if the finalized block number
+    /// passed a full block interval of the segment since its highest snapshot.
     fn get_snapshot_target_block_range(
         &self,
-        to_block_number: BlockNumber,
-        highest_snapshot: Option<BlockNumber>,
-    ) -> RangeInclusive<BlockNumber> {
-        let highest_snapshot = highest_snapshot.map_or(0, |block_number| block_number + 1);
-        highest_snapshot..=(highest_snapshot + self.block_interval - 1).min(to_block_number)
+        segment: SnapshotSegment,
+        finalized_block_number: BlockNumber,
+    ) -> Option<RangeInclusive<BlockNumber>> {
+        let block_interval = self.config.block_interval(segment)?;
+
+        // Round down `finalized_block_number` to a multiple of `block_interval`
+        let to_block_number = finalized_block_number.saturating_sub(
+            // Adjust for 0-indexed block numbers
+            (finalized_block_number + 1) % block_interval,
+        );
+
+        let highest_snapshot =
+            self.highest_snapshots.highest(segment).map_or(0, |block_number| block_number + 1);
+        let block_range =
+            highest_snapshot..=(highest_snapshot + block_interval - 1).min(to_block_number);
+
+        block_range
+            .size_hint()
+            .1
+            .expect("finalized block should be >= last snapshot")
+            .ge(&(block_interval as usize))
+            .then_some(block_range)
     }
 
     fn get_snapshot_target_tx_range(
@@ -327,14 +341,19 @@ mod tests {
         test_utils::{generators, generators::random_block_range},
         RethError,
     };
-    use reth_primitives::{snapshot::HighestSnapshots, B256};
+    use reth_primitives::{
+        snapshot::{HighestSnapshots, SnapshotConfig},
+        SnapshotSegment, B256,
+    };
     use reth_stages::test_utils::TestStageDB;
 
     #[test]
     fn new() {
         let db = TestStageDB::default();
         let snapshots_dir = tempfile::TempDir::new().unwrap();
-        let snapshotter = Snapshotter::new(db.factory, snapshots_dir.into_path(), 2).unwrap();
+        let snapshotter =
+            Snapshotter::new(db.factory, snapshots_dir.into_path(), SnapshotConfig::new(2))
+                .unwrap();
 
         assert_eq!(
             *snapshotter.highest_snapshot_receiver().borrow(),
@@ -351,7 +370,9 @@ mod tests {
         let blocks = random_block_range(&mut rng, 0..=3, B256::ZERO, 2..3);
         db.insert_blocks(blocks.iter(), None).expect("insert blocks");
 
-        let mut snapshotter = Snapshotter::new(db.factory, snapshots_dir.into_path(), 2).unwrap();
+        let mut snapshotter =
+            Snapshotter::new(db.factory, snapshots_dir.into_path(), SnapshotConfig::new(2))
+                .unwrap();
 
         // Snapshot targets has data per part up to the passed finalized block number,
         // respecting the block interval
@@ -364,7 +385,7 @@ mod tests {
                 transactions: Some((0..=1, 0..=3))
             }
         );
-        assert!(targets.is_multiple_of_block_interval(snapshotter.block_interval));
+        assert!(targets.is_multiple_of_block_interval(&snapshotter.config));
         assert!(targets.is_contiguous_to_highest_snapshots(snapshotter.highest_snapshots));
         // Imitate snapshotter run according to the targets which updates the last snapshots state
         snapshotter.set_highest_snapshots_from_targets(&targets);
@@ -386,7 +407,7 @@ mod tests {
                 transactions: Some((2..=3, 4..=7))
             }
         );
-        assert!(targets.is_multiple_of_block_interval(snapshotter.block_interval));
+        assert!(targets.is_multiple_of_block_interval(&snapshotter.config));
         assert!(targets.is_contiguous_to_highest_snapshots(snapshotter.highest_snapshots));
         // Imitate snapshotter run according to the targets which updates the last snapshots state
         snapshotter.set_highest_snapshots_from_targets(&targets);
@@ -394,4 +415,31 @@ mod tests {
         // Block body indices not found
         assert_matches!(snapshotter.get_snapshot_targets(5), Err(RethError::Custom(_)));
     }
+
+    #[test]
+    fn get_snapshot_targets_per_segment() {
+        let db = TestStageDB::default();
+        let snapshots_dir = tempfile::TempDir::new().unwrap();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(&mut rng, 0..=5, B256::ZERO, 2..3);
+        db.insert_blocks(blocks.iter(), None).expect("insert blocks");
+
+        let config = SnapshotConfig::new(2)
+            .with_block_interval(SnapshotSegment::Headers, 3)
+            .disable(SnapshotSegment::Transactions);
+        let snapshotter = Snapshotter::new(db.factory, snapshots_dir.into_path(), config).unwrap();
+
+        // Every segment is snapshotted with its own interval, disabled segments never are
+        let targets = snapshotter.get_snapshot_targets(4).expect("get snapshot targets");
+        assert_eq!(
+            targets,
+            SnapshotTargets {
+                headers: Some(0..=2),
+                receipts: Some((0..=1, 0..=3)),
+                transactions: None
+            }
+        );
+        assert!(targets.is_multiple_of_block_interval(&snapshotter.config));
+    }
 }
@@ -0,0 +1,126 @@
+use reth_primitives::{stage::StageId, BlockNumber};
+use std::time::Duration;
+
+/// The throughput of a single stage measured during a dry run of the pipeline, see
+/// [Pipeline::estimate](crate::Pipeline::estimate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageEstimate {
+    /// The stage that was sampled.
+    pub stage_id: StageId,
+    /// The block number the stage has reached before the dry run.
+    pub checkpoint: BlockNumber,
+    /// The number of blocks the stage processed during the dry run.
+    pub sampled_blocks: u64,
+    /// The time it took the stage to process the sampled blocks.
+    pub elapsed: Duration,
+    /// The number of blocks the stage has left to process until it reaches the target.
+    pub remaining_blocks: u64,
+}
+
+impl StageEstimate {
+    /// Returns the number of blocks per second the stage processed during the dry run.
+    ///
+    /// Returns `None` if no blocks were sampled.
+    pub fn blocks_per_second(&self) -> Option<f64> {
+        if self.sampled_blocks == 0 {
+            return None
+        }
+        Some(self.sampled_blocks as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON))
+    }
+
+    /// Returns the estimated time until the stage reaches the target, assuming the throughput of
+    /// the dry run.
+    ///
+    /// Returns `None` if the stage has blocks left to process, but none were sampled.
+    pub fn eta(&self) -> Option<Duration> {
+        if self.remaining_blocks == 0 {
+            return Some(Duration::ZERO)
+        }
+        if self.sampled_blocks == 0 {
+            return None
+        }
+        let nanos =
+            self.elapsed.as_nanos() * self.remaining_blocks as u128 / self.sampled_blocks as u128;
+        Some(Duration::from_nanos(nanos.try_into().unwrap_or(u64::MAX)))
+    }
+}
+
+/// The estimated time and disk usage of syncing the pipeline to a target block, see
+/// [Pipeline::estimate](crate::Pipeline::estimate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipelineEstimate {
+    /// The block the estimate was made for.
+    pub target: BlockNumber,
+    /// The estimates of all stages, in the order they are executed.
+    pub stages: Vec<StageEstimate>,
+    /// The size of the database in bytes before the dry run.
+    pub database_size: u64,
+}
+
+impl PipelineEstimate {
+    /// Returns the lowest block number reached by all stages.
+    pub fn synced_block(&self) -> BlockNumber {
+        self.stages.iter().map(|stage| stage.checkpoint).min().unwrap_or_default()
+    }
+
+    /// Returns the estimated time until all stages reach the target.
+    ///
+    /// Stages are executed one after another, so this is the sum of the estimates of all stages.
+    /// Returns `None` if the estimate of any stage is unknown.
+    pub fn eta(&self) -> Option<Duration> {
+        self.stages.iter().map(StageEstimate::eta).sum()
+    }
+
+    /// Returns the forecasted size of the database in bytes once all stages reach the target,
+    /// extrapolating the current size per synced block.
+    ///
+    /// Returns `None` if no block was synced yet.
+    pub fn database_size_forecast(&self) -> Option<u64> {
+        let synced_block = self.synced_block();
+        if synced_block == 0 {
+            return None
+        }
+        let remaining_blocks = self.target.saturating_sub(synced_block);
+        let growth = self.database_size as u128 * remaining_blocks as u128 / synced_block as u128;
+        Some(self.database_size.saturating_add(growth.try_into().unwrap_or(u64::MAX)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(checkpoint: BlockNumber, sampled_blocks: u64, elapsed: u64) -> StageEstimate {
+        StageEstimate {
+            stage_id: StageId::Execution,
+            checkpoint,
+            sampled_blocks,
+            elapsed: Duration::from_secs(elapsed),
+            remaining_blocks: 1_000 - checkpoint,
+        }
+    }
+
+    #[test]
+    fn stage_eta() {
+        assert_eq!(stage(0, 100, 10).eta(), Some(Duration::from_secs(100)));
+        assert_eq!(stage(0, 100, 10).blocks_per_second(), Some(10.0));
+        assert_eq!(stage(1_000, 0, 0).eta(), Some(Duration::ZERO));
+        assert_eq!(stage(0, 0, 0).eta(), None);
+    }
+
+    #[test]
+    fn pipeline_eta_and_forecast() {
+        let mut estimate = PipelineEstimate {
+            target: 1_000,
+            stages: vec![stage(500, 100, 10), stage(250, 50, 1)],
+            database_size: 1_000,
+        };
+        assert_eq!(estimate.synced_block(), 250);
+        assert_eq!(estimate.eta(), Some(Duration::from_secs(50 + 15)));
+        assert_eq!(estimate.database_size_forecast(), Some(4_000));
+
+        estimate.stages.push(stage(0, 0, 0));
+        assert_eq!(estimate.eta(), None);
+        assert_eq!(estimate.database_size_forecast(), None);
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     error::*, ExecInput, ExecOutput, MetricEvent, MetricEventsSender, Stage, StageExt, UnwindInput,
 };
 use futures_util::Future;
-use reth_db::database::Database;
+use reth_db::{database::Database, database_metrics::DatabaseMetrics};
 use reth_primitives::{
     constants::BEACON_CONSENSUS_REORG_UNWIND_DEPTH,
     stage::{StageCheckpoint, StageId},
@@ -10,19 +10,21 @@ use reth_primitives::{
 };
 use reth_provider::{ProviderFactory, StageCheckpointReader, StageCheckpointWriter};
 use reth_tokio_util::EventListeners;
-use std::pin::Pin;
+use std::{pin::Pin, time::Instant};
 use tokio::sync::watch;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::*;
 
 mod builder;
 mod ctrl;
+mod estimate;
 mod event;
 mod progress;
 mod set;
 
 pub use crate::pipeline::ctrl::ControlFlow;
 pub use builder::*;
+pub use estimate::*;
 pub use event::*;
 use progress::*;
 pub use set::*;
@@ -396,6 +398,75 @@ where
     }
 }
 
+impl<DB> Pipeline<DB>
+where
+    DB: Database + DatabaseMetrics + 'static,
+{
+    /// Estimates the time and disk space it takes to sync all stages to the `target` block,
+    /// without committing anything to the database.
+    ///
+    /// Every stage is executed once on at most `sample_blocks` blocks following its checkpoint,
+    /// and the throughput of that run is extrapolated to the blocks the stage has left to process.
+    /// All stages run in a single database transaction that is discarded afterwards, which means
+    /// a stage can only sample the blocks that the previous stage processed, either before or
+    /// during the dry run. The first stage samples up to the maximum block of the pipeline, if
+    /// set (see [PipelineBuilder::with_max_block]).
+    ///
+    /// The database size forecast extrapolates the current size of the database per block that
+    /// was synced by all stages.
+    pub async fn estimate(
+        &mut self,
+        target: BlockNumber,
+        sample_blocks: u64,
+    ) -> Result<PipelineEstimate, PipelineError> {
+        let database_size = self
+            .provider_factory
+            .db_ref()
+            .gauge_metrics()
+            .into_iter()
+            .filter(|(name, _, _)| *name == "db.table_size")
+            .map(|(_, size, _)| size as u64)
+            .sum();
+
+        let provider_rw = self.provider_factory.provider_rw()?;
+        let mut previous_stage = self.max_block;
+        let mut stages = Vec::with_capacity(self.stages.len());
+        for stage in &mut self.stages {
+            let stage_id = stage.id();
+            let checkpoint = provider_rw.get_stage_checkpoint(stage_id)?;
+            let from = checkpoint.unwrap_or_default().block_number;
+            let sample_target = previous_stage
+                .unwrap_or(target)
+                .min(target)
+                .min(from.saturating_add(sample_blocks));
+
+            let mut reached = from;
+            let started_at = Instant::now();
+            if sample_target > from {
+                let input = ExecInput { target: Some(sample_target), checkpoint };
+                stage.execute_ready(input).await?;
+                reached = stage.execute(&provider_rw, input)?.checkpoint.block_number;
+            }
+            let estimate = StageEstimate {
+                stage_id,
+                checkpoint: from,
+                sampled_blocks: reached.saturating_sub(from),
+                elapsed: started_at.elapsed(),
+                remaining_blocks: target.saturating_sub(from),
+            };
+            debug!(target: "sync::pipeline", stage = %stage_id, ?estimate, "Sampled stage");
+
+            stages.push(estimate);
+            previous_stage = Some(reached);
+        }
+
+        // discard everything the stages wrote during the dry run
+        drop(provider_rw);
+
+        Ok(PipelineEstimate { target, stages, database_size })
+    }
+}
+
 fn on_stage_error<DB: Database>(
     factory: &ProviderFactory<DB>,
     stage_id: StageId,
@@ -575,6 +646,40 @@ mod tests {
         );
     }
 
+    /// Estimates a simple pipeline without committing the dry run.
+    #[tokio::test]
+    async fn estimate_pipeline() {
+        let provider_factory = create_test_provider_factory();
+
+        let mut pipeline = Pipeline::builder()
+            .add_stage(
+                TestStage::new(StageId::Other("A"))
+                    .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(20), done: false })),
+            )
+            .add_stage(
+                TestStage::new(StageId::Other("B"))
+                    .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(10), done: true })),
+            )
+            .build(provider_factory.clone());
+
+        let estimate = pipeline.estimate(100, 50).await.unwrap();
+        assert_eq!(estimate.target, 100);
+        assert_eq!(
+            estimate
+                .stages
+                .iter()
+                .map(|stage| (stage.stage_id, stage.sampled_blocks, stage.remaining_blocks))
+                .collect::<Vec<_>>(),
+            vec![(StageId::Other("A"), 20, 100), (StageId::Other("B"), 10, 100)]
+        );
+        assert!(estimate.eta().is_some());
+
+        // the dry run was not committed
+        let provider = provider_factory.provider().unwrap();
+        assert_eq!(provider.get_stage_checkpoint(StageId::Other("A")).unwrap(), None);
+        assert_eq!(provider.get_stage_checkpoint(StageId::Other("B")).unwrap(), None);
+    }
+
     /// Unwinds a simple pipeline.
     #[tokio::test]
     async fn unwind_pipeline() {
@@ -6,7 +6,7 @@ use num_traits::Zero;
 use reth_db::{
     cursor::{DbCursorRO, DbCursorRW, DbDupCursorRO},
     database::Database,
-    models::BlockNumberAddress,
+    models::{BlockNumberAddress, HotAccountsDecay},
     tables,
     transaction::{DbTx, DbTxMut},
 };
@@ -18,8 +18,9 @@ use reth_primitives::{
     BlockNumber, Header, PruneModes, U256,
 };
 use reth_provider::{
-    BlockReader, DatabaseProviderRW, ExecutorFactory, HeaderProvider, LatestStateProviderRef,
-    OriginalValuesKnown, ProviderError, TransactionVariant,
+    BlockReader, DatabaseProviderRW, ExecutorFactory, ForkTransitionsWriter, HeaderProvider,
+    HotAccountsWriter, LatestStateProviderRef, OriginalValuesKnown, ProviderError,
+    TransactionVariant,
 };
 use std::{
     ops::RangeInclusive,
@@ -49,6 +50,9 @@ use tracing::*;
 /// - [tables::Bytecodes]
 /// - [tables::AccountChangeSet]
 /// - [tables::StorageChangeSet]
+/// - [tables::HotAccounts], if the stage doesn't execute a large range of blocks, e.g. on first
+///   sync
+/// - [tables::ForkTransitions], which is not unwound
 ///
 /// For unwinds we are accessing:
 /// - [tables::BlockBodyIndices] get tx index to know what needs to be unwinded
@@ -70,6 +74,8 @@ pub struct ExecutionStage<EF: ExecutorFactory> {
     external_clean_threshold: u64,
     /// Pruning configuration.
     prune_modes: PruneModes,
+    /// Whether to commit the blocks executed before a block with mismatching receipts.
+    commit_valid_prefix: bool,
 }
 
 impl<EF: ExecutorFactory> ExecutionStage<EF> {
@@ -86,6 +92,7 @@ impl<EF: ExecutorFactory> ExecutionStage<EF> {
             executor_factory,
             thresholds,
             prune_modes,
+            commit_valid_prefix: false,
         }
     }
 
@@ -107,6 +114,17 @@ impl<EF: ExecutorFactory> ExecutionStage<EF> {
         self
     }
 
+    /// Set whether to commit the blocks executed before a block with a mismatching receipts root
+    /// or logs bloom.
+    ///
+    /// If enabled, the batch ends at the block before the mismatching block, so its progress is
+    /// committed, and the mismatch is reported on the next execution of the stage, which starts at
+    /// the mismatching block.
+    pub fn with_commit_valid_prefix(mut self, commit_valid_prefix: bool) -> Self {
+        self.commit_valid_prefix = commit_valid_prefix;
+        self
+    }
+
     /// Execute the stage.
     pub fn execute_inner<DB: Database>(
         &mut self,
@@ -162,10 +180,21 @@ impl<EF: ExecutorFactory> ExecutionStage<EF> {
 
             // Execute the block
             let execute_start = Instant::now();
-            executor.execute_and_verify_receipt(&block, td).map_err(|error| StageError::Block {
-                block: Box::new(block.header.clone().seal_slow()),
-                error: BlockErrorKind::Execution(error),
-            })?;
+            if let Err(error) = executor.execute_and_verify_receipt(&block, td) {
+                // The state of the mismatching block is discarded by the executor, so the blocks
+                // executed before can still be committed.
+                if self.commit_valid_prefix &&
+                    block_number > start_block &&
+                    error.is_receipts_mismatch()
+                {
+                    warn!(target: "sync::stages::execution", number = block_number, %error, "Receipts mismatch, committing previously executed blocks");
+                    break
+                }
+                return Err(StageError::Block {
+                    block: Box::new(block.header.clone().seal_slow()),
+                    error: BlockErrorKind::Execution(error),
+                })
+            }
             execution_duration += execute_start.elapsed();
 
             // Gas metrics
@@ -193,6 +222,17 @@ impl<EF: ExecutorFactory> ExecutionStage<EF> {
         let state = executor.take_output_state();
         let write_preparation_duration = time.elapsed();
 
+        // record the accounts touched by the blocks close to the tip, as hints for the state that
+        // upcoming blocks are likely to touch
+        if max_block - start_block <= self.external_clean_threshold {
+            provider.record_hot_accounts(
+                start_block..=stage_progress,
+                state.hot_account_accesses(),
+                &HotAccountsDecay::default(),
+            )?;
+        }
+        provider.record_fork_transitions(start_block..=stage_progress)?;
+
         let time = Instant::now();
         // write output
         state.write_to_db(provider.tx_ref(), OriginalValuesKnown::Yes)?;
@@ -155,6 +155,7 @@ pub mod test_utils {
         database::Database,
         database_metrics::{DatabaseMetadata, DatabaseMetadataValue, DatabaseMetrics},
     };
+    use metrics::Label;
     use reth_libmdbx::MaxReadTransactionDuration;
     use reth_primitives::fs;
     use std::{path::PathBuf, sync::Arc};
@@ -217,6 +218,18 @@ pub mod test_utils {
         fn report_metrics(&self) {
             self.db().report_metrics()
         }
+
+        fn gauge_metrics(&self) -> Vec<(&'static str, f64, Vec<Label>)> {
+            self.db().gauge_metrics()
+        }
+
+        fn counter_metrics(&self) -> Vec<(&'static str, u64, Vec<Label>)> {
+            self.db().counter_metrics()
+        }
+
+        fn histogram_metrics(&self) -> Vec<(&'static str, f64, Vec<Label>)> {
+            self.db().histogram_metrics()
+        }
     }
 
     impl<DB: DatabaseMetadata> DatabaseMetadata for TempDatabase<DB> {
@@ -280,6 +293,7 @@ mod tests {
         version::{db_version_file_path, DatabaseVersionError},
     };
     use assert_matches::assert_matches;
+    use metrics::Label;
     use reth_libmdbx::MaxReadTransactionDuration;
     use reth_primitives::fs;
     use tempfile::tempdir;
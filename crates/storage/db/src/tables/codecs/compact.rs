@@ -47,7 +47,12 @@ impl_compression_for_compact!(
     TransactionSignedNoHash,
     CompactU256,
     StageCheckpoint,
-    PruneCheckpoint
+    PruneCheckpoint,
+    HotAccountStats,
+    LogsBloomBucket,
+    ForkTransition,
+    StoredParisBlock,
+    ChainSpec
 );
 
 macro_rules! impl_compression_fixed_compact {
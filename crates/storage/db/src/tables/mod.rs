@@ -28,6 +28,10 @@ use crate::{
         models::{
             accounts::{AccountBeforeTx, BlockNumberAddress},
             blocks::{HeaderHash, StoredBlockOmmers},
+            chain_state::{ChainStateKey, StoredParisBlock},
+            fork_transitions::ForkTransition,
+            hot_accounts::HotAccountStats,
+            logs_bloom::LogsBloomBucket,
             storage_sharded_key::StorageShardedKey,
             ShardedKey, StoredBlockBodyIndices, StoredBlockWithdrawals,
         },
@@ -36,8 +40,9 @@ use crate::{
 use reth_primitives::{
     stage::StageCheckpoint,
     trie::{StorageTrieEntry, StoredBranchNode, StoredNibbles, StoredNibblesSubKey},
-    Account, Address, BlockHash, BlockNumber, Bytecode, Header, IntegerList, PruneCheckpoint,
-    PruneSegment, Receipt, StorageEntry, TransactionSignedNoHash, TxHash, TxNumber, B256,
+    Account, Address, BlockHash, BlockNumber, Bytecode, ChainSpec, Header, IntegerList,
+    PruneCheckpoint, PruneSegment, Receipt, StorageEntry, TransactionSignedNoHash, TxHash,
+    TxNumber, B256,
 };
 use std::fmt;
 
@@ -369,6 +374,33 @@ tables! {
 
     /// Stores the highest pruned block number and prune mode of each prune segment.
     table PruneCheckpoints<Key = PruneSegment, Value = PruneCheckpoint>;
+
+    /// Stores the block hashes of the last persisted safe and finalized block.
+    table ChainState<Key = ChainStateKey, Value = BlockHash>;
+
+    /// Stores the headers of blocks that were found to be invalid, keyed by block hash. Blocks at or
+    /// below the finalized block are pruned.
+    table InvalidBlocks<Key = BlockHash, Value = Header>;
+
+    /// Stores decaying access statistics of accounts and their storage slots, gathered from
+    /// executed blocks. Used as hints for state that upcoming blocks are likely to touch.
+    table HotAccounts<Key = Address, Value = HotAccountStats>;
+
+    /// Stores the union of the logs blooms of fixed size block ranges, keyed by the first block
+    /// of the range. Used by log queries to skip ranges without matching logs.
+    table LogsBloomBuckets<Key = BlockNumber, Value = LogsBloomBucket>;
+
+    /// Stores the hardfork transitions processed by the node, in the order they were processed.
+    /// Kept across unwinds and chain spec changes as an audit log of the fork schedule.
+    table ForkTransitions<Key = u64, Value = ForkTransition>;
+
+    /// Stores the Paris block observed by the node, keyed by the genesis hash of the chain. Used
+    /// for chains whose chain spec doesn't know the block the merge happened at.
+    table ParisBlock<Key = B256, Value = StoredParisBlock>;
+
+    /// Stores the chain spec the node was last started with, keyed by the genesis hash of the
+    /// chain. Used to detect changes of the fork schedule of blocks that were already processed.
+    table ChainSpecs<Key = B256, Value = ChainSpec>;
 }
 
 // Alias types.
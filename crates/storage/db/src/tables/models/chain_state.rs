@@ -0,0 +1,65 @@
+//! Forkchoice related models and types.
+
+use crate::{
+    table::{Decode, Encode},
+    DatabaseError,
+};
+use reth_codecs::{main_codec, Compact};
+use reth_primitives::{BlockNumber, U256};
+
+/// The kind of block a persisted forkchoice state entry refers to.
+#[main_codec]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ChainStateKey {
+    /// The last safe block set by a forkchoice update.
+    Safe,
+    /// The last finalized block set by a forkchoice update.
+    Finalized,
+}
+
+/// The Paris block of a chain and the total difficulty of its terminal proof-of-work block, as
+/// observed by the node.
+#[main_codec]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct StoredParisBlock {
+    /// The number of the first proof-of-stake block.
+    pub block: BlockNumber,
+    /// The total difficulty of the terminal proof-of-work block.
+    pub final_difficulty: U256,
+}
+
+impl Encode for ChainStateKey {
+    type Encoded = [u8; 1];
+
+    fn encode(self) -> Self::Encoded {
+        let mut buf = [0u8];
+        self.to_compact(&mut buf.as_mut());
+        buf
+    }
+}
+
+impl Decode for ChainStateKey {
+    fn decode<B: AsRef<[u8]>>(value: B) -> Result<Self, DatabaseError> {
+        let buf = value.as_ref();
+        Ok(Self::from_compact(buf, buf.len()).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::{Compress, Decompress};
+
+    #[test]
+    fn chain_state_key_roundtrip() {
+        for key in [ChainStateKey::Safe, ChainStateKey::Finalized] {
+            assert_eq!(ChainStateKey::decode(key.encode()).unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn stored_paris_block_roundtrip() {
+        let paris = StoredParisBlock { block: 15537394, final_difficulty: U256::from(1u128 << 70) };
+        assert_eq!(StoredParisBlock::decompress(paris.compress()).unwrap(), paris);
+    }
+}
@@ -0,0 +1,76 @@
+//! Fork activation audit log related models and types.
+
+use reth_codecs::{main_codec, Compact};
+use reth_primitives::{BlockNumber, ForkHash, ForkId, B256};
+
+/// A hardfork transition processed by the node, i.e. a block whose fork id differs from the fork
+/// id of its parent.
+///
+/// Transitions are recorded for audits of nodes whose chain spec changed over their lifetime, so
+/// the genesis hash of the chain spec the block was processed with is recorded as well.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[main_codec]
+pub struct ForkTransition {
+    /// The number of the first block of the new fork.
+    pub block_number: BlockNumber,
+    /// The timestamp of the first block of the new fork.
+    pub timestamp: u64,
+    /// The fork hash before the transition.
+    pub fork_hash_before: u32,
+    /// The next fork announced before the transition.
+    pub fork_next_before: u64,
+    /// The fork hash after the transition.
+    pub fork_hash_after: u32,
+    /// The next fork announced after the transition.
+    pub fork_next_after: u64,
+    /// The genesis hash of the chain spec the block was processed with.
+    pub genesis_hash: B256,
+}
+
+impl ForkTransition {
+    /// Creates a new transition of the given block from the `before` to the `after` fork id.
+    pub fn new(
+        block_number: BlockNumber,
+        timestamp: u64,
+        before: ForkId,
+        after: ForkId,
+        genesis_hash: B256,
+    ) -> Self {
+        Self {
+            block_number,
+            timestamp,
+            fork_hash_before: u32::from_be_bytes(before.hash.0),
+            fork_next_before: before.next,
+            fork_hash_after: u32::from_be_bytes(after.hash.0),
+            fork_next_after: after.next,
+            genesis_hash,
+        }
+    }
+
+    /// Returns the fork id before the transition.
+    pub fn fork_id_before(&self) -> ForkId {
+        ForkId { hash: ForkHash(self.fork_hash_before.to_be_bytes()), next: self.fork_next_before }
+    }
+
+    /// Returns the fork id after the transition.
+    pub fn fork_id_after(&self) -> ForkId {
+        ForkId { hash: ForkHash(self.fork_hash_after.to_be_bytes()), next: self.fork_next_after }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::{Compress, Decompress};
+
+    #[test]
+    fn fork_transition_roundtrip() {
+        let before = ForkId { hash: ForkHash([0xfc, 0x64, 0xec, 0x04]), next: 1150000 };
+        let after = ForkId { hash: ForkHash([0x97, 0xc2, 0xc3, 0x4c]), next: 1920000 };
+        let transition = ForkTransition::new(1150000, 1457981393, before, after, B256::ZERO);
+
+        assert_eq!(transition.fork_id_before(), before);
+        assert_eq!(transition.fork_id_after(), after);
+        assert_eq!(ForkTransition::decompress(transition.compress()).unwrap(), transition);
+    }
+}
@@ -0,0 +1,152 @@
+//! Hot account statistics related models and types.
+
+use reth_codecs::{main_codec, Compact};
+use reth_primitives::{BlockNumber, B256};
+use std::collections::BTreeMap;
+
+/// How the scores of [HotAccountStats] decay over time.
+///
+/// Scores are halved every `half_life` blocks, so accounts and slots that are no longer touched
+/// drop out of the statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HotAccountsDecay {
+    /// The number of blocks after which a score is halved.
+    pub half_life: u64,
+    /// The maximum number of slots kept per account. The slots with the lowest scores are
+    /// dropped first.
+    pub max_slots: usize,
+}
+
+impl Default for HotAccountsDecay {
+    fn default() -> Self {
+        // roughly one day of mainnet blocks
+        Self { half_life: 7_200, max_slots: 64 }
+    }
+}
+
+/// The accesses of a single account in a range of executed blocks.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HotAccountAccess {
+    /// The number of blocks that touched the account.
+    pub blocks: u64,
+    /// The storage slots that were touched, with the number of blocks that touched them.
+    pub slots: BTreeMap<B256, u64>,
+}
+
+/// A storage slot of a [HotAccountStats] entry, together with its score.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[main_codec]
+pub struct HotSlot {
+    /// The storage key.
+    pub key: B256,
+    /// The decayed number of blocks that touched the slot.
+    pub score: u64,
+}
+
+/// Access statistics of an account, gathered from executed blocks.
+///
+/// These are used as hints for state that is likely touched again by upcoming blocks.
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+#[main_codec]
+pub struct HotAccountStats {
+    /// The block the scores were last decayed at.
+    pub last_block: BlockNumber,
+    /// The decayed number of blocks that touched the account.
+    pub score: u64,
+    /// The hottest storage slots of the account, sorted by descending score.
+    pub slots: Vec<HotSlot>,
+}
+
+impl HotAccountStats {
+    /// Decays all scores to the given block number.
+    ///
+    /// Only full half-lives are applied, the remainder is carried over to the next call.
+    pub fn decay(&mut self, block: BlockNumber, decay: &HotAccountsDecay) {
+        let half_lives = block.saturating_sub(self.last_block) / decay.half_life.max(1);
+        if half_lives == 0 {
+            return
+        }
+        self.last_block += half_lives * decay.half_life.max(1);
+
+        let shift = half_lives.min(u64::BITS as u64 - 1) as u32;
+        self.score >>= shift;
+        for slot in &mut self.slots {
+            slot.score >>= shift;
+        }
+        self.slots.retain(|slot| slot.score > 0);
+    }
+
+    /// Decays the scores to the given block number and adds the accesses.
+    pub fn record(
+        &mut self,
+        block: BlockNumber,
+        access: &HotAccountAccess,
+        decay: &HotAccountsDecay,
+    ) {
+        if self.score == 0 && self.slots.is_empty() {
+            self.last_block = block;
+        }
+        self.decay(block, decay);
+
+        self.score += access.blocks;
+        for (key, count) in &access.slots {
+            match self.slots.iter_mut().find(|slot| slot.key == *key) {
+                Some(slot) => slot.score += count,
+                None => self.slots.push(HotSlot { key: *key, score: *count }),
+            }
+        }
+        self.slots.sort_by(|a, b| b.score.cmp(&a.score).then(a.key.cmp(&b.key)));
+        self.slots.truncate(decay.max_slots);
+    }
+
+    /// Returns `true` if all scores decayed to zero.
+    pub fn is_cold(&self) -> bool {
+        self.score == 0 && self.slots.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::{Compress, Decompress};
+
+    #[test]
+    fn record_and_decay() {
+        let decay = HotAccountsDecay { half_life: 10, max_slots: 2 };
+        let slot = |byte| B256::with_last_byte(byte);
+        let access = HotAccountAccess {
+            blocks: 4,
+            slots: BTreeMap::from([(slot(1), 4), (slot(2), 1), (slot(3), 2)]),
+        };
+
+        let mut stats = HotAccountStats::default();
+        stats.record(100, &access, &decay);
+        assert_eq!(stats.last_block, 100);
+        assert_eq!(stats.score, 4);
+        assert_eq!(
+            stats.slots,
+            vec![HotSlot { key: slot(1), score: 4 }, HotSlot { key: slot(3), score: 2 }]
+        );
+
+        // less than a half-life, nothing decays
+        stats.decay(109, &decay);
+        assert_eq!(stats.score, 4);
+
+        // the remainder of a half-life is carried over
+        stats.decay(115, &decay);
+        assert_eq!(stats.last_block, 110);
+        assert_eq!(stats.score, 2);
+        assert_eq!(
+            stats.slots,
+            vec![HotSlot { key: slot(1), score: 2 }, HotSlot { key: slot(3), score: 1 }]
+        );
+
+        stats.decay(130, &decay);
+        assert_eq!(stats.score, 0);
+        assert!(stats.is_cold());
+
+        let mut stats = HotAccountStats::default();
+        stats.record(100, &access, &decay);
+        assert_eq!(HotAccountStats::decompress(stats.clone().compress()).unwrap(), stats);
+    }
+}
@@ -0,0 +1,37 @@
+//! Bucketed logs bloom index related models and types.
+
+use reth_codecs::{main_codec, Compact};
+use reth_primitives::{Bloom, B256};
+
+/// The number of blocks covered by a single [LogsBloomBucket].
+pub const LOGS_BLOOM_BUCKET_SIZE: u64 = 2048;
+
+/// The union of the logs blooms of [LOGS_BLOOM_BUCKET_SIZE] consecutive blocks.
+///
+/// If neither the addresses nor the topics of a log filter match the bloom of a bucket, none of
+/// the blocks of the bucket can contain a matching log and the whole bucket can be skipped.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+#[main_codec]
+pub struct LogsBloomBucket {
+    /// The hash of the last block of the bucket at the time the bucket was built.
+    ///
+    /// Used to detect buckets that no longer match the canonical chain after a reorg.
+    pub last_block_hash: B256,
+    /// The union of the logs blooms of the blocks of the bucket.
+    pub logs_bloom: Bloom,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::{Compress, Decompress};
+
+    #[test]
+    fn logs_bloom_bucket_roundtrip() {
+        let bucket = LogsBloomBucket {
+            last_block_hash: B256::with_last_byte(1),
+            logs_bloom: Bloom::with_last_byte(2),
+        };
+        assert_eq!(LogsBloomBucket::decompress(bucket.compress()).unwrap(), bucket);
+    }
+}
@@ -12,12 +12,20 @@ use reth_primitives::{
 
 pub mod accounts;
 pub mod blocks;
+pub mod chain_state;
+pub mod fork_transitions;
+pub mod hot_accounts;
 pub mod integer_list;
+pub mod logs_bloom;
 pub mod sharded_key;
 pub mod storage_sharded_key;
 
 pub use accounts::*;
 pub use blocks::*;
+pub use chain_state::{ChainStateKey, StoredParisBlock};
+pub use fork_transitions::ForkTransition;
+pub use hot_accounts::{HotAccountAccess, HotAccountStats, HotAccountsDecay, HotSlot};
+pub use logs_bloom::{LogsBloomBucket, LOGS_BLOOM_BUCKET_SIZE};
 pub use sharded_key::ShardedKey;
 
 /// Macro that implements [`Encode`] and [`Decode`] for uint types.
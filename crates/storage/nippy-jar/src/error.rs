@@ -45,4 +45,6 @@ pub enum NippyJarError {
     InvalidPruning(u64, u64),
     #[error("jar has been frozen and cannot be modified.")]
     FrozenJar,
+    #[error("checksum of data range {0}..{1} does not match the manifest.")]
+    ChecksumMismatch(u64, u64),
 }
@@ -38,11 +38,16 @@ pub use cursor::NippyJarCursor;
 mod writer;
 pub use writer::NippyJarWriter;
 
-const NIPPY_JAR_VERSION: usize = 1;
+mod manifest;
+pub use manifest::{NippyJarManifest, SegmentChecksum};
+
+/// The version of the [NippyJar] file format.
+pub const NIPPY_JAR_VERSION: usize = 1;
 
 const INDEX_FILE_EXTENSION: &str = "idx";
 const OFFSETS_FILE_EXTENSION: &str = "off";
 const CONFIG_FILE_EXTENSION: &str = "conf";
+const MANIFEST_FILE_EXTENSION: &str = "manifest";
 
 /// A [`RefRow`] is a list of column value slices pointing to either an internal buffer or a
 /// memory-mapped file.
@@ -269,6 +274,21 @@ impl<H: NippyJarHeader> NippyJar<H> {
         self.path.with_extension(CONFIG_FILE_EXTENSION)
     }
 
+    /// Returns the path for the manifest file
+    pub fn manifest_path(&self) -> PathBuf {
+        self.path.with_extension(MANIFEST_FILE_EXTENSION)
+    }
+
+    /// Verifies the data file against the checksums of the [`NippyJarManifest`].
+    ///
+    /// Jars written before checksums were introduced don't have a manifest and always pass.
+    pub fn verify_checksums(&self) -> Result<(), NippyJarError> {
+        if let Some(manifest) = NippyJarManifest::load(&self.manifest_path())? {
+            manifest.verify(&mut File::open(self.data_path())?)?;
+        }
+        Ok(())
+    }
+
     /// Returns a [`DataReader`] of the data and offset file
     pub fn open_data_reader(&self) -> Result<DataReader, NippyJarError> {
         DataReader::new(self.data_path())
@@ -523,7 +543,11 @@ impl DataReader {
 mod tests {
     use super::*;
     use rand::{rngs::SmallRng, seq::SliceRandom, RngCore, SeedableRng};
-    use std::{collections::HashSet, fs::OpenOptions};
+    use std::{
+        collections::HashSet,
+        fs::OpenOptions,
+        io::{Read, Seek, SeekFrom, Write},
+    };
 
     type ColumnResults<T> = Vec<ColumnResult<T>>;
     type ColumnValues = Vec<Vec<u8>>;
@@ -1020,6 +1044,54 @@ mod tests {
         test_append_consistency_partial_commit(file_path.path(), &col1, &col2);
     }
 
+    #[test]
+    fn test_checksums() {
+        let (col1, col2) = test_data(None);
+        let num_columns = 2;
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+
+        append_two_rows(num_columns, file_path.path(), &col1, &col2);
+
+        let mut nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        nippy.verify_checksums().unwrap();
+        let manifest = NippyJarManifest::load(&nippy.manifest_path()).unwrap().unwrap();
+        assert_eq!(manifest.committed.len(), 2);
+        assert_eq!(manifest.pending, None);
+
+        // Simulate an unexpected shutdown after offsets and configuration were committed, but
+        // before the manifest was. The pending segment should be committed.
+        let mut interrupted = manifest.clone();
+        interrupted.pending = interrupted.committed.pop();
+        interrupted.write(&nippy.manifest_path()).unwrap();
+        let _writer = NippyJarWriter::new(&mut nippy).unwrap();
+        assert_eq!(nippy.rows, 2);
+        assert_eq!(NippyJarManifest::load(&nippy.manifest_path()).unwrap().unwrap(), manifest);
+
+        // Simulate a partially written second row, whose last byte didn't make it to disk.
+        let mut data_file =
+            OpenOptions::new().read(true).write(true).open(nippy.data_path()).unwrap();
+        let data_len = data_file.metadata().unwrap().len();
+        let mut last_byte = [0u8; 1];
+        data_file.seek(SeekFrom::End(-1)).unwrap();
+        data_file.read_exact(&mut last_byte).unwrap();
+        data_file.seek(SeekFrom::End(-1)).unwrap();
+        data_file.write_all(&[!last_byte[0]]).unwrap();
+        assert!(matches!(
+            nippy.verify_checksums(),
+            Err(NippyJarError::ChecksumMismatch(start, end))
+                if start == manifest.committed[0].data_len && end == data_len
+        ));
+
+        // Writer should detect the mismatch and prune the second row.
+        let _writer = NippyJarWriter::new(&mut nippy).unwrap();
+        assert_eq!(nippy.rows, 1);
+        assert_eq!(
+            File::open(nippy.data_path()).unwrap().metadata().unwrap().len(),
+            manifest.committed[0].data_len
+        );
+        nippy.verify_checksums().unwrap();
+    }
+
     #[test]
     fn test_pruner() {
         let (col1, col2) = test_data(None);
@@ -0,0 +1,129 @@
+use crate::NippyJarError;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+/// Size of the buffer used to read the data file when computing checksums.
+const CHECKSUM_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Checksum of the data appended to a [`NippyJar`](crate::NippyJar) by a single commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentChecksum {
+    /// Number of rows of the jar after the commit.
+    pub rows: usize,
+    /// Size of the data file after the commit. The segment starts where the previous one ends.
+    pub data_len: u64,
+    /// CRC32 checksum of the segment data.
+    pub checksum: u32,
+}
+
+impl SegmentChecksum {
+    /// Verifies the segment against the data file, given the offset the segment starts at.
+    pub fn verify(&self, data_file: &mut File, start: u64) -> Result<(), NippyJarError> {
+        if data_file.metadata()?.len() < self.data_len ||
+            checksum_range(data_file, start, self.data_len)?.finalize() != self.checksum
+        {
+            return Err(NippyJarError::ChecksumMismatch(start, self.data_len))
+        }
+        Ok(())
+    }
+}
+
+/// Write-ahead manifest of a [`NippyJar`](crate::NippyJar) data file.
+///
+/// Every commit first records the segment it is about to commit as `pending`, and only moves it to
+/// `committed` once offsets and configuration are flushed. This makes it possible to tell apart
+/// interrupted commits and partially written data when the jar is opened again, see
+/// [`NippyJarWriter`](crate::NippyJarWriter).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NippyJarManifest {
+    /// Checksums of all committed segments, in the order they were written.
+    pub committed: Vec<SegmentChecksum>,
+    /// Segment of a commit that has started, but not finished.
+    pub pending: Option<SegmentChecksum>,
+}
+
+impl NippyJarManifest {
+    /// Loads the manifest from file. Returns `None` if the file doesn't exist.
+    pub fn load(path: &Path) -> Result<Option<Self>, NippyJarError> {
+        if !path.exists() {
+            return Ok(None)
+        }
+        Ok(Some(bincode::deserialize_from(File::open(path)?)?))
+    }
+
+    /// Writes the manifest to file.
+    ///
+    /// The manifest is written to a temporary file first, which then replaces the previous one, so
+    /// a crash never leaves a partially written manifest behind.
+    pub fn write(&self, path: &Path) -> Result<(), NippyJarError> {
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let file = File::create(&tmp_path)?;
+        bincode::serialize_into(&file, self)?;
+        file.sync_all()?;
+        std::fs::rename(tmp_path, path)?;
+
+        Ok(())
+    }
+
+    /// Returns the size of the data file covered by committed segments.
+    pub fn data_len(&self) -> u64 {
+        self.committed.last().map(|segment| segment.data_len).unwrap_or_default()
+    }
+
+    /// Returns the number of rows covered by committed segments.
+    pub fn rows(&self) -> usize {
+        self.committed.last().map(|segment| segment.rows).unwrap_or_default()
+    }
+
+    /// Appends a committed segment, unless it doesn't contain any data.
+    pub fn push(&mut self, segment: SegmentChecksum) {
+        if segment.data_len > self.data_len() {
+            self.committed.push(segment);
+        }
+    }
+
+    /// Removes all committed segments that end after `data_len`.
+    pub fn truncate(&mut self, data_len: u64) {
+        self.committed.retain(|segment| segment.data_len <= data_len);
+    }
+
+    /// Verifies all committed segments against the data file.
+    pub fn verify(&self, data_file: &mut File) -> Result<(), NippyJarError> {
+        let mut start = 0;
+        for segment in &self.committed {
+            segment.verify(data_file, start)?;
+            start = segment.data_len;
+        }
+        Ok(())
+    }
+}
+
+/// Returns a CRC32 hasher fed with the data file range `start..end`.
+///
+/// The file cursor is left at `end`.
+pub(crate) fn checksum_range(
+    data_file: &mut File,
+    start: u64,
+    end: u64,
+) -> Result<crc32fast::Hasher, NippyJarError> {
+    let mut hasher = crc32fast::Hasher::new();
+    data_file.seek(SeekFrom::Start(start))?;
+
+    let mut remaining = end.saturating_sub(start);
+    let mut buf = vec![0; CHECKSUM_BUFFER_SIZE.min(remaining as usize)];
+    while remaining > 0 {
+        let len = buf.len().min(remaining as usize);
+        data_file.read_exact(&mut buf[..len])?;
+        hasher.update(&buf[..len]);
+        remaining -= len as u64;
+    }
+
+    Ok(hasher)
+}
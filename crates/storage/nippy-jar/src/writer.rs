@@ -1,4 +1,9 @@
-use crate::{compression::Compression, ColumnResult, NippyJar, NippyJarError, NippyJarHeader};
+use crate::{
+    compression::Compression,
+    manifest::{checksum_range, NippyJarManifest, SegmentChecksum},
+    ColumnResult, NippyJar, NippyJarError, NippyJarHeader,
+};
+use crc32fast::Hasher;
 use std::{
     cmp::Ordering,
     fmt,
@@ -6,6 +11,7 @@ use std::{
     io::{Read, Seek, SeekFrom, Write},
     path::Path,
 };
+use tracing::warn;
 
 /// Size of one offset in bytes.
 const OFFSET_SIZE_BYTES: u64 = 8;
@@ -23,6 +29,12 @@ const OFFSET_SIZE_BYTES: u64 = 8;
 ///
 /// ## Data file layout
 /// The data file is represented just as a sequence of bytes of data without any delimiters
+///
+/// ## Manifest file layout
+/// The [`NippyJarManifest`] holds a checksum for every segment of the data file written by a
+/// `commit()`. It is updated before and after offsets and configuration are flushed, so segments
+/// that were only partially written before a crash are detected and truncated when the jar is
+/// opened again.
 pub struct NippyJarWriter<'a, H> {
     /// Reference to the associated [`NippyJar`], containing all necessary configurations for data
     /// handling.
@@ -39,6 +51,10 @@ pub struct NippyJarWriter<'a, H> {
     offsets: Vec<u64>,
     /// Column where writer is going to write next.
     column: usize,
+    /// Checksums of the committed segments of the data file.
+    manifest: NippyJarManifest,
+    /// Checksum of the data that was written since the last commit.
+    checksum: Hasher,
 }
 
 impl<H> fmt::Debug for NippyJarWriter<'_, H> {
@@ -51,6 +67,9 @@ impl<'a, H: NippyJarHeader> NippyJarWriter<'a, H> {
     pub fn new(jar: &'a mut NippyJar<H>) -> Result<Self, NippyJarError> {
         let (data_file, offsets_file, is_created) =
             Self::create_or_open_files(jar.data_path(), &jar.offsets_path())?;
+        let manifest =
+            if is_created { None } else { NippyJarManifest::load(&jar.manifest_path())? };
+        let has_manifest = manifest.is_some();
 
         let mut writer = Self {
             jar,
@@ -60,6 +79,8 @@ impl<'a, H: NippyJarHeader> NippyJarWriter<'a, H> {
             uncompressed_row_size: 0,
             offsets: Vec::with_capacity(1_000_000),
             column: 0,
+            manifest: manifest.unwrap_or_default(),
+            checksum: Hasher::new(),
         };
 
         // If we are opening a previously created jar, we need to check its consistency, and make
@@ -67,6 +88,7 @@ impl<'a, H: NippyJarHeader> NippyJarWriter<'a, H> {
         if !is_created {
             writer.check_consistency_and_heal()?;
         }
+        writer.check_checksums_and_heal(has_manifest)?;
 
         Ok(writer)
     }
@@ -78,7 +100,8 @@ impl<'a, H: NippyJarHeader> NippyJarWriter<'a, H> {
         let is_created = !data.exists() || !offsets.exists();
 
         let mut data_file = if !data.exists() {
-            File::create(data)?
+            // Needs to be readable to compute checksums.
+            OpenOptions::new().read(true).write(true).create(true).truncate(true).open(data)?
         } else {
             OpenOptions::new().read(true).write(true).open(data)?
         };
@@ -185,6 +208,68 @@ impl<'a, H: NippyJarHeader> NippyJarWriter<'a, H> {
         Ok(())
     }
 
+    /// Verifies the data file against the [`NippyJarManifest`] and acts upon any issues:
+    /// * Was a commit interrupted after offsets and configuration were flushed? Then its pending
+    ///   segment is committed.
+    /// * Does the last committed segment match its checksum? Otherwise it was only partially
+    ///   written, and its rows are pruned.
+    ///
+    /// Only the last segment is verified, since every commit syncs the data file before its segment
+    /// is committed. Jars without a manifest get one covering their whole data file.
+    ///
+    /// Needs to be called after [`Self::check_consistency_and_heal`], so the data file matches
+    /// the offsets and configuration.
+    fn check_checksums_and_heal(&mut self, has_manifest: bool) -> Result<(), NippyJarError> {
+        let data_len = self.data_file.metadata()?.len();
+
+        if !has_manifest {
+            let checksum = checksum_range(&mut self.data_file, 0, data_len)?;
+            self.manifest.push(SegmentChecksum {
+                rows: self.jar.rows,
+                data_len,
+                checksum: checksum.finalize(),
+            });
+        } else {
+            // Segments might have been pruned by the consistency check
+            self.manifest.truncate(data_len);
+
+            if let Some(pending) = self.manifest.pending.take() {
+                // Otherwise, the consistency check has already pruned the data of the interrupted
+                // commit.
+                if pending.data_len == data_len && pending.rows == self.jar.rows {
+                    self.manifest.push(pending);
+                }
+            }
+
+            if let Some(last) = self.manifest.committed.last().copied() {
+                let start = self
+                    .manifest
+                    .committed
+                    .iter()
+                    .rev()
+                    .nth(1)
+                    .map(|segment| segment.data_len)
+                    .unwrap_or_default();
+
+                if let Err(err) = last.verify(&mut self.data_file, start) {
+                    warn!(target: "nippy-jar", %err, "Pruning partially written segment.");
+                    self.manifest.committed.pop();
+                    self.prune_rows(self.jar.rows.saturating_sub(self.manifest.rows()))?;
+                }
+            }
+        }
+
+        // Data which is not covered by any segment, eg. of a partially pruned row, is part of the
+        // next commit.
+        let data_len = self.data_file.metadata()?.len();
+        self.checksum = checksum_range(&mut self.data_file, self.manifest.data_len(), data_len)?;
+        self.manifest.write(&self.jar.manifest_path())?;
+
+        self.data_file.seek(SeekFrom::End(0))?;
+
+        Ok(())
+    }
+
     /// Appends rows to data file.  `fn commit()` should be called to flush offsets and config to
     /// disk.
     ///
@@ -254,8 +339,10 @@ impl<'a, H: NippyJarHeader> NippyJarWriter<'a, H> {
             let before = self.tmp_buf.len();
             let len = compression.compress_to(value, &mut self.tmp_buf)?;
             self.data_file.write_all(&self.tmp_buf[before..before + len])?;
+            self.checksum.update(&self.tmp_buf[before..before + len]);
         } else {
             self.data_file.write_all(value)?;
+            self.checksum.update(value);
         }
 
         self.column += 1;
@@ -342,6 +429,24 @@ impl<'a, H: NippyJarHeader> NippyJarWriter<'a, H> {
         }
         self.jar.freeze_config()?;
 
+        // Pruned segments are removed from the manifest. If rows were pruned from disk, the
+        // remaining data of a partially pruned segment becomes a segment of its own.
+        let data_len = self.data_file.metadata()?.len();
+        self.manifest.truncate(data_len);
+        let checksum = checksum_range(&mut self.data_file, self.manifest.data_len(), data_len)?;
+        if remaining_to_prune > 0 {
+            self.manifest.push(SegmentChecksum {
+                rows: self.jar.rows,
+                data_len,
+                checksum: checksum.finalize(),
+            });
+            self.checksum = Hasher::new();
+        } else {
+            self.checksum = checksum;
+        }
+        self.manifest.write(&self.jar.manifest_path())?;
+        self.data_file.seek(SeekFrom::End(0))?;
+
         Ok(())
     }
 
@@ -360,11 +465,26 @@ impl<'a, H: NippyJarHeader> NippyJarWriter<'a, H> {
     pub fn commit(&mut self) -> Result<(), NippyJarError> {
         self.data_file.sync_all()?;
 
+        // Writes the segment ahead, so an interrupted commit can be told apart from partially
+        // written data.
+        let segment = SegmentChecksum {
+            rows: self.jar.rows,
+            data_len: self.data_file.metadata()?.len(),
+            checksum: self.checksum.clone().finalize(),
+        };
+        self.manifest.pending = Some(segment);
+        self.manifest.write(&self.jar.manifest_path())?;
+
         self.commit_offsets()?;
 
         // Flushes `max_row_size` and total `rows` to disk.
         self.jar.freeze_config()?;
 
+        self.manifest.pending = None;
+        self.manifest.push(segment);
+        self.manifest.write(&self.jar.manifest_path())?;
+        self.checksum = Hasher::new();
+
         Ok(())
     }
 
@@ -1,6 +1,7 @@
 use crate::{StateChanges, StateReverts};
 use reth_db::{
     cursor::{DbCursorRO, DbCursorRW},
+    models::HotAccountAccess,
     tables,
     transaction::{DbTx, DbTxMut},
 };
@@ -16,7 +17,7 @@ use revm::{
     db::{states::BundleState, BundleAccount},
     primitives::AccountInfo,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 pub use revm::db::states::OriginalValuesKnown;
 
@@ -126,6 +127,16 @@ impl BundleStateWithReceipts {
         self.bundle.account(address).and_then(|a| a.storage_slot(storage_key))
     }
 
+    /// Get the storage value before the changes of the bundle, if known.
+    ///
+    /// This is only known for storage slots that were changed by the bundle.
+    pub fn original_storage(&self, address: &Address, storage_key: U256) -> Option<U256> {
+        self.bundle
+            .account(address)
+            .and_then(|a| a.storage.get(&storage_key))
+            .map(|slot| slot.original_value())
+    }
+
     /// Return bytecode if known.
     pub fn bytecode(&self, code_hash: &B256) -> Option<Bytecode> {
         self.bundle.bytecode(code_hash).map(Bytecode)
@@ -212,6 +223,33 @@ impl BundleStateWithReceipts {
         self.first_block
     }
 
+    /// Returns the accounts and storage slots changed by the blocks of the bundle, together with
+    /// the number of blocks that changed them.
+    ///
+    /// This is derived from the per-block reverts. If the bundle has no reverts, all changes are
+    /// counted as a single block.
+    pub fn hot_account_accesses(&self) -> BTreeMap<Address, HotAccountAccess> {
+        let mut accesses = BTreeMap::<Address, HotAccountAccess>::new();
+        let mut record = |address: Address, slots: &mut dyn Iterator<Item = &U256>| {
+            let access = accesses.entry(address).or_default();
+            access.blocks += 1;
+            for slot in slots {
+                *access.slots.entry(B256::new(slot.to_be_bytes())).or_default() += 1;
+            }
+        };
+
+        if self.bundle.reverts.is_empty() {
+            for (address, account) in self.bundle.state() {
+                record(*address, &mut account.storage.keys());
+            }
+        } else {
+            for (address, revert) in self.bundle.reverts.iter().flatten() {
+                record(*address, &mut revert.storage.keys());
+            }
+        }
+        accesses
+    }
+
     /// Revert to given block number.
     ///
     /// If number is in future, or in the past return false
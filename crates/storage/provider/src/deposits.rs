@@ -0,0 +1,200 @@
+//! Contains [DepositEvents], an iterator over the deposits of a deposit contract.
+
+use crate::ReceiptProvider;
+use reth_interfaces::provider::{ProviderError, ProviderResult};
+use reth_primitives::{BlockNumber, DepositContract, DepositEvent};
+use std::collections::VecDeque;
+
+/// An iterator over the [DepositEvent]s of a [DepositContract], read from the receipts of a
+/// [ReceiptProvider].
+///
+/// The iterator starts at the deployment block of the contract and yields the events with the
+/// number of their block, in the order they were emitted. It ends after the last block whose
+/// receipts are available, or after the block set with [DepositEvents::until].
+///
+/// Chains that migrated to a new deposit contract list all of them in
+/// [ChainSpec::deposit_contracts](reth_primitives::ChainSpec::deposit_contracts), each of them is
+/// scanned with its own iterator.
+#[derive(Debug)]
+pub struct DepositEvents<P> {
+    /// The provider of the receipts.
+    provider: P,
+    /// The contract whose events are yielded.
+    contract: DepositContract,
+    /// The next block to read the receipts of.
+    next_block: BlockNumber,
+    /// The last block to read the receipts of.
+    last_block: BlockNumber,
+    /// The events of the last read block that were not yielded yet.
+    pending: VecDeque<DepositEvent>,
+    /// The block of the pending events.
+    pending_block: BlockNumber,
+}
+
+impl<P: ReceiptProvider> DepositEvents<P> {
+    /// Creates an iterator over the events of the contract, starting at its deployment block.
+    pub fn new(provider: P, contract: DepositContract) -> Self {
+        Self {
+            provider,
+            next_block: contract.block,
+            contract,
+            last_block: BlockNumber::MAX,
+            pending: VecDeque::new(),
+            pending_block: 0,
+        }
+    }
+
+    /// Starts the iteration at the given block instead, e.g. to resume a previous scan.
+    ///
+    /// Blocks before the deployment of the contract are skipped either way.
+    pub fn from_block(mut self, block: BlockNumber) -> Self {
+        self.next_block = block.max(self.contract.block);
+        self
+    }
+
+    /// Ends the iteration after the given block.
+    pub fn until(mut self, block: BlockNumber) -> Self {
+        self.last_block = block;
+        self
+    }
+
+    /// Returns the next block whose receipts are read, which is where a later scan resumes once
+    /// the iterator is exhausted.
+    pub fn next_block(&self) -> BlockNumber {
+        self.next_block
+    }
+
+    /// Reads the events of the next block, returning `false` if its receipts are not available.
+    fn read_next_block(&mut self) -> ProviderResult<bool> {
+        let block = self.next_block;
+        let Some(receipts) = self.provider.receipts_by_block(block.into())? else {
+            return Ok(false)
+        };
+        for log in receipts.iter().flat_map(|receipt| &receipt.logs) {
+            if self.contract.matches(log) {
+                let event = DepositEvent::decode(log)
+                    .map_err(|err| ProviderError::InvalidDepositEvent { block, err })?;
+                self.pending.push_back(event);
+            }
+        }
+        self.pending_block = block;
+        self.next_block += 1;
+        Ok(true)
+    }
+}
+
+impl<P: ReceiptProvider> Iterator for DepositEvents<P> {
+    type Item = ProviderResult<(BlockNumber, DepositEvent)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(Ok((self.pending_block, event)))
+            }
+            if self.next_block > self.last_block {
+                return None
+            }
+            match self.read_next_block() {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{
+        Address, BlockHashOrNumber, Bytes, Log, Receipt, TxHash, TxNumber, MAINNET,
+    };
+    use std::{collections::BTreeMap, ops::RangeBounds};
+
+    /// A provider of the receipts of a few blocks.
+    struct BlockReceipts(BTreeMap<BlockNumber, Vec<Receipt>>);
+
+    impl ReceiptProvider for BlockReceipts {
+        fn receipt(&self, _id: TxNumber) -> ProviderResult<Option<Receipt>> {
+            Ok(None)
+        }
+
+        fn receipt_by_hash(&self, _hash: TxHash) -> ProviderResult<Option<Receipt>> {
+            Ok(None)
+        }
+
+        fn receipts_by_block(
+            &self,
+            block: BlockHashOrNumber,
+        ) -> ProviderResult<Option<Vec<Receipt>>> {
+            let BlockHashOrNumber::Number(number) = block else { return Ok(None) };
+            Ok(self.0.get(&number).cloned())
+        }
+
+        fn receipts_by_tx_range(
+            &self,
+            _range: impl RangeBounds<TxNumber>,
+        ) -> ProviderResult<Vec<Receipt>> {
+            Ok(vec![])
+        }
+    }
+
+    /// Returns an ABI encoded deposit log of the contract with the given index.
+    fn deposit_log(contract: &DepositContract, index: u64) -> Log {
+        let fields: [Vec<u8>; 5] = [
+            vec![1; 48],
+            vec![2; 32],
+            32_000_000_000u64.to_le_bytes().to_vec(),
+            vec![3; 96],
+            index.to_le_bytes().to_vec(),
+        ];
+        let mut head = Vec::new();
+        let mut tail = Vec::new();
+        for field in fields {
+            head.extend_from_slice(&[0; 24]);
+            head.extend_from_slice(&(160 + tail.len() as u64).to_be_bytes());
+            tail.extend_from_slice(&[0; 24]);
+            tail.extend_from_slice(&(field.len() as u64).to_be_bytes());
+            tail.extend_from_slice(&field);
+            tail.resize(tail.len().next_multiple_of(32), 0);
+        }
+        head.extend(tail);
+        Log { address: contract.address, topics: vec![contract.topic], data: head.into() }
+    }
+
+    #[test]
+    fn deposit_events() {
+        let contract = MAINNET.deposit_contracts()[0].clone();
+        let start = contract.block;
+        let other =
+            Log { address: Address::ZERO, topics: vec![contract.topic], data: Bytes::new() };
+        let receipts = |logs: Vec<Log>| vec![Receipt { logs, ..Default::default() }];
+        let provider = BlockReceipts(BTreeMap::from([
+            (start - 1, receipts(vec![deposit_log(&contract, 99)])),
+            (start, receipts(vec![deposit_log(&contract, 0), other.clone()])),
+            (start + 1, receipts(vec![other])),
+            (start + 2, receipts(vec![deposit_log(&contract, 1), deposit_log(&contract, 2)])),
+        ]));
+
+        let events = DepositEvents::new(&provider, contract.clone())
+            .map(|event| event.map(|(block, event)| (block, event.index)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(events, vec![(start, 0), (start + 2, 1), (start + 2, 2)]);
+
+        let mut events = DepositEvents::new(&provider, contract.clone()).from_block(start + 1);
+        assert_eq!(events.next().unwrap().unwrap().1.index, 1);
+        assert_eq!(events.next_block(), start + 3);
+
+        let events = DepositEvents::new(&provider, contract.clone()).until(start + 1);
+        assert_eq!(events.count(), 1);
+
+        let invalid = Log { data: Bytes::new(), ..deposit_log(&contract, 3) };
+        let provider = BlockReceipts(BTreeMap::from([(start, receipts(vec![invalid]))]));
+        let mut events = DepositEvents::new(&provider, contract);
+        assert!(matches!(
+            events.next(),
+            Some(Err(ProviderError::InvalidDepositEvent { block, .. })) if block == start
+        ));
+    }
+}
@@ -4,21 +4,31 @@ use crate::{
         SnapshotProvider,
     },
     traits::{BlockSource, ReceiptProvider},
-    BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider, EvmEnvProvider,
-    HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider, HeaderSyncMode, ProviderError,
-    PruneCheckpointReader, StageCheckpointReader, StateProviderBox, TransactionVariant,
-    TransactionsProvider, WithdrawalsProvider,
+    BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider, ChainStateReader,
+    ChainStateWriter, EvmEnvProvider, ForkTransitionsReader, HeaderProvider, HeaderSyncGap,
+    HeaderSyncGapProvider, HeaderSyncMode, HotAccountsReader, ProviderError, PruneCheckpointReader,
+    StageCheckpointReader, StateProviderBox, TransactionVariant, TransactionsProvider,
+    WithdrawalsProvider,
+};
+use reth_db::{
+    database::Database,
+    init_db,
+    models::{
+        ChainStateKey, ForkTransition, HotAccountStats, StoredBlockBodyIndices, StoredParisBlock,
+    },
+    DatabaseEnv,
+};
+use reth_interfaces::{
+    blockchain_tree::ChainStateUpdate, provider::ProviderResult, RethError, RethResult,
 };
-use reth_db::{database::Database, init_db, models::StoredBlockBodyIndices, DatabaseEnv};
-use reth_interfaces::{provider::ProviderResult, RethError, RethResult};
 use reth_node_api::ConfigureEvmEnv;
 use reth_primitives::{
     snapshot::HighestSnapshots,
     stage::{StageCheckpoint, StageId},
-    Address, Block, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders, ChainInfo,
-    ChainSpec, Header, PruneCheckpoint, PruneSegment, Receipt, SealedBlock, SealedBlockWithSenders,
-    SealedHeader, TransactionMeta, TransactionSigned, TransactionSignedNoHash, TxHash, TxNumber,
-    Withdrawal, Withdrawals, B256, U256,
+    Address, Block, BlockHash, BlockHashOrNumber, BlockNumHash, BlockNumber, BlockWithSenders,
+    Bloom, ChainInfo, ChainSpec, Header, PruneCheckpoint, PruneSegment, Receipt, SealedBlock,
+    SealedBlockWithSenders, SealedHeader, SnapshotSegment, TransactionMeta, TransactionSigned,
+    TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, Withdrawals, B256, U256,
 };
 use revm::primitives::{BlockEnv, CfgEnvWithHandlerCfg};
 use std::{
@@ -112,6 +122,25 @@ impl<DB: Database> ProviderFactory<DB> {
         Ok(provider)
     }
 
+    /// Returns a read-only provider whose view of the database ends at the given canonical tip.
+    ///
+    /// Reads of the returned provider never observe a partially committed pipeline run: database
+    /// and snapshots are read at the same point in time, and the provider fails with
+    /// [`ProviderError::InconsistentView`] if the best block of that view is not `tip`, e.g.
+    /// because blocks were committed or unwound since `tip` was obtained. Long running requests
+    /// that read many blocks should use this instead of [`Self::provider`].
+    #[track_caller]
+    pub fn consistent_provider(&self, tip: BlockNumHash) -> ProviderResult<DatabaseProviderRO<DB>> {
+        let provider = self.provider()?;
+        let best_number = provider.best_block_number()?;
+        let best =
+            provider.block_hash(best_number)?.map(|hash| BlockNumHash::new(best_number, hash));
+        if best != Some(tip) {
+            return Err(ProviderError::InconsistentView { expected: tip, got: best })
+        }
+        Ok(provider)
+    }
+
     /// Returns a provider with a created `DbTxMut` inside, which allows fetching and updating
     /// data from the database using different types of providers. Example: [`HeaderProvider`]
     /// [`BlockHashReader`].  This may fail if the inner read/write database transaction fails to
@@ -327,6 +356,27 @@ impl<DB: Database> BlockReader for ProviderFactory<DB> {
     fn block_range(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<Vec<Block>> {
         self.provider()?.block_range(range)
     }
+
+    fn receipts_by_block_range_filtered(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+        predicate: impl FnMut(&SealedHeader) -> bool,
+    ) -> ProviderResult<Vec<(BlockNumHash, Vec<Receipt>)>> {
+        self.provider()?.receipts_by_block_range_filtered(range, predicate)
+    }
+
+    fn highest_snapshot_receipts_block(&self) -> Option<BlockNumber> {
+        self.snapshot_provider
+            .as_ref()
+            .and_then(|provider| provider.get_highest_snapshot_block(SnapshotSegment::Receipts))
+    }
+
+    fn logs_bloom_buckets(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(RangeInclusive<BlockNumber>, Bloom)>> {
+        self.provider()?.logs_bloom_buckets(range)
+    }
 }
 
 impl<DB: Database> TransactionsProvider for ProviderFactory<DB> {
@@ -524,27 +574,83 @@ impl<DB: Database> PruneCheckpointReader for ProviderFactory<DB> {
     }
 }
 
+impl<DB: Database> ChainStateReader for ProviderFactory<DB> {
+    fn get_chain_state(&self, key: ChainStateKey) -> ProviderResult<Option<BlockHash>> {
+        self.provider()?.get_chain_state(key)
+    }
+
+    fn invalid_block_headers(&self) -> ProviderResult<Vec<SealedHeader>> {
+        self.provider()?.invalid_block_headers()
+    }
+
+    fn paris_block(&self, genesis_hash: B256) -> ProviderResult<Option<StoredParisBlock>> {
+        self.provider()?.paris_block(genesis_hash)
+    }
+}
+
+impl<DB: Database> ChainStateWriter for ProviderFactory<DB> {
+    fn save_chain_state(&self, update: &ChainStateUpdate) -> ProviderResult<()> {
+        let provider = self.provider_rw()?;
+        provider.save_chain_state(update)?;
+        provider.commit()?;
+        Ok(())
+    }
+
+    fn save_paris_block(&self, genesis_hash: B256, paris: StoredParisBlock) -> ProviderResult<()> {
+        let provider = self.provider_rw()?;
+        provider.save_paris_block(genesis_hash, paris)?;
+        provider.commit()?;
+        Ok(())
+    }
+}
+
+impl<DB: Database> HotAccountsReader for ProviderFactory<DB> {
+    fn hot_account(&self, address: Address) -> ProviderResult<Option<HotAccountStats>> {
+        self.provider()?.hot_account(address)
+    }
+
+    fn hottest_accounts(&self, limit: usize) -> ProviderResult<Vec<(Address, HotAccountStats)>> {
+        self.provider()?.hottest_accounts(limit)
+    }
+}
+
+impl<DB: Database> ForkTransitionsReader for ProviderFactory<DB> {
+    fn fork_transitions(&self) -> ProviderResult<Vec<(u64, ForkTransition)>> {
+        self.provider()?.fork_transitions()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ProviderFactory;
     use crate::{
-        test_utils::create_test_provider_factory, BlockHashReader, BlockNumReader, BlockWriter,
-        HeaderSyncGapProvider, HeaderSyncMode, TransactionsProvider,
+        test_utils::create_test_provider_factory, BlockHashReader, BlockNumReader, BlockReader,
+        BlockWriter, ChainStateReader, ChainStateWriter, ForkTransitionsReader,
+        ForkTransitionsWriter, HeaderSyncGapProvider, HeaderSyncMode, LogsBloomIndexWriter,
+        TransactionsProvider,
     };
     use alloy_rlp::Decodable;
     use assert_matches::assert_matches;
     use rand::Rng;
-    use reth_db::{tables, test_utils::ERROR_TEMPDIR, transaction::DbTxMut, DatabaseEnv};
+    use reth_db::{
+        models::{ChainStateKey, ForkTransition, StoredParisBlock, LOGS_BLOOM_BUCKET_SIZE},
+        tables,
+        test_utils::ERROR_TEMPDIR,
+        transaction::DbTxMut,
+        DatabaseEnv,
+    };
     use reth_interfaces::{
+        blockchain_tree::ChainStateUpdate,
         provider::ProviderError,
         test_utils::{
             generators,
-            generators::{random_block, random_header},
+            generators::{random_block, random_header, random_receipt},
         },
         RethError,
     };
     use reth_primitives::{
-        hex_literal::hex, ChainSpecBuilder, PruneMode, PruneModes, SealedBlock, TxNumber, B256,
+        hex_literal::hex, BlockNumHash, Bloom, ChainSpecBuilder, Head, PruneMode, PruneModes,
+        SealedBlock, TxNumber, B256, MAINNET, U256,
     };
     use std::{ops::RangeInclusive, sync::Arc};
     use tokio::sync::watch;
@@ -575,6 +681,32 @@ mod tests {
         provider.block_hash(0).unwrap();
     }
 
+    #[test]
+    fn consistent_provider() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+        let genesis = random_header(&mut rng, 0, None);
+        let tip = BlockNumHash::new(0, genesis.hash());
+
+        assert_eq!(
+            factory.consistent_provider(tip).unwrap_err(),
+            ProviderError::InconsistentView { expected: tip, got: None }
+        );
+
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.tx_ref().put::<tables::CanonicalHeaders>(0, genesis.hash()).unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = factory.consistent_provider(tip).unwrap();
+        assert_eq!(provider.block_hash(0), Ok(Some(genesis.hash())));
+
+        let other = BlockNumHash::new(0, B256::random());
+        assert_eq!(
+            factory.consistent_provider(other).unwrap_err(),
+            ProviderError::InconsistentView { expected: other, got: Some(tip) }
+        );
+    }
+
     #[test]
     fn provider_factory_with_database_path() {
         let chain_spec = ChainSpecBuilder::mainnet().build();
@@ -592,6 +724,51 @@ mod tests {
         provider.block_hash(0).unwrap();
     }
 
+    #[test]
+    fn chain_state_roundtrip() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+        let finalized = random_header(&mut rng, 2, None);
+        let stale = random_header(&mut rng, 2, None);
+        let invalid = random_header(&mut rng, 3, Some(finalized.hash()));
+
+        assert_eq!(factory.get_chain_state(ChainStateKey::Safe), Ok(None));
+        assert_eq!(factory.invalid_block_headers(), Ok(vec![]));
+
+        let update = ChainStateUpdate {
+            safe: Some(finalized.hash()),
+            invalid_headers: vec![stale.clone(), invalid.clone()],
+            ..Default::default()
+        };
+        factory.save_chain_state(&update).unwrap();
+
+        assert_eq!(factory.get_chain_state(ChainStateKey::Safe), Ok(Some(finalized.hash())));
+        assert_eq!(factory.get_chain_state(ChainStateKey::Finalized), Ok(None));
+        assert_eq!(factory.invalid_block_headers().unwrap().len(), 2);
+
+        // finalizing a block prunes the invalid blocks at or below it
+        let provider = factory.provider_rw().unwrap();
+        provider.tx_ref().put::<tables::HeaderNumbers>(finalized.hash(), finalized.number).unwrap();
+        let update = ChainStateUpdate { finalized: Some(finalized.hash()), ..Default::default() };
+        provider.save_chain_state(&update).unwrap();
+        provider.commit().unwrap();
+
+        assert_eq!(factory.get_chain_state(ChainStateKey::Finalized), Ok(Some(finalized.hash())));
+        assert_eq!(factory.invalid_block_headers(), Ok(vec![invalid]));
+    }
+
+    #[test]
+    fn paris_block_roundtrip() {
+        let factory = create_test_provider_factory();
+        let genesis_hash = MAINNET.genesis_hash();
+        assert_eq!(factory.paris_block(genesis_hash), Ok(None));
+
+        let paris = StoredParisBlock { block: 10, final_difficulty: U256::from(100) };
+        factory.save_paris_block(genesis_hash, paris).unwrap();
+        assert_eq!(factory.paris_block(genesis_hash), Ok(Some(paris)));
+        assert_eq!(factory.paris_block(B256::ZERO), Ok(None));
+    }
+
     #[test]
     fn insert_block_with_prune_modes() {
         let factory = create_test_provider_factory();
@@ -672,6 +849,110 @@ mod tests {
         }
     }
 
+    #[test]
+    fn receipts_by_block_range_filtered() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+
+        let provider = factory.provider_rw().unwrap();
+        let mut receipts = Vec::new();
+        let mut parent = None;
+        for number in 0..3 {
+            let block = random_block(&mut rng, number, parent, Some(2), None);
+            parent = Some(block.hash());
+            provider.insert_block(block.clone().try_seal_with_senders().unwrap(), None).unwrap();
+            for tx in &block.body {
+                let receipt = random_receipt(&mut rng, tx, Some(1));
+                provider
+                    .tx_ref()
+                    .put::<tables::Receipts>(receipts.len() as TxNumber, receipt.clone())
+                    .unwrap();
+                receipts.push(receipt);
+            }
+        }
+        provider.commit().unwrap();
+
+        let blocks =
+            factory.receipts_by_block_range_filtered(0..=2, |header| header.number != 1).unwrap();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].0.number, 0);
+        assert_eq!(blocks[0].1, receipts[0..2]);
+        assert_eq!(blocks[1].0.number, 2);
+        assert_eq!(blocks[1].1, receipts[4..6]);
+    }
+
+    #[test]
+    fn logs_bloom_buckets() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+        let bucket =
+            |index: u64| index * LOGS_BLOOM_BUCKET_SIZE..=(index + 1) * LOGS_BLOOM_BUCKET_SIZE - 1;
+
+        let provider = factory.provider_rw().unwrap();
+        let tip = LOGS_BLOOM_BUCKET_SIZE * 2 + 10;
+        for number in 0..=tip {
+            let mut header = random_header(&mut rng, number, None).unseal();
+            if number == 1 || number == LOGS_BLOOM_BUCKET_SIZE + 2 {
+                header.logs_bloom = Bloom::with_last_byte(number as u8);
+            }
+            let header = header.seal_slow();
+            provider.tx_ref().put::<tables::CanonicalHeaders>(number, header.hash()).unwrap();
+            provider.tx_ref().put::<tables::Headers>(number, header.unseal()).unwrap();
+        }
+
+        // only complete buckets are indexed
+        assert_eq!(provider.index_logs_blooms(tip, 1), Ok(1));
+        assert_eq!(provider.index_logs_blooms(tip, usize::MAX), Ok(1));
+        assert_eq!(provider.index_logs_blooms(tip, usize::MAX), Ok(0));
+        provider.commit().unwrap();
+
+        let expected = vec![
+            (bucket(0), Bloom::with_last_byte(1)),
+            (bucket(1), Bloom::with_last_byte(LOGS_BLOOM_BUCKET_SIZE as u8 + 2)),
+        ];
+        assert_eq!(factory.logs_bloom_buckets(10..=tip), Ok(expected.clone()));
+        assert_eq!(factory.logs_bloom_buckets(0..=10), Ok(expected[..1].to_vec()));
+
+        // buckets whose last block was reorged out are ignored and rebuilt
+        let provider = factory.provider_rw().unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::CanonicalHeaders>(*bucket(1).end(), B256::random())
+            .unwrap();
+        assert_eq!(provider.logs_bloom_buckets(0..=tip), Ok(expected[..1].to_vec()));
+        assert_eq!(provider.index_logs_blooms(tip, usize::MAX), Ok(1));
+        assert_eq!(provider.logs_bloom_buckets(0..=tip), Ok(expected));
+    }
+
+    #[test]
+    fn fork_transitions() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+        let homestead = 1_150_000;
+
+        let provider = factory.provider_rw().unwrap();
+        for number in homestead - 2..=homestead + 1 {
+            let mut header = random_header(&mut rng, number, None).unseal();
+            header.timestamp = 0;
+            provider.tx_ref().put::<tables::Headers>(number, header).unwrap();
+        }
+
+        // recording the same blocks again doesn't duplicate the transition
+        provider.record_fork_transitions(homestead - 1..=homestead + 1).unwrap();
+        provider.record_fork_transitions(homestead..=homestead + 1).unwrap();
+        provider.commit().unwrap();
+
+        let fork_id = |number| MAINNET.fork_id(&Head { number, ..Default::default() });
+        let expected = ForkTransition::new(
+            homestead,
+            0,
+            fork_id(homestead - 1),
+            fork_id(homestead),
+            MAINNET.genesis_hash(),
+        );
+        assert_eq!(factory.fork_transitions(), Ok(vec![(0, expected)]));
+    }
+
     #[test]
     fn header_sync_gap_lookup() {
         let factory = create_test_provider_factory();
@@ -1,13 +1,15 @@
 use crate::{
     bundle_state::{BundleStateInit, BundleStateWithReceipts, HashedStateChanges, RevertsInit},
-    providers::{database::metrics, SnapshotProvider},
+    providers::{database::metrics, SnapshotBounds, SnapshotProvider},
     to_range,
     traits::{
         AccountExtReader, BlockSource, ChangeSetReader, ReceiptProvider, StageCheckpointWriter,
     },
     AccountReader, BlockExecutionWriter, BlockHashReader, BlockNumReader, BlockReader, BlockWriter,
-    Chain, EvmEnvProvider, HashingWriter, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider,
-    HeaderSyncMode, HistoryWriter, OriginalValuesKnown, ProviderError, PruneCheckpointReader,
+    Chain, ChainSpecProvider, ChainStateReader, ChainStateWriter, EvmEnvProvider,
+    ForkTransitionsReader, ForkTransitionsWriter, HashingWriter, HeaderProvider, HeaderSyncGap,
+    HeaderSyncGapProvider, HeaderSyncMode, HistoryWriter, HotAccountsReader, HotAccountsWriter,
+    LogsBloomIndexWriter, OriginalValuesKnown, ProviderError, PruneCheckpointReader,
     PruneCheckpointWriter, StageCheckpointReader, StorageReader, TransactionVariant,
     TransactionsProvider, TransactionsProviderExt, WithdrawalsProvider,
 };
@@ -18,7 +20,9 @@ use reth_db::{
     database::Database,
     models::{
         sharded_key, storage_sharded_key::StorageShardedKey, AccountBeforeTx, BlockNumberAddress,
-        ShardedKey, StoredBlockBodyIndices, StoredBlockOmmers, StoredBlockWithdrawals,
+        ChainStateKey, ForkTransition, HotAccountAccess, HotAccountStats, HotAccountsDecay,
+        LogsBloomBucket, ShardedKey, StoredBlockBodyIndices, StoredBlockOmmers,
+        StoredBlockWithdrawals, StoredParisBlock, LOGS_BLOOM_BUCKET_SIZE,
     },
     table::{Table, TableRow},
     tables,
@@ -26,6 +30,7 @@ use reth_db::{
     BlockNumberList, DatabaseError,
 };
 use reth_interfaces::{
+    blockchain_tree::ChainStateUpdate,
     p2p::headers::downloader::SyncTarget,
     provider::{ProviderResult, RootMismatch},
     RethError, RethResult,
@@ -36,11 +41,12 @@ use reth_primitives::{
     revm::{config::revm_spec, env::fill_block_env},
     stage::{StageCheckpoint, StageId},
     trie::Nibbles,
-    Account, Address, Block, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders,
-    ChainInfo, ChainSpec, GotExpected, Hardfork, Head, Header, PruneCheckpoint, PruneModes,
-    PruneSegment, Receipt, SealedBlock, SealedBlockWithSenders, SealedHeader, SnapshotSegment,
-    StorageEntry, TransactionMeta, TransactionSigned, TransactionSignedEcRecovered,
-    TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, Withdrawals, B256, U256,
+    Account, Address, Block, BlockHash, BlockHashOrNumber, BlockNumHash, BlockNumber,
+    BlockWithSenders, Bloom, ChainInfo, ChainSpec, GotExpected, Hardfork, Head, Header,
+    PruneCheckpoint, PruneModes, PruneSegment, Receipt, SealedBlock, SealedBlockWithSenders,
+    SealedHeader, SnapshotSegment, StorageEntry, TransactionMeta, TransactionSigned,
+    TransactionSignedEcRecovered, TransactionSignedNoHash, TxHash, TxNumber, Withdrawal,
+    Withdrawals, B256, U256,
 };
 use reth_trie::{
     prefix_set::{PrefixSet, PrefixSetMut, TriePrefixSets},
@@ -104,12 +110,15 @@ pub struct DatabaseProvider<TX> {
     /// Snapshot provider
     #[allow(dead_code)]
     snapshot_provider: Option<Arc<SnapshotProvider>>,
+    /// The snapshot bounds pinned when the snapshot provider was set, used to decide whether
+    /// data is read from snapshots or the database.
+    snapshot_bounds: SnapshotBounds,
 }
 
 impl<TX: DbTxMut> DatabaseProvider<TX> {
     /// Creates a provider with an inner read-write transaction.
     pub fn new_rw(tx: TX, chain_spec: Arc<ChainSpec>) -> Self {
-        Self { tx, chain_spec, snapshot_provider: None }
+        Self { tx, chain_spec, snapshot_provider: None, snapshot_bounds: SnapshotBounds::default() }
     }
 }
 
@@ -212,15 +221,25 @@ where
 impl<TX: DbTx> DatabaseProvider<TX> {
     /// Creates a provider with an inner read-only transaction.
     pub fn new(tx: TX, chain_spec: Arc<ChainSpec>) -> Self {
-        Self { tx, chain_spec, snapshot_provider: None }
+        Self { tx, chain_spec, snapshot_provider: None, snapshot_bounds: SnapshotBounds::default() }
     }
 
     /// Creates a new [`Self`] with access to a [`SnapshotProvider`].
+    ///
+    /// This pins the current [`SnapshotBounds`], and must be called after the transaction was
+    /// opened: snapshotted data is only removed from the database after the snapshot index was
+    /// updated, so the pinned bounds cover everything that is missing from the transaction's view.
     pub fn with_snapshot_provider(mut self, snapshot_provider: Arc<SnapshotProvider>) -> Self {
+        self.snapshot_bounds = snapshot_provider.bounds();
         self.snapshot_provider = Some(snapshot_provider);
         self
     }
 
+    /// Returns the snapshot bounds this provider reads with.
+    pub fn snapshot_bounds(&self) -> SnapshotBounds {
+        self.snapshot_bounds
+    }
+
     /// Consume `DbTx` or `DbTxMut`.
     pub fn into_tx(self) -> TX {
         self.tx
@@ -285,12 +304,7 @@ impl<TX: DbTx> DatabaseProvider<TX> {
 
         if let Some(snapshot_provider) = &self.snapshot_provider {
             // If there is, check the maximum block or transaction number of the segment.
-            if let Some(snapshot_upper_bound) = match segment {
-                SnapshotSegment::Headers => snapshot_provider.get_highest_snapshot_block(segment),
-                SnapshotSegment::Transactions | SnapshotSegment::Receipts => {
-                    snapshot_provider.get_highest_snapshot_tx(segment)
-                }
-            } {
+            if let Some(snapshot_upper_bound) = self.snapshot_bounds.highest(segment) {
                 if block_or_tx_range.start <= snapshot_upper_bound {
                     let end = block_or_tx_range.end.min(snapshot_upper_bound + 1);
                     data.extend(fetch_from_snapshot(
@@ -332,12 +346,7 @@ impl<TX: DbTx> DatabaseProvider<TX> {
     {
         if let Some(provider) = &self.snapshot_provider {
             // If there is, check the maximum block or transaction number of the segment.
-            let snapshot_upper_bound = match segment {
-                SnapshotSegment::Headers => provider.get_highest_snapshot_block(segment),
-                SnapshotSegment::Transactions | SnapshotSegment::Receipts => {
-                    provider.get_highest_snapshot_tx(segment)
-                }
-            };
+            let snapshot_upper_bound = self.snapshot_bounds.highest(segment);
 
             if snapshot_upper_bound
                 .map_or(false, |snapshot_upper_bound| snapshot_upper_bound >= number)
@@ -1425,6 +1434,72 @@ impl<TX: DbTx> BlockReader for DatabaseProvider<TX> {
         }
         Ok(blocks)
     }
+
+    /// Receipts of blocks that are fully covered by receipt snapshots are read with a single
+    /// sequential scan over the memory-mapped snapshot files, skipping the database.
+    fn receipts_by_block_range_filtered(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+        mut predicate: impl FnMut(&SealedHeader) -> bool,
+    ) -> ProviderResult<Vec<(BlockNumHash, Vec<Receipt>)>> {
+        let mut block_body_cursor = self.tx.cursor_read::<tables::BlockBodyIndices>()?;
+
+        let mut blocks = Vec::new();
+        for header in self.sealed_headers_range(range)? {
+            if !predicate(&header) {
+                continue
+            }
+            if let Some((_, block_body_indices)) = block_body_cursor.seek_exact(header.number)? {
+                blocks.push((header.num_hash(), block_body_indices.tx_num_range()));
+            }
+        }
+
+        // Transaction ranges are ascending, so all blocks whose receipts are snapshotted come
+        // first.
+        let snapshot_upper_bound = self.snapshot_bounds.highest(SnapshotSegment::Receipts);
+        let snapshotted = blocks.partition_point(|(_, tx_range)| {
+            snapshot_upper_bound.map_or(false, |upper_bound| tx_range.end <= upper_bound + 1)
+        });
+
+        let mut receipts = match &self.snapshot_provider {
+            Some(provider) if snapshotted > 0 => provider.receipts_by_tx_ranges(
+                blocks[..snapshotted].iter().map(|(_, tx_range)| tx_range.clone()),
+            )?,
+            _ => Vec::with_capacity(blocks.len()),
+        };
+        for (_, tx_range) in &blocks[snapshotted..] {
+            receipts.push(self.receipts_by_tx_range(tx_range.clone())?);
+        }
+
+        Ok(blocks.into_iter().map(|(block, _)| block).zip(receipts).collect())
+    }
+
+    fn highest_snapshot_receipts_block(&self) -> Option<BlockNumber> {
+        self.snapshot_provider
+            .as_ref()
+            .and_then(|provider| provider.get_highest_snapshot_block(SnapshotSegment::Receipts))
+    }
+
+    /// Buckets whose last block is no longer canonical are skipped.
+    fn logs_bloom_buckets(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(RangeInclusive<BlockNumber>, Bloom)>> {
+        let first_bucket = range.start() - range.start() % LOGS_BLOOM_BUCKET_SIZE;
+        let mut buckets = Vec::new();
+        for entry in self
+            .tx
+            .cursor_read::<tables::LogsBloomBuckets>()?
+            .walk_range(first_bucket..=*range.end())?
+        {
+            let (start, bucket) = entry?;
+            let end = start + LOGS_BLOOM_BUCKET_SIZE - 1;
+            if self.block_hash(end)? == Some(bucket.last_block_hash) {
+                buckets.push((start..=end, bucket.logs_bloom));
+            }
+        }
+        Ok(buckets)
+    }
 }
 
 impl<TX: DbTx> TransactionsProviderExt for DatabaseProvider<TX> {
@@ -1564,6 +1639,7 @@ impl<TX: DbTx> TransactionsProvider for DatabaseProvider<TX> {
                                 index,
                                 block_hash,
                                 block_number,
+                                timestamp: header.timestamp,
                                 base_fee: header.base_fee_per_gas,
                                 excess_blob_gas: header.excess_blob_gas,
                             };
@@ -1701,6 +1777,12 @@ impl<TX: DbTx> ReceiptProvider for DatabaseProvider<TX> {
     }
 }
 
+impl<TX: DbTx> ChainSpecProvider for DatabaseProvider<TX> {
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        self.chain_spec.clone()
+    }
+}
+
 impl<TX: DbTx> WithdrawalsProvider for DatabaseProvider<TX> {
     fn withdrawals_by_block(
         &self,
@@ -2536,6 +2618,8 @@ impl<TX: DbTxMut + DbTx> BlockWriter for DatabaseProvider<TX> {
         self.update_history_indices(first_number..=last_block_number)?;
         durations_recorder.record_relative(metrics::Action::InsertHistoryIndices);
 
+        self.record_fork_transitions(first_number..=last_block_number)?;
+
         // Update pipeline progress
         self.update_pipeline_stages(last_block_number, false)?;
         durations_recorder.record_relative(metrics::Action::UpdatePipelineStages);
@@ -2565,6 +2649,187 @@ impl<TX: DbTxMut> PruneCheckpointWriter for DatabaseProvider<TX> {
     }
 }
 
+impl<TX: DbTx> ChainStateReader for DatabaseProvider<TX> {
+    fn get_chain_state(&self, key: ChainStateKey) -> ProviderResult<Option<BlockHash>> {
+        Ok(self.tx.get::<tables::ChainState>(key)?)
+    }
+
+    fn invalid_block_headers(&self) -> ProviderResult<Vec<SealedHeader>> {
+        Ok(self
+            .tx
+            .cursor_read::<tables::InvalidBlocks>()?
+            .walk(None)?
+            .map(|entry| entry.map(|(hash, header)| header.seal(hash)))
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn paris_block(&self, genesis_hash: B256) -> ProviderResult<Option<StoredParisBlock>> {
+        Ok(self.tx.get::<tables::ParisBlock>(genesis_hash)?)
+    }
+}
+
+impl<TX: DbTxMut> ChainStateWriter for DatabaseProvider<TX> {
+    fn save_chain_state(&self, update: &ChainStateUpdate) -> ProviderResult<()> {
+        if let Some(safe) = update.safe {
+            self.tx.put::<tables::ChainState>(ChainStateKey::Safe, safe)?;
+        }
+        for header in &update.invalid_headers {
+            self.tx.put::<tables::InvalidBlocks>(header.hash(), header.header().clone())?;
+        }
+
+        let Some(finalized) = update.finalized else { return Ok(()) };
+        self.tx.put::<tables::ChainState>(ChainStateKey::Finalized, finalized)?;
+        let Some(finalized_number) = self.tx.get::<tables::HeaderNumbers>(finalized)? else {
+            return Ok(())
+        };
+        let mut cursor = self.tx.cursor_write::<tables::InvalidBlocks>()?;
+        let mut walker = cursor.walk(None)?;
+        while let Some((_, header)) = walker.next().transpose()? {
+            if header.number <= finalized_number {
+                walker.delete_current()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn save_paris_block(&self, genesis_hash: B256, paris: StoredParisBlock) -> ProviderResult<()> {
+        Ok(self.tx.put::<tables::ParisBlock>(genesis_hash, paris)?)
+    }
+}
+
+impl<TX: DbTx> HotAccountsReader for DatabaseProvider<TX> {
+    fn hot_account(&self, address: Address) -> ProviderResult<Option<HotAccountStats>> {
+        Ok(self.tx.get::<tables::HotAccounts>(address)?)
+    }
+
+    fn hottest_accounts(&self, limit: usize) -> ProviderResult<Vec<(Address, HotAccountStats)>> {
+        let mut accounts = self
+            .tx
+            .cursor_read::<tables::HotAccounts>()?
+            .walk(None)?
+            .collect::<Result<Vec<_>, _>>()?;
+        accounts.sort_by(|(_, a), (_, b)| b.score.cmp(&a.score));
+        accounts.truncate(limit);
+        Ok(accounts)
+    }
+}
+
+impl<TX: DbTxMut + DbTx> HotAccountsWriter for DatabaseProvider<TX> {
+    fn record_hot_accounts(
+        &self,
+        blocks: RangeInclusive<BlockNumber>,
+        accesses: BTreeMap<Address, HotAccountAccess>,
+        decay: &HotAccountsDecay,
+    ) -> ProviderResult<()> {
+        let block = *blocks.end();
+        let mut cursor = self.tx.cursor_write::<tables::HotAccounts>()?;
+        for (address, access) in accesses {
+            let mut stats = cursor.seek_exact(address)?.map(|(_, stats)| stats).unwrap_or_default();
+            stats.record(block, &access, decay);
+            cursor.upsert(address, stats)?;
+        }
+
+        // decay all accounts whenever a half-life boundary is crossed
+        let half_life = decay.half_life.max(1);
+        if blocks.start().saturating_sub(1) / half_life == block / half_life {
+            return Ok(())
+        }
+        let accounts = cursor.walk(None)?.collect::<Result<Vec<_>, _>>()?;
+        for (address, mut stats) in accounts {
+            stats.decay(block, decay);
+            if stats.is_cold() {
+                cursor.seek_exact(address)?;
+                cursor.delete_current()?;
+            } else {
+                cursor.upsert(address, stats)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<TX: DbTxMut + DbTx> LogsBloomIndexWriter for DatabaseProvider<TX> {
+    fn index_logs_blooms(&self, until: BlockNumber, max_buckets: usize) -> ProviderResult<usize> {
+        let mut cursor = self.tx.cursor_write::<tables::LogsBloomBuckets>()?;
+
+        // drop the buckets of blocks that were reorged out since they were built
+        while let Some((start, bucket)) = cursor.last()? {
+            if self.block_hash(start + LOGS_BLOOM_BUCKET_SIZE - 1)? == Some(bucket.last_block_hash)
+            {
+                break
+            }
+            cursor.delete_current()?;
+        }
+
+        let mut next = cursor.last()?.map_or(0, |(start, _)| start + LOGS_BLOOM_BUCKET_SIZE);
+        let mut built = 0;
+        while built < max_buckets && next + LOGS_BLOOM_BUCKET_SIZE - 1 <= until {
+            let headers = self.sealed_headers_range(next..=next + LOGS_BLOOM_BUCKET_SIZE - 1)?;
+            // only complete buckets are indexed
+            if headers.len() as u64 != LOGS_BLOOM_BUCKET_SIZE {
+                break
+            }
+            let logs_bloom =
+                headers.iter().fold(Bloom::ZERO, |bloom, header| bloom | header.logs_bloom);
+            let last_block_hash = headers.last().expect("bucket is not empty").hash();
+            cursor.append(next, LogsBloomBucket { last_block_hash, logs_bloom })?;
+
+            next += LOGS_BLOOM_BUCKET_SIZE;
+            built += 1;
+        }
+        Ok(built)
+    }
+}
+
+impl<TX: DbTx> ForkTransitionsReader for DatabaseProvider<TX> {
+    fn fork_transitions(&self) -> ProviderResult<Vec<(u64, ForkTransition)>> {
+        Ok(self
+            .tx
+            .cursor_read::<tables::ForkTransitions>()?
+            .walk(None)?
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+}
+
+impl<TX: DbTxMut + DbTx> ForkTransitionsWriter for DatabaseProvider<TX> {
+    fn record_fork_transitions(&self, blocks: RangeInclusive<BlockNumber>) -> ProviderResult<()> {
+        let (start, end) = blocks.into_inner();
+        let headers = self.headers_range(start.saturating_sub(1)..=end)?;
+        let genesis_hash = self.chain_spec.genesis_hash();
+        let fork_id = |header: &Header| {
+            self.chain_spec.fork_id(&Head {
+                number: header.number,
+                timestamp: header.timestamp,
+                ..Default::default()
+            })
+        };
+
+        let mut cursor = self.tx.cursor_write::<tables::ForkTransitions>()?;
+        let mut last = cursor.last()?;
+        for window in headers.windows(2) {
+            let (before, after) = (fork_id(&window[0]), fork_id(&window[1]));
+            if before == after {
+                continue
+            }
+            let transition = ForkTransition::new(
+                window[1].number,
+                window[1].timestamp,
+                before,
+                after,
+                genesis_hash,
+            );
+            // the same blocks are processed again after an unwind
+            if last.map(|(_, last)| last) == Some(transition) {
+                continue
+            }
+            let key = last.map_or(0, |(key, _)| key + 1);
+            cursor.append(key, transition)?;
+            last = Some((key, transition));
+        }
+        Ok(())
+    }
+}
+
 fn range_size_hint(range: &impl RangeBounds<TxNumber>) -> Option<usize> {
     let start = match range.start_bound().cloned() {
         Bound::Included(start) => start,
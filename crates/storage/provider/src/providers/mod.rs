@@ -1,14 +1,18 @@
 use crate::{
     AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
     BlockchainTreePendingStateProvider, BundleStateDataProvider, CanonChainTracker,
-    CanonStateNotifications, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    DatabaseProviderFactory, EvmEnvProvider, HeaderProvider, ProviderError, PruneCheckpointReader,
-    ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader, StateProviderBox,
-    StateProviderFactory, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    CanonStateNotifications, CanonStateSubscriptions, ChainSpecProvider, ChainStateReader,
+    ChainStateWriter, ChangeSetReader, DatabaseProviderFactory, EvmEnvProvider, HeaderProvider,
+    HotAccountsReader, ProviderError, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
+    StageCheckpointReader, StateProviderBox, StateProviderFactory, TransactionVariant,
+    TransactionsProvider, WithdrawalsProvider,
+};
+use reth_db::{
+    database::Database,
+    models::{ChainStateKey, HotAccountStats, StoredBlockBodyIndices, StoredParisBlock},
 };
-use reth_db::{database::Database, models::StoredBlockBodyIndices};
 use reth_interfaces::{
-    blockchain_tree::{BlockchainTreeEngine, BlockchainTreeViewer},
+    blockchain_tree::{BlockchainTreeEngine, BlockchainTreeViewer, ChainStateUpdate},
     consensus::ForkchoiceState,
     provider::ProviderResult,
     RethError, RethResult,
@@ -17,7 +21,7 @@ use reth_node_api::ConfigureEvmEnv;
 use reth_primitives::{
     stage::{StageCheckpoint, StageId},
     Account, Address, Block, BlockHash, BlockHashOrNumber, BlockId, BlockNumHash, BlockNumber,
-    BlockNumberOrTag, BlockWithSenders, ChainInfo, ChainSpec, Header, PruneCheckpoint,
+    BlockNumberOrTag, BlockWithSenders, Bloom, ChainInfo, ChainSpec, Header, PruneCheckpoint,
     PruneSegment, Receipt, SealedBlock, SealedBlockWithSenders, SealedHeader, TransactionMeta,
     TransactionSigned, TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, Withdrawals, B256,
     U256,
@@ -40,7 +44,7 @@ mod bundle_state_provider;
 mod chain_info;
 mod database;
 mod snapshot;
-pub use snapshot::{SnapshotJarProvider, SnapshotProvider};
+pub use snapshot::{SnapshotBounds, SnapshotJarProvider, SnapshotProvider};
 mod state;
 use crate::{providers::chain_info::ChainInfoTracker, traits::BlockSource};
 pub use bundle_state_provider::BundleStateProvider;
@@ -79,16 +83,32 @@ where
 {
     /// Create a new provider using only the database and the tree, fetching the latest header from
     /// the database to initialize the provider.
+    ///
+    /// The safe and finalized blocks of the last persisted forkchoice state are restored as well,
+    /// so they can be served before the consensus layer sends a new forkchoice update.
     pub fn new(database: ProviderFactory<DB>, tree: Tree) -> ProviderResult<Self> {
         let provider = database.provider()?;
         let best: ChainInfo = provider.chain_info()?;
-        match provider.header_by_number(best.best_number)? {
-            Some(header) => {
-                drop(provider);
-                Ok(Self::with_latest(database, tree, header.seal(best.best_hash)))
-            }
-            None => Err(ProviderError::HeaderNotFound(best.best_number.into())),
+        let Some(header) = provider.header_by_number(best.best_number)? else {
+            return Err(ProviderError::HeaderNotFound(best.best_number.into()))
+        };
+
+        let persisted_header = |key| -> ProviderResult<Option<SealedHeader>> {
+            let Some(hash) = provider.get_chain_state(key)? else { return Ok(None) };
+            Ok(provider.header(&hash)?.map(|header| header.seal(hash)))
+        };
+        let safe = persisted_header(ChainStateKey::Safe)?;
+        let finalized = persisted_header(ChainStateKey::Finalized)?;
+        drop(provider);
+
+        let this = Self::with_latest(database, tree, header.seal(best.best_hash));
+        if let Some(safe) = safe {
+            this.chain_info.set_safe(safe);
+        }
+        if let Some(finalized) = finalized {
+            this.chain_info.set_finalized(finalized);
         }
+        Ok(this)
     }
 }
 
@@ -299,6 +319,25 @@ where
     fn block_range(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<Vec<Block>> {
         self.database.provider()?.block_range(range)
     }
+
+    fn receipts_by_block_range_filtered(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+        predicate: impl FnMut(&SealedHeader) -> bool,
+    ) -> ProviderResult<Vec<(BlockNumHash, Vec<Receipt>)>> {
+        self.database.provider()?.receipts_by_block_range_filtered(range, predicate)
+    }
+
+    fn highest_snapshot_receipts_block(&self) -> Option<BlockNumber> {
+        self.database.highest_snapshot_receipts_block()
+    }
+
+    fn logs_bloom_buckets(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(RangeInclusive<BlockNumber>, Bloom)>> {
+        self.database.provider()?.logs_bloom_buckets(range)
+    }
 }
 
 impl<DB, Tree> TransactionsProvider for BlockchainProvider<DB, Tree>
@@ -439,6 +478,52 @@ where
     }
 }
 
+impl<DB, Tree> ChainStateReader for BlockchainProvider<DB, Tree>
+where
+    DB: Database,
+    Tree: Send + Sync,
+{
+    fn get_chain_state(&self, key: ChainStateKey) -> ProviderResult<Option<BlockHash>> {
+        self.database.get_chain_state(key)
+    }
+
+    fn invalid_block_headers(&self) -> ProviderResult<Vec<SealedHeader>> {
+        self.database.invalid_block_headers()
+    }
+
+    fn paris_block(&self, genesis_hash: B256) -> ProviderResult<Option<StoredParisBlock>> {
+        self.database.paris_block(genesis_hash)
+    }
+}
+
+impl<DB, Tree> ChainStateWriter for BlockchainProvider<DB, Tree>
+where
+    DB: Database,
+    Tree: Send + Sync,
+{
+    fn save_chain_state(&self, update: &ChainStateUpdate) -> ProviderResult<()> {
+        self.database.save_chain_state(update)
+    }
+
+    fn save_paris_block(&self, genesis_hash: B256, paris: StoredParisBlock) -> ProviderResult<()> {
+        self.database.save_paris_block(genesis_hash, paris)
+    }
+}
+
+impl<DB, Tree> HotAccountsReader for BlockchainProvider<DB, Tree>
+where
+    DB: Database,
+    Tree: Send + Sync,
+{
+    fn hot_account(&self, address: Address) -> ProviderResult<Option<HotAccountStats>> {
+        self.database.hot_account(address)
+    }
+
+    fn hottest_accounts(&self, limit: usize) -> ProviderResult<Vec<(Address, HotAccountStats)>> {
+        self.database.hottest_accounts(limit)
+    }
+}
+
 impl<DB, Tree> StageCheckpointReader for BlockchainProvider<DB, Tree>
 where
     DB: Database,
@@ -661,6 +746,10 @@ where
         self.tree.make_canonical(block_hash)
     }
 
+    fn update_chain_state(&self, update: ChainStateUpdate) {
+        self.tree.update_chain_state(update)
+    }
+
     fn unwind(&self, unwind_to: BlockNumber) -> RethResult<()> {
         self.tree.unwind(unwind_to)
     }
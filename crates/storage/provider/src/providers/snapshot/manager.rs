@@ -54,6 +54,33 @@ pub struct SnapshotProvider {
     load_filters: bool,
 }
 
+/// The highest snapshotted block or transaction of every snapshot segment, taken at one point in
+/// time.
+///
+/// Database providers pin the bounds when they are created, so that all their reads agree on which
+/// blocks and transactions are served from snapshots, even if snapshots are added concurrently.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SnapshotBounds {
+    /// The highest snapshotted block of the headers segment.
+    pub headers: Option<BlockNumber>,
+    /// The highest snapshotted transaction of the transactions segment.
+    pub transactions: Option<TxNumber>,
+    /// The highest snapshotted transaction of the receipts segment.
+    pub receipts: Option<TxNumber>,
+}
+
+impl SnapshotBounds {
+    /// Returns the highest snapshotted block of the headers segment, or the highest snapshotted
+    /// transaction of the transactions and receipts segments.
+    pub fn highest(&self, segment: SnapshotSegment) -> Option<u64> {
+        match segment {
+            SnapshotSegment::Headers => self.headers,
+            SnapshotSegment::Transactions => self.transactions,
+            SnapshotSegment::Receipts => self.receipts,
+        }
+    }
+}
+
 impl SnapshotProvider {
     /// Creates a new [`SnapshotProvider`].
     pub fn new(path: impl AsRef<Path>) -> ProviderResult<Self> {
@@ -229,9 +256,14 @@ impl SnapshotProvider {
     }
 
     /// Updates the inner transaction and block index
+    ///
+    /// The index is rebuilt from the snapshot directory and replaced at once, so that
+    /// [`SnapshotProvider::bounds`] never observes a partially updated index. Snapshotted data must
+    /// only be removed from the database after the index was updated, otherwise readers may find
+    /// neither the snapshot nor the database rows.
     pub fn update_index(&self) -> ProviderResult<()> {
-        let mut block_index = self.snapshots_block_index.write();
-        let mut tx_index = self.snapshots_tx_index.write();
+        let mut block_index = SegmentRanges::default();
+        let mut tx_index = SegmentRanges::default();
 
         for (segment, ranges) in iter_snapshots(&self.path)? {
             for (block_range, tx_range) in ranges {
@@ -258,9 +290,33 @@ impl SnapshotProvider {
             }
         }
 
+        let mut block_index_guard = self.snapshots_block_index.write();
+        let mut tx_index_guard = self.snapshots_tx_index.write();
+        *block_index_guard = block_index;
+        *tx_index_guard = tx_index;
+
         Ok(())
     }
 
+    /// Returns the highest snapshotted block or transaction of every segment.
+    ///
+    /// Both indexes are read under the same locks, so the bounds are consistent with each other.
+    pub fn bounds(&self) -> SnapshotBounds {
+        let block_index = self.snapshots_block_index.read();
+        let tx_index = self.snapshots_tx_index.read();
+        let highest_block = |segment| {
+            block_index.get(&segment).and_then(|index| index.last_key_value()).map(|(b, _)| *b)
+        };
+        let highest_tx = |segment| {
+            tx_index.get(&segment).and_then(|index| index.last_key_value()).map(|(tx, _)| *tx)
+        };
+        SnapshotBounds {
+            headers: highest_block(SnapshotSegment::Headers),
+            transactions: highest_tx(SnapshotSegment::Transactions),
+            receipts: highest_tx(SnapshotSegment::Receipts),
+        }
+    }
+
     /// Gets the highest snapshot block if it exists for a snapshot segment.
     pub fn get_highest_snapshot_block(&self, segment: SnapshotSegment) -> Option<BlockNumber> {
         self.snapshots_block_index
@@ -358,6 +414,49 @@ impl SnapshotProvider {
 
         Ok(result)
     }
+
+    /// Fetches the receipts of each of the given ascending transaction ranges.
+    ///
+    /// Unlike calling [`ReceiptProvider::receipts_by_tx_range`] for every range, this keeps the
+    /// cursor over the memory-mapped snapshot file open across ranges, so scanning the receipts
+    /// of many blocks only switches files once the current one is exhausted.
+    pub fn receipts_by_tx_ranges(
+        &self,
+        ranges: impl IntoIterator<Item = Range<TxNumber>>,
+    ) -> ProviderResult<Vec<Vec<Receipt>>> {
+        let ranges = ranges.into_iter().collect::<Vec<_>>();
+        let Some(start) = ranges.iter().find(|range| !range.is_empty()).map(|range| range.start)
+        else {
+            return Ok(vec![Vec::new(); ranges.len()])
+        };
+
+        let segment = SnapshotSegment::Receipts;
+        let mut provider = self.get_segment_provider_from_transaction(segment, start, None)?;
+        let mut cursor = provider.cursor()?;
+
+        let mut result = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            let mut receipts = Vec::with_capacity(range.len());
+            for number in range {
+                let receipt = match cursor.get_one::<ReceiptMask<Receipt>>(number.into())? {
+                    Some(receipt) => receipt,
+                    None => {
+                        // The transaction is in the next snapshot file
+                        provider =
+                            self.get_segment_provider_from_transaction(segment, number, None)?;
+                        cursor = provider.cursor()?;
+                        cursor
+                            .get_one::<ReceiptMask<Receipt>>(number.into())?
+                            .ok_or(ProviderError::MissingSnapshotTx(segment, number))?
+                    }
+                };
+                receipts.push(receipt);
+            }
+            result.push(receipts);
+        }
+
+        Ok(result)
+    }
 }
 
 impl HeaderProvider for SnapshotProvider {
@@ -1,5 +1,5 @@
 mod manager;
-pub use manager::SnapshotProvider;
+pub use manager::{SnapshotBounds, SnapshotProvider};
 
 mod jar;
 pub use jar::SnapshotJarProvider;
@@ -243,6 +243,7 @@ impl TransactionsProvider for MockEthProvider {
                         index: index as u64,
                         block_hash: *block_hash,
                         block_number: block.header.number,
+                        timestamp: block.header.timestamp,
                         base_fee: block.header.base_fee_per_gas,
                         excess_blob_gas: block.header.excess_blob_gas,
                     };
@@ -6,8 +6,9 @@ use auto_impl::auto_impl;
 use reth_db::models::StoredBlockBodyIndices;
 use reth_interfaces::provider::ProviderResult;
 use reth_primitives::{
-    Block, BlockHashOrNumber, BlockId, BlockNumber, BlockNumberOrTag, BlockWithSenders, ChainSpec,
-    Header, PruneModes, Receipt, SealedBlock, SealedBlockWithSenders, SealedHeader, B256,
+    Block, BlockHashOrNumber, BlockId, BlockNumHash, BlockNumber, BlockNumberOrTag,
+    BlockWithSenders, Bloom, ChainSpec, Header, PruneModes, Receipt, SealedBlock,
+    SealedBlockWithSenders, SealedHeader, B256,
 };
 use reth_trie::{updates::TrieUpdates, HashedPostState};
 use std::ops::RangeInclusive;
@@ -133,6 +134,50 @@ pub trait BlockReader:
     ///
     /// Note: returns only available blocks
     fn block_range(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<Vec<Block>>;
+
+    /// Returns the receipts of all blocks in the given range whose header satisfies the
+    /// `predicate`, e.g. a check of the header's logs bloom.
+    ///
+    /// Blocks are returned in ascending order, together with their number and hash. Blocks for
+    /// which the predicate returns `false` are skipped without reading their receipts.
+    ///
+    /// This is meant for historical ranges, see [BlockReader::highest_snapshot_receipts_block].
+    fn receipts_by_block_range_filtered(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+        predicate: impl FnMut(&SealedHeader) -> bool,
+    ) -> ProviderResult<Vec<(BlockNumHash, Vec<Receipt>)>> {
+        let mut predicate = predicate;
+        let mut blocks = Vec::new();
+        for header in self.sealed_headers_range(range)? {
+            if !predicate(&header) {
+                continue
+            }
+            let receipts = self.receipts_by_block(header.number.into())?.unwrap_or_default();
+            blocks.push((header.num_hash(), receipts));
+        }
+        Ok(blocks)
+    }
+
+    /// Returns the highest block whose receipts are stored in snapshots, if any.
+    ///
+    /// The receipts of the blocks up to this block are read most efficiently with
+    /// [BlockReader::receipts_by_block_range_filtered].
+    fn highest_snapshot_receipts_block(&self) -> Option<BlockNumber> {
+        None
+    }
+
+    /// Returns the indexed buckets of the logs bloom index that overlap the given range, in
+    /// ascending order, together with the union of the logs blooms of their blocks.
+    ///
+    /// Blocks that are not covered by a returned bucket have to be checked individually. Returns
+    /// no buckets if the provider has no index.
+    fn logs_bloom_buckets(
+        &self,
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(RangeInclusive<BlockNumber>, Bloom)>> {
+        Ok(Vec::new())
+    }
 }
 
 /// Trait extension for `BlockReader`, for types that implement `BlockId` conversion.
@@ -2,8 +2,9 @@
 
 use crate::{chain::BlockReceipts, Chain};
 use auto_impl::auto_impl;
-use reth_primitives::SealedBlockWithSenders;
+use reth_primitives::{Address, BlockNumHash, SealedBlockWithSenders, B256, U256};
 use std::{
+    collections::HashSet,
     pin::Pin,
     sync::Arc,
     task::{ready, Context, Poll},
@@ -32,6 +33,11 @@ pub trait CanonStateSubscriptions: Send + Sync {
             st: BroadcastStream::new(self.subscribe_to_canonical_state()),
         }
     }
+
+    /// Returns a stream of the changes of the given storage slots by new canonical chains.
+    fn storage_watch_stream(&self, watched: HashSet<(Address, B256)>) -> StorageWatchStream {
+        StorageWatchStream { st: self.canonical_state_stream(), watched }
+    }
 }
 
 /// A Stream of [CanonStateNotification].
@@ -59,6 +65,58 @@ impl Stream for CanonStateNotificationStream {
     }
 }
 
+/// A Stream of the changes of watched storage slots, see
+/// [CanonStateSubscriptions::storage_watch_stream].
+///
+/// Only canonical state notifications that change at least one watched slot yield an item.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct StorageWatchStream {
+    #[pin]
+    st: CanonStateNotificationStream,
+    watched: HashSet<(Address, B256)>,
+}
+
+impl Stream for StorageWatchStream {
+    type Item = StorageWatchNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(notification) = ready!(this.st.as_mut().poll_next(cx)) else {
+                return Poll::Ready(None)
+            };
+            let changes = notification.storage_changes(this.watched.iter());
+            if !changes.is_empty() {
+                let tip = notification.tip().num_hash();
+                return Poll::Ready(Some(StorageWatchNotification { tip, changes }))
+            }
+        }
+    }
+}
+
+/// The changes of watched storage slots by a canonical state notification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageWatchNotification {
+    /// The new tip of the canonical chain.
+    pub tip: BlockNumHash,
+    /// The changed slots, sorted by address and slot.
+    pub changes: Vec<StorageSlotChange>,
+}
+
+/// A storage slot whose value was changed by a canonical state notification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StorageSlotChange {
+    /// The address of the account.
+    pub address: Address,
+    /// The storage slot.
+    pub slot: B256,
+    /// The value before the notification, i.e. at the tip of the previous canonical chain.
+    pub previous: U256,
+    /// The value at the new tip of the canonical chain.
+    pub value: U256,
+}
+
 /// Chain action that is triggered when a new block is imported or old block is reverted.
 /// and will return all [`crate::BundleStateWithReceipts`] and
 /// [`reth_primitives::SealedBlockWithSenders`] of both reverted and committed blocks.
@@ -123,6 +181,46 @@ impl CanonStateNotification {
         }
     }
 
+    /// Returns the changes of the given storage slots between the previous and the new tip of the
+    /// canonical chain, sorted by address and slot.
+    ///
+    /// Slots whose previous value is not known from the chains, e.g. storage wiped by a self
+    /// destruct, are not reported.
+    pub fn storage_changes<'a>(
+        &self,
+        watched: impl IntoIterator<Item = &'a (Address, B256)>,
+    ) -> Vec<StorageSlotChange> {
+        let old = self.reverted();
+        let new = self.committed();
+
+        let mut changes = watched
+            .into_iter()
+            .filter_map(|(address, slot)| {
+                let key = U256::from_be_bytes(slot.0);
+                let reverted = old.as_ref().map(|old| old.state());
+                let committed = new.as_ref().map(|new| new.state());
+
+                // slots that are not changed by the reverted chain have the value they had before
+                // its first block, which the committed chain knows if it changed them as well
+                let previous = reverted
+                    .and_then(|state| state.storage(address, key))
+                    .or_else(|| committed.and_then(|state| state.original_storage(address, key)))?;
+                let value = committed
+                    .and_then(|state| state.storage(address, key))
+                    .or_else(|| reverted.and_then(|state| state.original_storage(address, key)))?;
+
+                (previous != value).then_some(StorageSlotChange {
+                    address: *address,
+                    slot: *slot,
+                    previous,
+                    value,
+                })
+            })
+            .collect::<Vec<_>>();
+        changes.sort_unstable_by_key(|change| (change.address, change.slot));
+        changes
+    }
+
     /// Return receipt with its block number and transaction hash.
     ///
     /// Last boolean is true if receipt is from reverted block.
@@ -0,0 +1,29 @@
+use reth_db::models::{ChainStateKey, StoredParisBlock};
+use reth_interfaces::{blockchain_tree::ChainStateUpdate, provider::ProviderResult};
+use reth_primitives::{BlockHash, SealedHeader, B256};
+
+/// The trait for fetching the persisted forkchoice state and known invalid blocks.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait ChainStateReader: Send + Sync {
+    /// Fetch the block hash of the last forkchoice state entry for the given key.
+    fn get_chain_state(&self, key: ChainStateKey) -> ProviderResult<Option<BlockHash>>;
+
+    /// Fetch the headers of all blocks that were persisted as invalid.
+    fn invalid_block_headers(&self) -> ProviderResult<Vec<SealedHeader>>;
+
+    /// Fetch the Paris block observed for the chain with the given genesis hash.
+    fn paris_block(&self, genesis_hash: B256) -> ProviderResult<Option<StoredParisBlock>>;
+}
+
+/// The trait for persisting the forkchoice state and known invalid blocks.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait ChainStateWriter: Send + Sync {
+    /// Save the forkchoice state and invalid blocks of the given update.
+    ///
+    /// Invalid blocks at or below the finalized block are pruned, they can't become canonical
+    /// anymore.
+    fn save_chain_state(&self, update: &ChainStateUpdate) -> ProviderResult<()>;
+
+    /// Save the Paris block observed for the chain with the given genesis hash.
+    fn save_paris_block(&self, genesis_hash: B256, paris: StoredParisBlock) -> ProviderResult<()>;
+}
@@ -31,6 +31,10 @@ pub trait BlockExecutor {
 
     /// Executes the block and checks receipts.
     ///
+    /// If the receipts root or the logs bloom of the block don't match the header, the state
+    /// changes of the block are discarded, so that the output state only contains the blocks
+    /// executed before.
+    ///
     /// See [execute](BlockExecutor::execute) for more details.
     fn execute_and_verify_receipt(
         &mut self,
@@ -0,0 +1,21 @@
+use reth_db::models::ForkTransition;
+use reth_interfaces::provider::ProviderResult;
+use reth_primitives::BlockNumber;
+use std::ops::RangeInclusive;
+
+/// The trait for fetching the hardfork transitions processed by the node.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait ForkTransitionsReader: Send + Sync {
+    /// Fetch all recorded fork transitions, in the order they were processed.
+    fn fork_transitions(&self) -> ProviderResult<Vec<(u64, ForkTransition)>>;
+}
+
+/// The trait for recording the hardfork transitions processed by the node.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait ForkTransitionsWriter: Send + Sync {
+    /// Record the fork transitions activated by the given range of processed blocks.
+    ///
+    /// Transitions are only ever appended, so the log survives unwinds. A transition equal to the
+    /// last recorded one is not recorded twice.
+    fn record_fork_transitions(&self, blocks: RangeInclusive<BlockNumber>) -> ProviderResult<()>;
+}
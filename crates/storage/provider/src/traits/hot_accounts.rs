@@ -0,0 +1,29 @@
+use reth_db::models::{HotAccountAccess, HotAccountStats, HotAccountsDecay};
+use reth_interfaces::provider::ProviderResult;
+use reth_primitives::{Address, BlockNumber};
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+/// The trait for fetching the access statistics of accounts gathered from executed blocks.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait HotAccountsReader: Send + Sync {
+    /// Fetch the access statistics of the given account.
+    fn hot_account(&self, address: Address) -> ProviderResult<Option<HotAccountStats>>;
+
+    /// Fetch the `limit` accounts with the highest scores, sorted by descending score.
+    fn hottest_accounts(&self, limit: usize) -> ProviderResult<Vec<(Address, HotAccountStats)>>;
+}
+
+/// The trait for recording the access statistics of accounts.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait HotAccountsWriter: Send + Sync {
+    /// Record the account accesses of the given range of executed blocks.
+    ///
+    /// Once per half-life of the [HotAccountsDecay], the scores of all accounts are decayed and
+    /// accounts that went cold are removed.
+    fn record_hot_accounts(
+        &self,
+        blocks: RangeInclusive<BlockNumber>,
+        accesses: BTreeMap<Address, HotAccountAccess>,
+        decay: &HotAccountsDecay,
+    ) -> ProviderResult<()>;
+}
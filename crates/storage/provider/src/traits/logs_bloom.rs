@@ -0,0 +1,14 @@
+use reth_interfaces::provider::ProviderResult;
+use reth_primitives::BlockNumber;
+
+/// The trait for building the bucketed logs bloom index.
+///
+/// See [BlockReader::logs_bloom_buckets](crate::BlockReader::logs_bloom_buckets) for reading it.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait LogsBloomIndexWriter: Send + Sync {
+    /// Build up to `max_buckets` missing buckets of the logs bloom index whose blocks are all at
+    /// or below `until`, returning the number of built buckets.
+    ///
+    /// Buckets whose last block is no longer canonical are removed first.
+    fn index_logs_blooms(&self, until: BlockNumber, max_buckets: usize) -> ProviderResult<usize>;
+}
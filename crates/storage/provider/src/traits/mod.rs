@@ -51,7 +51,8 @@ pub use executor::{BlockExecutor, BlockExecutorStats, ExecutorFactory, PrunableB
 mod chain;
 pub use chain::{
     CanonStateNotification, CanonStateNotificationSender, CanonStateNotificationStream,
-    CanonStateNotifications, CanonStateSubscriptions,
+    CanonStateNotifications, CanonStateSubscriptions, StorageSlotChange, StorageWatchNotification,
+    StorageWatchStream,
 };
 
 mod spec;
@@ -69,5 +70,17 @@ pub use history::HistoryWriter;
 mod prune_checkpoint;
 pub use prune_checkpoint::{PruneCheckpointReader, PruneCheckpointWriter};
 
+mod chain_state;
+pub use chain_state::{ChainStateReader, ChainStateWriter};
+
+mod hot_accounts;
+pub use hot_accounts::{HotAccountsReader, HotAccountsWriter};
+
+mod logs_bloom;
+pub use logs_bloom::LogsBloomIndexWriter;
+
+mod fork_transitions;
+pub use fork_transitions::{ForkTransitionsReader, ForkTransitionsWriter};
+
 mod database_provider;
 pub use database_provider::DatabaseProviderFactory;
@@ -2,7 +2,18 @@ use reth_primitives::ChainSpec;
 use std::sync::Arc;
 
 /// A trait for reading the current chainspec.
+///
+/// This is implemented by the providers and the node components that know the spec of the chain,
+/// and by `Arc<ChainSpec>` itself, so constructors can take any of them instead of an
+/// `Arc<ChainSpec>` that is threaded through by the caller.
+#[auto_impl::auto_impl(&)]
 pub trait ChainSpecProvider: Send + Sync {
     /// Get an [`Arc`] to the chainspec.
     fn chain_spec(&self) -> Arc<ChainSpec>;
 }
+
+impl ChainSpecProvider for Arc<ChainSpec> {
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        self.clone()
+    }
+}
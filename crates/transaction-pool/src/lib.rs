@@ -168,8 +168,11 @@ pub use crate::{
     error::PoolResult,
     ordering::{CoinbaseTipOrdering, Priority, TransactionOrdering},
     pool::{
-        blob_tx_priority, fee_delta, state::SubPool, AllTransactionsEvents, FullTransactionEvent,
-        TransactionEvent, TransactionEvents,
+        blob_tx_priority,
+        dump::{PoolDump, PoolDumpSender, PoolDumpTransaction},
+        fee_delta,
+        state::SubPool,
+        AllTransactionsEvents, FullTransactionEvent, TransactionEvent, TransactionEvents,
     },
     traits::*,
     validate::{
@@ -503,6 +506,10 @@ where
         self.pool.unique_senders()
     }
 
+    fn dump(&self) -> PoolDump {
+        self.pool.dump()
+    }
+
     fn get_blob(&self, tx_hash: TxHash) -> Result<Option<BlobTransactionSidecar>, BlobStoreError> {
         self.pool.blob_store().get(tx_hash)
     }
@@ -108,10 +108,10 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
         let info = BlockInfo {
             last_seen_block_hash: latest.hash(),
             last_seen_block_number: latest.number,
-            pending_basefee: latest
-                .next_block_base_fee(chain_spec.base_fee_params(latest.timestamp + 12))
+            pending_basefee: chain_spec
+                .next_block_base_fee(&latest, latest.timestamp + 12)
                 .unwrap_or_default(),
-            pending_blob_fee: latest.next_block_blob_fee(),
+            pending_blob_fee: chain_spec.next_block_blob_fee(&latest, latest.timestamp + 12),
         };
         pool.set_block_info(info);
     }
@@ -264,10 +264,11 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
                 let chain_spec = client.chain_spec();
 
                 // fees for the next block: `new_tip+1`
-                let pending_block_base_fee = new_tip
-                    .next_block_base_fee(chain_spec.base_fee_params(new_tip.timestamp + 12))
+                let pending_block_base_fee = chain_spec
+                    .next_block_base_fee(new_tip, new_tip.timestamp + 12)
                     .unwrap_or_default();
-                let pending_block_blob_fee = new_tip.next_block_blob_fee();
+                let pending_block_blob_fee =
+                    chain_spec.next_block_blob_fee(new_tip, new_tip.timestamp + 12);
 
                 // we know all changed account in the new chain
                 let new_changed_accounts: HashSet<_> =
@@ -369,10 +370,10 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
                 let chain_spec = client.chain_spec();
 
                 // fees for the next block: `tip+1`
-                let pending_block_base_fee = tip
-                    .next_block_base_fee(chain_spec.base_fee_params(tip.timestamp + 12))
-                    .unwrap_or_default();
-                let pending_block_blob_fee = tip.next_block_blob_fee();
+                let pending_block_base_fee =
+                    chain_spec.next_block_base_fee(tip, tip.timestamp + 12).unwrap_or_default();
+                let pending_block_blob_fee =
+                    chain_spec.next_block_blob_fee(tip, tip.timestamp + 12);
 
                 let first_block = blocks.first();
                 trace!(
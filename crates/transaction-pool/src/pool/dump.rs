@@ -0,0 +1,182 @@
+//! Snapshots of the pool state for offline analysis.
+
+use crate::{
+    identifier::TransactionId,
+    ordering::{Priority, TransactionOrdering},
+    pool::{
+        pending::PendingPool,
+        state::SubPool,
+        txpool::{PoolInternalTransaction, SenderInfo},
+    },
+    traits::{BlockInfo, TransactionOrigin},
+};
+use reth_primitives::{
+    Address, Bytes, FromRecoveredTransaction, IntoRecoveredTransaction, TransactionSigned, TxHash,
+    U256,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A snapshot of the full state of the pool: the sub-pool membership of every transaction, its
+/// ordering score and the on-chain state of its sender the pool validated it against.
+///
+/// This is intended for diagnostics, the dump can be written to a file and the pool reconstructed
+/// from it in a test harness, see also `test_utils::txpool_from_dump`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PoolDump {
+    /// The block the pool tracks.
+    pub block_info: BlockInfo,
+    /// The on-chain state of all senders of the transactions in the pool.
+    pub senders: Vec<PoolDumpSender>,
+    /// All transactions in the pool, ordered by sender and nonce.
+    pub transactions: Vec<PoolDumpTransaction>,
+}
+
+impl PoolDump {
+    /// Returns the on-chain state of the given sender.
+    pub fn sender(&self, address: &Address) -> Option<&PoolDumpSender> {
+        self.senders.iter().find(|sender| sender.address == *address)
+    }
+
+    /// Decodes the transactions of the dump, together with their origin.
+    pub fn decode_transactions<T: FromRecoveredTransaction>(
+        &self,
+    ) -> alloy_rlp::Result<Vec<(T, TransactionOrigin)>> {
+        self.transactions
+            .iter()
+            .map(|tx| {
+                let signed = TransactionSigned::decode_enveloped(&mut tx.raw.as_ref())?;
+                Ok((T::from_recovered_transaction(signed.with_signer(tx.sender)), tx.origin))
+            })
+            .collect()
+    }
+}
+
+/// The on-chain state of a sender, as tracked by the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PoolDumpSender {
+    /// The address of the sender.
+    pub address: Address,
+    /// The on-chain nonce of the sender.
+    pub state_nonce: u64,
+    /// The on-chain balance of the sender.
+    pub balance: U256,
+}
+
+impl PoolDumpSender {
+    /// Creates the dump entry of a sender of the pool.
+    pub(crate) fn new(address: Address, info: &SenderInfo) -> Self {
+        Self { address, state_nonce: info.state_nonce, balance: info.balance }
+    }
+}
+
+/// A transaction in a [PoolDump].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PoolDumpTransaction {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The sender of the transaction.
+    pub sender: Address,
+    /// The nonce of the transaction.
+    pub nonce: u64,
+    /// The sub-pool that contains the transaction.
+    pub subpool: SubPool,
+    /// The names of the state flags of the transaction the sub-pool is derived from, e.g.
+    /// `ENOUGH_BALANCE`.
+    pub state: Vec<String>,
+    /// The combined cost of all transactions of the sender that come before this transaction.
+    pub cumulative_cost: U256,
+    /// The priority assigned by the ordering, only set for pending transactions.
+    ///
+    /// This is the debug representation of the priority value of the ordering.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub priority: Option<String>,
+    /// The identifier that tags when the transaction was submitted to the pending sub-pool, ties
+    /// between equal priorities are broken by it.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub submission_id: Option<u64>,
+    /// Where the transaction originated from.
+    pub origin: TransactionOrigin,
+    /// Whether it is allowed to propagate the transaction.
+    pub propagate: bool,
+    /// The EIP-2718 encoded transaction.
+    pub raw: Bytes,
+}
+
+impl PoolDumpTransaction {
+    /// Creates the dump entry of a transaction of the pool.
+    pub(crate) fn new<T: TransactionOrdering>(
+        id: &TransactionId,
+        tx: &PoolInternalTransaction<T::Transaction>,
+        pending_pool: &PendingPool<T>,
+    ) -> Self {
+        let pending = pending_pool.get(id);
+        let transaction = &tx.transaction;
+        Self {
+            hash: *transaction.hash(),
+            sender: transaction.sender(),
+            nonce: transaction.nonce(),
+            subpool: tx.subpool,
+            state: tx.state.iter_names().map(|(name, _)| name.to_string()).collect(),
+            cumulative_cost: tx.cumulative_cost,
+            priority: pending.and_then(|tx| match &tx.priority {
+                Priority::Value(value) => Some(format!("{value:?}")),
+                Priority::None => None,
+            }),
+            submission_id: pending.map(|tx| tx.submission_id),
+            origin: transaction.origin,
+            propagate: transaction.propagate,
+            raw: transaction.to_recovered_transaction().envelope_encoded(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pool::txpool::TxPool,
+        test_utils::{txpool_from_dump, MockOrdering, MockTransaction, MockTransactionFactory},
+    };
+
+    #[test]
+    fn reconstruct_pool_from_dump() {
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        // a pending transaction, and a queued transaction of another sender with a nonce gap
+        let pending = MockTransaction::eip1559();
+        let queued = MockTransaction::eip1559().with_nonce(2);
+        pool.add_transaction(f.validated(pending.clone()), U256::MAX, 0).unwrap();
+        pool.add_transaction(f.validated(queued.clone()), U256::MAX, 0).unwrap();
+
+        let dump = pool.dump();
+        assert_eq!(dump.transactions.len(), 2);
+        assert_eq!(dump.senders.len(), 2);
+        let subpool = |dump: &PoolDump, sender: Address| {
+            dump.transactions.iter().find(|tx| tx.sender == sender).map(|tx| tx.subpool)
+        };
+        assert_eq!(subpool(&dump, pending.get_sender()), Some(SubPool::Pending));
+        assert_eq!(subpool(&dump, queued.get_sender()), Some(SubPool::Queued));
+        let entry = dump.transactions.iter().find(|tx| tx.subpool.is_pending()).unwrap();
+        assert!(entry.priority.is_some());
+        assert!(entry.state.iter().any(|flag| flag == "ENOUGH_BALANCE"));
+
+        let json = serde_json::to_string(&dump).unwrap();
+        let dump: PoolDump = serde_json::from_str(&json).unwrap();
+
+        let reconstructed =
+            txpool_from_dump(MockOrdering::default(), Default::default(), &dump).unwrap();
+        let redump = reconstructed.dump();
+        assert_eq!(redump.block_info, dump.block_info);
+        assert_eq!(redump.senders, dump.senders);
+        assert_eq!(subpool(&redump, pending.get_sender()), Some(SubPool::Pending));
+        assert_eq!(subpool(&redump, queued.get_sender()), Some(SubPool::Queued));
+    }
+}
@@ -99,7 +99,7 @@ mod events;
 use crate::{
     blobstore::BlobStore,
     metrics::BlobStoreMetrics,
-    pool::txpool::UpdateOutcome,
+    pool::{dump::PoolDump, txpool::UpdateOutcome},
     traits::{GetPooledTransactionLimit, NewBlobSidecar, TransactionListenerKind},
     validate::ValidTransaction,
 };
@@ -113,6 +113,7 @@ pub use pending::PendingPool;
 
 mod best;
 mod blob;
+pub(crate) mod dump;
 mod listener;
 mod parked;
 pub(crate) mod pending;
@@ -205,6 +206,11 @@ where
         self.get_pool_data().unique_senders()
     }
 
+    /// Returns a snapshot of the state of the pool.
+    pub(crate) fn dump(&self) -> PoolDump {
+        self.get_pool_data().dump()
+    }
+
     /// Converts the changed accounts to a map of sender ids to sender info (internal identifier
     /// used for accounts)
     fn changed_senders(
@@ -514,7 +514,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
     }
 
     /// Retrieves a transaction with the given ID from the pool, if it exists.
-    fn get(&self, id: &TransactionId) -> Option<&PendingTransaction<T>> {
+    pub(crate) fn get(&self, id: &TransactionId) -> Option<&PendingTransaction<T>> {
         self.by_id.get(id)
     }
 
@@ -8,6 +8,7 @@ use crate::{
     pool::{
         best::BestTransactions,
         blob::BlobTransactions,
+        dump::{PoolDump, PoolDumpSender, PoolDumpTransaction},
         parked::{BasefeeOrd, ParkedPool, QueuedOrd},
         pending::PendingPool,
         state::{SubPool, TxState},
@@ -117,6 +118,23 @@ impl<T: TransactionOrdering> TxPool<T> {
         self.all_transactions.txs.values().map(|tx| tx.transaction.sender()).collect()
     }
 
+    /// Returns a snapshot of the state of the pool, see [PoolDump].
+    pub(crate) fn dump(&self) -> PoolDump {
+        let mut senders = Vec::new();
+        let mut transactions = Vec::with_capacity(self.all_transactions.len());
+        let mut last_sender = None;
+        for (id, tx) in self.all_transactions.txs.iter() {
+            // transactions are sorted by sender
+            if last_sender != Some(id.sender) {
+                last_sender = Some(id.sender);
+                let info = self.sender_info.get(&id.sender).cloned().unwrap_or_default();
+                senders.push(PoolDumpSender::new(tx.transaction.sender(), &info));
+            }
+            transactions.push(PoolDumpTransaction::new(id, tx, &self.pending_pool));
+        }
+        PoolDump { block_info: self.block_info(), senders, transactions }
+    }
+
     /// Returns stats about the size of pool.
     pub fn size(&self) -> PoolSize {
         PoolSize {
@@ -1,7 +1,14 @@
 //! Internal helpers for testing.
 
-use crate::{blobstore::InMemoryBlobStore, noop::MockTransactionValidator, Pool, PoolConfig};
-use std::ops::Deref;
+use crate::{
+    blobstore::InMemoryBlobStore,
+    identifier::{SenderIdentifiers, TransactionId},
+    noop::MockTransactionValidator,
+    pool::txpool::TxPool,
+    Pool, PoolConfig, PoolDump, PoolTransaction, TransactionOrdering, ValidPoolTransaction,
+};
+use reth_primitives::FromRecoveredTransaction;
+use std::{ops::Deref, time::Instant};
 
 mod gen;
 pub use gen::*;
@@ -90,3 +97,45 @@ impl Deref for TestPoolBuilder {
 pub fn testing_pool() -> TestPool {
     TestPoolBuilder::default().into()
 }
+
+/// Reconstructs a [TxPool] from a [PoolDump], e.g. to analyze the dump of a live pool in a test.
+///
+/// The transactions are inserted against the on-chain state of their senders in the dump, so the
+/// sub-pools of the reconstructed pool match the dumped pool.
+///
+/// # Panics
+///
+/// If the pool rejects a transaction of the dump.
+pub fn txpool_from_dump<T>(
+    ordering: T,
+    config: PoolConfig,
+    dump: &PoolDump,
+) -> alloy_rlp::Result<TxPool<T>>
+where
+    T: TransactionOrdering,
+    T::Transaction: FromRecoveredTransaction,
+{
+    let mut pool = TxPool::new(ordering, config);
+    pool.set_block_info(dump.block_info);
+
+    let mut ids = SenderIdentifiers::default();
+    let transactions = dump.decode_transactions::<T::Transaction>()?;
+    for (entry, (transaction, origin)) in dump.transactions.iter().zip(transactions) {
+        let sender = transaction.sender();
+        let (on_chain_balance, on_chain_nonce) =
+            dump.sender(&sender).map(|info| (info.balance, info.state_nonce)).unwrap_or_default();
+        let transaction_id =
+            TransactionId::new(ids.sender_id_or_create(sender), transaction.nonce());
+        let tx = ValidPoolTransaction {
+            transaction,
+            transaction_id,
+            propagate: entry.propagate,
+            timestamp: Instant::now(),
+            origin,
+        };
+        let hash = *tx.hash();
+        pool.add_transaction(tx, on_chain_balance, on_chain_nonce)
+            .unwrap_or_else(|err| panic!("failed to insert transaction {hash}: {err}"));
+    }
+    Ok(pool)
+}
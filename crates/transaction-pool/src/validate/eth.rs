@@ -4,27 +4,28 @@ use crate::{
     blobstore::BlobStore,
     error::{Eip4844PoolTransactionError, InvalidPoolTransactionError},
     traits::TransactionOrigin,
-    validate::{ValidTransaction, ValidationTask, MAX_INIT_CODE_BYTE_SIZE},
+    validate::{ValidTransaction, ValidationTask},
     EthBlobTransactionSidecar, EthPoolTransaction, LocalTransactionConfig, PoolTransaction,
     TransactionValidationOutcome, TransactionValidationTaskExecutor, TransactionValidator,
 };
 use reth_primitives::{
-    constants::{
-        eip4844::{MAINNET_KZG_TRUSTED_SETUP, MAX_BLOBS_PER_BLOCK},
-        ETHEREUM_BLOCK_GAS_LIMIT,
-    },
+    constants::{eip4844::MAINNET_KZG_TRUSTED_SETUP, ETHEREUM_BLOCK_GAS_LIMIT},
     kzg::KzgSettings,
     revm::compat::calculate_intrinsic_gas_after_merge,
     ChainSpec, GotExpected, InvalidTransactionError, SealedBlock, EIP1559_TX_TYPE_ID,
     EIP2930_TX_TYPE_ID, EIP4844_TX_TYPE_ID, LEGACY_TX_TYPE_ID,
 };
-use reth_provider::{AccountReader, BlockReaderIdExt, StateProviderFactory};
+use reth_provider::{AccountReader, BlockReaderIdExt, ChainSpecProvider, StateProviderFactory};
 use reth_tasks::TaskSpawner;
 use std::{
     marker::PhantomData,
-    sync::{atomic::AtomicBool, Arc},
+    sync::{
+        atomic::{AtomicBool, AtomicU64},
+        Arc,
+    },
 };
 use tokio::sync::Mutex;
+use tracing::warn;
 
 #[cfg(feature = "optimism")]
 use reth_revm::optimism::RethL1BlockInfo;
@@ -67,6 +68,16 @@ where
     }
 }
 
+impl<Client, Tx> ChainSpecProvider for EthTransactionValidator<Client, Tx>
+where
+    Client: Send + Sync,
+    Tx: Send + Sync,
+{
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        self.inner.chain_spec.clone()
+    }
+}
+
 #[async_trait::async_trait]
 impl<Client, Tx> TransactionValidator for EthTransactionValidator<Client, Tx>
 where
@@ -204,9 +215,22 @@ where
             )
         }
 
+        // Reject transactions that exceed the maximum transaction size of the chain
+        if let Some(max_tx_size) = self.chain_spec.max_tx_size {
+            if transaction.encoded_length() > max_tx_size {
+                let size = transaction.encoded_length();
+                return TransactionValidationOutcome::Invalid(
+                    transaction,
+                    InvalidPoolTransactionError::OversizedData(size, max_tx_size),
+                )
+            }
+        }
+
         // Check whether the init code size has been exceeded.
         if self.fork_tracker.is_shanghai_activated() {
-            if let Err(err) = ensure_max_init_code_size(&transaction, MAX_INIT_CODE_BYTE_SIZE) {
+            if let Err(err) =
+                ensure_max_init_code_size(&transaction, self.chain_spec.max_init_code_size)
+            {
                 return TransactionValidationOutcome::Invalid(transaction, err)
             }
         }
@@ -277,14 +301,15 @@ where
                 )
             }
 
-            if blob_count > MAX_BLOBS_PER_BLOCK {
+            let max_blob_count = self.fork_tracker.max_blob_count() as usize;
+            if blob_count > max_blob_count {
                 // too many blobs
                 return TransactionValidationOutcome::Invalid(
                     transaction,
                     InvalidPoolTransactionError::Eip4844(
                         Eip4844PoolTransactionError::TooManyEip4844Blobs {
                             have: blob_count,
-                            permitted: MAX_BLOBS_PER_BLOCK,
+                            permitted: max_blob_count,
                         },
                     ),
                 )
@@ -454,6 +479,12 @@ where
         if self.chain_spec.is_shanghai_active_at_timestamp(new_tip_block.timestamp) {
             self.fork_tracker.shanghai.store(true, std::sync::atomic::Ordering::Relaxed);
         }
+
+        let max_blob_count =
+            self.chain_spec.blob_params_at_timestamp(new_tip_block.timestamp).max_blob_count;
+        self.fork_tracker
+            .max_blob_count
+            .store(max_blob_count, std::sync::atomic::Ordering::Relaxed);
     }
 }
 
@@ -465,6 +496,8 @@ pub struct EthTransactionValidatorBuilder {
     shanghai: bool,
     /// Fork indicator whether we are in the Cancun hardfork.
     cancun: bool,
+    /// The maximum number of blobs per block at the head.
+    max_blob_count: u64,
     /// Whether using EIP-2718 type transactions is allowed
     eip2718: bool,
     /// Whether using EIP-1559 type transactions is allowed
@@ -489,19 +522,33 @@ pub struct EthTransactionValidatorBuilder {
 }
 
 impl EthTransactionValidatorBuilder {
-    /// Creates a new builder for the given [ChainSpec]
-    pub fn new(chain_spec: Arc<ChainSpec>) -> Self {
+    /// Creates a new builder for the [ChainSpec] of the given provider
+    pub fn new(chain_spec: impl ChainSpecProvider) -> Self {
+        let chain_spec = chain_spec.chain_spec();
         // If cancun is enabled at genesis, enable it
         let cancun = chain_spec.is_cancun_active_at_timestamp(chain_spec.genesis_timestamp());
+        let max_blob_count =
+            chain_spec.blob_params_at_timestamp(chain_spec.genesis_timestamp()).max_blob_count;
+        // chains that raise the maximum transaction size accept larger transactions by default
+        let max_tx_input_bytes = chain_spec.max_tx_size.unwrap_or(DEFAULT_MAX_TX_INPUT_BYTES);
+        // the node loads the setup with its own error handling, see `kzg_settings`
+        let kzg_settings = chain_spec.kzg_settings().unwrap_or_else(|err| {
+            warn!(
+                target: "txpool",
+                %err,
+                "Failed to load the KZG settings of the chain, using the mainnet trusted setup"
+            );
+            Arc::clone(&MAINNET_KZG_TRUSTED_SETUP)
+        });
 
         Self {
             chain_spec,
             block_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT,
             minimum_priority_fee: None,
             additional_tasks: 1,
-            kzg_settings: Arc::clone(&MAINNET_KZG_TRUSTED_SETUP),
+            kzg_settings,
             local_transactions_config: Default::default(),
-            max_tx_input_bytes: DEFAULT_MAX_TX_INPUT_BYTES,
+            max_tx_input_bytes,
 
             // by default all transaction types are allowed
             eip2718: true,
@@ -513,6 +560,7 @@ impl EthTransactionValidatorBuilder {
 
             // TODO: can hard enable by default once mainnet transitioned
             cancun,
+            max_blob_count,
         }
     }
 
@@ -593,6 +641,7 @@ impl EthTransactionValidatorBuilder {
     pub fn with_head_timestamp(mut self, timestamp: u64) -> Self {
         self.cancun = self.chain_spec.is_cancun_active_at_timestamp(timestamp);
         self.shanghai = self.chain_spec.is_shanghai_active_at_timestamp(timestamp);
+        self.max_blob_count = self.chain_spec.blob_params_at_timestamp(timestamp).max_blob_count;
         self
     }
 
@@ -615,6 +664,7 @@ impl EthTransactionValidatorBuilder {
             chain_spec,
             shanghai,
             cancun,
+            max_blob_count,
             eip2718,
             eip1559,
             eip4844,
@@ -626,8 +676,11 @@ impl EthTransactionValidatorBuilder {
             ..
         } = self;
 
-        let fork_tracker =
-            ForkTracker { shanghai: AtomicBool::new(shanghai), cancun: AtomicBool::new(cancun) };
+        let fork_tracker = ForkTracker {
+            shanghai: AtomicBool::new(shanghai),
+            cancun: AtomicBool::new(cancun),
+            max_blob_count: AtomicU64::new(max_blob_count),
+        };
 
         let inner = EthTransactionValidatorInner {
             chain_spec,
@@ -699,6 +752,8 @@ pub(crate) struct ForkTracker {
     pub(crate) shanghai: AtomicBool,
     /// Tracks if cancun is activated at the block's timestamp.
     pub(crate) cancun: AtomicBool,
+    /// Tracks the maximum number of blobs per block at the block's timestamp.
+    pub(crate) max_blob_count: AtomicU64,
 }
 
 impl ForkTracker {
@@ -711,6 +766,11 @@ impl ForkTracker {
     pub(crate) fn is_cancun_activated(&self) -> bool {
         self.cancun.load(std::sync::atomic::Ordering::Relaxed)
     }
+
+    /// Returns the maximum number of blobs per block.
+    pub(crate) fn max_blob_count(&self) -> u64 {
+        self.max_blob_count.load(std::sync::atomic::Ordering::Relaxed)
+    }
 }
 
 /// Ensure that the code size is not greater than `max_init_code_size`.
@@ -763,7 +823,8 @@ mod tests {
             TransactionPool,
         };
         use reth_primitives::{
-            hex, FromRecoveredPooledTransaction, PooledTransactionsElement, MAINNET, U256,
+            hex, ChainSpecBuilder, FromRecoveredPooledTransaction, PooledTransactionsElement,
+            MAINNET, U256,
         };
         use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
 
@@ -800,6 +861,19 @@ mod tests {
         assert!(res.is_ok());
         let tx = pool.get(transaction.hash());
         assert!(tx.is_some());
+
+        // a chain with a lower init code limit rejects the contract creation
+        let chain_spec = ChainSpecBuilder::from(&*MAINNET).max_init_code_size(1024).build();
+        let validator = EthTransactionValidatorBuilder::new(Arc::new(chain_spec))
+            .build(MockEthProvider::default(), InMemoryBlobStore::default());
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::ExceedsMaxInitCodeSize(_, 1024)
+            )
+        ));
     }
 
     #[cfg(feature = "optimism")]
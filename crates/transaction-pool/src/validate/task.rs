@@ -7,8 +7,8 @@ use crate::{
     TransactionValidator,
 };
 use futures_util::{lock::Mutex, StreamExt};
-use reth_primitives::{ChainSpec, SealedBlock};
-use reth_provider::BlockReaderIdExt;
+use reth_primitives::SealedBlock;
+use reth_provider::{BlockReaderIdExt, ChainSpecProvider};
 use reth_tasks::TaskSpawner;
 use std::{future::Future, pin::Pin, sync::Arc};
 use tokio::{
@@ -92,7 +92,7 @@ pub struct TransactionValidationTaskExecutor<V> {
 
 impl TransactionValidationTaskExecutor<()> {
     /// Convenience method to create a [EthTransactionValidatorBuilder]
-    pub fn eth_builder(chain_spec: Arc<ChainSpec>) -> EthTransactionValidatorBuilder {
+    pub fn eth_builder(chain_spec: impl ChainSpecProvider) -> EthTransactionValidatorBuilder {
         EthTransactionValidatorBuilder::new(chain_spec)
     }
 }
@@ -101,13 +101,13 @@ impl<Client, Tx> TransactionValidationTaskExecutor<EthTransactionValidator<Clien
 where
     Client: BlockReaderIdExt,
 {
-    /// Creates a new instance for the given [ChainSpec]
+    /// Creates a new instance for the [ChainSpec](reth_primitives::ChainSpec) of the given provider
     ///
     /// This will spawn a single validation tasks that performs the actual validation.
     /// See [TransactionValidationTaskExecutor::eth_with_additional_tasks]
     pub fn eth<T, S: BlobStore>(
         client: Client,
-        chain_spec: Arc<ChainSpec>,
+        chain_spec: impl ChainSpecProvider,
         blob_store: S,
         tasks: T,
     ) -> Self
@@ -117,7 +117,7 @@ where
         Self::eth_with_additional_tasks(client, chain_spec, blob_store, tasks, 0)
     }
 
-    /// Creates a new instance for the given [ChainSpec]
+    /// Creates a new instance for the [ChainSpec](reth_primitives::ChainSpec) of the given provider
     ///
     /// By default this will enable support for:
     ///   - shanghai
@@ -128,7 +128,7 @@ where
     /// `num_additional_tasks` additional tasks.
     pub fn eth_with_additional_tasks<T, S: BlobStore>(
         client: Client,
-        chain_spec: Arc<ChainSpec>,
+        chain_spec: impl ChainSpecProvider,
         blob_store: S,
         tasks: T,
         num_additional_tasks: usize,
@@ -185,13 +185,11 @@ mod tests {
         All expected testspec results were obtained from querying proof RPC on the running geth instance `geth init crates/trie/testdata/proof-genesis.json && geth --http`.
     */
     static TEST_SPEC: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
-        ChainSpec {
-            chain: Chain::from_id(12345),
-            genesis: serde_json::from_str(include_str!("../testdata/proof-genesis.json"))
-                .expect("Can't deserialize test genesis json"),
-            ..Default::default()
-        }
-        .into()
+        let mut chain_spec = ChainSpec::default();
+        chain_spec.chain = Chain::from_id(12345);
+        chain_spec.genesis = serde_json::from_str(include_str!("../testdata/proof-genesis.json"))
+            .expect("Can't deserialize test genesis json");
+        chain_spec.into()
     });
 
     fn convert_to_proof<'a>(path: impl IntoIterator<Item = &'a str>) -> Vec<Bytes> {
@@ -63,8 +63,9 @@ impl RethNodeCommandConfig for MyCustomBuilder {
 
         let payload_job_config = BasicPayloadJobGeneratorConfig::default()
             .interval(conf.interval())
-            .deadline(conf.deadline())
+            .deadline(conf.deadline_for_chain(&components.chain_spec()))
             .max_payload_tasks(conf.max_payload_tasks())
+            .prewarm_transactions(conf.prewarm_transactions())
             .extradata(conf.extradata_rlp_bytes())
             .max_gas_limit(conf.max_gas_limit());
 
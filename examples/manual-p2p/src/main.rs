@@ -95,7 +95,7 @@ async fn handshake_p2p(
 // Perform a ETH Wire handshake with a peer
 async fn handshake_eth(p2p_stream: AuthedP2PStream) -> eyre::Result<(AuthedEthStream, Status)> {
     let fork_filter = MAINNET.fork_filter(Head {
-        timestamp: MAINNET.fork(Hardfork::Shanghai).as_timestamp().unwrap(),
+        timestamp: MAINNET.fork_timestamp(Hardfork::Shanghai).unwrap(),
         ..Default::default()
     });
 
@@ -1,36 +1,32 @@
 use reth_primitives::{
-    b256, BaseFeeParams, Chain, ChainSpec, ForkCondition, ForkTimestamps, Hardfork, Head,
-    NodeRecord, B256,
+    b256, Chain, ChainSpec, ChainSpecBuilder, ForkCondition, Hardfork, Head, NodeRecord,
+    SnapshotConfig, B256,
 };
 
-use std::{collections::BTreeMap, sync::Arc};
+use std::sync::Arc;
 
 const SHANGAI_BLOCK: u64 = 50523000;
 
 pub(crate) fn polygon_chain_spec() -> Arc<ChainSpec> {
     const GENESIS: B256 = b256!("a9c28ce2141b56c474f1dc504bee9b01eb1bd7d1a507580d5519d4437a97de1b");
 
-    ChainSpec {
-        chain: Chain::from_id(137),
+    let mut chain_spec = ChainSpecBuilder::default()
+        .chain(Chain::from_id(137))
         // <https://github.com/maticnetwork/bor/blob/d521b8e266b97efe9c8fdce8167e9dd77b04637d/builder/files/genesis-mainnet-v1.json>
-        genesis: serde_json::from_str(include_str!("./genesis.json")).expect("deserialize genesis"),
-        genesis_hash: Some(GENESIS),
-        fork_timestamps: ForkTimestamps::default().shanghai(1681338455),
-        paris_block_and_final_difficulty: None,
-        hardforks: BTreeMap::from([
-            (Hardfork::Petersburg, ForkCondition::Block(0)),
-            (Hardfork::Istanbul, ForkCondition::Block(3395000)),
-            (Hardfork::MuirGlacier, ForkCondition::Block(3395000)),
-            (Hardfork::Berlin, ForkCondition::Block(14750000)),
-            (Hardfork::London, ForkCondition::Block(23850000)),
-            (Hardfork::Shanghai, ForkCondition::Block(SHANGAI_BLOCK)),
-        ]),
-        deposit_contract: None,
-        base_fee_params: reth_primitives::BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-        snapshot_block_interval: 500_000,
-        prune_delete_limit: 0,
-    }
-    .into()
+        .genesis(serde_json::from_str(include_str!("./genesis.json")).expect("deserialize genesis"))
+        .with_fork(Hardfork::Petersburg, ForkCondition::Block(0))
+        .with_fork(Hardfork::Istanbul, ForkCondition::Block(3395000))
+        .with_fork(Hardfork::MuirGlacier, ForkCondition::Block(3395000))
+        .with_fork(Hardfork::Berlin, ForkCondition::Block(14750000))
+        .with_fork(Hardfork::London, ForkCondition::Block(23850000))
+        .with_fork(Hardfork::Shanghai, ForkCondition::Block(SHANGAI_BLOCK))
+        .max_future_timestamp_drift(15)
+        .block_time(2)
+        .build();
+    chain_spec.genesis_hash = Some(GENESIS);
+    chain_spec.snapshot_config = SnapshotConfig::new(500_000);
+    chain_spec.prune_config = Default::default();
+    chain_spec.into()
 }
 
 /// Polygon mainnet boot nodes <https://github.com/maticnetwork/bor/blob/master/params/bootnodes.go#L79>